@@ -1,4 +1,4 @@
-use chronx_core::constants::{POW_INITIAL_DIFFICULTY, POW_MAX_DIFFICULTY, POW_MIN_DIFFICULTY};
+use chronx_core::constants::POW_INITIAL_DIFFICULTY;
 
 /// Configuration and state for dynamic PoW difficulty adjustment.
 ///
@@ -15,6 +15,11 @@ pub struct DifficultyConfig {
     pub window_size: u32,
     /// Timestamps (ms) of transactions in the current window.
     window_samples: Vec<u64>,
+    /// Exponential moving average of the gap between solves (ms), smoothed
+    /// over `window_size` solves. 0 until two solves have been seen.
+    pub ema_gap_ms: f64,
+    /// Timestamp (ms) of the latest solve, for `ema_gap_ms`.
+    last_solve_ms: Option<u64>,
 }
 
 impl Default for DifficultyConfig {
@@ -24,6 +29,8 @@ impl Default for DifficultyConfig {
             target_solve_ms: 10_000, // 10 seconds
             window_size: 100,
             window_samples: Vec::new(),
+            ema_gap_ms: 0.0,
+            last_solve_ms: None,
         }
     }
 }
@@ -35,12 +42,24 @@ impl DifficultyConfig {
             target_solve_ms,
             window_size,
             window_samples: Vec::new(),
+            ema_gap_ms: 0.0,
+            last_solve_ms: None,
         }
     }
 
     /// Record a new transaction solve timestamp (Unix ms).
     /// Returns the new difficulty if an adjustment was triggered.
     pub fn record_solve(&mut self, timestamp_ms: u64) -> Option<u8> {
+        if let Some(last) = self.last_solve_ms {
+            let gap = timestamp_ms.saturating_sub(last) as f64;
+            self.ema_gap_ms = if self.ema_gap_ms == 0.0 {
+                gap
+            } else {
+                let alpha = 2.0 / (self.window_size as f64 + 1.0);
+                alpha * gap + (1.0 - alpha) * self.ema_gap_ms
+            };
+        }
+        self.last_solve_ms = Some(timestamp_ms);
         self.window_samples.push(timestamp_ms);
         if self.window_samples.len() >= self.window_size as usize {
             let new_diff = adjust_difficulty(self);
@@ -50,36 +69,27 @@ impl DifficultyConfig {
         }
         None
     }
-}
-
-/// Compute a new difficulty from the current window of solve timestamps.
-pub fn adjust_difficulty(config: &DifficultyConfig) -> u8 {
-    let samples = &config.window_samples;
-    if samples.len() < 2 {
-        return config.current;
-    }
 
-    // Average gap between consecutive solves (ms).
-    let mut total_gap = 0u64;
-    for i in 1..samples.len() {
-        total_gap += samples[i].saturating_sub(samples[i - 1]);
+    /// Force the current difficulty (operator override, e.g. on config reload).
+    /// Discards the in-progress adjustment window so stale samples don't
+    /// immediately undo the override.
+    pub fn set_manual_override(&mut self, difficulty: u8) {
+        self.current = difficulty;
+        self.window_samples.clear();
     }
-    let avg_gap_ms = total_gap / (samples.len() as u64 - 1);
-
-    if avg_gap_ms == 0 {
-        return (config.current + 1).min(POW_MAX_DIFFICULTY);
-    }
-
-    // Scale difficulty proportionally: if solves are too fast, increase; too slow, decrease.
-    // Use integer arithmetic: new = current * target / actual, clamped.
-    let scaled = (config.current as u64).saturating_mul(config.target_solve_ms) / avg_gap_ms;
+}
 
-    scaled.clamp(POW_MIN_DIFFICULTY as u64, POW_MAX_DIFFICULTY as u64) as u8
+/// Compute a new difficulty from the current window of solve timestamps,
+/// with the same rule the DAG applies in
+/// [`required_difficulty`](chronx_dag::required_difficulty).
+pub fn adjust_difficulty(config: &DifficultyConfig) -> u8 {
+    chronx_dag::difficulty::retarget(config.current, config.target_solve_ms, &config.window_samples)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chronx_core::constants::{POW_MAX_DIFFICULTY, POW_MIN_DIFFICULTY};
 
     #[test]
     fn difficulty_increases_when_too_fast() {
@@ -111,4 +121,28 @@ mod tests {
         );
         assert!(new_diff >= POW_MIN_DIFFICULTY);
     }
+
+    #[test]
+    fn ema_tracks_solve_gaps() {
+        let mut cfg = DifficultyConfig::new(20, 10_000, 3);
+        cfg.record_solve(0);
+        assert_eq!(cfg.ema_gap_ms, 0.0);
+        cfg.record_solve(4_000);
+        assert_eq!(cfg.ema_gap_ms, 4_000.0);
+        // alpha = 2 / (3 + 1) = 0.5
+        cfg.record_solve(6_000);
+        assert_eq!(cfg.ema_gap_ms, 3_000.0);
+    }
+
+    #[test]
+    fn manual_override_resets_window() {
+        let mut cfg = DifficultyConfig::new(20, 10_000, 4);
+        cfg.record_solve(0);
+        cfg.record_solve(1_000);
+        cfg.record_solve(2_000);
+        cfg.set_manual_override(12);
+        assert_eq!(cfg.current, 12);
+        // The pre-override samples are gone, so the next solve doesn't adjust.
+        assert!(cfg.record_solve(3_000).is_none());
+    }
 }
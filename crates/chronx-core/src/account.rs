@@ -9,6 +9,8 @@
 //! All fields added in V2, V3, and V3.1 use `#[serde(default)]` so that records
 //! serialised by older node versions deserialise correctly without migration.
 
+use std::sync::LazyLock;
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::{AccountId, Balance, DilithiumPublicKey, EvidenceHash, Nonce, Timestamp, TxId};
@@ -93,8 +95,10 @@ pub struct RecoveryState {
     /// Unix timestamp after which the recovery may be executed (delay for challenge window).
     pub recovery_execute_after: Option<Timestamp>,
     /// Chronos bonded by the recovery initiator (slashed on bad-faith recovery).
+    /// Held in [`RECOVERY_ESCROW_ACCOUNT`] while the recovery is active.
     pub recovery_bond: Balance,
     /// Chronos bonded by a challenger who disputes the recovery evidence.
+    /// Held in [`RECOVERY_ESCROW_ACCOUNT`] alongside `recovery_bond`.
     pub challenge_bond: Balance,
     /// Verifier decision status for this recovery.
     pub decision_status: RecoveryDecisionStatus,
@@ -108,6 +112,22 @@ pub struct RecoveryState {
     pub votes_reject: Vec<TxId>,
     /// Whether an active challenge has been raised against this recovery.
     pub challenge_active: bool,
+    /// Account that started this recovery and posted `recovery_bond`.
+    /// Refunded on a clean finalization; the party slashed if the recovery fails.
+    #[serde(default)]
+    pub initiator: Option<AccountId>,
+    /// Account that challenged this recovery and posted `challenge_bond`.
+    #[serde(default)]
+    pub challenger: Option<AccountId>,
+    /// Accounts of verifiers who have voted (either way) on this recovery.
+    #[serde(default)]
+    pub voters: Vec<AccountId>,
+    /// Off-chain location of the initiator's evidence (`ipfs://…` or `https://…`).
+    #[serde(default)]
+    pub evidence_uri: Option<String>,
+    /// Off-chain location of the challenger's counter-evidence.
+    #[serde(default)]
+    pub counter_evidence_uri: Option<String>,
 }
 
 impl Default for RecoveryState {
@@ -125,10 +145,28 @@ impl Default for RecoveryState {
             votes_approve: Vec::new(),
             votes_reject: Vec::new(),
             challenge_active: false,
+            initiator: None,
+            challenger: None,
+            voters: Vec::new(),
+            evidence_uri: None,
+            counter_evidence_uri: None,
         }
     }
 }
 
+/// Holds every in-flight `recovery_bond` and `challenge_bond`:
+/// `StartRecovery` and `ChallengeRecovery` credit it, `FinalizeRecovery` and
+/// `ExpireRecovery` pay out of it. No key controls it.
+/// BLAKE3("recovery_escrow_v1").
+pub static RECOVERY_ESCROW_ACCOUNT: LazyLock<AccountId> =
+    LazyLock::new(|| AccountId::from_bytes(*blake3::hash(b"recovery_escrow_v1").as_bytes()));
+
+/// Holds the bond of every open governance proposal:
+/// `SubmitGovernanceProposal` credits it and `FinalizeGovernance` pays it
+/// back. No key controls it. BLAKE3("governance_escrow_v1").
+pub static GOVERNANCE_ESCROW_ACCOUNT: LazyLock<AccountId> =
+    LazyLock::new(|| AccountId::from_bytes(*blake3::hash(b"governance_escrow_v1").as_bytes()));
+
 // ── PostRecoveryRestriction ───────────────────────────────────────────────────
 
 /// Temporary spending limits imposed after a recovery finalizes.
@@ -199,6 +237,23 @@ pub struct Account {
     /// Pending withdrawal flag (processed at next instrument expiry).
     #[serde(default)]
     pub savings_withdrawal_pending: bool,
+
+    // ── Nonce window ─────────────────────────────────────────────────────────
+    /// Out-of-order nonces already used: bit i set = `nonce + i` consumed.
+    /// Bit 0 is always clear once a transaction has been applied.
+    #[serde(default)]
+    pub nonce_bitmap: u64,
+
+    // ── Verifier activity ────────────────────────────────────────────────────
+    /// Recovery votes cast by this account as a verifier.
+    #[serde(default)]
+    pub votes_cast: u64,
+    /// When this account last cast a recovery vote.
+    #[serde(default)]
+    pub verifier_last_vote_at: Option<Timestamp>,
+    /// Recovery votes cast on recoveries not yet finalized.
+    #[serde(default)]
+    pub verifier_active_vote_count: u32,
 }
 
 impl Account {
@@ -225,6 +280,10 @@ impl Account {
             savings_balance: 0,
             savings_invested: false,
             savings_withdrawal_pending: false,
+            nonce_bitmap: 0,
+            votes_cast: 0,
+            verifier_last_vote_at: None,
+            verifier_active_vote_count: 0,
         }
     }
 
@@ -247,7 +306,11 @@ pub enum ExpiryPolicy {
     RedirectTo(AccountId),
 }
 
-/// Recurring schedule for future repeating locks (scaffold, inactive in V1).
+/// Recurring schedule for repeating locks. `count` is the total number of
+/// installments, including the lock created with the policy. Later
+/// installments are created one at a time as child locks when the previous
+/// one is claimed (see `Action::ClaimAndScheduleNext`) or, failing that,
+/// once it matures (`StateEngine::tick_recurring_locks`).
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub enum RecurringPolicy {
     None,
@@ -256,8 +319,32 @@ pub enum RecurringPolicy {
     Annual { count: u32 },
 }
 
-/// Future multi-recipient split lock (scaffold, inactive in V1).
-/// `recipients` is a list of (AccountId, basis_points); values must sum to 10000.
+impl RecurringPolicy {
+    /// Total installments in the series.
+    pub fn count(&self) -> u32 {
+        match self {
+            RecurringPolicy::None => 0,
+            RecurringPolicy::Weekly { count }
+            | RecurringPolicy::Monthly { count }
+            | RecurringPolicy::Annual { count } => *count,
+        }
+    }
+
+    /// Seconds between consecutive installments.
+    pub fn interval_secs(&self) -> i64 {
+        use crate::constants::{ONE_YEAR_SECS, RECURRING_MONTH_SECS, RECURRING_WEEK_SECS};
+        match self {
+            RecurringPolicy::None => 0,
+            RecurringPolicy::Weekly { .. } => RECURRING_WEEK_SECS,
+            RecurringPolicy::Monthly { .. } => RECURRING_MONTH_SECS,
+            RecurringPolicy::Annual { .. } => ONE_YEAR_SECS,
+        }
+    }
+}
+
+/// Multi-recipient split lock: a claim pays each recipient its share of the
+/// amount. `recipients` is a list of (AccountId, basis_points); values must
+/// sum to 10000.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct SplitPolicy {
     pub recipients: Vec<(AccountId, u16)>,
@@ -272,6 +359,8 @@ pub struct SplitPolicy {
 ///   Pending → ClaimOpen → ClaimCommitted → ClaimRevealed
 ///           → ClaimFinalized | ClaimSlashed
 /// Ambiguous path: Pending → Ambiguous → ClaimOpen (or ClaimSlashed on timeout).
+/// A claim slashed for a bad or missing reveal may be reopened until the
+/// lock's `claim_attempts_max` is used up; the last slash leaves it Ambiguous.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub enum TimeLockStatus {
     // ── V0 / Legacy ──────────────────────────────────────────────────────────
@@ -279,7 +368,7 @@ pub enum TimeLockStatus {
     Pending,
     /// Claimed directly by recipient after maturity (V0 path).
     Claimed { claimed_at: Timestamp },
-    /// Listed for secondary market sale (scaffold, not active in V1).
+    /// Listed for secondary market sale by the holder of the claim right.
     ForSale {
         ask_price: Balance,
         listed_at: Timestamp,
@@ -333,6 +422,10 @@ pub enum TimeLockStatus {
     OracleExpiredClean { expiry_price: f64 },
     /// Attestor group declared failed; lock escalated or awaiting intervention.
     AttestorFailed { failed_group_id: String, escalated_to: Option<String> },
+    /// Released by `ExpireTimeLock` after going unclaimed past its claim
+    /// window or expiry grace period; its funds went per `action_taken`.
+    /// The node's periodic sweeps mark such locks `Cancelled` instead.
+    Expired { action_taken: ExpiryPolicy, expired_at: Timestamp },
 }
 
 impl TimeLockStatus {
@@ -348,8 +441,34 @@ impl TimeLockStatus {
                 | TimeLockStatus::ExecutorWithdrawn { .. }
                 | TimeLockStatus::OracleTriggered { .. }
                 | TimeLockStatus::OracleExpiredClean { .. }
+                | TimeLockStatus::Expired { .. }
         )
     }
+
+    /// Variant name without its fields, as shown over RPC and gossip.
+    pub fn name(&self) -> &'static str {
+        match self {
+            TimeLockStatus::Pending => "Pending",
+            TimeLockStatus::Claimed { .. } => "Claimed",
+            TimeLockStatus::ForSale { .. } => "ForSale",
+            TimeLockStatus::Ambiguous { .. } => "Ambiguous",
+            TimeLockStatus::ClaimOpen { .. } => "ClaimOpen",
+            TimeLockStatus::ClaimCommitted { .. } => "ClaimCommitted",
+            TimeLockStatus::ClaimRevealed { .. } => "ClaimRevealed",
+            TimeLockStatus::ClaimChallenged { .. } => "ClaimChallenged",
+            TimeLockStatus::ClaimFinalized { .. } => "ClaimFinalized",
+            TimeLockStatus::ClaimSlashed { .. } => "ClaimSlashed",
+            TimeLockStatus::Cancelled { .. } => "Cancelled",
+            TimeLockStatus::Reverted { .. } => "Reverted",
+            TimeLockStatus::PendingExecutor { .. } => "PendingExecutor",
+            TimeLockStatus::ExecutorWithdrawn { .. } => "ExecutorWithdrawn",
+            TimeLockStatus::PartiallyReleased { .. } => "PartiallyReleased",
+            TimeLockStatus::OracleTriggered { .. } => "OracleTriggered",
+            TimeLockStatus::OracleExpiredClean { .. } => "OracleExpiredClean",
+            TimeLockStatus::AttestorFailed { .. } => "AttestorFailed",
+            TimeLockStatus::Expired { .. } => "Expired",
+        }
+    }
 }
 
 /// What happens to the locked funds if a time-lock goes unclaimed past its
@@ -469,7 +588,7 @@ pub struct TimeLockContract {
     /// Maximum failed claim attempts before Ambiguous mode. None = unlimited.
     #[serde(default)]
     pub claim_attempts_max: Option<u8>,
-    /// Recurring lock schedule (scaffold, inactive in V1).
+    /// Recurring lock schedule. Gated by the `recurring_enabled` feature flag.
     #[serde(default)]
     pub recurring: Option<RecurringPolicy>,
     /// Raw bytes reserved for future protocol extensions. Ignored by current nodes.
@@ -488,8 +607,8 @@ pub struct TimeLockContract {
     #[serde(default)]
     pub client_ref: Option<[u8; 16]>,
 
-    // ── V3.1 Transferability fields (dormant — no consensus logic yet) ────────
-    /// Whether the claim right on this lock can be assigned to another party.
+    // ── V3.1 Transferability fields ──────────────────────────────────────────
+    /// Whether the claim right on this lock can be sold (`TimeLockSell`).
     /// Set by the sender at creation time. Defaults to false (irrevocable claim).
     #[serde(default)]
     pub transferable: bool,
@@ -576,4 +695,39 @@ pub struct TimeLockContract {
     #[serde(default)]
     pub extensions_used: Option<u32>,
 
+    // ── Recurring schedule ──────────────────────────────────────────────────
+    /// Index of the child lock created when this installment is claimed.
+    /// The first lock of a series holds 1; 0 means the lock is not part of a
+    /// recurring series.
+    #[serde(default)]
+    pub next_recurring_index: u32,
+
+    /// Id of the first lock of the recurring series this installment belongs
+    /// to, which keys its `RecurringSchedule`. `None` outside a series.
+    #[serde(default)]
+    pub recurring_series_id: Option<TxId>,
+
+    /// Set by `ValidateSplitPolicy` once every split recipient exists and the
+    /// shares sum to 10,000 basis points. A split lock cannot be claimed
+    /// until then.
+    #[serde(default)]
+    pub split_policy_validated: bool,
+
+    /// Why the lock was moved to `Cancelled` or `Expired`, when it was not
+    /// cancelled by its sender — e.g. by the expiry-policy sweep.
+    #[serde(default)]
+    pub cancellation_reason: Option<String>,
+}
+
+impl TimeLockContract {
+    /// Chronos escrowed by this lock for installments not yet created as
+    /// child locks, on top of its own `amount`. Zero once the lock is no
+    /// longer active.
+    pub fn recurring_reserve(&self) -> Balance {
+        if self.next_recurring_index == 0 || self.status.is_terminal() {
+            return 0;
+        }
+        let count = self.recurring.as_ref().map_or(0, RecurringPolicy::count);
+        self.amount * count.saturating_sub(self.next_recurring_index) as Balance
+    }
 }
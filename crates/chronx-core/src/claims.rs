@@ -121,6 +121,17 @@ pub struct ClaimState {
     pub challenge_evidence_hash: Option<[u8; 32]>,
     /// When the challenge was submitted.
     pub challenged_at: Option<Timestamp>,
+    /// Claims on this lock slashed for a bad or missing reveal, counted
+    /// against the lock's `claim_attempts_max`.
+    #[serde(default)]
+    pub claim_attempts: u8,
+    /// Set when `submit_claim_commit` moved the claim to a higher lane because
+    /// the oracle price rose after open_claim.
+    #[serde(default)]
+    pub upgraded_lane: bool,
+    /// When the lane was upgraded.
+    #[serde(default)]
+    pub lane_upgraded_at: Option<Timestamp>,
 }
 
 impl ClaimState {
@@ -142,6 +153,9 @@ impl ClaimState {
             challenge_bond: 0,
             challenge_evidence_hash: None,
             challenged_at: None,
+            claim_attempts: 0,
+            upgraded_lane: false,
+            lane_upgraded_at: None,
         }
     }
 }
@@ -352,3 +366,15 @@ pub struct OracleSnapshot {
     /// Latest submission timestamp used.
     pub updated_at: Timestamp,
 }
+
+/// One past value of an oracle snapshot, appended each time the snapshot is
+/// recomputed. Kept for `ORACLE_HISTORY_MAX_AGE_SECS`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OracleHistoryEntry {
+    /// Median price in USD cents.
+    pub price_cents: u64,
+    /// How many submissions contributed.
+    pub num_submissions: u32,
+    /// When the snapshot was recomputed.
+    pub updated_at: Timestamp,
+}
@@ -66,9 +66,25 @@ pub const TREASURY_RELEASE_COUNT: u32 = 100;
 /// Adjusts dynamically; this is the genesis default (~10 second solve time).
 pub const POW_INITIAL_DIFFICULTY: u8 = 20;
 
+/// Nonces accepted ahead of an account's current nonce, so wallets can submit
+/// several transactions without waiting for each to apply. At most 64.
+pub const NONCE_WINDOW: u64 = 16;
+
 pub const POW_MIN_DIFFICULTY: u8 = 16;
 pub const POW_MAX_DIFFICULTY: u8 = 32;
 
+/// Target gap between vertices the difficulty retarget aims for (ms).
+pub const POW_TARGET_SOLVE_MS: u64 = 10_000;
+
+/// Difficulty is retargeted at every DAG depth that is a multiple of this,
+/// from the timestamps of the preceding window of vertices.
+pub const DIFFICULTY_WINDOW: u64 = 100;
+
+/// Longest gap between a vertex and its reference parent the retarget counts
+/// (ms). Timestamps are set by the sender, so a forward-dated vertex can
+/// stretch a window by at most this much.
+pub const DIFFICULTY_MAX_SOLVE_GAP_MS: u64 = 6 * POW_TARGET_SOLVE_MS;
+
 // ── DAG / Consensus ───────────────────────────────────────────────────────────
 
 /// Minimum parent references per non-genesis vertex.
@@ -77,6 +93,9 @@ pub const DAG_MIN_PARENTS: usize = 1;
 /// Maximum parent references per vertex.
 pub const DAG_MAX_PARENTS: usize = 8;
 
+/// Tip-set size at which new vertices must merge at least two existing tips.
+pub const MAX_DAG_TIPS: usize = 32;
+
 /// Fraction of validators required for finality (numerator / denominator).
 pub const FINALITY_THRESHOLD_NUM: u64 = 2;
 pub const FINALITY_THRESHOLD_DEN: u64 = 3;
@@ -89,6 +108,9 @@ pub const RECOVERY_EXECUTION_DELAY_SECS: i64 = 180 * 24 * 3600;
 /// Window during which a recovery can be challenged (seconds). Default: 120 days.
 pub const RECOVERY_CHALLENGE_WINDOW_SECS: i64 = 120 * 24 * 3600;
 
+/// Verifiers must vote within this many seconds of the recovery starting.
+pub const VERIFIER_VOTE_DEADLINE_SECS: i64 = RECOVERY_CHALLENGE_WINDOW_SECS;
+
 /// Default verifier threshold: 3-of-5.
 pub const RECOVERY_VERIFIER_THRESHOLD: u32 = 3;
 pub const RECOVERY_VERIFIER_TOTAL: u32 = 5;
@@ -96,12 +118,36 @@ pub const RECOVERY_VERIFIER_TOTAL: u32 = 5;
 /// Minimum bond to initiate recovery (Chronos).
 pub const MIN_RECOVERY_BOND_CHRONOS: u128 = 100_000_000; // 100 KX
 
+/// Share of the recovery bond paid to the approving verifiers when a
+/// recovery finalizes, in basis points. The rest goes back to the initiator.
+pub const RECOVERY_VERIFIER_REWARD_BPS: u128 = 1_000; // 10%
+
 /// Minimum bond to challenge a recovery (Chronos).
 pub const MIN_CHALLENGE_BOND_CHRONOS: u128 = 100_000_000; // 100 KX
 
-/// Minimum stake to register as a verifier (Chronos).
+/// Minimum stake to register as a verifier (Chronos). Governance may raise
+/// it with `ProposeVerifierStakeIncrease`.
 pub const MIN_VERIFIER_STAKE_CHRONOS: u128 = 1_000_000_000; // 1000 KX
 
+/// Bond burned by a `ProposeVerifierStakeIncrease`, at minimum (Chronos).
+pub const VERIFIER_STAKE_PROPOSAL_BOND_CHRONOS: u128 = 100_000_000; // 100 KX
+
+/// Time verifiers left below a raised stake minimum have to top up before
+/// they are deregistered.
+pub const VERIFIER_GRACE_PERIOD_SECS: i64 = 30 * 24 * 3600;
+
+/// Time after a verifier's last recovery vote before it may unstake.
+pub const VERIFIER_UNSTAKE_COOLDOWN_SECS: i64 = 30 * 24 * 3600;
+
+/// Shortest voting period a governance proposal may ask for.
+pub const GOVERNANCE_MIN_VOTING_PERIOD_SECS: i64 = 7 * 24 * 3600;
+
+/// Longest voting period a governance proposal may ask for.
+pub const GOVERNANCE_MAX_VOTING_PERIOD_SECS: i64 = 90 * 24 * 3600;
+
+/// Maximum length of a recovery evidence URI (bytes).
+pub const MAX_EVIDENCE_URI_BYTES: usize = 256;
+
 /// Post-recovery restriction period (seconds). Default: 30 days.
 pub const POST_RECOVERY_RESTRICTION_SECS: i64 = 30 * 24 * 3600;
 
@@ -110,7 +156,7 @@ pub const POST_RECOVERY_RESTRICTION_SECS: i64 = 30 * 24 * 3600;
 /// Minimum bond to submit a governance proposal (Chronos).
 pub const GOVERNANCE_PROPOSAL_BOND_CHRONOS: u128 = 10_000_000_000_000; // 10M KX
 
-/// Governance voting window (seconds). Default: 14 days.
+/// Default voting period of a governance proposal (seconds): 14 days.
 pub const GOVERNANCE_VOTING_WINDOW_SECS: i64 = 14 * 24 * 3600;
 
 /// Governance quorum: 60% of circulating supply must vote.
@@ -127,6 +173,9 @@ pub const SCHEMA_BOND_CHRONOS: u128 = 1_000_000_000; // 1,000 KX
 /// Age threshold for oracle submissions to be included in a snapshot (seconds).
 pub const ORACLE_MAX_AGE_SECS: i64 = 3_600; // 1 hour
 
+/// How long past oracle prices are kept for `chronx_getOracleHistory` (seconds).
+pub const ORACLE_HISTORY_MAX_AGE_SECS: i64 = 30 * 24 * 3600; // 30 days
+
 /// Minimum oracle submissions needed before a snapshot is valid.
 pub const ORACLE_MIN_SUBMISSIONS: usize = 3;
 
@@ -135,20 +184,44 @@ pub const ORACLE_MIN_SUBMISSIONS: usize = 3;
 /// After this window, V0 locks can still be claimed directly; V1 locks must use OpenClaim.
 pub const UNLOCK_GRACE_SECS: i64 = 7 * 24 * 3600; // 7 days
 
+/// How long before a lock's claim window closes an unnotified email
+/// recipient is flagged for off-chain notification (seconds).
+pub const CLAIM_WINDOW_WARNING_SECS: i64 = 24 * 3600; // 24 hours
+
 // ── V3 Lock / Transaction validation ─────────────────────────────────────────
 
 /// Minimum lock amount (1 grain).
 pub const MIN_LOCK_AMOUNT_CHRONOS: u128 = 1;
 
+/// Dust limit for transfers and new locks (1 milli-KX). Governance can
+/// override it through `GovernanceParams::min_transfer_chronos`.
+pub const MIN_TRANSFER_AMOUNT_CHRONOS: u128 = 1_000;
+
+/// Floor for the fee `chronx_estimateFee` suggests. Zero until governance
+/// sets a real minimum.
+pub const MIN_FEE_CHRONOS: u128 = 0;
+
 /// Maximum memo size in bytes (enforced at consensus level).
 pub const MAX_MEMO_BYTES: usize = 256;
 
+/// Maximum `org_identifier` size in bytes on a V1 lock.
+pub const MAX_ORG_IDENTIFIER_BYTES: usize = 256;
+
+/// Maximum times a lock's claim right may change hands (`transfer_history`).
+pub const MAX_LOCK_TRANSFERS: usize = 100;
+
 /// Maximum number of tags per lock.
 pub const MAX_TAGS_PER_LOCK: usize = 5;
 
 /// Maximum length of each individual tag (characters).
 pub const MAX_TAG_LENGTH: usize = 32;
 
+/// Default cap on an account's open outgoing locks; governance may change it.
+pub const MAX_OUTGOING_LOCKS_PER_ACCOUNT: u32 = 10_000;
+
+/// Default cap on an account's open incoming locks; governance may change it.
+pub const MAX_INCOMING_LOCKS_PER_ACCOUNT: u32 = 50_000;
+
 /// Maximum locks returned in a single RPC query (pagination cap).
 pub const MAX_LOCKS_PER_QUERY: usize = 100;
 
@@ -167,6 +240,12 @@ pub const MAX_EXTENSION_DATA_BYTES: usize = 1_024;
 /// Maximum recurring payment count (100 years of monthly payments).
 pub const MAX_RECURRING_COUNT: u32 = 1_200;
 
+/// Interval between weekly recurring installments.
+pub const RECURRING_WEEK_SECS: i64 = 7 * 24 * 3600;
+
+/// Interval between monthly recurring installments — a twelfth of a year.
+pub const RECURRING_MONTH_SECS: i64 = ONE_YEAR_SECS / 12;
+
 /// Maximum cancellation window — 7 days.
 pub const CANCELLATION_WINDOW_MAX_SECS: u32 = 604_800;
 
@@ -9,9 +9,24 @@ pub enum ChronxError {
     #[error("invalid nonce: expected {expected}, got {got}")]
     InvalidNonce { expected: u64, got: u64 },
 
+    #[error("nonce outside window: current {current}, window {window}")]
+    NonceOutOfWindow { current: u64, window: u64 },
+
     #[error("invalid signature")]
     InvalidSignature,
 
+    #[error("invalid signature length: expected {expected}, got {got}")]
+    InvalidSignatureLength { expected: usize, got: usize },
+
+    #[error("invalid public key length: expected {expected}, got {got}")]
+    InvalidPublicKeyLength { expected: usize, got: usize },
+
+    #[error("sender account has no registered key: first spend must include sender_public_key")]
+    MissingSenderPublicKey,
+
+    #[error("sender_public_key does not derive to the sending account")]
+    SenderPublicKeyMismatch,
+
     #[error("invalid proof-of-work")]
     InvalidPoW,
 
@@ -33,6 +48,12 @@ pub enum ChronxError {
     #[error("public memo not allowed on promises with unlock > 365 days")]
     LongHorizonMemoMustBePrivate,
 
+    #[error("sponsored transaction fee mismatch: sponsor signed for {expected} Chronos, inner transaction declares {got}")]
+    SponsoredTransactionFeeMismatch { expected: u128, got: u128 },
+
+    #[error("invalid sponsored transaction: {0}")]
+    InvalidSponsoredTransaction(String),
+
   // ── DAG errors ───────────────────────────────────────────────────────────
     #[error("vertex already exists: {0}")]
     DuplicateVertex(String),
@@ -46,6 +67,9 @@ pub enum ChronxError {
     #[error("too many parents: max {max}, got {got}")]
     TooManyParents { max: usize, got: usize },
 
+    #[error("too many active DAG tips ({current}, max {max}): vertex must merge at least two tips")]
+    TooManyActiveTips { current: usize, max: usize },
+
   // ── Time-lock errors ─────────────────────────────────────────────────────
     #[error("time-lock not yet matured (unlocks at {unlock_time})")]
     TimeLockNotMatured { unlock_time: i64 },
@@ -59,12 +83,24 @@ pub enum ChronxError {
     #[error("time-lock is irrevocable; cannot cancel after creation")]
     TimeLockIrrevocable,
 
+    #[error("time-lock is not transferable")]
+    LockNotTransferable,
+
+    #[error("time-lock is not listed for sale")]
+    LockNotForSale,
+
+    #[error("time-lock claim right already transferred the maximum of {max} times")]
+    LockTransferLimitReached { max: usize },
+
     #[error("unlock timestamp must be in the future")]
     UnlockTimestampInPast,
 
     #[error("lock amount below minimum ({min} Chronos required)")]
     LockAmountTooSmall { min: u128 },
 
+    #[error("transfer amount below dust limit ({min} Chronos required)")]
+    TransferBelowDustLimit { min: u128 },
+
     #[error("lock duration too short: minimum {min_secs} seconds")]
     LockDurationTooShort { min_secs: i64 },
 
@@ -80,18 +116,51 @@ pub enum ChronxError {
     #[error("tag too long: maximum {max} characters")]
     TagTooLong { max: usize },
 
+    #[error("sender has too many open locks: maximum {max}")]
+    TooManyOutgoingLocks { max: u32 },
+
+    #[error("recipient has too many open locks: maximum {max}")]
+    TooManyIncomingLocks { max: u32 },
+
     #[error("lock_marker exceeds maximum size of {max} bytes")]
     ExtensionDataTooLarge { max: usize },
 
     #[error("cancellation window exceeds maximum of {max} seconds")]
     CancellationWindowTooLong { max: u32 },
 
+    #[error("compound secondary amount {secondary} exceeds primary lock amount {primary}")]
+    CompoundSecondaryExceedsPrimary { primary: u128, secondary: u128 },
+
+    #[error("compound child lock already exists: {0}")]
+    CompoundChildLockExists(String),
+
     #[error("split policy basis points must sum to 10000; got {got}")]
     SplitPolicyBasisPointsMismatch { got: u32 },
 
+    #[error("lock has no split policy")]
+    NoSplitPolicy,
+
+    #[error("split policy recipient account not found: {0}")]
+    SplitRecipientNotFound(String),
+
+    #[error("split policy recipients must be distinct and exclude the lock sender: {0}")]
+    SplitPolicyDuplicateRecipient(String),
+
+    #[error("split policy must be validated with ValidateSplitPolicy before claiming")]
+    SplitPolicyNotValidated,
+
+    #[error("unsupported fiat currency: {0}")]
+    UnsupportedFiatCurrency(String),
+
     #[error("recurring count exceeds maximum of {max}")]
     RecurringCountTooLarge { max: u32 },
 
+    #[error("recurring lock schedule exhausted: no further installments to schedule")]
+    RecurringLockExhausted,
+
+    #[error("expiry policy could not be applied to lock {lock_id}: {reason}")]
+    ExpiryPolicySweep { lock_id: String, reason: String },
+
     #[error("cancellation window has expired")]
     CancellationWindowExpired,
 
@@ -113,7 +182,13 @@ pub enum ChronxError {
     #[error("only the original sender may reclaim an expired lock")]
     ReclaimNotBySender,
 
-    #[error("only the original sender may cancel a time-lock")]
+    #[error("lock has neither an unclaimed action nor an expiry policy")]
+    NoExpiryPolicy,
+
+    #[error("lock cannot expire before {expires_at}")]
+    LockNotExpired { expires_at: i64 },
+
+    #[error("only the original sender may cancel or amend a time-lock")]
     CancelNotBySender,
 
     #[error("transaction has expired (expires_at is in the past)")]
@@ -144,12 +219,66 @@ pub enum ChronxError {
     #[error("verifier already voted")]
     VerifierAlreadyVoted,
 
+    #[error("verifier vote deadline has passed")]
+    VerifierVoteDeadlinePassed,
+
     #[error("verifier stake below minimum ({min} Chronos required)")]
     VerifierStakeTooLow { min: u128 },
 
+    #[error("verifier stake minimum can only increase (currently {current} Chronos)")]
+    VerifierStakeMinimumNotIncreased { current: u128 },
+
+    #[error("verifier unstake cooldown active until {can_unstake_at}")]
+    VerifierUnstakeCooldownActive { can_unstake_at: i64 },
+
+    #[error("verifier has votes on recoveries not yet finalized")]
+    VerifierHasActiveVotes,
+
+    #[error("proposal bond below minimum ({min} Chronos required)")]
+    ProposalBondTooLow { min: u128 },
+
+    #[error("governance voting period has not elapsed (ends at {ends_at})")]
+    GovernanceVotingPeriodNotElapsed { ends_at: i64 },
+
+    #[error("governance voting period ended at {ended_at}")]
+    GovernanceVotingPeriodExpired { ended_at: i64 },
+
+    #[error("governance proposal not found: {0}")]
+    GovernanceProposalNotFound(String),
+
+    #[error("governance proposal {0} is already finalized")]
+    GovernanceProposalFinalized(String),
+
+    #[error("account has already voted on this governance proposal")]
+    GovernanceAlreadyVoted,
+
+    #[error("invalid governance proposal: {0}")]
+    InvalidGovernanceProposal(String),
+
     #[error("recovery not approved by verifiers")]
     RecoveryNotApproved,
 
+    #[error("post-recovery daily limit of {daily_limit} Chronos exceeded ({already_spent} already spent today)")]
+    PostRecoverySpendingLimitExceeded { daily_limit: u128, already_spent: u128 },
+
+    #[error("recovery escrow account missing from state")]
+    EscrowAccountMissing,
+
+    #[error("this account already has an active recovery initiation in progress")]
+    RecoveryAlreadyInitiatedByThisAccount,
+
+    #[error("recovery for account {0} has already been challenged")]
+    RecoveryAlreadyChallenged(String),
+
+    #[error("recovery has not failed: it is approved, or open to votes until {deadline}")]
+    RecoveryNotFailed { deadline: i64 },
+
+    #[error("evidence URI exceeds maximum length of {max} bytes")]
+    EvidenceUriTooLong { max: usize },
+
+    #[error("invalid evidence URI: {0}")]
+    InvalidEvidenceUri(String),
+
   // ── Auth errors ──────────────────────────────────────────────────────────
     #[error("multisig threshold not met: need {need}, got {got}")]
     MultisigThresholdNotMet { need: u32, got: u32 },
@@ -171,6 +300,12 @@ pub enum ChronxError {
     #[error("genesis supply mismatch: expected {expected}, got {got}")]
     GenesisSupplyMismatch { expected: u128, got: u128 },
 
+    #[error("custom genesis allocations overflow")]
+    GenesisAllocationOverflow,
+
+    #[error("custom genesis allocations total {requested} Chronos, public sale holds only {available}")]
+    GenesisAllocationExceedsPublicSale { requested: u128, available: u128 },
+
   // ── General ──────────────────────────────────────────────────────────────
     #[error("operation not permitted under current auth policy")]
     AuthPolicyViolation,
@@ -178,6 +313,12 @@ pub enum ChronxError {
     #[error("feature not active: {0}")]
     FeatureNotActive(String),
 
+    #[error("governance approval required: sender is not the governance wallet")]
+    GovernanceApprovalRequired,
+
+    #[error("{0} cannot be simulated: it writes outside the transaction batch")]
+    NotSimulatable(String),
+
   // ── Claims errors ─────────────────────────────────────────────────────────
     #[error("this lock uses the V2 claims framework; use open_claim instead of timelock_claim")]
     LockRequiresClaimsFramework,
@@ -185,6 +326,9 @@ pub enum ChronxError {
     #[error("claim not found for lock: {0}")]
     ClaimNotFound(String),
 
+    #[error("claim attempts exhausted; lock awaits manual resolution")]
+    ClaimAttemptsExhausted,
+
     #[error("invalid claim state transition")]
     InvalidClaimStateTransition,
 
@@ -203,6 +347,9 @@ pub enum ChronxError {
     #[error("claim bond below minimum ({min} Chronos required for this lane)")]
     ClaimBondTooLow { min: u128 },
 
+    #[error("claim lane upgraded to {new_lane} since open_claim; bond of {required_bond} Chronos required")]
+    ClaimBondTooLowAfterLaneUpgrade { new_lane: u8, required_bond: u128 },
+
     #[error("provider not found: {0}")]
     ProviderNotFound(String),
 
@@ -212,6 +359,9 @@ pub enum ChronxError {
     #[error("provider is revoked")]
     ProviderRevoked,
 
+    #[error("no active provider covers jurisdiction {code}")]
+    JurisdictionNotCovered { code: String },
+
     #[error("schema not found: {0}")]
     SchemaNotFound(u64),
 
@@ -230,6 +380,12 @@ pub enum ChronxError {
     #[error("lock must have claim_policy set to use the claims framework")]
     NoPolicyOnLock,
 
+    #[error("lock has already been upgraded to the claims framework")]
+    LockVersionAlreadyUpgraded,
+
+    #[error("org identifier exceeds maximum length of {max} bytes")]
+    OrgIdentifierTooLong { max: usize },
+
     #[error("lock is in ambiguous state; an outcome certificate is required")]
     LockAmbiguous,
 
@@ -448,3 +604,10 @@ pub enum ChronxError {
     #[error("database error")]
     DatabaseError,
 }
+
+impl ChronxError {
+    /// The variant name, e.g. `InsufficientBalance`.
+    pub fn variant_name(&self) -> String {
+        format!("{self:?}").chars().take_while(|c| c.is_alphanumeric() || *c == '_').collect()
+    }
+}
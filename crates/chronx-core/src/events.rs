@@ -0,0 +1,84 @@
+//! State events emitted by the engine.
+//!
+//! Every applied transaction appends one event per state-changing action to
+//! the `event_log` tree. The log is append-only and never read back by
+//! consensus; it exists for analytics, audit trails and read-model
+//! projections built outside the node.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{AccountId, Timestamp, TxId};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ChronxEvent {
+    TransferApplied {
+        from: AccountId,
+        to: AccountId,
+        amount: u128,
+    },
+    LockCreated {
+        lock_id: TxId,
+        sender: AccountId,
+        recipient: AccountId,
+        amount: u128,
+        unlock_at: Timestamp,
+    },
+    LockClaimed {
+        lock_id: TxId,
+        recipient: AccountId,
+        amount: u128,
+    },
+    LockCancelled {
+        lock_id: TxId,
+        sender: AccountId,
+        amount: u128,
+    },
+    RecoveryStarted {
+        target: AccountId,
+        bond: u128,
+    },
+    RecoveryVoteCast {
+        target: AccountId,
+        verifier: AccountId,
+        approve: bool,
+    },
+    RecoveryFinalized {
+        target: AccountId,
+    },
+    SplitPolicyValid {
+        lock_id: TxId,
+    },
+    RecoveryExpired {
+        target: AccountId,
+    },
+}
+
+impl ChronxEvent {
+    /// Every value `event_type` can return.
+    pub const TYPES: &'static [&'static str] = &[
+        "TransferApplied",
+        "LockCreated",
+        "LockClaimed",
+        "LockCancelled",
+        "RecoveryStarted",
+        "RecoveryVoteCast",
+        "RecoveryFinalized",
+        "SplitPolicyValid",
+        "RecoveryExpired",
+    ];
+
+    /// The variant name, used to filter the event log.
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            Self::TransferApplied { .. } => "TransferApplied",
+            Self::LockCreated { .. } => "LockCreated",
+            Self::LockClaimed { .. } => "LockClaimed",
+            Self::LockCancelled { .. } => "LockCancelled",
+            Self::RecoveryStarted { .. } => "RecoveryStarted",
+            Self::RecoveryVoteCast { .. } => "RecoveryVoteCast",
+            Self::RecoveryFinalized { .. } => "RecoveryFinalized",
+            Self::SplitPolicyValid { .. } => "SplitPolicyValid",
+            Self::RecoveryExpired { .. } => "RecoveryExpired",
+        }
+    }
+}
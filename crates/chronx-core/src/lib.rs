@@ -3,9 +3,11 @@ pub mod account;
 pub mod claims;
 pub mod constants;
 pub mod error;
+pub mod events;
 pub mod merkle;
 pub mod transaction;
 pub mod types;
+pub mod units;
 
 pub use account::*; // OraclePolicy also in transaction — account version takes precedence
 pub use claims::{
@@ -15,5 +17,6 @@ pub use claims::{
 };
 pub use constants::*;
 pub use error::ChronxError;
+pub use events::ChronxEvent;
 pub use transaction::*;
 pub use types::*;
@@ -79,6 +79,14 @@ impl BalanceMerkleTree {
         *hasher.finalize().as_bytes()
     }
 
+    /// Hash an internal node: `BLAKE3(left_child || right_child)`.
+    pub fn compute_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(left);
+        hasher.update(right);
+        *hasher.finalize().as_bytes()
+    }
+
     /// Compute the Merkle root from an array of leaf hashes.
     ///
     /// - Empty: `[0u8; 32]`
@@ -100,10 +108,7 @@ impl BalanceMerkleTree {
 
             let mut i = 0;
             while i + 1 < current_level.len() {
-                let mut hasher = blake3::Hasher::new();
-                hasher.update(&current_level[i]);
-                hasher.update(&current_level[i + 1]);
-                next_level.push(*hasher.finalize().as_bytes());
+                next_level.push(Self::compute_node(&current_level[i], &current_level[i + 1]));
                 i += 2;
             }
 
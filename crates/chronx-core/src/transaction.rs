@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::types::{
-    AccountId, Balance, DilithiumPublicKey, DilithiumSignature, EvidenceHash, Nonce, TimeLockId,
+    AccountId, Balance, DilithiumPublicKey, DilithiumSignature, EvidenceHash, GovernanceTarget, Nonce, TimeLockId,
     Timestamp, TxId,
 };
 
@@ -842,9 +842,9 @@ pub enum Action {
     /// Claim a matured time-lock. Callable only by the registered recipient.
     TimeLockClaim { lock_id: TimeLockId },
 
-    /// Mark a time-lock for sale at `ask_price` Chronos.
-    /// Data structure present; execution engine INACTIVE at V1 launch.
-    /// Secondary market scaffold — not a protocol guarantee of any return.
+    /// List a pending, transferable time-lock for sale at `ask_price` Chronos
+    /// (at least 1). Callable by the current holder of the claim right; see
+    /// `TimeLockBuy` and `DelistLock`. Not a protocol guarantee of any return.
     TimeLockSell {
         lock_id: TimeLockId,
         ask_price: Balance,
@@ -858,11 +858,15 @@ pub enum Action {
     // ── Account recovery ──────────────────────────────────────────────────────
     /// Initiate recovery of `target_account`.
     /// Requester posts a bond and commits to evidence hash.
+    /// `evidence_uri` optionally points at the evidence off-chain
+    /// (`ipfs://…` or `https://…`, max 256 bytes); only its format is checked.
     StartRecovery {
         target_account: AccountId,
         proposed_owner_key: DilithiumPublicKey,
         evidence_hash: EvidenceHash,
         bond_amount: Balance,
+        #[serde(default)]
+        evidence_uri: Option<String>,
     },
 
     /// Challenge an in-progress recovery.
@@ -870,6 +874,8 @@ pub enum Action {
         target_account: AccountId,
         counter_evidence_hash: EvidenceHash,
         bond_amount: Balance,
+        #[serde(default)]
+        counter_evidence_uri: Option<String>,
     },
 
     /// Finalize an approved recovery after delay + challenge window.
@@ -1512,6 +1518,347 @@ pub enum Action {
         applicant_signature: DilithiumSignature,
     },
 
+    // ── Compound locks ──────────────────────────────────────────────────────
+
+    /// Claim a matured lock and, in the same step, re-lock part of the
+    /// proceeds for a secondary recipient ("vest and re-lock").
+    /// The sender must be the primary lock's recipient. The sender is credited
+    /// `primary.amount - secondary_amount`; the child lock id is
+    /// [`compound_child_lock_id`] of the primary lock id.
+    CompoundLockCreate {
+        /// The matured lock being claimed.
+        primary_lock_id: TimeLockId,
+        /// Recipient of the child lock.
+        secondary_recipient: DilithiumPublicKey,
+        /// Chronos moved from the claimed amount into the child lock.
+        secondary_amount: Balance,
+        /// Unlock timestamp of the child lock.
+        secondary_unlock_at: Timestamp,
+    },
+
+    // ── Lock annotations ────────────────────────────────────────────────────
+
+    /// Replace the memo on a pending lock. Only the original sender may
+    /// update, and only while the lock's cancellation window is open.
+    UpdateLockMemo {
+        lock_id: TimeLockId,
+        /// New memo (max 256 bytes). `None` clears the memo.
+        new_memo: Option<String>,
+    },
+
+    /// Replace the tags on a pending lock. Same rules as `UpdateLockMemo`.
+    /// An empty list clears the tags.
+    UpdateLockTags {
+        lock_id: TimeLockId,
+        tags: Vec<String>,
+    },
+
+    /// Sponsored transaction: the outer sender (`sponsor`) pays the fee for a
+    /// transaction signed by another account. The inner transaction is
+    /// validated on its own (tx id, nonce, signature against its sender's
+    /// auth policy) and its actions are applied as that sender. The sponsor
+    /// is debited `sponsored_tx.fee_chronos`, which must equal the outer
+    /// transaction's `fee_chronos`. Sponsorship cannot be nested.
+    SponsoredTransaction {
+        sponsor: AccountId,
+        sponsored_tx: Box<Transaction>,
+    },
+
+    // ── Governance ──────────────────────────────────────────────────────────
+
+    /// Replace the protocol feature flags. Only the governance wallet (or the
+    /// Founder wallet) may submit this action.
+    UpdateFeatureFlags {
+        flags: FeatureFlags,
+    },
+
+    // ── Recurring locks ─────────────────────────────────────────────────────
+
+    /// Claim a matured installment of a recurring lock and create the next
+    /// one. The child lock id is [`recurring_child_lock_id`] of the claimed
+    /// lock and its `next_recurring_index`; it has the same amount and
+    /// recipient and unlocks one interval after the claimed lock. Claiming
+    /// the last installment creates no child.
+    ClaimAndScheduleNext {
+        lock_id: TimeLockId,
+    },
+
+    // ── Lock version migration ──────────────────────────────────────────────
+
+    /// Move a pending V0 lock onto the V2 claims framework (`lock_version`
+    /// 1). Only the lock's sender may submit this. Afterwards the lock can
+    /// no longer be claimed with `TimeLockClaim`; the recipient must go
+    /// through `OpenClaim` under `claim_policy_id`.
+    UpgradeLockToV1 {
+        lock_id: TimeLockId,
+        claim_policy_id: crate::claims::PolicyId,
+        org_identifier: Option<String>,
+        beneficiary_anchor: Option<[u8; 32]>,
+    },
+
+    // ── Split policy validation ─────────────────────────────────────────────
+
+    /// Confirm that every recipient in a pending lock's `split_policy` has an
+    /// account and that the shares sum to 10,000 basis points. Anyone may
+    /// submit this; a split lock cannot be claimed until it has succeeded.
+    ValidateSplitPolicy {
+        lock_id: TimeLockId,
+    },
+
+    // ── Preferred fiat currency ─────────────────────────────────────────────
+
+    /// Set the sender's preferred fiat currency (3-4 letter ISO code, e.g.
+    /// "EUR"). Must have an oracle snapshot (`KX/<currency>`) or be in the
+    /// governance `approved_currencies`. `OpenClaim` values locks without an
+    /// `oracle_hint` in the recipient's preferred currency.
+    SetPreferredFiatCurrency {
+        currency: String,
+    },
+
+    /// Clear the sender's preferred fiat currency; claims fall back to USD.
+    ClearPreferredFiatCurrency,
+
+    /// Raise the minimum verifier stake. Only the governance wallet (or the
+    /// Founder wallet) may submit this; it takes effect on approval and
+    /// `bond_amount` is burned. Verifiers already registered below
+    /// `new_minimum` have `VERIFIER_GRACE_PERIOD_SECS` to top up.
+    ProposeVerifierStakeIncrease {
+        new_minimum: Balance,
+        bond_amount: Balance,
+    },
+
+    // ── Secondary market ────────────────────────────────────────────────────
+
+    /// Buy the claim right on a lock listed with `TimeLockSell`. The ask
+    /// price moves from the buyer to the current holder, the buyer becomes
+    /// the lock's `current_beneficiary` and the lock returns to Pending.
+    TimeLockBuy {
+        lock_id: TimeLockId,
+    },
+
+    /// Withdraw a `TimeLockSell` listing; only the seller may submit this.
+    DelistLock {
+        lock_id: TimeLockId,
+    },
+
+    /// Hand the claim right on a transferable Pending lock to
+    /// `new_beneficiary`, without payment. Only the current holder may
+    /// submit this, and not before the lock's `earliest_transfer_date`.
+    TransferLockBeneficiary {
+        lock_id: TimeLockId,
+        new_beneficiary: AccountId,
+    },
+
+    // ── On-chain governance ─────────────────────────────────────────────────
+
+    /// Propose setting `target_parameter` to `proposed_value_bytes`, encoded
+    /// as that target documents. `body_hash` commits to the off-chain
+    /// proposal text. `bond_amount`, at least
+    /// `GOVERNANCE_PROPOSAL_BOND_CHRONOS`, is held until finalization and
+    /// then returned. Voting runs for `voting_period_secs`, between
+    /// `GOVERNANCE_MIN_VOTING_PERIOD_SECS` and
+    /// `GOVERNANCE_MAX_VOTING_PERIOD_SECS` (clients default to
+    /// `GOVERNANCE_VOTING_WINDOW_SECS`). The proposal is identified by this
+    /// transaction's id.
+    SubmitGovernanceProposal {
+        title: String,
+        body_hash: [u8; 32],
+        target_parameter: GovernanceTarget,
+        proposed_value_bytes: Vec<u8>,
+        bond_amount: Balance,
+        voting_period_secs: i64,
+    },
+
+    /// Vote on an open proposal within its voting period. The vote counts
+    /// `stake_weight`, capped at the sender's balance; each account votes
+    /// once.
+    VoteGovernance {
+        proposal_id: TxId,
+        approve: bool,
+        stake_weight: Balance,
+    },
+
+    /// Close a proposal once its voting window has ended. It passes with
+    /// `GOVERNANCE_QUORUM_PERCENT` of circulating supply voting and more
+    /// weight for than against. Anyone may submit this.
+    FinalizeGovernance {
+        proposal_id: TxId,
+    },
+
+    /// Release `unstake_amount` of the sender's verifier stake. Allowed once
+    /// `VERIFIER_UNSTAKE_COOLDOWN_SECS` have passed since the sender's last
+    /// recovery vote and every recovery it voted on has been finalized. A
+    /// remaining stake below the minimum deregisters the verifier.
+    DeregisterVerifier {
+        unstake_amount: Balance,
+    },
+
+    /// Release a Pending lock nobody claimed in time. With a claim window and
+    /// `unclaimed_action` it is due once the window has closed; otherwise,
+    /// with an `expiry_policy`, `UNLOCK_GRACE_SECS` after it unlocked. The
+    /// funds go back to the sender, are burned, or are redirected, and the
+    /// lock becomes `Expired`. Anyone may submit this.
+    ExpireTimeLock {
+        lock_id: TimeLockId,
+    },
+
+    /// Add to an existing verifier's stake, e.g. to meet a raised minimum
+    /// within `VERIFIER_GRACE_PERIOD_SECS`. Appended rather than kept next to
+    /// `RegisterVerifier` so the bincode index of every earlier action, and
+    /// with it every stored transaction, is unchanged.
+    TopUpVerifierStake { amount: Balance },
+
+    /// End a recovery that failed: rejected by `RECOVERY_VERIFIER_THRESHOLD`
+    /// verifiers, or short of that many approvals once
+    /// `VERIFIER_VOTE_DEADLINE_SECS` have passed. The initiator's bond is
+    /// forfeited to the challenger, if any, else burned; the challenge bond
+    /// is returned. Anyone may submit this.
+    ExpireRecovery { target_account: AccountId },
+}
+
+impl Action {
+    /// Variant name without its fields, e.g. `"Transfer"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Action::Transfer { .. } => "Transfer",
+            Action::TimeLockCreate { .. } => "TimeLockCreate",
+            Action::TimeLockClaim { .. } => "TimeLockClaim",
+            Action::TimeLockSell { .. } => "TimeLockSell",
+            Action::CancelTimeLock { .. } => "CancelTimeLock",
+            Action::StartRecovery { .. } => "StartRecovery",
+            Action::ChallengeRecovery { .. } => "ChallengeRecovery",
+            Action::FinalizeRecovery { .. } => "FinalizeRecovery",
+            Action::RegisterVerifier { .. } => "RegisterVerifier",
+            Action::VoteRecovery { .. } => "VoteRecovery",
+            Action::OpenClaim { .. } => "OpenClaim",
+            Action::SubmitClaimCommit { .. } => "SubmitClaimCommit",
+            Action::RevealClaim { .. } => "RevealClaim",
+            Action::ChallengeClaimReveal { .. } => "ChallengeClaimReveal",
+            Action::FinalizeClaim { .. } => "FinalizeClaim",
+            Action::RegisterProvider { .. } => "RegisterProvider",
+            Action::RevokeProvider { .. } => "RevokeProvider",
+            Action::RotateProviderKey { .. } => "RotateProviderKey",
+            Action::RegisterSchema { .. } => "RegisterSchema",
+            Action::DeactivateSchema { .. } => "DeactivateSchema",
+            Action::SubmitOraclePrice { .. } => "SubmitOraclePrice",
+            Action::TimeLockClaimWithSecret { .. } => "TimeLockClaimWithSecret",
+            Action::ReclaimExpiredLock { .. } => "ReclaimExpiredLock",
+            Action::VerifierRegister { .. } => "VerifierRegister",
+            Action::AgentRegister { .. } => "AgentRegister",
+            Action::AgentCodeUpdate { .. } => "AgentCodeUpdate",
+            Action::AgentLoanRequest { .. } => "AgentLoanRequest",
+            Action::ExecutorWithdraw { .. } => "ExecutorWithdraw",
+            Action::CreateInvoice { .. } => "CreateInvoice",
+            Action::FulfillInvoice { .. } => "FulfillInvoice",
+            Action::CancelInvoice { .. } => "CancelInvoice",
+            Action::CreateCredit { .. } => "CreateCredit",
+            Action::DrawCredit { .. } => "DrawCredit",
+            Action::RevokeCredit { .. } => "RevokeCredit",
+            Action::CreateDeposit { .. } => "CreateDeposit",
+            Action::SettleDeposit { .. } => "SettleDeposit",
+            Action::CreateConditional { .. } => "CreateConditional",
+            Action::AttestConditional { .. } => "AttestConditional",
+            Action::CreateLedgerEntry { .. } => "CreateLedgerEntry",
+            Action::CreateGroup { .. } => "CreateGroup",
+            Action::AddGroupMember { .. } => "AddGroupMember",
+            Action::RemoveGroupMember { .. } => "RemoveGroupMember",
+            Action::DissolveGroup { .. } => "DissolveGroup",
+            Action::TransferGroupOwnership { .. } => "TransferGroupOwnership",
+            Action::RejectInvoice { .. } => "RejectInvoice",
+            Action::LoanOffer { .. } => "LoanOffer",
+            Action::LoanAcceptance { .. } => "LoanAcceptance",
+            Action::LoanDecline { .. } => "LoanDecline",
+            Action::LoanOfferWithdrawn { .. } => "LoanOfferWithdrawn",
+            Action::LoanPayerUpdate { .. } => "LoanPayerUpdate",
+            Action::DefaultRecord { .. } => "DefaultRecord",
+            Action::LoanReinstatement { .. } => "LoanReinstatement",
+            Action::LoanWriteOff { .. } => "LoanWriteOff",
+            Action::LoanEarlyPayoff { .. } => "LoanEarlyPayoff",
+            Action::LoanCompletion { .. } => "LoanCompletion",
+            Action::LenderMemo { .. } => "LenderMemo",
+            Action::ChannelOpen { .. } => "ChannelOpen",
+            Action::ChannelClose { .. } => "ChannelClose",
+            Action::LoanExit { .. } => "LoanExit",
+            Action::LoanTransfer { .. } => "LoanTransfer",
+            Action::LoanRescissionCancel { .. } => "LoanRescissionCancel",
+            Action::LoanRescissionWaive { .. } => "LoanRescissionWaive",
+            Action::CreditVisibilityUpdate { .. } => "CreditVisibilityUpdate",
+            Action::LoanFlagPost { .. } => "LoanFlagPost",
+            Action::CreditHistoryPurge { .. } => "CreditHistoryPurge",
+            Action::AccreditedLenderRegister { .. } => "AccreditedLenderRegister",
+            Action::AccreditedLenderRevoke { .. } => "AccreditedLenderRevoke",
+            Action::DrawRequest { .. } => "DrawRequest",
+            Action::DrawApproval { .. } => "DrawApproval",
+            Action::DrawDecline { .. } => "DrawDecline",
+            Action::AuthorityGrant { .. } => "AuthorityGrant",
+            Action::AuthorityRevoke { .. } => "AuthorityRevoke",
+            Action::PartialExit { .. } => "PartialExit",
+            Action::ObligationTransfer { .. } => "ObligationTransfer",
+            Action::ObligationTranche { .. } => "ObligationTranche",
+            Action::ObligationRetire { .. } => "ObligationRetire",
+            Action::TransferFlagUpdate { .. } => "TransferFlagUpdate",
+            Action::TermsVisibilityUpdate { .. } => "TermsVisibilityUpdate",
+            Action::EscalateConditional { .. } => "EscalateConditional",
+            Action::DeclareAttestorFailure { .. } => "DeclareAttestorFailure",
+            Action::BondSlashCascade { .. } => "BondSlashCascade",
+            Action::CreateSavingsDeposit { .. } => "CreateSavingsDeposit",
+            Action::WithdrawSavings { .. } => "WithdrawSavings",
+            Action::DepositDefault { .. } => "DepositDefault",
+            Action::FriendlyLoanCreate { .. } => "FriendlyLoanCreate",
+            Action::FriendlyLoanRepay { .. } => "FriendlyLoanRepay",
+            Action::FriendlyLoanWriteOff { .. } => "FriendlyLoanWriteOff",
+            Action::FriendlyLoanAccept { .. } => "FriendlyLoanAccept",
+            Action::FriendlyLoanCancel { .. } => "FriendlyLoanCancel",
+            Action::TimeLockExtend { .. } => "TimeLockExtend",
+            Action::LoanChargeOff { .. } => "LoanChargeOff",
+            Action::CreditFacilityCreate { .. } => "CreditFacilityCreate",
+            Action::CreditFacilityDraw { .. } => "CreditFacilityDraw",
+            Action::CreditFacilityRepay { .. } => "CreditFacilityRepay",
+            Action::CreditFacilityTerminate { .. } => "CreditFacilityTerminate",
+            Action::ChildChainRecord { .. } => "ChildChainRecord",
+            Action::ChildChainRegister { .. } => "ChildChainRegister",
+            Action::CompoundLockCreate { .. } => "CompoundLockCreate",
+            Action::UpdateLockMemo { .. } => "UpdateLockMemo",
+            Action::UpdateLockTags { .. } => "UpdateLockTags",
+            Action::SponsoredTransaction { .. } => "SponsoredTransaction",
+            Action::UpdateFeatureFlags { .. } => "UpdateFeatureFlags",
+            Action::ClaimAndScheduleNext { .. } => "ClaimAndScheduleNext",
+            Action::UpgradeLockToV1 { .. } => "UpgradeLockToV1",
+            Action::ValidateSplitPolicy { .. } => "ValidateSplitPolicy",
+            Action::SetPreferredFiatCurrency { .. } => "SetPreferredFiatCurrency",
+            Action::ClearPreferredFiatCurrency => "ClearPreferredFiatCurrency",
+            Action::ProposeVerifierStakeIncrease { .. } => "ProposeVerifierStakeIncrease",
+            Action::TimeLockBuy { .. } => "TimeLockBuy",
+            Action::DelistLock { .. } => "DelistLock",
+            Action::TransferLockBeneficiary { .. } => "TransferLockBeneficiary",
+            Action::SubmitGovernanceProposal { .. } => "SubmitGovernanceProposal",
+            Action::VoteGovernance { .. } => "VoteGovernance",
+            Action::FinalizeGovernance { .. } => "FinalizeGovernance",
+            Action::DeregisterVerifier { .. } => "DeregisterVerifier",
+            Action::ExpireTimeLock { .. } => "ExpireTimeLock",
+            Action::TopUpVerifierStake { .. } => "TopUpVerifierStake",
+            Action::ExpireRecovery { .. } => "ExpireRecovery",
+        }
+    }
+}
+
+/// Deterministic id of the child lock created by `CompoundLockCreate`:
+/// `BLAKE3("compound" || primary_lock_id)`.
+pub fn compound_child_lock_id(primary_lock_id: &TxId) -> TxId {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(b"compound");
+    hasher.update(&primary_lock_id.0);
+    TxId(*hasher.finalize().as_bytes())
+}
+
+/// Deterministic id of the child lock created by `ClaimAndScheduleNext`:
+/// `BLAKE3("recurring_child" || parent_lock_id || index_le32)`.
+pub fn recurring_child_lock_id(parent_lock_id: &TxId, index: u32) -> TxId {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(b"recurring_child");
+    hasher.update(&parent_lock_id.0);
+    hasher.update(&index.to_le_bytes());
+    TxId(*hasher.finalize().as_bytes())
 }
 
 /// Credit history visibility setting for a wallet.
@@ -1528,7 +1875,7 @@ pub enum CreditVisibility {
 ///
 /// The transaction ID (`tx_id`) is computed as BLAKE3 of the canonical
 /// bincode serialization of all fields EXCEPT `signatures`.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct Transaction {
     /// Unique identifier (BLAKE3 of body fields).
     pub tx_id: TxId,
@@ -1619,14 +1966,82 @@ impl Transaction {
     }
 }
 
+// ── TransactionReceipt ────────────────────────────────────────────────────────
+
+/// Outcome of a successfully applied transaction.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct TransactionReceipt {
+    /// The applied transaction.
+    pub tx_id: TxId,
+    /// Child lock created by a `CompoundLockCreate` or `ClaimAndScheduleNext`
+    /// action, if any.
+    #[serde(default)]
+    pub child_lock_id: Option<TxId>,
+    /// Locks whose status was set by this transaction (including newly
+    /// created locks), with the new status.
+    #[serde(default)]
+    pub lock_status_changes: Vec<(TxId, crate::account::TimeLockStatus)>,
+}
+
 
 // ── Genesis 10b — GOVERNANCE PARAMS ────────────────────────────────────────
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct GovernanceParams {
     pub min_loan_size_chronos: Option<u64>,
     pub approved_currencies: Vec<String>,
     pub deprecated_currencies: Vec<DeprecatedCurrency>,
+    /// Overrides `MAX_OUTGOING_LOCKS_PER_ACCOUNT`.
+    #[serde(default)]
+    pub max_outgoing_locks_per_account: Option<u32>,
+    /// Overrides `MAX_INCOMING_LOCKS_PER_ACCOUNT`.
+    #[serde(default)]
+    pub max_incoming_locks_per_account: Option<u32>,
+    /// Raised `MIN_VERIFIER_STAKE_CHRONOS`, set by `ProposeVerifierStakeIncrease`.
+    #[serde(default)]
+    pub min_verifier_stake_chronos: Option<u128>,
+    /// When the verifier stake minimum was last raised; starts the top-up
+    /// grace period.
+    #[serde(default)]
+    pub verifier_stake_raised_at: Option<i64>,
+    /// Overrides `MIN_TRANSFER_AMOUNT_CHRONOS`.
+    #[serde(default)]
+    pub min_transfer_chronos: Option<u128>,
+}
+
+/// Switches for protocol features that ship dormant, so they can be turned on
+/// without a hard fork. Stored in the `governance_params` tree and replaced
+/// only by `Action::UpdateFeatureFlags`. Missing fields take the compiled-in
+/// defaults.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct FeatureFlags {
+    /// `TimeLockCreate` with a `recurring` schedule.
+    pub recurring_enabled: bool,
+    /// `TimeLockCreate` with a `split_policy`.
+    pub split_enabled: bool,
+    /// `TimeLockSell`.
+    pub secondary_market_enabled: bool,
+    /// `TimeLockCreate` requesting a transferable lock.
+    pub transfer_lock_enabled: bool,
+    /// Email locks (claim-code marker or `email_recipient_hash`).
+    pub email_lock_enabled: bool,
+    /// `CompoundLockCreate`.
+    pub compound_lock_enabled: bool,
+}
+
+impl Default for FeatureFlags {
+    /// Features live before flags existed stay on; scaffolded ones stay off.
+    fn default() -> Self {
+        Self {
+            recurring_enabled: false,
+            split_enabled: false,
+            secondary_market_enabled: false,
+            transfer_lock_enabled: false,
+            email_lock_enabled: true,
+            compound_lock_enabled: true,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
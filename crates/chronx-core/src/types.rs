@@ -107,10 +107,30 @@ impl fmt::Display for TimeLockId {
 
 // ── DilithiumPublicKey ────────────────────────────────────────────────────────
 
+/// Length of a Dilithium2 public key in bytes.
+pub const DILITHIUM2_PUBLIC_KEY_BYTES: usize = 1312;
+
+/// Length of a detached Dilithium2 signature in bytes.
+pub const DILITHIUM2_SIGNATURE_BYTES: usize = 2420;
+
 /// Dilithium2 public key (1312 bytes per NIST FIPS 204).
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DilithiumPublicKey(pub Vec<u8>);
 
+impl DilithiumPublicKey {
+    /// Reject keys that are not exactly `DILITHIUM2_PUBLIC_KEY_BYTES` long,
+    /// before they reach the verifier.
+    pub fn validate_length(&self) -> Result<(), crate::error::ChronxError> {
+        if self.0.len() != DILITHIUM2_PUBLIC_KEY_BYTES {
+            return Err(crate::error::ChronxError::InvalidPublicKeyLength {
+                expected: DILITHIUM2_PUBLIC_KEY_BYTES,
+                got: self.0.len(),
+            });
+        }
+        Ok(())
+    }
+}
+
 impl fmt::Debug for DilithiumPublicKey {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "DilithiumPublicKey({}b)", self.0.len())
@@ -121,6 +141,20 @@ impl fmt::Debug for DilithiumPublicKey {
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DilithiumSignature(pub Vec<u8>);
 
+impl DilithiumSignature {
+    /// Reject signatures that are not exactly `DILITHIUM2_SIGNATURE_BYTES`
+    /// long, before they reach the verifier.
+    pub fn validate_length(&self) -> Result<(), crate::error::ChronxError> {
+        if self.0.len() != DILITHIUM2_SIGNATURE_BYTES {
+            return Err(crate::error::ChronxError::InvalidSignatureLength {
+                expected: DILITHIUM2_SIGNATURE_BYTES,
+                got: self.0.len(),
+            });
+        }
+        Ok(())
+    }
+}
+
 impl fmt::Debug for DilithiumSignature {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "DilithiumSignature({}b)", self.0.len())
@@ -139,3 +173,38 @@ impl EvidenceHash {
         hex::encode(self.0)
     }
 }
+
+// ── GovernanceTarget ─────────────────────────────────────────────────────────
+
+/// Protocol parameter a `SubmitGovernanceProposal` may change. An approved
+/// proposal writes its `proposed_value_bytes` to the target's meta key.
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub enum GovernanceTarget {
+    /// Oracle submissions a price needs; 16-byte big-endian integer.
+    OracleMinSubmissions,
+    /// Minimum verifier stake in Chronos; 16-byte big-endian integer.
+    MinVerifierStake,
+    /// Minimum recovery bond in Chronos; 16-byte big-endian integer.
+    MinRecoveryBond,
+    /// Claim lane thresholds; bincode `LaneThresholds`.
+    LaneThresholds,
+    /// Account credited with transaction fees; base-58 account id.
+    FeeCollector,
+    /// Base PoW difficulty; a single byte.
+    PoWDifficulty,
+}
+
+impl GovernanceTarget {
+    /// Meta key the approved value is written to.
+    pub fn meta_key(&self) -> &'static str {
+        match self {
+            GovernanceTarget::OracleMinSubmissions => "gov_oracle_min_submissions",
+            GovernanceTarget::MinVerifierStake => "gov_min_verifier_stake",
+            GovernanceTarget::MinRecoveryBond => "gov_min_recovery_bond",
+            GovernanceTarget::LaneThresholds => "gov_lane_thresholds",
+            // The key the engine already reads the fee collector from.
+            GovernanceTarget::FeeCollector => "fee_collector",
+            GovernanceTarget::PoWDifficulty => "gov_pow_difficulty",
+        }
+    }
+}
@@ -0,0 +1,214 @@
+//! Conversions between Chronos and KX, and how amounts are shown.
+//!
+//! Amounts are held as integer Chronos ([`Balance`]); KX is only ever a
+//! display or input unit. The `_in` variants group digits for a
+//! [`Locale`]; the others use English formatting.
+
+use std::fmt;
+
+pub use num_format::Locale;
+use num_format::ToFormattedString;
+
+use crate::constants::CHRONOS_PER_KX;
+use crate::types::Balance;
+
+/// Decimal places in one KX.
+const KX_DECIMALS: usize = 6;
+
+/// Why [`parse_kx`] rejected an amount.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseError {
+    #[error("empty amount")]
+    Empty,
+    #[error("invalid amount: {0}")]
+    Invalid(String),
+    #[error("more than {KX_DECIMALS} decimal places: {0}")]
+    TooPrecise(String),
+    #[error("amount out of range: {0}")]
+    Overflow(String),
+}
+
+pub fn chronos_to_kx(chronos: u128) -> f64 {
+    chronos as f64 / CHRONOS_PER_KX as f64
+}
+
+/// Nearest whole number of Chronos. Negative and NaN amounts give 0.
+pub fn kx_to_chronos(kx: f64) -> u128 {
+    (kx * CHRONOS_PER_KX as f64).round() as u128
+}
+
+/// Exact KX amount with no digit grouping and no trailing zeros, e.g.
+/// `1.5` or `0.000001`. For machine-readable fields such as RPC `*_kx`.
+pub fn kx_string(chronos: u128) -> String {
+    kx_digits(chronos, "", ".")
+}
+
+/// `1,234,567 Chronos = 1.234567 KX`.
+pub fn format_chronos(chronos: u128) -> String {
+    format_chronos_in(chronos, &Locale::en)
+}
+
+pub fn format_chronos_in(chronos: u128, locale: &Locale) -> String {
+    format!(
+        "{} Chronos = {} KX",
+        chronos.to_formatted_string(locale),
+        kx_digits(chronos, locale.separator(), locale.decimal())
+    )
+}
+
+/// `1,234.5 KX`, rounded to the nearest Chronos.
+pub fn format_kx(kx: f64) -> String {
+    format_kx_in(kx, &Locale::en)
+}
+
+pub fn format_kx_in(kx: f64, locale: &Locale) -> String {
+    Amount(kx_to_chronos(kx)).kx_in(locale)
+}
+
+/// Parse a user-entered amount into Chronos. Accepts KX with or without a
+/// `KX` suffix (`1.5`, `1.5 KX`) or whole Chronos with a `Chronos` suffix
+/// (`1,500,000 Chronos`). Commas are digit separators; units are matched
+/// case-insensitively.
+pub fn parse_kx(s: &str) -> Result<u128, ParseError> {
+    let s = s.trim();
+    let lower = s.to_ascii_lowercase();
+    let (number, in_chronos) = if let Some(n) = lower.strip_suffix("chronos") {
+        (n, true)
+    } else if let Some(n) = lower.strip_suffix("kx") {
+        (n, false)
+    } else {
+        (lower.as_str(), false)
+    };
+    let number: String = number.trim().chars().filter(|c| *c != ',').collect();
+    if number.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    let (whole, frac) = number.split_once('.').unwrap_or((&number, ""));
+    let all_digits = |p: &str| p.chars().all(|c| c.is_ascii_digit());
+    if (whole.is_empty() && frac.is_empty()) || !all_digits(whole) || !all_digits(frac) {
+        return Err(ParseError::Invalid(s.to_string()));
+    }
+    let max_frac = if in_chronos { 0 } else { KX_DECIMALS };
+    if frac.len() > max_frac {
+        return Err(ParseError::TooPrecise(s.to_string()));
+    }
+
+    let overflow = || ParseError::Overflow(s.to_string());
+    let whole: u128 = if whole.is_empty() { 0 } else { whole.parse().map_err(|_| overflow())? };
+    if in_chronos {
+        return Ok(whole);
+    }
+    let frac: u128 = format!("{frac:0<KX_DECIMALS$}").parse().map_err(|_| overflow())?;
+    whole
+        .checked_mul(CHRONOS_PER_KX)
+        .and_then(|c| c.checked_add(frac))
+        .ok_or_else(overflow)
+}
+
+/// A [`Balance`] for display. `display()` shows both units; `Display`
+/// shows KX.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Amount(pub Balance);
+
+impl Amount {
+    /// `1,234,567 Chronos = 1.234567 KX`.
+    pub fn display(&self) -> String {
+        format_chronos(self.0)
+    }
+
+    /// The exact KX amount grouped for `locale`, e.g. `1.234,5 KX` in Spanish.
+    pub fn kx_in(&self, locale: &Locale) -> String {
+        format!("{} KX", kx_digits(self.0, locale.separator(), locale.decimal()))
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.kx_in(&Locale::en))
+    }
+}
+
+/// Whole KX grouped with `separator`, then any fraction after `decimal`
+/// without trailing zeros.
+fn kx_digits(chronos: u128, separator: &str, decimal: &str) -> String {
+    let whole = chronos / CHRONOS_PER_KX;
+    let frac = chronos % CHRONOS_PER_KX;
+    let mut out = if separator.is_empty() {
+        whole.to_string()
+    } else {
+        whole
+            .to_string()
+            .as_bytes()
+            .rchunks(3)
+            .rev()
+            .map(|g| std::str::from_utf8(g).unwrap_or_default())
+            .collect::<Vec<_>>()
+            .join(separator)
+    };
+    if frac > 0 {
+        let digits = format!("{frac:0>KX_DECIMALS$}");
+        out.push_str(decimal);
+        out.push_str(digits.trim_end_matches('0'));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_edge_amounts() {
+        assert_eq!(format_chronos(0), "0 Chronos = 0 KX");
+        assert_eq!(format_chronos(1_234_567), "1,234,567 Chronos = 1.234567 KX");
+        assert_eq!(format_chronos(1), "1 Chronos = 0.000001 KX");
+        assert_eq!(
+            format_chronos(u128::MAX),
+            "340,282,366,920,938,463,463,374,607,431,768,211,455 Chronos = \
+             340,282,366,920,938,463,463,374,607,431,768.211455 KX"
+        );
+        assert_eq!(format_chronos_in(12_000_005, &Locale::es), "12.000.005 Chronos = 12,000005 KX");
+
+        assert_eq!(format_kx(1.5), "1.5 KX");
+        assert_eq!(format_kx(1234.5), "1,234.5 KX");
+        assert_eq!(format_kx(0.0), "0 KX");
+        assert_eq!(format_kx_in(1234.5, &Locale::es), "1.234,5 KX");
+
+        assert_eq!(kx_string(1_500_000), "1.5");
+        assert_eq!(kx_string(1_234_000_000), "1234");
+        assert_eq!(Amount(2_500_000).to_string(), "2.5 KX");
+        assert_eq!(Amount(2_500_000).display(), "2,500,000 Chronos = 2.5 KX");
+    }
+
+    #[test]
+    fn converts_between_units() {
+        assert_eq!(chronos_to_kx(1_500_000), 1.5);
+        assert_eq!(kx_to_chronos(1.5), 1_500_000);
+        // 0.1 * 10^6 is not exact in f64; rounding recovers it.
+        assert_eq!(kx_to_chronos(0.1), 100_000);
+        assert_eq!(kx_to_chronos(-1.0), 0);
+        assert_eq!(kx_to_chronos(f64::NAN), 0);
+    }
+
+    #[test]
+    fn parses_kx_and_chronos() {
+        assert_eq!(parse_kx("1.5"), Ok(1_500_000));
+        assert_eq!(parse_kx("1.5 KX"), Ok(1_500_000));
+        assert_eq!(parse_kx(" 2kx "), Ok(2_000_000));
+        assert_eq!(parse_kx(".25"), Ok(250_000));
+        assert_eq!(parse_kx("0"), Ok(0));
+        assert_eq!(parse_kx("1,500,000 Chronos"), Ok(1_500_000));
+        assert_eq!(parse_kx("1,234.000001 KX"), Ok(1_234_000_001));
+        assert_eq!(parse_kx(&format!("{} chronos", u128::MAX)), Ok(u128::MAX));
+
+        assert_eq!(parse_kx(""), Err(ParseError::Empty));
+        assert_eq!(parse_kx("KX"), Err(ParseError::Empty));
+        assert!(matches!(parse_kx("1.5.1"), Err(ParseError::Invalid(_))));
+        assert!(matches!(parse_kx("-1"), Err(ParseError::Invalid(_))));
+        assert!(matches!(parse_kx("."), Err(ParseError::Invalid(_))));
+        assert!(matches!(parse_kx("0.0000001"), Err(ParseError::TooPrecise(_))));
+        assert!(matches!(parse_kx("1.5 Chronos"), Err(ParseError::TooPrecise(_))));
+        assert!(matches!(parse_kx(&u128::MAX.to_string()), Err(ParseError::Overflow(_))));
+    }
+}
@@ -1,4 +1,5 @@
 use chronx_core::types::{DilithiumPublicKey, DilithiumSignature};
+pub use chronx_core::types::{DILITHIUM2_PUBLIC_KEY_BYTES, DILITHIUM2_SIGNATURE_BYTES};
 use pqcrypto_dilithium::dilithium2;
 use pqcrypto_traits::sign::{DetachedSignature, PublicKey, SecretKey};
 use thiserror::Error;
@@ -9,6 +10,8 @@ pub enum SignatureError {
     InvalidSignature,
     #[error("invalid public key length: expected {expected}, got {got}")]
     InvalidPublicKeyLength { expected: usize, got: usize },
+    #[error("invalid signature length: expected {expected}, got {got}")]
+    InvalidSignatureLength { expected: usize, got: usize },
 }
 
 /// Sign `message` with a Dilithium2 secret key.
@@ -20,15 +23,28 @@ pub fn sign(secret_key_bytes: &[u8], message: &[u8]) -> Result<DilithiumSignatur
     Ok(DilithiumSignature(sig.as_bytes().to_vec()))
 }
 
-/// Verify a detached Dilithium2 signature.
+/// Verify a detached Dilithium2 signature. Keys and signatures of the wrong
+/// length are rejected before they reach the library.
 pub fn verify_signature(
     public_key: &DilithiumPublicKey,
     message: &[u8],
     signature: &DilithiumSignature,
 ) -> Result<(), SignatureError> {
+    if public_key.0.len() != DILITHIUM2_PUBLIC_KEY_BYTES {
+        return Err(SignatureError::InvalidPublicKeyLength {
+            expected: DILITHIUM2_PUBLIC_KEY_BYTES,
+            got: public_key.0.len(),
+        });
+    }
+    if signature.0.len() != DILITHIUM2_SIGNATURE_BYTES {
+        return Err(SignatureError::InvalidSignatureLength {
+            expected: DILITHIUM2_SIGNATURE_BYTES,
+            got: signature.0.len(),
+        });
+    }
     let pk = dilithium2::PublicKey::from_bytes(&public_key.0).map_err(|_| {
         SignatureError::InvalidPublicKeyLength {
-            expected: dilithium2::public_key_bytes(),
+            expected: DILITHIUM2_PUBLIC_KEY_BYTES,
             got: public_key.0.len(),
         }
     })?;
@@ -87,4 +103,52 @@ mod tests {
         let sig = signer.sign(b"original").unwrap();
         assert!(verify_signature(&pk_bytes, b"tampered", &sig).is_err());
     }
+
+    #[test]
+    fn lengths_match_library() {
+        assert_eq!(DILITHIUM2_PUBLIC_KEY_BYTES, dilithium2::public_key_bytes());
+        assert_eq!(DILITHIUM2_SIGNATURE_BYTES, dilithium2::signature_bytes());
+    }
+
+    #[test]
+    fn wrong_length_signature_rejected() {
+        let (pk, sk) = dilithium2::keypair();
+        let pk_bytes = DilithiumPublicKey(pk.as_bytes().to_vec());
+        let signer =
+            ChronxSigner::from_secret_key_bytes(sk.as_bytes().to_vec(), pk.as_bytes().to_vec());
+        let sig = signer.sign(b"msg").unwrap();
+        assert!(sig.validate_length().is_ok());
+        assert!(verify_signature(&pk_bytes, b"msg", &sig).is_ok());
+
+        let mut short = sig.clone();
+        short.0.pop();
+        let mut long = sig.clone();
+        long.0.push(0);
+        for bad in [short, long] {
+            assert!(bad.validate_length().is_err());
+            assert!(matches!(
+                verify_signature(&pk_bytes, b"msg", &bad),
+                Err(SignatureError::InvalidSignatureLength { expected: DILITHIUM2_SIGNATURE_BYTES, .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn wrong_length_public_key_rejected() {
+        let (pk, sk) = dilithium2::keypair();
+        let signer =
+            ChronxSigner::from_secret_key_bytes(sk.as_bytes().to_vec(), pk.as_bytes().to_vec());
+        let sig = signer.sign(b"msg").unwrap();
+        assert!(signer.public_key.validate_length().is_ok());
+
+        let short = DilithiumPublicKey(pk.as_bytes()[1..].to_vec());
+        let long = DilithiumPublicKey([pk.as_bytes(), &[0]].concat());
+        for bad in [short, long] {
+            assert!(bad.validate_length().is_err());
+            assert!(matches!(
+                verify_signature(&bad, b"msg", &sig),
+                Err(SignatureError::InvalidPublicKeyLength { expected: DILITHIUM2_PUBLIC_KEY_BYTES, .. })
+            ));
+        }
+    }
 }
@@ -14,3 +14,15 @@ pub fn account_id_from_pubkey(pubkey_bytes: &[u8]) -> chronx_core::types::Accoun
 pub fn tx_id_from_body(body_bytes: &[u8]) -> TxId {
     TxId::from_bytes(blake3_hash(body_bytes))
 }
+
+/// Message the sender or recipient of a private lock signs to read it via
+/// `chronx_getPrivateTimelockDetails`:
+/// `BLAKE3("view_private_lock" || lock_id || unix_hour as u64 BE)`, where
+/// `unix_hour = now / 3600`, so a signature is good until the hour rolls over.
+pub fn private_lock_view_challenge(lock_id: &TxId, now: i64) -> [u8; 32] {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(b"view_private_lock");
+    hasher.update(lock_id.as_bytes());
+    hasher.update(&((now / 3600) as u64).to_be_bytes());
+    *hasher.finalize().as_bytes()
+}
@@ -6,6 +6,9 @@ use zeroize::Zeroizing;
 
 use crate::hash::account_id_from_pubkey;
 
+/// blake3 key-derivation context for [`KeyPair::derive_child`].
+const CHILD_KEY_CONTEXT: &str = "chronx 2026 hd child key v1";
+
 /// A ChronX keypair: Dilithium2 public + secret keys with derived AccountId.
 ///
 /// The secret key is held in a `Zeroizing<Vec<u8>>` to wipe memory on drop.
@@ -29,6 +32,23 @@ impl KeyPair {
         }
     }
 
+    /// The keypair a 32-byte seed determines; the same seed always gives
+    /// the same keys.
+    pub fn from_seed(seed: &[u8; 32]) -> Self {
+        let (pk_bytes, sk_bytes) = crate::seeded::keypair_from_seed(seed);
+        Self::from_raw(pk_bytes, sk_bytes.to_vec())
+    }
+
+    /// Child keypair `index` of this one, as `chronx-wallet tree` derives
+    /// sub-accounts. The child's seed hashes this keypair's secret key with
+    /// the index, so only the holder of the parent can derive it.
+    pub fn derive_child(&self, index: u32) -> Self {
+        let mut material = Zeroizing::new(self.secret_key.clone());
+        material.extend_from_slice(&index.to_le_bytes());
+        let seed = Zeroizing::new(blake3::derive_key(CHILD_KEY_CONTEXT, &material));
+        Self::from_seed(&seed)
+    }
+
     /// Sign `message` using this keypair's secret key.
     pub fn sign(&self, message: &[u8]) -> chronx_core::types::DilithiumSignature {
         let sk = Zeroizing::new(self.secret_key.clone());
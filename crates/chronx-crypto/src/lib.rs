@@ -2,8 +2,11 @@ pub mod dilithium;
 pub mod hash;
 pub mod keypair;
 pub mod pow;
+mod seeded;
 
-pub use dilithium::{verify_signature, ChronxSigner};
-pub use hash::{blake3_hash, tx_id_from_body};
+pub use dilithium::{
+    verify_signature, ChronxSigner, DILITHIUM2_PUBLIC_KEY_BYTES, DILITHIUM2_SIGNATURE_BYTES,
+};
+pub use hash::{blake3_hash, private_lock_view_challenge, tx_id_from_body};
 pub use keypair::KeyPair;
 pub use pow::{mine_pow, verify_pow};
@@ -0,0 +1,229 @@
+//! Dilithium2 key generation from a 32-byte seed.
+//!
+//! `pqcrypto-dilithium` only generates keys from the OS random source. This
+//! runs the same steps as PQClean's `crypto_sign_keypair`, with the seed in
+//! place of `randombytes`, through the clean implementation's routines that
+//! the crate already links. Keys come out in the library's own encoding, so
+//! they sign and verify through [`crate::dilithium`] like any other.
+//!
+//! The symbols and struct layouts below are not part of the crate's API, so
+//! the workspace pins `pqcrypto-dilithium` to an exact version, and
+//! `nist_known_answer_test` checks the output against the reference KAT
+//! vectors. Re-run it whenever that pin moves.
+
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use sha3::Shake256;
+use zeroize::Zeroizing;
+
+use crate::dilithium::DILITHIUM2_PUBLIC_KEY_BYTES;
+
+/// Dilithium2 parameters, as in PQClean's `params.h`.
+const N: usize = 256;
+const K: usize = 4;
+const L: usize = 4;
+const SEEDBYTES: usize = 32;
+const CRHBYTES: usize = 64;
+const TRBYTES: usize = 64;
+const SECRET_KEY_BYTES: usize = 2560;
+
+/// `poly`, `polyvecl` and `polyveck` have the layout of these arrays.
+type Poly = [i32; N];
+type PolyVecL = [Poly; L];
+type PolyVecK = [Poly; K];
+
+extern "C" {
+    fn PQCLEAN_DILITHIUM2_CLEAN_polyvec_matrix_expand(mat: *mut PolyVecL, rho: *const u8);
+    fn PQCLEAN_DILITHIUM2_CLEAN_polyvecl_uniform_eta(v: *mut PolyVecL, seed: *const u8, nonce: u16);
+    fn PQCLEAN_DILITHIUM2_CLEAN_polyveck_uniform_eta(v: *mut PolyVecK, seed: *const u8, nonce: u16);
+    fn PQCLEAN_DILITHIUM2_CLEAN_polyvecl_ntt(v: *mut PolyVecL);
+    fn PQCLEAN_DILITHIUM2_CLEAN_polyvec_matrix_pointwise_montgomery(
+        t: *mut PolyVecK,
+        mat: *const PolyVecL,
+        v: *const PolyVecL,
+    );
+    fn PQCLEAN_DILITHIUM2_CLEAN_polyveck_reduce(v: *mut PolyVecK);
+    fn PQCLEAN_DILITHIUM2_CLEAN_polyveck_invntt_tomont(v: *mut PolyVecK);
+    fn PQCLEAN_DILITHIUM2_CLEAN_polyveck_add(w: *mut PolyVecK, u: *const PolyVecK, v: *const PolyVecK);
+    fn PQCLEAN_DILITHIUM2_CLEAN_polyveck_caddq(v: *mut PolyVecK);
+    fn PQCLEAN_DILITHIUM2_CLEAN_polyveck_power2round(v1: *mut PolyVecK, v0: *mut PolyVecK, v: *const PolyVecK);
+    fn PQCLEAN_DILITHIUM2_CLEAN_pack_pk(pk: *mut u8, rho: *const u8, t1: *const PolyVecK);
+    fn PQCLEAN_DILITHIUM2_CLEAN_pack_sk(
+        sk: *mut u8,
+        rho: *const u8,
+        tr: *const u8,
+        key: *const u8,
+        t0: *const PolyVecK,
+        s1: *const PolyVecL,
+        s2: *const PolyVecK,
+    );
+}
+
+fn shake256(input: &[u8], output: &mut [u8]) {
+    let mut hasher = Shake256::default();
+    hasher.update(input);
+    hasher.finalize_xof().read(output);
+}
+
+/// The (public key, secret key) bytes `seed` determines.
+pub(crate) fn keypair_from_seed(seed: &[u8; SEEDBYTES]) -> (Vec<u8>, Zeroizing<Vec<u8>>) {
+    let mut seedbuf = Zeroizing::new([0u8; 2 * SEEDBYTES + CRHBYTES]);
+    shake256(seed, &mut seedbuf[..]);
+    let (rho, rest) = seedbuf.split_at(SEEDBYTES);
+    let (rhoprime, key) = rest.split_at(CRHBYTES);
+
+    let mut mat = [[[0i32; N]; L]; K];
+    let mut s1 = Zeroizing::new([[0i32; N]; L]);
+    let mut s2 = Zeroizing::new([[0i32; N]; K]);
+    let mut t = Zeroizing::new([[0i32; N]; K]);
+    let mut t1 = [[0i32; N]; K];
+    let mut t0 = Zeroizing::new([[0i32; N]; K]);
+    let mut pk = vec![0u8; DILITHIUM2_PUBLIC_KEY_BYTES];
+    let mut sk = Zeroizing::new(vec![0u8; SECRET_KEY_BYTES]);
+    let mut tr = [0u8; TRBYTES];
+
+    // SAFETY: every pointer is to a live array of the size the C routine
+    // expects (see `params.h`), and no output aliases an input.
+    unsafe {
+        PQCLEAN_DILITHIUM2_CLEAN_polyvec_matrix_expand(mat.as_mut_ptr(), rho.as_ptr());
+        PQCLEAN_DILITHIUM2_CLEAN_polyvecl_uniform_eta(&mut *s1, rhoprime.as_ptr(), 0);
+        PQCLEAN_DILITHIUM2_CLEAN_polyveck_uniform_eta(&mut *s2, rhoprime.as_ptr(), L as u16);
+
+        let mut s1hat = Zeroizing::new(*s1);
+        PQCLEAN_DILITHIUM2_CLEAN_polyvecl_ntt(&mut *s1hat);
+        let mut as1 = Zeroizing::new([[0i32; N]; K]);
+        PQCLEAN_DILITHIUM2_CLEAN_polyvec_matrix_pointwise_montgomery(&mut *as1, mat.as_ptr(), &*s1hat);
+        PQCLEAN_DILITHIUM2_CLEAN_polyveck_reduce(&mut *as1);
+        PQCLEAN_DILITHIUM2_CLEAN_polyveck_invntt_tomont(&mut *as1);
+        PQCLEAN_DILITHIUM2_CLEAN_polyveck_add(&mut *t, &*as1, &*s2);
+
+        PQCLEAN_DILITHIUM2_CLEAN_polyveck_caddq(&mut *t);
+        PQCLEAN_DILITHIUM2_CLEAN_polyveck_power2round(&mut t1, &mut *t0, &*t);
+        PQCLEAN_DILITHIUM2_CLEAN_pack_pk(pk.as_mut_ptr(), rho.as_ptr(), &t1);
+
+        shake256(&pk, &mut tr);
+        PQCLEAN_DILITHIUM2_CLEAN_pack_sk(sk.as_mut_ptr(), rho.as_ptr(), tr.as_ptr(), key.as_ptr(), &*t0, &*s1, &*s2);
+    }
+    (pk, sk)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chronx_core::types::DilithiumPublicKey;
+    use pqcrypto_dilithium::dilithium2;
+
+    #[test]
+    fn seeded_keys_match_the_library_encoding() {
+        assert_eq!(SECRET_KEY_BYTES, dilithium2::secret_key_bytes());
+        let (pk, sk) = keypair_from_seed(&[7u8; 32]);
+        assert_eq!(pk.len(), dilithium2::public_key_bytes());
+
+        let sig = crate::dilithium::sign(&sk, b"msg").unwrap();
+        let pk = DilithiumPublicKey(pk);
+        assert!(crate::verify_signature(&pk, b"msg", &sig).is_ok());
+        assert!(crate::verify_signature(&pk, b"other", &sig).is_err());
+    }
+
+    /// PQClean's AES-256 CTR DRBG (`test/common/nistkatrng.c`), which the
+    /// NIST KAT generator draws its seeds and messages from.
+    struct KatRng {
+        key: [u8; 32],
+        v: [u8; 16],
+    }
+
+    impl KatRng {
+        fn new(entropy: &[u8; 48]) -> Self {
+            let mut rng = KatRng { key: [0; 32], v: [0; 16] };
+            rng.update(Some(entropy));
+            rng
+        }
+
+        fn next_block(&mut self) -> [u8; 16] {
+            use aes::cipher::{BlockEncrypt, KeyInit};
+            for byte in self.v.iter_mut().rev() {
+                *byte = byte.wrapping_add(1);
+                if *byte != 0 {
+                    break;
+                }
+            }
+            let mut block = self.v.into();
+            aes::Aes256::new(&self.key.into()).encrypt_block(&mut block);
+            block.into()
+        }
+
+        fn update(&mut self, data: Option<&[u8; 48]>) {
+            let mut temp = [0u8; 48];
+            for chunk in temp.chunks_mut(16) {
+                chunk.copy_from_slice(&self.next_block());
+            }
+            if let Some(data) = data {
+                temp.iter_mut().zip(data).for_each(|(t, d)| *t ^= d);
+            }
+            self.key.copy_from_slice(&temp[..32]);
+            self.v.copy_from_slice(&temp[32..]);
+        }
+
+        fn bytes<const LEN: usize>(&mut self) -> [u8; LEN] {
+            let mut out = [0u8; LEN];
+            for chunk in out.chunks_mut(16) {
+                chunk.copy_from_slice(&self.next_block()[..chunk.len()]);
+            }
+            self.update(None);
+            out
+        }
+    }
+
+    /// Rebuilds the first record of PQClean's `nistkat` output for
+    /// Dilithium2 and compares its digest with the `nistkat-sha256` in the
+    /// scheme's META.yml.
+    #[test]
+    fn nist_known_answer_test() {
+        use sha2::{Digest, Sha256};
+        use std::fmt::Write;
+
+        let entropy: [u8; 48] = std::array::from_fn(|i| i as u8);
+        let mut rng = KatRng::new(&entropy);
+        let seed: [u8; 48] = rng.bytes();
+        let msg: [u8; 33] = rng.bytes();
+
+        let mut rng = KatRng::new(&seed);
+        let (pk, sk) = keypair_from_seed(&rng.bytes());
+        let secret = <dilithium2::SecretKey as pqcrypto_traits::sign::SecretKey>::from_bytes(&sk).unwrap();
+        let signed = dilithium2::sign(&msg, &secret);
+        let sm = pqcrypto_traits::sign::SignedMessage::as_bytes(&signed);
+
+        let upper = |bytes: &[u8]| bytes.iter().map(|b| format!("{b:02X}")).collect::<String>();
+        let mut rsp = String::new();
+        writeln!(rsp, "count = 0").unwrap();
+        writeln!(rsp, "seed = {}", upper(&seed)).unwrap();
+        writeln!(rsp, "mlen = {}", msg.len()).unwrap();
+        writeln!(rsp, "msg = {}", upper(&msg)).unwrap();
+        writeln!(rsp, "pk = {}", upper(&pk)).unwrap();
+        writeln!(rsp, "sk = {}", upper(&sk)).unwrap();
+        writeln!(rsp, "smlen = {}", sm.len()).unwrap();
+        writeln!(rsp, "sm = {}", upper(sm)).unwrap();
+
+        assert_eq!(
+            hex::encode(Sha256::digest(rsp.as_bytes())),
+            "3198fb788e0b18103add2e9ea5408cf6545a967f9bbed8be06a11915fcd87ad5"
+        );
+    }
+
+    #[test]
+    fn same_seed_same_keys() {
+        let (pk, sk) = keypair_from_seed(&[1u8; 32]);
+        assert_eq!((pk.clone(), sk.clone()), keypair_from_seed(&[1u8; 32]));
+        assert_ne!(pk, keypair_from_seed(&[2u8; 32]).0);
+    }
+
+    #[test]
+    fn children_derive_from_the_parent_secret_key() {
+        let parent = crate::KeyPair::from_seed(&[3u8; 32]);
+        let child = parent.derive_child(0);
+        assert_eq!(child.account_id, parent.derive_child(0).account_id);
+        assert_ne!(child.account_id, parent.derive_child(1).account_id);
+        assert_ne!(child.account_id, crate::KeyPair::generate().derive_child(0).account_id);
+        let sig = child.sign(b"msg");
+        assert!(crate::verify_signature(&child.public_key, b"msg", &sig).is_ok());
+    }
+}
@@ -0,0 +1,220 @@
+use chronx_core::constants::{
+    DIFFICULTY_MAX_SOLVE_GAP_MS, DIFFICULTY_WINDOW, POW_MAX_DIFFICULTY, POW_MIN_DIFFICULTY, POW_TARGET_SOLVE_MS,
+};
+use chronx_core::types::TxId;
+
+use crate::vertex::Vertex;
+
+/// PoW difficulty a vertex with `parents` must meet.
+///
+/// Consensus rule, so every node computes the same value from the DAG alone:
+/// - The reference parent is the deepest parent (ties: lowest TxId).
+/// - A child inherits its reference parent's recorded difficulty, or `base`
+///   for parents applied before difficulty was recorded.
+/// - At depths that are a multiple of [`DIFFICULTY_WINDOW`], the inherited
+///   difficulty is retargeted from the timestamps of the preceding window
+///   along the reference-parent chain.
+///
+/// A `base` of 0 disables PoW (test and dev networks) and is never retargeted.
+/// Parentless (genesis) vertices are exempt from PoW and get `base`.
+pub fn required_difficulty<F>(parents: &[TxId], base: u8, get_vertex: F) -> u8
+where
+    F: Fn(&TxId) -> Option<Vertex>,
+{
+    if base == 0 {
+        return 0;
+    }
+    let Some(reference) = reference_parent(parents, &get_vertex) else {
+        return base;
+    };
+    let inherited = reference.difficulty.unwrap_or(base);
+    if (reference.depth + 1) % DIFFICULTY_WINDOW != 0 {
+        return inherited;
+    }
+    retarget(inherited, POW_TARGET_SOLVE_MS, &window_timestamps_ms(reference, &get_vertex))
+}
+
+/// Difficulty a vertex with `parents` would carry without a retarget: its
+/// reference parent's, else `base`. A vertex retargeted when its
+/// [`required_difficulty`] differs from this.
+pub fn inherited_difficulty<F>(parents: &[TxId], base: u8, get_vertex: F) -> u8
+where
+    F: Fn(&TxId) -> Option<Vertex>,
+{
+    reference_parent(parents, &get_vertex)
+        .and_then(|v| v.difficulty)
+        .unwrap_or(base)
+}
+
+/// Solve times (Unix ms, oldest first) of up to [`DIFFICULTY_WINDOW`]
+/// vertices ending at `last`, following reference parents.
+///
+/// Transaction timestamps are chosen by the sender, so each is clamped to
+/// between its reference parent's solve time and
+/// [`DIFFICULTY_MAX_SOLVE_GAP_MS`] after it. A backdated vertex then counts
+/// as solved with its parent rather than stretching the next gap, and a
+/// forward-dated one adds at most the maximum gap.
+pub fn window_timestamps_ms<F>(last: Vertex, get_vertex: F) -> Vec<u64>
+where
+    F: Fn(&TxId) -> Option<Vertex>,
+{
+    let ms = |vertex: &Vertex| vertex.transaction.timestamp.max(0) as u64 * 1_000;
+    let mut window = Vec::new();
+    let mut current = Some(last);
+    while let Some(vertex) = current {
+        current = reference_parent(vertex.parents(), &get_vertex);
+        window.push(ms(&vertex));
+        if window.len() as u64 >= DIFFICULTY_WINDOW {
+            break;
+        }
+    }
+    window.reverse();
+
+    // The oldest vertex is clamped against its own reference parent, when
+    // the window does not reach back to genesis.
+    let mut previous = current.as_ref().map(ms);
+    for timestamp in &mut window {
+        if let Some(parent) = previous {
+            *timestamp = (*timestamp).clamp(parent, parent.saturating_add(DIFFICULTY_MAX_SOLVE_GAP_MS));
+        }
+        previous = Some(*timestamp);
+    }
+    window
+}
+
+/// New difficulty from a window of solve timestamps (ms, oldest first):
+/// `current * target / average gap`, clamped to the PoW bounds. Fewer than
+/// two samples keep `current`; a zero average gap steps up by one.
+pub fn retarget(current: u8, target_solve_ms: u64, timestamps_ms: &[u64]) -> u8 {
+    if timestamps_ms.len() < 2 {
+        return current;
+    }
+    let total_gap: u64 = timestamps_ms.windows(2).map(|w| w[1].saturating_sub(w[0])).sum();
+    let avg_gap_ms = total_gap / (timestamps_ms.len() as u64 - 1);
+    if avg_gap_ms == 0 {
+        return current.saturating_add(1).min(POW_MAX_DIFFICULTY);
+    }
+    let scaled = (current as u64).saturating_mul(target_solve_ms) / avg_gap_ms;
+    scaled.clamp(POW_MIN_DIFFICULTY as u64, POW_MAX_DIFFICULTY as u64) as u8
+}
+
+/// The deepest of `parents` that exists, lowest TxId first among equals.
+pub fn reference_parent<F>(parents: &[TxId], get_vertex: &F) -> Option<Vertex>
+where
+    F: Fn(&TxId) -> Option<Vertex>,
+{
+    parents
+        .iter()
+        .filter_map(get_vertex)
+        .min_by(|a, b| b.depth.cmp(&a.depth).then_with(|| a.tx_id().0.cmp(&b.tx_id().0)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chronx_core::transaction::{AuthScheme, Transaction};
+    use chronx_core::types::AccountId;
+    use std::collections::HashMap;
+
+    /// A single chain of `len` vertices `gap_secs` apart, each recording
+    /// `difficulty`, keyed by TxId.
+    fn chain(len: u64, gap_secs: i64, difficulty: u8) -> (HashMap<TxId, Vertex>, TxId) {
+        let mut vertices = HashMap::new();
+        let mut parents = vec![];
+        for depth in 0..len {
+            let mut id = [0u8; 32];
+            id[..8].copy_from_slice(&depth.to_be_bytes());
+            let tx = Transaction {
+                tx_id: TxId::from_bytes(id),
+                parents: parents.clone(),
+                timestamp: 1_000_000 + gap_secs * depth as i64,
+                nonce: depth,
+                from: AccountId::from_bytes([1u8; 32]),
+                actions: vec![],
+                pow_nonce: 0,
+                signatures: vec![],
+                auth_scheme: AuthScheme::SingleSig,
+                tx_version: 1,
+                client_ref: None,
+                fee_chronos: 0,
+                expires_at: None,
+                sender_public_key: None,
+            };
+            let mut vertex = Vertex::new(tx, depth, 0);
+            vertex.difficulty = Some(difficulty);
+            parents = vec![vertex.tx_id().clone()];
+            vertices.insert(vertex.tx_id().clone(), vertex);
+        }
+        (vertices, parents[0].clone())
+    }
+
+    #[test]
+    fn inherits_between_retargets() {
+        let (vertices, tip) = chain(50, 1, 18);
+        assert_eq!(required_difficulty(&[tip], 20, |id| vertices.get(id).cloned()), 18);
+    }
+
+    #[test]
+    fn retargets_at_window_boundary() {
+        // Vertices 1 s apart against a 10 s target: the next one at depth
+        // DIFFICULTY_WINDOW must be harder.
+        let (vertices, tip) = chain(DIFFICULTY_WINDOW, 1, 17);
+        let required = required_difficulty(&[tip], 20, |id| vertices.get(id).cloned());
+        assert_eq!(required, POW_MAX_DIFFICULTY);
+    }
+
+    #[test]
+    fn zero_base_disables_pow() {
+        let (vertices, tip) = chain(DIFFICULTY_WINDOW, 1, 0);
+        assert_eq!(required_difficulty(&[tip], 0, |id| vertices.get(id).cloned()), 0);
+        assert_eq!(required_difficulty(&[], 0, |id| vertices.get(id).cloned()), 0);
+    }
+
+    #[test]
+    fn reference_parent_is_deepest_then_lowest_id() {
+        let (vertices, tip) = chain(3, 1, 18);
+        let shallow = TxId::from_bytes([0u8; 32]);
+        let reference = reference_parent(&[shallow, tip.clone()], &|id: &TxId| vertices.get(id).cloned());
+        assert_eq!(reference.unwrap().tx_id(), &tip);
+    }
+
+    #[test]
+    fn sender_timestamps_are_clamped_to_the_reference_chain() {
+        let (mut vertices, tip) = chain(5, 10, 18);
+        let retime = |vertices: &mut HashMap<TxId, Vertex>, depth: u64, timestamp: i64| {
+            let mut id = [0u8; 32];
+            id[..8].copy_from_slice(&depth.to_be_bytes());
+            vertices.get_mut(&TxId::from_bytes(id)).unwrap().transaction.timestamp = timestamp;
+        };
+        // Depth 2 backdated well before its parent, depth 4 forward-dated an hour.
+        retime(&mut vertices, 2, 0);
+        retime(&mut vertices, 4, 1_000_000 + 3_600);
+        let tip_vertex = vertices[&tip].clone();
+        let window = window_timestamps_ms(tip_vertex, |id| vertices.get(id).cloned());
+        let start = 1_000_000_000;
+        assert_eq!(
+            window,
+            vec![start, start + 10_000, start + 10_000, start + 30_000, start + 30_000 + DIFFICULTY_MAX_SOLVE_GAP_MS]
+        );
+    }
+
+    #[test]
+    fn backdating_does_not_lower_the_retarget() {
+        // A window solved 1 s apart, with every other vertex backdated a day:
+        // without clamping the gaps would average hours.
+        let (mut vertices, tip) = chain(DIFFICULTY_WINDOW, 1, 17);
+        for vertex in vertices.values_mut().filter(|v| v.depth % 2 == 1) {
+            vertex.transaction.timestamp -= 86_400;
+        }
+        let required = required_difficulty(&[tip], 20, |id| vertices.get(id).cloned());
+        assert_eq!(required, POW_MAX_DIFFICULTY);
+    }
+
+    #[test]
+    fn retarget_matches_ratio_rule() {
+        assert_eq!(retarget(20, 10_000, &[0]), 20);
+        assert_eq!(retarget(20, 10_000, &[5_000, 5_000]), 21);
+        assert_eq!(retarget(20, 10_000, &[0, 12_500]), 16);
+        assert_eq!(retarget(20, 10_000, &[0, 60_000]), POW_MIN_DIFFICULTY);
+    }
+}
@@ -1,5 +1,7 @@
+pub mod difficulty;
 pub mod validation;
 pub mod vertex;
 
+pub use difficulty::required_difficulty;
 pub use validation::validate_vertex;
 pub use vertex::{Vertex, VertexStatus};
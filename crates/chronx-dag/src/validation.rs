@@ -79,6 +79,8 @@ pub fn validate_signatures(
         // ── SingleSig ────────────────────────────────────────────────────────
         (AuthPolicy::SingleSig { public_key }, AuthScheme::SingleSig) => {
             let sig = tx.signatures.first().ok_or(ChronxError::InvalidSignature)?;
+            public_key.validate_length()?;
+            sig.validate_length()?;
             verify_signature(public_key, &body_bytes, sig)
                 .map_err(|_| ChronxError::InvalidSignature)
         }
@@ -98,6 +100,13 @@ pub fn validate_signatures(
                 });
             }
 
+            for pk in public_keys {
+                pk.validate_length()?;
+            }
+            for sig in &tx.signatures {
+                sig.validate_length()?;
+            }
+
             let mut valid_count = 0u32;
             let mut seen_keys = std::collections::HashSet::new();
 
@@ -126,6 +135,8 @@ pub fn validate_signatures(
         // ── RecoveryEnabled (uses owner key like SingleSig) ───────────────────
         (AuthPolicy::RecoveryEnabled { owner_key, .. }, AuthScheme::SingleSig) => {
             let sig = tx.signatures.first().ok_or(ChronxError::InvalidSignature)?;
+            owner_key.validate_length()?;
+            sig.validate_length()?;
             verify_signature(owner_key, &body_bytes, sig).map_err(|_| ChronxError::InvalidSignature)
         }
 
@@ -148,6 +159,7 @@ mod tests {
             memo: None,
             memo_encrypted: true,
             memo_public: false,
+            pay_as_amount: None,
         }];
         let auth_scheme = AuthScheme::SingleSig;
         let mut tx = Transaction {
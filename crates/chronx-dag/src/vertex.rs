@@ -1,5 +1,5 @@
 use chronx_core::transaction::Transaction;
-use chronx_core::types::{Timestamp, TxId};
+use chronx_core::types::{AccountId, Timestamp, TxId};
 use serde::{Deserialize, Serialize};
 
 /// Finality status of a vertex in the DAG.
@@ -43,6 +43,17 @@ pub struct Vertex {
     /// `None` for vertices created before v9.4 (backward compat via serde default).
     #[serde(default)]
     pub state_root: Option<[u8; 32]>,
+
+    /// Net balance change applying this vertex made to each account it
+    /// touched. Empty for vertices applied before balance history existed.
+    #[serde(default)]
+    pub balance_deltas: Vec<(AccountId, i128)>,
+
+    /// PoW difficulty this vertex was required to meet; see
+    /// [`required_difficulty`](crate::required_difficulty). `None` for
+    /// vertices applied before difficulty was consensus state.
+    #[serde(default)]
+    pub difficulty: Option<u8>,
 }
 
 impl Vertex {
@@ -55,6 +66,8 @@ impl Vertex {
             children: Vec::new(),
             confirmation_count: 0,
             state_root: None,
+            balance_deltas: Vec::new(),
+            difficulty: None,
         }
     }
 
@@ -73,4 +86,13 @@ impl Vertex {
     pub fn is_final(&self) -> bool {
         matches!(self.status, VertexStatus::Final)
     }
+
+    /// The balance change this vertex applied to `account`, i.e. what must
+    /// be subtracted to undo it; `None` if it left that balance unchanged.
+    pub fn reverse_action_for(&self, account: &AccountId) -> Option<i128> {
+        self.balance_deltas
+            .iter()
+            .find(|(id, _)| id == account)
+            .map(|(_, delta)| *delta)
+    }
 }
@@ -0,0 +1,17 @@
+//! chronx-explorer — GraphQL API over the ChronX state database.
+//!
+//! Queries read directly from a [`StateDb`](chronx_state::StateDb);
+//! subscriptions follow a broadcast channel of [`ExplorerEvent`]s fed by the
+//! node's main loop. The node embeds this server with `--explorer-port`; the
+//! `chronx-explorer` binary serves a database directory on its own.
+
+pub mod schema;
+pub mod server;
+pub mod types;
+
+pub use schema::{build_schema, ExplorerSchema};
+pub use server::{router, serve};
+pub use types::ExplorerEvent;
+
+/// Capacity of the event channel. Slow subscribers skip what they missed.
+pub const EVENT_CHANNEL_CAPACITY: usize = 1024;
@@ -0,0 +1,139 @@
+//! chronx-explorer — standalone GraphQL explorer.
+//!
+//! Serves a state database directory that no running node holds open
+//! (a checkpoint, or a stopped node's data dir). With `--rpc`, the node's
+//! recent transactions are polled and pushed to subscribers.
+//!
+//! To explore a live node's state, run the node with `--explorer-port`.
+//!
+//! Usage:
+//!   chronx-explorer --data-dir ~/.chronx/checkpoints/checkpoint_<ts> \
+//!       --rpc http://127.0.0.1:8545 --port 4000
+
+use std::collections::HashSet;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Context;
+use chronx_explorer::types::GqlTransactionApplied;
+use chronx_explorer::{build_schema, serve, ExplorerEvent, EVENT_CHANNEL_CAPACITY};
+use chronx_state::StateDb;
+use clap::Parser;
+use serde::Deserialize;
+use tokio::sync::broadcast;
+use tracing::{info, warn};
+
+#[derive(Parser, Debug)]
+#[command(name = "chronx-explorer", version, about = "ChronX GraphQL explorer")]
+struct Args {
+    /// State database directory to serve.
+    #[arg(long)]
+    data_dir: PathBuf,
+
+    /// Node JSON-RPC endpoint to poll for new transactions.
+    #[arg(long)]
+    rpc: Option<String>,
+
+    /// GraphQL listen port.
+    #[arg(long, default_value_t = 4000)]
+    port: u16,
+
+    /// Listen address.
+    #[arg(long, default_value = "127.0.0.1")]
+    host: std::net::IpAddr,
+
+    /// Seconds between `--rpc` polls.
+    #[arg(long, default_value_t = 2)]
+    poll_secs: u64,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| "info".parse().unwrap()),
+        )
+        .init();
+    let args = Args::parse();
+
+    let db = Arc::new(
+        StateDb::open(&args.data_dir)
+            .with_context(|| format!("opening state db {}", args.data_dir.display()))?,
+    );
+    let (events, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+
+    if let Some(rpc) = args.rpc.clone() {
+        let events = events.clone();
+        let every = Duration::from_secs(args.poll_secs.max(1));
+        tokio::spawn(async move { poll_recent_transactions(rpc, every, events).await });
+    }
+
+    let addr = SocketAddr::new(args.host, args.port);
+    serve(build_schema(db, events), addr).await.context("serving GraphQL")
+}
+
+#[derive(Deserialize)]
+struct RecentTx {
+    tx_id: String,
+    from: String,
+    timestamp: i64,
+}
+
+#[derive(Deserialize)]
+struct RpcResponse {
+    result: Option<Vec<RecentTx>>,
+}
+
+/// Publish a `TransactionApplied` event for every transaction the node
+/// reports that was not in the previous poll. The first poll only primes the
+/// seen set, so history is not replayed.
+async fn poll_recent_transactions(rpc: String, every: Duration, events: broadcast::Sender<ExplorerEvent>) {
+    let client = reqwest::Client::new();
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut primed = false;
+    let mut interval = tokio::time::interval(every);
+    loop {
+        interval.tick().await;
+        let body = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "chronx_getRecentTransactions",
+            "params": [200],
+        });
+        let recent = match client.post(&rpc).json(&body).send().await {
+            Ok(resp) => match resp.json::<RpcResponse>().await {
+                Ok(r) => r.result.unwrap_or_default(),
+                Err(e) => {
+                    warn!(error = %e, "bad response from node RPC");
+                    continue;
+                }
+            },
+            Err(e) => {
+                warn!(error = %e, "node RPC unreachable");
+                continue;
+            }
+        };
+
+        // Only the latest window is remembered; anything outside it has
+        // already been reported or is too old to matter.
+        let current: HashSet<String> = recent.iter().map(|tx| tx.tx_id.clone()).collect();
+        // Oldest first, so subscribers see events in ledger order.
+        for tx in recent.into_iter().rev() {
+            if primed && !seen.contains(&tx.tx_id) {
+                let _ = events.send(ExplorerEvent::TransactionApplied(GqlTransactionApplied {
+                    tx_id: tx.tx_id,
+                    from: tx.from,
+                    timestamp: tx.timestamp,
+                }));
+            }
+        }
+        seen = current;
+        if !primed {
+            info!(known = seen.len(), "polling node RPC for new transactions");
+            primed = true;
+        }
+    }
+}
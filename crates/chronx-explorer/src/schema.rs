@@ -0,0 +1,146 @@
+//! `Query` and `Subscription` roots.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use async_graphql::{Context, EmptyMutation, Object, Result, Schema, Subscription};
+use chronx_core::types::{AccountId, TxId};
+use chronx_state::StateDb;
+use futures::{Stream, StreamExt};
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::BroadcastStream;
+
+use crate::types::{
+    ExplorerEvent, GqlAccount, GqlChainStats, GqlOracleSnapshot, GqlProviderRecord, GqlTimeLock,
+    GqlTransaction,
+};
+
+pub type ExplorerSchema = Schema<Query, EmptyMutation, Subscription>;
+
+/// Default and maximum page size for `timelocks`.
+const DEFAULT_TIMELOCK_LIMIT: usize = 50;
+const MAX_TIMELOCK_LIMIT: usize = 500;
+
+/// Build the schema. Resolvers read from `db`; subscriptions follow `events`.
+pub fn build_schema(db: Arc<StateDb>, events: broadcast::Sender<ExplorerEvent>) -> ExplorerSchema {
+    Schema::build(Query, EmptyMutation, Subscription)
+        .data(db)
+        .data(events)
+        .finish()
+}
+
+fn db<'a>(ctx: &Context<'a>) -> &'a Arc<StateDb> {
+    ctx.data_unchecked::<Arc<StateDb>>()
+}
+
+fn parse_account(id: &str) -> Result<AccountId> {
+    AccountId::from_b58(id).map_err(|e| format!("invalid account id: {e}").into())
+}
+
+fn parse_tx(id: &str) -> Result<TxId> {
+    TxId::from_hex(id).map_err(|e| format!("invalid tx id: {e}").into())
+}
+
+pub struct Query;
+
+#[Object]
+impl Query {
+    async fn account(&self, ctx: &Context<'_>, id: String) -> Result<Option<GqlAccount>> {
+        let id = parse_account(&id)?;
+        Ok(db(ctx).get_account(&id)?.map(GqlAccount::from))
+    }
+
+    async fn transaction(&self, ctx: &Context<'_>, id: String) -> Result<Option<GqlTransaction>> {
+        let id = parse_tx(&id)?;
+        Ok(db(ctx)
+            .get_vertex(&id)?
+            .map(|v| GqlTransaction::new(&v.transaction, v.depth, v.state_root)))
+    }
+
+    /// Locks sent or received by `account_id`, newest first. `status` matches
+    /// the lock status name case-insensitively (e.g. `"Pending"`).
+    async fn timelocks(
+        &self,
+        ctx: &Context<'_>,
+        account_id: String,
+        status: Option<String>,
+        limit: Option<i32>,
+    ) -> Result<Vec<GqlTimeLock>> {
+        let id = parse_account(&account_id)?;
+        let db = db(ctx);
+        let limit = limit
+            .map(|l| (l.max(0) as usize).min(MAX_TIMELOCK_LIMIT))
+            .unwrap_or(DEFAULT_TIMELOCK_LIMIT);
+
+        let mut seen = HashSet::new();
+        let mut locks: Vec<_> = db
+            .iter_timelocks_for_sender(&id)?
+            .into_iter()
+            .chain(db.iter_timelocks_for_recipient(&id)?)
+            .filter(|tlc| seen.insert(tlc.id.clone()))
+            .filter(|tlc| {
+                status
+                    .as_deref()
+                    .is_none_or(|s| tlc.status.name().eq_ignore_ascii_case(s))
+            })
+            .collect();
+        locks.sort_by_key(|tlc| std::cmp::Reverse(tlc.created_at));
+        Ok(locks.into_iter().take(limit).map(GqlTimeLock::from).collect())
+    }
+
+    async fn oracle_snapshot(&self, ctx: &Context<'_>, pair: String) -> Result<Option<GqlOracleSnapshot>> {
+        Ok(db(ctx).get_oracle_snapshot(&pair)?.map(GqlOracleSnapshot::from))
+    }
+
+    async fn provider(&self, ctx: &Context<'_>, id: String) -> Result<Option<GqlProviderRecord>> {
+        let id = parse_account(&id)?;
+        Ok(db(ctx).get_provider(&id)?.map(GqlProviderRecord::from))
+    }
+
+    async fn chain_stats(&self, ctx: &Context<'_>) -> Result<GqlChainStats> {
+        let db = db(ctx);
+        let tips = db.get_tips()?;
+        let dag_depth = tips
+            .iter()
+            .filter_map(|t| db.get_vertex(t).ok().flatten())
+            .map(|v| v.depth)
+            .max()
+            .unwrap_or(0);
+        Ok(GqlChainStats {
+            total_accounts: db.count_accounts(),
+            total_timelocks: db.count_timelocks(),
+            total_vertices: db.count_vertices(),
+            dag_tip_count: tips.len() as u64,
+            dag_depth,
+            state_root: db.get_latest_state_root()?.map(hex::encode),
+        })
+    }
+}
+
+pub struct Subscription;
+
+#[Subscription]
+impl Subscription {
+    /// Every ledger event as it is applied.
+    async fn events(&self, ctx: &Context<'_>) -> impl Stream<Item = ExplorerEvent> {
+        subscribe(ctx)
+    }
+
+    /// Lock status changes, optionally for a single lock.
+    async fn lock_status(&self, ctx: &Context<'_>, lock_id: Option<String>) -> impl Stream<Item = ExplorerEvent> {
+        subscribe(ctx).filter(move |event| {
+            let keep = match event {
+                ExplorerEvent::LockStatusChanged(c) => lock_id.as_deref().is_none_or(|id| c.lock_id == id),
+                _ => false,
+            };
+            std::future::ready(keep)
+        })
+    }
+}
+
+/// A receiver on the shared event channel. Lagged subscribers skip the events
+/// they missed rather than ending the stream.
+fn subscribe(ctx: &Context<'_>) -> impl Stream<Item = ExplorerEvent> {
+    let rx = ctx.data_unchecked::<broadcast::Sender<ExplorerEvent>>().subscribe();
+    BroadcastStream::new(rx).filter_map(|event| std::future::ready(event.ok()))
+}
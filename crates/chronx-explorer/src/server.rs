@@ -0,0 +1,101 @@
+//! HTTP and WebSocket transport for the explorer schema.
+//!
+//! - `POST /graphql`    — queries
+//! - `GET  /graphql/ws` — subscriptions (`graphql-ws` or `graphql-transport-ws`)
+//! - `GET  /`           — GraphiQL
+
+use std::net::SocketAddr;
+use std::str::FromStr;
+
+use async_graphql::http::{
+    GraphiQLSource, WebSocket, WebSocketProtocols as Protocols, WsMessage, ALL_WEBSOCKET_PROTOCOLS,
+};
+use axum::extract::ws::{Message, WebSocket as AxumWebSocket, WebSocketUpgrade};
+use axum::extract::State;
+use axum::http::HeaderMap;
+use axum::response::{Html, IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use futures::{SinkExt, StreamExt};
+use tracing::info;
+
+use crate::schema::ExplorerSchema;
+
+const GRAPHQL_PATH: &str = "/graphql";
+const SUBSCRIPTION_PATH: &str = "/graphql/ws";
+
+/// Router serving `schema`.
+pub fn router(schema: ExplorerSchema) -> Router {
+    Router::new()
+        .route("/", get(graphiql))
+        .route(GRAPHQL_PATH, get(graphiql).post(graphql))
+        .route(SUBSCRIPTION_PATH, get(subscriptions))
+        .with_state(schema)
+}
+
+/// Bind `addr` and serve until the task is dropped.
+pub async fn serve(schema: ExplorerSchema, addr: SocketAddr) -> std::io::Result<()> {
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    info!(addr = %listener.local_addr()?, "GraphQL explorer listening");
+    axum::serve(listener, router(schema)).await
+}
+
+async fn graphiql() -> Html<String> {
+    Html(
+        GraphiQLSource::build()
+            .endpoint(GRAPHQL_PATH)
+            .subscription_endpoint(SUBSCRIPTION_PATH)
+            .finish(),
+    )
+}
+
+async fn graphql(
+    State(schema): State<ExplorerSchema>,
+    Json(request): Json<async_graphql::Request>,
+) -> Json<async_graphql::Response> {
+    Json(schema.execute(request).await)
+}
+
+async fn subscriptions(
+    State(schema): State<ExplorerSchema>,
+    headers: HeaderMap,
+    upgrade: WebSocketUpgrade,
+) -> Response {
+    let protocol = headers
+        .get("sec-websocket-protocol")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.split(',').find_map(|p| Protocols::from_str(p.trim()).ok()))
+        .unwrap_or(Protocols::SubscriptionsTransportWS);
+
+    upgrade
+        .protocols(ALL_WEBSOCKET_PROTOCOLS)
+        .on_upgrade(move |socket| run_subscriptions(socket, schema, protocol))
+        .into_response()
+}
+
+async fn run_subscriptions(socket: AxumWebSocket, schema: ExplorerSchema, protocol: Protocols) {
+    let (mut sink, stream) = socket.split();
+    let incoming = stream
+        .take_while(|msg| std::future::ready(msg.is_ok()))
+        .filter_map(|msg| {
+            std::future::ready(match msg {
+                Ok(Message::Text(text)) => Some(text.into_bytes()),
+                Ok(Message::Binary(bytes)) => Some(bytes),
+                _ => None,
+            })
+        });
+
+    let mut outgoing = WebSocket::new(schema, incoming, protocol);
+    while let Some(msg) = outgoing.next().await {
+        let msg = match msg {
+            WsMessage::Text(text) => Message::Text(text),
+            WsMessage::Close(code, reason) => Message::Close(Some(axum::extract::ws::CloseFrame {
+                code,
+                reason: reason.into(),
+            })),
+        };
+        if sink.send(msg).await.is_err() {
+            break;
+        }
+    }
+}
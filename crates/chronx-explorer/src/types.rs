@@ -0,0 +1,209 @@
+//! GraphQL object types mirroring the core ledger types.
+//!
+//! Balances are exposed as decimal strings: GraphQL `Int` is 32-bit and
+//! Chronos amounts are `u128`.
+
+use async_graphql::{SimpleObject, Union};
+use chronx_core::account::{Account, TimeLockContract};
+use chronx_core::claims::{OracleSnapshot, ProviderRecord, ProviderStatus};
+use chronx_core::constants::CHRONOS_PER_KX;
+use chronx_core::transaction::Transaction;
+
+#[derive(Clone, Debug, SimpleObject)]
+pub struct GqlAccount {
+    pub account_id: String,
+    pub balance_chronos: String,
+    pub spendable_chronos: String,
+    pub balance_kx: String,
+    pub nonce: u64,
+    pub is_verifier: bool,
+    pub created_at: Option<i64>,
+    pub incoming_locks_count: u32,
+}
+
+impl From<Account> for GqlAccount {
+    fn from(a: Account) -> Self {
+        Self {
+            account_id: a.account_id.to_b58(),
+            balance_chronos: a.balance.to_string(),
+            spendable_chronos: a.spendable_balance().to_string(),
+            balance_kx: (a.balance / CHRONOS_PER_KX).to_string(),
+            nonce: a.nonce,
+            is_verifier: a.is_verifier,
+            created_at: a.created_at,
+            incoming_locks_count: a.incoming_locks_count,
+        }
+    }
+}
+
+#[derive(Clone, Debug, SimpleObject)]
+pub struct GqlTimeLock {
+    pub lock_id: String,
+    pub sender: String,
+    pub recipient_account_id: String,
+    pub amount_chronos: String,
+    pub amount_kx: String,
+    pub unlock_at: i64,
+    pub created_at: i64,
+    pub status: String,
+    pub memo: Option<String>,
+}
+
+impl From<TimeLockContract> for GqlTimeLock {
+    fn from(tlc: TimeLockContract) -> Self {
+        Self {
+            lock_id: tlc.id.to_hex(),
+            sender: tlc.sender.to_b58(),
+            recipient_account_id: tlc.recipient_account_id.to_b58(),
+            amount_chronos: tlc.amount.to_string(),
+            amount_kx: (tlc.amount / CHRONOS_PER_KX).to_string(),
+            unlock_at: tlc.unlock_at,
+            created_at: tlc.created_at,
+            status: tlc.status.name().to_string(),
+            memo: tlc.memo,
+        }
+    }
+}
+
+#[derive(Clone, Debug, SimpleObject)]
+pub struct GqlTransaction {
+    pub tx_id: String,
+    pub from: String,
+    pub timestamp: i64,
+    pub nonce: u64,
+    pub parents: Vec<String>,
+    /// Action variant names, in order.
+    pub actions: Vec<String>,
+    pub depth: u64,
+    pub state_root: Option<String>,
+}
+
+impl GqlTransaction {
+    pub fn new(tx: &Transaction, depth: u64, state_root: Option<[u8; 32]>) -> Self {
+        Self {
+            tx_id: tx.tx_id.to_hex(),
+            from: tx.from.to_b58(),
+            timestamp: tx.timestamp,
+            nonce: tx.nonce,
+            parents: tx.parents.iter().map(|p| p.to_hex()).collect(),
+            actions: tx.actions.iter().map(action_name).collect(),
+            depth,
+            state_root: state_root.map(hex::encode),
+        }
+    }
+}
+
+/// Variant name of an action, taken from its `Debug` form.
+fn action_name(action: &chronx_core::transaction::Action) -> String {
+    let debug = format!("{action:?}");
+    debug
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[derive(Clone, Debug, SimpleObject)]
+pub struct GqlOracleSnapshot {
+    pub pair: String,
+    pub price_cents: u64,
+    pub num_submissions: u32,
+    pub updated_at: i64,
+}
+
+impl From<OracleSnapshot> for GqlOracleSnapshot {
+    fn from(s: OracleSnapshot) -> Self {
+        Self {
+            pair: s.pair,
+            price_cents: s.price_cents,
+            num_submissions: s.num_submissions,
+            updated_at: s.updated_at,
+        }
+    }
+}
+
+#[derive(Clone, Debug, SimpleObject)]
+pub struct GqlProviderRecord {
+    pub provider_id: String,
+    pub provider_class: String,
+    pub jurisdictions: Vec<String>,
+    pub status: String,
+    pub revoked_at: Option<i64>,
+    pub registration_bond_chronos: String,
+    pub registered_at: i64,
+}
+
+impl From<ProviderRecord> for GqlProviderRecord {
+    fn from(p: ProviderRecord) -> Self {
+        let (status, revoked_at) = match p.status {
+            ProviderStatus::Active => ("Active", None),
+            ProviderStatus::Revoked { revoked_at } => ("Revoked", Some(revoked_at)),
+        };
+        Self {
+            provider_id: p.provider_id.to_b58(),
+            provider_class: p.provider_class,
+            jurisdictions: p.jurisdictions,
+            status: status.to_string(),
+            revoked_at,
+            registration_bond_chronos: p.registration_bond.to_string(),
+            registered_at: p.registered_at,
+        }
+    }
+}
+
+#[derive(Clone, Debug, SimpleObject)]
+pub struct GqlChainStats {
+    pub total_accounts: u64,
+    pub total_timelocks: u64,
+    pub total_vertices: u64,
+    pub dag_tip_count: u64,
+    pub dag_depth: u64,
+    pub state_root: Option<String>,
+}
+
+// ── Subscription events ──────────────────────────────────────────────────────
+
+#[derive(Clone, Debug, SimpleObject)]
+pub struct GqlTransactionApplied {
+    pub tx_id: String,
+    pub from: String,
+    pub timestamp: i64,
+}
+
+#[derive(Clone, Debug, SimpleObject)]
+pub struct GqlLockStatusChanged {
+    pub lock_id: String,
+    pub new_status: String,
+    pub timestamp: i64,
+}
+
+/// A ledger event pushed to `Subscription` streams.
+#[derive(Clone, Debug, Union)]
+pub enum ExplorerEvent {
+    TransactionApplied(GqlTransactionApplied),
+    LockStatusChanged(GqlLockStatusChanged),
+}
+
+impl ExplorerEvent {
+    /// Events for one applied transaction: the transaction itself, then every
+    /// lock whose status it changed.
+    pub fn for_receipt(
+        tx: &Transaction,
+        receipt: &chronx_core::transaction::TransactionReceipt,
+        at: i64,
+    ) -> Vec<Self> {
+        let mut events = vec![Self::TransactionApplied(GqlTransactionApplied {
+            tx_id: tx.tx_id.to_hex(),
+            from: tx.from.to_b58(),
+            timestamp: at,
+        })];
+        events.extend(receipt.lock_status_changes.iter().map(|(id, status)| {
+            Self::LockStatusChanged(GqlLockStatusChanged {
+                lock_id: id.to_hex(),
+                new_status: status.name().to_string(),
+                timestamp: at,
+            })
+        }));
+        events
+    }
+}
@@ -23,20 +23,23 @@
 
 pub mod params;
 
-pub use params::GenesisParams;
+pub use params::{GenesisAllocation, GenesisParams};
+
+/// JSON schema describing the `genesis-params.json` file format.
+pub const GENESIS_PARAMS_SCHEMA: &str = include_str!("../genesis-params.schema.json");
 
 use chronx_core::account::{Account, AuthPolicy, TimeLockContract, TimeLockStatus};
 use chronx_core::constants::{
     CHRONOS_PER_KX, FAUCET_KX, FOUNDER_KX, GENESIS_TIMESTAMP, HUMANITY_STAKE_KX,
     HUMANITY_UNLOCK_TIMESTAMP, MILESTONE_2076_KX, MILESTONE_2076_UNLOCK_TIMESTAMP, MISAI_BOND_KX,
     NODE_REWARDS_KX, PROTOCOL_RESERVE_KX, PROTOCOL_RESERVE_UNLOCK_TIMESTAMP, PUBLIC_SALE_KX,
-    TREASURY_KX, VERIFAS_BOND_KX,
+    TOTAL_SUPPLY_CHRONOS, TREASURY_KX, VERIFAS_BOND_KX,
 };
 use chronx_core::error::ChronxError;
 use chronx_core::types::{AccountId, TxId};
 use chronx_state::StateDb;
 use chronx_timelock::{node_rewards_release_schedule, treasury_release_schedule};
-use tracing::info;
+use tracing::{debug, info};
 
 /// Seed accounts used in genesis.
 pub struct GenesisAccounts {
@@ -62,6 +65,12 @@ pub struct GenesisAccounts {
     pub faucet: AccountId,
 }
 
+/// Meta key holding the base-58 public sale account id.
+pub const GENESIS_PUBLIC_SALE_META_KEY: &str = "genesis_public_sale_account";
+
+/// Meta key holding the hex BLAKE3 hash of the JSON genesis parameters.
+pub const GENESIS_HASH_META_KEY: &str = "genesis_hash";
+
 /// Apply the genesis state to an empty `StateDb`.
 ///
 /// This writes accounts and time-lock contracts directly — no PoW, no
@@ -74,10 +83,13 @@ pub struct GenesisAccounts {
 pub fn apply_genesis(db: &StateDb, params: &GenesisParams) -> Result<GenesisAccounts, ChronxError> {
     info!("applying ChronX genesis state");
 
+    // Reject bad custom allocations before anything is written.
+    let custom_total = custom_allocations_total(params)?;
+
     let accounts = build_accounts(params);
 
     // ── 1. Public sale allocation ────────────────────────────────────────────
-    let mut public_sale_account = Account::new(
+    let mut public_sale_account = genesis_account(
         accounts.public_sale.clone(),
         AuthPolicy::SingleSig {
             public_key: params.public_sale_key.clone(),
@@ -92,13 +104,12 @@ pub fn apply_genesis(db: &StateDb, params: &GenesisParams) -> Result<GenesisAcco
     );
 
     // ── 2. Treasury time-locks (100 annual releases) ─────────────────────────
-    let treasury_account = Account::new(
+    let mut treasury_account = genesis_account(
         accounts.treasury.clone(),
         AuthPolicy::SingleSig {
             public_key: params.treasury_key.clone(),
         },
     );
-    db.put_account(&treasury_account)?;
 
     let schedule = treasury_release_schedule();
     for release in &schedule {
@@ -116,7 +127,9 @@ pub fn apply_genesis(db: &StateDb, params: &GenesisParams) -> Result<GenesisAcco
             )),
         );
         db.put_timelock(&contract)?;
+        add_genesis_lock(&mut treasury_account, &contract);
     }
+    db.put_account(&treasury_account)?;
     info!(
         releases = schedule.len(),
         total_kx = TREASURY_KX,
@@ -124,13 +137,12 @@ pub fn apply_genesis(db: &StateDb, params: &GenesisParams) -> Result<GenesisAcco
     );
 
     // ── 3. Node Rewards time-locks (100 annual releases) ─────────────────────
-    let node_rewards_account = Account::new(
+    let mut node_rewards_account = genesis_account(
         accounts.node_rewards.clone(),
         AuthPolicy::SingleSig {
             public_key: params.node_rewards_key.clone(),
         },
     );
-    db.put_account(&node_rewards_account)?;
 
     let nr_schedule = node_rewards_release_schedule();
     for release in &nr_schedule {
@@ -148,7 +160,9 @@ pub fn apply_genesis(db: &StateDb, params: &GenesisParams) -> Result<GenesisAcco
             )),
         );
         db.put_timelock(&contract)?;
+        add_genesis_lock(&mut node_rewards_account, &contract);
     }
+    db.put_account(&node_rewards_account)?;
     info!(
         releases = nr_schedule.len(),
         total_kx = NODE_REWARDS_KX,
@@ -156,13 +170,12 @@ pub fn apply_genesis(db: &StateDb, params: &GenesisParams) -> Result<GenesisAcco
     );
 
     // ── 4. Humanity stake time-lock ──────────────────────────────────────────
-    let humanity_account = Account::new(
+    let mut humanity_account = genesis_account(
         accounts.humanity.clone(),
         AuthPolicy::SingleSig {
             public_key: params.humanity_key.clone(),
         },
     );
-    db.put_account(&humanity_account)?;
 
     let humanity_lock = genesis_lock(
         humanity_lock_id(),
@@ -178,6 +191,8 @@ pub fn apply_genesis(db: &StateDb, params: &GenesisParams) -> Result<GenesisAcco
         ),
     );
     db.put_timelock(&humanity_lock)?;
+    add_genesis_lock(&mut humanity_account, &humanity_lock);
+    db.put_account(&humanity_account)?;
     info!(
         unlock_year = 2126,
         kx = HUMANITY_STAKE_KX,
@@ -185,13 +200,12 @@ pub fn apply_genesis(db: &StateDb, params: &GenesisParams) -> Result<GenesisAcco
     );
 
     // ── 5. Milestone 2076 lock (separate wallet v8.0) ────────────────────────
-    let milestone_account = Account::new(
+    let mut milestone_account = genesis_account(
         accounts.milestone.clone(),
         AuthPolicy::SingleSig {
             public_key: params.milestone_key.clone(),
         },
     );
-    db.put_account(&milestone_account)?;
 
     let milestone_lock = genesis_lock(
         milestone_2076_lock_id(),
@@ -207,6 +221,8 @@ pub fn apply_genesis(db: &StateDb, params: &GenesisParams) -> Result<GenesisAcco
         ),
     );
     db.put_timelock(&milestone_lock)?;
+    add_genesis_lock(&mut milestone_account, &milestone_lock);
+    db.put_account(&milestone_account)?;
     info!(
         unlock_year = 2076,
         kx = MILESTONE_2076_KX,
@@ -214,13 +230,12 @@ pub fn apply_genesis(db: &StateDb, params: &GenesisParams) -> Result<GenesisAcco
     );
 
     // ── 6. Protocol reserve lock (separate wallet v8.0) ──────────────────────
-    let reserve_account = Account::new(
+    let mut reserve_account = genesis_account(
         accounts.reserve.clone(),
         AuthPolicy::SingleSig {
             public_key: params.reserve_key.clone(),
         },
     );
-    db.put_account(&reserve_account)?;
 
     let reserve_lock = genesis_lock(
         protocol_reserve_lock_id(),
@@ -236,6 +251,8 @@ pub fn apply_genesis(db: &StateDb, params: &GenesisParams) -> Result<GenesisAcco
         ),
     );
     db.put_timelock(&reserve_lock)?;
+    add_genesis_lock(&mut reserve_account, &reserve_lock);
+    db.put_account(&reserve_account)?;
     info!(
         unlock_year = 2036,
         kx = PROTOCOL_RESERVE_KX,
@@ -243,7 +260,7 @@ pub fn apply_genesis(db: &StateDb, params: &GenesisParams) -> Result<GenesisAcco
     );
 
     // ── 7. Founder allocation  ──────────────────────────────────────────
-    let mut founder_account = Account::new(
+    let mut founder_account = genesis_account(
         accounts.founder.clone(),
         AuthPolicy::SingleSig {
             public_key: params.founder_key.clone(),
@@ -258,7 +275,7 @@ pub fn apply_genesis(db: &StateDb, params: &GenesisParams) -> Result<GenesisAcco
     );
 
     // ── 8. MISAI Bond allocation  ─────────────────────────────────────
-    let mut misai_account = Account::new(
+    let mut misai_account = genesis_account(
         accounts.misai.clone(),
         AuthPolicy::SingleSig {
             public_key: params.misai_key.clone(),
@@ -273,7 +290,7 @@ pub fn apply_genesis(db: &StateDb, params: &GenesisParams) -> Result<GenesisAcco
     );
 
     // ── 9. Verifas Bond allocation  ────────────────────────────────────
-    let mut verifas_account = Account::new(
+    let mut verifas_account = genesis_account(
         accounts.verifas.clone(),
         AuthPolicy::SingleSig {
             public_key: params.verifas_key.clone(),
@@ -288,7 +305,7 @@ pub fn apply_genesis(db: &StateDb, params: &GenesisParams) -> Result<GenesisAcco
     );
 
     // ── 10. Faucet allocation  ─────────────────────────────────────
-    let mut faucet_account = Account::new(
+    let mut faucet_account = genesis_account(
         accounts.faucet.clone(),
         AuthPolicy::SingleSig {
             public_key: params.faucet_key.clone(),
@@ -302,11 +319,52 @@ pub fn apply_genesis(db: &StateDb, params: &GenesisParams) -> Result<GenesisAcco
         "genesis: faucet allocation"
     );
 
-    // ── 11. Store axioms in genesis metadata  ─────────────────────────
+    // ── 11. Custom allocations (debited from public sale) ───────────────────
+    if !params.custom_allocations.is_empty() {
+        use chronx_crypto::hash::account_id_from_pubkey;
+        for alloc in &params.custom_allocations {
+            let amount = alloc.amount_kx * CHRONOS_PER_KX;
+            let recipient = account_id_from_pubkey(&alloc.recipient_key.0);
+            let mut recipient_account = db.get_account(&recipient)?.unwrap_or_else(|| {
+                genesis_account(
+                    recipient.clone(),
+                    AuthPolicy::SingleSig {
+                        public_key: alloc.recipient_key.clone(),
+                    },
+                )
+            });
+            recipient_account.balance += amount;
+            db.put_account(&recipient_account)?;
+            info!(
+                account = %recipient,
+                balance_kx = alloc.amount_kx,
+                "genesis: custom allocation"
+            );
+        }
+
+        let mut public_sale_account = db
+            .get_account(&accounts.public_sale)?
+            .ok_or_else(|| ChronxError::UnknownAccount(accounts.public_sale.to_string()))?;
+        public_sale_account.balance -= custom_total;
+        db.put_account(&public_sale_account)?;
+        info!(
+            allocations = params.custom_allocations.len(),
+            total_chronos = custom_total,
+            "genesis: custom allocations debited from public sale"
+        );
+    }
+
+    // ── 12. Store axioms in genesis metadata  ─────────────────────────
     if let Some(ref axioms) = params.axioms {
         db.put_meta("genesis_axioms", axioms.as_bytes())?;
         info!("genesis: axioms stored in metadata ({} bytes)", axioms.len());
     }
+    // The public sale account holds no genesis lock, so record it for
+    // `chronx_getGenesisInfo`.
+    db.put_meta(GENESIS_PUBLIC_SALE_META_KEY, accounts.public_sale.to_b58().as_bytes())?;
+    let params_json = serde_json::to_vec(params)
+        .map_err(|e| ChronxError::Serialization(e.to_string()))?;
+    db.put_meta(GENESIS_HASH_META_KEY, blake3::hash(&params_json).to_hex().as_bytes())?;
 
     // ── Verify supply ────────────────────────────────────────────────────────
     verify_genesis_supply(db, params)?;
@@ -320,21 +378,31 @@ pub fn apply_genesis(db: &StateDb, params: &GenesisParams) -> Result<GenesisAcco
 /// Verify that all genesis balances + pending time-locks sum correctly.
 /// Only Public Sale + timelocked allocations are created at genesis.
 /// Founder, Faucet, MISAI Bond, Verifas Bond are funded post-genesis from Public Sale.
+/// Custom allocations move balance out of Public Sale, so they leave the total unchanged.
 /// Genesis block total = TOTAL_SUPPLY_CHRONOS (all KX starts in Public Sale + timelocks).
 fn verify_genesis_supply(db: &StateDb, params: &GenesisParams) -> Result<(), ChronxError> {
-    
-    let bal = |key: &chronx_core::types::DilithiumPublicKey| -> Result<u128, ChronxError> {
-        Ok(db
-            .get_account(&chronx_crypto::hash::account_id_from_pubkey(&key.0))?
-            .map(|a| a.balance)
-            .unwrap_or(0))
-    };
+    use chronx_crypto::hash::account_id_from_pubkey;
 
-    let public_sale_bal = bal(&params.public_sale_key)?;
-    let founder_bal = bal(&params.founder_key)?;
-    let misai_bal = bal(&params.misai_key)?;
-    let verifas_bal = bal(&params.verifas_key)?;
-    let faucet_bal = bal(&params.faucet_key)?;
+    // Sum each funded account once — custom allocations may reuse a standard
+    // key, and the placeholder keys are shared between several allocations.
+    let mut seen = std::collections::HashSet::new();
+    let mut balances: u128 = 0;
+    let keys = [
+        &params.public_sale_key,
+        &params.founder_key,
+        &params.misai_key,
+        &params.verifas_key,
+        &params.faucet_key,
+    ]
+    .into_iter()
+    .chain(params.custom_allocations.iter().map(|a| &a.recipient_key));
+    for key in keys {
+        let id = account_id_from_pubkey(&key.0);
+        if !seen.insert(id.clone()) {
+            continue;
+        }
+        balances += db.get_account(&id)?.map(|a| a.balance).unwrap_or(0);
+    }
 
     let treasury_locks: u128 = treasury_release_schedule()
         .iter()
@@ -350,19 +418,14 @@ fn verify_genesis_supply(db: &StateDb, params: &GenesisParams) -> Result<(), Chr
     let milestone_amount = MILESTONE_2076_KX * CHRONOS_PER_KX;
     let reserve_amount = PROTOCOL_RESERVE_KX * CHRONOS_PER_KX;
 
-    let total = public_sale_bal
-        + founder_bal
-        + misai_bal
-        + verifas_bal
-        + faucet_bal
+    let total = balances
         + treasury_locks
         + node_rewards_locks
         + humanity_amount
         + milestone_amount
         + reserve_amount;
 
-    // genesis block total = 8,095,000,000 KX (175M distributed post-genesis)
-    let genesis_block_total: u128 = (PUBLIC_SALE_KX + TREASURY_KX + NODE_REWARDS_KX + HUMANITY_STAKE_KX + MILESTONE_2076_KX + PROTOCOL_RESERVE_KX) * CHRONOS_PER_KX;
+    let genesis_block_total = genesis_block_total_chronos();
     if total != genesis_block_total {
         return Err(ChronxError::GenesisSupplyMismatch {
             expected: genesis_block_total,
@@ -374,6 +437,150 @@ fn verify_genesis_supply(db: &StateDb, params: &GenesisParams) -> Result<(), Chr
     Ok(())
 }
 
+/// Chronos minted by genesis: 8,095,000,000 KX. The 175,000,000 KX gap to
+/// `TOTAL_SUPPLY_CHRONOS` is distributed post-genesis and never minted here.
+pub fn genesis_block_total_chronos() -> u128 {
+    (PUBLIC_SALE_KX
+        + TREASURY_KX
+        + NODE_REWARDS_KX
+        + HUMANITY_STAKE_KX
+        + MILESTONE_2076_KX
+        + PROTOCOL_RESERVE_KX)
+        * CHRONOS_PER_KX
+}
+
+/// Chronos a lock still holds. Funds leave a lock when it is paid out,
+/// cancelled or reverted; a lock slashed for a bad reveal keeps its amount
+/// (nothing releases it), while a successful challenge returns it to the sender.
+/// An active recurring installment also holds the reserve for the rest of its
+/// series.
+fn lock_held_chronos(tlc: &TimeLockContract) -> u128 {
+    use chronx_core::claims::SlashReason;
+    match &tlc.status {
+        TimeLockStatus::PartiallyReleased { remaining_chronos, .. } => *remaining_chronos as u128,
+        TimeLockStatus::ClaimSlashed { reason: SlashReason::SuccessfulChallenge, .. } => 0,
+        TimeLockStatus::ClaimSlashed { .. } => tlc.amount,
+        status if status.is_terminal() => 0,
+        _ => tlc.amount + tlc.recurring_reserve(),
+    }
+}
+
+/// Recompute the supply held in an existing database.
+///
+/// Counts account balances, savings and recovery bonds; Chronos still held by
+/// time-locks (V0 locks hold their amount directly) and by V2 claim bonds; and
+/// the running burned total. Funds escrowed by the loan, deposit and escrow
+/// subsystems are not counted.
+pub fn computed_supply_from_db(db: &StateDb) -> Result<u128, ChronxError> {
+    let audit = audit_account_supply(db)?;
+    Ok(audit.total - audit.unminted)
+}
+
+/// Result of [`audit_account_supply`]. Every field is in Chronos.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SupplyAudit {
+    /// Account balances (the recovery escrow account included) and savings.
+    pub accounts: u128,
+    /// Principal of `Pending` time-locks.
+    pub pending_locks: u128,
+    /// Everything else locks still hold (claims in progress, recurring
+    /// reserves) plus V2 claim bonds.
+    pub other_held: u128,
+    pub burned: u128,
+    /// The part of `TOTAL_SUPPLY_CHRONOS` genesis never mints.
+    pub unminted: u128,
+    /// Sum of the fields above.
+    pub total: u128,
+    /// `total - TOTAL_SUPPLY_CHRONOS`.
+    pub discrepancy: i128,
+}
+
+impl SupplyAudit {
+    pub fn is_ok(&self) -> bool {
+        self.discrepancy == 0
+    }
+}
+
+/// Sum every account balance and every pending lock amount and check the
+/// result against `TOTAL_SUPPLY_CHRONOS`.
+///
+/// Counts the same holdings as [`computed_supply_from_db`], plus the
+/// 175,000,000 KX genesis leaves unminted, so a healthy database audits to
+/// exactly the protocol total.
+pub fn audit_account_supply(db: &StateDb) -> Result<SupplyAudit, ChronxError> {
+    let mut accounts: u128 = 0;
+    for a in db.iter_all_accounts()? {
+        // Recovery and challenge bonds are not added separately: they sit
+        // in the balance of `RECOVERY_ESCROW_ACCOUNT`, counted like any other.
+        accounts += a.balance + a.savings_balance;
+    }
+    let timelocks = db.iter_all_timelocks()?;
+    let locks: u128 = timelocks.iter().map(lock_held_chronos).sum();
+    let pending_locks: u128 = timelocks
+        .iter()
+        .filter(|tlc| tlc.status == TimeLockStatus::Pending)
+        .map(|tlc| tlc.amount)
+        .sum();
+    let claim_bonds: u128 = db
+        .iter_all_claims()?
+        .iter()
+        .map(|c| c.commit_bond + c.challenge_bond)
+        .sum();
+    let burned = db.get_burned_chronos()?;
+    debug!(accounts, locks, claim_bonds, burned, "supply recomputed");
+
+    let other_held = locks - pending_locks + claim_bonds;
+    let unminted = TOTAL_SUPPLY_CHRONOS - genesis_block_total_chronos();
+    let total = accounts + pending_locks + other_held + burned + unminted;
+    Ok(SupplyAudit {
+        accounts,
+        pending_locks,
+        other_held,
+        burned,
+        unminted,
+        total,
+        discrepancy: total as i128 - TOTAL_SUPPLY_CHRONOS as i128,
+    })
+}
+
+/// Recompute the supply from an existing database and compare it to the
+/// genesis block total. Catches corrupted records, stray test writes and a
+/// faulty genesis.
+pub fn verify_genesis_supply_from_db(db: &StateDb) -> Result<(), ChronxError> {
+    let total = computed_supply_from_db(db)?;
+    let expected = genesis_block_total_chronos();
+    if total != expected {
+        return Err(ChronxError::GenesisSupplyMismatch { expected, got: total });
+    }
+    info!(total, "supply verified against genesis");
+    Ok(())
+}
+
+/// Total Chronos requested by `params.custom_allocations`.
+///
+/// Fails if the sum overflows or exceeds the public sale allocation the
+/// custom balances are carved out of.
+pub fn custom_allocations_total(params: &GenesisParams) -> Result<u128, ChronxError> {
+    let mut total: u128 = 0;
+    for alloc in &params.custom_allocations {
+        let amount = alloc
+            .amount_kx
+            .checked_mul(CHRONOS_PER_KX)
+            .ok_or(ChronxError::GenesisAllocationOverflow)?;
+        total = total
+            .checked_add(amount)
+            .ok_or(ChronxError::GenesisAllocationOverflow)?;
+    }
+    let available = PUBLIC_SALE_KX * CHRONOS_PER_KX;
+    if total > available {
+        return Err(ChronxError::GenesisAllocationExceedsPublicSale {
+            requested: total,
+            available,
+        });
+    }
+    Ok(total)
+}
+
 /// Derive the genesis `AccountId`s from the public keys in `params`.
 fn build_accounts(params: &GenesisParams) -> GenesisAccounts {
     use chronx_crypto::hash::account_id_from_pubkey;
@@ -391,6 +598,23 @@ fn build_accounts(params: &GenesisParams) -> GenesisAccounts {
     }
 }
 
+/// Build a genesis-time `Account`, created at `GENESIS_TIMESTAMP`.
+fn genesis_account(account_id: AccountId, auth_policy: AuthPolicy) -> Account {
+    let mut account = Account::new(account_id, auth_policy);
+    account.created_at = Some(GENESIS_TIMESTAMP);
+    account
+}
+
+/// Count a genesis lock in its sender's and recipient's cached lock
+/// counters; genesis locks are always addressed to their own sender.
+fn add_genesis_lock(account: &mut Account, lock: &TimeLockContract) {
+    debug_assert!(lock.sender == account.account_id && lock.recipient_account_id == account.account_id);
+    account.incoming_locks_count += 1;
+    account.outgoing_locks_count += 1;
+    account.total_locked_incoming_chronos += lock.amount;
+    account.total_locked_outgoing_chronos += lock.amount;
+}
+
 /// Build a genesis-time `TimeLockContract` with V3 fields set to safe defaults.
 fn genesis_lock(
     id: TxId,
@@ -453,6 +677,10 @@ fn genesis_lock(
         extension_right: None,
         max_extensions: None,
         extensions_used: None,
+        next_recurring_index: 0,
+        recurring_series_id: None,
+        split_policy_validated: false,
+        cancellation_reason: None,
     }
 }
 
@@ -525,13 +753,39 @@ mod tests {
             reserve_key: re.public_key.clone(),
             faucet_key: fa.public_key.clone(),
             axioms: None,
+            rate_limit_tx_per_wallet_per_minute: 10,
+            rate_limit_loan_actions_per_wallet_per_day: 100,
+            channel_threshold_daily_tx: 1000,
+            channel_open_min_lock_kx: 1,
+            sweep_loan_interval_seconds: 3600,
+            sweep_email_lock_interval_seconds: 300,
+            sweep_matured_timelock_interval_seconds: 60,
+            loan_min_settlement_chronos: 1000,
+            sweep_humanity_stake_interval_seconds: 86400,
+            sweep_guardian_transition_interval_seconds: 3600,
+            sweep_promise_chain_interval_seconds: 86400,
+            sweep_executor_interval_seconds: 60,
+            pay_as_max_usd: 100.0,
+            pay_as_enabled: true,
+            custom_allocations: Vec::new(),
         }
     }
 
+    fn temp_db(name: &str) -> (StateDb, std::path::PathBuf) {
+        let dir = std::env::temp_dir().join(name);
+        let _ = std::fs::remove_dir_all(&dir);
+        (StateDb::open(&dir).unwrap(), dir)
+    }
+
+    fn balance_of(db: &StateDb, key: &chronx_core::types::DilithiumPublicKey) -> u128 {
+        db.get_account(&chronx_crypto::hash::account_id_from_pubkey(&key.0))
+            .unwrap()
+            .map(|a| a.balance)
+            .unwrap_or(0)
+    }
+
     #[test]
     fn genesis_supply_is_exact() {
-        use chronx_core::constants::{FAUCET_KX, FOUNDER_KX, MISAI_BOND_KX, VERIFAS_BOND_KX};
-
         let dir = std::env::temp_dir().join("chronx_genesis_test");
         let _ = std::fs::remove_dir_all(&dir);
         let db = StateDb::open(&dir).unwrap();
@@ -548,6 +802,11 @@ mod tests {
         };
 
         let ps_bal = bal(&params.public_sale_key);
+        assert!(db
+            .iter_all_accounts()
+            .unwrap()
+            .iter()
+            .all(|a| a.created_at == Some(GENESIS_TIMESTAMP)));
         let fo_bal = bal(&params.founder_key);
         let mi_bal = bal(&params.misai_key);
         let ve_bal = bal(&params.verifas_key);
@@ -568,13 +827,275 @@ mod tests {
         assert_eq!(
             ps_bal + fo_bal + mi_bal + ve_bal + fa_bal + treasury_total + node_rewards_total
                 + humanity + milestone + reserve,
-            TOTAL_SUPPLY_CHRONOS,
-            "genesis total must equal TOTAL_SUPPLY_CHRONOS"
+            (PUBLIC_SALE_KX + TREASURY_KX + NODE_REWARDS_KX + HUMANITY_STAKE_KX
+                + MILESTONE_2076_KX + PROTOCOL_RESERVE_KX)
+                * CHRONOS_PER_KX,
+            "genesis total must equal the genesis block total"
         );
 
         let _ = std::fs::remove_dir_all(&dir);
     }
 
+    #[test]
+    fn single_custom_allocation_is_debited_from_public_sale() {
+        let (db, dir) = temp_db("chronx_genesis_custom_single");
+        let mut params = test_params();
+        let recipient = KeyPair::generate();
+        params.custom_allocations.push(GenesisAllocation {
+            recipient_key: recipient.public_key.clone(),
+            amount_kx: 1_000,
+        });
+
+        apply_genesis(&db, &params).expect("genesis must succeed");
+
+        assert_eq!(balance_of(&db, &recipient.public_key), 1_000 * CHRONOS_PER_KX);
+        assert_eq!(
+            balance_of(&db, &params.public_sale_key),
+            (PUBLIC_SALE_KX - 1_000) * CHRONOS_PER_KX
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn multiple_custom_allocations_conserve_supply() {
+        let (db, dir) = temp_db("chronx_genesis_custom_multi");
+        let mut params = test_params();
+        let a = KeyPair::generate();
+        let b = KeyPair::generate();
+        params.custom_allocations = vec![
+            GenesisAllocation { recipient_key: a.public_key.clone(), amount_kx: 250 },
+            GenesisAllocation { recipient_key: b.public_key.clone(), amount_kx: 750 },
+            // A second allocation to the same key accumulates.
+            GenesisAllocation { recipient_key: a.public_key.clone(), amount_kx: 50 },
+        ];
+
+        // apply_genesis runs verify_genesis_supply internally.
+        apply_genesis(&db, &params).expect("genesis must succeed");
+
+        assert_eq!(balance_of(&db, &a.public_key), 300 * CHRONOS_PER_KX);
+        assert_eq!(balance_of(&db, &b.public_key), 750 * CHRONOS_PER_KX);
+        assert_eq!(
+            balance_of(&db, &params.public_sale_key)
+                + balance_of(&db, &a.public_key)
+                + balance_of(&db, &b.public_key),
+            PUBLIC_SALE_KX * CHRONOS_PER_KX
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn custom_allocations_reject_overflow_and_oversubscription() {
+        let mut params = test_params();
+        params.custom_allocations.push(GenesisAllocation {
+            recipient_key: KeyPair::generate().public_key.clone(),
+            amount_kx: u128::MAX / 2,
+        });
+        assert!(matches!(
+            custom_allocations_total(&params),
+            Err(ChronxError::GenesisAllocationOverflow)
+        ));
+
+        params.custom_allocations[0].amount_kx = PUBLIC_SALE_KX + 1;
+        assert!(matches!(
+            custom_allocations_total(&params),
+            Err(ChronxError::GenesisAllocationExceedsPublicSale { .. })
+        ));
+
+        // Nothing is written when validation fails.
+        let (db, dir) = temp_db("chronx_genesis_custom_overflow");
+        assert!(apply_genesis(&db, &params).is_err());
+        assert!(db.get_account(&build_accounts(&params).public_sale).unwrap().is_none());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn supply_check_passes_on_fresh_genesis() {
+        let (db, dir) = temp_db("chronx_genesis_supply_ok");
+        let mut params = test_params();
+        params.custom_allocations.push(GenesisAllocation {
+            recipient_key: KeyPair::generate().public_key.clone(),
+            amount_kx: 5_000,
+        });
+        apply_genesis(&db, &params).expect("genesis must succeed");
+
+        verify_genesis_supply_from_db(&db).expect("fresh genesis must balance");
+
+        // Burned Chronos are accounted for once recorded.
+        let public_sale = build_accounts(&params).public_sale;
+        let mut acc = db.get_account(&public_sale).unwrap().unwrap();
+        acc.balance -= 7;
+        db.put_account(&acc).unwrap();
+        db.add_burned_chronos(7).unwrap();
+        verify_genesis_supply_from_db(&db).expect("burns must balance");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn supply_check_detects_corrupted_balance() {
+        let (db, dir) = temp_db("chronx_genesis_supply_corrupt");
+        let params = test_params();
+        apply_genesis(&db, &params).expect("genesis must succeed");
+
+        let public_sale = build_accounts(&params).public_sale;
+        let mut acc = db.get_account(&public_sale).unwrap().unwrap();
+        acc.balance += 1;
+        db.put_account(&acc).unwrap();
+
+        match verify_genesis_supply_from_db(&db) {
+            Err(ChronxError::GenesisSupplyMismatch { expected, got }) => {
+                assert_eq!(got, expected + 1);
+            }
+            other => panic!("expected GenesisSupplyMismatch, got {other:?}"),
+        }
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn recovery_bonds_keep_the_supply_balanced() {
+        use chronx_core::constants::{
+            MIN_CHALLENGE_BOND_CHRONOS, MIN_RECOVERY_BOND_CHRONOS, RECOVERY_EXECUTION_DELAY_SECS,
+            RECOVERY_VERIFIER_THRESHOLD, VERIFIER_VOTE_DEADLINE_SECS,
+        };
+        use chronx_core::transaction::{Action, AuthScheme, Transaction};
+        use chronx_core::types::EvidenceHash;
+        use chronx_state::StateEngine;
+
+        const NOW: i64 = 1_800_000_000;
+        let (db, dir) = temp_db("chronx_genesis_supply_recovery");
+        let params = test_params();
+        apply_genesis(&db, &params).expect("genesis must succeed");
+        let db = std::sync::Arc::new(db);
+        let engine = StateEngine::new(db.clone(), 0);
+
+        // Fund the parties out of the public sale, which keeps the supply whole.
+        let parties: [KeyPair; 5] = std::array::from_fn(|_| KeyPair::generate());
+        let [initiator_a, initiator_b, challenger, target_a, target_b] = &parties;
+        let fund = 10 * MIN_RECOVERY_BOND_CHRONOS;
+        let public_sale = build_accounts(&params).public_sale;
+        let mut sale = db.get_account(&public_sale).unwrap().unwrap();
+        sale.balance -= fund * parties.len() as u128;
+        db.put_account(&sale).unwrap();
+        for kp in &parties {
+            let mut acc = Account::new(kp.account_id.clone(), AuthPolicy::SingleSig { public_key: kp.public_key.clone() });
+            acc.balance = fund;
+            db.put_account(&acc).unwrap();
+        }
+        verify_genesis_supply_from_db(&db).expect("funding must balance");
+
+        let apply = |kp: &KeyPair, nonce: u64, action: Action, now: i64| {
+            let mut tx = Transaction {
+                tx_id: TxId::from_bytes([0u8; 32]),
+                parents: vec![],
+                timestamp: now,
+                nonce,
+                from: kp.account_id.clone(),
+                actions: vec![action],
+                pow_nonce: 0,
+                signatures: vec![],
+                auth_scheme: AuthScheme::SingleSig,
+                tx_version: 1,
+                client_ref: None,
+                fee_chronos: 0,
+                expires_at: None,
+                sender_public_key: Some(kp.public_key.clone()),
+            };
+            let body = tx.body_bytes();
+            tx.tx_id = chronx_crypto::tx_id_from_body(&body);
+            tx.signatures = vec![kp.sign(&body)];
+            engine.apply(&tx, now).unwrap();
+            verify_genesis_supply_from_db(&db).unwrap_or_else(|e| panic!("after {}: {e}", tx.actions[0].name()));
+        };
+        let start = |target: &KeyPair| Action::StartRecovery {
+            target_account: target.account_id.clone(),
+            proposed_owner_key: KeyPair::generate().public_key.clone(),
+            evidence_hash: EvidenceHash([1u8; 32]),
+            bond_amount: MIN_RECOVERY_BOND_CHRONOS,
+            evidence_uri: None,
+        };
+        let challenge = |target: &KeyPair| Action::ChallengeRecovery {
+            target_account: target.account_id.clone(),
+            counter_evidence_hash: EvidenceHash([2u8; 32]),
+            bond_amount: MIN_CHALLENGE_BOND_CHRONOS,
+            counter_evidence_uri: None,
+        };
+
+        // Start → challenge → finalize: the failed challenge pays the initiator.
+        apply(initiator_a, 0, start(target_a), NOW);
+        apply(challenger, 0, challenge(target_a), NOW + 1);
+        let mut target = db.get_account(&target_a.account_id).unwrap().unwrap();
+        target.recovery_state.votes_approve =
+            (0..RECOVERY_VERIFIER_THRESHOLD as u8).map(|i| TxId::from_bytes([i; 32])).collect();
+        db.put_account(&target).unwrap();
+        let finalize = Action::FinalizeRecovery { target_account: target_a.account_id.clone() };
+        apply(initiator_a, 1, finalize, NOW + RECOVERY_EXECUTION_DELAY_SECS);
+        let balance = |kp: &KeyPair| db.get_account(&kp.account_id).unwrap().unwrap().balance;
+        assert_eq!(balance(initiator_a), fund + MIN_CHALLENGE_BOND_CHRONOS);
+        assert_eq!(balance(challenger), fund - MIN_CHALLENGE_BOND_CHRONOS);
+
+        // Start → challenge → expire: the challenger takes the initiator's bond.
+        apply(initiator_b, 0, start(target_b), NOW);
+        apply(challenger, 1, challenge(target_b), NOW + 1);
+        let expire = Action::ExpireRecovery { target_account: target_b.account_id.clone() };
+        apply(initiator_b, 1, expire, NOW + VERIFIER_VOTE_DEADLINE_SECS + 1);
+        assert_eq!(balance(initiator_b), fund - MIN_RECOVERY_BOND_CHRONOS);
+        assert_eq!(balance(challenger), fund + MIN_RECOVERY_BOND_CHRONOS - MIN_CHALLENGE_BOND_CHRONOS);
+        let escrow = db.get_account(&chronx_core::account::RECOVERY_ESCROW_ACCOUNT).unwrap().unwrap();
+        assert_eq!(escrow.balance, 0);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn audits_pass_on_fresh_genesis() {
+        let (db, dir) = temp_db("chronx_genesis_audit_ok");
+        apply_genesis(&db, &test_params()).expect("genesis must succeed");
+
+        let expected = db.expected_pending_lock_total().unwrap();
+        let locks = db.audit_lock_amounts(expected).unwrap();
+        assert!(locks.is_ok(), "{locks:?}");
+        assert_eq!(locks.pending_count, db.iter_all_timelocks().unwrap().len() as u64);
+        assert_eq!((locks.claimed_count, locks.cancelled_count), (0, 0));
+
+        let supply = audit_account_supply(&db).unwrap();
+        assert!(supply.is_ok(), "{supply:?}");
+        assert_eq!(supply.total, TOTAL_SUPPLY_CHRONOS);
+        assert_eq!(supply.pending_locks, locks.total_locked);
+        assert_eq!(supply.unminted, 175_000_000 * CHRONOS_PER_KX);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn audits_detect_corrupted_state() {
+        let (db, dir) = temp_db("chronx_genesis_audit_corrupt");
+        let params = test_params();
+        apply_genesis(&db, &params).expect("genesis must succeed");
+
+        let mut lock = db.get_timelock(&humanity_lock_id()).unwrap().unwrap();
+        lock.amount += 5;
+        db.put_timelock(&lock).unwrap();
+        let expected = db.expected_pending_lock_total().unwrap();
+        assert_eq!(db.audit_lock_amounts(expected).unwrap().discrepancy, 5);
+        assert_eq!(audit_account_supply(&db).unwrap().discrepancy, 5);
+
+        // A balance change leaves the locks alone but breaks the supply.
+        lock.amount -= 5;
+        db.put_timelock(&lock).unwrap();
+        let public_sale = build_accounts(&params).public_sale;
+        let mut acc = db.get_account(&public_sale).unwrap().unwrap();
+        acc.balance -= 3;
+        db.put_account(&acc).unwrap();
+        assert!(db.audit_lock_amounts(expected).unwrap().is_ok());
+        assert_eq!(audit_account_supply(&db).unwrap().discrepancy, -3);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
     #[test]
     fn treasury_lock_ids_are_unique() {
         let ids: Vec<_> = (1..=100).map(treasury_lock_id).collect();
@@ -65,6 +65,19 @@ pub struct GenesisParams {
     pub pay_as_max_usd: f64,
     #[serde(default = "default_pay_as_enabled")]
     pub pay_as_enabled: bool,
+    /// Additional genesis balances, funded out of the public sale allocation.
+    /// Lets testnets and private chains start with an arbitrary distribution.
+    #[serde(default)]
+    pub custom_allocations: Vec<GenesisAllocation>,
+}
+
+/// A single custom genesis balance carved out of the public sale allocation.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GenesisAllocation {
+    /// Dilithium2 public key that controls the allocated account.
+    pub recipient_key: DilithiumPublicKey,
+    /// Amount in whole KX.
+    pub amount_kx: u128,
 }
 
 /// Default placeholder key — real genesis must supply real keys.
@@ -0,0 +1,432 @@
+//! chronx-mempool
+//!
+//! Transactions the node has received but not yet applied. [`Mempool`]
+//! releases each sender's transactions strictly in nonce order: one with
+//! nonce N+2 waits until N+1 has arrived and been taken, even though the
+//! engine's nonce window would accept it out of order. Transactions whose
+//! `expires_at` passes while they wait are evicted.
+//!
+//! Across senders, ready transactions are released highest `fee_chronos`
+//! first. A full pool makes room for a transaction by evicting its
+//! lowest-fee entry, provided the newcomer pays more.
+//!
+//! The pool also remembers the last [`RECENT_DROPS`] transactions it turned
+//! away or lost, so clients can tell "still queued" from "dropped".
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, VecDeque};
+
+use chronx_core::transaction::Transaction;
+use chronx_core::types::{AccountId, Balance, Nonce, Timestamp, TxId};
+use thiserror::Error;
+
+/// Transactions held at once unless the node asks for another limit.
+pub const DEFAULT_CAPACITY: usize = 10_000;
+
+/// Dropped transactions remembered by [`Mempool::recently_dropped`].
+pub const RECENT_DROPS: usize = 100;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum MempoolError {
+    #[error("transaction is already queued")]
+    Duplicate,
+
+    #[error("mempool is full ({capacity} transactions) and the fee does not exceed its lowest, {min_fee}")]
+    Full { capacity: usize, min_fee: Balance },
+
+    #[error("transaction expired at {expires_at}")]
+    Expired { expires_at: Timestamp },
+
+    #[error("another queued transaction from this sender has nonce {nonce}")]
+    NonceTaken { nonce: Nonce },
+}
+
+/// A queued transaction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingTx {
+    pub tx: Transaction,
+    pub received_at: Timestamp,
+}
+
+/// A transaction that left the pool without being applied, or was never let in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DroppedTx {
+    pub tx_id: TxId,
+    pub sender: AccountId,
+    pub reason: String,
+    pub at: Timestamp,
+}
+
+#[derive(Debug)]
+pub struct Mempool {
+    capacity: usize,
+    entries: HashMap<TxId, PendingTx>,
+    /// Queued transaction ids per sender, by nonce.
+    by_sender: HashMap<AccountId, BTreeMap<Nonce, TxId>>,
+    /// Queued transaction ids, lowest fee first; among equal fees the most
+    /// recently received first, so it is evicted before older ones.
+    by_fee: BTreeSet<(Balance, Reverse<Timestamp>, TxId)>,
+    dropped: VecDeque<DroppedTx>,
+    dropped_total: u64,
+}
+
+impl Default for Mempool {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+impl Mempool {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            by_sender: HashMap::new(),
+            by_fee: BTreeSet::new(),
+            dropped: VecDeque::new(),
+            dropped_total: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Senders with at least one queued transaction.
+    pub fn sender_count(&self) -> usize {
+        self.by_sender.len()
+    }
+
+    /// Lowest fee among queued transactions; what a newcomer must beat to
+    /// get into a full pool.
+    pub fn min_fee(&self) -> Option<Balance> {
+        self.by_fee.first().map(|(fee, _, _)| *fee)
+    }
+
+    /// Transactions dropped since the pool was created.
+    pub fn dropped_total(&self) -> u64 {
+        self.dropped_total
+    }
+
+    pub fn get(&self, tx_id: &TxId) -> Option<&PendingTx> {
+        self.entries.get(tx_id)
+    }
+
+    /// Every queued transaction, oldest first.
+    pub fn pending(&self) -> Vec<&PendingTx> {
+        let mut pending: Vec<&PendingTx> = self.entries.values().collect();
+        pending.sort_by(|a, b| {
+            a.received_at
+                .cmp(&b.received_at)
+                .then_with(|| a.tx.from.cmp(&b.tx.from))
+                .then(a.tx.nonce.cmp(&b.tx.nonce))
+        });
+        pending
+    }
+
+    /// The last [`RECENT_DROPS`] dropped transactions, newest first.
+    pub fn recently_dropped(&self) -> impl Iterator<Item = &DroppedTx> {
+        self.dropped.iter().rev()
+    }
+
+    /// Queue `tx`. When the pool is full, its lowest-fee entry is evicted
+    /// to make room if `tx` pays more. Refusals other than
+    /// [`MempoolError::Duplicate`] are recorded as drops.
+    pub fn insert(&mut self, tx: Transaction, now: Timestamp) -> Result<(), MempoolError> {
+        if self.entries.contains_key(&tx.tx_id) {
+            return Err(MempoolError::Duplicate);
+        }
+        let full = self.entries.len() >= self.capacity;
+        let refused = match tx.expires_at {
+            Some(expires_at) if expires_at < now => Some(MempoolError::Expired { expires_at }),
+            _ if self.by_sender.get(&tx.from).is_some_and(|n| n.contains_key(&tx.nonce)) => {
+                Some(MempoolError::NonceTaken { nonce: tx.nonce })
+            }
+            _ if full && self.min_fee().is_none_or(|min_fee| tx.fee_chronos <= min_fee) => Some(MempoolError::Full {
+                capacity: self.capacity,
+                min_fee: self.min_fee().unwrap_or(0),
+            }),
+            _ => None,
+        };
+        if let Some(err) = refused {
+            self.record_drop(tx.tx_id.clone(), tx.from.clone(), err.to_string(), now);
+            return Err(err);
+        }
+        if full {
+            if let Some((_, _, lowest)) = self.by_fee.first().cloned() {
+                self.remove(&lowest, "evicted by a higher-fee transaction".into(), now);
+            }
+        }
+        self.by_sender.entry(tx.from.clone()).or_default().insert(tx.nonce, tx.tx_id.clone());
+        self.by_fee.insert((tx.fee_chronos, Reverse(now), tx.tx_id.clone()));
+        self.entries.insert(tx.tx_id.clone(), PendingTx { tx, received_at: now });
+        Ok(())
+    }
+
+    /// Remove and return every transaction that can be applied now, in
+    /// application order. `next_nonce` gives a sender's next unused nonce;
+    /// from there each sender's transactions are taken while their nonces
+    /// run on without a gap. Queued transactions below `next_nonce` can
+    /// never apply and are dropped, as are expired ones.
+    ///
+    /// The highest fee goes first, except that a sender's transaction never
+    /// goes before its lower nonces; equal fees go oldest first.
+    pub fn take_ready(&mut self, now: Timestamp, next_nonce: impl Fn(&AccountId) -> Nonce) -> Vec<Transaction> {
+        self.evict_expired(now);
+        let mut senders: Vec<AccountId> = self.by_sender.keys().cloned().collect();
+        senders.sort();
+        // Each sender's ready run, in nonce order.
+        let mut runs: Vec<VecDeque<PendingTx>> = Vec::new();
+        for sender in senders {
+            let mut expected = next_nonce(&sender);
+            let Some(nonces) = self.by_sender.get_mut(&sender) else { continue };
+            let stale: Vec<TxId> = nonces.range(..expected).map(|(_, id)| id.clone()).collect();
+            let mut taken = Vec::new();
+            while let Some(id) = nonces.remove(&expected) {
+                taken.push(id);
+                expected += 1;
+            }
+            nonces.retain(|n, _| *n >= expected);
+            if nonces.is_empty() {
+                self.by_sender.remove(&sender);
+            }
+            for id in stale {
+                if self.take_entry(&id).is_some() {
+                    self.record_drop(id, sender.clone(), "nonce already used".into(), now);
+                }
+            }
+            let run: VecDeque<PendingTx> = taken.iter().filter_map(|id| self.take_entry(id)).collect();
+            if !run.is_empty() {
+                runs.push(run);
+            }
+        }
+
+        // Merge the runs by the fee of each run's next transaction.
+        let head_key = |run: usize, p: &PendingTx| (p.tx.fee_chronos, Reverse(p.received_at), Reverse(run));
+        let mut heads: BinaryHeap<_> = runs
+            .iter()
+            .enumerate()
+            .filter_map(|(i, run)| run.front().map(|p| head_key(i, p)))
+            .collect();
+        let mut ready = Vec::with_capacity(runs.iter().map(VecDeque::len).sum());
+        while let Some((_, _, Reverse(i))) = heads.pop() {
+            let Some(p) = runs[i].pop_front() else { continue };
+            ready.push(p.tx);
+            if let Some(next) = runs[i].front() {
+                heads.push(head_key(i, next));
+            }
+        }
+        ready
+    }
+
+    /// Drop every transaction whose `expires_at` is before `now`, matching
+    /// the engine's expiry check.
+    pub fn evict_expired(&mut self, now: Timestamp) -> Vec<TxId> {
+        let expired: Vec<TxId> = self
+            .entries
+            .values()
+            .filter(|p| p.tx.expires_at.is_some_and(|t| t < now))
+            .map(|p| p.tx.tx_id.clone())
+            .collect();
+        for id in &expired {
+            self.remove(id, "expired".into(), now);
+        }
+        expired
+    }
+
+    /// Remember that `tx_id`, taken from the pool, failed to apply.
+    pub fn record_rejected(&mut self, tx: &Transaction, reason: String, now: Timestamp) {
+        self.record_drop(tx.tx_id.clone(), tx.from.clone(), reason, now);
+    }
+
+    /// Remove `tx_id` from the entries and the fee index only.
+    fn take_entry(&mut self, tx_id: &TxId) -> Option<PendingTx> {
+        let pending = self.entries.remove(tx_id)?;
+        self.by_fee
+            .remove(&(pending.tx.fee_chronos, Reverse(pending.received_at), tx_id.clone()));
+        Some(pending)
+    }
+
+    fn remove(&mut self, tx_id: &TxId, reason: String, now: Timestamp) {
+        let Some(pending) = self.take_entry(tx_id) else { return };
+        let sender = pending.tx.from;
+        if let Some(nonces) = self.by_sender.get_mut(&sender) {
+            nonces.remove(&pending.tx.nonce);
+            if nonces.is_empty() {
+                self.by_sender.remove(&sender);
+            }
+        }
+        self.record_drop(tx_id.clone(), sender, reason, now);
+    }
+
+    fn record_drop(&mut self, tx_id: TxId, sender: AccountId, reason: String, at: Timestamp) {
+        if self.dropped.len() == RECENT_DROPS {
+            self.dropped.pop_front();
+        }
+        self.dropped.push_back(DroppedTx { tx_id, sender, reason, at });
+        self.dropped_total += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chronx_core::transaction::AuthScheme;
+
+    const NOW: Timestamp = 1_800_000_000;
+
+    fn tx(sender: u8, nonce: Nonce, expires_at: Option<Timestamp>) -> Transaction {
+        let mut id = [sender; 32];
+        id[..8].copy_from_slice(&nonce.to_be_bytes());
+        Transaction {
+            tx_id: TxId::from_bytes(id),
+            parents: vec![],
+            timestamp: NOW,
+            nonce,
+            from: AccountId::from_bytes([sender; 32]),
+            actions: vec![],
+            pow_nonce: 0,
+            signatures: vec![],
+            auth_scheme: AuthScheme::SingleSig,
+            tx_version: 1,
+            client_ref: None,
+            fee_chronos: 0,
+            expires_at,
+            sender_public_key: None,
+        }
+    }
+
+    fn fee_tx(sender: u8, nonce: Nonce, fee_chronos: Balance) -> Transaction {
+        Transaction { fee_chronos, ..tx(sender, nonce, None) }
+    }
+
+    fn nonces(txs: &[Transaction]) -> Vec<(u8, Nonce)> {
+        txs.iter().map(|t| (t.from.0[0], t.nonce)).collect()
+    }
+
+    #[test]
+    fn nonce_gap_waits_for_the_missing_nonce() {
+        let mut pool = Mempool::default();
+        pool.insert(tx(1, 7, None), NOW).unwrap();
+        pool.insert(tx(1, 5, None), NOW).unwrap();
+        pool.insert(tx(2, 0, None), NOW).unwrap();
+        let next = |id: &AccountId| if id.0[0] == 1 { 5 } else { 0 };
+
+        // Nonce 7 is held back until 6 shows up.
+        assert_eq!(nonces(&pool.take_ready(NOW, next)), vec![(1, 5), (2, 0)]);
+        assert_eq!(pool.len(), 1);
+        let next = |_: &AccountId| 6;
+        assert!(pool.take_ready(NOW, next).is_empty());
+        pool.insert(tx(1, 6, None), NOW + 1).unwrap();
+        assert_eq!(nonces(&pool.take_ready(NOW + 1, next)), vec![(1, 6), (1, 7)]);
+        assert!(pool.is_empty());
+        assert_eq!(pool.sender_count(), 0);
+        assert_eq!(pool.dropped_total(), 0);
+    }
+
+    #[test]
+    fn stale_nonces_are_dropped() {
+        let mut pool = Mempool::default();
+        pool.insert(tx(1, 2, None), NOW).unwrap();
+        pool.insert(tx(1, 4, None), NOW).unwrap();
+        assert_eq!(nonces(&pool.take_ready(NOW, |_| 4)), vec![(1, 4)]);
+        assert!(pool.is_empty());
+        let dropped: Vec<_> = pool.recently_dropped().collect();
+        assert_eq!(dropped.len(), 1);
+        assert_eq!(dropped[0].tx_id, tx(1, 2, None).tx_id);
+        assert_eq!(dropped[0].reason, "nonce already used");
+    }
+
+    #[test]
+    fn expired_transactions_are_evicted() {
+        let mut pool = Mempool::default();
+        pool.insert(tx(1, 1, Some(NOW + 10)), NOW).unwrap();
+        pool.insert(tx(1, 2, None), NOW).unwrap();
+        pool.insert(tx(2, 0, Some(NOW + 60)), NOW).unwrap();
+        assert_eq!(
+            pool.insert(tx(3, 0, Some(NOW - 1)), NOW),
+            Err(MempoolError::Expired { expires_at: NOW - 1 })
+        );
+
+        assert!(pool.evict_expired(NOW + 10).is_empty());
+        assert_eq!(pool.evict_expired(NOW + 11), vec![tx(1, 1, None).tx_id]);
+        assert_eq!(pool.len(), 2);
+        // take_ready evicts on its own too, and sender 1 now has a gap at
+        // nonce 1, so nonce 2 waits.
+        assert!(pool.take_ready(NOW + 61, |id| if id.0[0] == 1 { 1 } else { 0 }).is_empty());
+        assert_eq!(pool.len(), 1);
+
+        let reasons: Vec<_> = pool.recently_dropped().map(|d| (d.sender.0[0], d.reason.clone())).collect();
+        assert_eq!(reasons[0], (2, "expired".to_string()));
+        assert_eq!(reasons[1], (1, "expired".to_string()));
+        assert!(reasons[2].1.starts_with("transaction expired"));
+        assert_eq!(pool.dropped_total(), 3);
+    }
+
+    #[test]
+    fn refuses_duplicates_conflicts_and_overflow() {
+        let mut pool = Mempool::new(2);
+        pool.insert(tx(1, 0, None), NOW).unwrap();
+        assert_eq!(pool.insert(tx(1, 0, None), NOW), Err(MempoolError::Duplicate));
+        let mut conflicting = tx(1, 0, None);
+        conflicting.tx_id = TxId::from_bytes([9; 32]);
+        assert_eq!(pool.insert(conflicting, NOW), Err(MempoolError::NonceTaken { nonce: 0 }));
+        pool.insert(tx(2, 0, None), NOW + 1).unwrap();
+        assert_eq!(pool.insert(tx(3, 0, None), NOW), Err(MempoolError::Full { capacity: 2, min_fee: 0 }));
+
+        let pending: Vec<_> = pool.pending().iter().map(|p| (p.tx.from.0[0], p.received_at)).collect();
+        assert_eq!(pending, vec![(1, NOW), (2, NOW + 1)]);
+        assert!(pool.get(&tx(2, 0, None).tx_id).is_some());
+        // Duplicates are not drops.
+        assert_eq!(pool.dropped_total(), 2);
+
+        for _ in 0..RECENT_DROPS {
+            pool.record_rejected(&tx(4, 0, None), "rejected".into(), NOW);
+        }
+        assert_eq!(pool.recently_dropped().count(), RECENT_DROPS);
+        assert_eq!(pool.dropped_total(), 2 + RECENT_DROPS as u64);
+    }
+
+    #[test]
+    fn ready_transactions_go_highest_fee_first() {
+        let mut pool = Mempool::default();
+        pool.insert(fee_tx(1, 0, 5), NOW).unwrap();
+        pool.insert(fee_tx(1, 1, 50), NOW).unwrap();
+        pool.insert(fee_tx(2, 0, 20), NOW).unwrap();
+        pool.insert(fee_tx(3, 0, 5), NOW - 1).unwrap();
+        assert_eq!(pool.min_fee(), Some(5));
+
+        // Sender 1's fee-50 transaction still waits for its nonce 0; equal
+        // fees go oldest first.
+        assert_eq!(nonces(&pool.take_ready(NOW, |_| 0)), vec![(2, 0), (3, 0), (1, 0), (1, 1)]);
+        assert_eq!(pool.min_fee(), None);
+    }
+
+    #[test]
+    fn full_pool_evicts_its_lowest_fee_for_a_higher_one() {
+        let mut pool = Mempool::new(2);
+        pool.insert(fee_tx(1, 0, 10), NOW).unwrap();
+        pool.insert(fee_tx(2, 0, 10), NOW + 1).unwrap();
+        assert_eq!(
+            pool.insert(fee_tx(3, 0, 10), NOW + 2),
+            Err(MempoolError::Full { capacity: 2, min_fee: 10 })
+        );
+
+        // Of the two fee-10 entries, the newer goes.
+        pool.insert(fee_tx(4, 0, 11), NOW + 3).unwrap();
+        assert_eq!(pool.len(), 2);
+        assert!(pool.get(&fee_tx(2, 0, 0).tx_id).is_none());
+        let dropped = pool.recently_dropped().next().unwrap();
+        assert_eq!((dropped.sender.0[0], dropped.reason.as_str()), (2, "evicted by a higher-fee transaction"));
+        assert_eq!(pool.min_fee(), Some(10));
+        assert_eq!(nonces(&pool.take_ready(NOW + 3, |_| 0)), vec![(4, 0), (1, 0)]);
+    }
+}
@@ -0,0 +1,318 @@
+//! chronx-monitoring
+//!
+//! Alert rules evaluated against periodic node metrics. The node's metrics
+//! task builds a [`MetricsSnapshot`] every tick and hands it to an
+//! [`AlertManager`], which notifies once when a rule starts firing and keeps
+//! the list of currently active alerts for `chronx_getActiveAlerts`.
+
+use std::time::Duration;
+
+use chronx_core::constants::ORACLE_MAX_AGE_SECS;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+/// Seconds without a DAG depth increase before `DagDepthStalled` fires.
+pub const DAG_STALL_SECS: i64 = 600;
+
+/// Fraction of the transaction queue in use before `MemPoolFull` fires.
+pub const MEMPOOL_FULL_RATIO: f64 = 0.9;
+
+/// Fewest peers a networked node should have before `PeerCountLow` fires.
+pub const MIN_PEERS: u64 = 2;
+
+/// Node state sampled by the metrics task.
+#[derive(Debug, Clone, Default)]
+pub struct MetricsSnapshot {
+    /// When the snapshot was taken (Unix seconds).
+    pub taken_at: i64,
+    /// Greatest depth among the DAG tips.
+    pub dag_depth: u64,
+    /// When `dag_depth` last increased; see [`DepthTracker`].
+    pub dag_depth_changed_at: i64,
+    /// Transactions waiting to be applied.
+    pub mempool_size: usize,
+    pub mempool_capacity: usize,
+    /// `updated_at` of the KX/USD oracle snapshot, if one exists.
+    pub oracle_updated_at: Option<i64>,
+    pub peer_count: u64,
+    /// Whether the node was started with bootstrap peers, i.e. is meant to
+    /// be part of a network rather than running alone.
+    pub expects_peers: bool,
+    /// Supply recomputed from the state database; `None` if that failed.
+    pub computed_supply_chronos: Option<u128>,
+    /// Supply the database should hold.
+    pub expected_supply_chronos: u128,
+}
+
+/// Remembers when the DAG depth last grew, across snapshots.
+#[derive(Debug, Clone, Copy)]
+pub struct DepthTracker {
+    depth: u64,
+    changed_at: i64,
+}
+
+impl DepthTracker {
+    pub fn new(depth: u64, now: i64) -> Self {
+        Self { depth, changed_at: now }
+    }
+
+    /// Record the current depth and return when it last increased.
+    pub fn observe(&mut self, depth: u64, now: i64) -> i64 {
+        if depth > self.depth {
+            self.depth = depth;
+            self.changed_at = now;
+        }
+        self.changed_at
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AlertSeverity {
+    Warning,
+    Critical,
+}
+
+/// A rule that is currently firing.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Alert {
+    pub name: String,
+    pub severity: AlertSeverity,
+    pub message: String,
+    /// Snapshot time at which the rule started firing.
+    pub since: i64,
+}
+
+pub struct AlertRule {
+    pub name: String,
+    pub condition: Box<dyn Fn(&MetricsSnapshot) -> bool + Send + Sync>,
+    pub severity: AlertSeverity,
+    pub message: String,
+}
+
+impl AlertRule {
+    pub fn new(
+        name: &str,
+        severity: AlertSeverity,
+        message: &str,
+        condition: impl Fn(&MetricsSnapshot) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            condition: Box::new(condition),
+            severity,
+            message: message.to_string(),
+        }
+    }
+}
+
+/// The rules every node runs.
+pub fn builtin_rules() -> Vec<AlertRule> {
+    vec![
+        AlertRule::new(
+            "DagDepthStalled",
+            AlertSeverity::Warning,
+            "DAG depth has not increased for 10 minutes",
+            |m| m.taken_at - m.dag_depth_changed_at >= DAG_STALL_SECS,
+        ),
+        AlertRule::new(
+            "MemPoolFull",
+            AlertSeverity::Warning,
+            "transaction queue is over 90% full",
+            |m| m.mempool_capacity > 0 && m.mempool_size as f64 > m.mempool_capacity as f64 * MEMPOOL_FULL_RATIO,
+        ),
+        AlertRule::new(
+            "OracleStale",
+            AlertSeverity::Warning,
+            "KX/USD oracle snapshot is older than ORACLE_MAX_AGE_SECS",
+            |m| m.oracle_updated_at.is_some_and(|t| m.taken_at - t > ORACLE_MAX_AGE_SECS),
+        ),
+        AlertRule::new(
+            "PeerCountLow",
+            AlertSeverity::Warning,
+            "fewer than 2 connected peers",
+            |m| m.expects_peers && m.peer_count < MIN_PEERS,
+        ),
+        AlertRule::new(
+            "SupplyMismatch",
+            AlertSeverity::Critical,
+            "recomputed supply does not match genesis",
+            |m| m.computed_supply_chronos.is_some_and(|s| s != m.expected_supply_chronos),
+        ),
+    ]
+}
+
+/// Evaluates rules against snapshots and tracks which are firing.
+pub struct AlertManager {
+    pub rules: Vec<AlertRule>,
+    pub notify: Box<dyn Fn(&Alert) + Send + Sync>,
+    active: Vec<Alert>,
+}
+
+impl AlertManager {
+    pub fn new(rules: Vec<AlertRule>, notify: Box<dyn Fn(&Alert) + Send + Sync>) -> Self {
+        Self { rules, notify, active: Vec::new() }
+    }
+
+    /// Evaluate every rule. A rule that starts firing is notified once; one
+    /// that stops firing is dropped from the active list. Returns the alerts
+    /// active after this snapshot, in rule order.
+    pub fn evaluate(&mut self, snapshot: &MetricsSnapshot) -> &[Alert] {
+        let mut active = Vec::new();
+        for rule in &self.rules {
+            if !(rule.condition)(snapshot) {
+                continue;
+            }
+            match self.active.iter().find(|a| a.name == rule.name) {
+                Some(existing) => active.push(existing.clone()),
+                None => {
+                    let alert = Alert {
+                        name: rule.name.clone(),
+                        severity: rule.severity,
+                        message: rule.message.clone(),
+                        since: snapshot.taken_at,
+                    };
+                    (self.notify)(&alert);
+                    active.push(alert);
+                }
+            }
+        }
+        self.active = active;
+        &self.active
+    }
+
+    pub fn active(&self) -> &[Alert] {
+        &self.active
+    }
+}
+
+/// A notifier that logs each alert and, if `webhook` is set, POSTs it there
+/// as JSON. Must be called from within a Tokio runtime.
+pub fn alert_notifier(webhook: Option<String>) -> Box<dyn Fn(&Alert) + Send + Sync> {
+    let client = reqwest::Client::new();
+    Box::new(move |alert: &Alert| {
+        warn!(name = %alert.name, severity = ?alert.severity, message = %alert.message, "alert firing");
+        let Some(url) = webhook.clone() else {
+            return;
+        };
+        let request = client
+            .post(url)
+            .timeout(Duration::from_secs(10))
+            .json(alert);
+        tokio::spawn(async move {
+            if let Err(e) = request.send().await {
+                warn!(error = %e, "alert webhook delivery failed");
+            }
+        });
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    fn healthy() -> MetricsSnapshot {
+        MetricsSnapshot {
+            taken_at: 10_000,
+            dag_depth: 50,
+            dag_depth_changed_at: 9_990,
+            mempool_size: 3,
+            mempool_capacity: 512,
+            oracle_updated_at: Some(9_000),
+            peer_count: 5,
+            expects_peers: true,
+            computed_supply_chronos: Some(1_000),
+            expected_supply_chronos: 1_000,
+        }
+    }
+
+    fn firing(snapshot: &MetricsSnapshot) -> Vec<String> {
+        builtin_rules()
+            .into_iter()
+            .filter(|r| (r.condition)(snapshot))
+            .map(|r| r.name)
+            .collect()
+    }
+
+    #[test]
+    fn healthy_node_fires_nothing() {
+        assert!(firing(&healthy()).is_empty());
+    }
+
+    #[test]
+    fn dag_depth_stalled() {
+        let mut m = healthy();
+        m.dag_depth_changed_at = m.taken_at - DAG_STALL_SECS + 1;
+        assert!(firing(&m).is_empty());
+        m.dag_depth_changed_at = m.taken_at - DAG_STALL_SECS;
+        assert_eq!(firing(&m), vec!["DagDepthStalled"]);
+
+        let mut tracker = DepthTracker::new(5, 0);
+        assert_eq!(tracker.observe(5, 100), 0);
+        assert_eq!(tracker.observe(6, 200), 200);
+        assert_eq!(tracker.observe(6, 300), 200);
+    }
+
+    #[test]
+    fn mempool_full() {
+        let mut m = healthy();
+        m.mempool_size = 460;
+        assert!(firing(&m).is_empty());
+        m.mempool_size = 461;
+        assert_eq!(firing(&m), vec!["MemPoolFull"]);
+    }
+
+    #[test]
+    fn oracle_stale() {
+        let mut m = healthy();
+        m.oracle_updated_at = Some(m.taken_at - ORACLE_MAX_AGE_SECS);
+        assert!(firing(&m).is_empty());
+        m.oracle_updated_at = Some(m.taken_at - ORACLE_MAX_AGE_SECS - 1);
+        assert_eq!(firing(&m), vec!["OracleStale"]);
+        m.oracle_updated_at = None;
+        assert!(firing(&m).is_empty());
+    }
+
+    #[test]
+    fn peer_count_low_only_on_networked_nodes() {
+        let mut m = healthy();
+        m.peer_count = 1;
+        assert_eq!(firing(&m), vec!["PeerCountLow"]);
+        m.expects_peers = false;
+        assert!(firing(&m).is_empty());
+    }
+
+    #[test]
+    fn supply_mismatch() {
+        let mut m = healthy();
+        m.computed_supply_chronos = Some(999);
+        assert_eq!(firing(&m), vec!["SupplyMismatch"]);
+        m.computed_supply_chronos = None;
+        assert!(firing(&m).is_empty());
+    }
+
+    #[test]
+    fn manager_notifies_once_per_episode() {
+        let notified = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&notified);
+        let mut manager = AlertManager::new(
+            builtin_rules(),
+            Box::new(move |a: &Alert| sink.lock().unwrap().push(a.name.clone())),
+        );
+
+        let mut m = healthy();
+        m.peer_count = 0;
+        assert_eq!(manager.evaluate(&m).len(), 1);
+        m.taken_at += 60;
+        let active = manager.evaluate(&m);
+        assert_eq!(active[0].since, 10_000);
+        assert_eq!(notified.lock().unwrap().len(), 1);
+
+        m.peer_count = 5;
+        assert!(manager.evaluate(&m).is_empty());
+        m.peer_count = 0;
+        manager.evaluate(&m);
+        assert_eq!(*notified.lock().unwrap(), vec!["PeerCountLow", "PeerCountLow"]);
+        assert_eq!(manager.active()[0].severity, AlertSeverity::Warning);
+    }
+}
@@ -0,0 +1,67 @@
+//! Optional node config file, passed with `--config <path>`.
+//!
+//! ```toml
+//! [p2p]
+//! bootstrap = ["/ip4/203.0.113.7/tcp/7777/p2p/12D3KooW..."]
+//!
+//! [rpc]
+//! rate_limit = 100          # chronx_sendTransaction calls per minute, 0 = unlimited
+//!
+//! [consensus]
+//! pow_difficulty = 20
+//! ```
+//!
+//! Send the node `SIGHUP` and it re-reads the file, dials any new bootstrap
+//! peers and applies the RPC rate limit. Bootstrap peers are only ever
+//! added — removing one from the file does not disconnect it.
+//! `pow_difficulty` is the base the DAG retargets from, so it is read at
+//! startup only.
+//!
+//! `--data-dir`, `--genesis-params`, `--p2p-listen`, `--rpc-addr` and
+//! `--identity-file` are command-line only and require a restart.
+
+use std::path::Path;
+
+use anyhow::Context;
+use serde::Deserialize;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NodeConfig {
+    #[serde(default)]
+    pub p2p: P2pSection,
+    #[serde(default)]
+    pub rpc: RpcSection,
+    #[serde(default)]
+    pub consensus: ConsensusSection,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct P2pSection {
+    /// Extra bootstrap multiaddrs, merged with `--bootstrap`.
+    #[serde(default)]
+    pub bootstrap: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RpcSection {
+    /// Max `chronx_sendTransaction` calls per minute; 0 disables the limit.
+    pub rate_limit: Option<u64>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConsensusSection {
+    /// Base PoW difficulty (leading zero bits); overrides `--pow-difficulty`.
+    pub pow_difficulty: Option<u8>,
+}
+
+impl NodeConfig {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let raw = std::fs::read_to_string(path)
+            .with_context(|| format!("reading node config {}", path.display()))?;
+        toml::from_str(&raw).with_context(|| format!("parsing node config {}", path.display()))
+    }
+}
@@ -7,25 +7,35 @@
 //!   4. Start the JSON-RPC 2.0 server
 //!   5. Run the main loop: validate inbound txs → apply → broadcast
 
+use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use anyhow::Context;
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use tracing::{info, warn};
 
 /// Current node software version. Compared against https://chronx.io/version.json at startup.
 const NODE_VERSION: &str = "9.5.0";
 
-use chronx_consensus::DifficultyConfig;
-use chronx_core::constants::POW_INITIAL_DIFFICULTY;
+/// Periodic snapshots kept in `<data-dir>/snapshots/`; older ones are deleted.
+const SNAPSHOT_KEEP: usize = 3;
+
+use chronx_core::constants::{ORACLE_HISTORY_MAX_AGE_SECS, POW_INITIAL_DIFFICULTY};
+use chronx_core::error::ChronxError;
+use chronx_core::types::TxId;
 use chronx_crypto::KeyPair;
 use chronx_genesis::{apply_genesis, GenesisParams};
+use chronx_mempool::{Mempool, MempoolError, RECENT_DROPS};
+use chronx_monitoring::{alert_notifier, builtin_rules, AlertManager, DepthTracker, MetricsSnapshot};
 use chronx_p2p::{P2pConfig, P2pMessage, P2pNetwork};
-use chronx_rpc::server::RpcServerState;
-use chronx_rpc::RpcServer;
-use chronx_state::{StateDb, StateEngine};
+use chronx_rpc::server::{RpcRateLimiter, RpcServerState};
+use chronx_rpc::{RpcServer, SubmittedTx, TxOutcome};
+use chronx_state::{MetricsHandle, StateDb, StateEngine};
+
+mod config;
+use config::NodeConfig;
 
 #[derive(Parser, Debug)]
 #[command(
@@ -54,7 +64,9 @@ struct Args {
     #[arg(long)]
     genesis_params: Option<PathBuf>,
 
-    /// PoW difficulty override.
+    /// Base PoW difficulty the DAG retargets from; 0 disables PoW.
+    /// `[consensus] pow_difficulty` in the config file takes precedence, and
+    /// a value approved by on-chain governance over both.
     #[arg(long, default_value_t = POW_INITIAL_DIFFICULTY)]
     pow_difficulty: u8,
 
@@ -63,6 +75,161 @@ struct Args {
     /// If omitted, a random identity is used each run.
     #[arg(long)]
     identity_file: Option<PathBuf>,
+
+    /// Maximum peers we dial before evicting the lowest-reputation one.
+    #[arg(long, default_value_t = 8)]
+    max_outbound_peers: usize,
+
+    /// Maximum peers that dial us before evicting the lowest-reputation one.
+    #[arg(long, default_value_t = 32)]
+    max_inbound_peers: usize,
+
+    /// Accept gossip without an author signature. For test networks only;
+    /// this node signs its own gossip either way.
+    #[arg(long)]
+    allow_unsigned_gossip: bool,
+
+    /// Path to a TOML node config file. Re-read on SIGHUP; see `config.rs`
+    /// for which settings are hot-reloadable.
+    #[arg(long)]
+    config: Option<PathBuf>,
+
+    /// Directory for periodic state checkpoints. Checkpointing is off if unset.
+    #[arg(long)]
+    checkpoint_dir: Option<PathBuf>,
+
+    /// Seconds between checkpoints.
+    #[arg(long, default_value_t = 3600)]
+    checkpoint_interval_secs: u64,
+
+    /// Number of checkpoints to retain; older ones are deleted.
+    #[arg(long, default_value_t = 5)]
+    checkpoint_keep: usize,
+
+    /// Snapshot the state database into `<data-dir>/snapshots/` every this
+    /// many applied vertices, keeping the newest three. 0 disables.
+    #[arg(long, default_value_t = 100_000)]
+    snapshot_interval: u64,
+
+    /// Most transactions held in the mempool. A full pool evicts its
+    /// lowest-fee transaction for one that pays more.
+    #[arg(long, default_value_t = chronx_mempool::DEFAULT_CAPACITY)]
+    mempool_capacity: usize,
+
+    /// Seconds `chronx_sendTransaction` and `chronx_submitTransaction` wait
+    /// for a transaction to be applied before answering with it pending.
+    #[arg(long, default_value_t = chronx_rpc::DEFAULT_SUBMIT_TIMEOUT.as_secs())]
+    rpc_submit_timeout_secs: u64,
+
+    /// Start even if the recomputed supply does not match genesis.
+    /// Emergency use only: a mismatch means the state database is suspect.
+    #[arg(long)]
+    skip_supply_check: bool,
+
+    /// Serve the GraphQL explorer on this port, on the RPC listen address.
+    /// Off if unset.
+    #[arg(long)]
+    explorer_port: Option<u16>,
+
+    /// Serve Prometheus metrics at `/metrics` on this port, on the RPC
+    /// listen address. Off if unset.
+    #[arg(long)]
+    metrics_port: Option<u16>,
+
+    /// POST each newly firing alert to this URL as JSON. Alerts are always
+    /// logged and listed by `chronx_getActiveAlerts`.
+    #[arg(long)]
+    alert_webhook: Option<String>,
+
+    /// Disable `chronx_getChainTimeline`, which scans every vertex. For
+    /// low-resource nodes.
+    #[arg(long)]
+    no_timeline: bool,
+
+    /// Serve `chronx_getAllAccounts`, which pages through every account.
+    /// Off by default; enable on indexer or explorer nodes.
+    #[arg(long)]
+    rpc_enable_full_scan: bool,
+
+    /// Serve `chronx_getUnclaimedLockReport`, which lists long-matured
+    /// unclaimed locks. Off by default.
+    #[arg(long)]
+    rpc_enable_sweep_reports: bool,
+
+    /// Serve `chronx_getNetworkTopology`, which lists connected peers and
+    /// their addresses. Off by default.
+    #[arg(long)]
+    rpc_enable_topology: bool,
+
+    /// Compact the state database at startup when the last compaction is
+    /// older than this many hours (e.g. 24), and log its size on disk at the
+    /// same interval while running. Off if unset; see `chronx-node compact`.
+    #[arg(long)]
+    auto_compact_interval_hours: Option<u64>,
+
+    #[command(subcommand)]
+    command: Option<NodeCommand>,
+}
+
+#[derive(Subcommand, Debug)]
+enum NodeCommand {
+    /// Replace the state database with a checkpoint, then exit.
+    /// The node must not be running.
+    RestoreCheckpoint {
+        /// A `checkpoint_<ts>` directory, or a checkpoint directory to take
+        /// the newest checkpoint from.
+        #[arg(long)]
+        checkpoint_dir: PathBuf,
+
+        /// State database directory to restore into.
+        #[arg(long, default_value = "~/.chronx/data")]
+        data_dir: PathBuf,
+    },
+
+    /// Snapshot the state database into `<out>/snapshots/<vertex count>/`,
+    /// then exit. The node must not be running.
+    Snapshot {
+        /// State database directory to snapshot.
+        #[arg(long, default_value = "~/.chronx/data")]
+        data_dir: PathBuf,
+
+        /// Directory to write the snapshot under.
+        #[arg(long)]
+        out: PathBuf,
+    },
+
+    /// Replace the state database with a snapshot after checking it against
+    /// its metadata, then exit. The node must not be running.
+    Restore {
+        /// A snapshot directory, or a directory whose `snapshots/` holds the
+        /// snapshot to take (the newest is used).
+        #[arg(long)]
+        from: PathBuf,
+
+        /// State database directory to restore into.
+        #[arg(long, default_value = "~/.chronx/data")]
+        data_dir: PathBuf,
+    },
+
+    /// Print the entry count and size of every state database tree, then
+    /// exit. sled has no read-only mode, so the node must not be running.
+    Diagnose {
+        /// State database directory to inspect.
+        #[arg(long, default_value = "~/.chronx/data")]
+        data_dir: PathBuf,
+    },
+
+    /// Rebuild the state database from its live entries to reclaim space
+    /// held by deleted ones, then exit. The node must not be running.
+    Compact {
+        /// State database directory to compact.
+        #[arg(long, default_value = "~/.chronx/data")]
+        data_dir: PathBuf,
+
+        /// List the entries and size of every tree.
+        #[arg(long)]
+        verbose: bool,
+    },
 }
 
 #[tokio::main]
@@ -74,9 +241,45 @@ async fn main() -> anyhow::Result<()> {
         )
         .init();
 
+    let node_start_time = std::time::Instant::now();
     let args = Args::parse();
+
+    if let Some(NodeCommand::RestoreCheckpoint { checkpoint_dir, data_dir }) = &args.command {
+        let data_dir = expand_tilde(data_dir);
+        let restored = chronx_state::checkpoint::restore_checkpoint(&expand_tilde(checkpoint_dir), &data_dir)
+            .context("restoring checkpoint")?;
+        info!(from = %restored.display(), to = %data_dir.display(), "checkpoint restored");
+        return Ok(());
+    }
+    if let Some(NodeCommand::Snapshot { data_dir, out }) = &args.command {
+        return snapshot(&expand_tilde(data_dir), &expand_tilde(out));
+    }
+    if let Some(NodeCommand::Restore { from, data_dir }) = &args.command {
+        let data_dir = expand_tilde(data_dir);
+        let from = chronx_state::snapshot::resolve_snapshot(&expand_tilde(from)).context("finding snapshot")?;
+        let db = StateDb::restore_from_snapshot(&from, &data_dir).context("restoring snapshot")?;
+        info!(
+            from = %from.display(),
+            to = %data_dir.display(),
+            vertices = db.count_vertices(),
+            "snapshot restored"
+        );
+        return Ok(());
+    }
+    if let Some(NodeCommand::Diagnose { data_dir }) = &args.command {
+        return diagnose(&expand_tilde(data_dir));
+    }
+    if let Some(NodeCommand::Compact { data_dir, verbose }) = &args.command {
+        return compact(&expand_tilde(data_dir), *verbose);
+    }
     info!(version = NODE_VERSION, "ChronX node starting");
 
+    let config_path = args.config.as_deref().map(expand_tilde);
+    let node_config = match &config_path {
+        Some(p) => NodeConfig::load(p)?,
+        None => NodeConfig::default(),
+    };
+
     // ── Version check against chronx.io/version.json ─────────────────────────
     check_node_version().await;
 
@@ -85,6 +288,10 @@ async fn main() -> anyhow::Result<()> {
     std::fs::create_dir_all(&data_dir)
         .with_context(|| format!("creating data dir {}", data_dir.display()))?;
 
+    if let Some(hours) = args.auto_compact_interval_hours {
+        compact_if_due(&data_dir, hours)?;
+    }
+
     let db = Arc::new(StateDb::open(&data_dir).context("opening state database")?);
 
     // ── Genesis if fresh ──────────────────────────────────────────────────────
@@ -96,6 +303,46 @@ async fn main() -> anyhow::Result<()> {
         info!("existing database found — skipping genesis");
     }
 
+    // ── Supply check ──────────────────────────────────────────────────────────
+    if args.skip_supply_check {
+        warn!("--skip-supply-check set — genesis supply not verified");
+    } else if let Err(e) = chronx_genesis::verify_genesis_supply_from_db(&db) {
+        tracing::error!(error = %e, "CRITICAL: state supply does not match genesis — refusing to start");
+        anyhow::bail!("supply check failed: {e} (override with --skip-supply-check)");
+    }
+
+    // ── Lock audit ────────────────────────────────────────────────────────────
+    let lock_audit = db
+        .expected_pending_lock_total()
+        .and_then(|expected| db.audit_lock_amounts(expected))
+        .context("auditing lock amounts")?;
+    if lock_audit.is_ok() {
+        info!(
+            total_locked = lock_audit.total_locked,
+            pending = lock_audit.pending_count,
+            "pending lock amounts match account books"
+        );
+    } else {
+        warn!(
+            total_locked = lock_audit.total_locked,
+            discrepancy = lock_audit.discrepancy,
+            "pending lock amounts do not match account books"
+        );
+    }
+
+    // ── Consistency check ─────────────────────────────────────────────────────
+    db.ping().context("state database health check")?;
+    let report = db.validate_consistency().context("validating state database")?;
+    for w in &report.warnings {
+        warn!(warning = %w, "state consistency");
+    }
+    for e in &report.errors {
+        warn!(error = %e, "state consistency");
+    }
+    if report.is_ok() {
+        info!(warnings = report.warnings.len(), "state database consistent");
+    }
+
     // ── Store MISAI X25519 public key if provided and not yet stored ─────────
     if let Ok(pubkey_hex) = std::env::var("MISAI_X25519_PUBKEY") {
         if !pubkey_hex.is_empty() {
@@ -164,7 +411,13 @@ async fn main() -> anyhow::Result<()> {
 
     // ── State engine ──────────────────────────────────────────────────────────
     // Share the same DB handle — sled uses an Arc internally so this is safe.
-    let engine = Arc::new(StateEngine::new(Arc::clone(&db), args.pow_difficulty));
+    let metrics = Arc::new(MetricsHandle::new(prometheus::default_registry()).context("registering metrics")?);
+    // A governance-approved base difficulty applies from the next restart.
+    let base_difficulty = db
+        .get_governed_pow_difficulty()
+        .or(node_config.consensus.pow_difficulty)
+        .unwrap_or(args.pow_difficulty);
+    let engine = Arc::new(StateEngine::new_with_metrics(Arc::clone(&db), base_difficulty, Arc::clone(&metrics)));
 
     // ── Migrate account savings fields (bincode re-serialize) ────────────
     match engine.migrate_account_savings_fields() {
@@ -189,16 +442,14 @@ async fn main() -> anyhow::Result<()> {
 
     // ── Compute balance Merkle state root if missing ───────────────────────
     if db.get_latest_state_root().ok().flatten().is_none() {
-        match db.get_all_accounts() {
-            Ok(accounts) => {
-                let tree = chronx_core::merkle::BalanceMerkleTree::from_accounts(&accounts);
-                let root = tree.root();
+        match db.compute_state_root() {
+            Ok(root) => {
                 if let Err(e) = db.put_latest_state_root(&root) {
                     warn!(error = %e, "failed to persist startup state root");
                 } else {
                     info!(
                         state_root = %hex::encode(root),
-                        accounts = accounts.len(),
+                        accounts = db.count_accounts(),
                         "state root computed on startup"
                     );
                 }
@@ -214,15 +465,28 @@ async fn main() -> anyhow::Result<()> {
 
     // ── Inbound transaction queue ─────────────────────────────────────────────
     let (tx_sender, mut tx_receiver) =
-        tokio::sync::mpsc::channel::<chronx_core::transaction::Transaction>(512);
+        tokio::sync::mpsc::channel::<SubmittedTx>(512);
+    // Received transactions wait here until every earlier nonce from their
+    // sender has been applied.
+    let mempool = Arc::new(Mutex::new(Mempool::new(args.mempool_capacity)));
 
     // ── P2P network ───────────────────────────────────────────────────────────
+    let mut bootstrap_peers = args.bootstrap.clone();
+    for peer in &node_config.p2p.bootstrap {
+        if !bootstrap_peers.contains(peer) {
+            bootstrap_peers.push(peer.clone());
+        }
+    }
     let p2p_config = P2pConfig {
         listen_addr: args.p2p_listen.clone(),
-        bootstrap_peers: args.bootstrap.clone(),
+        bootstrap_peers,
         protocol_version: "/chronx/1.0.0".into(),
         vertex_topic: "chronx-vertices".into(),
         identity_file: args.identity_file.clone(),
+        max_outbound_peers: args.max_outbound_peers,
+        max_inbound_peers: args.max_inbound_peers,
+        gossip_require_signed: !args.allow_unsigned_gossip,
+        ..P2pConfig::default()
     };
     let (p2p_network, mut p2p_handle) =
         P2pNetwork::new(&p2p_config).map_err(|e| anyhow::anyhow!("building P2P network: {e}"))?;
@@ -235,37 +499,140 @@ async fn main() -> anyhow::Result<()> {
     );
 
     let outbound_tx = p2p_handle.outbound_tx.clone();
+    let bootstrap_tx = p2p_handle.bootstrap_tx.clone();
 
-    // Pipe gossip-received messages into the tx queue.
     let tx_sender_for_p2p = tx_sender.clone();
+    tokio::spawn(async move { p2p_network.run().await as () });
+
+    // ── RPC server ────────────────────────────────────────────────────────────
+    let rpc_state = Arc::new(RpcServerState {
+        db: Arc::clone(&db),
+        pow_difficulty: base_difficulty,
+        tx_sender: Some(tx_sender),
+        submit_timeout: std::time::Duration::from_secs(args.rpc_submit_timeout_secs),
+        mempool: Some(Arc::clone(&mempool)),
+        peer_multiaddr: Some(peer_multiaddr),
+        peer_count: p2p_handle.peer_count.clone(),
+        rate_limiter: Arc::new(RpcRateLimiter::new(node_config.rpc.rate_limit.unwrap_or(0))),
+        p2p_stats: Some(Arc::clone(&p2p_handle.stats)),
+        started_at: node_start_time,
+        lock_status_subscriptions: Default::default(),
+        events: tokio::sync::broadcast::channel(chronx_rpc::EVENT_CHANNEL_CAPACITY).0,
+        checkpoint_dir: args.checkpoint_dir.as_deref().map(expand_tilde),
+        active_alerts: Default::default(),
+        timeline_enabled: !args.no_timeline,
+        full_scan_enabled: args.rpc_enable_full_scan,
+        sweep_reports_enabled: args.rpc_enable_sweep_reports,
+        topology_enabled: args.rpc_enable_topology,
+        p2p_listen_addr: Some(p2p_config.listen_addr.clone()),
+        rpc_addr: Some(args.rpc_addr.to_string()),
+        admin_token: std::env::var("CHRONX_RPC_ADMIN_TOKEN").ok().filter(|t| !t.is_empty()),
+    });
+
+    // Pipe gossip-received vertices into the tx queue; forward peers' lock
+    // status notices to local subscribers for locks this node knows about.
+    let rpc_state_for_p2p = Arc::clone(&rpc_state);
     tokio::spawn(async move {
         while let Some(msg) = p2p_handle.inbound_rx.recv().await {
-            if let P2pMessage::NewVertex { payload } = msg {
-                match bincode::deserialize(&payload) {
+            match msg {
+                P2pMessage::NewVertex { payload } => match bincode::deserialize::<chronx_core::transaction::Transaction>(&payload) {
                     Ok(tx) => {
-                        let _ = tx_sender_for_p2p.send(tx).await;
+                        let _ = tx_sender_for_p2p.send(tx.into()).await;
                     }
                     Err(e) => warn!(error = %e, "failed to decode inbound vertex"),
+                },
+                P2pMessage::LockStatusChange { lock_id, new_status, at } => {
+                    let known = matches!(rpc_state_for_p2p.db.get_timelock(&lock_id), Ok(Some(_)));
+                    if known {
+                        // Only terminal changes are gossiped.
+                        rpc_state_for_p2p
+                            .notify_lock_status_event(&lock_id, &new_status, true, at)
+                            .await;
+                    }
                 }
+                _ => {}
             }
         }
     });
 
-    tokio::spawn(async move { p2p_network.run().await as () });
+    // ── SIGHUP: hot-reload the node config file ──────────────────────────────
+    #[cfg(unix)]
+    if let Some(path) = config_path.clone() {
+        let rate_limiter = Arc::clone(&rpc_state.rate_limiter);
+        let mut p2p_config = p2p_config.clone();
+        tokio::spawn(async move {
+            use tokio::signal::unix::{signal, SignalKind};
+            let mut hangup = match signal(SignalKind::hangup()) {
+                Ok(s) => s,
+                Err(e) => {
+                    warn!(error = %e, "failed to install SIGHUP handler — config reload disabled");
+                    return;
+                }
+            };
+            while hangup.recv().await.is_some() {
+                info!(path = %path.display(), "SIGHUP received — reloading node config");
+                let cfg = match NodeConfig::load(&path) {
+                    Ok(cfg) => cfg,
+                    Err(e) => {
+                        warn!(error = %e, "config reload failed — keeping previous settings");
+                        continue;
+                    }
+                };
+
+                let new_peers: Vec<String> = cfg
+                    .p2p
+                    .bootstrap
+                    .into_iter()
+                    .filter(|p| !p2p_config.bootstrap_peers.contains(p))
+                    .collect();
+                if !new_peers.is_empty() {
+                    p2p_config.bootstrap_peers.extend(new_peers.iter().cloned());
+                    if bootstrap_tx.send(new_peers).await.is_err() {
+                        warn!("P2P network stopped — cannot dial new bootstrap peers");
+                    }
+                }
 
-    // ── RPC server ────────────────────────────────────────────────────────────
-    let rpc_state = Arc::new(RpcServerState {
-        db: Arc::clone(&db),
-        pow_difficulty: args.pow_difficulty,
-        tx_sender: Some(tx_sender),
-        peer_multiaddr: Some(peer_multiaddr),
-        peer_count: p2p_handle.peer_count.clone(),
-    });
-    let _rpc_handle = RpcServer::new(rpc_state)
+                if let Some(limit) = cfg.rpc.rate_limit {
+                    rate_limiter.set_limit(limit);
+                }
+                if cfg.consensus.pow_difficulty.is_some_and(|d| d != base_difficulty) {
+                    warn!("consensus.pow_difficulty is the DAG's base difficulty — restart to change it");
+                }
+                info!(
+                    bootstrap_peers = p2p_config.bootstrap_peers.len(),
+                    rate_limit = rate_limiter.limit(),
+                    "node config reloaded"
+                );
+            }
+        });
+    }
+    let _rpc_handle = RpcServer::new(Arc::clone(&rpc_state))
         .start(args.rpc_addr)
         .await
         .context("starting RPC server")?;
 
+    // ── GraphQL explorer ──────────────────────────────────────────────────────
+    let (explorer_events, _) = tokio::sync::broadcast::channel(chronx_explorer::EVENT_CHANNEL_CAPACITY);
+    if let Some(port) = args.explorer_port {
+        let schema = chronx_explorer::build_schema(Arc::clone(&db), explorer_events.clone());
+        let addr = SocketAddr::new(args.rpc_addr.ip(), port);
+        tokio::spawn(async move {
+            if let Err(e) = chronx_explorer::serve(schema, addr).await {
+                warn!(error = %e, "GraphQL explorer stopped");
+            }
+        });
+    }
+
+    // ── Prometheus metrics ────────────────────────────────────────────────────
+    if let Some(port) = args.metrics_port {
+        let addr = SocketAddr::new(args.rpc_addr.ip(), port);
+        tokio::spawn(async move {
+            if let Err(e) = serve_metrics(addr).await {
+                warn!(error = %e, "metrics endpoint stopped");
+            }
+        });
+    }
+
 
     // ── Read sweep intervals from genesis-params.json ─────────────────────────
     let sweep_intervals = {
@@ -317,7 +684,56 @@ async fn main() -> anyhow::Result<()> {
         info!("child chain governance params loaded into meta store");
     }
 
-    // ── Background sweep: revert expired email locks every 5 minutes ──────────
+    // ── Background checkpoints ────────────────────────────────────────────────
+    if let Some(checkpoint_dir) = args.checkpoint_dir.as_deref().map(expand_tilde) {
+        let checkpoint_db = Arc::clone(&db);
+        let keep = args.checkpoint_keep.max(1);
+        let every = args.checkpoint_interval_secs.max(1);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(every));
+            interval.tick().await; // skip the immediate first tick
+            loop {
+                interval.tick().await;
+                let db = Arc::clone(&checkpoint_db);
+                let dir = checkpoint_dir.clone();
+                let result = tokio::task::spawn_blocking(move || {
+                    let dest = chronx_state::checkpoint::checkpoint_path(&dir, chrono::Utc::now().timestamp());
+                    db.create_checkpoint(&dest)?;
+                    let pruned = chronx_state::checkpoint::prune_checkpoints(&dir, keep)?;
+                    Ok::<_, chronx_core::error::ChronxError>((dest, pruned))
+                })
+                .await;
+                match result {
+                    Ok(Ok((dest, pruned))) => info!(path = %dest.display(), pruned, "state checkpoint written"),
+                    Ok(Err(e)) => warn!(error = %e, "state checkpoint failed"),
+                    Err(e) => warn!(error = %e, "state checkpoint task panicked"),
+                }
+            }
+        });
+        info!(every_secs = every, keep, "background checkpoint task started");
+    }
+
+    // ── Background size report for auto-compaction ────────────────────────────
+    if let Some(hours) = args.auto_compact_interval_hours {
+        let compact_db = Arc::clone(&db);
+        let every = hours.max(1) * 3600;
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(every));
+            interval.tick().await; // skip the immediate first tick
+            loop {
+                interval.tick().await;
+                // sled cannot be rebuilt while open, so compaction itself waits
+                // for the next start; flushing lets sled reclaim what it can.
+                match compact_db.flush().and_then(|()| compact_db.size_on_disk()) {
+                    Ok(bytes) => info!(size_on_disk = bytes, "state database compaction due at next start"),
+                    Err(e) => warn!(error = %e, "state database flush failed"),
+                }
+            }
+        });
+        info!(every_hours = hours, "auto-compaction enabled");
+    }
+
+    // ── Background sweep: enforce lock claim windows every 5 minutes ──────────
     {
         let sweep_engine = Arc::clone(&engine);
         tokio::spawn(async move {
@@ -325,10 +741,10 @@ async fn main() -> anyhow::Result<()> {
             interval.tick().await; // skip the immediate first tick
             loop {
                 interval.tick().await;
-                match sweep_engine.sweep_expired_email_locks(chrono::Utc::now().timestamp()) {
-                    Ok(0) => {} // nothing to revert — silent
-                    Ok(n) => info!(count = n, "sweep: reverted expired email locks"),
-                    Err(e) => warn!(error = %e, "sweep: failed to process expired locks"),
+                match sweep_engine.process_claim_windows(chrono::Utc::now().timestamp()) {
+                    Ok(0) => {} // nothing to process — silent
+                    Ok(n) => info!(count = n, "sweep: applied unclaimed actions of expired claim windows"),
+                    Err(e) => warn!(error = %e, "sweep: failed to process claim windows"),
                 }
 
                 // ── Day 91 activation trigger sweep ──────────────────────────
@@ -389,6 +805,48 @@ async fn main() -> anyhow::Result<()> {
         info!("wallet-to-wallet auto-delivery sweep started (every 60 seconds)");
     }
 
+    // ── Background sweep: schedule matured recurring installments every 60s ──
+    {
+        let recurring_engine = Arc::clone(&engine);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(60));
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                match recurring_engine.tick_recurring_locks(chrono::Utc::now().timestamp()) {
+                    Ok(spawned) if spawned.is_empty() => {}
+                    Ok(spawned) => {
+                        let ids: Vec<String> = spawned.iter().map(|id| id.to_hex()).collect();
+                        info!(count = spawned.len(), lock_ids = ?ids, "sweep: scheduled recurring installments");
+                    }
+                    Err(e) => warn!(error = %e, "sweep: failed to schedule recurring installments"),
+                }
+            }
+        });
+        info!("recurring installment sweep started (every 60 seconds)");
+    }
+
+    // ── Background sweep: apply expiry policies of unclaimed locks hourly ─────
+    {
+        let expired_lock_engine = Arc::clone(&engine);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(3600));
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                match expired_lock_engine.sweep_expired_locks(chrono::Utc::now().timestamp()) {
+                    Ok(processed) if processed.is_empty() => {}
+                    Ok(processed) => {
+                        let ids: Vec<String> = processed.iter().map(|id| id.to_hex()).collect();
+                        info!(count = processed.len(), lock_ids = ?ids, "sweep: applied expiry policies");
+                    }
+                    Err(e) => warn!(error = %e, "sweep: failed to apply expiry policies"),
+                }
+            }
+        });
+        info!("expiry policy sweep started (every 3600 seconds)");
+    }
+
     
 
     // ── Background sweep: humanity stake 100-year expiry (daily) ──────────────
@@ -582,6 +1040,117 @@ async fn main() -> anyhow::Result<()> {
         tracing::info!("friendly loan write-off sweep started (every 60 seconds)");
     }
 
+    // ── Background sweep: deregister under-staked verifiers (hourly) ─────────
+    {
+        let verifier_sweep_engine = Arc::clone(&engine);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(3600));
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                match verifier_sweep_engine.sweep_understaked_verifiers(chrono::Utc::now().timestamp()) {
+                    Ok(0) => {}
+                    Ok(n) => info!(count = n, "sweep: deregistered under-staked verifiers"),
+                    Err(e) => warn!(error = %e, "sweep: verifier stake check failed"),
+                }
+            }
+        });
+        info!("verifier stake sweep started (hourly)");
+    }
+
+    // ── Background sweep: prune oracle price history (hourly) ────────────────
+    {
+        let db = Arc::clone(&db);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(3600));
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                let cutoff = chrono::Utc::now().timestamp() - ORACLE_HISTORY_MAX_AGE_SECS;
+                match db.prune_oracle_history(cutoff) {
+                    Ok(0) => {}
+                    Ok(n) => info!(count = n, "sweep: pruned old oracle history"),
+                    Err(e) => warn!(error = %e, "sweep: oracle history prune failed"),
+                }
+            }
+        });
+        info!("oracle history prune started (hourly)");
+    }
+
+    // ── Background audit: lock and account supply (daily) ────────────────────
+    {
+        let db = Arc::clone(&db);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(86_400));
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                match db.expected_pending_lock_total().and_then(|e| db.audit_lock_amounts(e)) {
+                    Ok(r) if r.is_ok() => {}
+                    Ok(r) => warn!(
+                        total_locked = r.total_locked,
+                        discrepancy = r.discrepancy,
+                        "audit: pending lock amounts do not match account books"
+                    ),
+                    Err(e) => warn!(error = %e, "audit: lock audit failed"),
+                }
+                match chronx_genesis::audit_account_supply(&db) {
+                    Ok(a) if a.is_ok() => {}
+                    Ok(a) => warn!(
+                        total = a.total,
+                        discrepancy = a.discrepancy,
+                        "audit: account supply does not match TOTAL_SUPPLY_CHRONOS"
+                    ),
+                    Err(e) => warn!(error = %e, "audit: account supply audit failed"),
+                }
+            }
+        });
+        info!("supply audit started (daily)");
+    }
+
+    // ── Metrics and alerting (every 60 seconds) ──────────────────────────────
+    {
+        let db = Arc::clone(&db);
+        let rpc_state = Arc::clone(&rpc_state);
+        let metrics = Arc::clone(&metrics);
+        let expects_peers = !p2p_config.bootstrap_peers.is_empty();
+        let mut alerts = AlertManager::new(builtin_rules(), alert_notifier(args.alert_webhook.clone()));
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(60));
+            let mut depth = DepthTracker::new(dag_depth(&db), chrono::Utc::now().timestamp());
+            loop {
+                interval.tick().await;
+                let now = chrono::Utc::now().timestamp();
+                let (mempool_size, mempool_capacity) = rpc_state
+                    .mempool
+                    .as_ref()
+                    .map(|m| {
+                        let pool = m.lock().unwrap_or_else(|e| e.into_inner());
+                        (pool.len(), pool.capacity())
+                    })
+                    .unwrap_or_default();
+                let dag_depth = dag_depth(&db);
+                metrics.dag_depth.set(dag_depth as f64);
+                metrics.account_count.set(db.count_accounts() as f64);
+                let snapshot = MetricsSnapshot {
+                    taken_at: now,
+                    dag_depth,
+                    dag_depth_changed_at: depth.observe(dag_depth, now),
+                    mempool_size,
+                    mempool_capacity,
+                    oracle_updated_at: db.get_oracle_snapshot("KX/USD").ok().flatten().map(|s| s.updated_at),
+                    peer_count: rpc_state.peer_count.load(std::sync::atomic::Ordering::Relaxed),
+                    expects_peers,
+                    computed_supply_chronos: chronx_genesis::computed_supply_from_db(&db).ok(),
+                    expected_supply_chronos: chronx_genesis::genesis_block_total_chronos(),
+                };
+                let active = alerts.evaluate(&snapshot).to_vec();
+                *rpc_state.active_alerts.write().unwrap_or_else(|e| e.into_inner()) = active;
+            }
+        });
+        info!(webhook = args.alert_webhook.is_some(), "metrics and alerting task started (every 60 seconds)");
+    }
+
     // ── Periodic node version check (every 24 hours) ─────────────────────────
     tokio::spawn(async move {
         loop {
@@ -592,7 +1161,6 @@ async fn main() -> anyhow::Result<()> {
     tracing::info!("node version check scheduled (every 24 hours)");
 
     // ── Main loop: validate & apply ───────────────────────────────────────────
-    let mut difficulty = DifficultyConfig::new(args.pow_difficulty, 10_000, 100);
 
 
             // Store KXGC bond wallet in meta for TYPE A authority grant validation
@@ -612,58 +1180,167 @@ async fn main() -> anyhow::Result<()> {
             }
 
     info!("node ready");
-    while let Some(tx) = tx_receiver.recv().await {
-        let now = chrono::Utc::now().timestamp();
-        match engine.apply(&tx, now) {
-            Ok(()) => {
-                // Check if any action is an ExecutorWithdraw and fire alert email.
-                for action in &tx.actions {
-                    if let chronx_core::transaction::Action::ExecutorWithdraw {
-                        lock_id,
-                        destination,
-                        ..
-                    } = action
-                    {
-                        // Look up the withdrawal record for the amount.
-                        let lock_id_hex = lock_id.to_string();
-                        let amount_kx = match db.get_executor_withdrawal(&lock_id_hex) {
-                            Ok(Some(r)) => r.amount_chronos / 1_000_000,
-                            _ => 0,
-                        };
-                        let delay_secs: i64 = std::env::var("EXECUTOR_WITHDRAW_DELAY_SECONDS")
-                            .ok()
-                            .and_then(|s| s.parse().ok())
-                            .unwrap_or(86400);
-                        let finalize_at = now + delay_secs;
-                        // Fire alert email asynchronously.
-                        let lock_str = lock_id_hex.clone();
-                        let dest_str = destination.to_string();
-                        tokio::spawn(async move {
-                            send_executor_withdraw_alert(
-                                &lock_str,
-                                amount_kx,
-                                &dest_str,
-                                now,
-                                finalize_at,
-                            )
-                            .await;
+    let mut expiry_sweep = tokio::time::interval(std::time::Duration::from_secs(30));
+    let mut applied_since_snapshot = 0u64;
+    // RPC submitters waiting to hear how their transaction ends, by tx id.
+    let mut outcome_waiters: HashMap<TxId, tokio::sync::oneshot::Sender<TxOutcome>> = HashMap::new();
+    // Mempool drops already checked against `outcome_waiters`.
+    let mut drops_seen = 0u64;
+    loop {
+        let now = tokio::select! {
+            received = tx_receiver.recv() => {
+                let Some(SubmittedTx { tx, outcome }) = received else { break };
+                let now = chrono::Utc::now().timestamp();
+                // Gossip echoes back vertices this node already applied.
+                if db.vertex_exists(&tx.tx_id) {
+                    if let Some(outcome) = outcome {
+                        let _ = outcome.send(Ok(tx.tx_id));
+                    }
+                    continue;
+                }
+                let tx_id = tx.tx_id.clone();
+                let next_nonce = db.get_account(&tx.from).ok().flatten().map_or(0, |a| a.nonce);
+                let mut pool = mempool.lock().unwrap_or_else(|e| e.into_inner());
+                // Nonces below the sender's next one are all used; fail now
+                // rather than have the mempool drop it on the next take.
+                let queued = if tx.nonce < next_nonce {
+                    let reason = rejection_reason(&ChronxError::InvalidNonce { expected: next_nonce, got: tx.nonce });
+                    pool.record_rejected(&tx, reason.clone(), now);
+                    Err(reason)
+                } else {
+                    match pool.insert(tx, now) {
+                        // Already queued: wait on the queued copy.
+                        Ok(()) | Err(MempoolError::Duplicate) => Ok(()),
+                        Err(e) => Err(e.to_string()),
+                    }
+                };
+                drop(pool);
+                match (queued, outcome) {
+                    (Ok(()), Some(outcome)) => {
+                        outcome_waiters.insert(tx_id, outcome);
+                    }
+                    (Ok(()), None) => {}
+                    (Err(reason), outcome) => {
+                        warn!(tx_id = %tx_id, error = %reason, "transaction not queued");
+                        if let Some(outcome) = outcome {
+                            let _ = outcome.send(Err(reason));
+                        }
+                        continue;
+                    }
+                }
+                now
+            }
+            _ = expiry_sweep.tick() => {
+                outcome_waiters.retain(|_, outcome| !outcome.is_closed());
+                chrono::Utc::now().timestamp()
+            }
+        };
+        let ready = mempool.lock().unwrap_or_else(|e| e.into_inner()).take_ready(now, |id| {
+            db.get_account(id).ok().flatten().map_or(0, |a| a.nonce)
+        });
+        for tx in ready {
+            match engine.apply_with_receipt(&tx, now) {
+                Ok(receipt) => {
+                    if let Some(outcome) = outcome_waiters.remove(&tx.tx_id) {
+                        let _ = outcome.send(Ok(tx.tx_id.clone()));
+                    }
+                    rpc_state
+                        .notify_lock_status(&receipt.lock_status_changes, now)
+                        .await;
+                    rpc_state.publish_applied(&tx, &receipt, now);
+                    for notice in P2pMessage::terminal_lock_changes(&receipt.lock_status_changes, now) {
+                        let _ = outbound_tx.send(notice).await;
+                    }
+                    for event in chronx_explorer::ExplorerEvent::for_receipt(&tx, &receipt, now) {
+                        let _ = explorer_events.send(event);
+                    }
+                    // Check if any action is an ExecutorWithdraw and fire alert email.
+                    for action in &tx.actions {
+                        if let chronx_core::transaction::Action::ExecutorWithdraw {
+                            lock_id,
+                            destination,
+                            ..
+                        } = action
+                        {
+                            // Look up the withdrawal record for the amount.
+                            let lock_id_hex = lock_id.to_string();
+                            let amount_kx = match db.get_executor_withdrawal(&lock_id_hex) {
+                                Ok(Some(r)) => r.amount_chronos / 1_000_000,
+                                _ => 0,
+                            };
+                            let delay_secs: i64 = std::env::var("EXECUTOR_WITHDRAW_DELAY_SECONDS")
+                                .ok()
+                                .and_then(|s| s.parse().ok())
+                                .unwrap_or(86400);
+                            let finalize_at = now + delay_secs;
+                            // Fire alert email asynchronously.
+                            let lock_str = lock_id_hex.clone();
+                            let dest_str = destination.to_string();
+                            tokio::spawn(async move {
+                                send_executor_withdraw_alert(
+                                    &lock_str,
+                                    amount_kx,
+                                    &dest_str,
+                                    now,
+                                    finalize_at,
+                                )
+                                .await;
+                            });
+                        }
+                    }
+                    let payload = bincode::serialize(&tx).unwrap_or_default();
+                    let _ = outbound_tx.send(P2pMessage::NewVertex { payload }).await;
+
+                    applied_since_snapshot += 1;
+                    if args.snapshot_interval > 0 && applied_since_snapshot >= args.snapshot_interval {
+                        applied_since_snapshot = 0;
+                        // Taken inline: nothing may be applied while the
+                        // files are copied or they will not match the metadata.
+                        let result = tokio::task::block_in_place(|| {
+                            let dest = db.take_snapshot(db.count_vertices(), &data_dir)?;
+                            let pruned = chronx_state::snapshot::prune_snapshots(&data_dir, SNAPSHOT_KEEP)?;
+                            Ok::<_, chronx_core::error::ChronxError>((dest, pruned))
                         });
+                        match result {
+                            Ok((dest, pruned)) => info!(path = %dest.display(), pruned, "state snapshot written"),
+                            Err(e) => warn!(error = %e, "state snapshot failed"),
+                        }
                     }
                 }
-                let payload = bincode::serialize(&tx).unwrap_or_default();
-                let _ = outbound_tx.send(P2pMessage::NewVertex { payload }).await;
-                let ts_ms = (tx.timestamp * 1000) as u64;
-                if let Some(new_diff) = difficulty.record_solve(ts_ms) {
-                    info!(difficulty = new_diff, "PoW difficulty adjusted");
+                Err(e) => {
+                    warn!(error = %e, "transaction rejected");
+                    let reason = rejection_reason(&e);
+                    mempool.lock().unwrap_or_else(|e| e.into_inner()).record_rejected(&tx, reason.clone(), now);
+                    if let Some(outcome) = outcome_waiters.remove(&tx.tx_id) {
+                        let _ = outcome.send(Err(reason));
+                    }
+                }
+            }
+        }
+
+        // Tell submitters whose transaction the mempool dropped (stale
+        // nonce, expiry, eviction) since the last pass.
+        let pool = mempool.lock().unwrap_or_else(|e| e.into_inner());
+        let new_drops = pool.dropped_total() - drops_seen;
+        drops_seen = pool.dropped_total();
+        if !outcome_waiters.is_empty() {
+            for dropped in pool.recently_dropped().take(new_drops.min(RECENT_DROPS as u64) as usize) {
+                if let Some(outcome) = outcome_waiters.remove(&dropped.tx_id) {
+                    let _ = outcome.send(Err(dropped.reason.clone()));
                 }
             }
-            Err(e) => warn!(error = %e, "transaction rejected"),
         }
     }
 
     Ok(())
 }
 
+/// How a rejected transaction is reported to its submitter and in the
+/// mempool's drop log: the `ChronxError` variant, then its message.
+fn rejection_reason(e: &ChronxError) -> String {
+    format!("{}: {e}", e.variant_name())
+}
+
 /// Load genesis parameters from a JSON file, or generate ephemeral keypairs if no path is given.
 ///
 /// # Warning
@@ -710,6 +1387,7 @@ fn load_or_generate_genesis_params(
         sweep_executor_interval_seconds: 60,
         pay_as_max_usd: 100.0,
         pay_as_enabled: true,
+        custom_allocations: Vec::new(),
     })
 }
 
@@ -838,6 +1516,90 @@ async fn send_executor_withdraw_alert(
     }
 }
 
+/// `chronx-node diagnose`: print a per-tree breakdown of the database at
+/// `data_dir` and warn about trees this version does not know about.
+fn diagnose(data_dir: &Path) -> anyhow::Result<()> {
+    use chronx_state::db::SLED_CACHE_CAPACITY_BYTES;
+
+    // `sled::open` would create an empty database in a missing directory.
+    if !data_dir.exists() {
+        anyhow::bail!("no state database at {}", data_dir.display());
+    }
+    let db = StateDb::open(data_dir)
+        .with_context(|| format!("opening state database at {} (is the node running?)", data_dir.display()))?;
+    let mut trees = db.tree_diagnostics().context("reading trees")?;
+    trees.sort_by_key(|t| std::cmp::Reverse(t.approx_size_bytes));
+
+    println!("State database: {}", data_dir.display());
+    println!("{:<32} {:>12} {:>16}", "tree", "entries", "approx bytes");
+    for t in &trees {
+        let marker = if t.known { "" } else { "  (unknown)" };
+        println!("{:<32} {:>12} {:>16}{marker}", t.name, t.entry_count, t.approx_size_bytes);
+    }
+    let data_bytes: u64 = trees.iter().map(|t| t.approx_size_bytes).sum();
+    println!();
+    println!("Trees:            {}", trees.len());
+    println!("Data (approx):    {data_bytes} bytes");
+    println!("Size on disk:     {} bytes", db.size_on_disk()?);
+    println!(
+        "Cache estimate:   {:.1}% of {SLED_CACHE_CAPACITY_BYTES} bytes",
+        (data_bytes as f64 / SLED_CACHE_CAPACITY_BYTES as f64).min(1.0) * 100.0
+    );
+    for t in trees.iter().filter(|t| !t.known) {
+        warn!(tree = %t.name, entries = t.entry_count, "unknown tree, possibly from a newer node version");
+    }
+    Ok(())
+}
+
+/// Snapshot the database at `data_dir` under `out`, named by its vertex count.
+fn snapshot(data_dir: &Path, out: &Path) -> anyhow::Result<()> {
+    if !data_dir.join("db").is_file() {
+        anyhow::bail!("no state database at {}", data_dir.display());
+    }
+    let db = StateDb::open(data_dir)
+        .with_context(|| format!("opening state database at {} (is the node running?)", data_dir.display()))?;
+    let dest = db.take_snapshot(db.count_vertices(), out).context("taking snapshot")?;
+    println!("{}", dest.display());
+    Ok(())
+}
+
+fn compact(data_dir: &Path, verbose: bool) -> anyhow::Result<()> {
+    let mut report = chronx_state::compact::compact_database(data_dir)
+        .with_context(|| format!("compacting state database at {}", data_dir.display()))?;
+    if verbose {
+        report.trees.sort_by_key(|t| std::cmp::Reverse(t.approx_size_bytes));
+        println!("{:<32} {:>12} {:>16}", "tree", "entries", "approx bytes");
+        for t in &report.trees {
+            println!("{:<32} {:>12} {:>16}", t.name, t.entry_count, t.approx_size_bytes);
+        }
+        println!();
+    }
+    println!("State database: {}", data_dir.display());
+    println!("Size before:      {} bytes", report.size_before);
+    println!("Size after:       {} bytes", report.size_after);
+    println!("Saved:            {} bytes", report.bytes_saved());
+    Ok(())
+}
+
+/// Compact the database at `data_dir` if it has never been compacted or
+/// the last compaction is more than `hours` old. A fresh data dir is left
+/// alone.
+fn compact_if_due(data_dir: &Path, hours: u64) -> anyhow::Result<()> {
+    if !data_dir.join("db").is_file() {
+        return Ok(());
+    }
+    let last = {
+        let db = StateDb::open(data_dir).context("opening state database")?;
+        chronx_state::compact::last_compaction_at(&db)?
+    };
+    let due = last.is_none_or(|at| chrono::Utc::now().timestamp() - at >= (hours * 3600) as i64);
+    if due {
+        let report = chronx_state::compact::compact_database(data_dir).context("compacting state database")?;
+        info!(before = report.size_before, after = report.size_after, "state database compacted");
+    }
+    Ok(())
+}
+
 /// Expand a leading `~` to the user's home directory (`HOME` or `USERPROFILE`).
 fn expand_tilde(path: &Path) -> PathBuf {
     if let Ok(stripped) = path.strip_prefix("~") {
@@ -847,3 +1609,32 @@ fn expand_tilde(path: &Path) -> PathBuf {
     }
     path.to_path_buf()
 }
+
+/// Serve the default Prometheus registry in text format at `GET /metrics`.
+async fn serve_metrics(addr: SocketAddr) -> std::io::Result<()> {
+    use prometheus::Encoder;
+
+    async fn metrics() -> ([(axum::http::header::HeaderName, String); 1], Vec<u8>) {
+        let encoder = prometheus::TextEncoder::new();
+        let mut body = Vec::new();
+        if let Err(e) = encoder.encode(&prometheus::default_registry().gather(), &mut body) {
+            warn!(error = %e, "encoding metrics");
+        }
+        ([(axum::http::header::CONTENT_TYPE, encoder.format_type().to_string())], body)
+    }
+
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    info!(addr = %listener.local_addr()?, "Prometheus metrics listening");
+    axum::serve(listener, axum::Router::new().route("/metrics", axum::routing::get(metrics))).await
+}
+
+/// Greatest depth among the current DAG tips.
+fn dag_depth(db: &StateDb) -> u64 {
+    db.get_tips()
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|t| db.get_vertex(t).ok().flatten())
+        .map(|v| v.depth)
+        .max()
+        .unwrap_or(0)
+}
@@ -0,0 +1,110 @@
+//! GraphQL explorer integration test for chronx-node.
+//!
+//! Starts a node with `--explorer-port` and queries the live state over HTTP.
+//!
+//! Run with:
+//!   cargo test -p chronx-node --test explorer
+
+use std::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+
+use serde_json::{json, Value};
+
+struct NodeGuard {
+    child: Child,
+    data_dir: PathBuf,
+}
+
+impl Drop for NodeGuard {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+        let _ = std::fs::remove_dir_all(&self.data_dir);
+    }
+}
+
+fn free_port() -> u16 {
+    TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port()
+}
+
+fn wait_for_port(port: u16, timeout: Duration) -> bool {
+    let start = Instant::now();
+    while start.elapsed() < timeout {
+        if TcpStream::connect(("127.0.0.1", port)).is_ok() {
+            return true;
+        }
+        std::thread::sleep(Duration::from_millis(200));
+    }
+    false
+}
+
+async fn graphql(port: u16, query: &str) -> Value {
+    reqwest::Client::new()
+        .post(format!("http://127.0.0.1:{port}/graphql"))
+        .json(&json!({ "query": query }))
+        .send()
+        .await
+        .expect("explorer request failed")
+        .json()
+        .await
+        .expect("explorer returned non-JSON")
+}
+
+#[tokio::test]
+async fn explorer_serves_live_node_state() {
+    let data_dir = std::env::temp_dir().join(format!("chronx_explorer_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&data_dir);
+    std::fs::create_dir_all(&data_dir).unwrap();
+
+    let rpc_port = free_port();
+    let p2p_port = free_port();
+    let explorer_port = free_port();
+    let child = Command::new(env!("CARGO_BIN_EXE_chronx-node"))
+        .args([
+            "--data-dir",
+            data_dir.join("state").to_str().unwrap(),
+            "--rpc-addr",
+            &format!("127.0.0.1:{}", rpc_port),
+            "--p2p-listen",
+            &format!("/ip4/127.0.0.1/tcp/{}", p2p_port),
+            "--pow-difficulty",
+            "0",
+            "--explorer-port",
+            &explorer_port.to_string(),
+        ])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("failed to spawn chronx-node");
+    let _guard = NodeGuard { child, data_dir };
+
+    assert!(
+        wait_for_port(explorer_port, Duration::from_secs(20)),
+        "explorer did not start within 20 seconds"
+    );
+
+    let stats = graphql(
+        explorer_port,
+        "{ chainStats { totalAccounts totalVertices dagTipCount } }",
+    )
+    .await;
+    assert!(stats.get("errors").is_none(), "unexpected errors: {stats}");
+    assert!(stats["data"]["chainStats"]["totalVertices"].is_u64());
+
+    let missing = graphql(
+        explorer_port,
+        &format!(r#"{{ transaction(id: "{}") {{ txId }} }}"#, "00".repeat(32)),
+    )
+    .await;
+    assert!(missing["data"]["transaction"].is_null(), "got {missing}");
+
+    let bad = graphql(explorer_port, r#"{ account(id: "not-an-account") { balanceChronos } }"#).await;
+    let message = bad["errors"][0]["message"].as_str().unwrap_or_default();
+    assert!(message.contains("invalid account id"), "got {bad}");
+}
@@ -0,0 +1,126 @@
+//! SIGHUP config-reload integration test for chronx-node.
+//!
+//! Starts a node with an empty `--config` file, rewrites the file to add a
+//! bootstrap peer, sends SIGHUP, and verifies the node dials the new peer.
+//!
+//! Run with:
+//!   cargo test -p chronx-node --test reload
+
+#![cfg(unix)]
+
+use std::io::Write;
+use std::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+
+struct NodeGuard {
+    child: Child,
+    data_dir: PathBuf,
+}
+
+impl Drop for NodeGuard {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+        let _ = std::fs::remove_dir_all(&self.data_dir);
+    }
+}
+
+fn free_port() -> u16 {
+    TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port()
+}
+
+fn wait_for_port(port: u16, timeout: Duration) -> bool {
+    let start = Instant::now();
+    while start.elapsed() < timeout {
+        if TcpStream::connect(("127.0.0.1", port)).is_ok() {
+            return true;
+        }
+        std::thread::sleep(Duration::from_millis(200));
+    }
+    false
+}
+
+/// Wait until `listener` accepts a connection or `timeout` elapses.
+fn wait_for_dial(listener: &TcpListener, timeout: Duration) -> bool {
+    listener.set_nonblocking(true).unwrap();
+    let start = Instant::now();
+    while start.elapsed() < timeout {
+        if listener.accept().is_ok() {
+            return true;
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    }
+    false
+}
+
+#[test]
+fn sighup_dials_new_bootstrap_peers() {
+    let data_dir = std::env::temp_dir().join(format!("chronx_reload_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&data_dir);
+    std::fs::create_dir_all(&data_dir).unwrap();
+
+    let config_path = data_dir.join("node.toml");
+    std::fs::write(&config_path, "").unwrap();
+
+    // Stand-in bootstrap peer: the node only needs to open a TCP connection
+    // for us to know it attempted the dial.
+    let peer = TcpListener::bind("127.0.0.1:0").unwrap();
+    let peer_port = peer.local_addr().unwrap().port();
+
+    let rpc_port = free_port();
+    let p2p_port = free_port();
+    let child = Command::new(env!("CARGO_BIN_EXE_chronx-node"))
+        .args([
+            "--data-dir",
+            data_dir.join("state").to_str().unwrap(),
+            "--rpc-addr",
+            &format!("127.0.0.1:{}", rpc_port),
+            "--p2p-listen",
+            &format!("/ip4/127.0.0.1/tcp/{}", p2p_port),
+            "--pow-difficulty",
+            "0",
+            "--config",
+            config_path.to_str().unwrap(),
+        ])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("failed to spawn chronx-node");
+    let pid = child.id();
+    let _guard = NodeGuard { child, data_dir };
+
+    assert!(
+        wait_for_port(rpc_port, Duration::from_secs(20)),
+        "chronx-node did not become ready within 20 seconds"
+    );
+    assert!(
+        !wait_for_dial(&peer, Duration::from_millis(500)),
+        "node dialed the peer before it was configured"
+    );
+
+    let mut file = std::fs::File::create(&config_path).unwrap();
+    writeln!(
+        file,
+        "[p2p]\nbootstrap = [\"/ip4/127.0.0.1/tcp/{}/p2p/12D3KooWDpJ7As7BWAwRMfu1VU2WCqNjvq387JEYKDBj4kx6nXTN\"]\n\n[rpc]\nrate_limit = 100",
+        peer_port
+    )
+    .unwrap();
+    drop(file);
+
+    let status = Command::new("kill")
+        .args(["-HUP", &pid.to_string()])
+        .status()
+        .expect("failed to run kill");
+    assert!(status.success());
+
+    assert!(
+        wait_for_dial(&peer, Duration::from_secs(10)),
+        "node did not dial the bootstrap peer added on SIGHUP"
+    );
+}
@@ -11,11 +11,11 @@ use std::path::PathBuf;
 use std::process::{Child, Command, Stdio};
 use std::time::{Duration, Instant};
 
-use chronx_core::constants::CHRONOS_PER_KX;
+use chronx_core::constants::{CHRONOS_PER_KX, PUBLIC_SALE_KX};
 use chronx_core::transaction::{Action, AuthScheme, Transaction};
 use chronx_core::types::TxId;
 use chronx_crypto::{hash::tx_id_from_body, mine_pow, KeyPair};
-use chronx_genesis::GenesisParams;
+use chronx_tx_builder::{timelock_action, transfer_action};
 
 // ── Node lifecycle ────────────────────────────────────────────────────────────
 
@@ -68,6 +68,33 @@ async fn rpc_call(
     json["result"].clone()
 }
 
+/// Make an RPC call that must fail; returns its error object.
+async fn rpc_call_err(
+    client: &reqwest::Client,
+    url: &str,
+    method: &str,
+    params: serde_json::Value,
+) -> serde_json::Value {
+    let body = serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": method,
+        "params": params,
+        "id": 1
+    });
+    let json: serde_json::Value = client
+        .post(url)
+        .json(&body)
+        .send()
+        .await
+        .unwrap_or_else(|e| panic!("RPC call {method} failed: {e}"))
+        .json()
+        .await
+        .expect("parse RPC JSON");
+    json.get("error")
+        .unwrap_or_else(|| panic!("{method} unexpectedly succeeded: {json}"))
+        .clone()
+}
+
 /// Poll until the RPC server responds or the timeout elapses.
 async fn wait_for_rpc(client: &reqwest::Client, url: &str, timeout: Duration) -> bool {
     let body = serde_json::json!({
@@ -128,11 +155,12 @@ async fn send_tx(client: &reqwest::Client, url: &str, tx: &Transaction) -> Strin
     let result = rpc_call(
         client,
         url,
-        "chronx_sendTransaction",
+        "chronx_submitTransaction",
         serde_json::json!([tx_hex]),
     )
     .await;
-    result.as_str().expect("tx_id string").to_string()
+    assert_eq!(result["status"], "applied", "submitTransaction result: {result}");
+    result["tx_id"].as_str().expect("tx_id string").to_string()
 }
 
 // ── Transaction builder ───────────────────────────────────────────────────────
@@ -176,25 +204,13 @@ async fn smoke_transfer_and_timelock() {
     std::fs::create_dir_all(&data_dir).unwrap();
 
     let public_sale_kp = KeyPair::generate();
-    let treasury_kp = KeyPair::generate();
-    let humanity_kp = KeyPair::generate();
-    let nr_kp = KeyPair::generate();
-    let fo_kp = KeyPair::generate();
-    let mi_kp = KeyPair::generate();
-    let ve_kp = KeyPair::generate();
-
-    let params = GenesisParams {
-        public_sale_key: public_sale_kp.public_key.clone(),
-        treasury_key: treasury_kp.public_key.clone(),
-        humanity_key: humanity_kp.public_key.clone(),
-        node_rewards_key: nr_kp.public_key.clone(),
-        founder_key: fo_kp.public_key.clone(),
-        misai_key: mi_kp.public_key.clone(),
-        verifas_key: ve_kp.public_key.clone(),
-        axioms: None,
-    };
+    let params = serde_json::json!({
+        "public_sale_key": public_sale_kp.public_key,
+        "treasury_key": KeyPair::generate().public_key,
+        "humanity_key": KeyPair::generate().public_key,
+    });
     let params_path = data_dir.join("genesis-params.json");
-    std::fs::write(&params_path, serde_json::to_string(&params).unwrap()).unwrap();
+    std::fs::write(&params_path, params.to_string()).unwrap();
 
     // ── 2. Start node ─────────────────────────────────────────────────────────
     let rpc_port = free_port();
@@ -234,8 +250,8 @@ async fn smoke_transfer_and_timelock() {
     let genesis_bal = get_balance(&http, &rpc_url, &ps_b58).await;
     assert_eq!(
         genesis_bal,
-        7_268_000_000u128 * CHRONOS_PER_KX,
-        "public_sale genesis balance should be 7,268,000,000 KX"
+        PUBLIC_SALE_KX * CHRONOS_PER_KX,
+        "public_sale genesis balance should be the full public sale allocation"
     );
 
     // ── 5. Transfer 1000 KX public_sale → alice ───────────────────────────────
@@ -246,13 +262,7 @@ async fn smoke_transfer_and_timelock() {
         &public_sale_kp,
         nonce,
         tips,
-        vec![Action::Transfer {
-            to: alice.account_id.clone(),
-            amount: 1_000 * CHRONOS_PER_KX,
-            memo: None,
-            memo_encrypted: true,
-            memo_public: false,
-        }],
+        vec![transfer_action(alice.account_id.clone(), 1_000 * CHRONOS_PER_KX, None)],
     );
     send_tx(&http, &rpc_url, &tx1).await;
 
@@ -271,15 +281,59 @@ async fn smoke_transfer_and_timelock() {
     let ps_bal_after = get_balance(&http, &rpc_url, &ps_b58).await;
     assert_eq!(
         ps_bal_after,
-        (7_268_000_000u128 - 1_000) * CHRONOS_PER_KX,
+        (PUBLIC_SALE_KX - 1_000) * CHRONOS_PER_KX,
         "public_sale should be reduced by 1000 KX"
     );
 
-    // ── 7. Create a timelock public_sale → bob (200 KX, unlock in 1 year) ──────
-    // Note: alice's account was auto-created with an empty pubkey when she
-    // received funds (Transfer doesn't carry recipient pubkey), so alice cannot
-    // sign transactions yet. We test TimeLockCreate from the well-keyed
-    // public_sale account instead.
+    // ── 7. Alice spends 250 KX → carol ────────────────────────────────────────
+    // alice's account was auto-created with an empty pubkey when she received
+    // funds, so her first spend registers the key carried in sender_public_key.
+    let carol = KeyPair::generate();
+    let tips_alice = get_dag_tips(&http, &rpc_url).await;
+    let alice_nonce = get_nonce(&http, &rpc_url, &alice_b58).await;
+    let tx_alice = build_tx(
+        &alice,
+        alice_nonce,
+        tips_alice,
+        vec![transfer_action(carol.account_id.clone(), 250 * CHRONOS_PER_KX, None)],
+    );
+    send_tx(&http, &rpc_url, &tx_alice).await;
+    tokio::time::sleep(Duration::from_millis(600)).await;
+
+    assert_eq!(
+        get_balance(&http, &rpc_url, &alice_b58).await,
+        750 * CHRONOS_PER_KX,
+        "alice should have 750 KX left"
+    );
+    assert_eq!(
+        get_balance(&http, &rpc_url, &carol.account_id.to_b58()).await,
+        250 * CHRONOS_PER_KX,
+        "carol should have 250 KX"
+    );
+    assert_eq!(get_nonce(&http, &rpc_url, &alice_b58).await, alice_nonce + 1);
+
+    // ── 8. Reusing alice's nonce is rejected, and the reason is kept ──────────
+    let replay = build_tx(
+        &alice,
+        alice_nonce,
+        get_dag_tips(&http, &rpc_url).await,
+        vec![transfer_action(carol.account_id.clone(), 5 * CHRONOS_PER_KX, None)],
+    );
+    let replay_hex = hex::encode(bincode::serialize(&replay).unwrap());
+    let err = rpc_call_err(&http, &rpc_url, "chronx_sendTransaction", serde_json::json!([replay_hex])).await;
+    let message = err["message"].as_str().unwrap_or_default();
+    assert!(message.contains("InvalidNonce"), "unexpected rejection: {err}");
+    let status = rpc_call(
+        &http,
+        &rpc_url,
+        "chronx_getTransactionStatus",
+        serde_json::json!([replay.tx_id.to_hex()]),
+    )
+    .await;
+    assert_eq!(status["status"], "rejected");
+    assert!(status["reason"].as_str().unwrap().contains("InvalidNonce"), "{status}");
+
+    // ── 9. Create a timelock public_sale → bob (200 KX, unlock in 1 year) ──────
     let bob = KeyPair::generate();
     let unlock_at = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
@@ -293,37 +347,21 @@ async fn smoke_transfer_and_timelock() {
         &public_sale_kp,
         ps_nonce2,
         tips2,
-        vec![Action::TimeLockCreate {
-            recipient: bob.public_key.clone(),
-            amount: 200 * CHRONOS_PER_KX,
+        vec![timelock_action(
+            bob.public_key.clone(),
+            200 * CHRONOS_PER_KX,
             unlock_at,
-            memo: Some("smoke test timelock".into()),
-            cancellation_window_secs: None,
-            notify_recipient: None,
-            tags: None,
-            private: None,
-            expiry_policy: None,
-            split_policy: None,
-            claim_attempts_max: None,
-            recurring: None,
-            lock_marker: None,
-            oracle_hint: None,
-            jurisdiction_hint: None,
-            governance_proposal_id: None,
-            client_ref: None,
-            email_recipient_hash: None,
-            claim_window_secs: None,
-            unclaimed_action: None,
-        }],
+            Some("smoke test timelock".into()),
+        )],
     );
     send_tx(&http, &rpc_url, &tx2).await;
     tokio::time::sleep(Duration::from_millis(600)).await;
 
-    // ── 8. Verify public_sale's balance is now reduced by a further 200 KX ────
+    // ── 10. Verify public_sale's balance is now reduced by a further 200 KX ───
     let ps_bal_final = get_balance(&http, &rpc_url, &ps_b58).await;
     assert_eq!(
         ps_bal_final,
-        (7_268_000_000u128 - 1_000 - 200) * CHRONOS_PER_KX,
+        (PUBLIC_SALE_KX - 1_000 - 200) * CHRONOS_PER_KX,
         "public_sale should be reduced by 1000 + 200 KX"
     );
 }
@@ -0,0 +1,146 @@
+//! `chronx-tx-builder` integration test for chronx-node.
+//!
+//! Starts a node with a fresh genesis and drives it only through `TxBuilder`:
+//! a transfer, a time-lock, its claim once matured, and a custom action list.
+//!
+//! Run with:
+//!   cargo test -p chronx-node --test tx_builder
+
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+
+use chronx_core::constants::CHRONOS_PER_KX;
+use chronx_core::types::{TimeLockId, TxId};
+use chronx_crypto::KeyPair;
+use chronx_tx_builder::{transfer_action, TxBuilder};
+
+struct NodeGuard {
+    child: Child,
+    data_dir: PathBuf,
+}
+
+impl Drop for NodeGuard {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+        let _ = std::fs::remove_dir_all(&self.data_dir);
+    }
+}
+
+fn free_port() -> u16 {
+    TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port()
+}
+
+async fn rpc_call(url: &str, method: &str, params: serde_json::Value) -> Option<serde_json::Value> {
+    let body = serde_json::json!({ "jsonrpc": "2.0", "method": method, "params": params, "id": 1 });
+    let json: serde_json::Value =
+        reqwest::Client::new().post(url).json(&body).send().await.ok()?.json().await.ok()?;
+    json.get("result").cloned()
+}
+
+async fn balance(url: &str, kp: &KeyPair) -> u128 {
+    rpc_call(url, "chronx_getBalance", serde_json::json!([kp.account_id.to_b58()]))
+        .await
+        .and_then(|v| v.as_str()?.parse().ok())
+        .unwrap_or(0)
+}
+
+/// Poll until the node has applied `tx_id`.
+async fn wait_for_tx(url: &str, tx_id: &TxId) {
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while Instant::now() < deadline {
+        if rpc_call(url, "chronx_getTransaction", serde_json::json!([tx_id.to_hex()]))
+            .await
+            .is_some_and(|v| !v.is_null())
+        {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(200)).await;
+    }
+    panic!("node did not apply {tx_id}");
+}
+
+#[tokio::test]
+async fn tx_builder_drives_a_live_node() {
+    let data_dir = std::env::temp_dir().join(format!("chronx_tx_builder_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&data_dir);
+    std::fs::create_dir_all(&data_dir).unwrap();
+
+    let public_sale = KeyPair::generate();
+    let params = serde_json::json!({
+        "public_sale_key": public_sale.public_key,
+        "treasury_key": KeyPair::generate().public_key,
+        "humanity_key": KeyPair::generate().public_key,
+    });
+    let params_path = data_dir.join("genesis-params.json");
+    std::fs::write(&params_path, params.to_string()).unwrap();
+
+    let rpc_port = free_port();
+    let url = format!("http://127.0.0.1:{rpc_port}");
+    let child = Command::new(env!("CARGO_BIN_EXE_chronx-node"))
+        .args([
+            "--data-dir",
+            data_dir.join("state").to_str().unwrap(),
+            "--rpc-addr",
+            &format!("127.0.0.1:{rpc_port}"),
+            "--p2p-listen",
+            &format!("/ip4/127.0.0.1/tcp/{}", free_port()),
+            "--genesis-params",
+            params_path.to_str().unwrap(),
+            "--pow-difficulty",
+            "0",
+        ])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("failed to spawn chronx-node");
+    let _guard = NodeGuard { child, data_dir };
+
+    let deadline = Instant::now() + Duration::from_secs(20);
+    while rpc_call(&url, "chronx_getGenesisInfo", serde_json::json!([])).await.is_none() {
+        assert!(Instant::now() < deadline, "node did not become ready");
+        tokio::time::sleep(Duration::from_millis(250)).await;
+    }
+
+    let (alice, bob, carol) = (KeyPair::generate(), KeyPair::generate(), KeyPair::generate());
+    let sale = TxBuilder::new(&url, public_sale).with_pow_difficulty(0);
+
+    let tx = sale.transfer(alice.account_id.clone(), 100 * CHRONOS_PER_KX).await.unwrap();
+    let tx_id = sale.submit(tx).await.unwrap();
+    wait_for_tx(&url, &tx_id).await;
+    assert_eq!(balance(&url, &alice).await, 100 * CHRONOS_PER_KX);
+
+    // The builder picks up the next nonce on its own.
+    let unlock_at = chrono::Utc::now().timestamp() + 2;
+    let tx = sale.timelock(alice.public_key.clone(), 10 * CHRONOS_PER_KX, unlock_at).await.unwrap();
+    assert_eq!(tx.nonce, 1);
+    let lock_id = sale.submit(tx).await.unwrap();
+    wait_for_tx(&url, &lock_id).await;
+
+    while chrono::Utc::now().timestamp() <= unlock_at {
+        tokio::time::sleep(Duration::from_millis(200)).await;
+    }
+    let alice_builder = TxBuilder::new(&url, alice).with_pow_difficulty(0);
+    let tx = alice_builder.claim(TimeLockId(lock_id)).await.unwrap();
+    let claim_id = alice_builder.submit(tx).await.unwrap();
+    wait_for_tx(&url, &claim_id).await;
+    assert_eq!(balance(&url, &alice_builder.keypair).await, 110 * CHRONOS_PER_KX);
+
+    let tx = sale
+        .custom(vec![
+            transfer_action(bob.account_id.clone(), CHRONOS_PER_KX, None),
+            transfer_action(carol.account_id.clone(), 2 * CHRONOS_PER_KX, Some("second".into())),
+        ])
+        .await
+        .unwrap();
+    let tx_id = sale.submit(tx).await.unwrap();
+    wait_for_tx(&url, &tx_id).await;
+    assert_eq!(balance(&url, &bob).await, CHRONOS_PER_KX);
+    assert_eq!(balance(&url, &carol).await, 2 * CHRONOS_PER_KX);
+}
@@ -11,11 +11,31 @@ pub struct P2pConfig {
     pub protocol_version: String,
     /// GossipSub topic name for broadcasting new vertices.
     pub vertex_topic: String,
+    /// GossipSub topic for small latency-sensitive notices (lock status changes).
+    pub priority_topic: String,
     /// Optional path to a persistent identity key file.
     /// If set and the file exists, the keypair is loaded from it.
     /// If set and the file does not exist, a new keypair is generated and saved.
     /// If None, a fresh random identity is used each run.
     pub identity_file: Option<PathBuf>,
+    /// Compress large `NewVertex` gossip payloads with zstd.
+    pub compression_enabled: bool,
+    /// zstd compression level used when `compression_enabled` is set.
+    pub compression_level: i32,
+    /// Connections we dialed beyond this evict the lowest-reputation outbound peer.
+    pub max_outbound_peers: usize,
+    /// Connections peers dialed beyond this evict the lowest-reputation inbound peer.
+    pub max_inbound_peers: usize,
+    /// Drop gossip that carries no author signature and count it against the
+    /// forwarding peer. Outbound gossip is always signed; turn this off only
+    /// on test networks with unsigned publishers.
+    pub gossip_require_signed: bool,
+    /// Hold small outbound vertices for up to this long so several can be
+    /// gossiped as one `VertexBatch`.
+    pub batch_window_ms: u64,
+    /// Publish a batch as soon as it holds this many vertices. 1 or 0
+    /// disables batching.
+    pub batch_max_size: usize,
 }
 
 impl Default for P2pConfig {
@@ -25,7 +45,15 @@ impl Default for P2pConfig {
             bootstrap_peers: Vec::new(),
             protocol_version: "/chronx/1.0.0".into(),
             vertex_topic: "chronx-vertices".into(),
+            priority_topic: "chronx-priority".into(),
             identity_file: None,
+            compression_enabled: true,
+            compression_level: 3,
+            max_outbound_peers: 8,
+            max_inbound_peers: 32,
+            gossip_require_signed: true,
+            batch_window_ms: 50,
+            batch_max_size: 10,
         }
     }
 }
@@ -9,7 +9,9 @@
 pub mod config;
 pub mod message;
 pub mod network;
+pub mod peers;
 
 pub use config::P2pConfig;
-pub use message::P2pMessage;
-pub use network::{P2pHandle, P2pNetwork};
+pub use message::{P2pMessage, GOSSIP_COMPRESS_THRESHOLD, GOSSIP_MAX_BATCH_LEN};
+pub use network::{P2pHandle, P2pNetwork, P2pStats, PeerConnection};
+pub use peers::{Direction, PeerTracker};
@@ -1,6 +1,16 @@
-use chronx_core::types::TxId;
+use chronx_core::account::TimeLockStatus;
+use chronx_core::types::{Timestamp, TxId};
 use serde::{Deserialize, Serialize};
 
+/// `NewVertex` payloads larger than this (bytes) are zstd-compressed before gossip.
+pub const GOSSIP_COMPRESS_THRESHOLD: usize = 4096;
+
+/// Upper bound on a decompressed vertex payload, guarding against zstd bombs.
+pub const GOSSIP_MAX_DECOMPRESSED_LEN: u32 = 4 * 1024 * 1024;
+
+/// Most payloads a received `VertexBatch` may carry.
+pub const GOSSIP_MAX_BATCH_LEN: u32 = 64;
+
 /// Messages exchanged over the ChronX P2P gossip network.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum P2pMessage {
@@ -16,6 +26,19 @@ pub enum P2pMessage {
 
     /// Request to receive the current tip set from a peer.
     RequestTips,
+
+    /// A `NewVertex` whose payload is zstd-compressed.
+    /// `original_len` is the length of the uncompressed payload.
+    NewVertexCompressed { payload: Vec<u8>, original_len: u32 },
+
+    /// A lock reached a terminal status on the sending node. Published on the
+    /// priority topic so peers can notify subscribers before the vertex
+    /// itself propagates. Advisory only: never applied to state.
+    LockStatusChange { lock_id: TxId, new_status: String, at: Timestamp },
+
+    /// Several `NewVertex` payloads gossiped as one message. Each payload is
+    /// a bincode-serialized `Transaction`; `count` is `payloads.len()`.
+    VertexBatch { payloads: Vec<Vec<u8>>, count: u32 },
 }
 
 impl P2pMessage {
@@ -28,4 +51,190 @@ impl P2pMessage {
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, bincode::Error> {
         bincode::deserialize(bytes)
     }
+
+    /// Whether this message travels on the priority gossip topic.
+    pub fn is_priority(&self) -> bool {
+        matches!(self, P2pMessage::LockStatusChange { .. })
+    }
+
+    /// One `LockStatusChange` per terminal entry of a receipt's
+    /// `lock_status_changes`.
+    pub fn terminal_lock_changes(changes: &[(TxId, TimeLockStatus)], at: Timestamp) -> Vec<Self> {
+        changes
+            .iter()
+            .filter(|(_, status)| status.is_terminal())
+            .map(|(lock_id, status)| P2pMessage::LockStatusChange {
+                lock_id: lock_id.clone(),
+                new_status: status.name().to_string(),
+                at,
+            })
+            .collect()
+    }
+
+    /// Compress a large `NewVertex` into `NewVertexCompressed`.
+    /// Other messages, small payloads, and payloads that do not shrink are returned unchanged.
+    pub fn compress(self, level: i32) -> Self {
+        match self {
+            P2pMessage::NewVertex { payload } if payload.len() > GOSSIP_COMPRESS_THRESHOLD => {
+                match zstd::encode_all(payload.as_slice(), level) {
+                    Ok(compressed) if compressed.len() < payload.len() => {
+                        P2pMessage::NewVertexCompressed {
+                            payload: compressed,
+                            original_len: payload.len() as u32,
+                        }
+                    }
+                    _ => P2pMessage::NewVertex { payload },
+                }
+            }
+            other => other,
+        }
+    }
+
+    /// Expand a `NewVertexCompressed` back into `NewVertex`.
+    /// Fails on malformed data, an oversized `original_len`, or a length mismatch.
+    pub fn decompress(self) -> std::io::Result<Self> {
+        match self {
+            P2pMessage::NewVertexCompressed { payload, original_len } => {
+                if original_len > GOSSIP_MAX_DECOMPRESSED_LEN {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        format!("compressed vertex claims {original_len} bytes"),
+                    ));
+                }
+                let data = zstd::bulk::decompress(&payload, original_len as usize)?;
+                if data.len() != original_len as usize {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        "decompressed length does not match original_len",
+                    ));
+                }
+                Ok(P2pMessage::NewVertex { payload: data })
+            }
+            other => Ok(other),
+        }
+    }
+
+    /// Split a `VertexBatch` into one `NewVertex` per payload; any other
+    /// message is returned alone. Fails if `count` disagrees with the payloads
+    /// or exceeds `GOSSIP_MAX_BATCH_LEN`.
+    pub fn decompose(self) -> std::io::Result<Vec<Self>> {
+        match self {
+            P2pMessage::VertexBatch { payloads, count } => {
+                if count > GOSSIP_MAX_BATCH_LEN || count as usize != payloads.len() {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        format!("vertex batch claims {count} payloads, carries {}", payloads.len()),
+                    ));
+                }
+                Ok(payloads.into_iter().map(|payload| P2pMessage::NewVertex { payload }).collect())
+            }
+            other => Ok(vec![other]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Repetitive bytes resembling a bincode transaction with embedded certificates.
+    fn large_payload() -> Vec<u8> {
+        (0..16 * 1024u32)
+            .flat_map(|i| [(i % 7) as u8, 0, 0, 0, b'c', b'e', b'r', b't'])
+            .collect()
+    }
+
+    #[test]
+    fn large_vertex_round_trips_through_compression() {
+        let payload = large_payload();
+        let msg = P2pMessage::NewVertex { payload: payload.clone() }.compress(3);
+        let wire = match &msg {
+            P2pMessage::NewVertexCompressed { payload: c, original_len } => {
+                assert_eq!(*original_len as usize, payload.len());
+                assert!(c.len() * 10 < payload.len() * 4, "expected >60% reduction");
+                msg.to_bytes()
+            }
+            other => panic!("expected compressed variant, got {other:?}"),
+        };
+
+        let received = P2pMessage::from_bytes(&wire).unwrap().decompress().unwrap();
+        match received {
+            P2pMessage::NewVertex { payload: p } => assert_eq!(p, payload),
+            other => panic!("expected NewVertex, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn small_vertex_is_not_compressed() {
+        let msg = P2pMessage::NewVertex { payload: vec![1u8; GOSSIP_COMPRESS_THRESHOLD] }.compress(3);
+        assert!(matches!(msg, P2pMessage::NewVertex { .. }));
+    }
+
+    #[test]
+    fn malformed_compressed_payload_rejected() {
+        let garbage = P2pMessage::NewVertexCompressed {
+            payload: vec![0xde, 0xad, 0xbe, 0xef],
+            original_len: 100,
+        };
+        assert!(garbage.decompress().is_err());
+
+        let bomb = P2pMessage::NewVertexCompressed {
+            payload: zstd::encode_all(&[0u8; 64][..], 3).unwrap(),
+            original_len: GOSSIP_MAX_DECOMPRESSED_LEN + 1,
+        };
+        assert!(bomb.decompress().is_err());
+
+        let lying = P2pMessage::NewVertexCompressed {
+            payload: zstd::encode_all(&[0u8; 64][..], 3).unwrap(),
+            original_len: 128,
+        };
+        assert!(lying.decompress().is_err());
+    }
+
+    #[test]
+    fn vertex_batch_decomposes_in_order() {
+        let payloads: Vec<Vec<u8>> = (0..10u8).map(|i| vec![i; 300]).collect();
+        let batch = P2pMessage::VertexBatch { payloads: payloads.clone(), count: 10 };
+        let parts = P2pMessage::from_bytes(&batch.to_bytes()).unwrap().decompose().unwrap();
+        assert_eq!(parts.len(), 10);
+        for (part, expected) in parts.iter().zip(&payloads) {
+            assert!(matches!(part, P2pMessage::NewVertex { payload } if payload == expected));
+        }
+
+        let single = P2pMessage::RequestTips.decompose().unwrap();
+        assert!(matches!(single.as_slice(), [P2pMessage::RequestTips]));
+    }
+
+    #[test]
+    fn malformed_vertex_batch_rejected() {
+        let short = P2pMessage::VertexBatch { payloads: vec![vec![1], vec![2]], count: 3 };
+        assert!(short.decompose().is_err());
+
+        let oversized = P2pMessage::VertexBatch {
+            payloads: vec![vec![0]; GOSSIP_MAX_BATCH_LEN as usize + 1],
+            count: GOSSIP_MAX_BATCH_LEN + 1,
+        };
+        assert!(oversized.decompose().is_err());
+    }
+
+    /// Gossip bytes for ten typical vertices sent one per message versus in
+    /// one batch. Each GossipSub message also carries its author signature,
+    /// key, sequence number and topic (about 150 bytes), and costs one id in
+    /// every IHAVE/IWANT exchange.
+    #[test]
+    fn batching_reduces_gossip_bandwidth() {
+        const PER_MESSAGE_OVERHEAD: usize = 150;
+        let payloads: Vec<Vec<u8>> = (0..10u8).map(|i| vec![i; 400]).collect();
+        let separate: usize = payloads
+            .iter()
+            .map(|p| P2pMessage::NewVertex { payload: p.clone() }.to_bytes().len() + PER_MESSAGE_OVERHEAD)
+            .sum();
+        let batched = P2pMessage::VertexBatch { payloads, count: 10 }.to_bytes().len() + PER_MESSAGE_OVERHEAD;
+        let overhead_separate = separate - 10 * 400;
+        let overhead_batched = batched - 10 * 400;
+        assert!(
+            overhead_batched * 10 < overhead_separate * 3,
+            "expected >70% less framing: {overhead_batched} vs {overhead_separate} bytes"
+        );
+    }
 }
@@ -1,8 +1,9 @@
 use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 use std::hash::{Hash, Hasher};
-use std::time::Duration;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use futures::StreamExt;
 use libp2p::{
@@ -14,7 +15,8 @@ use tokio::sync::mpsc;
 use tracing::{debug, info, warn};
 
 use crate::config::P2pConfig;
-use crate::message::P2pMessage;
+use crate::message::{P2pMessage, GOSSIP_COMPRESS_THRESHOLD, GOSSIP_MAX_BATCH_LEN};
+use crate::peers::{Direction, PeerTracker};
 
 /// Combined libp2p network behaviour for ChronX.
 ///
@@ -28,25 +30,102 @@ pub struct ChronxBehaviour {
     pub ping: ping::Behaviour,
 }
 
+/// Snapshot of the node's view of the P2P network.
+///
+/// Refreshed by the swarm event loop (on connection changes and once a
+/// second) so readers never need to touch the swarm directly.
+#[derive(Debug, Clone, Default)]
+pub struct P2pStats {
+    /// Peer IDs with at least one open connection.
+    pub connected_peers: Vec<String>,
+    /// Number of entries across all Kademlia k-buckets.
+    pub kademlia_routing_table_size: u32,
+    /// Peer IDs in our GossipSub mesh for the vertex topic.
+    pub gossipsub_mesh_peers: Vec<String>,
+    /// Bootstrap multiaddrs from config, plus any added on reload.
+    pub bootstrap_peers: Vec<String>,
+    /// Identify protocol version string.
+    pub protocol_version: String,
+    /// Vertex gossip topic; nodes only exchange vertices within the same one.
+    pub network_id: String,
+    /// Connected peers we dialed.
+    pub outbound_peers: usize,
+    /// Connected peers that dialed us.
+    pub inbound_peers: usize,
+    /// Peers disconnected to stay within the connection limits since startup.
+    pub peers_evicted: u64,
+    /// Unsigned gossip messages dropped since startup.
+    pub unsigned_messages_rejected: u64,
+    /// GossipSub messages published since startup; a `VertexBatch` counts once.
+    pub gossip_messages_published: u64,
+    /// This node's peer ID.
+    pub local_peer_id: String,
+    /// One entry per connected peer, sorted by peer ID.
+    pub connections: Vec<PeerConnection>,
+}
+
+/// A connected peer as seen from this node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PeerConnection {
+    pub peer_id: String,
+    /// Remote address of the first open connection to the peer.
+    pub multiaddr: String,
+    pub direction: Direction,
+    /// Unix time that connection was established.
+    pub connected_since: i64,
+    /// Whether the peer is in our GossipSub mesh for the vertex topic.
+    pub gossipsub_in_mesh: bool,
+    /// Primary reputation input from `PeerRecord::reputation`: routing-table
+    /// membership for outbound peers, net valid gossip for inbound ones.
+    pub reputation_score: i32,
+}
+
 /// Application-facing handle returned from `P2pNetwork::new()`.
 pub struct P2pHandle {
     /// Send here to broadcast a message to all gossip peers.
     pub outbound_tx: mpsc::Sender<P2pMessage>,
     /// Receive here to consume messages arriving from peers.
     pub inbound_rx: mpsc::Receiver<P2pMessage>,
+    /// Send bootstrap multiaddrs here to dial them at runtime (config reload).
+    pub bootstrap_tx: mpsc::Sender<Vec<String>>,
     /// Local libp2p peer identity.
     pub local_peer_id: PeerId,
     /// Shared counter of currently connected peers.
     pub peer_count: Arc<AtomicU64>,
+    /// Network topology snapshot, updated by the event loop.
+    pub stats: Arc<RwLock<P2pStats>>,
+}
+
+impl P2pHandle {
+    /// Current network topology snapshot.
+    pub fn get_stats(&self) -> P2pStats {
+        self.stats.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
 }
 
 /// Owns the libp2p Swarm. Pass to `tokio::spawn(network.run())`.
 pub struct P2pNetwork {
     swarm: Swarm<ChronxBehaviour>,
     topic: gossipsub::IdentTopic,
+    priority_topic: gossipsub::IdentTopic,
     outbound_rx: mpsc::Receiver<P2pMessage>,
     inbound_tx: mpsc::Sender<P2pMessage>,
+    bootstrap_rx: mpsc::Receiver<Vec<String>>,
     peer_count: Arc<AtomicU64>,
+    stats: Arc<RwLock<P2pStats>>,
+    /// zstd level for large vertex payloads; `None` disables compression.
+    compression_level: Option<i32>,
+    /// Connected peers and their reputation inputs.
+    peers: PeerTracker,
+    max_outbound_peers: usize,
+    max_inbound_peers: usize,
+    gossip_require_signed: bool,
+    /// Outbound vertex payloads waiting to be published as one batch.
+    batch: Vec<Vec<u8>>,
+    /// When the pending batch must be published, if one is open.
+    batch_deadline: Option<tokio::time::Instant>,
+    batch_window: Duration,
+    batch_max_size: usize,
 }
 
 impl P2pNetwork {
@@ -55,6 +134,7 @@ impl P2pNetwork {
         config: &P2pConfig,
     ) -> Result<(Self, P2pHandle), Box<dyn std::error::Error + Send + Sync>> {
         let topic = gossipsub::IdentTopic::new(&config.vertex_topic);
+        let priority_topic = gossipsub::IdentTopic::new(&config.priority_topic);
 
         // Load or generate a persistent identity keypair.
         let keypair = load_or_generate_identity(&config.identity_file)?;
@@ -73,9 +153,13 @@ impl P2pNetwork {
                     gossipsub::MessageId::from(s.finish().to_string())
                 };
 
+                // Permissive still verifies any signature present, and that
+                // the signing key belongs to `source`; unsigned messages are
+                // let through so the event loop can reject them and penalize
+                // the peer that forwarded them.
                 let gossipsub_config = gossipsub::ConfigBuilder::default()
                     .heartbeat_interval(Duration::from_secs(1))
-                    .validation_mode(gossipsub::ValidationMode::Strict)
+                    .validation_mode(gossipsub::ValidationMode::Permissive)
                     .message_id_fn(message_id_fn)
                     .mesh_n(2)
                     .mesh_n_low(1)
@@ -111,59 +195,82 @@ impl P2pNetwork {
             .build();
 
         swarm.behaviour_mut().gossipsub.subscribe(&topic)?;
+        swarm.behaviour_mut().gossipsub.subscribe(&priority_topic)?;
 
         let listen_addr: Multiaddr = config.listen_addr.parse()?;
         swarm.listen_on(listen_addr)?;
 
-        for addr_str in &config.bootstrap_peers {
-            if let Ok(addr) = addr_str.parse::<Multiaddr>() {
-                if let Some(libp2p::multiaddr::Protocol::P2p(peer_id)) = addr.iter().last() {
-                    swarm
-                        .behaviour_mut()
-                        .kademlia
-                        .add_address(&peer_id, addr.clone());
-                    // Also dial immediately so the TCP connection is established
-                    // as soon as the swarm event loop starts.
-                    let _ = swarm.dial(addr.clone());
-                    debug!(peer = %peer_id, "added bootstrap peer, dialing");
-                }
-            }
-        }
+        add_bootstrap_peers(&mut swarm, &config.bootstrap_peers);
 
         let local_peer_id = *swarm.local_peer_id();
         let (outbound_tx, outbound_rx) = mpsc::channel(256);
         let (inbound_tx, inbound_rx) = mpsc::channel(256);
+        let (bootstrap_tx, bootstrap_rx) = mpsc::channel(8);
+        let stats = Arc::new(RwLock::new(P2pStats {
+            bootstrap_peers: config.bootstrap_peers.clone(),
+            protocol_version: config.protocol_version.clone(),
+            network_id: config.vertex_topic.clone(),
+            local_peer_id: swarm.local_peer_id().to_string(),
+            ..P2pStats::default()
+        }));
         let peer_count = Arc::new(AtomicU64::new(0));
 
         let network = P2pNetwork {
             swarm,
             topic,
+            priority_topic,
             outbound_rx,
             inbound_tx,
+            bootstrap_rx,
             peer_count: Arc::clone(&peer_count),
+            stats: Arc::clone(&stats),
+            compression_level: config.compression_enabled.then_some(config.compression_level),
+            peers: PeerTracker::default(),
+            max_outbound_peers: config.max_outbound_peers,
+            max_inbound_peers: config.max_inbound_peers,
+            gossip_require_signed: config.gossip_require_signed,
+            batch: Vec::new(),
+            batch_deadline: None,
+            batch_window: Duration::from_millis(config.batch_window_ms),
+            batch_max_size: config.batch_max_size.min(GOSSIP_MAX_BATCH_LEN as usize),
         };
         let handle = P2pHandle {
             outbound_tx,
             inbound_rx,
+            bootstrap_tx,
             local_peer_id,
             peer_count,
+            stats,
         };
 
         Ok((network, handle))
     }
 
+    /// `(outbound, inbound)` connected peer counts.
+    pub fn peer_connection_count(&self) -> (usize, usize) {
+        self.peers.counts()
+    }
+
     /// Drive the P2P event loop. Run in a dedicated tokio task.
     pub async fn run(mut self) {
+        let mut stats_tick = tokio::time::interval(Duration::from_secs(1));
         loop {
             tokio::select! {
-                Some(msg) = self.outbound_rx.recv() => {
-                    let data = msg.to_bytes();
-                    if let Err(e) = self.swarm
-                        .behaviour_mut()
-                        .gossipsub
-                        .publish(self.topic.clone(), data)
-                    {
-                        warn!(error = %e, "gossipsub publish failed");
+                _ = stats_tick.tick() => self.refresh_stats(),
+
+                Some(msg) = self.outbound_rx.recv() => self.send(msg),
+
+                _ = tokio::time::sleep_until(self.batch_deadline.unwrap_or_else(tokio::time::Instant::now)),
+                    if self.batch_deadline.is_some() => self.flush_batch(),
+
+                Some(peers) = self.bootstrap_rx.recv() => {
+                    info!(count = peers.len(), "dialing bootstrap peers from reloaded config");
+                    add_bootstrap_peers(&mut self.swarm, &peers);
+                    let mut stats = self.stats.write().unwrap_or_else(|e| e.into_inner());
+                    for peer in peers {
+                        if !stats.bootstrap_peers.contains(&peer) {
+                            stats.bootstrap_peers.push(peer);
+                        }
                     }
                 }
 
@@ -173,10 +280,25 @@ impl P2pNetwork {
                             info!(addr = %address, "P2P listening on");
                         }
                         SwarmEvent::Behaviour(ChronxBehaviourEvent::Gossipsub(
-                            gossipsub::Event::Message { message, .. },
+                            gossipsub::Event::Message { propagation_source, message, .. },
                         )) => {
-                            match P2pMessage::from_bytes(&message.data) {
-                                Ok(msg) => { let _ = self.inbound_tx.send(msg).await; }
+                            if self.gossip_require_signed && message.source.is_none() {
+                                warn!(peer = %propagation_source, "dropping unsigned gossip message");
+                                self.peers.record_message(&propagation_source, false);
+                                self.stats.write().unwrap_or_else(|e| e.into_inner()).unsigned_messages_rejected += 1;
+                                continue;
+                            }
+                            let decoded = P2pMessage::from_bytes(&message.data)
+                                .map_err(|e| e.to_string())
+                                .and_then(|msg| msg.decompress().map_err(|e| e.to_string()))
+                                .and_then(|msg| msg.decompose().map_err(|e| e.to_string()));
+                            self.peers.record_message(&propagation_source, decoded.is_ok());
+                            match decoded {
+                                Ok(msgs) => {
+                                    for msg in msgs {
+                                        let _ = self.inbound_tx.send(msg).await;
+                                    }
+                                }
                                 Err(e) => debug!(error = %e, "failed to decode gossip message"),
                             }
                         }
@@ -190,13 +312,30 @@ impl P2pNetwork {
                                     .add_address(&peer_id, addr);
                             }
                         }
-                        SwarmEvent::ConnectionEstablished { peer_id, .. } => {
+                        SwarmEvent::ConnectionEstablished { peer_id, endpoint, .. } => {
                             self.peer_count.fetch_add(1, Ordering::Relaxed);
                             debug!(peer = %peer_id, "connection established");
+                            let direction = if endpoint.is_dialer() {
+                                Direction::Outbound
+                            } else {
+                                Direction::Inbound
+                            };
+                            self.peers.connected(
+                                peer_id,
+                                direction,
+                                endpoint.get_remote_address().clone(),
+                                Instant::now(),
+                            );
+                            self.enforce_connection_limit(direction);
+                            self.refresh_stats();
                         }
-                        SwarmEvent::ConnectionClosed { peer_id, .. } => {
+                        SwarmEvent::ConnectionClosed { peer_id, num_established, .. } => {
                             self.peer_count.fetch_sub(1, Ordering::Relaxed);
                             debug!(peer = %peer_id, "connection closed");
+                            if num_established == 0 {
+                                self.peers.disconnected(&peer_id);
+                            }
+                            self.refresh_stats();
                         }
                         _ => {}
                     }
@@ -204,6 +343,143 @@ impl P2pNetwork {
             }
         }
     }
+
+    /// Queue small vertices into the pending batch and publish everything
+    /// else at once. A vertex that cannot be batched flushes the batch first
+    /// so peers see vertices in the order they were sent.
+    fn send(&mut self, msg: P2pMessage) {
+        match msg {
+            P2pMessage::NewVertex { payload }
+                if self.batch_max_size > 1 && payload.len() <= GOSSIP_COMPRESS_THRESHOLD =>
+            {
+                self.batch.push(payload);
+                if self.batch.len() >= self.batch_max_size {
+                    self.flush_batch();
+                } else if self.batch_deadline.is_none() {
+                    self.batch_deadline = Some(tokio::time::Instant::now() + self.batch_window);
+                }
+            }
+            msg @ P2pMessage::NewVertex { .. } => {
+                self.flush_batch();
+                self.publish(msg);
+            }
+            msg => self.publish(msg),
+        }
+    }
+
+    /// Publish the pending batch: a lone vertex as `NewVertex`, several as a
+    /// `VertexBatch`.
+    fn flush_batch(&mut self) {
+        self.batch_deadline = None;
+        let mut payloads = std::mem::take(&mut self.batch);
+        match payloads.len() {
+            0 => {}
+            1 => self.publish(P2pMessage::NewVertex { payload: payloads.remove(0) }),
+            n => self.publish(P2pMessage::VertexBatch { payloads, count: n as u32 }),
+        }
+    }
+
+    fn publish(&mut self, msg: P2pMessage) {
+        let msg = match self.compression_level {
+            Some(level) => msg.compress(level),
+            None => msg,
+        };
+        let topic = if msg.is_priority() {
+            self.priority_topic.clone()
+        } else {
+            self.topic.clone()
+        };
+        match self.swarm.behaviour_mut().gossipsub.publish(topic, msg.to_bytes()) {
+            Ok(_) => self.stats.write().unwrap_or_else(|e| e.into_inner()).gossip_messages_published += 1,
+            Err(e) => warn!(error = %e, "gossipsub publish failed"),
+        }
+    }
+
+    /// Disconnect the lowest-reputation peer in `direction` if that side is
+    /// over its limit.
+    fn enforce_connection_limit(&mut self, direction: Direction) {
+        let limit = match direction {
+            Direction::Outbound => self.max_outbound_peers,
+            Direction::Inbound => self.max_inbound_peers,
+        };
+        if direction == Direction::Outbound {
+            let routed: HashSet<PeerId> = self
+                .swarm
+                .behaviour_mut()
+                .kademlia
+                .kbuckets()
+                .flat_map(|b| b.iter().map(|e| *e.node.key.preimage()).collect::<Vec<_>>())
+                .collect();
+            let connected: Vec<PeerId> = self.swarm.connected_peers().copied().collect();
+            for peer in connected {
+                self.peers.set_in_routing_table(&peer, routed.contains(&peer));
+            }
+        }
+        if let Some(victim) = self.peers.eviction_candidate(direction, limit, Instant::now()) {
+            info!(peer = %victim, ?direction, limit, "peer limit exceeded, disconnecting lowest-reputation peer");
+            if self.swarm.disconnect_peer_id(victim).is_ok() {
+                self.stats.write().unwrap_or_else(|e| e.into_inner()).peers_evicted += 1;
+            }
+        }
+    }
+
+    /// Recompute the topology fields of the shared `P2pStats`.
+    fn refresh_stats(&mut self) {
+        let connected_peers = self.swarm.connected_peers().map(|p| p.to_string()).collect();
+        let kademlia_routing_table_size = self
+            .swarm
+            .behaviour_mut()
+            .kademlia
+            .kbuckets()
+            .map(|b| b.num_entries())
+            .sum::<usize>() as u32;
+        let mesh: HashSet<PeerId> = self.swarm.behaviour().gossipsub.mesh_peers(&self.topic.hash()).copied().collect();
+        let gossipsub_mesh_peers = mesh.iter().map(|p| p.to_string()).collect();
+
+        let (outbound_peers, inbound_peers) = self.peers.counts();
+        let now = Instant::now();
+        let unix_now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs() as i64);
+        let mut connections: Vec<PeerConnection> = self
+            .peers
+            .iter()
+            .map(|(peer, record)| PeerConnection {
+                peer_id: peer.to_string(),
+                multiaddr: record.remote_addr.to_string(),
+                direction: record.direction,
+                connected_since: unix_now - now.saturating_duration_since(record.connected_at).as_secs() as i64,
+                gossipsub_in_mesh: mesh.contains(peer),
+                reputation_score: record.reputation(now).0.clamp(i32::MIN as i64, i32::MAX as i64) as i32,
+            })
+            .collect();
+        connections.sort_by(|a, b| a.peer_id.cmp(&b.peer_id));
+
+        let mut stats = self.stats.write().unwrap_or_else(|e| e.into_inner());
+        stats.outbound_peers = outbound_peers;
+        stats.inbound_peers = inbound_peers;
+        stats.connected_peers = connected_peers;
+        stats.kademlia_routing_table_size = kademlia_routing_table_size;
+        stats.gossipsub_mesh_peers = gossipsub_mesh_peers;
+        stats.connections = connections;
+    }
+}
+
+/// Register each `/…/p2p/<PeerId>` multiaddr with Kademlia and dial it.
+/// Addresses without a trailing peer ID are skipped.
+fn add_bootstrap_peers(swarm: &mut Swarm<ChronxBehaviour>, peers: &[String]) {
+    for addr_str in peers {
+        if let Ok(addr) = addr_str.parse::<Multiaddr>() {
+            if let Some(libp2p::multiaddr::Protocol::P2p(peer_id)) = addr.iter().last() {
+                swarm
+                    .behaviour_mut()
+                    .kademlia
+                    .add_address(&peer_id, addr.clone());
+                // Also dial immediately so the TCP connection is established
+                // as soon as the swarm event loop starts.
+                let _ = swarm.dial(addr.clone());
+                debug!(peer = %peer_id, "added bootstrap peer, dialing");
+            }
+        }
+    }
 }
 
 /// Load an Ed25519 keypair from `path` (protobuf-encoded bytes).
@@ -232,3 +508,325 @@ fn load_or_generate_identity(
     info!(file = %p.display(), "generated and saved new P2P identity");
     Ok(kp)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn free_port() -> u16 {
+        std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port()
+    }
+
+    fn local_config(port: u16, bootstrap_peers: Vec<String>) -> P2pConfig {
+        P2pConfig {
+            listen_addr: format!("/ip4/127.0.0.1/tcp/{port}"),
+            bootstrap_peers,
+            ..P2pConfig::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn stats_report_two_node_topology() {
+        let a_port = free_port();
+        let (a_net, a) = P2pNetwork::new(&local_config(a_port, vec![])).unwrap();
+        let a_addr = format!("/ip4/127.0.0.1/tcp/{a_port}/p2p/{}", a.local_peer_id);
+        let (b_net, b) = P2pNetwork::new(&local_config(free_port(), vec![a_addr.clone()])).unwrap();
+        tokio::spawn(a_net.run());
+        tokio::spawn(b_net.run());
+
+        let a_id = a.local_peer_id.to_string();
+        let b_id = b.local_peer_id.to_string();
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(15);
+        loop {
+            let (sa, sb) = (a.get_stats(), b.get_stats());
+            if sa.connected_peers.contains(&b_id)
+                && sb.connected_peers.contains(&a_id)
+                && sb.gossipsub_mesh_peers.contains(&a_id)
+            {
+                assert!(sb.kademlia_routing_table_size >= 1);
+                assert_eq!(sb.bootstrap_peers, vec![a_addr]);
+                assert!(sa.bootstrap_peers.is_empty());
+                assert_eq!(sb.network_id, P2pConfig::default().vertex_topic);
+                break;
+            }
+            assert!(
+                tokio::time::Instant::now() < deadline,
+                "nodes did not see each other: a={sa:?} b={sb:?}"
+            );
+            tokio::time::sleep(Duration::from_millis(200)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn connections_describe_three_node_topology() {
+        let started = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64;
+        let (a_port, b_port) = (free_port(), free_port());
+        let (a_net, a) = P2pNetwork::new(&local_config(a_port, vec![])).unwrap();
+        let a_addr = format!("/ip4/127.0.0.1/tcp/{a_port}/p2p/{}", a.local_peer_id);
+        let (b_net, b) = P2pNetwork::new(&local_config(b_port, vec![a_addr.clone()])).unwrap();
+        let b_addr = format!("/ip4/127.0.0.1/tcp/{b_port}/p2p/{}", b.local_peer_id);
+        // C dials both, so every pair is connected once.
+        let (c_net, c) = P2pNetwork::new(&local_config(free_port(), vec![a_addr, b_addr])).unwrap();
+        tokio::spawn(a_net.run());
+        tokio::spawn(b_net.run());
+        tokio::spawn(c_net.run());
+
+        let (a_id, b_id, c_id) =
+            (a.local_peer_id.to_string(), b.local_peer_id.to_string(), c.local_peer_id.to_string());
+        let view = |stats: &P2pStats| -> Vec<(String, Direction)> {
+            stats.connections.iter().map(|p| (p.peer_id.clone(), p.direction)).collect()
+        };
+        let sorted = |mut peers: Vec<(String, Direction)>| {
+            peers.sort_by(|x, y| x.0.cmp(&y.0));
+            peers
+        };
+        let expected = [
+            sorted(vec![(b_id.clone(), Direction::Inbound), (c_id.clone(), Direction::Inbound)]),
+            sorted(vec![(a_id.clone(), Direction::Outbound), (c_id.clone(), Direction::Inbound)]),
+            sorted(vec![(a_id.clone(), Direction::Outbound), (b_id.clone(), Direction::Outbound)]),
+        ];
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(15);
+        loop {
+            let stats = [a.get_stats(), b.get_stats(), c.get_stats()];
+            if stats.iter().map(view).eq(expected.iter().cloned()) {
+                assert_eq!(stats[0].local_peer_id, a_id);
+                let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64;
+                for conn in stats.iter().flat_map(|s| &s.connections) {
+                    assert!((started..=now).contains(&conn.connected_since), "{conn:?}");
+                    assert!(conn.multiaddr.starts_with("/ip4/127.0.0.1/tcp/"), "{conn:?}");
+                }
+                let a_from_c = stats[2].connections.iter().find(|p| p.peer_id == a_id).unwrap();
+                assert!(a_from_c.multiaddr.starts_with(&format!("/ip4/127.0.0.1/tcp/{a_port}")));
+                break;
+            }
+            assert!(
+                tokio::time::Instant::now() < deadline,
+                "unexpected topology: a={:?} b={:?} c={:?}",
+                view(&stats[0]),
+                view(&stats[1]),
+                view(&stats[2])
+            );
+            tokio::time::sleep(Duration::from_millis(200)).await;
+        }
+
+        // Mesh membership is reported per connection once the mesh forms.
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(15);
+        loop {
+            let stats = a.get_stats();
+            let in_mesh: Vec<&String> =
+                stats.connections.iter().filter(|p| p.gossipsub_in_mesh).map(|p| &p.peer_id).collect();
+            if !in_mesh.is_empty() {
+                assert!(in_mesh.iter().all(|p| stats.gossipsub_mesh_peers.contains(p)));
+                break;
+            }
+            assert!(tokio::time::Instant::now() < deadline, "mesh did not form: {stats:?}");
+            tokio::time::sleep(Duration::from_millis(200)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn lock_status_change_propagates_on_priority_topic() {
+        use chronx_core::account::TimeLockStatus;
+        use chronx_core::types::TxId;
+
+        let a_port = free_port();
+        let (a_net, a) = P2pNetwork::new(&local_config(a_port, vec![])).unwrap();
+        let a_addr = format!("/ip4/127.0.0.1/tcp/{a_port}/p2p/{}", a.local_peer_id);
+        let (b_net, mut b) = P2pNetwork::new(&local_config(free_port(), vec![a_addr])).unwrap();
+        tokio::spawn(a_net.run());
+        tokio::spawn(b_net.run());
+
+        // Both topics are subscribed before dialing, so once B is in A's
+        // vertex mesh A also knows B's priority subscription.
+        let b_id = b.local_peer_id.to_string();
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(15);
+        while !a.get_stats().gossipsub_mesh_peers.contains(&b_id) {
+            assert!(tokio::time::Instant::now() < deadline, "mesh did not form");
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+
+        // A claim and a non-terminal change in the same receipt: only the
+        // claim is announced.
+        let lock_id = TxId::from_bytes([7u8; 32]);
+        let changes = vec![
+            (lock_id.clone(), TimeLockStatus::Claimed { claimed_at: 1_000 }),
+            (TxId::from_bytes([8u8; 32]), TimeLockStatus::Pending),
+        ];
+        let notices = P2pMessage::terminal_lock_changes(&changes, 1_000);
+        assert_eq!(notices.len(), 1);
+        assert!(notices[0].is_priority());
+
+        a.outbound_tx.send(notices[0].clone()).await.unwrap();
+        let received = tokio::time::timeout(Duration::from_millis(100), b.inbound_rx.recv())
+            .await
+            .expect("notice not delivered within 100ms")
+            .unwrap();
+        match received {
+            P2pMessage::LockStatusChange { lock_id: id, new_status, at } => {
+                assert_eq!(id, lock_id);
+                assert_eq!(new_status, "Claimed");
+                assert_eq!(at, 1_000);
+            }
+            other => panic!("expected LockStatusChange, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn inbound_limit_evicts_newcomer_and_keeps_forwarding_peer() {
+        let a_port = free_port();
+        let (a_net, mut a) = P2pNetwork::new(&P2pConfig {
+            max_inbound_peers: 1,
+            ..local_config(a_port, vec![])
+        })
+        .unwrap();
+        let a_addr = format!("/ip4/127.0.0.1/tcp/{a_port}/p2p/{}", a.local_peer_id);
+        let (b_net, b) = P2pNetwork::new(&local_config(free_port(), vec![a_addr.clone()])).unwrap();
+        tokio::spawn(a_net.run());
+        tokio::spawn(b_net.run());
+
+        // B earns reputation by forwarding a valid message to A.
+        let b_id = b.local_peer_id.to_string();
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(15);
+        while !a.get_stats().gossipsub_mesh_peers.contains(&b_id) {
+            assert!(tokio::time::Instant::now() < deadline, "mesh did not form");
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        b.outbound_tx.send(P2pMessage::RequestTips).await.unwrap();
+        tokio::time::timeout(Duration::from_secs(5), a.inbound_rx.recv())
+            .await
+            .expect("message from B not delivered")
+            .unwrap();
+
+        let (c_net, c) = P2pNetwork::new(&local_config(free_port(), vec![a_addr])).unwrap();
+        tokio::spawn(c_net.run());
+        let c_id = c.local_peer_id.to_string();
+
+        // C's connection pushes A over its inbound limit and is dropped.
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(15);
+        loop {
+            let stats = a.get_stats();
+            if stats.peers_evicted == 1 && stats.connected_peers == vec![b_id.clone()] {
+                assert_eq!(stats.inbound_peers, 1);
+                assert_eq!(stats.outbound_peers, 0);
+                break;
+            }
+            assert!(tokio::time::Instant::now() < deadline, "limit not enforced: a={stats:?}");
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        assert!(!a.get_stats().connected_peers.contains(&c_id));
+    }
+
+    #[tokio::test]
+    async fn vertices_sent_together_are_gossiped_as_one_batch() {
+        let a_port = free_port();
+        let (a_net, mut a) = P2pNetwork::new(&local_config(a_port, vec![])).unwrap();
+        let a_addr = format!("/ip4/127.0.0.1/tcp/{a_port}/p2p/{}", a.local_peer_id);
+        let (b_net, b) = P2pNetwork::new(&P2pConfig {
+            batch_window_ms: 1_000,
+            ..local_config(free_port(), vec![a_addr])
+        })
+        .unwrap();
+        tokio::spawn(a_net.run());
+        tokio::spawn(b_net.run());
+
+        let b_id = b.local_peer_id.to_string();
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(15);
+        while !a.get_stats().gossipsub_mesh_peers.contains(&b_id) {
+            assert!(tokio::time::Instant::now() < deadline, "mesh did not form");
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+
+        // Ten vertices fill a batch; the eleventh waits out the window and
+        // goes alone.
+        let batch_max_size = P2pConfig::default().batch_max_size;
+        for i in 0..=batch_max_size {
+            b.outbound_tx.send(P2pMessage::NewVertex { payload: vec![i as u8; 200] }).await.unwrap();
+        }
+        for i in 0..=batch_max_size {
+            let received = tokio::time::timeout(Duration::from_secs(5), a.inbound_rx.recv())
+                .await
+                .expect("vertex not delivered")
+                .unwrap();
+            assert!(matches!(received, P2pMessage::NewVertex { payload } if payload == vec![i as u8; 200]));
+        }
+        assert_eq!(b.get_stats().gossip_messages_published, 2);
+    }
+
+    /// Dial `target` with a bare gossipsub node that publishes without an
+    /// author signature, and publish `data` on the vertex topic once the
+    /// target is reachable.
+    fn spawn_unsigned_publisher(target: Multiaddr, data: Vec<u8>) {
+        let mut swarm = libp2p::SwarmBuilder::with_new_identity()
+            .with_tokio()
+            .with_tcp(tcp::Config::default(), noise::Config::new, yamux::Config::default)
+            .unwrap()
+            .with_behaviour(|_| {
+                let config = gossipsub::ConfigBuilder::default()
+                    .validation_mode(gossipsub::ValidationMode::Anonymous)
+                    .message_id_fn(|msg: &gossipsub::Message| {
+                        let mut s = DefaultHasher::new();
+                        msg.data.hash(&mut s);
+                        gossipsub::MessageId::from(s.finish().to_string())
+                    })
+                    .build()
+                    .unwrap();
+                gossipsub::Behaviour::<gossipsub::IdentityTransform>::new(gossipsub::MessageAuthenticity::Anonymous, config)
+                    .unwrap()
+            })
+            .unwrap()
+            .build();
+        let topic = gossipsub::IdentTopic::new(P2pConfig::default().vertex_topic);
+        swarm.behaviour_mut().subscribe(&topic).unwrap();
+        swarm.dial(target).unwrap();
+        // The target's subscription can arrive before gossipsub treats it as
+        // a publish peer, so keep trying until the message goes out once.
+        tokio::spawn(async move {
+            let mut retry = tokio::time::interval(Duration::from_millis(200));
+            let mut published = false;
+            loop {
+                tokio::select! {
+                    _ = swarm.select_next_some() => {}
+                    _ = retry.tick(), if !published => {
+                        published = swarm.behaviour_mut().publish(topic.clone(), data.clone()).is_ok();
+                    }
+                }
+            }
+        });
+    }
+
+    #[tokio::test]
+    async fn unsigned_gossip_is_rejected_unless_allowed() {
+        let mut nodes = Vec::new();
+        for require_signed in [true, false] {
+            let port = free_port();
+            let (net, handle) = P2pNetwork::new(&P2pConfig {
+                gossip_require_signed: require_signed,
+                ..local_config(port, vec![])
+            })
+            .unwrap();
+            tokio::spawn(net.run());
+            let addr: Multiaddr = format!("/ip4/127.0.0.1/tcp/{port}/p2p/{}", handle.local_peer_id).parse().unwrap();
+            spawn_unsigned_publisher(addr, P2pMessage::RequestTips.to_bytes());
+            nodes.push(handle);
+        }
+        let (mut strict, mut lenient) = (nodes.remove(0), nodes.remove(0));
+
+        let received = tokio::time::timeout(Duration::from_secs(10), lenient.inbound_rx.recv())
+            .await
+            .expect("unsigned message not delivered to a lenient node")
+            .unwrap();
+        assert!(matches!(received, P2pMessage::RequestTips));
+
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(10);
+        while strict.get_stats().unsigned_messages_rejected == 0 {
+            assert!(tokio::time::Instant::now() < deadline, "unsigned message not rejected");
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        assert!(strict.inbound_rx.try_recv().is_err());
+        assert_eq!(lenient.get_stats().unsigned_messages_rejected, 0);
+    }
+}
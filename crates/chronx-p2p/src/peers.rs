@@ -0,0 +1,186 @@
+//! Connection bookkeeping and reputation-based eviction.
+//!
+//! The swarm event loop records every connected peer here. When a new
+//! connection pushes the inbound or outbound count past its limit, the
+//! lowest-reputation peer in that direction is disconnected to make room.
+//!
+//! Reputation depends on direction:
+//! - outbound peers are ranked by Kademlia routing-table membership, then uptime;
+//! - inbound peers are ranked by valid gossip forwarded (minus undecodable
+//!   messages), then uptime.
+//!
+//! Ties go against the most recently connected peer.
+
+use std::collections::HashMap;
+use std::time::Instant;
+
+use libp2p::{Multiaddr, PeerId};
+
+/// Which side opened the connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// We dialed the peer.
+    Outbound,
+    /// The peer dialed us.
+    Inbound,
+}
+
+/// What the event loop knows about one connected peer.
+#[derive(Debug, Clone)]
+pub struct PeerRecord {
+    pub direction: Direction,
+    /// Remote address of the first connection.
+    pub remote_addr: Multiaddr,
+    pub connected_at: Instant,
+    /// Gossip messages from this peer that decoded cleanly.
+    pub valid_messages: u64,
+    /// Gossip messages from this peer that failed to decode.
+    pub invalid_messages: u64,
+    /// Whether the peer is in our Kademlia routing table.
+    pub in_routing_table: bool,
+}
+
+impl PeerRecord {
+    /// Reputation score; higher is better. Comparable only between peers of
+    /// the same direction.
+    pub fn reputation(&self, now: Instant) -> (i64, u64) {
+        let uptime = now.saturating_duration_since(self.connected_at).as_millis() as u64;
+        let primary = match self.direction {
+            Direction::Outbound => self.in_routing_table as i64,
+            Direction::Inbound => self.valid_messages as i64 - self.invalid_messages as i64,
+        };
+        (primary, uptime)
+    }
+}
+
+/// Connected peers, keyed by peer id.
+#[derive(Debug, Default)]
+pub struct PeerTracker {
+    peers: HashMap<PeerId, PeerRecord>,
+}
+
+impl PeerTracker {
+    /// Record a newly connected peer. A peer that is already tracked keeps
+    /// its original record.
+    pub fn connected(&mut self, peer: PeerId, direction: Direction, remote_addr: Multiaddr, now: Instant) {
+        self.peers.entry(peer).or_insert(PeerRecord {
+            direction,
+            remote_addr,
+            connected_at: now,
+            valid_messages: 0,
+            invalid_messages: 0,
+            in_routing_table: false,
+        });
+    }
+
+    /// Forget a peer whose last connection closed.
+    pub fn disconnected(&mut self, peer: &PeerId) {
+        self.peers.remove(peer);
+    }
+
+    /// Count a gossip message forwarded by `peer`.
+    pub fn record_message(&mut self, peer: &PeerId, valid: bool) {
+        if let Some(record) = self.peers.get_mut(peer) {
+            if valid {
+                record.valid_messages += 1;
+            } else {
+                record.invalid_messages += 1;
+            }
+        }
+    }
+
+    /// Mark whether `peer` is in the Kademlia routing table.
+    pub fn set_in_routing_table(&mut self, peer: &PeerId, in_table: bool) {
+        if let Some(record) = self.peers.get_mut(peer) {
+            record.in_routing_table = in_table;
+        }
+    }
+
+    pub fn get(&self, peer: &PeerId) -> Option<&PeerRecord> {
+        self.peers.get(peer)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&PeerId, &PeerRecord)> {
+        self.peers.iter()
+    }
+
+    /// `(outbound, inbound)` connected peer counts.
+    pub fn counts(&self) -> (usize, usize) {
+        let outbound = self
+            .peers
+            .values()
+            .filter(|r| r.direction == Direction::Outbound)
+            .count();
+        (outbound, self.peers.len() - outbound)
+    }
+
+    /// The peer to disconnect when `direction` holds more than `limit` peers:
+    /// the one with the lowest reputation, newest first on ties.
+    pub fn eviction_candidate(&self, direction: Direction, limit: usize, now: Instant) -> Option<PeerId> {
+        let (outbound, inbound) = self.counts();
+        let count = match direction {
+            Direction::Outbound => outbound,
+            Direction::Inbound => inbound,
+        };
+        if count <= limit {
+            return None;
+        }
+        self.peers
+            .iter()
+            .filter(|(_, r)| r.direction == direction)
+            .min_by_key(|(_, r)| (r.reputation(now), std::cmp::Reverse(r.connected_at)))
+            .map(|(peer, _)| *peer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn over_limit_evicts_lowest_reputation_inbound_peer() {
+        let start = Instant::now();
+        let mut tracker = PeerTracker::default();
+        let (trusted, quiet, noisy) = (PeerId::random(), PeerId::random(), PeerId::random());
+        tracker.connected(trusted, Direction::Inbound, Multiaddr::empty(), start);
+        tracker.connected(quiet, Direction::Inbound, Multiaddr::empty(), start + Duration::from_secs(1));
+        tracker.connected(noisy, Direction::Inbound, Multiaddr::empty(), start + Duration::from_secs(2));
+        for _ in 0..5 {
+            tracker.record_message(&trusted, true);
+        }
+        tracker.record_message(&noisy, true);
+        tracker.record_message(&noisy, false);
+        tracker.record_message(&noisy, false);
+
+        let now = start + Duration::from_secs(10);
+        assert_eq!(tracker.eviction_candidate(Direction::Inbound, 3, now), None);
+        assert_eq!(tracker.eviction_candidate(Direction::Inbound, 2, now), Some(noisy));
+
+        tracker.disconnected(&noisy);
+        // Equal reputation: the newer of the two quiet peers goes.
+        let newcomer = PeerId::random();
+        tracker.connected(newcomer, Direction::Inbound, Multiaddr::empty(), now);
+        assert_eq!(tracker.eviction_candidate(Direction::Inbound, 2, now), Some(newcomer));
+        assert!(tracker.get(&trusted).is_some());
+    }
+
+    #[test]
+    fn outbound_prefers_routing_table_then_uptime() {
+        let start = Instant::now();
+        let mut tracker = PeerTracker::default();
+        let (veteran, routed, fresh) = (PeerId::random(), PeerId::random(), PeerId::random());
+        tracker.connected(veteran, Direction::Outbound, Multiaddr::empty(), start);
+        tracker.connected(routed, Direction::Outbound, Multiaddr::empty(), start + Duration::from_secs(5));
+        tracker.connected(fresh, Direction::Outbound, Multiaddr::empty(), start + Duration::from_secs(8));
+        tracker.connected(PeerId::random(), Direction::Inbound, Multiaddr::empty(), start);
+        tracker.set_in_routing_table(&veteran, true);
+        tracker.set_in_routing_table(&routed, true);
+
+        let now = start + Duration::from_secs(10);
+        assert_eq!(tracker.counts(), (3, 1));
+        assert_eq!(tracker.eviction_candidate(Direction::Outbound, 2, now), Some(fresh));
+        tracker.disconnected(&fresh);
+        assert_eq!(tracker.eviction_candidate(Direction::Outbound, 1, now), Some(routed));
+    }
+}
@@ -1,13 +1,15 @@
 use chronx_core::constants::MIN_VERIFIER_STAKE_CHRONOS;
 use chronx_core::error::ChronxError;
 use chronx_core::types::AccountId;
+use chronx_state::db::VerifierRegistration;
 use chronx_state::StateDb;
 
 /// Read-only view over verifier registry state stored in the `StateDb`.
 ///
 /// The canonical source of verifier registration is the `Account` struct
-/// (`is_verifier`, `verifier_stake`). This struct provides convenience
-/// methods for querying that state without going through the full engine.
+/// (`is_verifier`, `verifier_stake`); the engine mirrors it into the
+/// `verifier_registrations` tree, which [`Self::list_eligible`] reads so it
+/// need not scan every account.
 pub struct VerifierRegistry<'a> {
     db: &'a StateDb,
 }
@@ -41,6 +43,12 @@ impl<'a> VerifierRegistry<'a> {
         Ok(stake >= MIN_VERIFIER_STAKE_CHRONOS)
     }
 
+    /// Registered verifiers meeting the current (possibly governance-raised)
+    /// minimum stake, and so able to vote on recoveries.
+    pub fn list_eligible(&self) -> Result<Vec<VerifierRegistration>, ChronxError> {
+        self.db.get_active_verifiers(self.db.verifier_min_stake())
+    }
+
     /// Returns the minimum verifier stake in Chronos.
     pub fn min_stake() -> u128 {
         MIN_VERIFIER_STAKE_CHRONOS
@@ -1,4 +1,4 @@
-use jsonrpsee::core::RpcResult;
+use jsonrpsee::core::{RpcResult, SubscriptionResult};
 use jsonrpsee::proc_macros::rpc;
 
 use crate::types::{
@@ -6,14 +6,22 @@ use crate::types::{
     RpcConditionalRecord, RpcLedgerEntryRecord,
     RpcSignOfLifeRecord, RpcPromiseChainRecord,
     RpcIdentityRecord,
-    RpcAccount, RpcCascadeDetails, RpcChainStats, RpcClaimState, RpcGenesisInfo,
-    RpcGlobalLockStats, RpcHumanityStakeBalance, RpcIncomingTransfer, RpcOutgoingTransfer, RpcNetworkInfo,
-    RpcOracleSnapshot, RpcPromiseAxioms, RpcPromiseTriggerStatus, RpcProvider, RpcRecentTx,
-    RpcSchema, RpcSearchQuery, RpcTimeLock, RpcVerifierRecord, RpcVersionInfo,
+    RpcAccount, RpcCascadeDetails, RpcChainStats, RpcClaimHistoryEntry, RpcClaimState, RpcDifficulty, RpcDifficultyAdjustment, RpcGenesisInfo,
+    RpcLockTimelineDay,
+    RpcGlobalLockStats, RpcHumanityStakeBalance, RpcIncomingTransfer, RpcOutgoingTransfer, RpcNetworkInfo, RpcNetworkTopology,
+    RpcOracleHistoryEntry, RpcOracleSnapshot, RpcPromiseAxioms, RpcPromiseTriggerStatus, RpcProvider, RpcRecentTx,
+    RpcFeeEstimate, RpcSchema, RpcSearchQuery, RpcSimulationResult, RpcSubmitResult, RpcTimeLock, RpcTransactionStatus, RpcVerifierRecord, RpcVersionInfo,
     RpcAgentRecord, RpcAgentLoanRecord, RpcAgentCustodyRecord, RpcAxiomConsentRecord, RpcInvestablePromise, RpcDetailedTx,
     RpcLoanPaymentStage, RpcLoanDefaultRecord, RpcOraclePrice, RpcLoanCounts,
     RpcStateRoot, RpcSupplyInvariant,
     RpcChildChainRecord, RpcChildChainNamespaceInfo, RpcChildChainStats,
+    RpcLockCountdown, RpcLockSummary, RpcDbDiagnostics, RpcRecoveryStatus, RpcRecoveryEvidence, RpcPostRecoveryRestriction, RpcHealth, RpcConsistencyReport,
+    RpcLockAudit, RpcSupplyAudit,
+    RpcTagStat, RpcCheckpoint, RpcFeatureFlags,
+    RpcAccountMatch, RpcAccountPage, RpcAccountAgeStats, RpcExplorerSummary, RpcVerifierVote, RpcVerifierRank, RpcVerifierRegistration, RpcGovernanceProposal, RpcRecurringSchedule, RpcDagHealth,
+    RpcEvent, RpcAlert, RpcTimelineBucket, RpcBalancePoint,
+    RpcSplitPolicyValidation, RpcSystemInfo, RpcUnclaimedLock, RpcSearchResult,
+    RpcMempoolInfo, RpcPendingTx,
 };
 
 /// ChronX JSON-RPC 2.0 API definition.
@@ -25,6 +33,26 @@ pub trait ChronxApi {
     #[method(name = "getAccount")]
     async fn get_account(&self, account_id: String) -> RpcResult<Option<RpcAccount>>;
 
+    /// Page through every account in id order, at most 100 per call. Start
+    /// with `cursor = null` and pass back `next_cursor` until it is null.
+    /// With `created_after` and/or `created_before` (exclusive unix
+    /// timestamps), only accounts created in that window are returned, in
+    /// creation order. Only served on nodes run with `--rpc-enable-full-scan`.
+    #[method(name = "getAllAccounts")]
+    async fn get_all_accounts(
+        &self,
+        cursor: Option<String>,
+        limit: u32,
+        created_after: Option<i64>,
+        created_before: Option<i64>,
+    ) -> RpcResult<RpcAccountPage>;
+
+    /// Oldest and newest account creation times and accounts created per
+    /// calendar month (UTC). Accounts with no recorded creation time are
+    /// not counted.
+    #[method(name = "getAccountAgeStats")]
+    async fn get_account_age_stats(&self) -> RpcResult<RpcAccountAgeStats>;
+
     /// Get balance in Chronos by base-58 account ID.
     #[method(name = "getBalance")]
     async fn get_balance(&self, account_id: String) -> RpcResult<String>;
@@ -34,6 +62,29 @@ pub trait ChronxApi {
     #[method(name = "sendTransaction")]
     async fn send_transaction(&self, tx_hex: String) -> RpcResult<String>;
 
+    /// Submit a signed transaction, encoded as for `sendTransaction`.
+    /// Waits for the node to apply it and returns its TxId hex with status
+    /// `applied`, or `pending` if the node's submit timeout elapses first.
+    /// A rejected transaction is an error naming the `ChronxError` variant.
+    #[method(name = "submitTransaction")]
+    async fn submit_transaction(&self, tx_hex: String) -> RpcResult<RpcSubmitResult>;
+
+    /// Whether a transaction was applied, is still queued, or was rejected
+    /// (and why). Rejections are remembered for the node's most recent drops only.
+    #[method(name = "getTransactionStatus")]
+    async fn get_transaction_status(&self, tx_id: String) -> RpcResult<RpcTransactionStatus>;
+
+    /// Quote a fee for a transaction carrying `actions_json` (JSON-encoded
+    /// `Action`s): their serialized size times the node's fee rate per byte.
+    #[method(name = "estimateFee")]
+    async fn estimate_fee(&self, actions_json: Vec<serde_json::Value>) -> RpcResult<RpcFeeEstimate>;
+
+    /// Validate and apply a transaction, encoded as for `sendTransaction`,
+    /// against current state without committing it. Validation failures are
+    /// reported in the result rather than as RPC errors.
+    #[method(name = "simulateTransaction")]
+    async fn simulate_transaction(&self, tx_hex: String) -> RpcResult<RpcSimulationResult>;
+
     /// Get a transaction (DAG vertex) by its TxId hex.
     /// Returns decoded transaction details (JSON) or null if not found.
     #[method(name = "getTransaction")]
@@ -52,11 +103,18 @@ pub trait ChronxApi {
     #[method(name = "getGenesisInfo")]
     async fn get_genesis_info(&self) -> RpcResult<RpcGenesisInfo>;
 
-    /// Return the node's P2P identity (peer multiaddress).
-    /// Other nodes pass this as `--bootstrap` to connect.
+    /// Return the node's P2P identity (peer multiaddress) and topology:
+    /// connected and mesh peers, routing table size, bootstrap list, uptime.
+    /// Other nodes pass the multiaddress as `--bootstrap` to connect.
     #[method(name = "getNetworkInfo")]
     async fn get_network_info(&self) -> RpcResult<RpcNetworkInfo>;
 
+    /// This node's connections for topology visualization: each connected
+    /// peer's address, direction, uptime, mesh membership and reputation.
+    /// Disabled unless the node runs with `--rpc-enable-topology`.
+    #[method(name = "getNetworkTopology")]
+    async fn get_network_topology(&self) -> RpcResult<RpcNetworkTopology>;
+
     // ── V2 Claims queries ─────────────────────────────────────────────────────
 
     /// Return all registered certificate providers.
@@ -75,16 +133,53 @@ pub trait ChronxApi {
     #[method(name = "getClaimState")]
     async fn get_claim_state(&self, lock_id: String) -> RpcResult<Option<RpcClaimState>>;
 
+    /// Every state transition of the V2 claim on a lock (by TxId hex),
+    /// oldest first. Empty if no claim was ever opened on it.
+    #[method(name = "getClaimStateHistory")]
+    async fn get_claim_state_history(&self, lock_id: String) -> RpcResult<Vec<RpcClaimHistoryEntry>>;
+
+    /// Current PoW difficulty, its bounds and target, and the latest adjustment.
+    #[method(name = "getDifficulty")]
+    async fn get_difficulty(&self) -> RpcResult<RpcDifficulty>;
+
+    /// The most recent `limit` difficulty adjustments, oldest first. The
+    /// node keeps the last 100.
+    #[method(name = "getDifficultyHistory")]
+    async fn get_difficulty_history(&self, limit: u32) -> RpcResult<Vec<RpcDifficultyAdjustment>>;
+
     /// Return the current oracle snapshot for a trading pair (e.g. "KX/USD").
     #[method(name = "getOracleSnapshot")]
     async fn get_oracle_snapshot(&self, pair: String) -> RpcResult<Option<RpcOracleSnapshot>>;
 
+    /// Past snapshot prices for a pair between `from_ts` and `to_ts`
+    /// (inclusive), oldest first, at most `limit` (capped at 1000) points.
+    /// History is kept for 30 days.
+    #[method(name = "getOracleHistory")]
+    async fn get_oracle_history(
+        &self,
+        pair: String,
+        from_ts: i64,
+        to_ts: i64,
+        limit: u32,
+    ) -> RpcResult<Vec<RpcOracleHistoryEntry>>;
+
     // ── V3 New methods ────────────────────────────────────────────────────────
 
     /// Return a single time-lock contract by its TxId hex.
     #[method(name = "getLockById")]
     async fn get_lock_by_id(&self, lock_id: String) -> RpcResult<Option<RpcTimeLock>>;
 
+    /// Full details of a private lock, for its sender or recipient.
+    /// `challenge_signature_hex` is a Dilithium2 signature by either party's
+    /// account key over `private_lock_view_challenge(lock_id, now)`, which
+    /// changes every hour.
+    #[method(name = "getPrivateTimelockDetails")]
+    async fn get_private_timelock_details(
+        &self,
+        lock_id: String,
+        challenge_signature_hex: String,
+    ) -> RpcResult<RpcTimeLock>;
+
     /// Return all **Pending** time-lock contracts where `account_id` is the recipient.
     /// Results are sorted by `unlock_at` ascending.
     #[method(name = "getPendingIncoming")]
@@ -116,6 +211,43 @@ pub trait ChronxApi {
         to_unix: i64,
     ) -> RpcResult<Vec<RpcTimeLock>>;
 
+    /// Pending locks unlocking today, in the caller's time zone
+    /// (`timezone_offset_hours` from UTC, -12 to +14), earliest first.
+    #[method(name = "getLocksExpiringToday")]
+    async fn get_locks_expiring_today(&self, timezone_offset_hours: i32) -> RpcResult<Vec<RpcTimeLock>>;
+
+    /// Like `getLocksExpiringToday`, for the current Monday-to-Sunday week.
+    #[method(name = "getLocksExpiringThisWeek")]
+    async fn get_locks_expiring_this_week(&self, timezone_offset_hours: i32) -> RpcResult<Vec<RpcTimeLock>>;
+
+    /// Like `getLocksExpiringToday`, for the current calendar month.
+    #[method(name = "getLocksExpiringThisMonth")]
+    async fn get_locks_expiring_this_month(&self, timezone_offset_hours: i32) -> RpcResult<Vec<RpcTimeLock>>;
+
+    /// The account's pending locks grouped by UTC unlock day, earliest
+    /// first, through the unlock-time index. `role` is `sender`,
+    /// `recipient` or `both`; `from_ts`/`to_ts` bound `unlock_at` as
+    /// `[from_ts, to_ts)` and default to all time.
+    #[method(name = "getAccountLockTimeline")]
+    async fn get_account_lock_timeline(
+        &self,
+        account_id: String,
+        from_ts: Option<i64>,
+        to_ts: Option<i64>,
+        role: String,
+    ) -> RpcResult<Vec<RpcLockTimelineDay>>;
+
+    /// Pending locks that matured more than `older_than_secs` ago and are
+    /// still unclaimed, largest first. Locks whose claim window has lapsed
+    /// are left out unless `include_expired` is set. Only served on nodes run
+    /// with `--rpc-enable-sweep-reports`.
+    #[method(name = "getUnclaimedLockReport")]
+    async fn get_unclaimed_lock_report(
+        &self,
+        older_than_secs: i64,
+        include_expired: bool,
+    ) -> RpcResult<Vec<RpcUnclaimedLock>>;
+
     /// Return node and protocol version information.
     #[method(name = "getVersion")]
     async fn get_version(&self) -> RpcResult<RpcVersionInfo>;
@@ -130,6 +262,12 @@ pub trait ChronxApi {
     #[method(name = "searchLocks")]
     async fn search_locks(&self, query: RpcSearchQuery) -> RpcResult<Vec<RpcTimeLock>>;
 
+    /// Everything a block explorer home page shows, in one call: chain
+    /// stats, recent transactions, top lock holders, oracle prices, activity
+    /// counts and 7-day transaction volume. Cached for 60 seconds.
+    #[method(name = "getExplorerSummary")]
+    async fn get_explorer_summary(&self) -> RpcResult<RpcExplorerSummary>;
+
     /// Return aggregate statistics across all active (Pending) timelocks.
     /// Lightweight alternative to fetching all contracts — designed for the public stats bar.
     #[method(name = "getLockStats")]
@@ -534,7 +672,8 @@ pub trait ChronxApi {
     #[method(name = "submitChildChainRecord")]
     async fn submit_child_chain_record(&self, params: serde_json::Value) -> RpcResult<serde_json::Value>;
 
-    /// Return the latest BLAKE3 balance Merkle state root and vertex count.
+    /// Return the latest BLAKE3 balance Merkle state root, the depth and time
+    /// it was computed at, and the vertex count.
     #[method(name = "getStateRoot")]
     async fn get_state_root(&self) -> RpcResult<RpcStateRoot>;
 
@@ -542,4 +681,254 @@ pub trait ChronxApi {
     #[method(name = "verifySupplyInvariant")]
     async fn verify_supply_invariant(&self) -> RpcResult<RpcSupplyInvariant>;
 
+    /// Maturity countdown for up to 50 locks, returned in input order.
+    /// Unknown ids are reported with status `"NotFound"`.
+    #[method(name = "getLockCountdownBatch")]
+    async fn get_lock_countdown_batch(&self, lock_ids: Vec<String>) -> RpcResult<Vec<RpcLockCountdown>>;
+
+    /// Status, amount and maturity of up to 100 locks, in input order; one
+    /// call in place of a `getTimelockById` per lock. Unknown ids come back
+    /// as `{ lock_id, error: "not_found" }`. Private locks leave out
+    /// `amount_kx`, since the caller is not authenticated here; their
+    /// parties can use `getPrivateTimelockDetails`.
+    #[method(name = "getLockSummaryBatch")]
+    async fn get_lock_summary_batch(&self, lock_ids: Vec<String>) -> RpcResult<Vec<RpcLockSummary>>;
+
+    /// Return the full recovery state of an account, or null if the account is unknown.
+    #[method(name = "getRecoveryStatus")]
+    async fn get_recovery_status(&self, account_id: String) -> RpcResult<Option<RpcRecoveryStatus>>;
+
+    /// WebSocket only. Push an `RpcLockStatusEvent` each time the lock's status
+    /// changes; the subscription ends once the lock reaches a terminal state.
+    #[subscription(
+        name = "subscribeLockStatus" => "lockStatus",
+        unsubscribe = "unsubscribeLockStatus",
+        item = crate::types::RpcLockStatusEvent
+    )]
+    async fn subscribe_lock_status(&self, lock_id: String) -> SubscriptionResult;
+
+    /// WebSocket only. Push an `RpcVertexNotification` for every transaction
+    /// the node applies. A subscriber that falls behind skips what it missed.
+    #[subscription(
+        name = "subscribeNewVertices" => "newVertex",
+        unsubscribe = "unsubscribeNewVertices",
+        item = crate::types::RpcVertexNotification
+    )]
+    async fn subscribe_new_vertices(&self) -> SubscriptionResult;
+
+    /// WebSocket only. Push an `RpcLockUpdateEvent` each time a lock the
+    /// account sends, receives or holds the claim right to changes status.
+    /// A subscriber that falls behind skips what it missed.
+    #[subscription(
+        name = "subscribeLockUpdates" => "lockUpdate",
+        unsubscribe = "unsubscribeLockUpdates",
+        item = crate::types::RpcLockUpdateEvent
+    )]
+    async fn subscribe_lock_updates(&self, account_id: String) -> SubscriptionResult;
+
+    /// Return the evidence hashes and URIs of an account's recovery, or null
+    /// if the account is unknown.
+    #[method(name = "getRecoveryEvidence")]
+    async fn get_recovery_evidence(&self, account_id: String) -> RpcResult<Option<RpcRecoveryEvidence>>;
+
+    /// Return the daily transfer limit a finalized recovery placed on an
+    /// account, or null if the account is unknown or carries no unexpired
+    /// restriction.
+    #[method(name = "getPostRecoveryRestriction")]
+    async fn get_post_recovery_restriction(&self, account_id: String) -> RpcResult<Option<RpcPostRecoveryRestriction>>;
+
+    /// Node liveness: verifies the state database can be written and read back.
+    #[method(name = "health")]
+    async fn health(&self) -> RpcResult<RpcHealth>;
+
+    /// Admin: scan the state database for dangling cross-tree references.
+    /// Walks every lock and claim, so avoid calling it on a hot path.
+    #[method(name = "validateConsistency")]
+    async fn validate_consistency(&self) -> RpcResult<RpcConsistencyReport>;
+
+    /// Admin: sum every `Pending` lock and compare it to the senders'
+    /// outgoing lock totals. Walks every account and lock.
+    #[method(name = "auditLockSupply")]
+    async fn audit_lock_supply(&self) -> RpcResult<RpcLockAudit>;
+
+    /// Admin: add up accounts, locks, claim bonds, burned and unminted
+    /// Chronos and compare the sum to `TOTAL_SUPPLY_CHRONOS`.
+    #[method(name = "auditAccountSupply")]
+    async fn audit_account_supply(&self) -> RpcResult<RpcSupplyAudit>;
+
+    /// Non-private locks carrying `tag` across all accounts, newest first.
+    /// `status` filters like `searchLocks`; `limit` is capped at 100.
+    #[method(name = "searchByTag")]
+    async fn search_by_tag(
+        &self,
+        tag: String,
+        status: Option<String>,
+        limit: u32,
+        offset: u32,
+    ) -> RpcResult<Vec<RpcTimeLock>>;
+
+    /// Non-private locks carrying all (`require_all`) or any of `tags`,
+    /// newest first, optionally only those `account_id` sent or receives.
+    /// `limit` is capped at 100.
+    #[method(name = "getTimelockContractsForTags")]
+    async fn get_timelock_contracts_for_tags(
+        &self,
+        tags: Vec<String>,
+        require_all: bool,
+        account_id: Option<String>,
+        limit: u32,
+    ) -> RpcResult<Vec<RpcTimeLock>>;
+
+    /// The 20 most-used tags on non-private locks with their lock counts.
+    #[method(name = "getTagStats")]
+    async fn get_tag_stats(&self) -> RpcResult<Vec<RpcTagStat>>;
+
+    /// Admin: state checkpoints on disk, oldest first. Empty when the node
+    /// runs without `--checkpoint-dir`.
+    #[method(name = "getCheckpoints")]
+    async fn get_checkpoints(&self) -> RpcResult<Vec<RpcCheckpoint>>;
+
+    /// Admin: mark the lock's recipient as notified, without a transaction.
+    /// For the notification service; needs the node's admin token.
+    #[method(name = "markNotificationSent")]
+    async fn mark_notification_sent(&self, lock_id: String, admin_token: String) -> RpcResult<bool>;
+
+    /// Pending locks with `notify_recipient` set whose recipient has not
+    /// been notified yet, earliest unlock first. `limit` is capped at 100.
+    #[method(name = "getUnnotifiedLocks")]
+    async fn get_unnotified_locks(&self, limit: u32) -> RpcResult<Vec<RpcTimeLock>>;
+
+    /// Admin: entry count and size of every state database tree, with
+    /// trees this version does not know about listed separately. Reads
+    /// every entry.
+    #[method(name = "getDbDiagnostics")]
+    async fn get_db_diagnostics(&self) -> RpcResult<RpcDbDiagnostics>;
+
+    /// Which dormant protocol features governance has switched on.
+    #[method(name = "getFeatureFlags")]
+    async fn get_feature_flags(&self) -> RpcResult<RpcFeatureFlags>;
+
+    /// Accounts whose id bytes start with `prefix_hex` (at least 8 hex
+    /// characters). Returns at most 10 matches.
+    #[method(name = "findAccountByPrefix")]
+    async fn find_account_by_prefix(&self, prefix_hex: String) -> RpcResult<Vec<RpcAccountMatch>>;
+
+    /// Hex ids of locks whose id starts with `prefix_hex` (at least 8 hex
+    /// characters). Returns at most 10 matches.
+    #[method(name = "findLockByPrefix")]
+    async fn find_lock_by_prefix(&self, prefix_hex: String) -> RpcResult<Vec<String>>;
+
+    /// Resolve `query` to whatever it identifies, trying in turn: a
+    /// transaction id, a base-58 account id, a lock id, and a lock's 16-byte
+    /// `client_ref` in hex. The first match is returned.
+    #[method(name = "search")]
+    async fn search(&self, query: String) -> RpcResult<RpcSearchResult>;
+
+    /// Recovery votes cast by `verifier_id`, newest first. `limit` is capped at 100.
+    #[method(name = "getVerifierHistory")]
+    async fn get_verifier_history(&self, verifier_id: String, limit: u32) -> RpcResult<Vec<RpcVerifierVote>>;
+
+    /// Verifiers ranked by a slash-free record, then votes cast. `limit` is
+    /// capped at 100.
+    #[method(name = "getVerifierLeaderboard")]
+    async fn get_verifier_leaderboard(&self, limit: u32) -> RpcResult<Vec<RpcVerifierRank>>;
+
+    /// Registered recovery verifiers, from the `verifier_registrations` tree
+    /// rather than a scan of every account. Verifiers whose stake is below
+    /// the current minimum (waiting out a raise's grace period) are left out
+    /// unless `include_inactive` is set.
+    #[method(name = "getVerifierRegistrations")]
+    async fn get_verifier_registrations(&self, include_inactive: bool) -> RpcResult<Vec<RpcVerifierRegistration>>;
+
+    /// Governance proposals not yet finalized, oldest first, including any
+    /// whose voting window has closed but that nobody has finalized yet.
+    #[method(name = "getActiveProposals")]
+    async fn get_active_proposals(&self) -> RpcResult<Vec<RpcGovernanceProposal>>;
+
+    /// Finalized governance proposals, most recently finalized first.
+    #[method(name = "getGovernanceHistory")]
+    async fn get_governance_history(&self) -> RpcResult<Vec<RpcGovernanceProposal>>;
+
+    /// The recurring series a lock belongs to, given the id of any of its
+    /// installments. `None` if the lock is not part of a series.
+    #[method(name = "getRecurringSchedule")]
+    async fn get_recurring_schedule(&self, lock_id: String) -> RpcResult<Option<RpcRecurringSchedule>>;
+
+    /// Current DAG tip count against `MAX_DAG_TIPS`, with advice for wallets
+    /// choosing parents.
+    #[method(name = "getDagHealth")]
+    async fn get_dag_health(&self) -> RpcResult<RpcDagHealth>;
+
+    /// Event-log entries applied at or after `from_ts`, oldest first,
+    /// optionally only those of `event_type`. `limit` is capped at 1000.
+    #[method(name = "getEvents")]
+    async fn get_events(
+        &self,
+        from_ts: i64,
+        limit: u32,
+        event_type: Option<String>,
+    ) -> RpcResult<Vec<RpcEvent>>;
+
+    /// Alert rules currently firing on this node, as of the last metrics tick.
+    #[method(name = "getActiveAlerts")]
+    async fn get_active_alerts(&self) -> RpcResult<Vec<RpcAlert>>;
+
+    /// Vertex counts, senders, fees and action types per `bucket_secs`
+    /// interval of `[from_ts, to_ts)`, empty intervals included. At most
+    /// 1000 buckets; results are cached for 60 seconds.
+    #[method(name = "getChainTimeline")]
+    async fn get_chain_timeline(
+        &self,
+        bucket_secs: u64,
+        from_ts: i64,
+        to_ts: i64,
+    ) -> RpcResult<Vec<RpcTimelineBucket>>;
+
+    /// The account's balance after each vertex between `from_depth` and
+    /// `to_depth` (inclusive) that changed it, oldest first. Reconstructed
+    /// backward from the current balance; changes made outside transactions
+    /// (lock sweeps) are not attributed to any vertex.
+    #[method(name = "getAccountBalanceHistory")]
+    async fn get_account_balance_history(
+        &self,
+        account_id: String,
+        from_depth: u64,
+        to_depth: u64,
+    ) -> RpcResult<Vec<RpcBalancePoint>>;
+
+    /// Check a lock's split policy the way `ValidateSplitPolicy` would,
+    /// without submitting anything. `None` if the lock does not exist.
+    #[method(name = "validateSplitPolicy")]
+    async fn validate_split_policy(&self, lock_id: String) -> RpcResult<Option<RpcSplitPolicyValidation>>;
+
+    /// Node version, build toolchain, host platform, storage and network
+    /// settings, for monitoring without shell access to the host.
+    #[method(name = "getSystemInfo")]
+    async fn get_system_info(&self) -> RpcResult<RpcSystemInfo>;
+
+    /// Fiat currency codes with an oracle snapshot against KX (e.g.
+    /// `["EUR", "USD"]`), usable with `SetPreferredFiatCurrency`.
+    #[method(name = "getSupportedCurrencies")]
+    async fn get_supported_currencies(&self) -> RpcResult<Vec<String>>;
+
+    /// Transactions this node has accepted but not yet applied, oldest
+    /// first, optionally only those sent by `account_id`. A transaction
+    /// stays queued while an earlier nonce from its sender is missing.
+    #[method(name = "getPendingTransactions")]
+    async fn get_pending_transactions(&self, account_id: Option<String>) -> RpcResult<Vec<RpcPendingTx>>;
+
+    /// Mempool size, capacity and the most recently dropped transactions.
+    #[method(name = "getMempoolInfo")]
+    async fn get_mempool_info(&self) -> RpcResult<RpcMempoolInfo>;
+
+    /// Locks listed with `TimeLockSell`, in lock id order. `offset` is the
+    /// number of listings to skip; `limit` is the page size (max 100).
+    #[method(name = "getLocksForSale")]
+    async fn get_locks_for_sale(&self, offset: u32, limit: u32) -> RpcResult<Vec<RpcTimeLock>>;
+
+    /// Ids of the transactions that moved the lock's claim right
+    /// (`TransferLockBeneficiary` and `TimeLockBuy`), oldest first.
+    #[method(name = "getLockTransferHistory")]
+    async fn get_lock_transfer_history(&self, lock_id: String) -> RpcResult<Vec<String>>;
+
 }
@@ -7,18 +7,28 @@
 //!   chronx_getAccount          — full account state
 //!   chronx_getBalance          — balance in Chronos
 //!   chronx_sendTransaction     — submit a signed transaction (hex-encoded bincode)
+//!   chronx_submitTransaction   — submit one and report whether it applied
+//!   chronx_getTransactionStatus — applied, pending or rejected, with the reason
 //!   chronx_getTransaction      — get a vertex/tx by TxId hex
 //!   chronx_getLocks — list time-locks for an account
 //!   chronx_getDagTips           — current DAG tip TxIds
 //!   chronx_getGenesisInfo       — protocol constants
+//!   chronx_subscribeLockStatus  — WebSocket push of a lock's status changes
+//!   chronx_subscribeNewVertices — WebSocket push of every applied transaction
+//!   chronx_subscribeLockUpdates — WebSocket push of an account's lock changes
 
 pub mod api;
 pub mod server;
 pub mod types;
 
 pub use server::RpcServer;
-pub use server::RpcServerState;
+pub use server::{
+    RpcChainEvent, RpcServerState, SubmittedTx, TxOutcome, DEFAULT_SUBMIT_TIMEOUT, EVENT_CHANNEL_CAPACITY,
+};
 pub use types::{
-    RpcAccount, RpcCascadeDetails, RpcCascadeEntry, RpcClaimState, RpcGenesisInfo,
-    RpcNetworkInfo, RpcOracleSnapshot, RpcProvider, RpcSchema, RpcTimeLock,
+    RpcAccount, RpcAccountMatch, RpcAccountPage, RpcAccountAgeStats, RpcMonthCount, RpcCascadeDetails, RpcDayCount, RpcExplorerSummary, RpcLockHolder, RpcCascadeEntry, RpcClaimHistoryEntry, RpcClaimState, RpcDifficulty, RpcDifficultyAdjustment, RpcGenesisInfo,
+    RpcLockTimelineDay, RpcLockTimelineEntry,
+    RpcDroppedTx, RpcMempoolInfo, RpcPendingTx,
+    RpcLockStatusEvent, RpcLockSummary, RpcLockUpdateEvent, RpcVertexNotification, RpcDbDiagnostics, RpcTreeDiagnostic, RpcNetworkInfo, RpcNetworkTopology, RpcPeerConnection, RpcOracleHistoryEntry, RpcOracleSnapshot, RpcPostRecoveryRestriction, RpcProvider, RpcRecoveryEvidence, RpcRecoveryStatus, RpcSchema, RpcTimeLock,
+    RpcAlert, RpcBalanceDelta, RpcBalancePoint, RpcDagHealth, RpcEvent, RpcFeeEstimate, RpcGovernanceProposal, RpcRecurringInstallment, RpcRecurringSchedule, RpcSearchResult, RpcSimulationResult, RpcSplitPolicyValidation, RpcSplitRecipient, RpcSubmitResult, RpcSystemInfo, RpcTimelineBucket, RpcTransactionStatus, RpcVerifierRank, RpcVerifierRegistration, RpcVerifierVote,
 };
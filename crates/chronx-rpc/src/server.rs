@@ -9,25 +9,35 @@
 //! Errors return standard JSON-RPC error objects:
 //! - `-32602` for invalid or missing parameters
 //! - `-32603` for internal errors (DB failure, full queue, etc.)
+//! - `-32005` when the node's transaction-submit rate limit is exhausted
+//! - `-32010` when the node rejects a submitted transaction; the message
+//!   starts with the `ChronxError` variant, e.g. `InvalidNonce: ...`
 
+use std::collections::HashSet;
 use std::net::SocketAddr;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 
-use jsonrpsee::core::{async_trait, RpcResult};
-use jsonrpsee::server::{Server, ServerHandle};
+use jsonrpsee::core::{async_trait, RpcResult, SubscriptionResult};
+use jsonrpsee::server::{PendingSubscriptionSink, Server, ServerHandle, SubscriptionMessage, SubscriptionSink};
 use jsonrpsee::types::ErrorObject;
+use tokio::sync::broadcast;
 use tower_http::cors::{Any, CorsLayer};
 use tracing::{info, warn};
 
-use chronx_core::account::TimeLockStatus;
+use chronx_core::account::{Account, AuthPolicy, TimeLockStatus};
 use chronx_core::error::ChronxError;
+use chronx_core::events::ChronxEvent;
 use chronx_core::claims::ProviderStatus;
 use chronx_core::constants::{CHRONOS_PER_KX, TOTAL_SUPPLY_CHRONOS};
-use chronx_core::transaction::{Action, Transaction};
-use chronx_core::types::{AccountId, TxId};
+use chronx_core::units::{chronos_to_kx, kx_string};
+use chronx_core::transaction::{Action, Transaction, TransactionReceipt};
+use chronx_core::types::{AccountId, DilithiumSignature, TxId};
+use chronx_mempool::Mempool;
+use chronx_monitoring::Alert;
+use chronx_p2p::P2pStats;
 use chronx_state::StateDb;
-use chronx_state::db::{InvoiceStatus, CreditStatus, DepositStatus, ConditionalStatus, LoanStatus};
+use chronx_state::db::{GovernanceProposal, GovernanceProposalStatus, InvoiceStatus, CreditStatus, DepositStatus, ConditionalStatus, LoanStatus, TimelineBucket, TimelineCache};
 
 use crate::api::ChronxApiServer;
 use crate::types::{
@@ -35,21 +45,91 @@ use crate::types::{
     RpcConditionalRecord, RpcLedgerEntryRecord,
     RpcSignOfLifeRecord, RpcPromiseChainRecord,
     RpcIdentityRecord,
-    RpcAccount, RpcCascadeDetails, RpcChainStats, RpcClaimState, RpcGenesisInfo,
-    RpcGlobalLockStats, RpcHumanityStakeBalance, RpcIncomingTransfer, RpcOutgoingTransfer, RpcNetworkInfo,
-    RpcOracleSnapshot, RpcPromiseAxioms, RpcPromiseTriggerStatus, RpcProvider, RpcRecentTx,
-    RpcSchema, RpcSearchQuery, RpcTimeLock, RpcVerifierRecord, RpcVersionInfo,
+    RpcAccount, RpcCascadeDetails, RpcChainStats, RpcClaimHistoryEntry, RpcClaimState, RpcDifficulty, RpcDifficultyAdjustment, RpcGenesisInfo,
+    RpcLockTimelineDay, RpcLockTimelineEntry,
+    RpcGlobalLockStats, RpcHumanityStakeBalance, RpcIncomingTransfer, RpcOutgoingTransfer, RpcNetworkInfo, RpcNetworkTopology, RpcPeerConnection,
+    RpcOracleHistoryEntry, RpcOracleSnapshot, RpcPromiseAxioms, RpcPromiseTriggerStatus, RpcProvider, RpcRecentTx,
+    RpcFeeEstimate, RpcSchema, RpcSearchQuery, RpcBalanceDelta, RpcSimulationResult, RpcSubmitResult, RpcTimeLock, RpcTransactionStatus, RpcVerifierRecord, RpcVersionInfo,
     RpcAgentRecord, RpcAgentLoanRecord, RpcAgentCustodyRecord, RpcAxiomConsentRecord, RpcInvestablePromise,
     RpcDetailedTx, RpcActionSummary,
     RpcLoanPaymentStage, RpcLoanDefaultRecord, RpcOraclePrice, RpcLoanCounts,
     RpcStateRoot, RpcSupplyInvariant,
     RpcChildChainRecord, RpcChildChainNamespaceInfo, RpcChildChainStats,
+    RpcLockCountdown, RpcLockSummary, RpcDbDiagnostics, RpcTreeDiagnostic, RpcRecoveryStatus, RpcLockStatusEvent, RpcRecoveryEvidence, RpcPostRecoveryRestriction, RpcSplitRecipient,
+    RpcHealth, RpcConsistencyReport, RpcLockAudit, RpcSupplyAudit, RpcTagStat, RpcCheckpoint, RpcFeatureFlags,
+    RpcAccountMatch, RpcVerifierVote, RpcVerifierRank, RpcVerifierRegistration, RpcGovernanceProposal, RpcRecurringInstallment, RpcRecurringSchedule, RpcDagHealth,
+    RpcEvent, RpcAlert, RpcTimelineBucket, RpcBalancePoint,
+    RpcSplitPolicyValidation, RpcSystemInfo, RpcAccountPage, RpcAccountAgeStats, RpcMonthCount,
+    RpcDayCount, RpcExplorerSummary, RpcLockHolder, RpcUnclaimedLock, RpcSearchResult,
+    RpcDroppedTx, RpcMempoolInfo, RpcPendingTx, RpcLockUpdateEvent, RpcVertexNotification,
 };
 
 fn rpc_err(code: i32, msg: impl Into<String>) -> ErrorObject<'static> {
     ErrorObject::owned(code, msg.into(), None::<()>)
 }
 
+fn governance_proposal_to_rpc(p: &GovernanceProposal) -> RpcGovernanceProposal {
+    RpcGovernanceProposal {
+        proposal_id: p.proposal_id.to_hex(),
+        proposer: p.proposer.to_b58(),
+        title: p.title.clone(),
+        body_hash: hex::encode(p.body_hash),
+        target_parameter: format!("{:?}", p.target_parameter),
+        proposed_value_hex: hex::encode(&p.proposed_value_bytes),
+        bond_chronos: p.bond_amount.to_string(),
+        submitted_at: p.submitted_at,
+        voting_period_secs: p.voting_period_secs,
+        voting_ends_at: p.voting_ends_at,
+        votes_for_chronos: p.votes_for.to_string(),
+        votes_against_chronos: p.votes_against.to_string(),
+        voter_count: p.voters.len() as u64,
+        status: p.status.name().to_string(),
+        finalized_at: p.finalized_at,
+    }
+}
+
+/// `chronx_getDifficulty` direction of a change from `before` to `after`.
+fn direction_name(before: u8, after: u8) -> &'static str {
+    match after.cmp(&before) {
+        std::cmp::Ordering::Greater => "increased",
+        std::cmp::Ordering::Less => "decreased",
+        std::cmp::Ordering::Equal => "none",
+    }
+}
+
+/// Capacity of the applied-transaction event channel. Slow WebSocket
+/// subscribers skip what they missed.
+pub const EVENT_CHANNEL_CAPACITY: usize = 1024;
+
+/// How long `chronx_sendTransaction` and `chronx_submitTransaction` wait for
+/// the node to apply a transaction before answering with it still pending.
+pub const DEFAULT_SUBMIT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// What became of a transaction sent down the node pipeline: its id once
+/// applied, or why it was rejected, as `"<ChronxError variant>: <message>"`.
+pub type TxOutcome = Result<TxId, String>;
+
+/// A transaction on its way to the node's apply loop.
+#[derive(Debug)]
+pub struct SubmittedTx {
+    pub tx: Transaction,
+    /// Completed with the outcome when the submitter waits for one.
+    pub outcome: Option<tokio::sync::oneshot::Sender<TxOutcome>>,
+}
+
+impl From<Transaction> for SubmittedTx {
+    fn from(tx: Transaction) -> Self {
+        Self { tx, outcome: None }
+    }
+}
+
+/// An applied-transaction event fanned out to WebSocket subscribers.
+#[derive(Debug, Clone)]
+pub enum RpcChainEvent {
+    NewVertex(RpcVertexNotification),
+    LockUpdate(RpcLockUpdateEvent),
+}
+
 /// Shared state passed to the RPC server.
 pub struct RpcServerState {
     pub db: Arc<StateDb>,
@@ -57,22 +137,245 @@ pub struct RpcServerState {
 
 
     /// Optional sender to forward incoming transactions to the node pipeline.
-    pub tx_sender: Option<tokio::sync::mpsc::Sender<Transaction>>,
+    pub tx_sender: Option<tokio::sync::mpsc::Sender<SubmittedTx>>,
+    /// How long transaction submits wait for the outcome; see
+    /// [`DEFAULT_SUBMIT_TIMEOUT`].
+    pub submit_timeout: std::time::Duration,
+    /// Transactions received but not yet applied, shared with the node's
+    /// apply loop (`None` in tests that do not queue transactions).
+    pub mempool: Option<Arc<std::sync::Mutex<Mempool>>>,
     /// Full libp2p multiaddress of this node (e.g. `/ip4/127.0.0.1/tcp/7777/p2p/<PeerId>`).
     /// Used by peers to bootstrap; returned by `chronx_getNetworkInfo`.
     pub peer_multiaddr: Option<String>,
     /// Shared counter of currently connected P2P peers.
     pub peer_count: Arc<AtomicU64>,
+    /// Node-wide cap on transaction submits. Hot-reloadable.
+    pub rate_limiter: Arc<RpcRateLimiter>,
+    /// P2P topology snapshot shared with the swarm event loop (`None` without P2P).
+    pub p2p_stats: Option<Arc<std::sync::RwLock<P2pStats>>>,
+    /// When the node process started, for `node_uptime_secs`.
+    pub started_at: std::time::Instant,
+    /// `chronx_subscribeLockStatus` subscribers, keyed by lock id.
+    pub lock_status_subscriptions: dashmap::DashMap<TxId, Vec<SubscriptionSink>>,
+    /// Feeds `chronx_subscribeNewVertices` and `chronx_subscribeLockUpdates`;
+    /// see [`RpcServerState::publish_applied`].
+    pub events: broadcast::Sender<RpcChainEvent>,
+    /// Where the node writes state checkpoints (`None` if checkpointing is off).
+    pub checkpoint_dir: Option<std::path::PathBuf>,
+    /// Alerts firing as of the last metrics tick; written by the node's
+    /// metrics task.
+    pub active_alerts: Arc<std::sync::RwLock<Vec<Alert>>>,
+    /// Whether `chronx_getChainTimeline` is served; off on low-resource
+    /// nodes, since it scans every vertex.
+    pub timeline_enabled: bool,
+    /// Whether `chronx_getAllAccounts` is served; off by default, since it
+    /// walks the whole account tree.
+    pub full_scan_enabled: bool,
+    /// Whether `chronx_getUnclaimedLockReport` is served; off by default.
+    pub sweep_reports_enabled: bool,
+    /// Whether `chronx_getNetworkTopology` is served; off by default, since
+    /// it exposes peer addresses.
+    pub topology_enabled: bool,
+    /// P2P listen multiaddress and RPC bind address, reported by
+    /// `chronx_getSystemInfo` (`None` when not applicable).
+    pub p2p_listen_addr: Option<String>,
+    pub rpc_addr: Option<String>,
+    /// Token privileged off-chain calls (`chronx_markNotificationSent`)
+    /// must present; `None` disables them.
+    pub admin_token: Option<String>,
+}
+
+impl RpcServerState {
+    /// Fails unless `token` matches the configured admin token. Compared
+    /// through BLAKE3 hashes, whose equality check is constant-time.
+    fn check_admin_token(&self, token: &str) -> Result<(), ErrorObject<'static>> {
+        match &self.admin_token {
+            None => Err(rpc_err(-32603, "admin calls are disabled on this node")),
+            Some(expected) if blake3::hash(expected.as_bytes()) == blake3::hash(token.as_bytes()) => Ok(()),
+            Some(_) => Err(rpc_err(-32602, "invalid admin token")),
+        }
+    }
+
+    /// Push status changes from a `TransactionReceipt` to lock subscribers.
+    ///
+    /// Disconnected subscribers are dropped, and every subscriber of a lock
+    /// is dropped once it reaches a terminal status.
+    pub async fn notify_lock_status(&self, changes: &[(TxId, TimeLockStatus)], timestamp: i64) {
+        for (lock_id, status) in changes {
+            self.notify_lock_status_event(lock_id, status.name(), status.is_terminal(), timestamp)
+                .await;
+        }
+    }
+
+    /// Publish an applied transaction, then every lock whose status it
+    /// changed, to the event subscriptions. Never waits on subscribers.
+    pub fn publish_applied(&self, tx: &Transaction, receipt: &TransactionReceipt, timestamp: i64) {
+        if self.events.receiver_count() == 0 {
+            return;
+        }
+        let _ = self.events.send(RpcChainEvent::NewVertex(RpcVertexNotification {
+            tx_id: tx.tx_id.to_hex(),
+            from: tx.from.to_b58(),
+            timestamp,
+            action_types: tx.actions.iter().map(|a| a.name().to_string()).collect(),
+        }));
+        for (lock_id, status) in &receipt.lock_status_changes {
+            let lock = match self.db.get_timelock(lock_id) {
+                Ok(Some(lock)) => lock,
+                Ok(None) => continue,
+                Err(e) => {
+                    warn!(lock_id = %lock_id, error = %e, "failed to load lock for update event");
+                    continue;
+                }
+            };
+            let _ = self.events.send(RpcChainEvent::LockUpdate(RpcLockUpdateEvent {
+                lock_id: lock_id.to_hex(),
+                sender: lock.sender.to_b58(),
+                recipient: lock.recipient_account_id.to_b58(),
+                beneficiary: lock.current_beneficiary.as_ref().map(|b| b.to_b58()),
+                new_status: status.name().to_string(),
+                timestamp,
+                is_terminal: status.is_terminal(),
+            }));
+        }
+    }
+
+    /// Push one status change to the lock's subscribers. Also used for
+    /// changes announced by peers before the vertex is applied locally.
+    pub async fn notify_lock_status_event(
+        &self,
+        lock_id: &TxId,
+        new_status: &str,
+        is_terminal: bool,
+        timestamp: i64,
+    ) {
+        // Take the sinks out so no map guard is held across `.await`.
+        let Some((_, sinks)) = self.lock_status_subscriptions.remove(lock_id) else {
+            return;
+        };
+        let event = RpcLockStatusEvent {
+            lock_id: lock_id.to_hex(),
+            new_status: new_status.to_string(),
+            timestamp,
+            is_terminal,
+        };
+        let msg = match SubscriptionMessage::from_json(&event) {
+            Ok(msg) => msg,
+            Err(e) => {
+                warn!(error = %e, "failed to encode lock status event");
+                return;
+            }
+        };
+
+        let mut live = Vec::with_capacity(sinks.len());
+        for sink in sinks {
+            if sink.send(msg.clone()).await.is_ok() {
+                live.push(sink);
+            }
+        }
+        if !is_terminal && !live.is_empty() {
+            self.lock_status_subscriptions
+                .entry(lock_id.clone())
+                .or_default()
+                .extend(live);
+        }
+    }
+}
+
+/// Forward the events `select` picks from `rx` to `sink` until the client
+/// goes away. A send waits on this subscriber alone; if it falls more than
+/// [`EVENT_CHANNEL_CAPACITY`] events behind, it skips the ones it missed.
+async fn forward_events<T: serde::Serialize>(
+    sink: SubscriptionSink,
+    mut rx: broadcast::Receiver<RpcChainEvent>,
+    select: impl Fn(RpcChainEvent) -> Option<T>,
+) {
+    loop {
+        let event = tokio::select! {
+            _ = sink.closed() => return,
+            event = rx.recv() => event,
+        };
+        let item = match event {
+            Ok(event) => match select(event) {
+                Some(item) => item,
+                None => continue,
+            },
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                warn!(subscription = ?sink.subscription_id(), skipped, "slow subscriber skipped events");
+                continue;
+            }
+            Err(broadcast::error::RecvError::Closed) => return,
+        };
+        let msg = match SubscriptionMessage::from_json(&item) {
+            Ok(msg) => msg,
+            Err(e) => {
+                warn!(error = %e, "failed to encode subscription event");
+                continue;
+            }
+        };
+        if sink.send(msg).await.is_err() {
+            return;
+        }
+    }
+}
+
+/// Node-wide limit on `chronx_sendTransaction` and `chronx_submitTransaction`
+/// calls per minute.
+///
+/// Uses a fixed one-minute window. The limit may be changed while the server
+/// is running (the node does so on SIGHUP); `0` disables limiting.
+pub struct RpcRateLimiter {
+    limit_per_minute: AtomicU64,
+    /// (minute index, calls accepted in that minute)
+    window: std::sync::Mutex<(i64, u64)>,
+}
+
+impl RpcRateLimiter {
+    pub fn new(limit_per_minute: u64) -> Self {
+        Self {
+            limit_per_minute: AtomicU64::new(limit_per_minute),
+            window: std::sync::Mutex::new((0, 0)),
+        }
+    }
+
+    pub fn limit(&self) -> u64 {
+        self.limit_per_minute.load(Ordering::Relaxed)
+    }
+
+    pub fn set_limit(&self, limit_per_minute: u64) {
+        self.limit_per_minute.store(limit_per_minute, Ordering::Relaxed);
+    }
+
+    /// Count one call at `now` (unix seconds). Returns `false` if the current
+    /// minute's budget is already spent.
+    pub fn check(&self, now: i64) -> bool {
+        let limit = self.limit();
+        if limit == 0 {
+            return true;
+        }
+        let minute = now.div_euclid(60);
+        let mut window = self.window.lock().unwrap_or_else(|e| e.into_inner());
+        if window.0 != minute {
+            *window = (minute, 0);
+        }
+        if window.1 >= limit {
+            return false;
+        }
+        window.1 += 1;
+        true
+    }
 }
 
 /// The RPC server implementation.
 pub struct RpcServer {
     state: Arc<RpcServerState>,
+    /// Last `chronx_getExplorerSummary` result and when it was computed.
+    explorer_cache: std::sync::Mutex<Option<(i64, RpcExplorerSummary)>>,
 }
 
 impl RpcServer {
     pub fn new(state: Arc<RpcServerState>) -> Self {
-        Self { state }
+        Self { state, explorer_cache: std::sync::Mutex::new(None) }
     }
 
     /// Start the JSON-RPC server on `addr` with permissive CORS headers. Returns a handle to stop it.
@@ -92,35 +395,107 @@ impl RpcServer {
         info!(%addr, "RPC server started");
         Ok(handle)
     }
+
+    /// Approximate tip depth as chain height.
+    fn tip_height(&self) -> u64 {
+        self.state
+            .db
+            .get_tips()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|t| self.state.db.get_vertex(&t).ok().flatten())
+            .map(|v| v.depth)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Pending locks unlocking within the current `period` of the caller's
+    /// local calendar, through the unlock-time index.
+    fn locks_expiring_in(&self, period: CalendarPeriod, offset_hours: i32) -> RpcResult<Vec<RpcTimeLock>> {
+        if !(-12..=14).contains(&offset_hours) {
+            return Err(rpc_err(-32602, "timezone_offset_hours must be between -12 and 14"));
+        }
+        let (from, to) = period.bounds(chrono::Utc::now().timestamp(), offset_hours);
+        let locks = self
+            .state
+            .db
+            .iter_pending_timelocks_unlocking(from, to)
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+        Ok(locks.into_iter().map(tlc_to_rpc).collect())
+    }
+
+    /// Build the RPC view of an account, summing its pending outgoing locks.
+    fn account_to_rpc(&self, a: Account, tip_height: u64) -> RpcAccount {
+        // Sum pending time-lock amounts where this account is the sender.
+        let locked: u128 = self
+            .state
+            .db
+            .iter_timelocks_for_sender(&a.account_id)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|tlc| tlc.status == TimeLockStatus::Pending)
+            .map(|tlc| tlc.amount)
+            .sum();
+
+        let spendable = a.spendable_balance();
+
+        RpcAccount {
+            account_id: a.account_id.to_b58(),
+            balance_chronos: a.balance.to_string(),
+            balance_kx: kx_string(a.balance),
+            spendable_chronos: spendable.to_string(),
+            spendable_kx: kx_string(spendable),
+            locked_chronos: locked.to_string(),
+            locked_kx: kx_string(locked),
+            verifier_stake_chronos: a.verifier_stake.to_string(),
+            nonce: a.nonce,
+            is_verifier: a.is_verifier,
+            recovery_active: a.recovery_state.active,
+            tip_height,
+            // V3 cached lock counters
+            account_version: a.account_version,
+            created_at: a.created_at,
+            incoming_locks_count: a.incoming_locks_count,
+            outgoing_locks_count: a.outgoing_locks_count,
+            incoming_locked_chronos: a.total_locked_incoming_chronos.to_string(),
+            outgoing_locked_chronos: a.total_locked_outgoing_chronos.to_string(),
+            public_key: match &a.auth_policy {
+                AuthPolicy::SingleSig { public_key } => Some(hex::encode(&public_key.0)),
+                AuthPolicy::RecoveryEnabled { owner_key, .. } => Some(hex::encode(&owner_key.0)),
+                AuthPolicy::MultiSig { .. } => None,
+            },
+        }
+    }
 }
 
 // ── Internal helper: convert a TimeLockContract to an RpcTimeLock ────────────
 
 fn tlc_status_str(status: &TimeLockStatus) -> String {
-    match status {
-        TimeLockStatus::Pending => "Pending".to_string(),
-        TimeLockStatus::Claimed { .. } => "Claimed".to_string(),
-        TimeLockStatus::ForSale { .. } => "ForSale".to_string(),
-        TimeLockStatus::Ambiguous { .. } => "Ambiguous".to_string(),
-        TimeLockStatus::ClaimOpen { .. } => "ClaimOpen".to_string(),
-        TimeLockStatus::ClaimCommitted { .. } => "ClaimCommitted".to_string(),
-        TimeLockStatus::ClaimRevealed { .. } => "ClaimRevealed".to_string(),
-        TimeLockStatus::ClaimChallenged { .. } => "ClaimChallenged".to_string(),
-        TimeLockStatus::ClaimFinalized { .. } => "ClaimFinalized".to_string(),
-        TimeLockStatus::ClaimSlashed { .. } => "ClaimSlashed".to_string(),
-        TimeLockStatus::Cancelled { .. } => "Cancelled".to_string(),
-        TimeLockStatus::Reverted { .. } => "Reverted".to_string(),
-        TimeLockStatus::PendingExecutor { .. } => "PendingExecutor".to_string(),
-        TimeLockStatus::ExecutorWithdrawn { .. } => "ExecutorWithdrawn".to_string(),
-        TimeLockStatus::PartiallyReleased { .. } => "PartiallyReleased".to_string(),
-        TimeLockStatus::OracleTriggered { .. } => "OracleTriggered".to_string(),
-        TimeLockStatus::OracleExpiredClean { .. } => "OracleExpiredClean".to_string(),
-        TimeLockStatus::AttestorFailed { .. } => "AttestorFailed".to_string(),
+    status.name().to_string()
+}
+
+fn unclaimed_action_str(action: &chronx_core::account::UnclaimedAction) -> String {
+    match action {
+        chronx_core::account::UnclaimedAction::RevertToSender => "RevertToSender".to_string(),
+        chronx_core::account::UnclaimedAction::Burn => "Burn".to_string(),
+        chronx_core::account::UnclaimedAction::ForwardTo(id) => format!("ForwardTo({})", id.to_b58()),
+    }
+}
+
+fn expiry_policy_str(policy: &chronx_core::account::ExpiryPolicy) -> String {
+    match policy {
+        chronx_core::account::ExpiryPolicy::ReturnToSender => "ReturnToSender".to_string(),
+        chronx_core::account::ExpiryPolicy::Burn => "Burn".to_string(),
+        chronx_core::account::ExpiryPolicy::RedirectTo(id) => format!("RedirectTo({})", id.to_b58()),
     }
 }
 
 fn tlc_to_rpc(tlc: chronx_core::account::TimeLockContract) -> RpcTimeLock {
     let status = tlc_status_str(&tlc.status);
+    let expiry_action = match &tlc.status {
+        TimeLockStatus::Expired { action_taken, .. } => Some(expiry_policy_str(action_taken)),
+        _ => None,
+    };
 
     // If lock_marker starts with 0xC5 marker and is 33 bytes,
     // the remaining 32 bytes are BLAKE3(claim_code). Locks sharing the
@@ -136,12 +511,13 @@ fn tlc_to_rpc(tlc: chronx_core::account::TimeLockContract) -> RpcTimeLock {
     let email_recipient_hash = tlc.email_recipient_hash.map(hex::encode);
     let cancellation_window_secs = tlc.cancellation_window_secs;
     let claim_window_secs_val = tlc.claim_window_secs;
-    let unclaimed_action_str = tlc.unclaimed_action.as_ref().map(|ua| {
-        match ua {
-            chronx_core::account::UnclaimedAction::RevertToSender => "RevertToSender".to_string(),
-            chronx_core::account::UnclaimedAction::Burn => "Burn".to_string(),
-            chronx_core::account::UnclaimedAction::ForwardTo(id) => format!("ForwardTo({})", id.to_b58()),
-        }
+    let unclaimed_action_str = tlc.unclaimed_action.as_ref().map(unclaimed_action_str);
+    let split_recipients = tlc.split_policy.map(|policy| {
+        policy
+            .recipients
+            .into_iter()
+            .map(|(id, basis_points)| RpcSplitRecipient { account_id: id.to_b58(), basis_points })
+            .collect()
     });
 
     RpcTimeLock {
@@ -149,7 +525,7 @@ fn tlc_to_rpc(tlc: chronx_core::account::TimeLockContract) -> RpcTimeLock {
         sender: tlc.sender.to_b58(),
         recipient_account_id: tlc.recipient_account_id.to_b58(),
         amount_chronos: tlc.amount.to_string(),
-        amount_kx: (tlc.amount / CHRONOS_PER_KX).to_string(),
+        amount_kx: kx_string(tlc.amount),
         unlock_at: tlc.unlock_at,
         created_at: tlc.created_at,
         status,
@@ -165,6 +541,11 @@ fn tlc_to_rpc(tlc: chronx_core::account::TimeLockContract) -> RpcTimeLock {
         lock_type: tlc.lock_type,
         lock_metadata: tlc.lock_metadata,
         convert_to: None, // populated by caller from convert_to_suggestion tree
+        oracle_hint: tlc.oracle_hint,
+        claim_policy: tlc.claim_policy,
+        cancellation_reason: tlc.cancellation_reason,
+        expiry_action,
+        split_recipients,
     }
 }
 
@@ -188,52 +569,67 @@ impl ChronxApiServer for RpcServer {
             return Ok(None);
         };
 
-        // Sum pending time-lock amounts where this account is the sender.
-        let locked: u128 = self
-            .state
-            .db
-            .iter_timelocks_for_sender(&id)
-            .unwrap_or_default()
-            .into_iter()
-            .filter(|tlc| tlc.status == TimeLockStatus::Pending)
-            .map(|tlc| tlc.amount)
-            .sum();
+        Ok(Some(self.account_to_rpc(a, self.tip_height())))
+    }
+
+    /// `chronx_getAllAccounts` — one page of accounts in id order, resuming
+    /// after `cursor` (the `next_cursor` of the previous page).
+    async fn get_all_accounts(
+        &self,
+        cursor: Option<String>,
+        limit: u32,
+        created_after: Option<i64>,
+        created_before: Option<i64>,
+    ) -> RpcResult<RpcAccountPage> {
+        if !self.state.full_scan_enabled {
+            return Err(rpc_err(-32603, "full account scan is disabled on this node"));
+        }
+        let cursor = cursor
+            .map(|c| AccountId::from_b58(&c))
+            .transpose()
+            .map_err(|e| rpc_err(-32602, format!("invalid cursor: {e}")))?;
+        let limit = (limit as usize).min(MAX_ACCOUNTS_PAGE);
+        let page = if created_after.is_some() || created_before.is_some() {
+            self.state.db.iter_accounts_created_paged(
+                created_after,
+                created_before,
+                cursor.as_ref(),
+                limit,
+            )
+        } else {
+            self.state.db.iter_accounts_paged(cursor.as_ref(), limit)
+        };
+        let (accounts, next) = page.map_err(|e| rpc_err(-32603, e.to_string()))?;
+        let tip_height = self.tip_height();
+        Ok(RpcAccountPage {
+            accounts: accounts.into_iter().map(|a| self.account_to_rpc(a, tip_height)).collect(),
+            next_cursor: next.map(|id| id.to_b58()),
+        })
+    }
 
-        // Approximate tip depth as chain height.
-        let tip_height = self
+    /// `chronx_getAccountAgeStats` — account creation spread, from the
+    /// creation-time index.
+    async fn get_account_age_stats(&self) -> RpcResult<RpcAccountAgeStats> {
+        let times = self
             .state
             .db
-            .get_tips()
-            .unwrap_or_default()
-            .into_iter()
-            .filter_map(|t| self.state.db.get_vertex(&t).ok().flatten())
-            .map(|v| v.depth)
-            .max()
-            .unwrap_or(0);
-
-        let spendable = a.spendable_balance();
-
-        Ok(Some(RpcAccount {
-            account_id: a.account_id.to_b58(),
-            balance_chronos: a.balance.to_string(),
-            balance_kx: (a.balance / CHRONOS_PER_KX).to_string(),
-            spendable_chronos: spendable.to_string(),
-            spendable_kx: (spendable / CHRONOS_PER_KX).to_string(),
-            locked_chronos: locked.to_string(),
-            locked_kx: (locked / CHRONOS_PER_KX).to_string(),
-            verifier_stake_chronos: a.verifier_stake.to_string(),
-            nonce: a.nonce,
-            is_verifier: a.is_verifier,
-            recovery_active: a.recovery_state.active,
-            tip_height,
-            // V3 cached lock counters
-            account_version: a.account_version,
-            created_at: a.created_at,
-            incoming_locks_count: a.incoming_locks_count,
-            outgoing_locks_count: a.outgoing_locks_count,
-            incoming_locked_chronos: a.total_locked_incoming_chronos.to_string(),
-            outgoing_locked_chronos: a.total_locked_outgoing_chronos.to_string(),
-        }))
+            .account_creation_times()
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+        let mut accounts_per_month: Vec<RpcMonthCount> = Vec::new();
+        for ts in &times {
+            let month = chrono::DateTime::from_timestamp(*ts, 0)
+                .map(|d| d.format("%Y-%m").to_string())
+                .unwrap_or_default();
+            match accounts_per_month.last_mut() {
+                Some(last) if last.month == month => last.count += 1,
+                _ => accounts_per_month.push(RpcMonthCount { month, count: 1 }),
+            }
+        }
+        Ok(RpcAccountAgeStats {
+            oldest_account_created_at: times.first().copied().unwrap_or(0),
+            newest_account_created_at: times.last().copied().unwrap_or(0),
+            accounts_per_month,
+        })
     }
 
     /// `chronx_getBalance` — return raw balance in Chronos (1 KX = 1,000,000 Chronos).
@@ -252,33 +648,134 @@ impl ChronxApiServer for RpcServer {
         Ok(balance.to_string())
     }
 
-    /// `chronx_sendTransaction` — submit a hex-encoded, signed, PoW-solved transaction.
-    /// Returns the transaction ID on success. The transaction is validated and applied
-    /// by the `StateEngine` in the node's main loop, then broadcast to peers via P2P.
+    /// `chronx_sendTransaction` — [`submit_transaction`](Self::submit_transaction)
+    /// answering with just the TxId hex, applied or still pending.
     async fn send_transaction(&self, tx_hex: String) -> RpcResult<String> {
+        self.submit_transaction(tx_hex).await.map(|r| r.tx_id)
+    }
+
+    /// `chronx_submitTransaction` — submit a hex-encoded, signed, PoW-solved transaction.
+    /// The transaction is validated and applied by the `StateEngine` in the node's
+    /// main loop, then broadcast to peers via P2P. Waits up to `submit_timeout` for
+    /// the outcome: a rejection comes back as error `-32010`.
+    async fn submit_transaction(&self, tx_hex: String) -> RpcResult<RpcSubmitResult> {
+        if !self.state.rate_limiter.check(chrono::Utc::now().timestamp()) {
+            return Err(rpc_err(-32005, "rate limit exceeded, retry next minute"));
+        }
+
         let tx_bytes =
             hex::decode(&tx_hex).map_err(|e| rpc_err(-32602, format!("invalid hex: {e}")))?;
 
         let tx: Transaction = bincode::deserialize(&tx_bytes)
-            .map_err(|e| {
-                let hex_preview = if tx_hex.len() > 400 { &tx_hex[..400] } else { &tx_hex };
-                eprintln!("[DEBUG] DESER FAIL: {} | len={} | first400hex={}", e, tx_bytes.len(), hex_preview);
-                rpc_err(-32602, format!("invalid transaction encoding: {e}"))
-            })?;
+            .map_err(|e| rpc_err(-32602, format!("invalid transaction encoding: {e}")))?;
 
         let tx_id = tx.tx_id.to_hex();
 
-        if let Some(sender) = &self.state.tx_sender {
-            sender
-                .send(tx)
-                .await
-                .map_err(|_| rpc_err(-32603, "transaction queue full"))?;
-        } else {
-            warn!("RPC: sendTransaction called but no tx pipeline configured");
+        let Some(sender) = &self.state.tx_sender else {
+            warn!("RPC: transaction submitted but no tx pipeline configured");
             return Err(rpc_err(-32603, "node tx pipeline not connected"));
+        };
+        let (outcome_tx, outcome_rx) = tokio::sync::oneshot::channel();
+        sender
+            .send(SubmittedTx { tx, outcome: Some(outcome_tx) })
+            .await
+            .map_err(|_| rpc_err(-32603, "transaction queue full"))?;
+
+        match tokio::time::timeout(self.state.submit_timeout, outcome_rx).await {
+            Ok(Ok(Ok(_))) => Ok(RpcSubmitResult { tx_id, status: "applied".into() }),
+            Ok(Ok(Err(reason))) => Err(rpc_err(-32010, reason)),
+            // Still queued (or still being verified) when the wait ran out;
+            // chronx_getTransactionStatus reports how it ends.
+            Ok(Err(_)) | Err(_) => Ok(RpcSubmitResult { tx_id, status: "pending".into() }),
         }
+    }
 
-        Ok(tx_id)
+    /// `chronx_getTransactionStatus` — the DAG first, then the mempool's
+    /// queue and its record of recent drops.
+    async fn get_transaction_status(&self, tx_id: String) -> RpcResult<RpcTransactionStatus> {
+        let id = TxId::from_hex(&tx_id).map_err(|e| rpc_err(-32602, format!("invalid tx id: {e}")))?;
+        let status = |status: &str| RpcTransactionStatus {
+            tx_id: tx_id.clone(),
+            status: status.into(),
+            reason: None,
+            rejected_at: None,
+        };
+        if self.state.db.vertex_exists(&id) {
+            return Ok(status("applied"));
+        }
+        if let Some(mempool) = &self.state.mempool {
+            let pool = mempool.lock().unwrap_or_else(|e| e.into_inner());
+            if pool.get(&id).is_some() {
+                return Ok(status("pending"));
+            }
+            let dropped = pool.recently_dropped().find(|d| d.tx_id == id).cloned();
+            if let Some(dropped) = dropped {
+                return Ok(RpcTransactionStatus {
+                    reason: Some(dropped.reason),
+                    rejected_at: Some(dropped.at),
+                    ..status("rejected")
+                });
+            }
+        }
+        Ok(status("unknown"))
+    }
+
+    /// `chronx_estimateFee` — serialized action bytes times the fee rate per
+    /// byte stored in meta, floored at `MIN_FEE_CHRONOS` for the suggestion.
+    async fn estimate_fee(&self, actions_json: Vec<serde_json::Value>) -> RpcResult<RpcFeeEstimate> {
+        use chronx_core::constants::MIN_FEE_CHRONOS;
+
+        let mut bytes: u128 = 0;
+        for (i, value) in actions_json.into_iter().enumerate() {
+            let action: Action = serde_json::from_value(value)
+                .map_err(|e| rpc_err(-32602, format!("action {i}: {e}")))?;
+            let encoded = bincode::serialize(&action).map_err(|e| rpc_err(-32603, e.to_string()))?;
+            bytes += encoded.len() as u128;
+        }
+        let rate = self
+            .state
+            .db
+            .get_fee_rate_per_byte()
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+        let base = bytes.saturating_mul(rate);
+        // A no-op while the minimum is zero.
+        #[allow(clippy::unnecessary_min_or_max)]
+        let suggested = base.max(MIN_FEE_CHRONOS);
+        Ok(RpcFeeEstimate {
+            base_fee_chronos: base.to_string(),
+            suggested_fee_chronos: suggested.to_string(),
+            note: format!("{bytes} serialized action bytes at {rate} Chronos per byte"),
+        })
+    }
+
+    /// `chronx_simulateTransaction` — dry-run a transaction against current
+    /// state. Runs the same validation and actions as the node's apply loop
+    /// but commits nothing.
+    async fn simulate_transaction(&self, tx_hex: String) -> RpcResult<RpcSimulationResult> {
+        let tx_bytes =
+            hex::decode(&tx_hex).map_err(|e| rpc_err(-32602, format!("invalid hex: {e}")))?;
+        let tx: Transaction = bincode::deserialize(&tx_bytes)
+            .map_err(|e| rpc_err(-32602, format!("invalid transaction encoding: {e}")))?;
+
+        let engine = chronx_state::StateEngine::new(Arc::clone(&self.state.db), self.state.pow_difficulty);
+        Ok(match engine.simulate(&tx, chrono::Utc::now().timestamp()) {
+            Ok(sim) => RpcSimulationResult {
+                ok: true,
+                error: None,
+                error_message: None,
+                balance_deltas: sim
+                    .balance_deltas
+                    .into_iter()
+                    .map(|(id, delta)| RpcBalanceDelta { account_id: id.to_string(), delta_chronos: delta.to_string() })
+                    .collect(),
+            },
+            Err(e) => RpcSimulationResult {
+                ok: false,
+                error: Some(e.variant_name()),
+                error_message: Some(e.to_string()),
+                balance_deltas: Vec::new(),
+            },
+        })
     }
 
     /// `chronx_getTransaction` — fetch a serialised DAG vertex by transaction ID (hex).
@@ -302,7 +799,7 @@ impl ChronxApiServer for RpcServer {
                             action_type: "Transfer".to_string(),
                             to_address: Some(to.to_b58()),
                             amount_chronos: Some(amount.to_string()),
-                            amount_kx: Some((amount / CHRONOS_PER_KX).to_string()),
+                            amount_kx: Some(kx_string(*amount)),
                             lock_until: None,
                             memo: None,
                             email_hash: None,
@@ -317,7 +814,7 @@ impl ChronxApiServer for RpcServer {
                                 action_type: atype.to_string(),
                                 to_address: None,
                                 amount_chronos: Some(amount.to_string()),
-                                amount_kx: Some((amount / CHRONOS_PER_KX).to_string()),
+                                amount_kx: Some(kx_string(*amount)),
                                 lock_until: Some(*unlock_at),
                                 memo: memo.clone(),
                                 email_hash: email_hash_hex,
@@ -368,7 +865,7 @@ impl ChronxApiServer for RpcServer {
                             action_type: "LoanOffer".to_string(),
                             to_address: Some(lo.borrower_wallet.to_string()),
                             amount_chronos: Some(lo.principal_chronos.to_string()),
-                            amount_kx: Some((lo.principal_chronos as u128 / CHRONOS_PER_KX).to_string()),
+                            amount_kx: Some(kx_string(lo.principal_chronos as u128)),
                             lock_until: None,
                             memo: lo.memo.clone(),
                             email_hash: None,
@@ -418,7 +915,7 @@ impl ChronxApiServer for RpcServer {
                             action_type: "DrawRequest".to_string(),
                             to_address: None,
                             amount_chronos: Some(amount_chronos.to_string()),
-                            amount_kx: Some((*amount_chronos as u128 / CHRONOS_PER_KX).to_string()),
+                            amount_kx: Some(kx_string(*amount_chronos as u128)),
                             lock_until: None,
                             memo: None,
                             email_hash: None,
@@ -428,7 +925,7 @@ impl ChronxApiServer for RpcServer {
                             action_type: "DrawApproval".to_string(),
                             to_address: None,
                             amount_chronos: Some(amount_chronos.to_string()),
-                            amount_kx: Some((*amount_chronos as u128 / CHRONOS_PER_KX).to_string()),
+                            amount_kx: Some(kx_string(*amount_chronos as u128)),
                             lock_until: None,
                             memo: None,
                             email_hash: None,
@@ -448,7 +945,7 @@ impl ChronxApiServer for RpcServer {
                             action_type: "PartialExit".to_string(),
                             to_address: None,
                             amount_chronos: Some(amount_chronos.to_string()),
-                            amount_kx: Some((*amount_chronos as u128 / CHRONOS_PER_KX).to_string()),
+                            amount_kx: Some(kx_string(*amount_chronos as u128)),
                             lock_until: None,
                             memo: None,
                             email_hash: None,
@@ -475,7 +972,7 @@ impl ChronxApiServer for RpcServer {
                             action_type: "DepositCreate".to_string(),
                             to_address: Some("Savings".to_string()),
                             amount_chronos: Some(d.principal_chronos.to_string()),
-                            amount_kx: Some((d.principal_chronos as u128 / CHRONOS_PER_KX).to_string()),
+                            amount_kx: Some(kx_string(d.principal_chronos as u128)),
                             lock_until: Some(d.term_seconds as i64),
                             memo: None,
                             email_hash: None,
@@ -485,7 +982,7 @@ impl ChronxApiServer for RpcServer {
                             action_type: "DepositSettle".to_string(),
                             to_address: Some("Available Balance".to_string()),
                             amount_chronos: Some(s.amount_chronos.to_string()),
-                            amount_kx: Some((s.amount_chronos as u128 / CHRONOS_PER_KX).to_string()),
+                            amount_kx: Some(kx_string(s.amount_chronos as u128)),
                             lock_until: None,
                             memo: None,
                             email_hash: None,
@@ -505,7 +1002,7 @@ impl ChronxApiServer for RpcServer {
                             action_type: "SavingsDeposit".to_string(),
                             to_address: None,
                             amount_chronos: Some(amount_chronos.to_string()),
-                            amount_kx: Some((*amount_chronos as u128 / CHRONOS_PER_KX).to_string()),
+                            amount_kx: Some(kx_string(*amount_chronos as u128)),
                             lock_until: None,
                             memo: None,
                             email_hash: None,
@@ -515,7 +1012,7 @@ impl ChronxApiServer for RpcServer {
                             action_type: "SavingsWithdraw".to_string(),
                             to_address: None,
                             amount_chronos: Some(amount_chronos.to_string()),
-                            amount_kx: Some((*amount_chronos as u128 / CHRONOS_PER_KX).to_string()),
+                            amount_kx: Some(kx_string(*amount_chronos as u128)),
                             lock_until: None,
                             memo: None,
                             email_hash: None,
@@ -596,16 +1093,84 @@ impl ChronxApiServer for RpcServer {
     /// `chronx_getGenesisInfo` — genesis timestamp, total supply (Chronos and KX),
     /// and initial PoW difficulty.
     async fn get_genesis_info(&self) -> RpcResult<RpcGenesisInfo> {
-        Ok(RpcGenesisInfo::current(self.state.pow_difficulty))
+        use chronx_genesis::{humanity_lock_id, treasury_lock_id, GENESIS_PUBLIC_SALE_META_KEY};
+
+        let db = &self.state.db;
+        let lock_sender = |id: TxId| {
+            db.get_timelock(&id)
+                .ok()
+                .flatten()
+                .map(|tlc| tlc.sender.to_b58())
+        };
+        let mut info = RpcGenesisInfo::current(self.state.pow_difficulty);
+        info.public_sale_account = db
+            .get_meta(GENESIS_PUBLIC_SALE_META_KEY)
+            .ok()
+            .flatten()
+            .and_then(|b| String::from_utf8(b).ok());
+        info.treasury_account = lock_sender(treasury_lock_id(1));
+        info.humanity_account = lock_sender(humanity_lock_id());
+        Ok(info)
     }
 
     /// `chronx_getNetworkInfo` — the node's full libp2p multiaddress
     /// (e.g. `/ip4/1.2.3.4/tcp/7777/p2p/<PeerId>`). Share with other nodes
     /// as a `--bootstrap` peer.
     async fn get_network_info(&self) -> RpcResult<RpcNetworkInfo> {
+        let stats = self
+            .state
+            .p2p_stats
+            .as_ref()
+            .map(|s| s.read().unwrap_or_else(|e| e.into_inner()).clone())
+            .unwrap_or_default();
         Ok(RpcNetworkInfo {
             peer_multiaddr: self.state.peer_multiaddr.clone().unwrap_or_default(),
             peer_count: self.state.peer_count.load(Ordering::Relaxed),
+            kademlia_routing_table_size: stats.kademlia_routing_table_size,
+            gossipsub_mesh_size: stats.gossipsub_mesh_peers.len() as u32,
+            gossipsub_mesh_peers: stats.gossipsub_mesh_peers,
+            connected_peers: stats.connected_peers,
+            bootstrap_peers: stats.bootstrap_peers,
+            node_uptime_secs: self.state.started_at.elapsed().as_secs(),
+            p2p_protocol_version: stats.protocol_version,
+            network_id: stats.network_id,
+            outbound_peers: stats.outbound_peers as u32,
+            inbound_peers: stats.inbound_peers as u32,
+            peers_evicted: stats.peers_evicted,
+        })
+    }
+
+    async fn get_network_topology(&self) -> RpcResult<RpcNetworkTopology> {
+        if !self.state.topology_enabled {
+            return Err(rpc_err(-32603, "network topology is disabled on this node"));
+        }
+        let stats = self
+            .state
+            .p2p_stats
+            .as_ref()
+            .map(|s| s.read().unwrap_or_else(|e| e.into_inner()).clone())
+            .unwrap_or_default();
+        let connections = stats
+            .connections
+            .into_iter()
+            .map(|c| RpcPeerConnection {
+                peer_id: c.peer_id,
+                multiaddr: c.multiaddr,
+                direction: match c.direction {
+                    chronx_p2p::Direction::Inbound => "inbound",
+                    chronx_p2p::Direction::Outbound => "outbound",
+                }
+                .to_string(),
+                connected_since: c.connected_since,
+                gossipsub_in_mesh: c.gossipsub_in_mesh,
+                reputation_score: c.reputation_score,
+            })
+            .collect();
+        Ok(RpcNetworkTopology {
+            self_peer_id: stats.local_peer_id,
+            connections,
+            kademlia_table_size: stats.kademlia_routing_table_size,
+            gossipsub_mesh_peers: stats.gossipsub_mesh_peers,
         })
     }
 
@@ -711,9 +1276,87 @@ impl ChronxApiServer for RpcServer {
             opened_at: cs.opened_at,
             agent_id: cs.agent_id.map(|a| a.to_b58()),
             status,
+            claim_attempts: cs.claim_attempts,
+            upgraded_lane: cs.upgraded_lane,
+            lane_upgraded_at: cs.lane_upgraded_at,
         }))
     }
 
+    async fn get_claim_state_history(&self, lock_id: String) -> RpcResult<Vec<RpcClaimHistoryEntry>> {
+        let id = TxId::from_hex(&lock_id)
+            .map_err(|e| rpc_err(-32602, format!("invalid lock id: {e}")))?;
+        let history = self
+            .state
+            .db
+            .get_claim_history(&id)
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+        Ok(history
+            .into_iter()
+            .map(|r| RpcClaimHistoryEntry {
+                status: r.status,
+                at: r.at,
+                tx_id: Some(r.tx_id.to_hex()),
+                actor: Some(r.actor.to_b58()),
+                details: serde_json::json!({
+                    "lane": r.lane,
+                    "bond_chronos": r.bond_amount.to_string(),
+                    "certificate_count": r.certificate_count,
+                }),
+            })
+            .collect())
+    }
+
+    async fn get_difficulty(&self) -> RpcResult<RpcDifficulty> {
+        use chronx_core::constants::{POW_MAX_DIFFICULTY, POW_MIN_DIFFICULTY, POW_TARGET_SOLVE_MS};
+
+        let history = self
+            .state
+            .db
+            .get_difficulty_history()
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+        // Everything below is derived from the DAG, so every node reports the
+        // same difficulty for the same tips.
+        let db = &self.state.db;
+        let tips = db.get_tips().map_err(|e| rpc_err(-32603, e.to_string()))?;
+        let lookup = |id: &TxId| db.get_vertex(id).ok().flatten();
+        let current = chronx_dag::required_difficulty(&tips, self.state.pow_difficulty, lookup);
+        let window = chronx_dag::difficulty::reference_parent(&tips, &lookup)
+            .map(|tip| chronx_dag::difficulty::window_timestamps_ms(tip, lookup))
+            .unwrap_or_default();
+        let ema = match window.as_slice() {
+            [first, .., last] => (last - first) as f64 / (window.len() - 1) as f64,
+            _ => 0.0,
+        };
+        // The first adjustment is measured against the configured difficulty.
+        let direction = match history.as_slice() {
+            [] => "none",
+            [.., before, last] => direction_name(before.1, last.1),
+            [only] => direction_name(self.state.pow_difficulty, only.1),
+        };
+        Ok(RpcDifficulty {
+            current_difficulty: current,
+            min_difficulty: POW_MIN_DIFFICULTY,
+            max_difficulty: POW_MAX_DIFFICULTY,
+            target_interval_ms: POW_TARGET_SOLVE_MS,
+            current_ema_ms: ema,
+            last_adjustment_depth: history.last().map_or(0, |&(depth, _, _)| depth),
+            last_adjustment_direction: direction.to_string(),
+        })
+    }
+
+    async fn get_difficulty_history(&self, limit: u32) -> RpcResult<Vec<RpcDifficultyAdjustment>> {
+        let history = self
+            .state
+            .db
+            .get_difficulty_history()
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+        let skip = history.len().saturating_sub(limit as usize);
+        Ok(history[skip..]
+            .iter()
+            .map(|&(depth, difficulty, at)| RpcDifficultyAdjustment { depth, difficulty, at })
+            .collect())
+    }
+
     async fn get_oracle_snapshot(&self, pair: String) -> RpcResult<Option<RpcOracleSnapshot>> {
         let snap = self
             .state
@@ -728,6 +1371,34 @@ impl ChronxApiServer for RpcServer {
         }))
     }
 
+    /// `chronx_getOracleHistory` — price points for charting a pair over time.
+    async fn get_oracle_history(
+        &self,
+        pair: String,
+        from_ts: i64,
+        to_ts: i64,
+        limit: u32,
+    ) -> RpcResult<Vec<RpcOracleHistoryEntry>> {
+        if to_ts < from_ts {
+            return Err(rpc_err(-32602, "need to_ts >= from_ts"));
+        }
+        let limit = (limit as usize).min(MAX_ORACLE_HISTORY_POINTS);
+        let history = self
+            .state
+            .db
+            .iter_oracle_history(&pair, from_ts, to_ts)
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+        Ok(history
+            .into_iter()
+            .take(limit)
+            .map(|h| RpcOracleHistoryEntry {
+                price_cents: h.price_cents,
+                num_submissions: h.num_submissions,
+                updated_at: h.updated_at,
+            })
+            .collect())
+    }
+
     // ── V3 New methods ────────────────────────────────────────────────────────
 
     /// `chronx_getLockById` — fetch a single time-lock by its TxId hex.
@@ -742,6 +1413,52 @@ impl ChronxApiServer for RpcServer {
         Ok(tlc.map(tlc_to_rpc))
     }
 
+    /// `chronx_getPrivateTimelockDetails` — unredacted lock, once the caller
+    /// proves they are its sender or recipient by signing this hour's challenge.
+    async fn get_private_timelock_details(
+        &self,
+        lock_id: String,
+        challenge_signature_hex: String,
+    ) -> RpcResult<RpcTimeLock> {
+        let id = TxId::from_hex(&lock_id)
+            .map_err(|e| rpc_err(-32602, format!("invalid lock id: {e}")))?;
+        let sig = hex::decode(&challenge_signature_hex)
+            .map(DilithiumSignature)
+            .map_err(|e| rpc_err(-32602, format!("invalid signature hex: {e}")))?;
+        let tlc = self
+            .state
+            .db
+            .get_timelock(&id)
+            .map_err(|e| rpc_err(-32603, e.to_string()))?
+            .ok_or_else(|| rpc_err(-32602, format!("lock not found: {lock_id}")))?;
+
+        let challenge =
+            chronx_crypto::private_lock_view_challenge(&id, chrono::Utc::now().timestamp());
+        let mut keys = vec![tlc.recipient_key.clone()];
+        for party in [&tlc.sender, &tlc.recipient_account_id] {
+            let acc = self
+                .state
+                .db
+                .get_account(party)
+                .map_err(|e| rpc_err(-32603, e.to_string()))?;
+            match acc.map(|a| a.auth_policy) {
+                Some(AuthPolicy::SingleSig { public_key }) => keys.push(public_key),
+                Some(AuthPolicy::RecoveryEnabled { owner_key, .. }) => keys.push(owner_key),
+                _ => {}
+            }
+        }
+        if !keys
+            .iter()
+            .any(|pk| chronx_crypto::verify_signature(pk, &challenge, &sig).is_ok())
+        {
+            return Err(rpc_err(
+                -32602,
+                "signature does not match the lock's sender or recipient for the current hour",
+            ));
+        }
+        Ok(tlc_to_rpc(tlc))
+    }
+
     /// `chronx_getPendingIncoming` — all `Pending` locks where the account is the recipient,
     /// sorted by `unlock_at` ascending (soonest first).
     async fn get_pending_incoming(&self, account_id: String) -> RpcResult<Vec<RpcTimeLock>> {
@@ -830,6 +1547,24 @@ impl ChronxApiServer for RpcServer {
             .flatten()
             .map(hex::encode);
 
+        let burnt_supply_chronos = self
+            .state
+            .db
+            .get_burned_chronos()
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+
+        let fee_collector = self
+            .state
+            .db
+            .get_fee_collector()
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+        let fee_pool_chronos = self
+            .state
+            .db
+            .get_account(&fee_collector)
+            .map_err(|e| rpc_err(-32603, e.to_string()))?
+            .map_or(0, |a| a.balance);
+
         Ok(RpcChainStats {
             total_accounts,
             total_timelocks,
@@ -837,7 +1572,9 @@ impl ChronxApiServer for RpcServer {
             dag_tip_count,
             dag_depth,
             total_supply_chronos: TOTAL_SUPPLY_CHRONOS.to_string(),
-            total_supply_kx: (TOTAL_SUPPLY_CHRONOS / CHRONOS_PER_KX).to_string(),
+            total_supply_kx: kx_string(TOTAL_SUPPLY_CHRONOS),
+            burnt_supply_chronos: burnt_supply_chronos.to_string(),
+            fee_pool_chronos: fee_pool_chronos.to_string(),
             state_root,
         })
     }
@@ -892,6 +1629,116 @@ impl ChronxApiServer for RpcServer {
         Ok(locks)
     }
 
+    async fn get_locks_expiring_today(&self, timezone_offset_hours: i32) -> RpcResult<Vec<RpcTimeLock>> {
+        self.locks_expiring_in(CalendarPeriod::Day, timezone_offset_hours)
+    }
+
+    async fn get_locks_expiring_this_week(&self, timezone_offset_hours: i32) -> RpcResult<Vec<RpcTimeLock>> {
+        self.locks_expiring_in(CalendarPeriod::Week, timezone_offset_hours)
+    }
+
+    async fn get_locks_expiring_this_month(&self, timezone_offset_hours: i32) -> RpcResult<Vec<RpcTimeLock>> {
+        self.locks_expiring_in(CalendarPeriod::Month, timezone_offset_hours)
+    }
+
+    /// `chronx_getAccountLockTimeline` — the account's pending locks by
+    /// unlock day, read from the unlock-time index.
+    async fn get_account_lock_timeline(
+        &self,
+        account_id: String,
+        from_ts: Option<i64>,
+        to_ts: Option<i64>,
+        role: String,
+    ) -> RpcResult<Vec<RpcLockTimelineDay>> {
+        let id = AccountId::from_b58(&account_id).map_err(|e| rpc_err(-32602, e.to_string()))?;
+        let (as_sender, as_recipient) = match role.as_str() {
+            "sender" => (true, false),
+            "recipient" => (false, true),
+            "both" => (true, true),
+            _ => return Err(rpc_err(-32602, "role must be sender, recipient or both")),
+        };
+        let locks = self
+            .state
+            .db
+            .iter_pending_timelocks_unlocking(from_ts.unwrap_or(0), to_ts.unwrap_or(i64::MAX))
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+
+        let mut days: Vec<(RpcLockTimelineDay, u128)> = Vec::new();
+        for tlc in locks {
+            let counterparty = if as_sender && tlc.sender == id {
+                &tlc.recipient_account_id
+            } else if as_recipient && tlc.recipient_account_id == id {
+                &tlc.sender
+            } else {
+                continue;
+            };
+            let date = chrono::DateTime::from_timestamp(tlc.unlock_at, 0)
+                .map(|d| d.format("%Y-%m-%d").to_string())
+                .unwrap_or_default();
+            let entry = RpcLockTimelineEntry {
+                lock_id: tlc.id.to_hex(),
+                amount_kx: kx_string(tlc.amount),
+                counterparty: counterparty.to_b58(),
+            };
+            // The index yields locks by unlock time, so a day's locks are adjacent.
+            match days.last_mut() {
+                Some((day, total)) if day.unlock_date_iso == date => {
+                    day.locks.push(entry);
+                    *total = total.saturating_add(tlc.amount);
+                }
+                _ => days.push((
+                    RpcLockTimelineDay { unlock_date_iso: date, locks: vec![entry], total_amount_kx: String::new() },
+                    tlc.amount,
+                )),
+            }
+        }
+        Ok(days
+            .into_iter()
+            .map(|(day, total)| RpcLockTimelineDay { total_amount_kx: kx_string(total), ..day })
+            .collect())
+    }
+
+    /// `chronx_getUnclaimedLockReport` — long-matured pending locks, read
+    /// from the unlock-time index.
+    async fn get_unclaimed_lock_report(
+        &self,
+        older_than_secs: i64,
+        include_expired: bool,
+    ) -> RpcResult<Vec<RpcUnclaimedLock>> {
+        if !self.state.sweep_reports_enabled {
+            return Err(rpc_err(-32603, "sweep reports are disabled on this node"));
+        }
+        if older_than_secs < 0 {
+            return Err(rpc_err(-32602, "older_than_secs must not be negative"));
+        }
+        let now = chrono::Utc::now().timestamp();
+        let mut locks = self
+            .state
+            .db
+            .iter_pending_timelocks_unlocking(0, now.saturating_sub(older_than_secs))
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+        if !include_expired {
+            locks.retain(|tlc| {
+                tlc.claim_window_secs
+                    .is_none_or(|w| now <= tlc.created_at.saturating_add(w as i64))
+            });
+        }
+        locks.sort_by_key(|tlc| std::cmp::Reverse(tlc.amount));
+        Ok(locks
+            .into_iter()
+            .map(|tlc| RpcUnclaimedLock {
+                lock_id: tlc.id.to_hex(),
+                amount_kx: kx_string(tlc.amount),
+                recipient: tlc.recipient_account_id.to_b58(),
+                unlock_at: tlc.unlock_at,
+                matured_days_ago: (now - tlc.unlock_at) as f64 / 86_400.0,
+                has_claim_policy: tlc.claim_policy.is_some(),
+                expiry_policy: tlc.expiry_policy.as_ref().map(expiry_policy_str),
+                unclaimed_action: tlc.unclaimed_action.as_ref().map(unclaimed_action_str),
+            })
+            .collect())
+    }
+
     /// `chronx_getVersion` — node version, protocol version ("3.3"), and API version.
     async fn get_version(&self) -> RpcResult<RpcVersionInfo> {
         Ok(RpcVersionInfo {
@@ -925,7 +1772,7 @@ impl ChronxApiServer for RpcServer {
 
         if let Some(sender) = &self.state.tx_sender {
             sender
-                .send(tx)
+                .send(tx.into())
                 .await
                 .map_err(|_| rpc_err(-32603, "transaction queue full"))?;
         } else {
@@ -1153,6 +2000,89 @@ impl ChronxApiServer for RpcServer {
         results.truncate(500);
         Ok(results)
     }
+    /// `chronx_getExplorerSummary` — block explorer home page data, served
+    /// from a 60-second cache.
+    async fn get_explorer_summary(&self) -> RpcResult<RpcExplorerSummary> {
+        let now = chrono::Utc::now().timestamp();
+        if let Some((computed_at, summary)) = self.explorer_cache.lock().unwrap().as_ref() {
+            if now - computed_at < EXPLORER_CACHE_SECS {
+                return Ok(summary.clone());
+            }
+        }
+
+        let db = &self.state.db;
+        let chain_stats = self.get_chain_stats().await?;
+        let recent_transactions = self.get_recent_transactions(EXPLORER_RECENT_TXS).await?;
+        let total_locked_kx = self.get_lock_stats().await?.total_locked_kx;
+
+        let top_lock_holders = db
+            .top_locked_incoming(EXPLORER_TOP_HOLDERS)
+            .map_err(|e| rpc_err(-32603, e.to_string()))?
+            .into_iter()
+            .map(|(id, chronos)| RpcLockHolder {
+                account_id: id.to_b58(),
+                pending_incoming_kx: kx_string(chronos),
+            })
+            .collect();
+        let oracle_snapshots = db
+            .iter_oracle_snapshots()
+            .map_err(|e| rpc_err(-32603, e.to_string()))?
+            .into_iter()
+            .map(|s| RpcOracleSnapshot {
+                pair: s.pair,
+                price_cents: s.price_cents,
+                num_submissions: s.num_submissions,
+                updated_at: s.updated_at,
+            })
+            .collect();
+        let active_recoveries = db.count_active_recoveries().map_err(|e| rpc_err(-32603, e.to_string()))?;
+        let providers_active = db
+            .iter_providers()
+            .map_err(|e| rpc_err(-32603, e.to_string()))?
+            .iter()
+            .filter(|p| matches!(p.status, ProviderStatus::Active))
+            .count();
+        let schemas_active = db
+            .iter_schemas()
+            .map_err(|e| rpc_err(-32603, e.to_string()))?
+            .iter()
+            .filter(|s| s.active)
+            .count();
+
+        let today = now.div_euclid(86_400);
+        let mut daily = [0u64; 7];
+        for v in db.iter_all_vertices().map_err(|e| rpc_err(-32603, e.to_string()))? {
+            let days_ago = today - v.transaction.timestamp.div_euclid(86_400);
+            if (0..7).contains(&days_ago) {
+                daily[6 - days_ago as usize] += 1;
+            }
+        }
+        let daily_transaction_counts_7d = daily
+            .iter()
+            .enumerate()
+            .map(|(i, &count)| RpcDayCount {
+                date: chrono::DateTime::from_timestamp((today - 6 + i as i64) * 86_400, 0)
+                    .map(|d| d.format("%Y-%m-%d").to_string())
+                    .unwrap_or_default(),
+                count,
+            })
+            .collect();
+
+        let summary = RpcExplorerSummary {
+            chain_stats,
+            recent_transactions,
+            top_lock_holders,
+            oracle_snapshots,
+            active_recoveries: active_recoveries as u32,
+            providers_active: providers_active as u32,
+            schemas_active: schemas_active as u32,
+            daily_transaction_counts_7d,
+            total_locked_kx,
+        };
+        *self.explorer_cache.lock().unwrap() = Some((now, summary.clone()));
+        Ok(summary)
+    }
+
     /// `chronx_getLockStats` — aggregate stats across all Pending timelocks.
     /// Designed for the public website stats bar: single cheap call, no pagination.
     async fn get_lock_stats(&self) -> RpcResult<RpcGlobalLockStats> {
@@ -1172,13 +2102,10 @@ impl ChronxApiServer for RpcServer {
             }
         }
 
-        const CHRONOS_PER_KX: u128 = 1_000_000;
-        let total_locked_kx = total_locked_chronos / CHRONOS_PER_KX;
-
         Ok(RpcGlobalLockStats {
             active_lock_count,
             total_locked_chronos: total_locked_chronos.to_string(),
-            total_locked_kx: total_locked_kx.to_string(),
+            total_locked_kx: kx_string(total_locked_chronos),
         })
     }
 
@@ -1241,7 +2168,7 @@ impl ChronxApiServer for RpcServer {
             claim_secret_hash,
             lock_count: locks.len() as u32,
             total_chronos: total_chronos.to_string(),
-            total_kx: (total_chronos / CHRONOS_PER_KX).to_string(),
+            total_kx: kx_string(total_chronos),
             pending_count,
             claimed_count,
             locks,
@@ -1318,7 +2245,7 @@ impl ChronxApiServer for RpcServer {
             .unwrap_or(0);
         Ok(RpcHumanityStakeBalance {
             balance_chronos: balance.to_string(),
-            balance_kx: (balance / CHRONOS_PER_KX).to_string(),
+            balance_kx: kx_string(balance),
         })
     }
 
@@ -1461,7 +2388,7 @@ impl ChronxApiServer for RpcServer {
                 lock_id: tlc.id.to_hex(),
                 sender: tlc.sender.to_b58(),
                 amount_chronos: tlc.amount.to_string(),
-                amount_kx: (tlc.amount / CHRONOS_PER_KX).to_string(),
+                amount_kx: kx_string(tlc.amount),
                 unlock_at: tlc.unlock_at,
                 lock_type: tlc.lock_type,
                 lock_metadata: tlc.lock_metadata,
@@ -1521,7 +2448,7 @@ impl ChronxApiServer for RpcServer {
                             action_type: "Transfer".to_string(),
                             to_address: Some(to.to_b58()),
                             amount_chronos: Some(amount.to_string()),
-                            amount_kx: Some((amount / CHRONOS_PER_KX).to_string()),
+                            amount_kx: Some(kx_string(*amount)),
                             lock_until: None,
                             memo: None,
                             email_hash: None,
@@ -1536,7 +2463,7 @@ impl ChronxApiServer for RpcServer {
                                 action_type: atype.to_string(),
                                 to_address: None,
                                 amount_chronos: Some(amount.to_string()),
-                                amount_kx: Some((amount / CHRONOS_PER_KX).to_string()),
+                                amount_kx: Some(kx_string(*amount)),
                                 lock_until: Some(*unlock_at),
                                 memo: memo.clone(),
                                 email_hash: email_hash_hex,
@@ -1587,7 +2514,7 @@ impl ChronxApiServer for RpcServer {
                             action_type: "LoanOffer".to_string(),
                             to_address: Some(lo.borrower_wallet.to_string()),
                             amount_chronos: Some(lo.principal_chronos.to_string()),
-                            amount_kx: Some((lo.principal_chronos as u128 / CHRONOS_PER_KX).to_string()),
+                            amount_kx: Some(kx_string(lo.principal_chronos as u128)),
                             lock_until: None,
                             memo: lo.memo.clone(),
                             email_hash: None,
@@ -1637,7 +2564,7 @@ impl ChronxApiServer for RpcServer {
                             action_type: "DrawRequest".to_string(),
                             to_address: None,
                             amount_chronos: Some(amount_chronos.to_string()),
-                            amount_kx: Some((*amount_chronos as u128 / CHRONOS_PER_KX).to_string()),
+                            amount_kx: Some(kx_string(*amount_chronos as u128)),
                             lock_until: None,
                             memo: None,
                             email_hash: None,
@@ -1647,7 +2574,7 @@ impl ChronxApiServer for RpcServer {
                             action_type: "DrawApproval".to_string(),
                             to_address: None,
                             amount_chronos: Some(amount_chronos.to_string()),
-                            amount_kx: Some((*amount_chronos as u128 / CHRONOS_PER_KX).to_string()),
+                            amount_kx: Some(kx_string(*amount_chronos as u128)),
                             lock_until: None,
                             memo: None,
                             email_hash: None,
@@ -1667,7 +2594,7 @@ impl ChronxApiServer for RpcServer {
                             action_type: "PartialExit".to_string(),
                             to_address: None,
                             amount_chronos: Some(amount_chronos.to_string()),
-                            amount_kx: Some((*amount_chronos as u128 / CHRONOS_PER_KX).to_string()),
+                            amount_kx: Some(kx_string(*amount_chronos as u128)),
                             lock_until: None,
                             memo: None,
                             email_hash: None,
@@ -1694,7 +2621,7 @@ impl ChronxApiServer for RpcServer {
                             action_type: "DepositCreate".to_string(),
                             to_address: Some("Savings".to_string()),
                             amount_chronos: Some(d.principal_chronos.to_string()),
-                            amount_kx: Some((d.principal_chronos as u128 / CHRONOS_PER_KX).to_string()),
+                            amount_kx: Some(kx_string(d.principal_chronos as u128)),
                             lock_until: Some(d.term_seconds as i64),
                             memo: None,
                             email_hash: None,
@@ -1704,7 +2631,7 @@ impl ChronxApiServer for RpcServer {
                             action_type: "DepositSettle".to_string(),
                             to_address: Some("Available Balance".to_string()),
                             amount_chronos: Some(s.amount_chronos.to_string()),
-                            amount_kx: Some((s.amount_chronos as u128 / CHRONOS_PER_KX).to_string()),
+                            amount_kx: Some(kx_string(s.amount_chronos as u128)),
                             lock_until: None,
                             memo: None,
                             email_hash: None,
@@ -1724,7 +2651,7 @@ impl ChronxApiServer for RpcServer {
                             action_type: "SavingsDeposit".to_string(),
                             to_address: None,
                             amount_chronos: Some(amount_chronos.to_string()),
-                            amount_kx: Some((*amount_chronos as u128 / CHRONOS_PER_KX).to_string()),
+                            amount_kx: Some(kx_string(*amount_chronos as u128)),
                             lock_until: None,
                             memo: None,
                             email_hash: None,
@@ -1734,7 +2661,7 @@ impl ChronxApiServer for RpcServer {
                             action_type: "SavingsWithdraw".to_string(),
                             to_address: None,
                             amount_chronos: Some(amount_chronos.to_string()),
-                            amount_kx: Some((*amount_chronos as u128 / CHRONOS_PER_KX).to_string()),
+                            amount_kx: Some(kx_string(*amount_chronos as u128)),
                             lock_until: None,
                             memo: None,
                             email_hash: None,
@@ -2023,7 +2950,7 @@ impl ChronxApiServer for RpcServer {
 
         if let Some(sender) = &self.state.tx_sender {
             sender
-                .send(tx)
+                .send(tx.into())
                 .await
                 .map_err(|_| rpc_err(-32603, "transaction queue full"))?;
         } else {
@@ -3113,10 +4040,18 @@ impl ChronxApiServer for RpcServer {
             .map_err(|e| rpc_err(-32603, e.to_string()))?
             .unwrap_or([0u8; 32]);
 
+        let (at_depth, at_timestamp) = self
+            .state
+            .db
+            .get_latest_state_root_at()
+            .map_err(|e| rpc_err(-32603, e.to_string()))?
+            .unwrap_or((0, 0));
         let vertex_count = self.state.db.count_vertices();
 
         Ok(RpcStateRoot {
-            root: hex::encode(root),
+            root_hex: hex::encode(root),
+            at_depth,
+            at_timestamp,
             vertex_count,
         })
     }
@@ -3153,118 +4088,1327 @@ impl ChronxApiServer for RpcServer {
         })
     }
 
-}
+    /// `chronx_getLockCountdownBatch` — countdowns for several locks at once.
+    async fn get_lock_countdown_batch(&self, lock_ids: Vec<String>) -> RpcResult<Vec<RpcLockCountdown>> {
+        if lock_ids.len() > MAX_COUNTDOWN_BATCH {
+            return Err(rpc_err(
+                -32602,
+                format!("at most {MAX_COUNTDOWN_BATCH} lock ids per call"),
+            ));
+        }
+        // One timestamp for the whole batch so every entry is consistent.
+        let now = chrono::Utc::now().timestamp();
+        lock_countdowns(&self.state.db, &lock_ids, now).map_err(|e| rpc_err(-32603, e.to_string()))
+    }
+
+    /// `chronx_getLockSummaryBatch` — one summary per id, read concurrently.
+    async fn get_lock_summary_batch(&self, lock_ids: Vec<String>) -> RpcResult<Vec<RpcLockSummary>> {
+        if lock_ids.len() > MAX_SUMMARY_BATCH {
+            return Err(rpc_err(
+                -32602,
+                format!("at most {MAX_SUMMARY_BATCH} lock ids per call"),
+            ));
+        }
+        let now = chrono::Utc::now().timestamp();
+        let reads = lock_ids.into_iter().map(|lock_id| {
+            let db = Arc::clone(&self.state.db);
+            tokio::task::spawn_blocking(move || {
+                let tlc = match TxId::from_hex(&lock_id) {
+                    Ok(id) => db.get_timelock(&id)?,
+                    Err(_) => None,
+                };
+                Ok::<_, ChronxError>(lock_summary(lock_id, tlc, now))
+            })
+        });
+        futures::future::join_all(reads)
+            .await
+            .into_iter()
+            .map(|read| {
+                read.map_err(|e| rpc_err(-32603, e.to_string()))?
+                    .map_err(|e| rpc_err(-32603, e.to_string()))
+            })
+            .collect()
+    }
 
+    /// `chronx_getRecoveryStatus` — every `RecoveryState` field for one account.
+    async fn get_recovery_status(&self, account_id: String) -> RpcResult<Option<RpcRecoveryStatus>> {
+        use chronx_core::account::RecoveryDecisionStatus;
+        use chronx_core::constants::RECOVERY_VERIFIER_THRESHOLD;
 
-// ── RPC conversion helpers ──────────────────────────────────────
+        let id = AccountId::from_b58(&account_id)
+            .map_err(|e| rpc_err(-32602, format!("invalid account id: {e}")))?;
+        let Some(acc) = self
+            .state
+            .db
+            .get_account(&id)
+            .map_err(|e| rpc_err(-32603, e.to_string()))?
+        else {
+            return Ok(None);
+        };
 
-fn invoice_to_rpc(r: &chronx_state::db::InvoiceRecord) -> RpcInvoiceRecord {
-    let status = match r.status {
-        InvoiceStatus::Open => "Open",
-        InvoiceStatus::Fulfilled => "Fulfilled",
-        InvoiceStatus::Lapsed => "Lapsed",
-        InvoiceStatus::Cancelled => "Cancelled",
-        InvoiceStatus::Rejected => "Rejected",
-    };
-    RpcInvoiceRecord {
-        invoice_id: hex::encode(r.invoice_id),
-        issuer_pubkey: hex::encode(&r.issuer_pubkey),
-        payer_pubkey: r.payer_pubkey.as_ref().map(hex::encode),
-        amount_chronos: r.amount_chronos.to_string(),
-        amount_kx: format!("{}", r.amount_chronos / CHRONOS_PER_KX as u64),
-        expiry: r.expiry,
-        status: status.to_string(),
-        created_at: r.created_at,
-        fulfilled_at: r.fulfilled_at,
-    }
-}
+        let rs = acc.recovery_state;
+        let now = chrono::Utc::now().timestamp();
+        let decision_status = match rs.decision_status {
+            RecoveryDecisionStatus::Pending => "Pending",
+            RecoveryDecisionStatus::Approved => "Approved",
+            RecoveryDecisionStatus::Rejected => "Rejected",
+        };
 
-fn credit_to_rpc(r: &chronx_state::db::CreditRecord) -> RpcCreditRecord {
-    let status = match r.status {
-        CreditStatus::Open => "Open",
-        CreditStatus::Closed => "Closed",
-        CreditStatus::Lapsed => "Lapsed",
-        CreditStatus::Revoked => "Revoked",
-    };
-    RpcCreditRecord {
-        credit_id: hex::encode(r.credit_id),
-        grantor_pubkey: hex::encode(&r.grantor_pubkey),
-        beneficiary_pubkey: hex::encode(&r.beneficiary_pubkey),
-        ceiling_chronos: r.ceiling_chronos.to_string(),
-        ceiling_kx: format!("{}", r.ceiling_chronos / CHRONOS_PER_KX as u64),
-        per_draw_max_chronos: r.per_draw_max_chronos.map(|v| v.to_string()),
-        expiry: r.expiry,
-        drawn_chronos: r.drawn_chronos.to_string(),
-        drawn_kx: format!("{}", r.drawn_chronos / CHRONOS_PER_KX as u64),
-        status: status.to_string(),
-        created_at: r.created_at,
+        Ok(Some(RpcRecoveryStatus {
+            account_id: acc.account_id.to_b58(),
+            active: rs.active,
+            initiator: rs.initiator.map(|a| a.to_b58()),
+            challenger: rs.challenger.map(|a| a.to_b58()),
+            proposed_owner_key: rs.proposed_owner_key.map(|k| hex::encode(&k.0)),
+            recovery_bond_chronos: rs.recovery_bond.to_string(),
+            challenge_bond_chronos: rs.challenge_bond.to_string(),
+            recovery_start_time: rs.recovery_start_time,
+            recovery_execute_after: rs.recovery_execute_after,
+            seconds_until_execution: rs.recovery_execute_after.map(|t| (t - now).max(0)),
+            challenge_active: rs.challenge_active,
+            votes_approve: rs.votes_approve.len() as u32,
+            votes_reject: rs.votes_reject.len() as u32,
+            verifier_threshold: RECOVERY_VERIFIER_THRESHOLD,
+            decision_status: decision_status.to_string(),
+            evidence_hash: rs.evidence_hash.map(|h| h.to_hex()),
+            counter_evidence_hash: rs.counter_evidence_hash.map(|h| h.to_hex()),
+        }))
     }
-}
 
-fn deposit_to_rpc(r: &chronx_state::db::DepositRecord) -> RpcDepositRecord {
-    let status = match r.status {
-        DepositStatus::Active => "Active",
-        DepositStatus::Matured => "Matured",
-        DepositStatus::Settled => "Settled",
-        DepositStatus::Defaulted => "Defaulted",
-    };
-    RpcDepositRecord {
-        deposit_id: hex::encode(r.deposit_id),
-        depositor_pubkey: hex::encode(&r.depositor_pubkey),
-        obligor_pubkey: hex::encode(&r.obligor_pubkey),
-        principal_chronos: r.principal_chronos,
-        principal_kx: r.principal_chronos as f64 / CHRONOS_PER_KX as f64,
-        rate_basis_points: r.rate_basis_points,
-        term_seconds: r.term_seconds,
-        compounding: r.compounding.clone(),
-        maturity_timestamp: r.maturity_timestamp,
-        total_due_chronos: r.total_due_chronos,
-        total_due_kx: r.total_due_chronos as f64 / CHRONOS_PER_KX as f64,
-        status: status.to_string(),
-        created_at: r.created_at,
-        settled_at: r.settled_at,
+    /// `chronx_subscribeLockStatus` — register for status changes of one lock.
+    async fn subscribe_lock_status(
+        &self,
+        pending: PendingSubscriptionSink,
+        lock_id: String,
+    ) -> SubscriptionResult {
+        let id = match TxId::from_hex(&lock_id) {
+            Ok(id) => id,
+            Err(e) => {
+                pending.reject(rpc_err(-32602, format!("invalid lock id: {e}"))).await;
+                return Ok(());
+            }
+        };
+        let sink = pending.accept().await?;
+        let mut subscribers = self.state.lock_status_subscriptions.entry(id).or_default();
+        subscribers.retain(|s| !s.is_closed());
+        subscribers.push(sink);
+        Ok(())
     }
-}
 
-fn conditional_to_rpc(r: &chronx_state::db::ConditionalRecord) -> RpcConditionalRecord {
-    let status = match r.status {
-        ConditionalStatus::Pending => "Pending",
-        ConditionalStatus::Released => "Released",
-        ConditionalStatus::Voided => "Voided",
-        ConditionalStatus::Returned => "Returned",
-        ConditionalStatus::Escrowed => "Escrowed",
-        ConditionalStatus::PartiallyReleased => "PartiallyReleased",
-    };
-    RpcConditionalRecord {
-        type_v_id: hex::encode(r.type_v_id),
-        sender_pubkey: hex::encode(&r.sender_pubkey),
-        recipient_pubkey: hex::encode(&r.recipient_pubkey),
-        amount_chronos: r.amount_chronos.to_string(),
-        amount_kx: format!("{}", r.amount_chronos / CHRONOS_PER_KX as u64),
-        min_attestors: r.min_attestors,
-        attestations_received: r.attestations_received.len() as u32,
-        valid_until: r.valid_until,
-        fallback: r.fallback.clone(),
-        status: status.to_string(),
-        created_at: r.created_at,
+    /// `chronx_subscribeNewVertices` — every transaction the node applies.
+    async fn subscribe_new_vertices(&self, pending: PendingSubscriptionSink) -> SubscriptionResult {
+        let rx = self.state.events.subscribe();
+        let sink = pending.accept().await?;
+        tokio::spawn(forward_events(sink, rx, |event| match event {
+            RpcChainEvent::NewVertex(v) => Some(v),
+            RpcChainEvent::LockUpdate(_) => None,
+        }));
+        Ok(())
     }
-}
 
-fn ledger_entry_to_rpc(r: &chronx_state::db::LedgerEntryRecord) -> RpcLedgerEntryRecord {
-    RpcLedgerEntryRecord {
-        entry_id: hex::encode(r.entry_id),
-        author_pubkey: hex::encode(&r.author_pubkey),
-        promise_id: r.promise_id.map(hex::encode),
-        entry_type: r.entry_type.clone(),
-        content_hash: hex::encode(r.content_hash),
-        content_summary: String::from_utf8_lossy(&r.content_summary).to_string(),
-        timestamp: r.timestamp,
+    /// `chronx_subscribeLockUpdates` — status changes of the account's locks.
+    async fn subscribe_lock_updates(
+        &self,
+        pending: PendingSubscriptionSink,
+        account_id: String,
+    ) -> SubscriptionResult {
+        let account = match AccountId::from_b58(&account_id) {
+            Ok(id) => id.to_b58(),
+            Err(e) => {
+                pending.reject(rpc_err(-32602, format!("invalid account id: {e}"))).await;
+                return Ok(());
+            }
+        };
+        let rx = self.state.events.subscribe();
+        let sink = pending.accept().await?;
+        tokio::spawn(forward_events(sink, rx, move |event| match event {
+            RpcChainEvent::LockUpdate(u)
+                if u.sender == account || u.recipient == account || u.beneficiary.as_ref() == Some(&account) =>
+            {
+                Some(u)
+            }
+            _ => None,
+        }));
+        Ok(())
     }
-}
 
+    /// `chronx_getRecoveryEvidence` — evidence hashes and off-chain URIs.
+    async fn get_recovery_evidence(&self, account_id: String) -> RpcResult<Option<RpcRecoveryEvidence>> {
+        let id = AccountId::from_b58(&account_id)
+            .map_err(|e| rpc_err(-32602, format!("invalid account id: {e}")))?;
+        let Some(acc) = self
+            .state
+            .db
+            .get_account(&id)
+            .map_err(|e| rpc_err(-32603, e.to_string()))?
+        else {
+            return Ok(None);
+        };
+
+        let rs = acc.recovery_state;
+        Ok(Some(RpcRecoveryEvidence {
+            account_id: acc.account_id.to_b58(),
+            evidence_hash: rs.evidence_hash.map(|h| h.to_hex()),
+            evidence_uri: rs.evidence_uri,
+            counter_evidence_hash: rs.counter_evidence_hash.map(|h| h.to_hex()),
+            counter_evidence_uri: rs.counter_evidence_uri,
+        }))
+    }
+
+    /// `chronx_getPostRecoveryRestriction` — the daily limit as `Transfer`
+    /// would see it now, with the day's total reset at UTC midnight.
+    async fn get_post_recovery_restriction(&self, account_id: String) -> RpcResult<Option<RpcPostRecoveryRestriction>> {
+        let id = AccountId::from_b58(&account_id)
+            .map_err(|e| rpc_err(-32602, format!("invalid account id: {e}")))?;
+        let Some(acc) = self
+            .state
+            .db
+            .get_account(&id)
+            .map_err(|e| rpc_err(-32603, e.to_string()))?
+        else {
+            return Ok(None);
+        };
+        let now = chrono::Utc::now().timestamp();
+        let Some(r) = acc.post_recovery_restriction.filter(|r| now < r.expires_at) else {
+            return Ok(None);
+        };
+
+        let transferred_today = if r.last_transfer_day == (now / 86_400) as i32 { r.transferred_today } else { 0 };
+        Ok(Some(RpcPostRecoveryRestriction {
+            account_id: acc.account_id.to_b58(),
+            expires_at: r.expires_at,
+            daily_limit_chronos: r.daily_limit_chronos.to_string(),
+            transferred_today_chronos: transferred_today.to_string(),
+            remaining_today_chronos: r.daily_limit_chronos.saturating_sub(transferred_today).to_string(),
+        }))
+    }
+
+    /// `chronx_health` — database round-trip plus uptime.
+    async fn health(&self) -> RpcResult<RpcHealth> {
+        let db_error = self.state.db.ping().err().map(|e| e.to_string());
+        Ok(RpcHealth {
+            healthy: db_error.is_none(),
+            db_error,
+            uptime_secs: self.state.started_at.elapsed().as_secs(),
+        })
+    }
+
+    /// `chronx_validateConsistency` — run `StateDb::validate_consistency`.
+    async fn validate_consistency(&self) -> RpcResult<RpcConsistencyReport> {
+        let report = self
+            .state
+            .db
+            .validate_consistency()
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+        Ok(RpcConsistencyReport {
+            consistent: report.is_ok(),
+            errors: report.errors,
+            warnings: report.warnings,
+        })
+    }
+
+    /// `chronx_auditLockSupply` — run `StateDb::audit_lock_amounts` against
+    /// the senders' books.
+    async fn audit_lock_supply(&self) -> RpcResult<RpcLockAudit> {
+        let db = &self.state.db;
+        let expected = db
+            .expected_pending_lock_total()
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+        let report = db
+            .audit_lock_amounts(expected)
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+        Ok(RpcLockAudit {
+            total_locked_chronos: report.total_locked.to_string(),
+            expected_chronos: expected.to_string(),
+            pending_count: report.pending_count,
+            claimed_count: report.claimed_count,
+            cancelled_count: report.cancelled_count,
+            discrepancy_chronos: report.discrepancy.to_string(),
+            ok: report.is_ok(),
+        })
+    }
+
+    /// `chronx_auditAccountSupply` — run `chronx_genesis::audit_account_supply`.
+    async fn audit_account_supply(&self) -> RpcResult<RpcSupplyAudit> {
+        let audit = chronx_genesis::audit_account_supply(&self.state.db)
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+        Ok(RpcSupplyAudit {
+            accounts_chronos: audit.accounts.to_string(),
+            pending_locks_chronos: audit.pending_locks.to_string(),
+            other_held_chronos: audit.other_held.to_string(),
+            burned_chronos: audit.burned.to_string(),
+            unminted_chronos: audit.unminted.to_string(),
+            total_chronos: audit.total.to_string(),
+            expected_chronos: TOTAL_SUPPLY_CHRONOS.to_string(),
+            discrepancy_chronos: audit.discrepancy.to_string(),
+            ok: audit.is_ok(),
+        })
+    }
+
+    /// `chronx_searchByTag` — cross-account lookup through the tag index.
+    async fn search_by_tag(
+        &self,
+        tag: String,
+        status: Option<String>,
+        limit: u32,
+        offset: u32,
+    ) -> RpcResult<Vec<RpcTimeLock>> {
+        use chronx_core::constants::MAX_LOCKS_PER_QUERY;
+
+        let mut locks = self
+            .state
+            .db
+            .iter_timelocks_by_tag(&tag)
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+        if let Some(ref status) = status {
+            locks.retain(|tlc| tlc_status_str(&tlc.status) == *status);
+        }
+        locks.sort_by_key(|tlc| std::cmp::Reverse(tlc.created_at));
+        Ok(locks
+            .into_iter()
+            .skip(offset as usize)
+            .take((limit as usize).min(MAX_LOCKS_PER_QUERY))
+            .map(tlc_to_rpc)
+            .collect())
+    }
+
+    /// `chronx_getTimelockContractsForTags` — AND/OR tag search over the tag
+    /// index. Private locks are never indexed, so never returned.
+    async fn get_timelock_contracts_for_tags(
+        &self,
+        tags: Vec<String>,
+        require_all: bool,
+        account_id: Option<String>,
+        limit: u32,
+    ) -> RpcResult<Vec<RpcTimeLock>> {
+        use chronx_core::constants::MAX_LOCKS_PER_QUERY;
+
+        if tags.is_empty() {
+            return Err(rpc_err(-32602, "at least one tag is required"));
+        }
+        let account = account_id
+            .map(|a| AccountId::from_b58(&a))
+            .transpose()
+            .map_err(|e| rpc_err(-32602, format!("invalid account id: {e}")))?;
+        let db = &self.state.db;
+
+        let mut ids: Option<HashSet<TxId>> = None;
+        for tag in &tags {
+            let tagged: HashSet<TxId> = db
+                .timelock_ids_by_tag(tag)
+                .map_err(|e| rpc_err(-32603, e.to_string()))?
+                .into_iter()
+                .collect();
+            ids = Some(match ids {
+                None => tagged,
+                Some(acc) if require_all => acc.intersection(&tagged).cloned().collect(),
+                Some(mut acc) => {
+                    acc.extend(tagged);
+                    acc
+                }
+            });
+        }
+
+        let mut locks = Vec::new();
+        for id in ids.unwrap_or_default() {
+            let Some(tlc) = db.get_timelock(&id).map_err(|e| rpc_err(-32603, e.to_string()))? else {
+                continue;
+            };
+            if tlc.private {
+                continue;
+            }
+            if let Some(ref a) = account {
+                if tlc.sender != *a && tlc.recipient_account_id != *a {
+                    continue;
+                }
+            }
+            locks.push(tlc);
+        }
+        locks.sort_by_key(|tlc| std::cmp::Reverse(tlc.created_at));
+        Ok(locks
+            .into_iter()
+            .take((limit as usize).min(MAX_LOCKS_PER_QUERY))
+            .map(tlc_to_rpc)
+            .collect())
+    }
+
+    /// `chronx_getTagStats` — top 20 tags by lock count.
+    async fn get_tag_stats(&self) -> RpcResult<Vec<RpcTagStat>> {
+        let tags = self
+            .state
+            .db
+            .top_tags(20)
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+        Ok(tags
+            .into_iter()
+            .map(|(tag, lock_count)| RpcTagStat { tag, lock_count })
+            .collect())
+    }
+
+    /// `chronx_getCheckpoints` — checkpoints on disk, oldest first.
+    async fn get_checkpoints(&self) -> RpcResult<Vec<RpcCheckpoint>> {
+        let Some(dir) = &self.state.checkpoint_dir else {
+            return Ok(Vec::new());
+        };
+        let checkpoints = chronx_state::checkpoint::list_checkpoints(dir)
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+        Ok(checkpoints
+            .into_iter()
+            .map(|c| RpcCheckpoint {
+                path: c.path.display().to_string(),
+                created_at: c.created_at,
+                size_bytes: c.size_bytes,
+            })
+            .collect())
+    }
+
+    /// `chronx_markNotificationSent` — set a lock's `notification_sent` flag.
+    async fn mark_notification_sent(&self, lock_id: String, admin_token: String) -> RpcResult<bool> {
+        self.state.check_admin_token(&admin_token)?;
+        let id = TxId::from_hex(&lock_id)
+            .map_err(|e| rpc_err(-32602, format!("invalid lock id: {e}")))?;
+        self.state.db.set_notification_sent(&id, true).map_err(|e| match e {
+            ChronxError::TimeLockNotFound(_) => rpc_err(-32602, format!("lock not found: {lock_id}")),
+            e => rpc_err(-32603, e.to_string()),
+        })?;
+        Ok(true)
+    }
+
+    /// `chronx_getUnnotifiedLocks` — pending locks still awaiting a notification.
+    async fn get_unnotified_locks(&self, limit: u32) -> RpcResult<Vec<RpcTimeLock>> {
+        use chronx_core::constants::MAX_LOCKS_PER_QUERY;
+
+        let locks = self
+            .state
+            .db
+            .iter_unnotified_timelocks((limit as usize).min(MAX_LOCKS_PER_QUERY))
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+        Ok(locks.into_iter().map(tlc_to_rpc).collect())
+    }
+
+    /// `chronx_getDbDiagnostics` — `StateDb::tree_diagnostics` plus totals.
+    async fn get_db_diagnostics(&self) -> RpcResult<RpcDbDiagnostics> {
+        use chronx_state::db::SLED_CACHE_CAPACITY_BYTES;
+
+        let db = &self.state.db;
+        let trees = db.tree_diagnostics().map_err(|e| rpc_err(-32603, e.to_string()))?;
+        let data_bytes: u64 = trees.iter().map(|t| t.approx_size_bytes).sum();
+        Ok(RpcDbDiagnostics {
+            unknown_trees: trees.iter().filter(|t| !t.known).map(|t| t.name.clone()).collect(),
+            trees: trees
+                .into_iter()
+                .map(|t| RpcTreeDiagnostic {
+                    name: t.name,
+                    entry_count: t.entry_count,
+                    approx_size_bytes: t.approx_size_bytes,
+                    known: t.known,
+                })
+                .collect(),
+            total_size_bytes: db.size_on_disk().map_err(|e| rpc_err(-32603, e.to_string()))?,
+            cache_capacity_bytes: SLED_CACHE_CAPACITY_BYTES,
+            cache_utilization: (data_bytes as f64 / SLED_CACHE_CAPACITY_BYTES as f64).min(1.0),
+        })
+    }
+
+    /// `chronx_getFeatureFlags` — stored feature flags, or the defaults.
+    async fn get_feature_flags(&self) -> RpcResult<RpcFeatureFlags> {
+        let stored = self
+            .state
+            .db
+            .get_feature_flags()
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+        let flags = stored.unwrap_or_default();
+        Ok(RpcFeatureFlags {
+            recurring_enabled: flags.recurring_enabled,
+            split_enabled: flags.split_enabled,
+            secondary_market_enabled: flags.secondary_market_enabled,
+            transfer_lock_enabled: flags.transfer_lock_enabled,
+            email_lock_enabled: flags.email_lock_enabled,
+            compound_lock_enabled: flags.compound_lock_enabled,
+            governance_set: stored.is_some(),
+        })
+    }
+
+    /// `chronx_findAccountByPrefix` — resolve a shortened account id.
+    async fn find_account_by_prefix(&self, prefix_hex: String) -> RpcResult<Vec<RpcAccountMatch>> {
+        let prefix = parse_id_prefix(&prefix_hex)?;
+        let accounts = self
+            .state
+            .db
+            .find_accounts_by_prefix(&prefix)
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+        Ok(accounts
+            .into_iter()
+            .take(MAX_PREFIX_MATCHES)
+            .map(|a| RpcAccountMatch {
+                account_id: a.account_id.to_b58(),
+                balance_kx: kx_string(a.balance),
+            })
+            .collect())
+    }
+
+    /// `chronx_findLockByPrefix` — resolve a shortened lock id.
+    async fn find_lock_by_prefix(&self, prefix_hex: String) -> RpcResult<Vec<String>> {
+        let prefix = parse_id_prefix(&prefix_hex)?;
+        let ids = self
+            .state
+            .db
+            .find_lock_ids_by_prefix(&prefix)
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+        Ok(ids.into_iter().take(MAX_PREFIX_MATCHES).map(|id| id.to_hex()).collect())
+    }
+
+    /// `chronx_search` — unified lookup for the explorer search box.
+    async fn search(&self, query: String) -> RpcResult<RpcSearchResult> {
+        fn found(result_type: &str, data: impl serde::Serialize) -> RpcResult<RpcSearchResult> {
+            let data = serde_json::to_value(data).map_err(|e| rpc_err(-32603, e.to_string()))?;
+            Ok(RpcSearchResult { result_type: result_type.to_string(), data })
+        }
+
+        let query = query.trim().to_string();
+        if TxId::from_hex(&query).is_ok() {
+            if let Some(tx) = self.get_transaction(query.clone()).await? {
+                return found("transaction", tx);
+            }
+            if let Some(lock) = self.get_lock_by_id(query.clone()).await? {
+                return found("lock", lock);
+            }
+        }
+        // `AccountId::from_b58` panics on short input, so check the length first.
+        if bs58::decode(&query).into_vec().is_ok_and(|b| b.len() == 32) {
+            if let Some(account) = self.get_account(query.clone()).await? {
+                return found("account", account);
+            }
+        }
+        if let Some(client_ref) = hex::decode(&query).ok().and_then(|b| <[u8; 16]>::try_from(b).ok()) {
+            let lock = self
+                .state
+                .db
+                .get_timelock_by_client_ref(&client_ref)
+                .map_err(|e| rpc_err(-32603, e.to_string()))?;
+            if let Some(lock) = lock {
+                return found("lock", tlc_to_rpc(lock));
+            }
+        }
+        Ok(RpcSearchResult { result_type: "not_found".to_string(), data: serde_json::Value::Null })
+    }
+
+    /// `chronx_getVerifierHistory` — a verifier's recovery votes.
+    async fn get_verifier_history(&self, verifier_id: String, limit: u32) -> RpcResult<Vec<RpcVerifierVote>> {
+        let verifier = AccountId::from_b58(&verifier_id)
+            .map_err(|e| rpc_err(-32602, format!("invalid verifier id: {e}")))?;
+        let votes = self
+            .state
+            .db
+            .iter_verifier_votes(&verifier)
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+        Ok(votes
+            .into_iter()
+            .take((limit as usize).min(MAX_VERIFIER_QUERY))
+            .map(|v| RpcVerifierVote {
+                target_account: v.target_account.to_b58(),
+                recovery_started_at: v.recovery_started_at,
+                vote: if v.approve { "approve" } else { "reject" }.to_string(),
+                voted_at_tx_id: v.vote_tx_id.to_hex(),
+                outcome: v.outcome,
+            })
+            .collect())
+    }
+
+    /// `chronx_getVerifierLeaderboard` — verifiers by record and activity.
+    async fn get_verifier_leaderboard(&self, limit: u32) -> RpcResult<Vec<RpcVerifierRank>> {
+        use chronx_core::constants::MIN_VERIFIER_STAKE_CHRONOS;
+
+        let accounts = self
+            .state
+            .db
+            .iter_all_accounts()
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+        let mut ranks: Vec<RpcVerifierRank> = accounts
+            .into_iter()
+            .filter(|a| a.is_verifier || a.votes_cast > 0)
+            .map(|a| RpcVerifierRank {
+                verifier_id: a.account_id.to_b58(),
+                votes_cast: a.votes_cast,
+                verifier_stake_kx: kx_string(a.verifier_stake),
+                slash_free: a.verifier_stake >= MIN_VERIFIER_STAKE_CHRONOS,
+            })
+            .collect();
+        ranks.sort_by(|a, b| {
+            b.slash_free
+                .cmp(&a.slash_free)
+                .then(b.votes_cast.cmp(&a.votes_cast))
+                .then_with(|| a.verifier_id.cmp(&b.verifier_id))
+        });
+        ranks.truncate((limit as usize).min(MAX_VERIFIER_QUERY));
+        Ok(ranks)
+    }
+
+    /// `chronx_getVerifierRegistrations` — registered recovery verifiers.
+    async fn get_verifier_registrations(&self, include_inactive: bool) -> RpcResult<Vec<RpcVerifierRegistration>> {
+        let min = self.state.db.verifier_min_stake();
+        let registrations = self
+            .state
+            .db
+            .get_active_verifiers(if include_inactive { 0 } else { min })
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+        Ok(registrations
+            .into_iter()
+            .map(|r| RpcVerifierRegistration {
+                account_id: r.account_id.to_b58(),
+                stake_chronos: r.stake.to_string(),
+                stake_kx: kx_string(r.stake),
+                registered_at: r.registered_at,
+                last_vote_at: r.last_vote_at,
+                total_votes: r.total_votes,
+                slash_count: r.slash_count,
+                active: r.stake >= min,
+            })
+            .collect())
+    }
+
+    /// `chronx_getActiveProposals` — proposals still `Active`.
+    async fn get_active_proposals(&self) -> RpcResult<Vec<RpcGovernanceProposal>> {
+        let proposals = self
+            .state
+            .db
+            .get_governance_proposals()
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+        Ok(proposals
+            .iter()
+            .filter(|p| p.status == GovernanceProposalStatus::Active)
+            .map(governance_proposal_to_rpc)
+            .collect())
+    }
+
+    /// `chronx_getGovernanceHistory` — finalized proposals.
+    async fn get_governance_history(&self) -> RpcResult<Vec<RpcGovernanceProposal>> {
+        let mut proposals = self
+            .state
+            .db
+            .get_governance_proposals()
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+        proposals.retain(|p| p.status != GovernanceProposalStatus::Active);
+        proposals.sort_by_key(|p| std::cmp::Reverse(p.finalized_at));
+        Ok(proposals.iter().map(governance_proposal_to_rpc).collect())
+    }
+
+    /// `chronx_getRecurringSchedule` — a recurring series and its installments.
+    async fn get_recurring_schedule(&self, lock_id: String) -> RpcResult<Option<RpcRecurringSchedule>> {
+        let id = TxId::from_hex(&lock_id)
+            .map_err(|e| rpc_err(-32602, format!("invalid lock id: {e}")))?;
+        let db = &self.state.db;
+        let series_id = match db.get_timelock(&id).map_err(|e| rpc_err(-32603, e.to_string()))? {
+            Some(lock) => match lock.recurring_series_id {
+                Some(series_id) => series_id,
+                None => return Ok(None),
+            },
+            None => return Err(rpc_err(-32602, format!("lock {lock_id} not found"))),
+        };
+        let Some(schedule) = db.get_recurring_schedule(&series_id).map_err(|e| rpc_err(-32603, e.to_string()))? else {
+            return Ok(None);
+        };
+        let installments = db
+            .get_recurring_installments(&schedule)
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+
+        let total = schedule.policy.count();
+        let claimed = installments
+            .iter()
+            .filter(|l| matches!(l.status, TimeLockStatus::Claimed { .. }))
+            .count() as u32;
+        // A series ends when its newest installment is terminal and nothing
+        // follows it: claimed in full, or cut short by a cancellation.
+        let ended = installments.iter().all(|l| l.status.is_terminal());
+        let status = if !ended {
+            "Active"
+        } else if claimed == total {
+            "Exhausted"
+        } else if installments.last().is_some_and(|l| matches!(l.status, TimeLockStatus::Claimed { .. })) {
+            // Delivered without a transaction; the next installment is
+            // created by the engine's recurring tick.
+            "Active"
+        } else {
+            "Cancelled"
+        };
+        let remaining = if status == "Active" {
+            schedule.amount * total.saturating_sub(claimed) as u128
+        } else {
+            0
+        };
+        let policy = match schedule.policy {
+            chronx_core::account::RecurringPolicy::None => "None",
+            chronx_core::account::RecurringPolicy::Weekly { .. } => "Weekly",
+            chronx_core::account::RecurringPolicy::Monthly { .. } => "Monthly",
+            chronx_core::account::RecurringPolicy::Annual { .. } => "Annual",
+        };
+        Ok(Some(RpcRecurringSchedule {
+            series_id: schedule.series_id.to_hex(),
+            sender: schedule.sender.to_b58(),
+            recipient_account_id: schedule.recipient_account_id.to_b58(),
+            amount_per_installment_chronos: schedule.amount.to_string(),
+            policy: policy.to_string(),
+            interval_secs: schedule.policy.interval_secs(),
+            total_installments: total,
+            installments_claimed: claimed,
+            remaining_chronos: remaining.to_string(),
+            status: status.to_string(),
+            installments: installments
+                .iter()
+                .enumerate()
+                .map(|(i, l)| RpcRecurringInstallment {
+                    lock_id: l.id.to_hex(),
+                    index: i as u32 + 1,
+                    unlock_at: l.unlock_at,
+                    status: tlc_status_str(&l.status),
+                })
+                .collect(),
+        }))
+    }
+
+    /// `chronx_getDagHealth` — tip count and fork pressure.
+    async fn get_dag_health(&self) -> RpcResult<RpcDagHealth> {
+        use chronx_core::constants::MAX_DAG_TIPS;
+
+        let tip_count = self.state.db.tip_count();
+        let fork_pressure = tip_count as f64 / MAX_DAG_TIPS as f64;
+        let recommendation = if tip_count >= MAX_DAG_TIPS {
+            "tip limit reached: new vertices must reference at least two current tips"
+        } else if tip_count > MAX_DAG_TIPS / 2 {
+            "fork width elevated: reference multiple tips as parents"
+        } else {
+            "healthy"
+        };
+        Ok(RpcDagHealth {
+            tip_count: tip_count as u64,
+            max_tips: MAX_DAG_TIPS as u64,
+            fork_pressure,
+            recommendation: recommendation.to_string(),
+        })
+    }
+
+    /// `chronx_getEvents` — read the engine event log.
+    async fn get_events(
+        &self,
+        from_ts: i64,
+        limit: u32,
+        event_type: Option<String>,
+    ) -> RpcResult<Vec<RpcEvent>> {
+        if let Some(t) = &event_type {
+            if !ChronxEvent::TYPES.contains(&t.as_str()) {
+                return Err(rpc_err(
+                    -32602,
+                    format!("unknown event type {t}; expected one of {}", ChronxEvent::TYPES.join(", ")),
+                ));
+            }
+        }
+        let records = self
+            .state
+            .db
+            .iter_events_since(from_ts)
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+        Ok(records
+            .into_iter()
+            .filter(|r| event_type.as_deref().is_none_or(|t| r.event.event_type() == t))
+            .take((limit as usize).min(MAX_EVENTS_PER_QUERY))
+            .map(|r| RpcEvent {
+                timestamp: r.timestamp,
+                tx_id: r.tx_id.to_hex(),
+                event_type: r.event.event_type().to_string(),
+                data: event_data(&r.event),
+            })
+            .collect())
+    }
+
+    /// `chronx_getActiveAlerts` — alert rules currently firing.
+    async fn get_active_alerts(&self) -> RpcResult<Vec<RpcAlert>> {
+        let alerts = self.state.active_alerts.read().unwrap_or_else(|e| e.into_inner());
+        Ok(alerts
+            .iter()
+            .map(|a| RpcAlert {
+                name: a.name.clone(),
+                severity: format!("{:?}", a.severity),
+                message: a.message.clone(),
+                since: a.since,
+            })
+            .collect())
+    }
+
+    /// `chronx_getChainTimeline` — DAG vertex distribution over time.
+    async fn get_chain_timeline(
+        &self,
+        bucket_secs: u64,
+        from_ts: i64,
+        to_ts: i64,
+    ) -> RpcResult<Vec<RpcTimelineBucket>> {
+        if !self.state.timeline_enabled {
+            return Err(rpc_err(-32603, "chain timeline is disabled on this node"));
+        }
+        if bucket_secs == 0 || to_ts <= from_ts {
+            return Err(rpc_err(-32602, "need bucket_secs > 0 and to_ts > from_ts"));
+        }
+        let span = (to_ts as i128 - from_ts as i128) as u128;
+        let bucket_count = span.div_ceil(bucket_secs as u128);
+        if bucket_count > MAX_TIMELINE_BUCKETS as u128 {
+            return Err(rpc_err(
+                -32602,
+                format!("range spans {bucket_count} buckets; at most {MAX_TIMELINE_BUCKETS} allowed"),
+            ));
+        }
+        let bucket_count = bucket_count as usize;
+
+        let db = &self.state.db;
+        let now = chrono::Utc::now().timestamp();
+        if let Some(cache) = db.get_timeline_cache().map_err(|e| rpc_err(-32603, e.to_string()))? {
+            if (cache.bucket_secs, cache.from_ts, cache.to_ts) == (bucket_secs, from_ts, to_ts)
+                && now - cache.computed_at < TIMELINE_CACHE_SECS
+            {
+                return Ok(cache.buckets.into_iter().map(timeline_bucket_to_rpc).collect());
+            }
+        }
+
+        let mut buckets: Vec<TimelineBucket> = (0..bucket_count)
+            .map(|i| TimelineBucket {
+                bucket_start: (from_ts as i128 + i as i128 * bucket_secs as i128) as i64,
+                ..Default::default()
+            })
+            .collect();
+        let mut senders: Vec<HashSet<AccountId>> = vec![HashSet::new(); bucket_count];
+        let mut fees: Vec<u128> = vec![0; bucket_count];
+        let vertices = db.iter_all_vertices().map_err(|e| rpc_err(-32603, e.to_string()))?;
+        for v in vertices {
+            let tx = &v.transaction;
+            if tx.timestamp < from_ts || tx.timestamp >= to_ts {
+                continue;
+            }
+            let i = ((tx.timestamp - from_ts) as u64 / bucket_secs) as usize;
+            let bucket = &mut buckets[i];
+            bucket.vertex_count += 1;
+            senders[i].insert(tx.from.clone());
+            fees[i] = fees[i].saturating_add(tx.fee_chronos);
+            for action in &tx.actions {
+                *bucket.action_type_counts.entry(action.name().to_string()).or_insert(0) += 1;
+            }
+        }
+        for (i, bucket) in buckets.iter_mut().enumerate() {
+            bucket.unique_senders = senders[i].len() as u64;
+            if bucket.vertex_count > 0 {
+                bucket.avg_fee_chronos = fees[i] / bucket.vertex_count as u128;
+            }
+        }
+
+        let cache = TimelineCache { bucket_secs, from_ts, to_ts, computed_at: now, buckets };
+        if let Err(e) = db.put_timeline_cache(&cache) {
+            warn!(error = %e, "failed to cache chain timeline");
+        }
+        Ok(cache.buckets.into_iter().map(timeline_bucket_to_rpc).collect())
+    }
+
+    /// `chronx_getAccountBalanceHistory` — balance after each vertex that
+    /// touched the account, walking back from the current balance.
+    async fn get_account_balance_history(
+        &self,
+        account_id: String,
+        from_depth: u64,
+        to_depth: u64,
+    ) -> RpcResult<Vec<RpcBalancePoint>> {
+        let id = AccountId::from_b58(&account_id)
+            .map_err(|e| rpc_err(-32602, format!("invalid account id: {e}")))?;
+        if to_depth < from_depth {
+            return Err(rpc_err(-32602, "to_depth must not be below from_depth"));
+        }
+        if to_depth - from_depth >= MAX_BALANCE_HISTORY_DEPTHS {
+            return Err(rpc_err(
+                -32602,
+                format!("at most {MAX_BALANCE_HISTORY_DEPTHS} depths per query"),
+            ));
+        }
+        let db = &self.state.db;
+        let mut balance = db
+            .get_account(&id)
+            .map_err(|e| rpc_err(-32603, e.to_string()))?
+            .map_or(0, |a| a.balance) as i128;
+
+        // Everything above `to_depth` has to be undone too, to reach the
+        // balances inside the range.
+        let mut vertices = db
+            .iter_vertices_from_depth(from_depth)
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+        vertices.sort_by_key(|v| (v.depth, v.received_at));
+
+        let mut history = Vec::new();
+        for v in vertices.iter().rev() {
+            let Some(delta) = v.reverse_action_for(&id) else {
+                continue;
+            };
+            if v.depth <= to_depth {
+                history.push(RpcBalancePoint {
+                    depth: v.depth,
+                    tx_id: v.transaction.tx_id.to_hex(),
+                    balance_after: balance.to_string(),
+                });
+            }
+            balance -= delta;
+        }
+        history.reverse();
+        Ok(history)
+    }
+
+    /// `chronx_validateSplitPolicy` — dry run of `ValidateSplitPolicy`.
+    async fn validate_split_policy(&self, lock_id: String) -> RpcResult<Option<RpcSplitPolicyValidation>> {
+        let id = TxId::from_hex(&lock_id).map_err(|e| rpc_err(-32602, format!("invalid lock id: {e}")))?;
+        let db = &self.state.db;
+        let Some(contract) = db.get_timelock(&id).map_err(|e| rpc_err(-32603, e.to_string()))? else {
+            return Ok(None);
+        };
+        let policy = contract
+            .split_policy
+            .as_ref()
+            .ok_or_else(|| rpc_err(-32602, "lock has no split policy"))?;
+        let mut missing_recipients = Vec::new();
+        let mut duplicate_recipients = Vec::new();
+        let mut seen = HashSet::new();
+        for (recipient, _) in &policy.recipients {
+            if db.get_account(recipient).map_err(|e| rpc_err(-32603, e.to_string()))?.is_none() {
+                missing_recipients.push(recipient.to_b58());
+            }
+            if *recipient == contract.sender || !seen.insert(recipient) {
+                duplicate_recipients.push(recipient.to_b58());
+            }
+        }
+        let basis_points_total: u32 = policy.recipients.iter().map(|(_, bp)| *bp as u32).sum();
+        Ok(Some(RpcSplitPolicyValidation {
+            lock_id: contract.id.to_hex(),
+            valid: contract.status == TimeLockStatus::Pending
+                && missing_recipients.is_empty()
+                && duplicate_recipients.is_empty()
+                && basis_points_total == 10_000,
+            validated_on_chain: contract.split_policy_validated,
+            basis_points_total,
+            missing_recipients,
+            duplicate_recipients,
+        }))
+    }
+
+    /// `chronx_getSystemInfo` — build, host, storage and network details.
+    async fn get_system_info(&self) -> RpcResult<RpcSystemInfo> {
+        use chronx_core::constants::GENESIS_TIMESTAMP;
+
+        let db = &self.state.db;
+        let genesis_hash = db
+            .get_meta(chronx_genesis::GENESIS_HASH_META_KEY)
+            .map_err(|e| rpc_err(-32603, e.to_string()))?
+            .and_then(|b| String::from_utf8(b).ok())
+            .unwrap_or_default();
+        let network_id = self
+            .state
+            .p2p_stats
+            .as_ref()
+            .map(|s| s.read().unwrap_or_else(|e| e.into_inner()).network_id.clone())
+            .unwrap_or_default();
+        Ok(RpcSystemInfo {
+            node_version: env!("CARGO_PKG_VERSION").to_string(),
+            rust_version: env!("CHRONX_RUSTC_VERSION").to_string(),
+            os: std::env::consts::OS.to_string(),
+            arch: std::env::consts::ARCH.to_string(),
+            data_dir: db.path().display().to_string(),
+            db_size_bytes: db.size_on_disk().map_err(|e| rpc_err(-32603, e.to_string()))?,
+            uptime_secs: self.state.started_at.elapsed().as_secs(),
+            genesis_timestamp: GENESIS_TIMESTAMP,
+            genesis_hash,
+            total_supply_chronos: TOTAL_SUPPLY_CHRONOS.to_string(),
+            pow_difficulty: self.state.pow_difficulty,
+            network_id,
+            p2p_listen_addr: self.state.p2p_listen_addr.clone().unwrap_or_default(),
+            rpc_addr: self.state.rpc_addr.clone().unwrap_or_default(),
+            feature_flags: self.get_feature_flags().await?,
+        })
+    }
+
+    /// `chronx_getSupportedCurrencies` — currencies of stored `KX/*` oracle snapshots.
+    async fn get_supported_currencies(&self) -> RpcResult<Vec<String>> {
+        let snapshots = self
+            .state
+            .db
+            .iter_oracle_snapshots()
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+        Ok(snapshots
+            .into_iter()
+            .filter_map(|s| s.pair.strip_prefix("KX/").map(str::to_string))
+            .collect())
+    }
+
+    /// `chronx_getPendingTransactions` — queued transactions, oldest first.
+    async fn get_pending_transactions(&self, account_id: Option<String>) -> RpcResult<Vec<RpcPendingTx>> {
+        let sender = account_id
+            .map(|a| AccountId::from_b58(&a))
+            .transpose()
+            .map_err(|e| rpc_err(-32602, format!("invalid account id: {e}")))?;
+        let Some(mempool) = &self.state.mempool else {
+            return Ok(Vec::new());
+        };
+        let now = chrono::Utc::now().timestamp();
+        let pool = mempool.lock().unwrap_or_else(|e| e.into_inner());
+        Ok(pool
+            .pending()
+            .into_iter()
+            .filter(|p| sender.as_ref().is_none_or(|s| *s == p.tx.from))
+            .map(|p| RpcPendingTx {
+                tx_id: p.tx.tx_id.to_hex(),
+                sender: p.tx.from.to_b58(),
+                nonce: p.tx.nonce,
+                received_at: p.received_at,
+                age_secs: now - p.received_at,
+                expires_at: p.tx.expires_at,
+            })
+            .collect())
+    }
+
+    /// `chronx_getMempoolInfo` — mempool occupancy and recent drops.
+    async fn get_mempool_info(&self) -> RpcResult<RpcMempoolInfo> {
+        let Some(mempool) = &self.state.mempool else {
+            return Ok(RpcMempoolInfo {
+                size: 0,
+                capacity: 0,
+                sender_count: 0,
+                oldest_age_secs: None,
+                dropped_total: 0,
+                recently_dropped: Vec::new(),
+            });
+        };
+        let now = chrono::Utc::now().timestamp();
+        let pool = mempool.lock().unwrap_or_else(|e| e.into_inner());
+        Ok(RpcMempoolInfo {
+            size: pool.len(),
+            capacity: pool.capacity(),
+            sender_count: pool.sender_count(),
+            oldest_age_secs: pool.pending().first().map(|p| now - p.received_at),
+            dropped_total: pool.dropped_total(),
+            recently_dropped: pool
+                .recently_dropped()
+                .map(|d| RpcDroppedTx {
+                    tx_id: d.tx_id.to_hex(),
+                    sender: d.sender.to_b58(),
+                    reason: d.reason.clone(),
+                    dropped_at: d.at,
+                })
+                .collect(),
+        })
+    }
+
+    /// `chronx_getLocksForSale` — a page of the secondary market listings.
+    async fn get_locks_for_sale(&self, offset: u32, limit: u32) -> RpcResult<Vec<RpcTimeLock>> {
+        use chronx_core::constants::MAX_LOCKS_PER_QUERY;
+
+        let locks = self
+            .state
+            .db
+            .iter_timelocks_for_sale(offset as usize, (limit as usize).min(MAX_LOCKS_PER_QUERY))
+            .map_err(|e| rpc_err(-32603, e.to_string()))?;
+        Ok(locks.into_iter().map(tlc_to_rpc).collect())
+    }
+
+    /// `chronx_getLockTransferHistory` — claim-right transfers of one lock.
+    async fn get_lock_transfer_history(&self, lock_id: String) -> RpcResult<Vec<String>> {
+        let id = TxId::from_hex(&lock_id)
+            .map_err(|e| rpc_err(-32602, format!("invalid lock id: {e}")))?;
+        let tlc = self
+            .state
+            .db
+            .get_timelock(&id)
+            .map_err(|e| rpc_err(-32603, e.to_string()))?
+            .ok_or_else(|| rpc_err(-32602, format!("lock not found: {lock_id}")))?;
+        Ok(tlc.transfer_history.iter().map(|tx_id| tx_id.to_hex()).collect())
+    }
+
+}
+
+/// Shortest id prefix, in bytes, accepted by the `find*ByPrefix` methods.
+/// Anything shorter matches too much of the tree to be a useful lookup.
+const MIN_ID_PREFIX_BYTES: usize = 4;
+
+/// Most matches returned by the `find*ByPrefix` methods.
+const MAX_PREFIX_MATCHES: usize = 10;
+
+/// Most entries returned by the verifier history and leaderboard methods.
+const MAX_VERIFIER_QUERY: usize = 100;
+
+/// Most points `chronx_getOracleHistory` returns per call.
+const MAX_ORACLE_HISTORY_POINTS: usize = 1000;
+
+/// Most accounts `chronx_getAllAccounts` returns per page.
+const MAX_ACCOUNTS_PAGE: usize = 100;
+
+/// Most entries returned by `chronx_getEvents`.
+const MAX_EVENTS_PER_QUERY: usize = 1000;
+
+/// Most buckets `chronx_getChainTimeline` computes in one call.
+const MAX_TIMELINE_BUCKETS: usize = 1000;
+
+/// How long a cached `chronx_getChainTimeline` result is served.
+const TIMELINE_CACHE_SECS: i64 = 60;
+
+/// Local calendar span used by the `getLocksExpiring*` methods.
+#[derive(Clone, Copy)]
+enum CalendarPeriod {
+    Day,
+    /// Monday through Sunday.
+    Week,
+    Month,
+}
+
+impl CalendarPeriod {
+    /// UTC `[start, end)` of the period containing `now`, in a time zone
+    /// `offset_hours` from UTC.
+    fn bounds(self, now: i64, offset_hours: i32) -> (i64, i64) {
+        use chrono::{Datelike, Days, Months, NaiveDate};
+
+        let offset = offset_hours as i64 * 3600;
+        let today = chrono::DateTime::from_timestamp(now + offset, 0)
+            .unwrap_or_default()
+            .date_naive();
+        let (start, end) = match self {
+            CalendarPeriod::Day => (today, today + Days::new(1)),
+            CalendarPeriod::Week => {
+                let monday = today - Days::new(today.weekday().num_days_from_monday() as u64);
+                (monday, monday + Days::new(7))
+            }
+            CalendarPeriod::Month => {
+                let first = today.with_day(1).unwrap_or(today);
+                (first, first + Months::new(1))
+            }
+        };
+        let utc = |d: NaiveDate| d.and_time(chrono::NaiveTime::MIN).and_utc().timestamp() - offset;
+        (utc(start), utc(end))
+    }
+}
+
+/// How long a cached `chronx_getExplorerSummary` result is served.
+const EXPLORER_CACHE_SECS: i64 = 60;
+
+/// Recent transactions and top lock holders in `chronx_getExplorerSummary`.
+const EXPLORER_RECENT_TXS: u32 = 10;
+const EXPLORER_TOP_HOLDERS: usize = 10;
+
+/// Widest depth range `chronx_getAccountBalanceHistory` accepts.
+const MAX_BALANCE_HISTORY_DEPTHS: u64 = 10_000;
+
+fn timeline_bucket_to_rpc(b: TimelineBucket) -> RpcTimelineBucket {
+    RpcTimelineBucket {
+        bucket_start: b.bucket_start,
+        vertex_count: b.vertex_count,
+        unique_senders: b.unique_senders,
+        avg_fee_chronos: b.avg_fee_chronos.to_string(),
+        action_type_counts: b.action_type_counts,
+    }
+}
+
+/// JSON fields of an event for `RpcEvent::data`.
+fn event_data(event: &ChronxEvent) -> serde_json::Value {
+    use serde_json::json;
+    match event {
+        ChronxEvent::TransferApplied { from, to, amount } => json!({
+            "from": from.to_b58(), "to": to.to_b58(), "amount_chronos": amount.to_string(),
+        }),
+        ChronxEvent::LockCreated { lock_id, sender, recipient, amount, unlock_at } => json!({
+            "lock_id": lock_id.to_hex(), "sender": sender.to_b58(), "recipient": recipient.to_b58(),
+            "amount_chronos": amount.to_string(), "unlock_at": unlock_at,
+        }),
+        ChronxEvent::LockClaimed { lock_id, recipient, amount } => json!({
+            "lock_id": lock_id.to_hex(), "recipient": recipient.to_b58(), "amount_chronos": amount.to_string(),
+        }),
+        ChronxEvent::LockCancelled { lock_id, sender, amount } => json!({
+            "lock_id": lock_id.to_hex(), "sender": sender.to_b58(), "amount_chronos": amount.to_string(),
+        }),
+        ChronxEvent::RecoveryStarted { target, bond } => json!({
+            "target": target.to_b58(), "bond_chronos": bond.to_string(),
+        }),
+        ChronxEvent::RecoveryVoteCast { target, verifier, approve } => json!({
+            "target": target.to_b58(), "verifier": verifier.to_b58(), "approve": approve,
+        }),
+        ChronxEvent::RecoveryFinalized { target } => json!({ "target": target.to_b58() }),
+        ChronxEvent::SplitPolicyValid { lock_id } => json!({ "lock_id": lock_id.to_hex() }),
+        ChronxEvent::RecoveryExpired { target } => json!({ "target": target.to_b58() }),
+    }
+}
+
+fn parse_id_prefix(prefix_hex: &str) -> RpcResult<Vec<u8>> {
+    let prefix = hex::decode(prefix_hex)
+        .map_err(|e| rpc_err(-32602, format!("invalid prefix hex: {e}")))?;
+    if prefix.len() < MIN_ID_PREFIX_BYTES {
+        return Err(rpc_err(
+            -32602,
+            format!("prefix must be at least {} hex characters", MIN_ID_PREFIX_BYTES * 2),
+        ));
+    }
+    Ok(prefix)
+}
+
+/// Maximum lock ids accepted by `chronx_getLockCountdownBatch`.
+const MAX_COUNTDOWN_BATCH: usize = 50;
+
+fn lock_countdowns(
+    db: &StateDb,
+    lock_ids: &[String],
+    now: i64,
+) -> Result<Vec<RpcLockCountdown>, ChronxError> {
+    let mut out = Vec::with_capacity(lock_ids.len());
+    for lock_id in lock_ids {
+        let tlc = match TxId::from_hex(lock_id) {
+            Ok(id) => db.get_timelock(&id)?,
+            Err(_) => None,
+        };
+        out.push(match tlc {
+            Some(tlc) => RpcLockCountdown {
+                lock_id: lock_id.clone(),
+                unlock_at: tlc.unlock_at,
+                seconds_remaining: tlc.unlock_at - now,
+                is_matured: now >= tlc.unlock_at,
+                status: tlc_status_str(&tlc.status),
+            },
+            None => RpcLockCountdown {
+                lock_id: lock_id.clone(),
+                unlock_at: 0,
+                seconds_remaining: 0,
+                is_matured: false,
+                status: "NotFound".to_string(),
+            },
+        });
+    }
+    Ok(out)
+}
+
+/// Maximum lock ids accepted by `chronx_getLockSummaryBatch`.
+const MAX_SUMMARY_BATCH: usize = 100;
+
+fn lock_summary(
+    lock_id: String,
+    tlc: Option<chronx_core::account::TimeLockContract>,
+    now: i64,
+) -> RpcLockSummary {
+    match tlc {
+        Some(tlc) => RpcLockSummary::Found {
+            lock_id,
+            status: tlc_status_str(&tlc.status),
+            amount_kx: (!tlc.private).then(|| kx_string(tlc.amount)),
+            unlock_at: tlc.unlock_at,
+            seconds_until_unlock: tlc.unlock_at - now,
+            is_matured: now >= tlc.unlock_at,
+            recipient: tlc.recipient_account_id.to_b58(),
+        },
+        None => RpcLockSummary::NotFound { lock_id, error: "not_found".to_string() },
+    }
+}
+
+// ── RPC conversion helpers ──────────────────────────────────────
+
+fn invoice_to_rpc(r: &chronx_state::db::InvoiceRecord) -> RpcInvoiceRecord {
+    let status = match r.status {
+        InvoiceStatus::Open => "Open",
+        InvoiceStatus::Fulfilled => "Fulfilled",
+        InvoiceStatus::Lapsed => "Lapsed",
+        InvoiceStatus::Cancelled => "Cancelled",
+        InvoiceStatus::Rejected => "Rejected",
+    };
+    RpcInvoiceRecord {
+        invoice_id: hex::encode(r.invoice_id),
+        issuer_pubkey: hex::encode(&r.issuer_pubkey),
+        payer_pubkey: r.payer_pubkey.as_ref().map(hex::encode),
+        amount_chronos: r.amount_chronos.to_string(),
+        amount_kx: kx_string(r.amount_chronos as u128),
+        expiry: r.expiry,
+        status: status.to_string(),
+        created_at: r.created_at,
+        fulfilled_at: r.fulfilled_at,
+    }
+}
+
+fn credit_to_rpc(r: &chronx_state::db::CreditRecord) -> RpcCreditRecord {
+    let status = match r.status {
+        CreditStatus::Open => "Open",
+        CreditStatus::Closed => "Closed",
+        CreditStatus::Lapsed => "Lapsed",
+        CreditStatus::Revoked => "Revoked",
+    };
+    RpcCreditRecord {
+        credit_id: hex::encode(r.credit_id),
+        grantor_pubkey: hex::encode(&r.grantor_pubkey),
+        beneficiary_pubkey: hex::encode(&r.beneficiary_pubkey),
+        ceiling_chronos: r.ceiling_chronos.to_string(),
+        ceiling_kx: kx_string(r.ceiling_chronos as u128),
+        per_draw_max_chronos: r.per_draw_max_chronos.map(|v| v.to_string()),
+        expiry: r.expiry,
+        drawn_chronos: r.drawn_chronos.to_string(),
+        drawn_kx: kx_string(r.drawn_chronos as u128),
+        status: status.to_string(),
+        created_at: r.created_at,
+    }
+}
+
+fn deposit_to_rpc(r: &chronx_state::db::DepositRecord) -> RpcDepositRecord {
+    let status = match r.status {
+        DepositStatus::Active => "Active",
+        DepositStatus::Matured => "Matured",
+        DepositStatus::Settled => "Settled",
+        DepositStatus::Defaulted => "Defaulted",
+    };
+    RpcDepositRecord {
+        deposit_id: hex::encode(r.deposit_id),
+        depositor_pubkey: hex::encode(&r.depositor_pubkey),
+        obligor_pubkey: hex::encode(&r.obligor_pubkey),
+        principal_chronos: r.principal_chronos,
+        principal_kx: chronos_to_kx(r.principal_chronos as u128),
+        rate_basis_points: r.rate_basis_points,
+        term_seconds: r.term_seconds,
+        compounding: r.compounding.clone(),
+        maturity_timestamp: r.maturity_timestamp,
+        total_due_chronos: r.total_due_chronos,
+        total_due_kx: chronos_to_kx(r.total_due_chronos as u128),
+        status: status.to_string(),
+        created_at: r.created_at,
+        settled_at: r.settled_at,
+    }
+}
+
+fn conditional_to_rpc(r: &chronx_state::db::ConditionalRecord) -> RpcConditionalRecord {
+    let status = match r.status {
+        ConditionalStatus::Pending => "Pending",
+        ConditionalStatus::Released => "Released",
+        ConditionalStatus::Voided => "Voided",
+        ConditionalStatus::Returned => "Returned",
+        ConditionalStatus::Escrowed => "Escrowed",
+        ConditionalStatus::PartiallyReleased => "PartiallyReleased",
+    };
+    RpcConditionalRecord {
+        type_v_id: hex::encode(r.type_v_id),
+        sender_pubkey: hex::encode(&r.sender_pubkey),
+        recipient_pubkey: hex::encode(&r.recipient_pubkey),
+        amount_chronos: r.amount_chronos.to_string(),
+        amount_kx: kx_string(r.amount_chronos as u128),
+        min_attestors: r.min_attestors,
+        attestations_received: r.attestations_received.len() as u32,
+        valid_until: r.valid_until,
+        fallback: r.fallback.clone(),
+        status: status.to_string(),
+        created_at: r.created_at,
+    }
+}
+
+fn ledger_entry_to_rpc(r: &chronx_state::db::LedgerEntryRecord) -> RpcLedgerEntryRecord {
+    RpcLedgerEntryRecord {
+        entry_id: hex::encode(r.entry_id),
+        author_pubkey: hex::encode(&r.author_pubkey),
+        promise_id: r.promise_id.map(hex::encode),
+        entry_type: r.entry_type.clone(),
+        content_hash: hex::encode(r.content_hash),
+        content_summary: String::from_utf8_lossy(&r.content_summary).to_string(),
+        timestamp: r.timestamp,
+    }
+}
+
+
+// ── Genesis 10a — Loan RPC conversion helpers ───────────────────────────────
 
-// ── Genesis 10a — Loan RPC conversion helpers ───────────────────────────────
-
 
 
 
@@ -3279,3 +5423,2257 @@ fn hex_to_32(hex_str: &str) -> RpcResult<[u8; 32]> {
     arr.copy_from_slice(&decoded);
     Ok(arr)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chronx_core::account::TimeLockContract;
+    use chronx_core::types::DilithiumPublicKey;
+
+    fn temp_db(name: &str) -> StateDb {
+        let dir = std::env::temp_dir().join(format!("chronx_rpc_test_{}", name));
+        let _ = std::fs::remove_dir_all(&dir);
+        StateDb::open(&dir).expect("open temp db")
+    }
+
+    fn seed_lock(db: &StateDb, id: TxId, unlock_at: i64) {
+        let contract = TimeLockContract {
+            id,
+            sender: AccountId::from_bytes([1u8; 32]),
+            recipient_key: DilithiumPublicKey(vec![]),
+            recipient_account_id: AccountId::from_bytes([2u8; 32]),
+            amount: CHRONOS_PER_KX,
+            unlock_at,
+            created_at: 0,
+            status: TimeLockStatus::Pending,
+            memo: None,
+            lock_version: 0,
+            claim_policy: None,
+            beneficiary_anchor_commitment: None,
+            org_identifier: None,
+            cancellation_window_secs: None,
+            notify_recipient: true,
+            tags: None,
+            private: false,
+            expiry_policy: None,
+            split_policy: None,
+            claim_attempts_max: None,
+            recurring: None,
+            lock_marker: None,
+            oracle_hint: None,
+            jurisdiction_hint: None,
+            governance_proposal_id: None,
+            client_ref: None,
+            transferable: false,
+            transfer_policy: None,
+            current_beneficiary: None,
+            transfer_history: Vec::new(),
+            earliest_transfer_date: None,
+            email_recipient_hash: None,
+            claim_window_secs: None,
+            unclaimed_action: None,
+            notification_sent: false,
+            condition_description: None,
+            condition_expiry: None,
+            condition_oracle: None,
+            condition_precision: None,
+            condition_status: None,
+            condition_attestation_id: None,
+            condition_disputed: false,
+            condition_dispute_window_secs: None,
+            lock_type: None,
+            yield_opt_out: None,
+            lock_metadata: None,
+            extension_right: None,
+            max_extensions: None,
+            extensions_used: None,
+            next_recurring_index: 0,
+            recurring_series_id: None,
+            split_policy_validated: false,
+            cancellation_reason: None,
+        };
+        db.put_timelock(&contract).unwrap();
+    }
+
+    #[test]
+    fn countdown_batch_mixed_ids_in_input_order() {
+        let db = temp_db("countdown_batch");
+        let now = 1_000_000;
+        let matured = TxId::from_bytes([10u8; 32]);
+        let pending = TxId::from_bytes([11u8; 32]);
+        let missing = TxId::from_bytes([12u8; 32]);
+        seed_lock(&db, matured.clone(), now - 5);
+        seed_lock(&db, pending.clone(), now + 90);
+
+        let ids = vec![
+            pending.to_hex(),
+            missing.to_hex(),
+            "not-hex".to_string(),
+            matured.to_hex(),
+        ];
+        let out = lock_countdowns(&db, &ids, now).unwrap();
+
+        assert_eq!(out.len(), 4);
+        assert_eq!(out[0].lock_id, pending.to_hex());
+        assert_eq!(out[0].seconds_remaining, 90);
+        assert!(!out[0].is_matured);
+        assert_eq!(out[0].status, "Pending");
+
+        assert_eq!(out[1].status, "NotFound");
+        assert_eq!(out[2].status, "NotFound");
+
+        assert_eq!(out[3].lock_id, matured.to_hex());
+        assert_eq!(out[3].seconds_remaining, -5);
+        assert!(out[3].is_matured);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn summary_batch_mixed_ids_in_input_order() {
+        let db = temp_db("summary_batch");
+        let now = chrono::Utc::now().timestamp();
+        let matured = TxId::from_bytes([20u8; 32]);
+        let private = TxId::from_bytes([21u8; 32]);
+        let missing = TxId::from_bytes([22u8; 32]);
+        seed_lock(&db, matured.clone(), now - 5);
+        seed_lock(&db, private.clone(), now + 3_600);
+        let mut tlc = db.get_timelock(&private).unwrap().unwrap();
+        tlc.private = true;
+        db.put_timelock(&tlc).unwrap();
+        let server = test_server(db);
+
+        let ids = vec![private.to_hex(), missing.to_hex(), "not-hex".to_string(), matured.to_hex()];
+        let out = server.get_lock_summary_batch(ids).await.unwrap();
+        assert_eq!(out.len(), 4);
+        match &out[0] {
+            RpcLockSummary::Found { lock_id, amount_kx, is_matured, seconds_until_unlock, status, .. } => {
+                assert_eq!(*lock_id, private.to_hex());
+                assert_eq!(*amount_kx, None, "private lock amount withheld");
+                assert!(!is_matured);
+                assert!(*seconds_until_unlock > 0);
+                assert_eq!(status, "Pending");
+            }
+            other => panic!("expected a summary, got {other:?}"),
+        }
+        for (entry, id) in out[1..3].iter().zip([missing.to_hex(), "not-hex".to_string()]) {
+            assert_eq!(*entry, RpcLockSummary::NotFound { lock_id: id, error: "not_found".into() });
+        }
+        match &out[3] {
+            RpcLockSummary::Found { amount_kx, is_matured, recipient, .. } => {
+                assert_eq!(amount_kx.as_deref(), Some("1"));
+                assert!(is_matured);
+                assert_eq!(*recipient, AccountId::from_bytes([2u8; 32]).to_b58());
+            }
+            other => panic!("expected a summary, got {other:?}"),
+        }
+
+        // Not-found entries round-trip through the untagged encoding.
+        let json = serde_json::to_value(&out).unwrap();
+        assert_eq!(json[1], serde_json::json!({ "lock_id": missing.to_hex(), "error": "not_found" }));
+        assert!(json[0].get("amount_kx").is_none());
+        assert_eq!(serde_json::from_value::<Vec<RpcLockSummary>>(json).unwrap(), out);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn summary_batch_reads_a_full_batch_concurrently() {
+        let db = temp_db("summary_batch_full");
+        let now = chrono::Utc::now().timestamp();
+        let ids: Vec<TxId> = (0..MAX_SUMMARY_BATCH as u8).map(|i| TxId::from_bytes([i; 32])).collect();
+        for (i, id) in ids.iter().enumerate() {
+            seed_lock(&db, id.clone(), now + i as i64 * 60);
+        }
+        let server = test_server(db);
+
+        let hex: Vec<String> = ids.iter().map(TxId::to_hex).collect();
+        let out = server.get_lock_summary_batch(hex.clone()).await.unwrap();
+        let got: Vec<&str> = out
+            .iter()
+            .map(|s| match s {
+                RpcLockSummary::Found { lock_id, .. } => lock_id.as_str(),
+                RpcLockSummary::NotFound { .. } => panic!("seeded lock reported missing"),
+            })
+            .collect();
+        assert_eq!(got, hex);
+
+        let mut too_many = hex;
+        too_many.push(TxId::from_bytes([0xFF; 32]).to_hex());
+        assert!(server.get_lock_summary_batch(too_many).await.is_err());
+    }
+
+    #[test]
+    fn rate_limiter_enforces_and_reloads_limit() {
+        let limiter = RpcRateLimiter::new(2);
+        assert!(limiter.check(120));
+        assert!(limiter.check(121));
+        assert!(!limiter.check(122));
+        // A new minute resets the window.
+        assert!(limiter.check(180));
+
+        limiter.set_limit(0);
+        assert!((0..100).all(|_| limiter.check(181)));
+    }
+
+    #[tokio::test]
+    async fn network_info_reports_p2p_stats() {
+        let stats = P2pStats {
+            connected_peers: vec!["peerA".into(), "peerB".into()],
+            kademlia_routing_table_size: 3,
+            gossipsub_mesh_peers: vec!["peerA".into()],
+            bootstrap_peers: vec!["/ip4/127.0.0.1/tcp/7777/p2p/peerA".into()],
+            protocol_version: "/chronx/1.0.0".into(),
+            network_id: "chronx-vertices".into(),
+            outbound_peers: 1,
+            inbound_peers: 1,
+            peers_evicted: 4,
+            unsigned_messages_rejected: 0,
+            gossip_messages_published: 0,
+            ..P2pStats::default()
+        };
+        let server = RpcServer::new(Arc::new(RpcServerState {
+            db: Arc::new(temp_db("network_info")),
+            pow_difficulty: 0,
+            tx_sender: None,
+            submit_timeout: DEFAULT_SUBMIT_TIMEOUT,
+            mempool: None,
+            peer_multiaddr: Some("/ip4/127.0.0.1/tcp/7778/p2p/self".into()),
+            peer_count: Arc::new(AtomicU64::new(2)),
+            rate_limiter: Arc::new(RpcRateLimiter::new(0)),
+            p2p_stats: Some(Arc::new(std::sync::RwLock::new(stats))),
+            started_at: std::time::Instant::now(),
+            lock_status_subscriptions: Default::default(),
+            events: broadcast::channel(EVENT_CHANNEL_CAPACITY).0,
+            checkpoint_dir: None,
+            active_alerts: Default::default(),
+            timeline_enabled: true,
+            full_scan_enabled: true,
+            sweep_reports_enabled: true,
+            topology_enabled: true,
+            admin_token: None,
+            p2p_listen_addr: None,
+            rpc_addr: None,
+        }));
+
+        let info = server.get_network_info().await.unwrap();
+        assert_eq!(info.peer_count, 2);
+        assert_eq!(info.connected_peers.len(), 2);
+        assert_eq!(info.gossipsub_mesh_size, 1);
+        assert_eq!(info.kademlia_routing_table_size, 3);
+        assert_eq!(info.bootstrap_peers.len(), 1);
+        assert_eq!(info.network_id, "chronx-vertices");
+        assert_eq!(info.p2p_protocol_version, "/chronx/1.0.0");
+        assert_eq!((info.outbound_peers, info.inbound_peers), (1, 1));
+        assert_eq!(info.peers_evicted, 4);
+    }
+
+    #[tokio::test]
+    async fn network_topology_lists_connections_when_enabled() {
+        let connection = |peer: &str, direction, in_mesh| chronx_p2p::PeerConnection {
+            peer_id: peer.into(),
+            multiaddr: format!("/ip4/127.0.0.1/tcp/7777/p2p/{peer}"),
+            direction,
+            connected_since: 1_700_000_000,
+            gossipsub_in_mesh: in_mesh,
+            reputation_score: 3,
+        };
+        let stats = P2pStats {
+            local_peer_id: "self".into(),
+            kademlia_routing_table_size: 2,
+            gossipsub_mesh_peers: vec!["peerA".into()],
+            connections: vec![
+                connection("peerA", chronx_p2p::Direction::Outbound, true),
+                connection("peerB", chronx_p2p::Direction::Inbound, false),
+            ],
+            ..P2pStats::default()
+        };
+        let mut server = test_server(temp_db("network_topology"));
+        Arc::get_mut(&mut server.state).unwrap().p2p_stats = Some(Arc::new(std::sync::RwLock::new(stats)));
+
+        let topology = server.get_network_topology().await.unwrap();
+        assert_eq!(topology.self_peer_id, "self");
+        assert_eq!(topology.kademlia_table_size, 2);
+        assert_eq!(topology.gossipsub_mesh_peers, vec!["peerA"]);
+        let directions: Vec<(&str, &str, bool)> = topology
+            .connections
+            .iter()
+            .map(|c| (c.peer_id.as_str(), c.direction.as_str(), c.gossipsub_in_mesh))
+            .collect();
+        assert_eq!(directions, [("peerA", "outbound", true), ("peerB", "inbound", false)]);
+        assert_eq!(topology.connections[1].multiaddr, "/ip4/127.0.0.1/tcp/7777/p2p/peerB");
+        assert_eq!(topology.connections[1].connected_since, 1_700_000_000);
+
+        Arc::get_mut(&mut server.state).unwrap().topology_enabled = false;
+        assert!(server.get_network_topology().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn system_info_reports_host_and_storage() {
+        let db = temp_db("system_info");
+        db.put_meta(chronx_genesis::GENESIS_HASH_META_KEY, b"abc123").unwrap();
+        db.flush().unwrap();
+        let data_dir = db.path().display().to_string();
+        let server = test_server(db);
+
+        let info = server.get_system_info().await.unwrap();
+        assert_eq!(info.os, std::env::consts::OS);
+        assert_eq!(info.arch, std::env::consts::ARCH);
+        assert_eq!(info.data_dir, data_dir);
+        assert!(info.db_size_bytes > 0);
+        assert!(info.rust_version.starts_with("rustc"));
+        assert_eq!(info.genesis_hash, "abc123");
+        assert_eq!(info.total_supply_chronos, TOTAL_SUPPLY_CHRONOS.to_string());
+        assert!(!info.feature_flags.governance_set);
+    }
+
+    #[tokio::test]
+    async fn notification_flag_update_and_unnotified_query() {
+        let db = temp_db("unnotified_locks");
+        let later = TxId::from_bytes([40u8; 32]);
+        let sooner = TxId::from_bytes([41u8; 32]);
+        let silent = TxId::from_bytes([42u8; 32]);
+        let claimed = TxId::from_bytes([43u8; 32]);
+        for (id, unlock_at) in [(&later, 2_000), (&sooner, 1_000), (&silent, 500), (&claimed, 100)] {
+            seed_lock(&db, id.clone(), unlock_at);
+        }
+        let mut tlc = db.get_timelock(&silent).unwrap().unwrap();
+        tlc.notify_recipient = false;
+        db.put_timelock(&tlc).unwrap();
+        let mut tlc = db.get_timelock(&claimed).unwrap().unwrap();
+        tlc.status = TimeLockStatus::Claimed { claimed_at: 150 };
+        db.put_timelock(&tlc).unwrap();
+        let mut server = test_server(db);
+        Arc::get_mut(&mut server.state).unwrap().admin_token = Some("s3cret".into());
+
+        let ids = |locks: Vec<RpcTimeLock>| locks.into_iter().map(|l| l.lock_id).collect::<Vec<_>>();
+        let pending = server.get_unnotified_locks(10).await.unwrap();
+        assert_eq!(ids(pending), [sooner.to_hex(), later.to_hex()]);
+        assert_eq!(ids(server.get_unnotified_locks(1).await.unwrap()), [sooner.to_hex()]);
+
+        assert!(server.mark_notification_sent(sooner.to_hex(), "wrong".into()).await.is_err());
+        assert!(server.mark_notification_sent(TxId::from_bytes([44u8; 32]).to_hex(), "s3cret".into()).await.is_err());
+        assert!(server.mark_notification_sent(sooner.to_hex(), "s3cret".into()).await.unwrap());
+        assert!(server.state.db.get_timelock(&sooner).unwrap().unwrap().notification_sent);
+        assert_eq!(ids(server.get_unnotified_locks(10).await.unwrap()), [later.to_hex()]);
+
+        // Without a configured token the call is refused outright.
+        Arc::get_mut(&mut server.state).unwrap().admin_token = None;
+        assert!(server.mark_notification_sent(later.to_hex(), "s3cret".into()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn db_diagnostics_cover_every_tree() {
+        let db = temp_db("db_diagnostics");
+        seed_lock(&db, TxId::from_bytes([30u8; 32]), 0);
+        db.flush().unwrap();
+        let server = test_server(db);
+
+        let diag = server.get_db_diagnostics().await.unwrap();
+        assert_eq!(diag.trees.len(), chronx_state::db::KNOWN_TREES.len());
+        assert!(diag.unknown_trees.is_empty());
+        let timelocks = diag.trees.iter().find(|t| t.name == "timelocks").unwrap();
+        assert_eq!(timelocks.entry_count, 1);
+        assert!(diag.total_size_bytes > 0);
+        assert!(diag.cache_utilization > 0.0 && diag.cache_utilization < 1.0);
+    }
+
+    #[tokio::test]
+    async fn lock_status_subscription_pushes_claim() {
+        use chronx_core::account::{Account, AuthPolicy};
+        use chronx_core::transaction::AuthScheme;
+        use chronx_core::types::TimeLockId;
+        use chronx_crypto::{hash::tx_id_from_body, KeyPair};
+        use jsonrpsee::core::client::SubscriptionClientT;
+        use jsonrpsee::rpc_params;
+        use jsonrpsee::ws_client::WsClientBuilder;
+
+        let db = Arc::new(temp_db("lock_status_sub"));
+        let recipient = KeyPair::generate();
+        db.put_account(&Account::new(
+            recipient.account_id.clone(),
+            AuthPolicy::SingleSig { public_key: recipient.public_key.clone() },
+        ))
+        .unwrap();
+        let lock_id = TxId::from_bytes([42u8; 32]);
+        seed_lock(&db, lock_id.clone(), 1_000);
+        let mut lock = db.get_timelock(&lock_id).unwrap().unwrap();
+        lock.recipient_key = recipient.public_key.clone();
+        lock.recipient_account_id = recipient.account_id.clone();
+        db.put_timelock(&lock).unwrap();
+
+        let state = Arc::new(RpcServerState {
+            db: Arc::clone(&db),
+            pow_difficulty: 0,
+            tx_sender: None,
+            submit_timeout: DEFAULT_SUBMIT_TIMEOUT,
+            mempool: None,
+            peer_multiaddr: None,
+            peer_count: Arc::new(AtomicU64::new(0)),
+            rate_limiter: Arc::new(RpcRateLimiter::new(0)),
+            p2p_stats: None,
+            started_at: std::time::Instant::now(),
+            lock_status_subscriptions: Default::default(),
+            events: broadcast::channel(EVENT_CHANNEL_CAPACITY).0,
+            checkpoint_dir: None,
+            active_alerts: Default::default(),
+            timeline_enabled: true,
+            full_scan_enabled: true,
+            sweep_reports_enabled: true,
+            topology_enabled: true,
+            admin_token: None,
+            p2p_listen_addr: None,
+            rpc_addr: None,
+        });
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let _handle = RpcServer::new(Arc::clone(&state))
+            .start(([127, 0, 0, 1], port).into())
+            .await
+            .unwrap();
+
+        let client = WsClientBuilder::default()
+            .build(format!("ws://127.0.0.1:{port}"))
+            .await
+            .unwrap();
+        let mut sub = client
+            .subscribe::<RpcLockStatusEvent, _>(
+                "chronx_subscribeLockStatus",
+                rpc_params![lock_id.to_hex()],
+                "chronx_unsubscribeLockStatus",
+            )
+            .await
+            .unwrap();
+
+        // Claim the matured lock through the engine, as the node's main loop does.
+        let engine = chronx_state::StateEngine::new(Arc::clone(&db), 0);
+        let mut tx = Transaction {
+            tx_id: TxId::from_bytes([0u8; 32]),
+            parents: vec![],
+            timestamp: 2_000,
+            nonce: 0,
+            from: recipient.account_id.clone(),
+            actions: vec![Action::TimeLockClaim { lock_id: TimeLockId(lock_id.clone()) }],
+            pow_nonce: 0,
+            signatures: vec![],
+            auth_scheme: AuthScheme::SingleSig,
+            tx_version: 1,
+            client_ref: None,
+            fee_chronos: 0,
+            expires_at: None,
+            sender_public_key: Some(recipient.public_key.clone()),
+        };
+        let body_bytes = tx.body_bytes();
+        tx.tx_id = tx_id_from_body(&body_bytes);
+        tx.signatures = vec![recipient.sign(&body_bytes)];
+        let receipt = engine.apply_with_receipt(&tx, 2_000).unwrap();
+        state.notify_lock_status(&receipt.lock_status_changes, 2_000).await;
+
+        let event = tokio::time::timeout(std::time::Duration::from_millis(500), sub.next())
+            .await
+            .expect("no lock status notification within 500ms")
+            .unwrap()
+            .unwrap();
+        assert_eq!(event.lock_id, lock_id.to_hex());
+        assert_eq!(event.new_status, "Claimed");
+        assert_eq!(event.timestamp, 2_000);
+        assert!(event.is_terminal);
+        // Terminal status drops every subscriber for the lock.
+        assert!(state.lock_status_subscriptions.get(&lock_id).is_none());
+    }
+
+    #[tokio::test]
+    async fn event_subscriptions_push_applied_transactions() {
+        use chronx_core::account::{Account, AuthPolicy};
+        use chronx_core::transaction::AuthScheme;
+        use chronx_core::types::TimeLockId;
+        use chronx_crypto::{hash::tx_id_from_body, KeyPair};
+        use jsonrpsee::core::client::{ClientT, SubscriptionClientT};
+        use jsonrpsee::http_client::HttpClientBuilder;
+        use jsonrpsee::rpc_params;
+        use jsonrpsee::ws_client::WsClientBuilder;
+
+        let db = Arc::new(temp_db("event_subs"));
+        let kp = KeyPair::generate();
+        let mut account = Account::new(
+            kp.account_id.clone(),
+            AuthPolicy::SingleSig { public_key: kp.public_key.clone() },
+        );
+        account.balance = 10 * CHRONOS_PER_KX;
+        db.put_account(&account).unwrap();
+        let lock_id = TxId::from_bytes([43u8; 32]);
+        seed_lock(&db, lock_id.clone(), 1_000);
+        let mut lock = db.get_timelock(&lock_id).unwrap().unwrap();
+        lock.recipient_key = kp.public_key.clone();
+        lock.recipient_account_id = kp.account_id.clone();
+        db.put_timelock(&lock).unwrap();
+
+        // Stand in for the node's apply loop.
+        let (tx_sender, mut tx_receiver) = tokio::sync::mpsc::channel::<SubmittedTx>(8);
+        let state = Arc::new(RpcServerState {
+            db: Arc::clone(&db),
+            pow_difficulty: 0,
+            tx_sender: Some(tx_sender),
+            submit_timeout: DEFAULT_SUBMIT_TIMEOUT,
+            mempool: None,
+            peer_multiaddr: None,
+            peer_count: Arc::new(AtomicU64::new(0)),
+            rate_limiter: Arc::new(RpcRateLimiter::new(0)),
+            p2p_stats: None,
+            started_at: std::time::Instant::now(),
+            lock_status_subscriptions: Default::default(),
+            events: broadcast::channel(EVENT_CHANNEL_CAPACITY).0,
+            checkpoint_dir: None,
+            active_alerts: Default::default(),
+            timeline_enabled: true,
+            full_scan_enabled: true,
+            sweep_reports_enabled: true,
+            topology_enabled: true,
+            admin_token: None,
+            p2p_listen_addr: None,
+            rpc_addr: None,
+        });
+        let apply_state = Arc::clone(&state);
+        tokio::spawn(async move {
+            let engine = chronx_state::StateEngine::new(Arc::clone(&apply_state.db), 0);
+            while let Some(SubmittedTx { tx, outcome }) = tx_receiver.recv().await {
+                let receipt = engine.apply_with_receipt(&tx, 2_000).unwrap();
+                apply_state.publish_applied(&tx, &receipt, 2_000);
+                let _ = outcome.unwrap().send(Ok(tx.tx_id.clone()));
+            }
+        });
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let _handle = RpcServer::new(Arc::clone(&state))
+            .start(([127, 0, 0, 1], port).into())
+            .await
+            .unwrap();
+
+        let ws = WsClientBuilder::default()
+            .build(format!("ws://127.0.0.1:{port}"))
+            .await
+            .unwrap();
+        let mut vertices = ws
+            .subscribe::<RpcVertexNotification, _>(
+                "chronx_subscribeNewVertices",
+                rpc_params![],
+                "chronx_unsubscribeNewVertices",
+            )
+            .await
+            .unwrap();
+        let mut mine = ws
+            .subscribe::<RpcLockUpdateEvent, _>(
+                "chronx_subscribeLockUpdates",
+                rpc_params![kp.account_id.to_b58()],
+                "chronx_unsubscribeLockUpdates",
+            )
+            .await
+            .unwrap();
+        let mut others = ws
+            .subscribe::<RpcLockUpdateEvent, _>(
+                "chronx_subscribeLockUpdates",
+                rpc_params![AccountId::from_bytes([9u8; 32]).to_b58()],
+                "chronx_unsubscribeLockUpdates",
+            )
+            .await
+            .unwrap();
+
+        // Claim the lock and pass some of it on, submitted over HTTP.
+        let mut tx = Transaction {
+            tx_id: TxId::from_bytes([0u8; 32]),
+            parents: vec![],
+            timestamp: 2_000,
+            nonce: 0,
+            from: kp.account_id.clone(),
+            actions: vec![
+                Action::TimeLockClaim { lock_id: TimeLockId(lock_id.clone()) },
+                Action::Transfer {
+                    to: AccountId::from_bytes([7u8; 32]),
+                    amount: CHRONOS_PER_KX,
+                    memo: None,
+                    memo_encrypted: false,
+                    memo_public: false,
+                    pay_as_amount: None,
+                },
+            ],
+            pow_nonce: 0,
+            signatures: vec![],
+            auth_scheme: AuthScheme::SingleSig,
+            tx_version: 1,
+            client_ref: None,
+            fee_chronos: 0,
+            expires_at: None,
+            sender_public_key: Some(kp.public_key.clone()),
+        };
+        let body_bytes = tx.body_bytes();
+        tx.tx_id = tx_id_from_body(&body_bytes);
+        tx.signatures = vec![kp.sign(&body_bytes)];
+        let http = HttpClientBuilder::default()
+            .build(format!("http://127.0.0.1:{port}"))
+            .unwrap();
+        let tx_hex = hex::encode(bincode::serialize(&tx).unwrap());
+        let sent: RpcSubmitResult = http.request("chronx_submitTransaction", rpc_params![tx_hex]).await.unwrap();
+        assert_eq!((sent.tx_id.as_str(), sent.status.as_str()), (tx.tx_id.to_hex().as_str(), "applied"));
+
+        let wait = std::time::Duration::from_millis(500);
+        let vertex = tokio::time::timeout(wait, vertices.next())
+            .await
+            .expect("no vertex notification within 500ms")
+            .unwrap()
+            .unwrap();
+        assert_eq!(vertex.tx_id, tx.tx_id.to_hex());
+        assert_eq!(vertex.from, kp.account_id.to_b58());
+        assert_eq!(vertex.timestamp, 2_000);
+        assert_eq!(vertex.action_types, vec!["TimeLockClaim", "Transfer"]);
+
+        let update = tokio::time::timeout(wait, mine.next())
+            .await
+            .expect("no lock update within 500ms")
+            .unwrap()
+            .unwrap();
+        assert_eq!(update.lock_id, lock_id.to_hex());
+        assert_eq!(update.recipient, kp.account_id.to_b58());
+        assert_eq!(update.new_status, "Claimed");
+        assert!(update.is_terminal);
+        // Subscribers for other accounts hear nothing.
+        assert!(tokio::time::timeout(std::time::Duration::from_millis(100), others.next()).await.is_err());
+    }
+
+    fn test_server(db: StateDb) -> RpcServer {
+        RpcServer::new(Arc::new(RpcServerState {
+            db: Arc::new(db),
+            pow_difficulty: 0,
+            tx_sender: None,
+            submit_timeout: DEFAULT_SUBMIT_TIMEOUT,
+            mempool: None,
+            peer_multiaddr: None,
+            peer_count: Arc::new(AtomicU64::new(0)),
+            rate_limiter: Arc::new(RpcRateLimiter::new(0)),
+            p2p_stats: None,
+            started_at: std::time::Instant::now(),
+            lock_status_subscriptions: Default::default(),
+            events: broadcast::channel(EVENT_CHANNEL_CAPACITY).0,
+            checkpoint_dir: None,
+            active_alerts: Default::default(),
+            timeline_enabled: true,
+            full_scan_enabled: true,
+            sweep_reports_enabled: true,
+            topology_enabled: true,
+            admin_token: None,
+            p2p_listen_addr: None,
+            rpc_addr: None,
+        }))
+    }
+
+    fn seed_prefixed_account(db: &StateDb, head: [u8; 4], fill: u8) -> AccountId {
+        use chronx_core::account::{Account, AuthPolicy};
+        let mut bytes = [fill; 32];
+        bytes[..4].copy_from_slice(&head);
+        let id = AccountId::from_bytes(bytes);
+        let mut account = Account::new(
+            id.clone(),
+            AuthPolicy::SingleSig { public_key: DilithiumPublicKey(vec![]) },
+        );
+        account.balance = 3 * CHRONOS_PER_KX;
+        db.put_account(&account).unwrap();
+        id
+    }
+
+    #[tokio::test]
+    async fn find_account_by_prefix_single_match() {
+        let db = temp_db("prefix_single");
+        let target = seed_prefixed_account(&db, [0xab, 0xcd, 0x01, 0x03], 1);
+        seed_prefixed_account(&db, [0xab, 0xcd, 0x01, 0x02], 1);
+        let server = test_server(db);
+
+        let found = server.find_account_by_prefix("abcd0103".into()).await.unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].account_id, target.to_b58());
+        assert_eq!(found[0].balance_kx, "3");
+    }
+
+    #[tokio::test]
+    async fn find_by_prefix_returns_every_match() {
+        let db = temp_db("prefix_multiple");
+        let a = seed_prefixed_account(&db, [0xab, 0xcd, 0x01, 0x02], 1);
+        let b = seed_prefixed_account(&db, [0xab, 0xcd, 0x01, 0x02], 2);
+        seed_prefixed_account(&db, [0xab, 0xcd, 0x01, 0x03], 1);
+        seed_lock(&db, TxId::from_bytes([0x77; 32]), 0);
+        let mut other = [0x77; 32];
+        other[31] = 0;
+        seed_lock(&db, TxId::from_bytes(other), 0);
+        seed_lock(&db, TxId::from_bytes([0x78; 32]), 0);
+        let server = test_server(db);
+
+        let accounts = server.find_account_by_prefix("ABCD0102".into()).await.unwrap();
+        let ids: Vec<_> = accounts.into_iter().map(|m| m.account_id).collect();
+        assert_eq!(ids, vec![a.to_b58(), b.to_b58()]);
+
+        let locks = server.find_lock_by_prefix("77777777".into()).await.unwrap();
+        assert_eq!(locks.len(), 2);
+        assert!(locks.iter().all(|id| id.starts_with("77777777")));
+    }
+
+    #[tokio::test]
+    async fn find_by_prefix_rejects_short_prefix() {
+        let db = temp_db("prefix_short");
+        seed_prefixed_account(&db, [0xab, 0xcd, 0x01, 0x02], 1);
+        let server = test_server(db);
+
+        let err = server.find_account_by_prefix("abcd01".into()).await.unwrap_err();
+        assert_eq!(err.code(), -32602);
+        let err = server.find_lock_by_prefix("abcd01".into()).await.unwrap_err();
+        assert_eq!(err.code(), -32602);
+        let err = server.find_account_by_prefix("not-hex!".into()).await.unwrap_err();
+        assert_eq!(err.code(), -32602);
+    }
+
+    #[tokio::test]
+    async fn private_lock_details_need_a_party_signature() {
+        use chronx_core::account::{Account, AuthPolicy};
+        use chronx_crypto::{private_lock_view_challenge, KeyPair};
+
+        let db = temp_db("private_lock_details");
+        let lock_id = TxId::from_bytes([0x42; 32]);
+        seed_lock(&db, lock_id.clone(), 0);
+        let mut tlc = db.get_timelock(&lock_id).unwrap().unwrap();
+        tlc.private = true;
+        tlc.memo = Some("for your eyes only".into());
+        db.put_timelock(&tlc).unwrap();
+        let sender_kp = KeyPair::generate();
+        db.put_account(&Account::new(
+            tlc.sender.clone(),
+            AuthPolicy::SingleSig { public_key: sender_kp.public_key.clone() },
+        ))
+        .unwrap();
+        let server = test_server(db);
+        let now = chrono::Utc::now().timestamp();
+        let sign_hex = |kp: &KeyPair, at: i64| {
+            hex::encode(kp.sign(&private_lock_view_challenge(&lock_id, at)).0)
+        };
+
+        let lock = server
+            .get_private_timelock_details(lock_id.to_hex(), sign_hex(&sender_kp, now))
+            .await
+            .unwrap();
+        assert_eq!(lock.memo.as_deref(), Some("for your eyes only"));
+        assert!(lock.private);
+
+        // A stranger's key, or last hour's challenge, is refused.
+        let stranger = KeyPair::generate();
+        let err = server
+            .get_private_timelock_details(lock_id.to_hex(), sign_hex(&stranger, now))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), -32602);
+        let err = server
+            .get_private_timelock_details(lock_id.to_hex(), sign_hex(&sender_kp, now - 3600))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), -32602);
+    }
+
+    #[tokio::test]
+    async fn get_all_accounts_empty_and_single_page() {
+        let server = test_server(temp_db("all_accounts_empty"));
+        let page = server.get_all_accounts(None, 10, None, None).await.unwrap();
+        assert!(page.accounts.is_empty());
+        assert!(page.next_cursor.is_none());
+
+        let db = temp_db("all_accounts_single");
+        let a = seed_prefixed_account(&db, [0x01, 0, 0, 0], 1);
+        let b = seed_prefixed_account(&db, [0x02, 0, 0, 0], 1);
+        let server = test_server(db);
+        let page = server.get_all_accounts(None, 10, None, None).await.unwrap();
+        let ids: Vec<_> = page.accounts.iter().map(|x| x.account_id.clone()).collect();
+        assert_eq!(ids, vec![a.to_b58(), b.to_b58()]);
+        assert_eq!(page.accounts[0].balance_kx, "3");
+        assert!(page.next_cursor.is_none());
+    }
+
+    #[tokio::test]
+    async fn get_all_accounts_paginates_in_id_order() {
+        let db = temp_db("all_accounts_multi");
+        let ids: Vec<_> = (1..=5u8).map(|i| seed_prefixed_account(&db, [i, 0, 0, 0], 1)).collect();
+        let server = test_server(db);
+
+        let mut seen = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = server.get_all_accounts(cursor, 2, None, None).await.unwrap();
+            assert!(page.accounts.len() <= 2);
+            seen.extend(page.accounts.into_iter().map(|a| a.account_id));
+            cursor = page.next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        assert_eq!(seen, ids.iter().map(|id| id.to_b58()).collect::<Vec<_>>());
+
+        // An exactly-full last page still ends the scan.
+        let page = server.get_all_accounts(None, 5, None, None).await.unwrap();
+        assert_eq!(page.accounts.len(), 5);
+        assert!(page.next_cursor.is_none());
+
+        let err = server.get_all_accounts(Some("not-an-id".into()), 2, None, None).await.unwrap_err();
+        assert_eq!(err.code(), -32602);
+    }
+
+    #[tokio::test]
+    async fn get_all_accounts_cursor_survives_writes() {
+        let db = temp_db("all_accounts_cursor");
+        for i in [0x10u8, 0x20, 0x30] {
+            seed_prefixed_account(&db, [i, 0, 0, 0], 1);
+        }
+        let mut server = test_server(db);
+
+        let first = server.get_all_accounts(None, 2, None, None).await.unwrap();
+        let cursor = first.next_cursor.clone().unwrap();
+        assert_eq!(cursor, first.accounts[1].account_id);
+
+        // Accounts created behind the cursor are skipped; ones ahead of it
+        // show up, and nothing from the first page repeats.
+        seed_prefixed_account(&server.state.db, [0x05, 0, 0, 0], 1);
+        let ahead = seed_prefixed_account(&server.state.db, [0x40, 0, 0, 0], 1);
+        let second = server.get_all_accounts(Some(cursor), 2, None, None).await.unwrap();
+        assert_eq!(second.accounts.len(), 2);
+        assert_eq!(second.accounts[1].account_id, ahead.to_b58());
+        assert!(second.next_cursor.is_none());
+        assert!(second
+            .accounts
+            .iter()
+            .all(|a| first.accounts.iter().all(|f| f.account_id != a.account_id)));
+
+        Arc::get_mut(&mut server.state).unwrap().full_scan_enabled = false;
+        let err = server.get_all_accounts(None, 2, None, None).await.unwrap_err();
+        assert_eq!(err.code(), -32603);
+    }
+
+    #[tokio::test]
+    async fn accounts_by_creation_time_filters_and_stats() {
+        use chronx_core::account::{Account, AuthPolicy};
+        let db = temp_db("accounts_by_creation");
+        // 2026-01-15, 2026-01-20, 2026-02-03, 2026-03-01 (UTC).
+        let times = [1_768_435_200, 1_768_867_200, 1_770_076_800, 1_772_323_200];
+        let mut ids = Vec::new();
+        for (i, ts) in times.iter().enumerate() {
+            let id = AccountId::from_bytes([0x90 - i as u8; 32]);
+            let mut acc = Account::new(
+                id.clone(),
+                AuthPolicy::SingleSig { public_key: DilithiumPublicKey(vec![]) },
+            );
+            acc.created_at = Some(*ts);
+            db.put_account(&acc).unwrap();
+            ids.push(id.to_b58());
+        }
+        seed_prefixed_account(&db, [0xee, 0, 0, 0], 1); // no created_at
+        let server = test_server(db);
+
+        // Creation order, not id order; the undated account is left out.
+        let first = server
+            .get_all_accounts(None, 2, Some(times[0]), None)
+            .await
+            .unwrap();
+        assert_eq!(
+            first.accounts.iter().map(|a| a.account_id.clone()).collect::<Vec<_>>(),
+            vec![ids[1].clone(), ids[2].clone()]
+        );
+        let rest = server
+            .get_all_accounts(first.next_cursor, 2, Some(times[0]), None)
+            .await
+            .unwrap();
+        assert_eq!(rest.accounts.len(), 1);
+        assert_eq!(rest.accounts[0].account_id, ids[3]);
+        assert!(rest.next_cursor.is_none());
+
+        let window = server
+            .get_all_accounts(None, 10, None, Some(times[2]))
+            .await
+            .unwrap();
+        assert_eq!(window.accounts.len(), 2);
+
+        let stats = server.get_account_age_stats().await.unwrap();
+        assert_eq!(stats.oldest_account_created_at, times[0]);
+        assert_eq!(stats.newest_account_created_at, times[3]);
+        let months: Vec<_> =
+            stats.accounts_per_month.iter().map(|m| (m.month.as_str(), m.count)).collect();
+        assert_eq!(months, vec![("2026-01", 2), ("2026-02", 1), ("2026-03", 1)]);
+    }
+
+    #[tokio::test]
+    async fn verifier_history_and_leaderboard() {
+        use chronx_core::constants::MIN_VERIFIER_STAKE_CHRONOS;
+        use chronx_state::db::VerifierVoteRecord;
+
+        let db = temp_db("verifier_history");
+        let busy = seed_prefixed_account(&db, [1, 0, 0, 0], 1);
+        let idle = seed_prefixed_account(&db, [2, 0, 0, 0], 2);
+        let slashed = seed_prefixed_account(&db, [3, 0, 0, 0], 3);
+        let target = seed_prefixed_account(&db, [4, 0, 0, 0], 4);
+        for (id, votes, stake) in [
+            (&busy, 2, MIN_VERIFIER_STAKE_CHRONOS),
+            (&idle, 0, MIN_VERIFIER_STAKE_CHRONOS),
+            (&slashed, 5, MIN_VERIFIER_STAKE_CHRONOS - 1),
+        ] {
+            let mut account = db.get_account(id).unwrap().unwrap();
+            account.is_verifier = true;
+            account.verifier_stake = stake;
+            account.votes_cast = votes;
+            db.put_account(&account).unwrap();
+        }
+        for (i, approve) in [(1u8, true), (2, false)] {
+            db.put_verifier_vote(&VerifierVoteRecord {
+                verifier: busy.clone(),
+                target_account: target.clone(),
+                recovery_started_at: 100,
+                approve,
+                vote_tx_id: TxId::from_bytes([i; 32]),
+                voted_at: 100 + i as i64,
+                outcome: (i == 1).then(|| "executed".to_string()),
+            })
+            .unwrap();
+        }
+        let server = test_server(db);
+
+        let history = server.get_verifier_history(busy.to_b58(), 10).await.unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].vote, "reject");
+        assert_eq!(history[0].outcome, None);
+        assert_eq!(history[1].vote, "approve");
+        assert_eq!(history[1].target_account, target.to_b58());
+        assert_eq!(history[1].outcome.as_deref(), Some("executed"));
+        assert_eq!(server.get_verifier_history(busy.to_b58(), 1).await.unwrap().len(), 1);
+        let err = server.get_verifier_history("not-b58!".into(), 10).await.unwrap_err();
+        assert_eq!(err.code(), -32602);
+
+        let board = server.get_verifier_leaderboard(10).await.unwrap();
+        let order: Vec<_> = board.iter().map(|r| r.verifier_id.clone()).collect();
+        assert_eq!(order, vec![busy.to_b58(), idle.to_b58(), slashed.to_b58()]);
+        assert!(!board[2].slash_free);
+        assert_eq!(board[0].votes_cast, 2);
+        assert_eq!(server.get_verifier_leaderboard(1).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn verifier_registrations_hide_understaked_unless_asked() {
+        use chronx_core::constants::MIN_VERIFIER_STAKE_CHRONOS;
+        use chronx_state::db::VerifierRegistration;
+
+        let db = temp_db("verifier_registrations");
+        for (byte, stake, votes) in [(1u8, MIN_VERIFIER_STAKE_CHRONOS, 3), (2, MIN_VERIFIER_STAKE_CHRONOS - 1, 0)] {
+            db.put_verifier_registration(&VerifierRegistration {
+                account_id: AccountId::from_bytes([byte; 32]),
+                stake,
+                registered_at: 100,
+                last_vote_at: (votes > 0).then_some(200),
+                total_votes: votes,
+                slash_count: 0,
+            })
+            .unwrap();
+        }
+        let server = test_server(db);
+
+        let active = server.get_verifier_registrations(false).await.unwrap();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].account_id, AccountId::from_bytes([1; 32]).to_b58());
+        assert_eq!((active[0].total_votes, active[0].last_vote_at, active[0].active), (3, Some(200), true));
+        assert_eq!(active[0].stake_chronos, MIN_VERIFIER_STAKE_CHRONOS.to_string());
+
+        let all = server.get_verifier_registrations(true).await.unwrap();
+        assert_eq!(all.len(), 2);
+        assert!(!all[1].active);
+    }
+
+    #[tokio::test]
+    async fn mempool_queries_report_queue_and_drops() {
+        use chronx_core::transaction::AuthScheme;
+        use chronx_mempool::Mempool;
+
+        let tx = |sender: u8, nonce: u64| Transaction {
+            tx_id: TxId::from_bytes([sender * 16 + nonce as u8; 32]),
+            parents: vec![],
+            timestamp: 1_000,
+            nonce,
+            from: AccountId::from_bytes([sender; 32]),
+            actions: vec![],
+            pow_nonce: 0,
+            signatures: vec![],
+            auth_scheme: AuthScheme::SingleSig,
+            tx_version: 1,
+            client_ref: None,
+            fee_chronos: 0,
+            expires_at: None,
+            sender_public_key: None,
+        };
+        let now = chrono::Utc::now().timestamp();
+        let mut pool = Mempool::new(10);
+        pool.insert(tx(1, 2), now - 30).unwrap();
+        pool.insert(tx(2, 0), now - 10).unwrap();
+        pool.insert(tx(1, 1), now - 5).unwrap();
+        pool.record_rejected(&tx(3, 0), "unknown account".into(), now);
+
+        let server = test_server(temp_db("mempool_queries"));
+        assert!(server.get_pending_transactions(None).await.unwrap().is_empty());
+        assert_eq!(server.get_mempool_info().await.unwrap().capacity, 0);
+
+        let mut state = Arc::try_unwrap(server.state).ok().unwrap();
+        state.mempool = Some(Arc::new(std::sync::Mutex::new(pool)));
+        let server = RpcServer::new(Arc::new(state));
+
+        let pending = server.get_pending_transactions(None).await.unwrap();
+        let order: Vec<_> = pending.iter().map(|p| (p.sender.clone(), p.nonce)).collect();
+        let sender = |b: u8| AccountId::from_bytes([b; 32]).to_b58();
+        assert_eq!(order, vec![(sender(1), 2), (sender(2), 0), (sender(1), 1)]);
+        assert_eq!(pending[0].tx_id, tx(1, 2).tx_id.to_hex());
+        assert!(pending[0].age_secs >= 30);
+        let mine = server.get_pending_transactions(Some(sender(2))).await.unwrap();
+        assert_eq!(mine.len(), 1);
+        let err = server.get_pending_transactions(Some("not-b58!".into())).await.unwrap_err();
+        assert_eq!(err.code(), -32602);
+
+        let info = server.get_mempool_info().await.unwrap();
+        assert_eq!((info.size, info.capacity, info.sender_count, info.dropped_total), (3, 10, 2, 1));
+        assert!(info.oldest_age_secs.unwrap() >= 30);
+        assert_eq!(info.recently_dropped[0].sender, sender(3));
+        assert_eq!(info.recently_dropped[0].reason, "unknown account");
+    }
+
+    #[tokio::test]
+    async fn locks_for_sale_pages_listings_only() {
+        let db = temp_db("locks_for_sale");
+        for i in 0..4u8 {
+            let id = TxId::from_bytes([0x70 + i; 32]);
+            seed_lock(&db, id.clone(), 2_000_000);
+            if i != 2 {
+                let mut lock = db.get_timelock(&id).unwrap().unwrap();
+                lock.status = TimeLockStatus::ForSale { ask_price: 5 + i as u128, listed_at: 1_000 };
+                db.put_timelock(&lock).unwrap();
+            }
+        }
+        // Delisting drops the lock from the index.
+        let delisted = TxId::from_bytes([0x73; 32]);
+        let mut lock = db.get_timelock(&delisted).unwrap().unwrap();
+        lock.status = TimeLockStatus::Pending;
+        db.put_timelock(&lock).unwrap();
+        let server = test_server(db);
+
+        let all = server.get_locks_for_sale(0, 10).await.unwrap();
+        let ids: Vec<_> = all.iter().map(|l| l.lock_id.clone()).collect();
+        assert_eq!(ids, vec![TxId::from_bytes([0x70; 32]).to_hex(), TxId::from_bytes([0x71; 32]).to_hex()]);
+        assert!(all.iter().all(|l| l.status == "ForSale"));
+        let page = server.get_locks_for_sale(1, 10).await.unwrap();
+        assert_eq!(page.len(), 1);
+        assert_eq!(page[0].lock_id, ids[1]);
+        assert!(server.get_locks_for_sale(0, 0).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn lock_transfer_history_lists_transfers_in_order() {
+        let db = temp_db("lock_transfer_history");
+        let id = TxId::from_bytes([0x82; 32]);
+        seed_lock(&db, id.clone(), 2_000_000);
+        let mut lock = db.get_timelock(&id).unwrap().unwrap();
+        lock.transfer_history = vec![TxId::from_bytes([1; 32]), TxId::from_bytes([2; 32])];
+        db.put_timelock(&lock).unwrap();
+        let server = test_server(db);
+
+        let history = server.get_lock_transfer_history(id.to_hex()).await.unwrap();
+        assert_eq!(history, vec![TxId::from_bytes([1; 32]).to_hex(), TxId::from_bytes([2; 32]).to_hex()]);
+        assert!(server.get_lock_transfer_history(TxId::from_bytes([0x83; 32]).to_hex()).await.is_err());
+        assert!(server.get_lock_transfer_history("zz".into()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn dag_health_reports_fork_pressure() {
+        use chronx_core::constants::MAX_DAG_TIPS;
+
+        let db = temp_db("dag_health");
+        for i in 0..(MAX_DAG_TIPS / 2 + 1) as u8 {
+            db.add_tip(&TxId::from_bytes([i; 32])).unwrap();
+        }
+        let server = test_server(db);
+
+        let health = server.get_dag_health().await.unwrap();
+        assert_eq!(health.tip_count, (MAX_DAG_TIPS / 2 + 1) as u64);
+        assert_eq!(health.max_tips, MAX_DAG_TIPS as u64);
+        assert!(health.fork_pressure > 0.5 && health.fork_pressure < 1.0);
+        assert!(health.recommendation.starts_with("fork width elevated"));
+
+        for i in 0..MAX_DAG_TIPS as u8 {
+            server.state.db.add_tip(&TxId::from_bytes([i; 32])).unwrap();
+        }
+        let health = server.get_dag_health().await.unwrap();
+        assert_eq!(health.fork_pressure, 1.0);
+        assert!(health.recommendation.starts_with("tip limit reached"));
+    }
+
+    /// Apply genesis with fresh keys, returned in `GenesisParams` field
+    /// order: public_sale, treasury, humanity, node_rewards, founder, misai,
+    /// verifas, milestone, reserve, faucet.
+    fn apply_test_genesis(db: &StateDb) -> Vec<chronx_crypto::KeyPair> {
+        let names = [
+            "public_sale", "treasury", "humanity", "node_rewards", "founder",
+            "misai", "verifas", "milestone", "reserve", "faucet",
+        ];
+        let keys: Vec<_> = names.iter().map(|_| chronx_crypto::KeyPair::generate()).collect();
+        let params: chronx_genesis::GenesisParams = serde_json::from_value(serde_json::Value::Object(
+            names
+                .iter()
+                .zip(&keys)
+                .map(|(name, kp)| (format!("{name}_key"), serde_json::to_value(&kp.public_key).unwrap()))
+                .collect(),
+        ))
+        .unwrap();
+        chronx_genesis::apply_genesis(db, &params).unwrap();
+        keys
+    }
+
+    #[tokio::test]
+    async fn genesis_info_reports_genesis_accounts_and_keys() {
+        let db = temp_db("genesis_info_accounts");
+        let keys = apply_test_genesis(&db);
+        let server = test_server(db);
+
+        let info = server.get_genesis_info().await.unwrap();
+        assert_eq!(info.public_sale_account, Some(keys[0].account_id.to_b58()));
+        assert_eq!(info.treasury_account, Some(keys[1].account_id.to_b58()));
+        assert_eq!(info.humanity_account, Some(keys[2].account_id.to_b58()));
+
+        let treasury = server.get_account(keys[1].account_id.to_b58()).await.unwrap().unwrap();
+        assert_eq!(treasury.public_key, Some(hex::encode(&keys[1].public_key.0)));
+    }
+
+    #[tokio::test]
+    async fn explorer_summary_after_genesis() {
+        use chronx_core::claims::{
+            CertificateSchema, OracleSnapshot, ProviderRecord, ProviderStatus, SignatureRules,
+        };
+        use chronx_core::constants::{HUMANITY_STAKE_KX, TREASURY_KX};
+        use chronx_core::transaction::AuthScheme;
+        use chronx_dag::vertex::Vertex;
+
+        let db = temp_db("explorer_summary");
+        let keys = apply_test_genesis(&db);
+        let now = chrono::Utc::now().timestamp();
+        let founder = keys[4].account_id.clone();
+        let tx = Transaction {
+            tx_id: TxId::from_bytes([7; 32]),
+            parents: vec![],
+            timestamp: now - 86_400,
+            nonce: 0,
+            from: founder.clone(),
+            actions: vec![],
+            pow_nonce: 0,
+            signatures: vec![],
+            auth_scheme: AuthScheme::SingleSig,
+            tx_version: 1,
+            client_ref: None,
+            fee_chronos: 0,
+            expires_at: None,
+            sender_public_key: None,
+        };
+        db.put_vertex(&Vertex::new(tx, 1, 0)).unwrap();
+        db.put_oracle_snapshot(&OracleSnapshot {
+            pair: "KX/USD".into(),
+            price_cents: 250,
+            num_submissions: 3,
+            updated_at: now,
+        })
+        .unwrap();
+        db.put_provider(&ProviderRecord {
+            provider_id: founder.clone(),
+            public_keys: vec![],
+            provider_class: "kyc".into(),
+            jurisdictions: vec!["US".into()],
+            status: ProviderStatus::Active,
+            registration_bond: 0,
+            registered_at: now,
+        })
+        .unwrap();
+        db.put_schema(&CertificateSchema {
+            schema_id: 1,
+            name: "ComplianceCertificate".into(),
+            version: 1,
+            required_fields_hash: [0; 32],
+            provider_class_thresholds: vec![],
+            signature_rules: SignatureRules { min_providers: 1, max_cert_age_secs: 3600 },
+            active: true,
+            registered_by: founder.clone(),
+            registered_at: now,
+        })
+        .unwrap();
+        let mut target = db.get_account(&keys[9].account_id).unwrap().unwrap();
+        target.recovery_state.active = true;
+        db.put_account(&target).unwrap();
+        let server = test_server(db);
+
+        let summary = server.get_explorer_summary().await.unwrap();
+        assert!(summary.chain_stats.total_accounts >= 10);
+        assert_eq!(summary.recent_transactions.len(), 1);
+        assert_eq!(summary.recent_transactions[0].from, founder.to_b58());
+        // Treasury and node rewards (tied), then the humanity stake.
+        let holders: Vec<_> = summary.top_lock_holders.iter().map(|h| h.account_id.clone()).collect();
+        assert_eq!(holders.len(), 5);
+        assert!(holders[..2].contains(&keys[1].account_id.to_b58()));
+        assert!(holders[..2].contains(&keys[3].account_id.to_b58()));
+        assert_eq!(summary.top_lock_holders[0].pending_incoming_kx, TREASURY_KX.to_string());
+        assert_eq!(holders[2], keys[2].account_id.to_b58());
+        assert_eq!(summary.top_lock_holders[2].pending_incoming_kx, HUMANITY_STAKE_KX.to_string());
+        assert_eq!(summary.oracle_snapshots.len(), 1);
+        assert_eq!(summary.active_recoveries, 1);
+        assert_eq!(summary.providers_active, 1);
+        assert_eq!(summary.schemas_active, 1);
+        let counts: Vec<u64> = summary.daily_transaction_counts_7d.iter().map(|d| d.count).collect();
+        assert_eq!(counts, vec![0, 0, 0, 0, 0, 1, 0]);
+        let today = chrono::Utc::now().format("%Y-%m-%d").to_string();
+        assert_eq!(summary.daily_transaction_counts_7d[6].date, today);
+        assert_ne!(summary.total_locked_kx, "0");
+
+        // Served from the cache until it expires.
+        server.state.db.put_oracle_snapshot(&OracleSnapshot {
+            pair: "KX/EUR".into(),
+            price_cents: 230,
+            num_submissions: 3,
+            updated_at: now,
+        })
+        .unwrap();
+        assert_eq!(server.get_explorer_summary().await.unwrap().oracle_snapshots.len(), 1);
+        server.explorer_cache.lock().unwrap().as_mut().unwrap().0 -= EXPLORER_CACHE_SECS;
+        assert_eq!(server.get_explorer_summary().await.unwrap().oracle_snapshots.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn timelocks_for_tags_and_or_and_account() {
+        let db = temp_db("locks_for_tags");
+        let other = AccountId::from_bytes([9u8; 32]);
+        // (id byte, tags, created_at, sender is `other`, private)
+        let specs: [(u8, &[&str], i64, bool, bool); 5] = [
+            (1, &["rent", "Monthly"], 10, false, false),
+            (2, &["rent"], 20, true, false),
+            (3, &["gift", "monthly"], 30, false, false),
+            (4, &["rent", "monthly"], 40, true, true),
+            (5, &["bonus"], 50, false, false),
+        ];
+        for (b, tags, created_at, by_other, private) in specs {
+            let id = TxId::from_bytes([b; 32]);
+            seed_lock(&db, id.clone(), 0);
+            let mut tlc = db.get_timelock(&id).unwrap().unwrap();
+            tlc.tags = Some(tags.iter().map(|t| t.to_string()).collect());
+            tlc.created_at = created_at;
+            tlc.private = private;
+            if by_other {
+                tlc.sender = other.clone();
+            }
+            db.put_timelock(&tlc).unwrap();
+        }
+        let server = test_server(db);
+        let query = |tags: &[&str], require_all: bool, account: Option<&AccountId>| {
+            let tags = tags.iter().map(|t| t.to_string()).collect();
+            let account = account.map(|a| a.to_b58());
+            let server = &server;
+            async move {
+                server
+                    .get_timelock_contracts_for_tags(tags, require_all, account, 100)
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|l| l.lock_id)
+                    .collect::<Vec<_>>()
+            }
+        };
+        let hex = |b: u8| TxId::from_bytes([b; 32]).to_hex();
+
+        // AND: both tags, case-insensitive; the private lock is left out.
+        assert_eq!(query(&["rent", "MONTHLY"], true, None).await, vec![hex(1)]);
+        // OR: either tag, deduplicated, newest first.
+        assert_eq!(query(&["rent", "monthly"], false, None).await, vec![hex(3), hex(2), hex(1)]);
+        // Mixed: a tag nobody uses empties AND but not OR.
+        assert!(query(&["rent", "nope"], true, None).await.is_empty());
+        assert_eq!(query(&["bonus", "nope"], false, None).await, vec![hex(5)]);
+        // Account filter on top of OR: `other` only sent lock 2 (and private 4).
+        assert_eq!(query(&["rent", "monthly"], false, Some(&other)).await, vec![hex(2)]);
+        let recipient = AccountId::from_bytes([2u8; 32]);
+        assert_eq!(query(&["rent"], true, Some(&recipient)).await, vec![hex(2), hex(1)]);
+
+        assert!(server.get_timelock_contracts_for_tags(vec![], true, None, 10).await.is_err());
+    }
+
+    #[test]
+    fn calendar_period_bounds_follow_local_time() {
+        // Monday 2024-01-01 00:00 UTC.
+        const MON: i64 = 1_704_067_200;
+        const DAY: i64 = 86_400;
+        let wed_2330 = MON + 2 * DAY + 23 * 3600 + 1800;
+        assert_eq!(CalendarPeriod::Day.bounds(wed_2330, 0), (MON + 2 * DAY, MON + 3 * DAY));
+        // Already Thursday in UTC+2, still Wednesday afternoon in UTC-5.
+        assert_eq!(CalendarPeriod::Day.bounds(wed_2330, 2), (MON + 3 * DAY - 7200, MON + 4 * DAY - 7200));
+        assert_eq!(CalendarPeriod::Day.bounds(wed_2330, -5), (MON + 2 * DAY + 18_000, MON + 3 * DAY + 18_000));
+        assert_eq!(CalendarPeriod::Week.bounds(wed_2330, 0), (MON, MON + 7 * DAY));
+        assert_eq!(CalendarPeriod::Month.bounds(wed_2330, 0), (MON, MON + 31 * DAY));
+    }
+
+    #[tokio::test]
+    async fn locks_expiring_today_uses_pending_unlock_index() {
+        let db = temp_db("locks_expiring_today");
+        let (start, end) = CalendarPeriod::Day.bounds(chrono::Utc::now().timestamp(), 0);
+        seed_lock(&db, TxId::from_bytes([1; 32]), end - 1);
+        seed_lock(&db, TxId::from_bytes([2; 32]), start);
+        seed_lock(&db, TxId::from_bytes([3; 32]), end);
+        seed_lock(&db, TxId::from_bytes([4; 32]), start - 1);
+        seed_lock(&db, TxId::from_bytes([5; 32]), start + 60);
+        let mut claimed = db.get_timelock(&TxId::from_bytes([5; 32])).unwrap().unwrap();
+        claimed.status = TimeLockStatus::Claimed { claimed_at: start + 60 };
+        db.put_timelock(&claimed).unwrap();
+        let server = test_server(db);
+
+        let ids: Vec<_> = server
+            .get_locks_expiring_today(0)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|l| l.lock_id)
+            .collect();
+        assert_eq!(ids, vec![TxId::from_bytes([2; 32]).to_hex(), TxId::from_bytes([1; 32]).to_hex()]);
+        assert!(server.get_locks_expiring_this_month(0).await.unwrap().len() >= 2);
+        assert!(server.get_locks_expiring_today(15).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn account_lock_timeline_groups_pending_locks_by_day() {
+        let db = temp_db("account_lock_timeline");
+        const DAY: i64 = 86_400;
+        let jan1 = 1_893_456_000; // 2030-01-01T00:00:00Z
+        let account = AccountId::from_bytes([1u8; 32]);
+        let other = AccountId::from_bytes([9u8; 32]);
+        let seed = |id: u8, unlock_at: i64, amount: u128, sender: &AccountId, recipient: &AccountId| {
+            seed_lock(&db, TxId::from_bytes([id; 32]), unlock_at);
+            let mut tlc = db.get_timelock(&TxId::from_bytes([id; 32])).unwrap().unwrap();
+            tlc.amount = amount;
+            tlc.sender = sender.clone();
+            tlc.recipient_account_id = recipient.clone();
+            db.put_timelock(&tlc).unwrap();
+            tlc
+        };
+        let recipient = AccountId::from_bytes([2u8; 32]);
+        seed(1, jan1 + 7_200, 2_500_000, &account, &recipient);
+        seed(2, jan1 + 3_600, CHRONOS_PER_KX, &account, &recipient);
+        seed(3, jan1 + DAY, 3 * CHRONOS_PER_KX, &other, &account);
+        seed(4, jan1 + DAY, CHRONOS_PER_KX, &other, &recipient);
+        let mut claimed = seed(5, jan1 + 2 * DAY, CHRONOS_PER_KX, &other, &account);
+        claimed.status = TimeLockStatus::Claimed { claimed_at: jan1 };
+        db.put_timelock(&claimed).unwrap();
+        let server = test_server(db);
+        let timeline = |from: Option<i64>, to: Option<i64>, role: &str| {
+            server.get_account_lock_timeline(account.to_b58(), from, to, role.to_string())
+        };
+
+        let days = timeline(None, None, "both").await.unwrap();
+        assert_eq!(days.len(), 2);
+        assert_eq!(days[0].unlock_date_iso, "2030-01-01");
+        assert_eq!(days[0].total_amount_kx, "3.5");
+        let ids: Vec<_> = days[0].locks.iter().map(|l| l.lock_id.clone()).collect();
+        assert_eq!(ids, vec![TxId::from_bytes([2; 32]).to_hex(), TxId::from_bytes([1; 32]).to_hex()]);
+        assert_eq!(days[0].locks[1].amount_kx, "2.5");
+        assert_eq!(days[0].locks[0].counterparty, recipient.to_b58());
+        assert_eq!(days[1].unlock_date_iso, "2030-01-02");
+        assert_eq!(days[1].locks.len(), 1, "unrelated lock on the same day is left out");
+        assert_eq!(days[1].locks[0].counterparty, other.to_b58());
+        assert_eq!(days[1].total_amount_kx, "3");
+
+        let dates = |days: Vec<RpcLockTimelineDay>| days.into_iter().map(|d| d.unlock_date_iso).collect::<Vec<_>>();
+        assert_eq!(dates(timeline(None, None, "sender").await.unwrap()), vec!["2030-01-01"]);
+        assert_eq!(dates(timeline(None, None, "recipient").await.unwrap()), vec!["2030-01-02"]);
+        assert_eq!(dates(timeline(Some(jan1 + DAY), None, "both").await.unwrap()), vec!["2030-01-02"]);
+        assert_eq!(dates(timeline(None, Some(jan1 + DAY), "both").await.unwrap()), vec!["2030-01-01"]);
+        assert!(timeline(None, None, "owner").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn unclaimed_lock_report_lists_long_matured_locks_by_amount() {
+        use chronx_core::account::UnclaimedAction;
+
+        let db = temp_db("unclaimed_lock_report");
+        let now = chrono::Utc::now().timestamp();
+        let day = 86_400;
+        let seed = |id: u8, unlock_at: i64, kx: u128| {
+            seed_lock(&db, TxId::from_bytes([id; 32]), unlock_at);
+            let mut tlc = db.get_timelock(&TxId::from_bytes([id; 32])).unwrap().unwrap();
+            tlc.amount = kx * CHRONOS_PER_KX;
+            tlc
+        };
+        db.put_timelock(&seed(1, now - 10 * day, 1)).unwrap();
+        let mut flagged = seed(2, now - 20 * day, 5);
+        flagged.unclaimed_action = Some(UnclaimedAction::RevertToSender);
+        flagged.claim_window_secs = Some(u64::MAX / 2);
+        db.put_timelock(&flagged).unwrap();
+        db.put_timelock(&seed(3, now - 3600, 9)).unwrap();
+        let mut lapsed = seed(4, now - 30 * day, 2);
+        lapsed.claim_window_secs = Some(1);
+        db.put_timelock(&lapsed).unwrap();
+        let mut claimed = seed(5, now - 40 * day, 7);
+        claimed.status = TimeLockStatus::Claimed { claimed_at: now };
+        db.put_timelock(&claimed).unwrap();
+        let mut server = test_server(db);
+
+        let report = server.get_unclaimed_lock_report(day, false).await.unwrap();
+        let ids: Vec<_> = report.iter().map(|l| l.lock_id.clone()).collect();
+        assert_eq!(ids, vec![TxId::from_bytes([2; 32]).to_hex(), TxId::from_bytes([1; 32]).to_hex()]);
+        assert_eq!(report[0].amount_kx, "5");
+        assert_eq!(report[0].unclaimed_action.as_deref(), Some("RevertToSender"));
+        assert_eq!(report[0].recipient, AccountId::from_bytes([2u8; 32]).to_b58());
+        assert!((report[0].matured_days_ago - 20.0).abs() < 0.01);
+        assert!(report[1].unclaimed_action.is_none() && !report[1].has_claim_policy);
+
+        let amounts: Vec<_> = server
+            .get_unclaimed_lock_report(day, true)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|l| l.amount_kx)
+            .collect();
+        assert_eq!(amounts, vec!["5", "2", "1"]);
+        assert!(server.get_unclaimed_lock_report(-1, false).await.is_err());
+
+        Arc::get_mut(&mut server.state).unwrap().sweep_reports_enabled = false;
+        assert!(server.get_unclaimed_lock_report(day, false).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn search_resolves_each_kind_of_id() {
+        use chronx_core::transaction::AuthScheme;
+        use chronx_dag::vertex::Vertex;
+
+        let db = temp_db("search");
+        let keys = apply_test_genesis(&db);
+        let tx = Transaction {
+            tx_id: TxId::from_bytes([7; 32]),
+            parents: vec![],
+            timestamp: 1_000,
+            nonce: 0,
+            from: keys[0].account_id.clone(),
+            actions: vec![],
+            pow_nonce: 0,
+            signatures: vec![],
+            auth_scheme: AuthScheme::SingleSig,
+            tx_version: 1,
+            client_ref: None,
+            fee_chronos: 0,
+            expires_at: None,
+            sender_public_key: None,
+        };
+        db.put_vertex(&Vertex::new(tx, 1, 0)).unwrap();
+        let lock_id = TxId::from_bytes([8; 32]);
+        seed_lock(&db, lock_id.clone(), 2_000);
+        let mut lock = db.get_timelock(&lock_id).unwrap().unwrap();
+        lock.client_ref = Some([0xab; 16]);
+        db.put_timelock(&lock).unwrap();
+        let server = test_server(db);
+
+        let result = server.search(TxId::from_bytes([7; 32]).to_hex()).await.unwrap();
+        assert_eq!(result.result_type, "transaction");
+        assert_eq!(result.data["tx_id"], TxId::from_bytes([7; 32]).to_hex());
+
+        let account = keys[0].account_id.to_b58();
+        let result = server.search(format!(" {account} ")).await.unwrap();
+        assert_eq!(result.result_type, "account");
+        assert_eq!(result.data["account_id"], account);
+
+        let result = server.search(lock_id.to_hex()).await.unwrap();
+        assert_eq!(result.result_type, "lock");
+        assert_eq!(result.data["lock_id"], lock_id.to_hex());
+
+        let result = server.search(hex::encode([0xab; 16])).await.unwrap();
+        assert_eq!(result.result_type, "lock");
+        assert_eq!(result.data["lock_id"], lock_id.to_hex());
+
+        for query in [TxId::from_bytes([9; 32]).to_hex(), hex::encode([0xcd; 16]), "nonsense".to_string()] {
+            let result = server.search(query).await.unwrap();
+            assert_eq!(result.result_type, "not_found");
+            assert!(result.data.is_null());
+        }
+    }
+
+    #[tokio::test]
+    async fn get_events_filters_by_time_and_type() {
+        use chronx_core::events::ChronxEvent;
+
+        let db = temp_db("get_events");
+        let (a, b) = (AccountId::from_bytes([1; 32]), AccountId::from_bytes([2; 32]));
+        let transfer = ChronxEvent::TransferApplied { from: a.clone(), to: b.clone(), amount: 5 };
+        db.put_events(100, &TxId::from_bytes([1; 32]), &[transfer.clone(), ChronxEvent::RecoveryStarted { target: b.clone(), bond: 7 }])
+            .unwrap();
+        db.put_events(200, &TxId::from_bytes([2; 32]), &[transfer]).unwrap();
+        db.put_events(300, &TxId::from_bytes([3; 32]), &[ChronxEvent::RecoveryFinalized { target: b.clone() }])
+            .unwrap();
+        let server = test_server(db);
+
+        let all = server.get_events(0, 10, None).await.unwrap();
+        let types: Vec<_> = all.iter().map(|e| e.event_type.as_str()).collect();
+        assert_eq!(types, vec!["TransferApplied", "RecoveryStarted", "TransferApplied", "RecoveryFinalized"]);
+        assert_eq!(all[0].data["from"], a.to_b58());
+        assert_eq!(all[0].data["amount_chronos"], "5");
+        assert_eq!(all[3].tx_id, TxId::from_bytes([3; 32]).to_hex());
+
+        let transfers = server.get_events(150, 10, Some("TransferApplied".into())).await.unwrap();
+        assert_eq!(transfers.len(), 1);
+        assert_eq!(transfers[0].timestamp, 200);
+
+        assert_eq!(server.get_events(0, 2, None).await.unwrap().len(), 2);
+        let err = server.get_events(0, 10, Some("Bogus".into())).await.unwrap_err();
+        assert_eq!(err.code(), -32602);
+    }
+
+    #[tokio::test]
+    async fn active_alerts_reflect_metrics_task() {
+        use chronx_monitoring::AlertSeverity;
+
+        let server = test_server(temp_db("active_alerts"));
+        assert!(server.get_active_alerts().await.unwrap().is_empty());
+
+        server.state.active_alerts.write().unwrap().push(Alert {
+            name: "SupplyMismatch".into(),
+            severity: AlertSeverity::Critical,
+            message: "recomputed supply does not match genesis".into(),
+            since: 1_700_000_000,
+        });
+        let alerts = server.get_active_alerts().await.unwrap();
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].name, "SupplyMismatch");
+        assert_eq!(alerts[0].severity, "Critical");
+        assert_eq!(alerts[0].since, 1_700_000_000);
+    }
+
+    #[tokio::test]
+    async fn chain_timeline_buckets_vertices() {
+        use chronx_core::transaction::AuthScheme;
+        use chronx_core::types::TimeLockId;
+        use chronx_dag::vertex::Vertex;
+
+        let db = temp_db("chain_timeline");
+        // 100 vertices, ten per 60-second bucket starting at t = 6_000. Sender
+        // i % 3 sends a transfer; every fifth vertex also creates a lock.
+        for i in 0..100u8 {
+            let mut actions = vec![Action::Transfer {
+                to: AccountId::from_bytes([9u8; 32]),
+                amount: 1,
+                memo: None,
+                memo_encrypted: false,
+                memo_public: false,
+                pay_as_amount: None,
+            }];
+            if i % 5 == 0 {
+                actions.push(Action::CancelTimeLock { lock_id: TimeLockId(TxId::from_bytes([i; 32])) });
+            }
+            let tx = Transaction {
+                tx_id: TxId::from_bytes([i; 32]),
+                parents: vec![],
+                timestamp: 6_000 + (i as i64 / 10) * 60 + (i as i64 % 10),
+                nonce: i as u64,
+                from: AccountId::from_bytes([i % 3; 32]),
+                actions,
+                pow_nonce: 0,
+                signatures: vec![],
+                auth_scheme: AuthScheme::SingleSig,
+                tx_version: 1,
+                client_ref: None,
+                fee_chronos: i as u128 % 10,
+                expires_at: None,
+                sender_public_key: None,
+            };
+            db.put_vertex(&Vertex::new(tx, 1, 0)).unwrap();
+        }
+        let server = test_server(db);
+
+        let buckets = server.get_chain_timeline(60, 6_000, 6_600).await.unwrap();
+        assert_eq!(buckets.len(), 10);
+        for (i, b) in buckets.iter().enumerate() {
+            assert_eq!(b.bucket_start, 6_000 + i as i64 * 60);
+            assert_eq!(b.vertex_count, 10);
+            assert_eq!(b.unique_senders, 3);
+            // Fees 0..=9 per bucket.
+            assert_eq!(b.avg_fee_chronos, "4");
+            assert_eq!(b.action_type_counts["Transfer"], 10);
+            assert_eq!(b.action_type_counts["CancelTimeLock"], 2);
+        }
+
+        // A sub-range with a partial trailing bucket; empty buckets included.
+        let buckets = server.get_chain_timeline(120, 6_540, 6_900).await.unwrap();
+        let counts: Vec<u64> = buckets.iter().map(|b| b.vertex_count).collect();
+        assert_eq!(counts, vec![10, 0, 0]);
+        assert_eq!(buckets[1].avg_fee_chronos, "0");
+
+        // Served from the cache until it expires, even if vertices change.
+        let mut cache = server.state.db.get_timeline_cache().unwrap().unwrap();
+        assert_eq!((cache.bucket_secs, cache.from_ts, cache.to_ts), (120, 6_540, 6_900));
+        cache.buckets[0].vertex_count = 99;
+        server.state.db.put_timeline_cache(&cache).unwrap();
+        assert_eq!(server.get_chain_timeline(120, 6_540, 6_900).await.unwrap()[0].vertex_count, 99);
+        cache.computed_at -= TIMELINE_CACHE_SECS;
+        server.state.db.put_timeline_cache(&cache).unwrap();
+        assert_eq!(server.get_chain_timeline(120, 6_540, 6_900).await.unwrap()[0].vertex_count, 10);
+
+        for (secs, from, to) in [(0, 0, 10), (10, 10, 10), (1, 0, 1_001)] {
+            let err = server.get_chain_timeline(secs, from, to).await.unwrap_err();
+            assert_eq!(err.code(), -32602);
+        }
+        assert_eq!(server.get_chain_timeline(1, 0, 1_000).await.unwrap().len(), 1_000);
+    }
+
+    #[tokio::test]
+    async fn balance_history_reconstructs_past_balances() {
+        use chronx_core::account::{Account, AuthPolicy};
+        use chronx_core::transaction::AuthScheme;
+        use chronx_core::types::TimeLockId;
+        use chronx_crypto::{hash::tx_id_from_body, KeyPair};
+
+        let db = Arc::new(temp_db("balance_history"));
+        let (alice, bob) = (KeyPair::generate(), KeyPair::generate());
+        for (kp, balance) in [(&alice, 100 * CHRONOS_PER_KX), (&bob, 0)] {
+            let mut acc = Account::new(
+                kp.account_id.clone(),
+                AuthPolicy::SingleSig { public_key: kp.public_key.clone() },
+            );
+            acc.balance = balance;
+            db.put_account(&acc).unwrap();
+        }
+        let lock_id = TxId::from_bytes([43u8; 32]);
+        seed_lock(&db, lock_id.clone(), 1_000);
+        let mut lock = db.get_timelock(&lock_id).unwrap().unwrap();
+        lock.recipient_key = bob.public_key.clone();
+        lock.recipient_account_id = bob.account_id.clone();
+        db.put_timelock(&lock).unwrap();
+
+        let transfer = |to: &KeyPair, kx: u128| Action::Transfer {
+            to: to.account_id.clone(),
+            amount: kx * CHRONOS_PER_KX,
+            memo: None,
+            memo_encrypted: false,
+            memo_public: false,
+            pay_as_amount: None,
+        };
+        // One transaction per depth, each on top of the previous one.
+        let steps = vec![
+            (&alice, 0, transfer(&bob, 10)),
+            (&alice, 1, transfer(&bob, 5)),
+            (&bob, 0, transfer(&alice, 3)),
+            (&bob, 1, Action::TimeLockClaim { lock_id: TimeLockId(lock_id.clone()) }),
+            (&alice, 2, transfer(&bob, 1)),
+        ];
+        let engine = chronx_state::StateEngine::new(Arc::clone(&db), 0);
+        let mut parents = vec![];
+        let mut tx_ids = vec![];
+        for (kp, nonce, action) in steps {
+            let mut tx = Transaction {
+                tx_id: TxId::from_bytes([0u8; 32]),
+                parents: parents.clone(),
+                timestamp: 2_000,
+                nonce,
+                from: kp.account_id.clone(),
+                actions: vec![action],
+                pow_nonce: 0,
+                signatures: vec![],
+                auth_scheme: AuthScheme::SingleSig,
+                tx_version: 1,
+                client_ref: None,
+                fee_chronos: 0,
+                expires_at: None,
+                sender_public_key: Some(kp.public_key.clone()),
+            };
+            let body_bytes = tx.body_bytes();
+            tx.tx_id = tx_id_from_body(&body_bytes);
+            tx.signatures = vec![kp.sign(&body_bytes)];
+            engine.apply(&tx, 2_000).unwrap();
+            parents = vec![tx.tx_id.clone()];
+            tx_ids.push(tx.tx_id.to_hex());
+        }
+        drop(engine);
+        let server = test_server(Arc::try_unwrap(db).ok().unwrap());
+
+        let kx = |n: u128| (n * CHRONOS_PER_KX).to_string();
+        let history = server.get_account_balance_history(alice.account_id.to_b58(), 0, 4).await.unwrap();
+        let points: Vec<_> = history.iter().map(|p| (p.depth, p.balance_after.clone())).collect();
+        assert_eq!(points, vec![(0, kx(90)), (1, kx(85)), (2, kx(88)), (4, kx(87))]);
+        assert_eq!(history[3].tx_id, tx_ids[4]);
+
+        let history = server.get_account_balance_history(bob.account_id.to_b58(), 1, 3).await.unwrap();
+        let points: Vec<_> = history.iter().map(|p| (p.depth, p.balance_after.clone())).collect();
+        assert_eq!(points, vec![(1, kx(15)), (2, kx(12)), (3, kx(13))]);
+
+        let err = server.get_account_balance_history(bob.account_id.to_b58(), 3, 2).await.unwrap_err();
+        assert_eq!(err.code(), -32602);
+    }
+
+    #[tokio::test]
+    async fn validate_split_policy_reports_problems() {
+        use chronx_core::account::{Account, AuthPolicy, SplitPolicy};
+
+        let db = temp_db("validate_split");
+        let (known, unknown) = (AccountId::from_bytes([2u8; 32]), AccountId::from_bytes([7u8; 32]));
+        db.put_account(&Account::new(known.clone(), AuthPolicy::SingleSig { public_key: DilithiumPublicKey(vec![]) }))
+            .unwrap();
+        let lock_id = TxId::from_bytes([44u8; 32]);
+        seed_lock(&db, lock_id.clone(), 1_000);
+        let mut lock = db.get_timelock(&lock_id).unwrap().unwrap();
+        lock.split_policy = Some(SplitPolicy { recipients: vec![(known.clone(), 4_000), (unknown.clone(), 4_000)] });
+        db.put_timelock(&lock).unwrap();
+        let plain = TxId::from_bytes([45u8; 32]);
+        seed_lock(&db, plain.clone(), 1_000);
+        let server = test_server(db);
+
+        let report = server.validate_split_policy(lock_id.to_hex()).await.unwrap().unwrap();
+        assert!(!report.valid);
+        assert!(!report.validated_on_chain);
+        assert_eq!(report.basis_points_total, 8_000);
+        assert_eq!(report.missing_recipients, vec![unknown.to_b58()]);
+        assert!(report.duplicate_recipients.is_empty());
+
+        let mut lock = server.state.db.get_timelock(&lock_id).unwrap().unwrap();
+        lock.split_policy = Some(SplitPolicy { recipients: vec![(known.clone(), 5_000), (known.clone(), 5_000)] });
+        server.state.db.put_timelock(&lock).unwrap();
+        let report = server.validate_split_policy(lock_id.to_hex()).await.unwrap().unwrap();
+        assert!(!report.valid);
+        assert_eq!(report.duplicate_recipients, vec![known.to_b58()]);
+
+        lock.split_policy = Some(SplitPolicy { recipients: vec![(known, 10_000)] });
+        lock.split_policy_validated = true;
+        server.state.db.put_timelock(&lock).unwrap();
+        let report = server.validate_split_policy(lock_id.to_hex()).await.unwrap().unwrap();
+        assert!(report.valid && report.validated_on_chain);
+
+        assert!(server.validate_split_policy(TxId::from_bytes([46u8; 32]).to_hex()).await.unwrap().is_none());
+        assert_eq!(server.validate_split_policy(plain.to_hex()).await.unwrap_err().code(), -32602);
+    }
+
+    #[tokio::test]
+    async fn claim_state_history_lists_transitions_in_order() {
+        use chronx_state::db::ClaimHistoryRecord;
+
+        let db = temp_db("claim_history");
+        let lock_id = TxId::from_bytes([47u8; 32]);
+        let agent = AccountId::from_bytes([3u8; 32]);
+        for (i, (status, at, bond)) in [("ClaimOpen", 100, 0), ("ClaimCommitted", 100, 5_000)].into_iter().enumerate() {
+            db.put_claim_history(&ClaimHistoryRecord {
+                lock_id: lock_id.clone(),
+                status: status.into(),
+                at,
+                tx_id: TxId::from_bytes([i as u8; 32]),
+                action_index: 0,
+                actor: agent.clone(),
+                lane: Some(1),
+                bond_amount: bond,
+                certificate_count: 0,
+            })
+            .unwrap();
+        }
+        let server = test_server(db);
+
+        let history = server.get_claim_state_history(lock_id.to_hex()).await.unwrap();
+        let statuses: Vec<&str> = history.iter().map(|e| e.status.as_str()).collect();
+        assert_eq!(statuses, ["ClaimOpen", "ClaimCommitted"]);
+        assert_eq!(history[1].actor.as_deref(), Some(agent.to_b58().as_str()));
+        assert_eq!(history[1].details["bond_chronos"], "5000");
+        assert_eq!(history[1].details["lane"], 1);
+
+        assert!(server.get_claim_state_history(TxId::from_bytes([48u8; 32]).to_hex()).await.unwrap().is_empty());
+        assert_eq!(server.get_claim_state_history("zz".into()).await.unwrap_err().code(), -32602);
+    }
+
+    #[tokio::test]
+    async fn difficulty_is_read_from_the_dag() {
+        use chronx_core::transaction::AuthScheme;
+        use chronx_dag::vertex::Vertex;
+
+        let mut server = test_server(temp_db("difficulty"));
+        Arc::get_mut(&mut server.state).unwrap().pow_difficulty = 20;
+        let none = server.get_difficulty().await.unwrap();
+        assert_eq!((none.current_difficulty, none.last_adjustment_direction.as_str()), (20, "none"));
+        assert!(server.get_difficulty_history(10).await.unwrap().is_empty());
+
+        // Two adjustments, recorded the way the engine's commit does.
+        let db = &server.state.db;
+        db.push_difficulty_adjustment(100, chronx_core::constants::POW_MAX_DIFFICULTY, 3).unwrap();
+        db.push_difficulty_adjustment(200, chronx_core::constants::POW_MIN_DIFFICULTY, 243).unwrap();
+
+        // A chain of three vertices 4 s apart; the tip carries difficulty 17.
+        let mut parents = vec![];
+        for (i, secs) in [240i64, 244, 248].into_iter().enumerate() {
+            let tx = Transaction {
+                tx_id: TxId::from_bytes([60 + i as u8; 32]),
+                parents: parents.clone(),
+                timestamp: secs,
+                nonce: 0,
+                from: AccountId::from_bytes([1; 32]),
+                actions: vec![],
+                pow_nonce: 0,
+                signatures: vec![],
+                auth_scheme: AuthScheme::SingleSig,
+                tx_version: 1,
+                client_ref: None,
+                fee_chronos: 0,
+                expires_at: None,
+                sender_public_key: None,
+            };
+            let mut vertex = Vertex::new(tx.clone(), 200 + i as u64, secs);
+            vertex.difficulty = Some(17);
+            db.put_vertex(&vertex).unwrap();
+            for parent in &parents {
+                db.remove_tip(parent).unwrap();
+            }
+            db.add_tip(&tx.tx_id).unwrap();
+            parents = vec![tx.tx_id];
+        }
+
+        let history = server.get_difficulty_history(10).await.unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!((history[0].depth, history[0].at), (100, 3));
+        assert_eq!(server.get_difficulty_history(1).await.unwrap()[0].depth, 200);
+
+        let difficulty = server.get_difficulty().await.unwrap();
+        assert_eq!(difficulty.current_difficulty, 17);
+        assert_eq!(difficulty.last_adjustment_depth, 200);
+        assert_eq!(difficulty.last_adjustment_direction, "decreased");
+        assert_eq!(difficulty.current_ema_ms, 4_000.0);
+        assert_eq!(difficulty.target_interval_ms, 10_000);
+    }
+
+    #[tokio::test]
+    async fn estimate_fee_scales_with_action_size_and_rate() {
+        let server = test_server(temp_db("estimate_fee"));
+        let transfer = serde_json::to_value(Action::Transfer {
+            to: AccountId::from_bytes([7u8; 32]),
+            amount: CHRONOS_PER_KX,
+            memo: Some("rent".into()),
+            memo_encrypted: false,
+            memo_public: false,
+            pay_as_amount: None,
+        })
+        .unwrap();
+
+        let free = server.estimate_fee(vec![transfer.clone()]).await.unwrap();
+        assert_eq!((free.base_fee_chronos.as_str(), free.suggested_fee_chronos.as_str()), ("0", "0"));
+
+        server.state.db.set_fee_rate_per_byte(3).unwrap();
+        let one = server.estimate_fee(vec![transfer.clone()]).await.unwrap();
+        let two = server.estimate_fee(vec![transfer.clone(), transfer]).await.unwrap();
+        let one_fee: u128 = one.base_fee_chronos.parse().unwrap();
+        assert!(one_fee > 0 && one_fee.is_multiple_of(3));
+        assert_eq!(two.base_fee_chronos, (2 * one_fee).to_string());
+        assert_eq!(one.suggested_fee_chronos, one.base_fee_chronos);
+
+        assert!(server.estimate_fee(vec![serde_json::json!({"NoSuchAction": {}})]).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn chain_stats_report_the_fee_pool() {
+        use chronx_core::account::{Account, AuthPolicy};
+
+        let server = test_server(temp_db("fee_pool"));
+        assert_eq!(server.get_chain_stats().await.unwrap().fee_pool_chronos, "0");
+
+        let collector = AccountId::from_bytes([5u8; 32]);
+        let mut account = Account::new(collector.clone(), AuthPolicy::SingleSig { public_key: DilithiumPublicKey(vec![]) });
+        account.balance = 1_234;
+        server.state.db.put_account(&account).unwrap();
+        server.state.db.set_fee_collector(&collector).unwrap();
+        assert_eq!(server.get_chain_stats().await.unwrap().fee_pool_chronos, "1234");
+    }
+
+    #[tokio::test]
+    async fn simulate_transaction_reports_errors_and_deltas() {
+        use chronx_core::account::{Account, AuthPolicy};
+        use chronx_core::transaction::AuthScheme;
+        use chronx_crypto::{hash::tx_id_from_body, KeyPair};
+
+        let server = test_server(temp_db("simulate_tx"));
+        let kp = KeyPair::generate();
+        let mut account = Account::new(
+            kp.account_id.clone(),
+            AuthPolicy::SingleSig { public_key: kp.public_key.clone() },
+        );
+        account.balance = CHRONOS_PER_KX;
+        server.state.db.put_account(&account).unwrap();
+
+        let signed_transfer = |amount: u128| {
+            let mut tx = Transaction {
+                tx_id: TxId::from_bytes([0u8; 32]),
+                parents: vec![],
+                timestamp: 2_000,
+                nonce: 0,
+                from: kp.account_id.clone(),
+                actions: vec![Action::Transfer {
+                    to: AccountId::from_bytes([7u8; 32]),
+                    amount,
+                    memo: None,
+                    memo_encrypted: false,
+                    memo_public: false,
+                    pay_as_amount: None,
+                }],
+                pow_nonce: 0,
+                signatures: vec![],
+                auth_scheme: AuthScheme::SingleSig,
+                tx_version: 1,
+                client_ref: None,
+                fee_chronos: 0,
+                expires_at: None,
+                sender_public_key: None,
+            };
+            let body_bytes = tx.body_bytes();
+            tx.tx_id = tx_id_from_body(&body_bytes);
+            tx.signatures = vec![kp.sign(&body_bytes)];
+            hex::encode(bincode::serialize(&tx).unwrap())
+        };
+
+        let rejected = server.simulate_transaction(signed_transfer(5 * CHRONOS_PER_KX)).await.unwrap();
+        assert!(!rejected.ok);
+        assert_eq!(rejected.error.as_deref(), Some("InsufficientBalance"));
+        assert!(rejected.error_message.unwrap().contains("insufficient balance"));
+        assert!(rejected.balance_deltas.is_empty());
+
+        let accepted = server.simulate_transaction(signed_transfer(CHRONOS_PER_KX / 2)).await.unwrap();
+        assert!(accepted.ok && accepted.error.is_none());
+        let sender_delta = accepted.balance_deltas.iter().find(|d| d.account_id == kp.account_id.to_string());
+        assert_eq!(sender_delta.unwrap().delta_chronos, format!("-{}", CHRONOS_PER_KX / 2));
+        // Nothing was committed.
+        assert_eq!(server.state.db.get_account(&kp.account_id).unwrap().unwrap().balance, CHRONOS_PER_KX);
+        assert_eq!(server.state.db.count_vertices(), 0);
+
+        assert!(server.simulate_transaction("zz".into()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn submit_transaction_waits_for_the_outcome() {
+        use chronx_core::transaction::AuthScheme;
+
+        let tx = |nonce: u64| {
+            let mut tx = Transaction {
+                tx_id: TxId::from_bytes([nonce as u8 + 1; 32]),
+                parents: vec![],
+                timestamp: 2_000,
+                nonce,
+                from: AccountId::from_bytes([1u8; 32]),
+                actions: vec![],
+                pow_nonce: 0,
+                signatures: vec![],
+                auth_scheme: AuthScheme::SingleSig,
+                tx_version: 1,
+                client_ref: None,
+                fee_chronos: 0,
+                expires_at: None,
+                sender_public_key: None,
+            };
+            tx.signatures = vec![DilithiumSignature(vec![])];
+            hex::encode(bincode::serialize(&tx).unwrap())
+        };
+        let mempool = Arc::new(std::sync::Mutex::new(Mempool::new(10)));
+
+        // Stand in for the node's apply loop: reject nonce 0, never answer
+        // for anything else.
+        let (tx_sender, mut tx_receiver) = tokio::sync::mpsc::channel::<SubmittedTx>(8);
+        let pool = Arc::clone(&mempool);
+        tokio::spawn(async move {
+            let mut held = Vec::new();
+            while let Some(SubmittedTx { tx, outcome }) = tx_receiver.recv().await {
+                if tx.nonce == 0 {
+                    let reason = ChronxError::InvalidNonce { expected: 1, got: 0 };
+                    let reason = format!("{}: {reason}", reason.variant_name());
+                    pool.lock().unwrap().record_rejected(&tx, reason.clone(), 2_000);
+                    let _ = outcome.unwrap().send(Err(reason));
+                } else {
+                    held.push(outcome);
+                }
+            }
+        });
+        let server = test_server(temp_db("send_tx_outcome"));
+        let mut state = Arc::try_unwrap(server.state).ok().unwrap();
+        state.tx_sender = Some(tx_sender);
+        state.submit_timeout = std::time::Duration::from_millis(100);
+        state.mempool = Some(mempool);
+        let server = RpcServer::new(Arc::new(state));
+
+        for err in [server.submit_transaction(tx(0)).await.unwrap_err(), server.send_transaction(tx(0)).await.unwrap_err()] {
+            assert_eq!(err.code(), -32010);
+            assert!(err.message().starts_with("InvalidNonce: invalid nonce"), "{}", err.message());
+        }
+        let pending = server.submit_transaction(tx(5)).await.unwrap();
+        assert_eq!(pending.status, "pending");
+        // sendTransaction keeps answering with the bare TxId hex.
+        assert_eq!(server.send_transaction(tx(6)).await.unwrap(), TxId::from_bytes([7u8; 32]).to_hex());
+
+        let rejected = server.get_transaction_status(TxId::from_bytes([1u8; 32]).to_hex()).await.unwrap();
+        assert_eq!(rejected.status, "rejected");
+        assert!(rejected.reason.unwrap().starts_with("InvalidNonce"));
+        assert_eq!(rejected.rejected_at, Some(2_000));
+        let unknown = server.get_transaction_status(pending.tx_id).await.unwrap();
+        assert_eq!((unknown.status.as_str(), unknown.reason), ("unknown", None));
+        assert_eq!(server.get_transaction_status("zz".into()).await.unwrap_err().code(), -32602);
+    }
+
+    #[tokio::test]
+    async fn governance_queries_split_active_from_finalized() {
+        use chronx_core::types::GovernanceTarget;
+
+        let db = temp_db("governance");
+        let proposal = |n: u8, status, finalized_at| GovernanceProposal {
+            proposal_id: TxId::from_bytes([n; 32]),
+            proposer: AccountId::from_bytes([9u8; 32]),
+            title: format!("proposal {n}"),
+            body_hash: [n; 32],
+            target_parameter: GovernanceTarget::PoWDifficulty,
+            proposed_value_bytes: vec![n],
+            bond_amount: 10,
+            submitted_at: n as i64,
+            voting_period_secs: 100 - n as i64,
+            voting_ends_at: 100,
+            votes_for: 7,
+            votes_against: 3,
+            voters: vec![AccountId::from_bytes([8u8; 32])],
+            status,
+            finalized_at,
+        };
+        db.put_governance_proposal(&proposal(1, GovernanceProposalStatus::Approved, Some(100))).unwrap();
+        db.put_governance_proposal(&proposal(2, GovernanceProposalStatus::Active, None)).unwrap();
+        db.put_governance_proposal(&proposal(3, GovernanceProposalStatus::Rejected, Some(200))).unwrap();
+        let server = test_server(db);
+
+        let active = server.get_active_proposals().await.unwrap();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].proposal_id, TxId::from_bytes([2u8; 32]).to_hex());
+        assert_eq!(active[0].target_parameter, "PoWDifficulty");
+        assert_eq!(active[0].proposed_value_hex, "02");
+        assert_eq!((active[0].votes_for_chronos.as_str(), active[0].voter_count), ("7", 1));
+
+        // Most recently finalized first.
+        let history = server.get_governance_history().await.unwrap();
+        let statuses: Vec<_> = history.iter().map(|p| (p.status.as_str(), p.finalized_at)).collect();
+        assert_eq!(statuses, vec![("Rejected", Some(200)), ("Approved", Some(100))]);
+    }
+
+    #[tokio::test]
+    async fn recurring_schedule_follows_a_series_to_exhaustion() {
+        use chronx_core::account::RecurringPolicy;
+        use chronx_core::transaction::recurring_child_lock_id;
+        use chronx_state::db::RecurringSchedule;
+
+        let db = temp_db("recurring_schedule");
+        let policy = RecurringPolicy::Monthly { count: 3 };
+        let put = |db: &StateDb, id: &TxId, series: &TxId, status: TimeLockStatus| {
+            seed_lock(db, id.clone(), 100);
+            let mut lock = db.get_timelock(id).unwrap().unwrap();
+            lock.recurring = Some(policy.clone());
+            lock.recurring_series_id = Some(series.clone());
+            lock.status = status;
+            db.put_timelock(&lock).unwrap();
+        };
+        let schedule = |series: &TxId| RecurringSchedule {
+            series_id: series.clone(),
+            sender: AccountId::from_bytes([1u8; 32]),
+            recipient_account_id: AccountId::from_bytes([2u8; 32]),
+            amount: CHRONOS_PER_KX,
+            policy: policy.clone(),
+            first_unlock_at: 100,
+            created_at: 0,
+        };
+        let claimed = TimeLockStatus::Claimed { claimed_at: 100 };
+
+        let first = TxId::from_bytes([20u8; 32]);
+        let second = recurring_child_lock_id(&first, 1);
+        let third = recurring_child_lock_id(&second, 2);
+        db.put_recurring_schedule(&schedule(&first)).unwrap();
+        put(&db, &first, &first, claimed.clone());
+        put(&db, &second, &first, TimeLockStatus::Pending);
+        seed_lock(&db, TxId::from_bytes([21u8; 32]), 100);
+        let server = test_server(db);
+
+        // Any installment resolves to the whole series.
+        let s = server.get_recurring_schedule(second.to_hex()).await.unwrap().unwrap();
+        assert_eq!(s.series_id, first.to_hex());
+        assert_eq!((s.policy.as_str(), s.total_installments, s.installments_claimed), ("Monthly", 3, 1));
+        assert_eq!(s.status, "Active");
+        assert_eq!(s.remaining_chronos, (2 * CHRONOS_PER_KX).to_string());
+        let ids: Vec<_> = s.installments.iter().map(|i| (i.index, i.lock_id.clone())).collect();
+        assert_eq!(ids, vec![(1, first.to_hex()), (2, second.to_hex())]);
+
+        let db = &server.state.db;
+        put(db, &second, &first, claimed.clone());
+        put(db, &third, &first, claimed.clone());
+        let s = server.get_recurring_schedule(first.to_hex()).await.unwrap().unwrap();
+        assert_eq!((s.status.as_str(), s.installments_claimed), ("Exhausted", 3));
+        assert_eq!(s.remaining_chronos, "0");
+        assert_eq!(s.installments[2].status, "Claimed");
+
+        // Cancelling the first lock ends the series early.
+        let cancelled = TxId::from_bytes([22u8; 32]);
+        db.put_recurring_schedule(&schedule(&cancelled)).unwrap();
+        put(db, &cancelled, &cancelled, TimeLockStatus::Cancelled { cancelled_at: 50 });
+        let s = server.get_recurring_schedule(cancelled.to_hex()).await.unwrap().unwrap();
+        assert_eq!((s.status.as_str(), s.remaining_chronos.as_str()), ("Cancelled", "0"));
+
+        assert!(server.get_recurring_schedule(TxId::from_bytes([21u8; 32]).to_hex()).await.unwrap().is_none());
+        let missing = server.get_recurring_schedule(TxId::from_bytes([23u8; 32]).to_hex()).await;
+        assert_eq!(missing.unwrap_err().code(), -32602);
+    }
+
+    #[tokio::test]
+    async fn recurring_schedule_reports_series_ended_by_the_engine() {
+        use chronx_core::account::{Account, AuthPolicy, RecurringPolicy, UnclaimedAction};
+        use chronx_core::transaction::{recurring_child_lock_id, AuthScheme, FeatureFlags};
+        use chronx_core::types::TimeLockId;
+        use chronx_crypto::{hash::tx_id_from_body, KeyPair};
+
+        let db = Arc::new(temp_db("recurring_schedule_engine"));
+        let (alice, bob) = (KeyPair::generate(), KeyPair::generate());
+        for (kp, balance) in [(&alice, 100 * CHRONOS_PER_KX), (&bob, 0)] {
+            let mut acc = Account::new(
+                kp.account_id.clone(),
+                AuthPolicy::SingleSig { public_key: kp.public_key.clone() },
+            );
+            acc.balance = balance;
+            db.put_account(&acc).unwrap();
+        }
+        db.put_feature_flags(&FeatureFlags { recurring_enabled: true, ..FeatureFlags::default() }).unwrap();
+        let engine = chronx_state::StateEngine::new(Arc::clone(&db), 0);
+        let apply = |kp: &KeyPair, nonce: u64, action: Action, now: i64| {
+            let mut tx = Transaction {
+                tx_id: TxId::from_bytes([0u8; 32]),
+                parents: vec![],
+                timestamp: now,
+                nonce,
+                from: kp.account_id.clone(),
+                actions: vec![action],
+                pow_nonce: 0,
+                signatures: vec![],
+                auth_scheme: AuthScheme::SingleSig,
+                tx_version: 1,
+                client_ref: None,
+                fee_chronos: 0,
+                expires_at: None,
+                sender_public_key: Some(kp.public_key.clone()),
+            };
+            let body_bytes = tx.body_bytes();
+            tx.tx_id = tx_id_from_body(&body_bytes);
+            tx.signatures = vec![kp.sign(&body_bytes)];
+            engine.apply(&tx, now).unwrap();
+            tx.tx_id
+        };
+        // Monthly{count: 3} of 2 KX each; an installment Bob leaves
+        // unclaimed for a day may be taken back by Alice.
+        let series = |unlock_at: i64| {
+            let mut action = chronx_tx_builder::timelock_action(bob.public_key.clone(), 2 * CHRONOS_PER_KX, unlock_at, None);
+            if let Action::TimeLockCreate { recurring, claim_window_secs, unclaimed_action, .. } = &mut action {
+                *recurring = Some(RecurringPolicy::Monthly { count: 3 });
+                *claim_window_secs = Some(86_400);
+                *unclaimed_action = Some(UnclaimedAction::RevertToSender);
+            }
+            action
+        };
+        let claim = |lock_id: &TxId| Action::ClaimAndScheduleNext { lock_id: TimeLockId(lock_id.clone()) };
+        let month = RecurringPolicy::Monthly { count: 3 }.interval_secs();
+        let balance = |kp: &KeyPair| db.get_account(&kp.account_id).unwrap().unwrap().balance;
+
+        // Bob claims the first installment; Alice takes back the second
+        // once its claim window closes, which refunds the third with it.
+        let first = apply(&alice, 0, series(2_000), 1_000);
+        apply(&bob, 0, claim(&first), 2_000);
+        let second = recurring_child_lock_id(&first, 1);
+        let reclaim = Action::ReclaimExpiredLock { lock_id: TimeLockId(second.clone()) };
+        apply(&alice, 1, reclaim, 2_000 + 86_401);
+        assert_eq!(balance(&alice), 98 * CHRONOS_PER_KX);
+
+        // A second series runs to its last installment.
+        let start = 3_000 + 86_401;
+        let exhausted = apply(&alice, 2, series(start), start - 1);
+        let mut lock = exhausted.clone();
+        for index in 1..=3u32 {
+            apply(&bob, index as u64, claim(&lock), start + month * (index as i64 - 1));
+            lock = recurring_child_lock_id(&lock, index);
+        }
+        assert_eq!(balance(&bob), 8 * CHRONOS_PER_KX);
+        drop(engine);
+        let server = test_server(Arc::try_unwrap(db).ok().unwrap());
+
+        let s = server.get_recurring_schedule(second.to_hex()).await.unwrap().unwrap();
+        assert_eq!((s.status.as_str(), s.installments_claimed), ("Cancelled", 1));
+        assert_eq!(s.remaining_chronos, "0");
+        let statuses: Vec<_> = s.installments.iter().map(|i| i.status.as_str()).collect();
+        assert_eq!(statuses, vec!["Claimed", "Reverted"]);
+
+        let s = server.get_recurring_schedule(exhausted.to_hex()).await.unwrap().unwrap();
+        assert_eq!((s.status.as_str(), s.installments_claimed), ("Exhausted", 3));
+        assert_eq!(s.remaining_chronos, "0");
+        // Nothing follows the last installment.
+        assert_eq!(server.get_recurring_schedule(lock.to_hex()).await.unwrap_err().code(), -32602);
+    }
+    #[tokio::test]
+    async fn expired_lock_reports_the_action_taken() {
+        use chronx_core::account::ExpiryPolicy;
+        let db = temp_db("expired_lock_action");
+        let (pending, expired) = (TxId::from_bytes([30u8; 32]), TxId::from_bytes([31u8; 32]));
+        seed_lock(&db, pending.clone(), 100);
+        seed_lock(&db, expired.clone(), 100);
+        let mut lock = db.get_timelock(&expired).unwrap().unwrap();
+        lock.status = TimeLockStatus::Expired { action_taken: ExpiryPolicy::Burn, expired_at: 500 };
+        lock.cancellation_reason = Some("expired unclaimed".into());
+        db.put_timelock(&lock).unwrap();
+        let server = test_server(db);
+
+        let rpc = server.get_lock_by_id(expired.to_hex()).await.unwrap().unwrap();
+        assert_eq!(rpc.status, "Expired");
+        assert_eq!(rpc.expiry_action.as_deref(), Some("Burn"));
+        let rpc = server.get_lock_by_id(pending.to_hex()).await.unwrap().unwrap();
+        assert_eq!(rpc.expiry_action, None);
+    }
+
+    #[tokio::test]
+    async fn split_lock_lists_its_recipients() {
+        use chronx_core::account::SplitPolicy;
+        let db = temp_db("split_lock_recipients");
+        let (plain, split) = (TxId::from_bytes([32u8; 32]), TxId::from_bytes([33u8; 32]));
+        let (a, b) = (AccountId::from_bytes([34u8; 32]), AccountId::from_bytes([35u8; 32]));
+        seed_lock(&db, plain.clone(), 100);
+        seed_lock(&db, split.clone(), 100);
+        let mut lock = db.get_timelock(&split).unwrap().unwrap();
+        lock.split_policy = Some(SplitPolicy { recipients: vec![(a.clone(), 7_000), (b.clone(), 3_000)] });
+        db.put_timelock(&lock).unwrap();
+        let server = test_server(db);
+
+        let rpc = server.get_lock_by_id(split.to_hex()).await.unwrap().unwrap();
+        let recipients: Vec<_> = rpc.split_recipients.unwrap().into_iter().map(|r| (r.account_id, r.basis_points)).collect();
+        assert_eq!(recipients, vec![(a.to_b58(), 7_000), (b.to_b58(), 3_000)]);
+        assert!(server.get_lock_by_id(plain.to_hex()).await.unwrap().unwrap().split_recipients.is_none());
+    }
+    #[tokio::test]
+    async fn post_recovery_restriction_reports_todays_allowance() {
+        use chronx_core::account::{Account, AuthPolicy, PostRecoveryRestriction};
+        let db = temp_db("post_recovery_restriction");
+        let now = chrono::Utc::now().timestamp();
+        let policy = AuthPolicy::SingleSig { public_key: DilithiumPublicKey(vec![1]) };
+        let mut acc = Account::new(AccountId::from_bytes([40u8; 32]), policy);
+        let restriction = PostRecoveryRestriction {
+            expires_at: now + 86_400,
+            daily_limit_chronos: 10 * CHRONOS_PER_KX,
+            transferred_today: 4 * CHRONOS_PER_KX,
+            last_transfer_day: (now / 86_400) as i32,
+        };
+        acc.post_recovery_restriction = Some(restriction.clone());
+        db.put_account(&acc).unwrap();
+        let server = test_server(db);
+        let id = acc.account_id.to_b58();
+
+        let r = server.get_post_recovery_restriction(id.clone()).await.unwrap().unwrap();
+        assert_eq!(r.transferred_today_chronos, (4 * CHRONOS_PER_KX).to_string());
+        assert_eq!(r.remaining_today_chronos, (6 * CHRONOS_PER_KX).to_string());
+
+        // Yesterday's spending no longer counts.
+        let yesterday = restriction.last_transfer_day - 1;
+        acc.post_recovery_restriction = Some(PostRecoveryRestriction { last_transfer_day: yesterday, ..restriction.clone() });
+        server.state.db.put_account(&acc).unwrap();
+        let r = server.get_post_recovery_restriction(id.clone()).await.unwrap().unwrap();
+        assert_eq!(r.remaining_today_chronos, r.daily_limit_chronos);
+
+        acc.post_recovery_restriction = Some(PostRecoveryRestriction { expires_at: now - 1, ..restriction });
+        server.state.db.put_account(&acc).unwrap();
+        assert!(server.get_post_recovery_restriction(id).await.unwrap().is_none());
+    }
+}
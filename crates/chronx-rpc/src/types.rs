@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 /// P2P network identity returned by `chronx_getNetworkInfo`.
@@ -8,6 +10,56 @@ use serde::{Deserialize, Serialize};
 pub struct RpcNetworkInfo {
     pub peer_multiaddr: String,
     pub peer_count: u64,
+    /// Entries in the Kademlia routing table.
+    pub kademlia_routing_table_size: u32,
+    /// Size of the GossipSub mesh for the vertex topic.
+    pub gossipsub_mesh_size: u32,
+    /// Peer IDs in the GossipSub mesh.
+    pub gossipsub_mesh_peers: Vec<String>,
+    /// Peer IDs with an open connection.
+    pub connected_peers: Vec<String>,
+    /// Configured bootstrap multiaddrs (including any added on reload).
+    pub bootstrap_peers: Vec<String>,
+    /// Seconds since the node process started.
+    pub node_uptime_secs: u64,
+    pub p2p_protocol_version: String,
+    /// Vertex gossip topic — nodes on different network ids never sync.
+    pub network_id: String,
+    /// Connected peers this node dialed.
+    #[serde(default)]
+    pub outbound_peers: u32,
+    /// Connected peers that dialed this node.
+    #[serde(default)]
+    pub inbound_peers: u32,
+    /// Peers disconnected to stay within the connection limits.
+    #[serde(default)]
+    pub peers_evicted: u64,
+}
+
+/// The node's direct connections, returned by `chronx_getNetworkTopology`.
+/// Only covers peers this node is connected to, not the whole network.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcNetworkTopology {
+    pub self_peer_id: String,
+    pub connections: Vec<RpcPeerConnection>,
+    /// Entries in the Kademlia routing table.
+    pub kademlia_table_size: u32,
+    /// Peer IDs in the GossipSub mesh for the vertex topic.
+    pub gossipsub_mesh_peers: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcPeerConnection {
+    pub peer_id: String,
+    pub multiaddr: String,
+    /// `"inbound"` or `"outbound"`.
+    pub direction: String,
+    /// Unix time the connection was established.
+    pub connected_since: i64,
+    pub gossipsub_in_mesh: bool,
+    /// Routing-table membership for outbound peers, net valid gossip for
+    /// inbound ones; only comparable between peers of the same direction.
+    pub reputation_score: i32,
 }
 
 /// JSON-serializable account summary returned by `chronx_getAccount`.
@@ -39,6 +91,35 @@ pub struct RpcAccount {
     pub incoming_locked_chronos: String,
     /// Sum of pending outgoing chronos (u128 as string).
     pub outgoing_locked_chronos: String,
+    /// Hex Dilithium2 key of a single-key or recovery-enabled account;
+    /// null for multisig.
+    #[serde(default)]
+    pub public_key: Option<String>,
+}
+
+/// One page of `chronx_getAllAccounts`. Pass `next_cursor` back to get the
+/// following page; it is `None` on the last one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcAccountPage {
+    pub accounts: Vec<RpcAccount>,
+    pub next_cursor: Option<String>,
+}
+
+/// Account creation spread returned by `chronx_getAccountAgeStats`. Both
+/// timestamps are 0 when no account has a recorded creation time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcAccountAgeStats {
+    pub oldest_account_created_at: i64,
+    pub newest_account_created_at: i64,
+    /// Oldest month first; months with no new accounts are omitted.
+    pub accounts_per_month: Vec<RpcMonthCount>,
+}
+
+/// Accounts created in one calendar month (`"YYYY-MM"`, UTC).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcMonthCount {
+    pub month: String,
+    pub count: u64,
 }
 
 /// JSON-serializable time-lock summary.
@@ -75,6 +156,49 @@ pub struct RpcTimeLock {
     pub lock_metadata: Option<String>,
     /// Suggestion-only conversion currency hint.
     pub convert_to: Option<String>,
+    /// Fiat currency (or full oracle pair) to value the lock in at claim time.
+    #[serde(default)]
+    pub oracle_hint: Option<String>,
+    /// On-chain claim policy; V1 locks with one must go through the claims
+    /// workflow (`OpenClaim`) instead of `TimeLockClaim`.
+    #[serde(default)]
+    pub claim_policy: Option<u64>,
+    /// Why a `Cancelled` or `Expired` lock was released other than by its
+    /// sender.
+    #[serde(default)]
+    pub cancellation_reason: Option<String>,
+    /// For an `Expired` lock, the policy its funds were released under:
+    /// `ReturnToSender`, `Burn` or `RedirectTo(<account>)`.
+    #[serde(default)]
+    pub expiry_action: Option<String>,
+    /// Who a split lock pays on claim, in policy order. The first recipient
+    /// also receives the rounding remainder.
+    #[serde(default)]
+    pub split_recipients: Option<Vec<RpcSplitRecipient>>,
+}
+
+/// One payee of a split lock.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcSplitRecipient {
+    pub account_id: String,
+    pub basis_points: u16,
+}
+
+/// One entry of `chronx_getUnclaimedLockReport`: a pending lock that matured
+/// a while ago and has not been claimed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcUnclaimedLock {
+    pub lock_id: String,
+    pub amount_kx: String,
+    /// Recipient account (base-58).
+    pub recipient: String,
+    pub unlock_at: i64,
+    pub matured_days_ago: f64,
+    pub has_claim_policy: bool,
+    pub expiry_policy: Option<String>,
+    /// What happens to the funds if the claim window lapses, when the lock
+    /// sets one.
+    pub unclaimed_action: Option<String>,
 }
 
 /// Protocol constants returned by `chronx_getGenesisInfo`.
@@ -91,6 +215,13 @@ pub struct RpcGenesisInfo {
     pub pow_difficulty: u8,
     pub node_rewards_kx: String,
     pub node_rewards_start: i64,
+    /// Genesis account ids (base-58), when the node can determine them.
+    #[serde(default)]
+    pub public_sale_account: Option<String>,
+    #[serde(default)]
+    pub treasury_account: Option<String>,
+    #[serde(default)]
+    pub humanity_account: Option<String>,
 }
 
 impl RpcGenesisInfo {
@@ -101,13 +232,16 @@ impl RpcGenesisInfo {
             ticker: "KX".into(),
             base_unit: "Chrono".into(),
             chronos_per_kx: CHRONOS_PER_KX as u64,
-            total_supply_kx: (TOTAL_SUPPLY_CHRONOS / CHRONOS_PER_KX).to_string(),
+            total_supply_kx: chronx_core::units::kx_string(TOTAL_SUPPLY_CHRONOS),
             genesis_timestamp: GENESIS_TIMESTAMP,
             treasury_start: TREASURY_START_TIMESTAMP,
             humanity_unlock: HUMANITY_UNLOCK_TIMESTAMP,
             pow_difficulty,
             node_rewards_kx: NODE_REWARDS_KX.to_string(),
             node_rewards_start: TREASURY_START_TIMESTAMP,
+            public_sale_account: None,
+            treasury_account: None,
+            humanity_account: None,
         }
     }
 }
@@ -143,9 +277,68 @@ pub struct RpcClaimState {
     pub opened_at: i64,
     pub agent_id: Option<String>,
     pub status: String,
+    /// Claims slashed for a bad or missing reveal so far.
+    pub claim_attempts: u8,
+    /// Whether the lane was raised at commit time after an oracle price rise.
+    pub upgraded_lane: bool,
+    pub lane_upgraded_at: Option<i64>,
+}
+
+/// One transition of `chronx_getClaimStateHistory`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcClaimHistoryEntry {
+    /// Lock status after the transition, e.g. `ClaimCommitted`.
+    pub status: String,
+    pub at: i64,
+    pub tx_id: Option<String>,
+    /// Base-58 account that sent the action.
+    pub actor: Option<String>,
+    /// `lane`, `bond_chronos` (posted by this action) and `certificate_count`.
+    pub details: serde_json::Value,
+}
+
+/// Result of `chronx_getDifficulty`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcDifficulty {
+    pub current_difficulty: u8,
+    pub min_difficulty: u8,
+    pub max_difficulty: u8,
+    pub target_interval_ms: u64,
+    /// Average gap between the vertices of the retarget window ending at
+    /// the deepest tip; 0 until two have been applied.
+    pub current_ema_ms: f64,
+    /// DAG depth of the latest adjustment; 0 if there has been none.
+    pub last_adjustment_depth: u64,
+    /// `increased`, `decreased` or `none`.
+    pub last_adjustment_direction: String,
+}
+
+/// One entry of `chronx_getDifficultyHistory`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcDifficultyAdjustment {
+    pub depth: u64,
+    pub difficulty: u8,
+    pub at: i64,
+}
+
+/// Pending locks of one account unlocking on one UTC day, from
+/// `chronx_getAccountLockTimeline`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcLockTimelineDay {
+    /// `YYYY-MM-DD`.
+    pub unlock_date_iso: String,
+    pub locks: Vec<RpcLockTimelineEntry>,
+    pub total_amount_kx: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcLockTimelineEntry {
+    pub lock_id: String,
+    pub amount_kx: String,
+    /// The other party; the account itself for a lock to its own key.
+    pub counterparty: String,
 }
 
-/// JSON oracle snapshot.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RpcOracleSnapshot {
     pub pair: String,
@@ -154,6 +347,14 @@ pub struct RpcOracleSnapshot {
     pub updated_at: i64,
 }
 
+/// One point of `chronx_getOracleHistory`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcOracleHistoryEntry {
+    pub price_cents: u64,
+    pub num_submissions: u32,
+    pub updated_at: i64,
+}
+
 // ── V3 New RPC types ─────────────────────────────────────────────────────────
 
 /// On-chain statistics summary returned by `chronx_getChainStats`.
@@ -166,20 +367,91 @@ pub struct RpcChainStats {
     pub dag_depth: u64,
     pub total_supply_chronos: String,
     pub total_supply_kx: String,
+    /// Chronos burned so far, including expired locks swept under
+    /// `ExpiryPolicy::Burn`.
+    #[serde(default)]
+    pub burnt_supply_chronos: String,
+    /// Balance of the fee collector account, which every transaction fee
+    /// is credited to.
+    #[serde(default)]
+    pub fee_pool_chronos: String,
     /// Hex-encoded BLAKE3 balance Merkle root (None if not yet computed).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub state_root: Option<String>,
 }
 
-/// State root and vertex count returned by `chronx_getStateRoot`.
+/// Balance Merkle state root returned by `chronx_getStateRoot`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RpcStateRoot {
     /// Hex-encoded BLAKE3 balance Merkle root.
-    pub root: String,
+    pub root_hex: String,
+    /// DAG depth of the vertex whose application produced the root
+    /// (0 when the root was computed at startup).
+    pub at_depth: u64,
+    /// When the root was computed (Unix seconds; 0 when computed at startup).
+    pub at_timestamp: i64,
     /// Total number of vertices (transaction count / "block height").
     pub vertex_count: u64,
 }
 
+/// Fee quote returned by `chronx_estimateFee`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcFeeEstimate {
+    /// Serialized size of the actions times the fee rate per byte.
+    pub base_fee_chronos: String,
+    /// `base_fee_chronos`, raised to the protocol minimum if below it.
+    pub suggested_fee_chronos: String,
+    /// How the estimate was reached.
+    pub note: String,
+}
+
+/// Dry-run result returned by `chronx_simulateTransaction`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcSimulationResult {
+    /// Whether the transaction would be applied.
+    pub ok: bool,
+    /// The `ChronxError` variant that rejected it, e.g. `InsufficientBalance`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// The error's message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+    /// Net balance change per account if applied; empty when rejected.
+    pub balance_deltas: Vec<RpcBalanceDelta>,
+}
+
+/// One account's balance change in an `RpcSimulationResult`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcBalanceDelta {
+    pub account_id: String,
+    /// Signed change in Chronos, as a decimal string.
+    pub delta_chronos: String,
+}
+
+/// Result of `chronx_submitTransaction`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcSubmitResult {
+    pub tx_id: String,
+    /// `applied`, or `pending` if the node had not applied the transaction
+    /// when the submit timeout elapsed.
+    pub status: String,
+}
+
+/// Result of `chronx_getTransactionStatus`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcTransactionStatus {
+    pub tx_id: String,
+    /// `applied`, `pending` (queued in the mempool), `rejected`, or
+    /// `unknown` when the node has no record of it.
+    pub status: String,
+    /// Why the node rejected or dropped it; set when `rejected`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Unix timestamp of the rejection; set when `rejected`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rejected_at: Option<i64>,
+}
+
 /// Supply invariant verification result returned by `chronx_verifySupplyInvariant`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RpcSupplyInvariant {
@@ -216,6 +488,38 @@ pub struct RpcVersionInfo {
     pub api_version: String,
 }
 
+/// Block explorer home-page snapshot returned by `chronx_getExplorerSummary`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcExplorerSummary {
+    pub chain_stats: RpcChainStats,
+    /// Most recent transactions, newest first.
+    pub recent_transactions: Vec<RpcRecentTx>,
+    /// Accounts with the most KX in pending incoming locks, largest first.
+    pub top_lock_holders: Vec<RpcLockHolder>,
+    pub oracle_snapshots: Vec<RpcOracleSnapshot>,
+    pub active_recoveries: u32,
+    pub providers_active: u32,
+    pub schemas_active: u32,
+    /// Transactions per UTC day for the last 7 days (today last), zero-filled.
+    pub daily_transaction_counts_7d: Vec<RpcDayCount>,
+    /// Sum of all Pending lock amounts, in whole KX.
+    pub total_locked_kx: String,
+}
+
+/// One entry of `RpcExplorerSummary::top_lock_holders`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcLockHolder {
+    pub account_id: String,
+    pub pending_incoming_kx: String,
+}
+
+/// Transaction count for one UTC day, `date` as `"YYYY-MM-DD"`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcDayCount {
+    pub date: String,
+    pub count: u64,
+}
+
 /// A lightweight recent-transaction summary returned by `chronx_getRecentTransactions`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RpcRecentTx {
@@ -680,3 +984,478 @@ pub struct RpcChildChainStats {
     pub daily_limit: u64,
     pub daily_remaining: u64,
 }
+
+// ── Lock countdown ───────────────────────────────────────────────────────
+
+/// One entry of `chronx_getLockCountdownBatch`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RpcLockCountdown {
+    pub lock_id: String,
+    pub unlock_at: i64,
+    /// `unlock_at - now`; negative once the lock has matured.
+    pub seconds_remaining: i64,
+    pub is_matured: bool,
+    /// Lock status, or `"NotFound"` for unknown / malformed ids.
+    pub status: String,
+}
+
+/// One entry of `chronx_getLockSummaryBatch`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum RpcLockSummary {
+    Found {
+        lock_id: String,
+        status: String,
+        /// Omitted for private locks.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        amount_kx: Option<String>,
+        unlock_at: i64,
+        /// `unlock_at - now`; negative once the lock has matured.
+        seconds_until_unlock: i64,
+        is_matured: bool,
+        /// Recipient account (base-58).
+        recipient: String,
+    },
+    /// Unknown or malformed id; `error` is `"not_found"`.
+    NotFound { lock_id: String, error: String },
+}
+
+// ── Recovery status ──────────────────────────────────────────────────────
+
+/// Full recovery state of an account, returned by `chronx_getRecoveryStatus`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcRecoveryStatus {
+    pub account_id: String,
+    pub active: bool,
+    /// Account that started the recovery (base-58).
+    pub initiator: Option<String>,
+    /// Account that challenged the recovery (base-58).
+    pub challenger: Option<String>,
+    /// Proposed new owner public key (hex).
+    pub proposed_owner_key: Option<String>,
+    pub recovery_bond_chronos: String,
+    pub challenge_bond_chronos: String,
+    pub recovery_start_time: Option<i64>,
+    pub recovery_execute_after: Option<i64>,
+    /// Seconds until the recovery may be finalized (0 once the delay has elapsed).
+    pub seconds_until_execution: Option<i64>,
+    pub challenge_active: bool,
+    pub votes_approve: u32,
+    pub votes_reject: u32,
+    pub verifier_threshold: u32,
+    /// "Pending", "Approved" or "Rejected".
+    pub decision_status: String,
+    pub evidence_hash: Option<String>,
+    pub counter_evidence_hash: Option<String>,
+}
+
+/// Evidence hashes and off-chain URIs of a recovery, returned by
+/// `chronx_getRecoveryEvidence`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcRecoveryEvidence {
+    pub account_id: String,
+    pub evidence_hash: Option<String>,
+    /// `ipfs://…` or `https://…` reference supplied with StartRecovery.
+    pub evidence_uri: Option<String>,
+    pub counter_evidence_hash: Option<String>,
+    /// Reference supplied with ChallengeRecovery.
+    pub counter_evidence_uri: Option<String>,
+}
+
+/// Spending limit on a recovered account, returned by
+/// `chronx_getPostRecoveryRestriction`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcPostRecoveryRestriction {
+    pub account_id: String,
+    pub expires_at: i64,
+    pub daily_limit_chronos: String,
+    /// Sent so far in the current UTC day; "0" if nothing was sent today.
+    pub transferred_today_chronos: String,
+    pub remaining_today_chronos: String,
+}
+
+// ── Node health ─────────────────────────────────────────────────────────
+
+/// Returned by `chronx_health`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcHealth {
+    /// True when the state database passed its read/write round-trip.
+    pub healthy: bool,
+    /// Why the database check failed, if it did.
+    pub db_error: Option<String>,
+    pub uptime_secs: u64,
+}
+
+/// Returned by `chronx_validateConsistency`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcConsistencyReport {
+    pub consistent: bool,
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+/// Returned by `chronx_auditLockSupply`. Amounts are Chronos as decimal strings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcLockAudit {
+    /// Principal of every `Pending` lock.
+    pub total_locked_chronos: String,
+    /// Pending principal implied by the senders' outgoing lock totals.
+    pub expected_chronos: String,
+    pub pending_count: u64,
+    pub claimed_count: u64,
+    pub cancelled_count: u64,
+    /// `total_locked - expected`; may be negative.
+    pub discrepancy_chronos: String,
+    pub ok: bool,
+}
+
+/// Returned by `chronx_auditAccountSupply`. Amounts are Chronos as decimal strings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcSupplyAudit {
+    /// Account balances, savings and recovery bonds.
+    pub accounts_chronos: String,
+    /// Principal of `Pending` locks.
+    pub pending_locks_chronos: String,
+    /// Other Chronos held by open locks and claim bonds.
+    pub other_held_chronos: String,
+    pub burned_chronos: String,
+    /// The part of the total supply genesis never mints.
+    pub unminted_chronos: String,
+    pub total_chronos: String,
+    /// `TOTAL_SUPPLY_CHRONOS`.
+    pub expected_chronos: String,
+    /// `total - expected`; may be negative.
+    pub discrepancy_chronos: String,
+    pub ok: bool,
+}
+
+/// One entry of `chronx_getTagStats`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcTagStat {
+    /// Normalized (trimmed, lower-case) tag.
+    pub tag: String,
+    /// Number of non-private locks carrying the tag.
+    pub lock_count: u64,
+}
+
+/// One state checkpoint, returned by `chronx_getCheckpoints`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcCheckpoint {
+    pub path: String,
+    pub created_at: i64,
+    pub size_bytes: u64,
+}
+
+/// Protocol feature flags in effect, returned by `chronx_getFeatureFlags`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcFeatureFlags {
+    pub recurring_enabled: bool,
+    pub split_enabled: bool,
+    pub secondary_market_enabled: bool,
+    pub transfer_lock_enabled: bool,
+    pub email_lock_enabled: bool,
+    pub compound_lock_enabled: bool,
+    /// False when no flags have been stored and compiled-in defaults apply.
+    pub governance_set: bool,
+}
+
+/// An account matched by `chronx_findAccountByPrefix`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcAccountMatch {
+    /// Base-58 account id.
+    pub account_id: String,
+    pub balance_kx: String,
+}
+
+/// What `chronx_search` resolved a query to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcSearchResult {
+    /// `"transaction"`, `"account"`, `"lock"` or `"not_found"`.
+    pub result_type: String,
+    /// The matching `RpcDetailedTx`, `RpcAccount` or `RpcTimeLock`; null
+    /// when nothing matched.
+    pub data: serde_json::Value,
+}
+
+/// One recovery vote, returned by `chronx_getVerifierHistory`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcVerifierVote {
+    pub target_account: String,
+    pub recovery_started_at: i64,
+    /// `"approve"` or `"reject"`.
+    pub vote: String,
+    pub voted_at_tx_id: String,
+    /// How the recovery ended (`"executed"`); null while it is still open.
+    pub outcome: Option<String>,
+}
+
+/// One verifier's standing, returned by `chronx_getVerifierLeaderboard`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcVerifierRank {
+    pub verifier_id: String,
+    pub votes_cast: u64,
+    pub verifier_stake_kx: String,
+    /// Stake still at or above the registration minimum.
+    pub slash_free: bool,
+}
+
+/// A registered recovery verifier, from `chronx_getVerifierRegistrations`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcVerifierRegistration {
+    pub account_id: String,
+    pub stake_chronos: String,
+    pub stake_kx: String,
+    pub registered_at: i64,
+    pub last_vote_at: Option<i64>,
+    pub total_votes: u64,
+    pub slash_count: u32,
+    /// Stake at or above the current minimum, so the verifier may vote.
+    pub active: bool,
+}
+
+/// An on-chain governance proposal, from `chronx_getActiveProposals` and
+/// `chronx_getGovernanceHistory`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcGovernanceProposal {
+    pub proposal_id: String,
+    pub proposer: String,
+    pub title: String,
+    /// Hex of the hash committing to the proposal text.
+    pub body_hash: String,
+    /// `GovernanceTarget` variant, e.g. `MinRecoveryBond`.
+    pub target_parameter: String,
+    pub proposed_value_hex: String,
+    pub bond_chronos: String,
+    pub submitted_at: i64,
+    pub voting_period_secs: i64,
+    pub voting_ends_at: i64,
+    pub votes_for_chronos: String,
+    pub votes_against_chronos: String,
+    pub voter_count: u64,
+    /// `Active`, `Approved` or `Rejected`.
+    pub status: String,
+    pub finalized_at: Option<i64>,
+}
+
+/// A recurring lock series, returned by `chronx_getRecurringSchedule`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcRecurringSchedule {
+    /// Id of the first lock of the series.
+    pub series_id: String,
+    pub sender: String,
+    pub recipient_account_id: String,
+    pub amount_per_installment_chronos: String,
+    /// `Weekly`, `Monthly` or `Annual`.
+    pub policy: String,
+    pub interval_secs: i64,
+    pub total_installments: u32,
+    pub installments_claimed: u32,
+    /// Escrowed Chronos not yet paid out; zero once the series has ended.
+    pub remaining_chronos: String,
+    /// `Active`, `Exhausted` (every installment claimed) or `Cancelled`.
+    pub status: String,
+    /// Installments created so far, first to last.
+    pub installments: Vec<RpcRecurringInstallment>,
+}
+
+/// One lock of a recurring series.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcRecurringInstallment {
+    pub lock_id: String,
+    /// 1-based position in the series.
+    pub index: u32,
+    pub unlock_at: i64,
+    pub status: String,
+}
+
+/// DAG fork-width summary, returned by `chronx_getDagHealth`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcDagHealth {
+    pub tip_count: u64,
+    pub max_tips: u64,
+    /// `tip_count / max_tips`; at 1.0 or above, vertices must merge two tips.
+    pub fork_pressure: f64,
+    pub recommendation: String,
+}
+
+/// One event-log entry, returned by `chronx_getEvents`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcEvent {
+    pub timestamp: i64,
+    pub tx_id: String,
+    /// Event variant name, e.g. `"TransferApplied"`.
+    pub event_type: String,
+    /// Event fields: account ids in base-58, lock ids in hex, amounts in
+    /// Chronos as strings.
+    pub data: serde_json::Value,
+}
+
+/// An alert rule that is currently firing, returned by `chronx_getActiveAlerts`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcAlert {
+    pub name: String,
+    /// `"Warning"` or `"Critical"`.
+    pub severity: String,
+    pub message: String,
+    /// Unix time the rule started firing.
+    pub since: i64,
+}
+
+/// One interval of `chronx_getChainTimeline`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcTimelineBucket {
+    pub bucket_start: i64,
+    pub vertex_count: u64,
+    pub unique_senders: u64,
+    pub avg_fee_chronos: String,
+    /// Actions in the bucket, keyed by action variant name.
+    pub action_type_counts: HashMap<String, u64>,
+}
+
+/// An account's balance after one vertex, from `chronx_getAccountBalanceHistory`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcBalancePoint {
+    pub depth: u64,
+    pub tx_id: String,
+    /// Balance in Chronos once this vertex was applied.
+    pub balance_after: String,
+}
+
+/// Result of `chronx_validateSplitPolicy`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcSplitPolicyValidation {
+    pub lock_id: String,
+    /// Whether a `ValidateSplitPolicy` transaction would succeed now.
+    pub valid: bool,
+    /// Whether `ValidateSplitPolicy` has already been applied to the lock.
+    pub validated_on_chain: bool,
+    pub basis_points_total: u32,
+    /// Split recipients (base58) with no account.
+    pub missing_recipients: Vec<String>,
+    /// Split recipients (base58) listed more than once, or the lock's sender.
+    pub duplicate_recipients: Vec<String>,
+}
+
+/// Node build, host and runtime details, returned by `chronx_getSystemInfo`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcSystemInfo {
+    pub node_version: String,
+    /// Compiler the node was built with, e.g. `"rustc 1.80.0 (...)"`.
+    pub rust_version: String,
+    pub os: String,
+    pub arch: String,
+    pub data_dir: String,
+    pub db_size_bytes: u64,
+    pub uptime_secs: u64,
+    pub genesis_timestamp: i64,
+    /// Hex BLAKE3 hash of the genesis parameters; empty for databases
+    /// created before the hash was recorded.
+    pub genesis_hash: String,
+    pub total_supply_chronos: String,
+    pub pow_difficulty: u8,
+    pub network_id: String,
+    pub p2p_listen_addr: String,
+    pub rpc_addr: String,
+    pub feature_flags: RpcFeatureFlags,
+}
+
+/// One sled tree, in `RpcDbDiagnostics`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcTreeDiagnostic {
+    pub name: String,
+    pub entry_count: u64,
+    /// Sum of key and value lengths.
+    pub approx_size_bytes: u64,
+    /// False for trees this node version does not know about.
+    pub known: bool,
+}
+
+/// State database breakdown returned by `chronx_getDbDiagnostics`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcDbDiagnostics {
+    pub trees: Vec<RpcTreeDiagnostic>,
+    /// Names of the trees with `known == false`.
+    pub unknown_trees: Vec<String>,
+    pub total_size_bytes: u64,
+    pub cache_capacity_bytes: u64,
+    /// Share of the page cache the tree data would fill, 0.0–1.0.
+    pub cache_utilization: f64,
+}
+
+// ── Lock status subscription ─────────────────────────────────────────────
+
+/// Notification pushed to `chronx_subscribeLockStatus` subscribers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcLockStatusEvent {
+    pub lock_id: String,
+    /// Same status names as `RpcTimeLock::status`.
+    pub new_status: String,
+    /// Unix timestamp at which the change was applied.
+    pub timestamp: i64,
+    /// The lock can no longer change; the subscription ends after this event.
+    pub is_terminal: bool,
+}
+
+/// Notification pushed to `chronx_subscribeNewVertices` subscribers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcVertexNotification {
+    pub tx_id: String,
+    pub from: String,
+    /// Unix timestamp at which the transaction was applied.
+    pub timestamp: i64,
+    /// `Action` variant names, in order.
+    pub action_types: Vec<String>,
+}
+
+/// Notification pushed to `chronx_subscribeLockUpdates` subscribers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcLockUpdateEvent {
+    pub lock_id: String,
+    pub sender: String,
+    pub recipient: String,
+    /// Whoever bought the claim right, if it was sold.
+    pub beneficiary: Option<String>,
+    /// Same status names as `RpcTimeLock::status`.
+    pub new_status: String,
+    /// Unix timestamp at which the change was applied.
+    pub timestamp: i64,
+    pub is_terminal: bool,
+}
+
+/// A transaction waiting in the node's mempool, from
+/// `chronx_getPendingTransactions`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcPendingTx {
+    pub tx_id: String,
+    pub sender: String,
+    pub nonce: u64,
+    /// Unix timestamp at which the node queued it.
+    pub received_at: i64,
+    pub age_secs: i64,
+    pub expires_at: Option<i64>,
+}
+
+/// A transaction the mempool dropped or turned away.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcDroppedTx {
+    pub tx_id: String,
+    pub sender: String,
+    /// E.g. `expired`, `nonce already used`, or the engine's rejection.
+    pub reason: String,
+    pub dropped_at: i64,
+}
+
+/// Mempool occupancy, returned by `chronx_getMempoolInfo`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcMempoolInfo {
+    pub size: usize,
+    pub capacity: usize,
+    /// Accounts with at least one queued transaction.
+    pub sender_count: usize,
+    /// Age of the longest-waiting transaction; `None` when empty.
+    pub oldest_age_secs: Option<i64>,
+    /// Transactions dropped since the node started.
+    pub dropped_total: u64,
+    /// The most recent drops, newest first.
+    pub recently_dropped: Vec<RpcDroppedTx>,
+}
@@ -0,0 +1,147 @@
+//! Atomic multi-tree writes for the state database.
+//!
+//! A [`WriteBatch`] collects inserts and removals across any of the
+//! [`StateDb`](crate::StateDb) trees and applies them in a single sled
+//! transaction: either every write lands or none do. Reads made through the
+//! batch see its own pending writes, so index maintenance that reads the
+//! previous value of a key stays correct when one batch writes it twice.
+
+use std::collections::BTreeMap;
+use std::ops::Bound;
+
+use chronx_core::error::ChronxError;
+use sled::transaction::{ConflictableTransactionError, TransactionError, TransactionalTree};
+use sled::{Transactional, Tree};
+
+/// Pending writes against one tree; `None` removes the key.
+type Overlay = BTreeMap<Vec<u8>, Option<Vec<u8>>>;
+
+/// Key-value pairs read through a batch.
+pub(crate) type Entries = Vec<(Vec<u8>, Vec<u8>)>;
+
+/// Writes staged against a [`StateDb`](crate::StateDb), applied atomically
+/// by [`commit`](WriteBatch::commit). Build one with `StateDb::batch`.
+pub struct WriteBatch<'a> {
+    /// Touched trees in first-write order, each with its pending writes.
+    trees: Vec<(&'a Tree, Overlay)>,
+    /// Abort the commit on this write (1-based); fault injection for tests.
+    fail_on_write: Option<usize>,
+}
+
+/// An empty batch with no fault injection, for moving a batch out of a
+/// struct that holds one.
+impl Default for WriteBatch<'_> {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl<'a> WriteBatch<'a> {
+    pub(crate) fn new(fail_on_write: Option<usize>) -> Self {
+        Self { trees: Vec::new(), fail_on_write }
+    }
+
+    fn overlay(&self, tree: &Tree) -> Option<&Overlay> {
+        self.trees
+            .iter()
+            .find(|(t, _)| std::ptr::eq(*t, tree))
+            .map(|(_, overlay)| overlay)
+    }
+
+    fn overlay_mut(&mut self, tree: &'a Tree) -> &mut Overlay {
+        let pos = match self.trees.iter().position(|(t, _)| std::ptr::eq(*t, tree)) {
+            Some(pos) => pos,
+            None => {
+                self.trees.push((tree, Overlay::new()));
+                self.trees.len() - 1
+            }
+        };
+        &mut self.trees[pos].1
+    }
+
+    /// The value of `key` once this batch is applied.
+    pub(crate) fn get(&self, tree: &Tree, key: impl AsRef<[u8]>) -> Result<Option<Vec<u8>>, ChronxError> {
+        if let Some(pending) = self.overlay(tree).and_then(|o| o.get(key.as_ref())) {
+            return Ok(pending.clone());
+        }
+        tree.get(key)
+            .map(|v| v.map(|iv| iv.to_vec()))
+            .map_err(|e| ChronxError::Storage(e.to_string()))
+    }
+
+    pub(crate) fn insert(&mut self, tree: &'a Tree, key: impl AsRef<[u8]>, value: impl AsRef<[u8]>) {
+        self.overlay_mut(tree)
+            .insert(key.as_ref().to_vec(), Some(value.as_ref().to_vec()));
+    }
+
+    pub(crate) fn remove(&mut self, tree: &'a Tree, key: impl AsRef<[u8]>) {
+        self.overlay_mut(tree).insert(key.as_ref().to_vec(), None);
+    }
+
+    /// Entries under `prefix` once this batch is applied, in key order.
+    pub(crate) fn scan_prefix(
+        &self,
+        tree: &Tree,
+        prefix: &[u8],
+    ) -> Result<Entries, ChronxError> {
+        let mut merged = BTreeMap::new();
+        for item in tree.scan_prefix(prefix) {
+            let (key, value) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
+            merged.insert(key.to_vec(), value.to_vec());
+        }
+        if let Some(overlay) = self.overlay(tree) {
+            let from = Bound::Included(prefix.to_vec());
+            for (key, value) in overlay.range((from, Bound::Unbounded)) {
+                if !key.starts_with(prefix) {
+                    break;
+                }
+                match value {
+                    Some(v) => merged.insert(key.clone(), v.clone()),
+                    None => merged.remove(key),
+                };
+            }
+        }
+        Ok(merged.into_iter().collect())
+    }
+
+    /// Number of pending writes.
+    pub fn len(&self) -> usize {
+        self.trees.iter().map(|(_, overlay)| overlay.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Apply every pending write in one transaction.
+    pub fn commit(self) -> Result<(), ChronxError> {
+        if self.trees.is_empty() {
+            return Ok(());
+        }
+        let trees: Vec<&Tree> = self.trees.iter().map(|(t, _)| *t).collect();
+        trees
+            .as_slice()
+            .transaction(|views: &Vec<TransactionalTree>| {
+                let mut written = 0;
+                for ((_, overlay), view) in self.trees.iter().zip(views) {
+                    for (key, value) in overlay {
+                        written += 1;
+                        if self.fail_on_write == Some(written) {
+                            return Err(ConflictableTransactionError::Abort(ChronxError::Storage(
+                                format!("injected failure on write {written}"),
+                            )));
+                        }
+                        match value {
+                            Some(v) => view.insert(key.as_slice(), v.as_slice())?,
+                            None => view.remove(key.as_slice())?,
+                        };
+                    }
+                }
+                Ok(())
+            })
+            .map_err(|e| match e {
+                TransactionError::Abort(e) => e,
+                TransactionError::Storage(e) => ChronxError::Storage(e.to_string()),
+            })
+    }
+}
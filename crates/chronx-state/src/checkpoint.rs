@@ -0,0 +1,197 @@
+//! On-disk checkpoints of the state database.
+//!
+//! A checkpoint is a full copy of the sled directory taken right after a
+//! flush, stored as `<checkpoint_dir>/checkpoint_<unix_ts>/`. Created by
+//! [`StateDb::create_checkpoint`](crate::StateDb::create_checkpoint); this
+//! module lists, prunes and restores them.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chronx_core::error::ChronxError;
+use serde::{Deserialize, Serialize};
+
+const CHECKPOINT_PREFIX: &str = "checkpoint_";
+
+/// One checkpoint directory.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CheckpointInfo {
+    pub path: PathBuf,
+    /// Unix timestamp taken from the directory name.
+    pub created_at: i64,
+    pub size_bytes: u64,
+}
+
+fn io_err(e: std::io::Error) -> ChronxError {
+    ChronxError::Storage(e.to_string())
+}
+
+/// Directory name for a checkpoint taken at `ts`.
+pub fn checkpoint_path(checkpoint_dir: &Path, ts: i64) -> PathBuf {
+    checkpoint_dir.join(format!("{CHECKPOINT_PREFIX}{ts}"))
+}
+
+/// Every checkpoint under `checkpoint_dir`, oldest first. A missing directory
+/// yields an empty list.
+pub fn list_checkpoints(checkpoint_dir: &Path) -> Result<Vec<CheckpointInfo>, ChronxError> {
+    let entries = match fs::read_dir(checkpoint_dir) {
+        Ok(e) => e,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(io_err(e)),
+    };
+    let mut out = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(io_err)?;
+        let name = entry.file_name();
+        let Some(created_at) = name
+            .to_str()
+            .and_then(|n| n.strip_prefix(CHECKPOINT_PREFIX))
+            .and_then(|ts| ts.parse::<i64>().ok())
+        else {
+            continue;
+        };
+        if !entry.file_type().map_err(io_err)?.is_dir() {
+            continue;
+        }
+        let path = entry.path();
+        out.push(CheckpointInfo {
+            size_bytes: dir_size(&path)?,
+            path,
+            created_at,
+        });
+    }
+    out.sort_by_key(|c| c.created_at);
+    Ok(out)
+}
+
+/// Delete all but the newest `keep` checkpoints. Returns how many were removed.
+pub fn prune_checkpoints(checkpoint_dir: &Path, keep: usize) -> Result<usize, ChronxError> {
+    let all = list_checkpoints(checkpoint_dir)?;
+    let excess = all.len().saturating_sub(keep);
+    for cp in &all[..excess] {
+        fs::remove_dir_all(&cp.path).map_err(io_err)?;
+    }
+    Ok(excess)
+}
+
+/// Copy checkpoint `src` into `data_dir`. An existing non-empty `data_dir` is
+/// moved aside to `<data_dir>.pre-restore-<unix_ts>` rather than deleted.
+///
+/// `src` may be a single checkpoint or a checkpoint directory, in which case
+/// the newest checkpoint inside it is used. Returns the checkpoint restored.
+pub fn restore_checkpoint(src: &Path, data_dir: &Path) -> Result<PathBuf, ChronxError> {
+    let checkpoint = if src.join("db").is_file() {
+        src.to_path_buf()
+    } else {
+        list_checkpoints(src)?
+            .pop()
+            .map(|c| c.path)
+            .ok_or_else(|| ChronxError::Storage(format!("no checkpoint found in {}", src.display())))?
+    };
+
+    let occupied = fs::read_dir(data_dir).map(|mut d| d.next().is_some()).unwrap_or(false);
+    if occupied {
+        let mut aside = data_dir.as_os_str().to_owned();
+        aside.push(format!(".pre-restore-{}", unix_now()));
+        fs::rename(data_dir, &aside).map_err(io_err)?;
+    }
+    copy_dir(&checkpoint, data_dir)?;
+    Ok(checkpoint)
+}
+
+/// Recursively copy `src` into `dest`, creating `dest` if needed.
+pub(crate) fn copy_dir(src: &Path, dest: &Path) -> Result<(), ChronxError> {
+    fs::create_dir_all(dest).map_err(io_err)?;
+    for entry in fs::read_dir(src).map_err(io_err)? {
+        let entry = entry.map_err(io_err)?;
+        let target = dest.join(entry.file_name());
+        if entry.file_type().map_err(io_err)?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), &target).map_err(io_err)?;
+        }
+    }
+    Ok(())
+}
+
+fn dir_size(path: &Path) -> Result<u64, ChronxError> {
+    let mut total = 0;
+    for entry in fs::read_dir(path).map_err(io_err)? {
+        let entry = entry.map_err(io_err)?;
+        let meta = entry.metadata().map_err(io_err)?;
+        total += if meta.is_dir() { dir_size(&entry.path())? } else { meta.len() };
+    }
+    Ok(total)
+}
+
+pub(crate) fn unix_now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StateDb;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("chronx_checkpoint_test_{name}"));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn checkpoint_is_openable_and_restorable() {
+        let root = temp_dir("roundtrip");
+        let checkpoints = root.join("checkpoints");
+        let data = root.join("data");
+        {
+            let db = StateDb::open(&data).unwrap();
+            db.put_meta("marker", b"before").unwrap();
+            db.create_checkpoint(&checkpoint_path(&checkpoints, 100)).unwrap();
+            db.put_meta("marker", b"after").unwrap();
+            db.flush().unwrap();
+        }
+
+        let listed = list_checkpoints(&checkpoints).unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].created_at, 100);
+        assert!(listed[0].size_bytes > 0);
+
+        // The copy is a valid sled database in its own right.
+        {
+            let copy = StateDb::open(&listed[0].path).unwrap();
+            assert_eq!(copy.get_meta("marker").unwrap().as_deref(), Some(&b"before"[..]));
+        }
+
+        let restored = restore_checkpoint(&checkpoints, &data).unwrap();
+        assert_eq!(restored, listed[0].path);
+        let db = StateDb::open(&data).unwrap();
+        assert_eq!(db.get_meta("marker").unwrap().as_deref(), Some(&b"before"[..]));
+        // The replaced data dir was kept, not deleted.
+        let aside = fs::read_dir(&root)
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_name().to_string_lossy().starts_with("data.pre-restore-"))
+            .count();
+        assert_eq!(aside, 1);
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn prune_keeps_newest() {
+        let root = temp_dir("prune");
+        for ts in [5, 1, 3, 2, 4] {
+            fs::create_dir_all(checkpoint_path(&root, ts)).unwrap();
+        }
+        fs::create_dir_all(root.join("unrelated")).unwrap();
+
+        assert_eq!(prune_checkpoints(&root, 2).unwrap(), 3);
+        let left: Vec<i64> = list_checkpoints(&root).unwrap().iter().map(|c| c.created_at).collect();
+        assert_eq!(left, vec![4, 5]);
+        assert!(root.join("unrelated").exists());
+        let _ = fs::remove_dir_all(&root);
+    }
+}
@@ -0,0 +1,156 @@
+//! Offline compaction of the state database.
+//!
+//! sled 0.34 has no in-place compaction: space held by deleted and
+//! overwritten entries is only reclaimed as its log segments are rewritten.
+//! [`compact_database`] rebuilds the database from its live entries with
+//! [`StateDb::export_compacted`] and swaps the copy in. sled holds a lock on
+//! an open database, so this fails while the node is running.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chronx_core::error::ChronxError;
+use serde::{Deserialize, Serialize};
+
+use crate::checkpoint::unix_now;
+use crate::db::TreeDiagnostic;
+use crate::snapshot::SNAPSHOTS_DIR;
+use crate::StateDb;
+
+/// Meta key holding the unix time of the last compaction, as 8 big-endian bytes.
+pub const LAST_COMPACTION_KEY: &str = "last_compaction_at";
+
+/// Result of one [`compact_database`] run.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CompactionReport {
+    /// Every tree that was copied, with its live entries and their size.
+    pub trees: Vec<TreeDiagnostic>,
+    /// `size_on_disk` before compaction.
+    pub size_before: u64,
+    /// `size_on_disk` of the rebuilt database.
+    pub size_after: u64,
+}
+
+impl CompactionReport {
+    pub fn bytes_saved(&self) -> u64 {
+        self.size_before.saturating_sub(self.size_after)
+    }
+}
+
+fn io_err(e: std::io::Error) -> ChronxError {
+    ChronxError::Storage(e.to_string())
+}
+
+fn sibling(data_dir: &Path, suffix: &str) -> PathBuf {
+    let mut name = data_dir.as_os_str().to_owned();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+/// Rebuild the database at `data_dir` from its live entries and record the
+/// time in [`LAST_COMPACTION_KEY`]. The old directory is deleted only once
+/// the copy is in place.
+pub fn compact_database(data_dir: &Path) -> Result<CompactionReport, ChronxError> {
+    if !data_dir.join("db").is_file() {
+        return Err(ChronxError::Storage(format!("no state database at {}", data_dir.display())));
+    }
+    let tmp = sibling(data_dir, ".compacting");
+    if tmp.exists() {
+        fs::remove_dir_all(&tmp).map_err(io_err)?;
+    }
+
+    let (trees, size_before) = {
+        let db = StateDb::open(data_dir).map_err(|e| {
+            ChronxError::Storage(format!("opening {} (is the node running?): {e}", data_dir.display()))
+        })?;
+        db.flush()?;
+        let size_before = db.size_on_disk()?;
+        db.export_compacted(&tmp)?;
+        (db.tree_diagnostics()?, size_before)
+    };
+
+    let aside = sibling(data_dir, &format!(".pre-compact-{}", unix_now()));
+    fs::rename(data_dir, &aside).map_err(io_err)?;
+    if let Err(e) = fs::rename(&tmp, data_dir) {
+        let _ = fs::rename(&aside, data_dir);
+        return Err(io_err(e));
+    }
+    // Snapshots live inside the data dir but are not part of the database.
+    let snapshots = aside.join(SNAPSHOTS_DIR);
+    if snapshots.is_dir() {
+        fs::rename(&snapshots, data_dir.join(SNAPSHOTS_DIR)).map_err(io_err)?;
+    }
+    fs::remove_dir_all(&aside).map_err(io_err)?;
+
+    let db = StateDb::open(data_dir)?;
+    db.put_meta(LAST_COMPACTION_KEY, &unix_now().to_be_bytes())?;
+    db.flush()?;
+    Ok(CompactionReport { trees, size_before, size_after: db.size_on_disk()? })
+}
+
+/// Unix time of the last compaction, if the database has been compacted.
+pub fn last_compaction_at(db: &StateDb) -> Result<Option<i64>, ChronxError> {
+    Ok(db
+        .get_meta(LAST_COMPACTION_KEY)?
+        .and_then(|v| <[u8; 8]>::try_from(v.as_slice()).ok())
+        .map(i64::from_be_bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("chronx_compact_test_{name}"));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn compaction_shrinks_database_and_keeps_live_entries() {
+        let root = temp_dir("shrink");
+        let data = root.join("data");
+        {
+            let db = StateDb::open(&data).unwrap();
+            let value = vec![7u8; 512];
+            for i in 0..20_000u32 {
+                db.child_records.insert(i.to_be_bytes(), value.as_slice()).unwrap();
+            }
+            for i in 0..20_000u32 {
+                db.child_records.remove(i.to_be_bytes()).unwrap();
+            }
+            db.put_meta("marker", b"kept").unwrap();
+            db.flush().unwrap();
+        }
+
+        let report = compact_database(&data).unwrap();
+        assert!(report.size_after < report.size_before, "{report:?}");
+        assert!(report.bytes_saved() > 0);
+        assert!(report.trees.iter().any(|t| t.name == "child_records" && t.entry_count == 0));
+
+        let db = StateDb::open(&data).unwrap();
+        assert_eq!(db.get_meta("marker").unwrap().as_deref(), Some(&b"kept"[..]));
+        assert!(last_compaction_at(&db).unwrap().is_some());
+        drop(db);
+        // Only the compacted database is left behind.
+        let names: Vec<String> =
+            fs::read_dir(&root).unwrap().map(|e| e.unwrap().file_name().to_string_lossy().into_owned()).collect();
+        assert_eq!(names, vec!["data".to_string()]);
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn refuses_open_or_missing_database() {
+        let root = temp_dir("locked");
+        let data = root.join("data");
+        assert!(compact_database(&data).is_err());
+
+        let db = StateDb::open(&data).unwrap();
+        db.flush().unwrap();
+        let err = compact_database(&data).unwrap_err();
+        assert!(err.to_string().contains("is the node running?"), "{err}");
+        assert!(last_compaction_at(&db).unwrap().is_none());
+        drop(db);
+        let _ = fs::remove_dir_all(&root);
+    }
+}
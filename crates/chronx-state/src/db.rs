@@ -1,14 +1,103 @@
 use tracing;
 use serde_json;
 use hex;
-use chronx_core::account::{Account, TimeLockContract};
-use chronx_core::claims::{CertificateSchema, ClaimState, OracleSnapshot, ProviderRecord};
+use chronx_core::account::{Account, AuthPolicy, RecurringPolicy, TimeLockContract, TimeLockStatus, RECOVERY_ESCROW_ACCOUNT};
+use chronx_core::claims::{
+    CertificateSchema, ClaimState, LaneThresholds, OracleHistoryEntry, OracleSnapshot, ProviderRecord, ProviderStatus,
+};
 use chronx_core::error::ChronxError;
-use chronx_core::types::{AccountId, TxId};
+use chronx_core::events::ChronxEvent;
+use chronx_core::transaction::{recurring_child_lock_id, FeatureFlags, GovernanceParams};
+use chronx_core::types::{AccountId, GovernanceTarget, Timestamp, TxId};
 use chronx_dag::vertex::Vertex;
 use serde::{Deserialize, Serialize};
+
+use crate::batch::WriteBatch;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 
+/// Key of the [`FeatureFlags`] record in the `governance_params` tree.
+const FEATURE_FLAGS_KEY: &[u8] = b"feature_flags";
+
+/// Key of the [`GovernanceParams`] record in the `governance_params` tree.
+const GOVERNANCE_PARAMS_KEY: &[u8] = b"current";
+
+/// Meta key of the running total of burned Chronos (16-byte big-endian).
+const BURNED_CHRONOS_KEY: &str = "burned_chronos";
+
+/// Meta key of the account credited with transaction fees (base-58 string).
+pub const FEE_COLLECTOR_KEY: &str = "fee_collector";
+
+/// Meta key of the fee rate in Chronos per serialized action byte
+/// (16-byte big-endian).
+pub const FEE_RATE_PER_BYTE_KEY: &str = "fee_rate_per_byte";
+
+/// Meta key of the recent PoW difficulty adjustments, a bincode
+/// `Vec<(depth, difficulty, timestamp)>`, oldest first.
+const DIFFICULTY_HISTORY_KEY: &str = "difficulty_history";
+
+/// Difficulty adjustments kept under [`DIFFICULTY_HISTORY_KEY`].
+pub const DIFFICULTY_HISTORY_LEN: usize = 100;
+
+/// Meta key set once `verifier_vote_history` has been backfilled from vertices.
+const VERIFIER_VOTES_BUILT_KEY: &str = "verifier_vote_history_built";
+
+/// Meta key set once `verifier_registrations` has been built from accounts.
+const VERIFIER_REGISTRATIONS_BUILT_KEY: &str = "verifier_registrations_built";
+
+/// Meta key set once `claim_history` has been backfilled from vertices.
+const CLAIM_HISTORY_BUILT_KEY: &str = "claim_history_built";
+
+/// Meta key set once `timelocks_by_unlock` has been built (it is empty
+/// whenever no lock is pending).
+const UNLOCK_INDEX_BUILT_KEY: &str = "timelocks_by_unlock_built";
+
+/// Meta key set once `timelocks_by_sender` and `timelocks_by_recipient`
+/// have been built from existing locks.
+const PARTY_INDEX_BUILT_KEY: &str = "timelocks_by_party_built";
+
+/// Meta key set once `accounts_by_locked_incoming` has been built. The index
+/// is legitimately empty when no locks are pending, so emptiness can't be
+/// the trigger.
+const LOCKED_INCOMING_BUILT_KEY: &str = "accounts_by_locked_incoming_built";
+
+/// Meta key set once `search_index` has been built from existing locks.
+const SEARCH_INDEX_BUILT_KEY: &str = "search_index_built";
+
+/// Meta key set once bonds of recoveries started before the escrow account
+/// existed have been moved into [`RECOVERY_ESCROW_ACCOUNT`].
+const RECOVERY_ESCROW_BUILT_KEY: &str = "recovery_escrow_built";
+
+/// `search_index` key prefix for lock `client_ref`s.
+const CLIENT_REF_SEARCH_PREFIX: &[u8] = b"client_ref:";
+
+/// Meta key of the leaf count of the tree held in `merkle_cache`. Missing
+/// when the cache must be rebuilt before its next incremental update.
+const MERKLE_LEAF_COUNT_KEY: &str = "merkle_leaf_count";
+
+/// Meta key set once `merkle_cache` has been rebuilt with its leaves in
+/// account ID order; earlier databases held them in insertion order.
+const MERKLE_SORTED_BUILT_KEY: &str = "merkle_cache_sorted";
+
+/// Meta key of the depth and timestamp at which `latest_state_root` was
+/// computed.
+const LATEST_STATE_ROOT_AT_KEY: &str = "latest_state_root_at";
+
+/// `merkle_cache` key of the node at `position` on `level` (0 = leaves).
+fn merkle_cache_key(level: u8, position: u64) -> [u8; 9] {
+    let mut key = [0u8; 9];
+    key[0] = level;
+    key[1..].copy_from_slice(&position.to_be_bytes());
+    key
+}
+
+/// Meta key of the jurisdiction codes covered by active providers; see
+/// [`StateDb::get_jurisdictions_covered`].
+const JURISDICTIONS_COVERED_KEY: &str = "jurisdictions_covered";
+
+/// Meta key of the last `chronx_getChainTimeline` result; see [`TimelineCache`].
+const TIMELINE_CACHE_KEY: &str = "timeline_cache";
+
 // ── Verified Delivery Protocol data structures ───────────────────
 
 /// Contents of a package created at promise time and sent to the Verifas vault
@@ -66,6 +155,198 @@ pub struct VerifierRecord {
     pub status: String,
 }
 
+/// One entry of the `event_log` tree.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct EventRecord {
+    /// Time the transaction was applied.
+    pub timestamp: i64,
+    pub tx_id: TxId,
+    pub event: ChronxEvent,
+}
+
+/// One recovery vote cast by a verifier. Stored in the `verifier_vote_history`
+/// tree keyed by verifier AccountId bytes ‖ vote TxId bytes.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct VerifierVoteRecord {
+    pub verifier: AccountId,
+    pub target_account: AccountId,
+    /// `recovery_start_time` of the recovery voted on.
+    pub recovery_started_at: i64,
+    pub approve: bool,
+    pub vote_tx_id: TxId,
+    pub voted_at: i64,
+    /// How the recovery ended (`"executed"`); `None` while it is still open.
+    pub outcome: Option<String>,
+}
+
+/// A recovery verifier (`RegisterVerifier`). Stored in the
+/// `verifier_registrations` tree keyed by AccountId bytes, from registration
+/// until the verifier is deregistered.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct VerifierRegistration {
+    pub account_id: AccountId,
+    /// Mirrors `Account::verifier_stake`.
+    pub stake: u128,
+    /// First registration; the account's creation time for verifiers
+    /// registered before this tree existed.
+    pub registered_at: i64,
+    pub last_vote_at: Option<i64>,
+    pub total_votes: u64,
+    /// Slashes against the verifier's stake. Verifier stakes are not slashed
+    /// yet, so this is always 0.
+    pub slash_count: u32,
+}
+
+/// Where a governance proposal stands.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GovernanceProposalStatus {
+    /// Open for votes, or waiting to be finalized.
+    Active,
+    /// Finalized with quorum and a majority for; its value was applied.
+    Approved,
+    /// Finalized without quorum or without a majority for.
+    Rejected,
+}
+
+impl GovernanceProposalStatus {
+    pub fn name(&self) -> &'static str {
+        match self {
+            GovernanceProposalStatus::Active => "Active",
+            GovernanceProposalStatus::Approved => "Approved",
+            GovernanceProposalStatus::Rejected => "Rejected",
+        }
+    }
+}
+
+/// Terms of a recurring lock series, written when its first lock is created.
+/// Stored in the `recurring_schedules` tree keyed by the first lock's TxId
+/// bytes. Progress is read from the installment locks themselves (see
+/// [`StateDb::get_recurring_installments`]).
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct RecurringSchedule {
+    /// Id of the first lock, shared by every installment as
+    /// `recurring_series_id`.
+    pub series_id: TxId,
+    pub sender: AccountId,
+    pub recipient_account_id: AccountId,
+    /// Amount of each installment.
+    pub amount: u128,
+    pub policy: RecurringPolicy,
+    pub first_unlock_at: Timestamp,
+    pub created_at: Timestamp,
+}
+
+/// A `SubmitGovernanceProposal` and its votes. Stored in the
+/// `governance_proposals` tree keyed by proposal TxId bytes.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct GovernanceProposal {
+    /// Id of the submitting transaction.
+    pub proposal_id: TxId,
+    pub proposer: AccountId,
+    pub title: String,
+    pub body_hash: [u8; 32],
+    pub target_parameter: GovernanceTarget,
+    pub proposed_value_bytes: Vec<u8>,
+    /// Held in `GOVERNANCE_ESCROW_ACCOUNT` until finalization.
+    pub bond_amount: u128,
+    pub submitted_at: i64,
+    /// Requested by the proposer, within the governance bounds.
+    pub voting_period_secs: i64,
+    /// `submitted_at + voting_period_secs`. Votes are accepted before this
+    /// time; finalization from it on.
+    pub voting_ends_at: i64,
+    pub votes_for: u128,
+    pub votes_against: u128,
+    /// Accounts that have voted, in voting order.
+    pub voters: Vec<AccountId>,
+    pub status: GovernanceProposalStatus,
+    pub finalized_at: Option<i64>,
+}
+
+/// One state transition of a V2 claim. Stored in the `claim_history` tree
+/// keyed by lock TxId bytes ‖ u32 be sequence number.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ClaimHistoryRecord {
+    pub lock_id: TxId,
+    /// Lock status after the action, e.g. `ClaimCommitted` or `ClaimSlashed`.
+    pub status: String,
+    pub at: i64,
+    pub tx_id: TxId,
+    pub action_index: u32,
+    /// Sender of the action.
+    pub actor: AccountId,
+    /// Claim lane after the action; `None` when no claim state exists, as
+    /// for a lock flagged `Ambiguous`, or for backfilled entries.
+    pub lane: Option<u8>,
+    /// Bond posted by this action (commit or challenge); 0 for others.
+    pub bond_amount: u128,
+    /// Certificates submitted with a reveal; 0 for other actions.
+    pub certificate_count: u32,
+}
+
+impl ClaimHistoryRecord {
+    /// The entry for a V2 claim action, with its status taken from the
+    /// action alone. `None` for any other action.
+    pub fn from_action(
+        action: &chronx_core::transaction::Action,
+        actor: &AccountId,
+        tx_id: &TxId,
+        action_index: u32,
+        at: i64,
+    ) -> Option<Self> {
+        use chronx_core::transaction::Action;
+
+        let (lock_id, status, bond_amount, certificate_count) = match action {
+            Action::OpenClaim { lock_id } => (lock_id, "ClaimOpen", 0, 0),
+            Action::SubmitClaimCommit { lock_id, bond_amount, .. } => {
+                (lock_id, "ClaimCommitted", *bond_amount, 0)
+            }
+            Action::RevealClaim { lock_id, certificates, .. } => {
+                (lock_id, "ClaimRevealed", 0, certificates.len() as u32)
+            }
+            Action::ChallengeClaimReveal { lock_id, bond_amount, .. } => {
+                (lock_id, "ClaimChallenged", *bond_amount, 0)
+            }
+            Action::FinalizeClaim { lock_id } => (lock_id, "ClaimFinalized", 0, 0),
+            _ => return None,
+        };
+        Some(Self {
+            lock_id: lock_id.0.clone(),
+            status: status.to_string(),
+            at,
+            tx_id: tx_id.clone(),
+            action_index,
+            actor: actor.clone(),
+            lane: None,
+            bond_amount,
+            certificate_count,
+        })
+    }
+}
+
+/// Vertices whose timestamp falls in `[bucket_start, bucket_start + bucket_secs)`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
+pub struct TimelineBucket {
+    pub bucket_start: i64,
+    pub vertex_count: u64,
+    pub unique_senders: u64,
+    /// Integer mean of `fee_chronos`; 0 for an empty bucket.
+    pub avg_fee_chronos: u128,
+    /// Action count by `Action::name`.
+    pub action_type_counts: HashMap<String, u64>,
+}
+
+/// The most recently computed timeline and the query it answers. Only one
+/// is kept; a query with different parameters replaces it.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct TimelineCache {
+    pub bucket_secs: u64,
+    pub from_ts: i64,
+    pub to_ts: i64,
+    pub computed_at: i64,
+    pub buckets: Vec<TimelineBucket>,
+}
+
 // ── AI Agent Architecture data structures ────────────────────
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -385,6 +666,143 @@ pub struct IdentityRecord {
     pub issuer_notes: Option<String>,
 }
 
+// ── Consistency check ───────────────────────────────────────────────────────
+
+/// Result of [`StateDb::validate_consistency`].
+///
+/// `errors` are broken cross-tree references; `warnings` are records that
+/// could not be decoded and were skipped.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ConsistencyReport {
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+impl ConsistencyReport {
+    pub fn is_ok(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Result of [`StateDb::audit_lock_amounts`].
+///
+/// `total_locked` is the principal of every `Pending` lock; `discrepancy` is
+/// `total_locked` minus the expected total (positive means more is locked
+/// than expected).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditReport {
+    pub total_locked: u128,
+    pub pending_count: u64,
+    pub claimed_count: u64,
+    pub cancelled_count: u64,
+    pub discrepancy: i128,
+}
+
+impl AuditReport {
+    pub fn is_ok(&self) -> bool {
+        self.discrepancy == 0
+    }
+}
+
+/// Every tree [`StateDb::open`] creates, plus sled's default tree.
+/// [`StateDb::tree_diagnostics`] reports any other tree as unknown.
+pub const KNOWN_TREES: &[&str] = &[
+    "__sled__default",
+    "accounts",
+    "vertices",
+    "timelocks",
+    "dag_tips",
+    "meta",
+    "timelocks_by_tag",
+    "timelocks_by_unlock",
+    "timelocks_by_sender",
+    "timelocks_by_recipient",
+    "timelocks_for_sale",
+    "providers",
+    "schemas",
+    "claims",
+    "oracle_snapshots",
+    "oracle_submissions",
+    "oracle_history",
+    "email_claim_hashes",
+    "promise_packages",
+    "promise_triggers",
+    "verifier_registry",
+    "agent_registry",
+    "agent_loans",
+    "agent_custody_records",
+    "axiom_consents",
+    "sign_of_life",
+    "promise_chains",
+    "invoices",
+    "credits",
+    "deposits",
+    "conditionals",
+    "ledger_entries",
+    "identity_index",
+    "badge_blackouts",
+    "convert_to_suggestion",
+    "ledger_promise_index",
+    "executor_withdrawals",
+    "groups",
+    "loans",
+    "loan_stages",
+    "loan_defaults",
+    "loan_payments",
+    "oracle_cache",
+    "escrow_accounts",
+    "escrow_deposits",
+    "micro_loans",
+    "loan_memos",
+    "governance_params",
+    "authority_grants",
+    "escalations",
+    "attestor_failures",
+    "oracle_trigger_history",
+    "partial_release_history",
+    "pending_drawrequests",
+    "escalation_errors",
+    "bond_slash_cascade",
+    "hedge_instruments",
+    "pool_health_scores",
+    "friendly_loans",
+    "lock_extension_offers",
+    "lock_extension_requests",
+    "charge_offs",
+    "twap_orders",
+    "hedge_twap_orders",
+    "credit_facilities",
+    "child_records",
+    "child_index",
+    "verifier_vote_history",
+    "verifier_registrations",
+    "event_log",
+    "claim_history",
+    "vertices_by_depth",
+    "accounts_by_creation_time",
+    "accounts_by_locked_incoming",
+    "search_index",
+    "merkle_cache",
+    "merkle_leaf_index",
+    "governance_proposals",
+    "recurring_schedules",
+];
+
+/// sled's default page cache size, which `sled::open` uses.
+pub const SLED_CACHE_CAPACITY_BYTES: u64 = 1024 * 1024 * 1024;
+
+/// Size of one sled tree, from [`StateDb::tree_diagnostics`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct TreeDiagnostic {
+    pub name: String,
+    pub entry_count: u64,
+    /// Sum of key and value lengths; sled's own overhead is not included.
+    pub approx_size_bytes: u64,
+    /// Whether the tree is in [`KNOWN_TREES`]. An unknown tree was most
+    /// likely created by a newer node version.
+    pub known: bool,
+}
+
 /// Persistent state database backed by sled (pure-Rust, no C dependencies).
 ///
 /// Named trees:
@@ -398,23 +816,70 @@ pub struct IdentityRecord {
 /// claims — TxId bytes       → bincode(ClaimState)       [V2]
 /// oracle_snapshots — pair utf8 bytes  → bincode(OracleSnapshot)   [V2]
 /// oracle_submissions — (pair + AccountId) → bincode(OracleSubmission) [V2]
+/// oracle_history — pair utf8 bytes ‖ timestamp be → bincode(OracleHistoryEntry)
 /// email_claim_hashes — TxId bytes     → 32-byte BLAKE3 hash       [V3.3]
 /// promise_packages — TxId bytes       → bincode(PromisePackageRecord) [G7]
 /// promise_triggers — TxId bytes       → bincode(PromiseTriggerRecord) [G7]
 /// verifier_registry — wallet bytes    → bincode(VerifierRecord)       [G7]
+/// verifier_vote_history — AccountId bytes ‖ TxId bytes → bincode(VerifierVoteRecord)
+/// verifier_registrations — AccountId bytes → bincode(VerifierRegistration) (registered recovery verifiers)
+/// event_log — timestamp be ‖ TxId bytes ‖ u32 be index → bincode(EventRecord)
+/// claim_history — lock TxId bytes ‖ u32 be sequence → bincode(ClaimHistoryRecord)
+/// timelocks_by_tag — normalized tag bytes ‖ TxId bytes → [] (public locks only)
+/// timelocks_by_unlock — unlock_at be ‖ TxId bytes → [] (Pending locks only)
+/// timelocks_by_sender — sender AccountId bytes ‖ TxId bytes → []
+/// timelocks_by_recipient — recipient AccountId bytes ‖ TxId bytes → []
+/// timelocks_for_sale — TxId bytes → ask price u128 le (ForSale locks only)
+/// vertices_by_depth — depth be ‖ TxId bytes → [] (every stored vertex)
+/// accounts_by_creation_time — created_at be ‖ AccountId bytes → [] (accounts with created_at)
+/// accounts_by_locked_incoming — u128 be ‖ AccountId bytes → [] (accounts with pending incoming locks)
+/// search_index — "client_ref:" ‖ client_ref → TxId bytes (locks with a client_ref)
+/// merkle_cache — level u8 ‖ position u64 be → 32-byte balance Merkle node
+/// merkle_leaf_index — AccountId bytes → leaf position u64 be (account ID order)
+/// governance_proposals — proposal TxId bytes → bincode(GovernanceProposal)
+/// recurring_schedules — first lock TxId bytes → bincode(RecurringSchedule)
 pub struct StateDb {
     _db: sled::Db,
+    /// Directory the database was opened from (copied by `create_checkpoint`).
+    path: std::path::PathBuf,
     accounts: sled::Tree,
     vertices: sled::Tree,
     timelocks: sled::Tree,
     dag_tips: sled::Tree,
     meta: sled::Tree,
+    /// Tag index over non-private locks; maintained by `put_timelock`.
+    timelocks_by_tag: sled::Tree,
+    /// Unlock-time index over Pending locks; maintained by `put_timelock`.
+    timelocks_by_unlock: sled::Tree,
+    /// Sender and recipient indexes over every lock; maintained by `put_timelock`.
+    timelocks_by_sender: sled::Tree,
+    timelocks_by_recipient: sled::Tree,
+    /// Ask prices of ForSale locks; maintained by `put_timelock`.
+    timelocks_for_sale: sled::Tree,
+    /// Depth index over vertices; maintained by `put_vertex`.
+    vertices_by_depth: sled::Tree,
+    /// Creation-time index over accounts; maintained by `put_account`.
+    accounts_by_creation_time: sled::Tree,
+    /// Pending-incoming-amount index over accounts; maintained by `put_account`.
+    accounts_by_locked_incoming: sled::Tree,
+    /// Lookup keys for `chronx_search`; maintained by `put_timelock`.
+    search_index: sled::Tree,
+    /// Balance Merkle tree nodes; maintained by `update_state_root_for_account`.
+    merkle_cache: sled::Tree,
+    /// Leaf position of each account in `merkle_cache`.
+    merkle_leaf_index: sled::Tree,
+    /// On-chain governance proposals; maintained by the state engine.
+    governance_proposals: sled::Tree,
+    /// Recurring lock series; written by the state engine.
+    recurring_schedules: sled::Tree,
     // V2 Claims trees
     providers: sled::Tree,
     schemas: sled::Tree,
     claims: sled::Tree,
     oracle_snapshots: sled::Tree,
     oracle_submissions: sled::Tree,
+    /// Past snapshot values per pair; appended by the state engine.
+    oracle_history: sled::Tree,
     /// V3.3 Secure email claims: maps TxId (lock_id) → BLAKE3 hash of claim secret.
     /// Separate tree so that TimeLockContract serialisation format is unchanged.
     email_claim_hashes: sled::Tree,
@@ -422,6 +887,13 @@ pub struct StateDb {
     promise_packages: sled::Tree,
     promise_triggers: sled::Tree,
     verifier_registry: sled::Tree,
+    /// Recovery votes per verifier; maintained by the state engine.
+    verifier_vote_history: sled::Tree,
+    /// Registered recovery verifiers; maintained by the state engine.
+    verifier_registrations: sled::Tree,
+    /// Append-only log of engine events; maintained by the state engine.
+    event_log: sled::Tree,
+    claim_history: sled::Tree,
 
     // protocol — AI Agent Architecture trees
     agent_registry: sled::Tree,
@@ -498,12 +970,16 @@ pub struct StateDb {
     pub child_records: sled::Tree,
     pub child_index: sled::Tree,
 
+    /// Abort every batch commit on this write (1-based); see [`WriteBatch`].
+    #[cfg(test)]
+    pub(crate) fail_on_write: std::sync::Mutex<Option<usize>>,
 }
 
 impl StateDb {
     /// Open or create the state database at `path`.
     pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, ChronxError> {
-        let db = sled::open(path).map_err(|e| ChronxError::Storage(e.to_string()))?;
+        let path = path.as_ref().to_path_buf();
+        let db = sled::open(&path).map_err(|e| ChronxError::Storage(e.to_string()))?;
         let accounts = db
             .open_tree("accounts")
             .map_err(|e| ChronxError::Storage(e.to_string()))?;
@@ -519,6 +995,21 @@ impl StateDb {
         let meta = db
             .open_tree("meta")
             .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        let timelocks_by_tag = db
+            .open_tree("timelocks_by_tag")
+            .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        let timelocks_by_unlock = db
+            .open_tree("timelocks_by_unlock")
+            .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        let timelocks_by_sender = db
+            .open_tree("timelocks_by_sender")
+            .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        let timelocks_by_recipient = db
+            .open_tree("timelocks_by_recipient")
+            .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        let timelocks_for_sale = db
+            .open_tree("timelocks_for_sale")
+            .map_err(|e| ChronxError::Storage(e.to_string()))?;
         let providers = db
             .open_tree("providers")
             .map_err(|e| ChronxError::Storage(e.to_string()))?;
@@ -534,6 +1025,9 @@ impl StateDb {
         let oracle_submissions = db
             .open_tree("oracle_submissions")
             .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        let oracle_history = db
+            .open_tree("oracle_history")
+            .map_err(|e| ChronxError::Storage(e.to_string()))?;
         let email_claim_hashes = db
             .open_tree("email_claim_hashes")
             .map_err(|e| ChronxError::Storage(e.to_string()))?;
@@ -686,22 +1180,77 @@ impl StateDb {
         let child_index = db
             .open_tree("child_index")
             .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        let verifier_vote_history = db
+            .open_tree("verifier_vote_history")
+            .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        let verifier_registrations = db
+            .open_tree("verifier_registrations")
+            .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        let event_log = db
+            .open_tree("event_log")
+            .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        let claim_history = db
+            .open_tree("claim_history")
+            .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        let vertices_by_depth = db
+            .open_tree("vertices_by_depth")
+            .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        let accounts_by_creation_time = db
+            .open_tree("accounts_by_creation_time")
+            .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        let accounts_by_locked_incoming = db
+            .open_tree("accounts_by_locked_incoming")
+            .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        let search_index = db
+            .open_tree("search_index")
+            .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        let merkle_cache = db
+            .open_tree("merkle_cache")
+            .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        let merkle_leaf_index = db
+            .open_tree("merkle_leaf_index")
+            .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        let governance_proposals = db
+            .open_tree("governance_proposals")
+            .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        let recurring_schedules = db
+            .open_tree("recurring_schedules")
+            .map_err(|e| ChronxError::Storage(e.to_string()))?;
         let result = Ok(Self {
             _db: db,
+            path,
             accounts,
             vertices,
             timelocks,
             dag_tips,
             meta,
+            timelocks_by_tag,
+            timelocks_by_unlock,
+            timelocks_by_sender,
+            timelocks_by_recipient,
+            timelocks_for_sale,
+            vertices_by_depth,
+            accounts_by_creation_time,
+            accounts_by_locked_incoming,
+            search_index,
+            merkle_cache,
+            merkle_leaf_index,
+            governance_proposals,
+            recurring_schedules,
             providers,
             schemas,
             claims,
             oracle_snapshots,
             oracle_submissions,
+            oracle_history,
             email_claim_hashes,
             promise_packages,
             promise_triggers,
             verifier_registry,
+            verifier_vote_history,
+            verifier_registrations,
+            event_log,
+            claim_history,
             agent_registry,
             agent_loans,
             agent_custody_records,
@@ -749,8 +1298,14 @@ impl StateDb {
             child_records,
             child_index,
 
+            #[cfg(test)]
+            fail_on_write: std::sync::Mutex::new(None),
         });
 
+        // ── Records stored under an older layout; first, so the backfills
+        // below can read them.
+        let result = result.and_then(|s| s.migrate_storage_layout().map(|()| s));
+
         // ── One-time bincode→JSON migration for deposits tree ────────────
         // Safe: any record that fails serde_json parse is old bincode format.
         // Remove this block after one deploy.
@@ -767,9 +1322,374 @@ impl StateDb {
             }
         }
 
+        // ── One-time backfill of the tag index for databases created before it.
+        if let Ok(ref s) = result {
+            if s.timelocks_by_tag.is_empty() && !s.timelocks.is_empty() {
+                let mut indexed = 0usize;
+                for tlc in s.iter_all_timelocks().unwrap_or_default() {
+                    let mut batch = s.batch();
+                    s.index_timelock_tags(&mut batch, &tlc);
+                    if batch.commit().is_ok() {
+                        indexed += 1;
+                    }
+                }
+                tracing::info!(locks = indexed, "timelocks_by_tag index built");
+            }
+        }
+
+        // ── One-time build of the unlock-time index for databases created before it.
+        if let Ok(ref s) = result {
+            if matches!(s.get_meta(UNLOCK_INDEX_BUILT_KEY), Ok(None)) {
+                let mut indexed = 0usize;
+                for tlc in s.iter_all_timelocks().unwrap_or_default() {
+                    if !matches!(tlc.status, TimeLockStatus::Pending) {
+                        continue;
+                    }
+                    let mut batch = s.batch();
+                    s.index_timelock_unlock(&mut batch, &tlc);
+                    if batch.commit().is_ok() {
+                        indexed += 1;
+                    }
+                }
+                let _ = s.put_meta(UNLOCK_INDEX_BUILT_KEY, b"1");
+                tracing::info!(locks = indexed, "timelocks_by_unlock index built");
+            }
+        }
+
+        // ── One-time build of the sender/recipient indexes for databases created before them.
+        if let Ok(ref s) = result {
+            if matches!(s.get_meta(PARTY_INDEX_BUILT_KEY), Ok(None)) {
+                match s.backfill_timelock_parties() {
+                    Ok(locks) => tracing::info!(locks, "timelocks_by_sender and timelocks_by_recipient built"),
+                    Err(e) => tracing::warn!(error = %e, "timelock sender/recipient index backfill failed"),
+                }
+            }
+        }
+
+        // ── One-time backfill of the depth index for databases created before it.
+        if let Ok(ref s) = result {
+            if s.vertices_by_depth.is_empty() && !s.vertices.is_empty() {
+                let mut indexed = 0usize;
+                for v in s.iter_all_vertices().unwrap_or_default() {
+                    let mut batch = s.batch();
+                    s.index_vertex_depth(&mut batch, &v);
+                    if batch.commit().is_ok() {
+                        indexed += 1;
+                    }
+                }
+                tracing::info!(vertices = indexed, "vertices_by_depth index built");
+            }
+        }
+
+        // ── One-time backfill of the creation-time index for databases created before it.
+        if let Ok(ref s) = result {
+            if s.accounts_by_creation_time.is_empty() && !s.accounts.is_empty() {
+                let mut indexed = 0usize;
+                for acc in s.iter_all_accounts().unwrap_or_default() {
+                    if acc.created_at.is_none() {
+                        continue;
+                    }
+                    let mut batch = s.batch();
+                    s.index_account_creation(&mut batch, &acc);
+                    if batch.commit().is_ok() {
+                        indexed += 1;
+                    }
+                }
+                tracing::info!(accounts = indexed, "accounts_by_creation_time index built");
+            }
+        }
+
+        // ── One-time build of the locked-incoming index for databases created before it.
+        if let Ok(ref s) = result {
+            if matches!(s.get_meta(LOCKED_INCOMING_BUILT_KEY), Ok(None)) {
+                let mut indexed = 0usize;
+                for acc in s.iter_all_accounts().unwrap_or_default() {
+                    if acc.total_locked_incoming_chronos == 0 {
+                        continue;
+                    }
+                    let mut batch = s.batch();
+                    s.index_locked_incoming(&mut batch, None, &acc);
+                    if batch.commit().is_ok() {
+                        indexed += 1;
+                    }
+                }
+                let _ = s.put_meta(LOCKED_INCOMING_BUILT_KEY, b"1");
+                tracing::info!(accounts = indexed, "accounts_by_locked_incoming index built");
+            }
+        }
+
+        // ── One-time build of the search index for databases created before it.
+        if let Ok(ref s) = result {
+            if matches!(s.get_meta(SEARCH_INDEX_BUILT_KEY), Ok(None)) {
+                let mut indexed = 0usize;
+                for tlc in s.iter_all_timelocks().unwrap_or_default() {
+                    if tlc.client_ref.is_none() {
+                        continue;
+                    }
+                    let mut batch = s.batch();
+                    s.index_timelock_search(&mut batch, &tlc);
+                    if batch.commit().is_ok() {
+                        indexed += 1;
+                    }
+                }
+                let _ = s.put_meta(SEARCH_INDEX_BUILT_KEY, b"1");
+                tracing::info!(locks = indexed, "search_index built");
+            }
+        }
+
+        // ── One-time move of in-flight recovery bonds into the escrow account.
+        if let Ok(ref s) = result {
+            if matches!(s.get_meta(RECOVERY_ESCROW_BUILT_KEY), Ok(None)) {
+                let bonds: u128 = s
+                    .iter_all_accounts()
+                    .unwrap_or_default()
+                    .iter()
+                    .filter(|a| a.recovery_state.active)
+                    .map(|a| a.recovery_state.recovery_bond)
+                    .sum();
+                if bonds > 0 {
+                    let mut escrow = s.get_account(&RECOVERY_ESCROW_ACCOUNT).ok().flatten().unwrap_or_else(|| {
+                        Account::new(
+                            RECOVERY_ESCROW_ACCOUNT.clone(),
+                            AuthPolicy::SingleSig { public_key: chronx_core::types::DilithiumPublicKey(vec![]) },
+                        )
+                    });
+                    escrow.balance += bonds;
+                    let _ = s.put_account(&escrow);
+                }
+                let _ = s.put_meta(RECOVERY_ESCROW_BUILT_KEY, b"1");
+                tracing::info!(bonds, "recovery escrow account built");
+            }
+        }
+
+        // ── One-time rebuild of the Merkle cache in account ID leaf order.
+        if let Ok(ref s) = result {
+            if matches!(s.get_meta(MERKLE_SORTED_BUILT_KEY), Ok(None)) {
+                if !s.accounts.is_empty() {
+                    let _ = s.write_atomic(|batch| {
+                        let root = s.stage_merkle_rebuild(batch)?;
+                        s.stage_latest_state_root(batch, &root);
+                        Ok(())
+                    });
+                }
+                let _ = s.put_meta(MERKLE_SORTED_BUILT_KEY, b"1");
+                tracing::info!("merkle_cache rebuilt in account ID order");
+            }
+        }
+
+        // ── One-time backfill of verifier vote history from applied vertices.
+        if let Ok(ref s) = result {
+            if matches!(s.get_meta(VERIFIER_VOTES_BUILT_KEY), Ok(None)) {
+                match s.backfill_verifier_votes() {
+                    Ok(votes) => tracing::info!(votes, "verifier_vote_history built"),
+                    Err(e) => tracing::warn!(error = %e, "verifier_vote_history backfill failed"),
+                }
+            }
+        }
+
+        // ── One-time build of the verifier registrations from accounts.
+        if let Ok(ref s) = result {
+            if matches!(s.get_meta(VERIFIER_REGISTRATIONS_BUILT_KEY), Ok(None)) {
+                match s.backfill_verifier_registrations() {
+                    Ok(verifiers) => tracing::info!(verifiers, "verifier_registrations built"),
+                    Err(e) => tracing::warn!(error = %e, "verifier_registrations backfill failed"),
+                }
+            }
+        }
+
+        // ── One-time backfill of claim history from applied vertices.
+        if let Ok(ref s) = result {
+            if matches!(s.get_meta(CLAIM_HISTORY_BUILT_KEY), Ok(None)) {
+                match s.backfill_claim_history() {
+                    Ok(entries) => tracing::info!(entries, "claim_history built"),
+                    Err(e) => tracing::warn!(error = %e, "claim_history backfill failed"),
+                }
+            }
+        }
+
         result
     }
 
+    /// Run the [`migrate`](crate::migrate) steps newer than the database's
+    /// recorded layout version, recording each once it has run.
+    fn migrate_storage_layout(&self) -> Result<(), ChronxError> {
+        use crate::migrate::{self, STORAGE_LAYOUT_KEY, STORAGE_LAYOUT_VERSION};
+
+        let current = match self.get_meta(STORAGE_LAYOUT_KEY)? {
+            Some(b) => u32::from_be_bytes(
+                b.as_slice()
+                    .try_into()
+                    .map_err(|_| ChronxError::Storage(format!("corrupt {STORAGE_LAYOUT_KEY}")))?,
+            ),
+            None => 0,
+        };
+        if current > STORAGE_LAYOUT_VERSION {
+            return Err(ChronxError::Storage(format!(
+                "database layout version {current} is newer than this node supports ({STORAGE_LAYOUT_VERSION})"
+            )));
+        }
+        for version in current + 1..=STORAGE_LAYOUT_VERSION {
+            let rewritten = match version {
+                1 => migrate::rewrite_tree::<Vertex>(&self.vertices, "vertices", migrate::upgrade_vertex_v1)?,
+                2 => migrate::rewrite_tree::<Account>(&self.accounts, "accounts", migrate::upgrade_account_v2)?,
+                3 => {
+                    migrate::rewrite_tree::<TimeLockContract>(&self.timelocks, "timelocks", migrate::upgrade_timelock_v3)?
+                        + migrate::rewrite_tree::<ClaimState>(&self.claims, "claims", migrate::upgrade_claim_state_v3)?
+                }
+                _ => unreachable!("no migration step for layout version {version}"),
+            };
+            self.put_meta(STORAGE_LAYOUT_KEY, &version.to_be_bytes())?;
+            tracing::info!(version, rewritten, "storage layout migrated");
+        }
+        Ok(())
+    }
+
+    /// Rebuild `claim_history` from the V2 claim actions in stored vertices,
+    /// timed by when each vertex was applied. Statuses come from the actions,
+    /// so a reveal that was slashed is recorded as `ClaimRevealed`. Actions
+    /// applied in the same second are ordered by claim stage.
+    pub(crate) fn backfill_claim_history(&self) -> Result<usize, ChronxError> {
+        use chronx_core::transaction::Action;
+
+        let stage = |status: &str| {
+            ["ClaimOpen", "ClaimCommitted", "ClaimRevealed", "ClaimChallenged", "ClaimFinalized"]
+                .iter()
+                .position(|s| *s == status)
+        };
+        let mut records = Vec::new();
+        for v in &self.iter_all_vertices()? {
+            let tx = &v.transaction;
+            let mut actions: Vec<(&TxId, &AccountId, usize, &Action)> =
+                tx.actions.iter().enumerate().map(|(i, a)| (&tx.tx_id, &tx.from, i, a)).collect();
+            for a in &tx.actions {
+                if let Action::SponsoredTransaction { sponsored_tx, .. } = a {
+                    actions.extend(
+                        sponsored_tx
+                            .actions
+                            .iter()
+                            .enumerate()
+                            .map(|(i, inner)| (&sponsored_tx.tx_id, &sponsored_tx.from, i, inner)),
+                    );
+                }
+            }
+            for (tx_id, from, i, action) in actions {
+                records.extend(ClaimHistoryRecord::from_action(action, from, tx_id, i as u32, v.received_at));
+            }
+        }
+        records.sort_by_key(|r| (r.at, stage(&r.status)));
+
+        self.claim_history.clear().map_err(|e| ChronxError::Storage(e.to_string()))?;
+        for record in &records {
+            self.put_claim_history(record)?;
+        }
+        self.put_meta(CLAIM_HISTORY_BUILT_KEY, b"1")?;
+        Ok(records.len())
+    }
+
+    /// Rebuild `verifier_vote_history` and `Account::votes_cast` from the
+    /// `VoteRecovery` actions in stored vertices. Each vote is matched to the
+    /// latest `StartRecovery` on its target at or before the vote; a vote on a
+    /// recovery that is no longer active was on one that has since executed.
+    fn backfill_verifier_votes(&self) -> Result<usize, ChronxError> {
+        use chronx_core::transaction::Action;
+        use std::collections::HashMap;
+
+        let mut vertices = self.iter_all_vertices()?;
+        vertices.sort_by_key(|v| v.transaction.timestamp);
+
+        let mut starts: HashMap<AccountId, i64> = HashMap::new();
+        let mut records = Vec::new();
+        for v in &vertices {
+            let tx = &v.transaction;
+            let mut actions: Vec<(&AccountId, &Action)> = tx.actions.iter().map(|a| (&tx.from, a)).collect();
+            for a in &tx.actions {
+                if let Action::SponsoredTransaction { sponsored_tx, .. } = a {
+                    actions.extend(sponsored_tx.actions.iter().map(|inner| (&sponsored_tx.from, inner)));
+                }
+            }
+            for (from, action) in actions {
+                match action {
+                    Action::StartRecovery { target_account, .. } => {
+                        starts.insert(target_account.clone(), tx.timestamp);
+                    }
+                    Action::VoteRecovery { target_account, approve, .. } => {
+                        records.push(VerifierVoteRecord {
+                            verifier: from.clone(),
+                            target_account: target_account.clone(),
+                            recovery_started_at: starts.get(target_account).copied().unwrap_or(0),
+                            approve: *approve,
+                            vote_tx_id: tx.tx_id.clone(),
+                            voted_at: tx.timestamp,
+                            outcome: None,
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let mut votes_cast: HashMap<AccountId, u64> = HashMap::new();
+        for mut record in records.iter().cloned() {
+            let open = self.get_account(&record.target_account)?.is_some_and(|t| {
+                t.recovery_state.active
+                    && t.recovery_state.recovery_start_time == Some(record.recovery_started_at)
+            });
+            if !open {
+                record.outcome = Some("executed".into());
+            }
+            *votes_cast.entry(record.verifier.clone()).or_default() += 1;
+            self.put_verifier_vote(&record)?;
+        }
+        for (id, count) in votes_cast {
+            if let Some(mut acc) = self.get_account(&id)? {
+                acc.votes_cast = count;
+                self.put_account(&acc)?;
+            }
+        }
+        self.put_meta(VERIFIER_VOTES_BUILT_KEY, b"1")?;
+        Ok(records.len())
+    }
+
+    /// Rebuild `timelocks_by_sender` and `timelocks_by_recipient` from every
+    /// stored lock. Returns the number of locks indexed.
+    pub(crate) fn backfill_timelock_parties(&self) -> Result<usize, ChronxError> {
+        self.timelocks_by_sender.clear().map_err(|e| ChronxError::Storage(e.to_string()))?;
+        self.timelocks_by_recipient.clear().map_err(|e| ChronxError::Storage(e.to_string()))?;
+        let locks = self.iter_all_timelocks()?;
+        for tlc in &locks {
+            let mut batch = self.batch();
+            self.index_timelock_parties(&mut batch, tlc);
+            batch.commit()?;
+        }
+        self.put_meta(PARTY_INDEX_BUILT_KEY, b"1")?;
+        Ok(locks.len())
+    }
+
+    /// Rebuild `verifier_registrations` from the accounts flagged
+    /// `is_verifier`, taking vote counts from `Account::votes_cast` and the
+    /// last vote from `verifier_vote_history`.
+    pub(crate) fn backfill_verifier_registrations(&self) -> Result<usize, ChronxError> {
+        self.verifier_registrations.clear().map_err(|e| ChronxError::Storage(e.to_string()))?;
+        let mut count = 0;
+        for account in self.iter_all_accounts()? {
+            if !account.is_verifier {
+                continue;
+            }
+            let last_vote_at = self.iter_verifier_votes(&account.account_id)?.first().map(|v| v.voted_at);
+            self.put_verifier_registration(&VerifierRegistration {
+                account_id: account.account_id.clone(),
+                stake: account.verifier_stake,
+                registered_at: account.created_at.unwrap_or(0),
+                last_vote_at,
+                total_votes: account.votes_cast,
+                slash_count: 0,
+            })?;
+            count += 1;
+        }
+        self.put_meta(VERIFIER_REGISTRATIONS_BUILT_KEY, b"1")?;
+        Ok(count)
+    }
+
     // ── Accounts ─────────────────────────────────────────────────────────────
 
     pub fn get_account(&self, id: &AccountId) -> Result<Option<Account>, ChronxError> {
@@ -787,69 +1707,315 @@ impl StateDb {
         }
     }
 
+    /// Store `account`, updating its leaf in the Merkle cache and the
+    /// latest state root so a write outside
+    /// [`StateEngine::apply`](crate::StateEngine::apply) leaves neither stale.
     pub fn put_account(&self, account: &Account) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_account_with_root(batch, account))
+    }
+
+    /// [`put_account`](Self::put_account) into `batch`. Every account write
+    /// outside a transaction's commit goes through this; the commit itself
+    /// uses [`stage_account`](Self::stage_account) and updates the root once
+    /// for all the accounts it touched.
+    pub fn stage_account_with_root<'a>(&'a self, batch: &mut WriteBatch<'a>, account: &Account) -> Result<(), ChronxError> {
+        let old_balance = self.get_account_staged(batch, &account.account_id)?.map_or(0, |a| a.balance);
+        self.stage_account(batch, account)?;
+        let root = self.stage_state_root_for_account(batch, &account.account_id, old_balance, account.balance)?;
+        self.stage_latest_state_root(batch, &root);
+        Ok(())
+    }
+
+    /// [`get_account`](Self::get_account) as of the end of `batch`.
+    pub fn get_account_staged(
+        &self,
+        batch: &WriteBatch<'_>,
+        id: &AccountId,
+    ) -> Result<Option<Account>, ChronxError> {
+        batch
+            .get(&self.accounts, id.as_bytes())?
+            .map(|b| bincode::deserialize(&b).map_err(|e| ChronxError::Serialization(e.to_string())))
+            .transpose()
+    }
+
+    /// Store `account` into `batch`, leaving the Merkle cache and state
+    /// root alone; see [`stage_account_with_root`](Self::stage_account_with_root).
+    pub fn stage_account<'a>(&'a self, batch: &mut WriteBatch<'a>, account: &Account) -> Result<(), ChronxError> {
         let bytes =
             bincode::serialize(account).map_err(|e| ChronxError::Serialization(e.to_string()))?;
-        self.accounts
-            .insert(account.account_id.as_bytes(), bytes)
-            .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        let previous = self.get_account_staged(batch, &account.account_id).ok().flatten();
+        batch.insert(&self.accounts, account.account_id.as_bytes(), bytes);
+        self.index_account_creation(batch, account);
+        self.index_locked_incoming(batch, previous.as_ref(), account);
         Ok(())
     }
 
-    pub fn account_exists(&self, id: &AccountId) -> bool {
-        self.accounts.contains_key(id.as_bytes()).unwrap_or(false)
+    /// Move the account's entry when its pending incoming amount changes;
+    /// accounts with nothing pending have no entry.
+    fn index_locked_incoming<'a>(&'a self, batch: &mut WriteBatch<'a>, previous: Option<&Account>, account: &Account) {
+        let old = previous.map_or(0, |a| a.total_locked_incoming_chronos);
+        let new = account.total_locked_incoming_chronos;
+        if previous.is_some() && old == new {
+            return;
+        }
+        if old > 0 {
+            batch.remove(&self.accounts_by_locked_incoming, locked_incoming_key(old, &account.account_id));
+        }
+        if new > 0 {
+            batch.insert(&self.accounts_by_locked_incoming, locked_incoming_key(new, &account.account_id), []);
+        }
     }
 
-    /// Return all accounts as `(AccountId, balance_chronos)` pairs.
-    pub fn get_all_accounts(&self) -> Result<Vec<(AccountId, u128)>, ChronxError> {
-        let mut result = Vec::new();
+    /// Up to `limit` accounts with the most KX in pending incoming locks,
+    /// largest first, as `(account, total_locked_incoming_chronos)`.
+    pub fn top_locked_incoming(&self, limit: usize) -> Result<Vec<(AccountId, u128)>, ChronxError> {
+        self.accounts_by_locked_incoming
+            .iter()
+            .keys()
+            .rev()
+            .take(limit)
+            .map(|key| {
+                let key = key.map_err(|e| ChronxError::Storage(e.to_string()))?;
+                let mut amount = [0u8; 16];
+                amount.copy_from_slice(&key[..16]);
+                let mut id = [0u8; 32];
+                id.copy_from_slice(&key[16..]);
+                Ok((AccountId::from_bytes(id), u128::from_be_bytes(amount)))
+            })
+            .collect()
+    }
+
+    /// Number of accounts with a recovery in progress.
+    pub fn count_active_recoveries(&self) -> Result<u64, ChronxError> {
+        let mut count = 0;
         for item in self.accounts.iter() {
-            let (key, value) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
+            let (_, value) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
             let acc: Account = bincode::deserialize(&value)
                 .map_err(|e| ChronxError::Serialization(e.to_string()))?;
-            let mut id_bytes = [0u8; 32];
-            if key.len() == 32 {
-                id_bytes.copy_from_slice(&key);
+            if acc.recovery_state.active {
+                count += 1;
             }
-            result.push((AccountId::from_bytes(id_bytes), acc.balance));
         }
-        Ok(result)
+        Ok(count)
     }
 
-    // ── Vertices ─────────────────────────────────────────────────────────────
-
-    pub fn get_vertex(&self, tx_id: &TxId) -> Result<Option<Vertex>, ChronxError> {
-        match self
-            .vertices
-            .get(tx_id.as_bytes())
-            .map_err(|e| ChronxError::Storage(e.to_string()))?
-        {
-            Some(bytes) => {
-                let v = bincode::deserialize(&bytes)
-                    .map_err(|e| ChronxError::Serialization(e.to_string()))?;
-                Ok(Some(v))
-            }
-            None => Ok(None),
+    /// `created_at` is set once, when the account is first written, so
+    /// entries are only ever added.
+    fn index_account_creation<'a>(&'a self, batch: &mut WriteBatch<'a>, account: &Account) {
+        if let Some(created_at) = account.created_at {
+            batch.insert(&self.accounts_by_creation_time, creation_index_key(created_at, &account.account_id), []);
         }
     }
 
-    pub fn put_vertex(&self, vertex: &Vertex) -> Result<(), ChronxError> {
-        let bytes =
-            bincode::serialize(vertex).map_err(|e| ChronxError::Serialization(e.to_string()))?;
-        self.vertices
-            .insert(vertex.tx_id().as_bytes(), bytes)
-            .map_err(|e| ChronxError::Storage(e.to_string()))?;
-        Ok(())
+    /// Creation times of every account that has one, oldest first.
+    pub fn account_creation_times(&self) -> Result<Vec<i64>, ChronxError> {
+        self.accounts_by_creation_time
+            .iter()
+            .keys()
+            .map(|key| {
+                let key = key.map_err(|e| ChronxError::Storage(e.to_string()))?;
+                let mut ts = [0u8; 8];
+                ts.copy_from_slice(&key[..8]);
+                Ok(i64::from_be_bytes(ts))
+            })
+            .collect()
     }
 
-    pub fn vertex_exists(&self, tx_id: &TxId) -> bool {
-        self.vertices
-            .contains_key(tx_id.as_bytes())
-            .unwrap_or(false)
+    pub fn account_exists(&self, id: &AccountId) -> bool {
+        self.accounts.contains_key(id.as_bytes()).unwrap_or(false)
     }
 
-    // ── Time-lock contracts ───────────────────────────────────────────────────
-
+    /// Return the account (if any) with an active recovery started by `initiator`.
+    pub fn find_active_recovery_by_initiator(
+        &self,
+        initiator: &AccountId,
+    ) -> Result<Option<AccountId>, ChronxError> {
+        for item in self.accounts.iter() {
+            let (_, value) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
+            let acc: Account = bincode::deserialize(&value)
+                .map_err(|e| ChronxError::Serialization(e.to_string()))?;
+            if acc.recovery_state.active
+                && acc.recovery_state.initiator.as_ref() == Some(initiator)
+            {
+                return Ok(Some(acc.account_id));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Every account record.
+    pub fn iter_all_accounts(&self) -> Result<Vec<Account>, ChronxError> {
+        self.accounts
+            .iter()
+            .map(|item| {
+                let (_, value) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
+                bincode::deserialize(&value).map_err(|e| ChronxError::Serialization(e.to_string()))
+            })
+            .collect()
+    }
+
+    /// Up to `limit` accounts in key order, starting after `cursor` (the last
+    /// account id seen). Returns the page and the cursor for the next one,
+    /// which is `None` once the scan reaches the end.
+    pub fn iter_accounts_paged(
+        &self,
+        cursor: Option<&AccountId>,
+        limit: usize,
+    ) -> Result<(Vec<Account>, Option<AccountId>), ChronxError> {
+        use std::ops::Bound::{Excluded, Unbounded};
+        let start = match cursor {
+            Some(c) => Excluded(c.as_bytes().to_vec()),
+            None => Unbounded,
+        };
+        let mut iter = self.accounts.range::<Vec<u8>, _>((start, Unbounded));
+        let mut page = Vec::with_capacity(limit);
+        for item in iter.by_ref().take(limit) {
+            let (_, value) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
+            let acc: Account = bincode::deserialize(&value)
+                .map_err(|e| ChronxError::Serialization(e.to_string()))?;
+            page.push(acc);
+        }
+        let next = match (page.last(), iter.next()) {
+            (Some(last), Some(_)) => Some(last.account_id.clone()),
+            _ => None,
+        };
+        Ok((page, next))
+    }
+
+    /// Like `iter_accounts_paged`, but only accounts with
+    /// `created_after < created_at < created_before` (either bound optional),
+    /// in creation order. Accounts without a `created_at` are never returned.
+    pub fn iter_accounts_created_paged(
+        &self,
+        created_after: Option<i64>,
+        created_before: Option<i64>,
+        cursor: Option<&AccountId>,
+        limit: usize,
+    ) -> Result<(Vec<Account>, Option<AccountId>), ChronxError> {
+        use std::ops::Bound::{Excluded, Unbounded};
+        let resume = match cursor {
+            Some(c) => self
+                .get_account(c)?
+                .and_then(|a| a.created_at)
+                .map(|ts| creation_index_key(ts, c)),
+            None => None,
+        };
+        let after = created_after.map(|ts| creation_index_key(ts, &AccountId::from_bytes([0xff; 32])));
+        let start = match (resume, after) {
+            (Some(a), Some(b)) => Excluded(a.max(b)),
+            (Some(key), None) | (None, Some(key)) => Excluded(key),
+            (None, None) => Unbounded,
+        };
+        let end = match created_before {
+            Some(before) => Excluded(creation_index_key(before, &AccountId::from_bytes([0; 32]))),
+            None => Unbounded,
+        };
+        let mut iter = self.accounts_by_creation_time.range::<Vec<u8>, _>((start, end));
+        let mut page = Vec::with_capacity(limit);
+        for item in iter.by_ref().take(limit) {
+            let (key, _) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
+            let mut id = [0u8; 32];
+            id.copy_from_slice(&key[8..]);
+            if let Some(acc) = self.get_account(&AccountId::from_bytes(id))? {
+                page.push(acc);
+            }
+        }
+        let next = match (page.last(), iter.next()) {
+            (Some(last), Some(_)) => Some(last.account_id.clone()),
+            _ => None,
+        };
+        Ok((page, next))
+    }
+
+    /// Accounts whose id bytes start with `prefix`, in key order.
+    pub fn find_accounts_by_prefix(&self, prefix: &[u8]) -> Result<Vec<Account>, ChronxError> {
+        self.accounts
+            .scan_prefix(prefix)
+            .map(|item| {
+                let (_, value) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
+                bincode::deserialize(&value).map_err(|e| ChronxError::Serialization(e.to_string()))
+            })
+            .collect()
+    }
+
+    /// Return all accounts as `(AccountId, balance_chronos)` pairs.
+    pub fn get_all_accounts(&self) -> Result<Vec<(AccountId, u128)>, ChronxError> {
+        self.get_all_accounts_staged(&self.batch())
+    }
+
+    /// [`get_all_accounts`](Self::get_all_accounts) as of the end of `batch`.
+    pub fn get_all_accounts_staged(&self, batch: &WriteBatch<'_>) -> Result<Vec<(AccountId, u128)>, ChronxError> {
+        let mut result = Vec::new();
+        for (key, value) in batch.scan_prefix(&self.accounts, &[])? {
+            let acc: Account = bincode::deserialize(&value)
+                .map_err(|e| ChronxError::Serialization(e.to_string()))?;
+            let mut id_bytes = [0u8; 32];
+            if key.len() == 32 {
+                id_bytes.copy_from_slice(&key);
+            }
+            result.push((AccountId::from_bytes(id_bytes), acc.balance));
+        }
+        Ok(result)
+    }
+
+    // ── Vertices ─────────────────────────────────────────────────────────────
+
+    pub fn get_vertex(&self, tx_id: &TxId) -> Result<Option<Vertex>, ChronxError> {
+        match self
+            .vertices
+            .get(tx_id.as_bytes())
+            .map_err(|e| ChronxError::Storage(e.to_string()))?
+        {
+            Some(bytes) => {
+                let v = bincode::deserialize(&bytes)
+                    .map_err(|e| ChronxError::Serialization(e.to_string()))?;
+                Ok(Some(v))
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn put_vertex(&self, vertex: &Vertex) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_vertex(batch, vertex))
+    }
+
+    /// [`put_vertex`](Self::put_vertex) into `batch`.
+    pub fn stage_vertex<'a>(&'a self, batch: &mut WriteBatch<'a>, vertex: &Vertex) -> Result<(), ChronxError> {
+        let bytes =
+            bincode::serialize(vertex).map_err(|e| ChronxError::Serialization(e.to_string()))?;
+        batch.insert(&self.vertices, vertex.tx_id().as_bytes(), bytes);
+        self.index_vertex_depth(batch, vertex);
+        Ok(())
+    }
+
+    fn index_vertex_depth<'a>(&'a self, batch: &mut WriteBatch<'a>, vertex: &Vertex) {
+        let mut key = vertex.depth.to_be_bytes().to_vec();
+        key.extend_from_slice(vertex.tx_id().as_bytes());
+        batch.insert(&self.vertices_by_depth, key, []);
+    }
+
+    /// Vertices at `from_depth` or deeper, in ascending depth order.
+    pub fn iter_vertices_from_depth(&self, from_depth: u64) -> Result<Vec<Vertex>, ChronxError> {
+        let mut result = Vec::new();
+        for item in self.vertices_by_depth.range(from_depth.to_be_bytes().to_vec()..) {
+            let (key, _) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
+            let mut id = [0u8; 32];
+            id.copy_from_slice(&key[8..]);
+            if let Some(v) = self.get_vertex(&TxId::from_bytes(id))? {
+                result.push(v);
+            }
+        }
+        Ok(result)
+    }
+
+    pub fn vertex_exists(&self, tx_id: &TxId) -> bool {
+        self.vertices
+            .contains_key(tx_id.as_bytes())
+            .unwrap_or(false)
+    }
+
+    // ── Time-lock contracts ───────────────────────────────────────────────────
+
     pub fn get_timelock(&self, id: &TxId) -> Result<Option<TimeLockContract>, ChronxError> {
         match self
             .timelocks
@@ -866,48 +2032,254 @@ impl StateDb {
     }
 
     pub fn put_timelock(&self, contract: &TimeLockContract) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_timelock(batch, contract))
+    }
+
+    /// [`get_timelock`](Self::get_timelock) as of the end of `batch`.
+    pub fn get_timelock_staged(
+        &self,
+        batch: &WriteBatch<'_>,
+        id: &TxId,
+    ) -> Result<Option<TimeLockContract>, ChronxError> {
+        batch
+            .get(&self.timelocks, id.as_bytes())?
+            .map(|b| bincode::deserialize(&b).map_err(|e| ChronxError::Serialization(e.to_string())))
+            .transpose()
+    }
+
+    /// [`put_timelock`](Self::put_timelock) into `batch`.
+    pub fn stage_timelock<'a>(
+        &'a self,
+        batch: &mut WriteBatch<'a>,
+        contract: &TimeLockContract,
+    ) -> Result<(), ChronxError> {
+        // Tags, privacy, status and unlock time can change after creation;
+        // drop the old index entries.
+        if let Some(old) = self.get_timelock_staged(batch, &contract.id)? {
+            for tag in old.tags.iter().flatten() {
+                batch.remove(&self.timelocks_by_tag, tag_index_key(tag, &contract.id));
+            }
+            batch.remove(&self.timelocks_by_unlock, unlock_index_key(old.unlock_at, &contract.id));
+            batch.remove(&self.timelocks_by_sender, party_index_key(&old.sender, &contract.id));
+            batch.remove(&self.timelocks_by_recipient, party_index_key(&old.recipient_account_id, &contract.id));
+        }
         let bytes =
             bincode::serialize(contract).map_err(|e| ChronxError::Serialization(e.to_string()))?;
-        self.timelocks
-            .insert(contract.id.as_bytes(), bytes)
-            .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        batch.insert(&self.timelocks, contract.id.as_bytes(), bytes);
+        self.index_timelock_tags(batch, contract);
+        self.index_timelock_search(batch, contract);
+        self.index_timelock_parties(batch, contract);
+        if matches!(contract.status, TimeLockStatus::Pending) {
+            self.index_timelock_unlock(batch, contract);
+        }
+        match contract.status {
+            TimeLockStatus::ForSale { ask_price, .. } => {
+                batch.insert(&self.timelocks_for_sale, contract.id.as_bytes(), ask_price.to_le_bytes())
+            }
+            _ => batch.remove(&self.timelocks_for_sale, contract.id.as_bytes()),
+        }
         Ok(())
     }
 
-    /// Return all time-lock contracts where `recipient_id` is the registered recipient.
-    pub fn iter_timelocks_for_recipient(
+    /// Locks listed for sale, in lock id order, skipping the first `offset`
+    /// and returning at most `limit`.
+    pub fn iter_timelocks_for_sale(
         &self,
-        recipient_id: &AccountId,
+        offset: usize,
+        limit: usize,
     ) -> Result<Vec<TimeLockContract>, ChronxError> {
         let mut result = Vec::new();
-        for item in self.timelocks.iter() {
-            let (_, bytes) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
-            let tlc: TimeLockContract = bincode::deserialize(&bytes)
-                .map_err(|e| ChronxError::Serialization(e.to_string()))?;
-            if tlc.recipient_account_id == *recipient_id {
+        for item in self.timelocks_for_sale.iter().skip(offset).take(limit) {
+            let (key, _) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
+            let bytes: [u8; 32] = key
+                .as_ref()
+                .try_into()
+                .map_err(|_| ChronxError::Serialization("malformed for-sale index entry".into()))?;
+            if let Some(tlc) = self.get_timelock(&TxId::from_bytes(bytes))? {
                 result.push(tlc);
             }
         }
         Ok(result)
     }
 
-    /// Return all time-lock contracts where `sender_id` is the originating sender.
-    pub fn iter_timelocks_for_sender(
+    fn index_timelock_search<'a>(&'a self, batch: &mut WriteBatch<'a>, contract: &TimeLockContract) {
+        if let Some(client_ref) = contract.client_ref {
+            batch.insert(&self.search_index, client_ref_search_key(&client_ref), contract.id.as_bytes());
+        }
+    }
+
+    /// The lock created with `client_ref`, if any.
+    pub fn get_timelock_by_client_ref(&self, client_ref: &[u8; 16]) -> Result<Option<TimeLockContract>, ChronxError> {
+        let Some(id) = self
+            .search_index
+            .get(client_ref_search_key(client_ref))
+            .map_err(|e| ChronxError::Storage(e.to_string()))?
+        else {
+            return Ok(None);
+        };
+        let bytes: [u8; 32] = id
+            .as_ref()
+            .try_into()
+            .map_err(|_| ChronxError::Serialization("malformed search index entry".into()))?;
+        self.get_timelock(&TxId::from_bytes(bytes))
+    }
+
+    fn index_timelock_parties<'a>(&'a self, batch: &mut WriteBatch<'a>, contract: &TimeLockContract) {
+        batch.insert(&self.timelocks_by_sender, party_index_key(&contract.sender, &contract.id), []);
+        batch.insert(&self.timelocks_by_recipient, party_index_key(&contract.recipient_account_id, &contract.id), []);
+    }
+
+    /// The locks listed under `account` in a sender or recipient index, in
+    /// lock id order.
+    fn timelocks_in_party_index(
         &self,
-        sender_id: &AccountId,
+        index: &sled::Tree,
+        account: &AccountId,
     ) -> Result<Vec<TimeLockContract>, ChronxError> {
         let mut result = Vec::new();
-        for item in self.timelocks.iter() {
-            let (_, bytes) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
-            let tlc: TimeLockContract = bincode::deserialize(&bytes)
-                .map_err(|e| ChronxError::Serialization(e.to_string()))?;
-            if tlc.sender == *sender_id {
+        for key in index.scan_prefix(account.as_bytes()).keys() {
+            let key = key.map_err(|e| ChronxError::Storage(e.to_string()))?;
+            let mut arr = [0u8; 32];
+            arr.copy_from_slice(&key[32..]);
+            if let Some(tlc) = self.get_timelock(&TxId::from_bytes(arr))? {
+                result.push(tlc);
+            }
+        }
+        Ok(result)
+    }
+
+    fn index_timelock_unlock<'a>(&'a self, batch: &mut WriteBatch<'a>, contract: &TimeLockContract) {
+        batch.insert(&self.timelocks_by_unlock, unlock_index_key(contract.unlock_at, &contract.id), []);
+    }
+
+    /// Pending locks with `from <= unlock_at < to`, earliest first.
+    pub fn iter_pending_timelocks_unlocking(
+        &self,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<TimeLockContract>, ChronxError> {
+        // Keys are raw big-endian, so negative times would sort last.
+        let from = from.max(0);
+        let mut result = Vec::new();
+        if to <= from {
+            return Ok(result);
+        }
+        for item in self.timelocks_by_unlock.range(from.to_be_bytes()..to.to_be_bytes()) {
+            let (key, _) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
+            let mut arr = [0u8; 32];
+            arr.copy_from_slice(&key[8..]);
+            if let Some(tlc) = self.get_timelock(&TxId::from_bytes(arr))? {
+                result.push(tlc);
+            }
+        }
+        Ok(result)
+    }
+
+    /// Record whether the recipient of lock `lock_id` has been notified.
+    /// An off-chain flag for the notification service; no transaction.
+    pub fn set_notification_sent(&self, lock_id: &TxId, sent: bool) -> Result<(), ChronxError> {
+        let mut tlc = self
+            .get_timelock(lock_id)?
+            .ok_or_else(|| ChronxError::TimeLockNotFound(lock_id.to_hex()))?;
+        tlc.notification_sent = sent;
+        self.put_timelock(&tlc)
+    }
+
+    /// Up to `limit` Pending locks whose recipient asked to be notified and
+    /// has not been yet, earliest unlock first.
+    pub fn iter_unnotified_timelocks(&self, limit: usize) -> Result<Vec<TimeLockContract>, ChronxError> {
+        let mut result = Vec::new();
+        for item in self.timelocks_by_unlock.iter() {
+            if result.len() >= limit {
+                break;
+            }
+            let (key, _) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
+            let mut arr = [0u8; 32];
+            arr.copy_from_slice(&key[8..]);
+            if let Some(tlc) = self.get_timelock(&TxId::from_bytes(arr))? {
+                if tlc.notify_recipient && !tlc.notification_sent {
+                    result.push(tlc);
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    fn index_timelock_tags<'a>(&'a self, batch: &mut WriteBatch<'a>, contract: &TimeLockContract) {
+        if contract.private {
+            return;
+        }
+        for tag in contract.tags.iter().flatten() {
+            batch.insert(&self.timelocks_by_tag, tag_index_key(tag, &contract.id), []);
+        }
+    }
+
+    /// Return every non-private time-lock carrying `tag` (case-insensitive).
+    pub fn iter_timelocks_by_tag(&self, tag: &str) -> Result<Vec<TimeLockContract>, ChronxError> {
+        let mut result = Vec::new();
+        for id in self.timelock_ids_by_tag(tag)? {
+            if let Some(tlc) = self.get_timelock(&id)? {
                 result.push(tlc);
             }
         }
         Ok(result)
     }
 
+    /// Ids of every non-private time-lock carrying `tag` (case-insensitive),
+    /// straight from the tag index.
+    pub fn timelock_ids_by_tag(&self, tag: &str) -> Result<Vec<TxId>, ChronxError> {
+        let prefix = normalize_tag(tag);
+        let mut result = Vec::new();
+        for item in self.timelocks_by_tag.scan_prefix(prefix.as_bytes()) {
+            let (key, _) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
+            // Skip longer tags that merely share this prefix.
+            if key.len() != prefix.len() + 32 {
+                continue;
+            }
+            let mut arr = [0u8; 32];
+            arr.copy_from_slice(&key[prefix.len()..]);
+            result.push(TxId::from_bytes(arr));
+        }
+        Ok(result)
+    }
+
+    /// Most-used tags across non-private locks as `(tag, lock_count)`,
+    /// highest count first (ties by tag name).
+    pub fn top_tags(&self, limit: usize) -> Result<Vec<(String, u64)>, ChronxError> {
+        let mut counts: std::collections::HashMap<Vec<u8>, u64> = std::collections::HashMap::new();
+        for item in self.timelocks_by_tag.iter() {
+            let (key, _) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
+            if key.len() > 32 {
+                *counts.entry(key[..key.len() - 32].to_vec()).or_default() += 1;
+            }
+        }
+        let mut tags: Vec<(String, u64)> = counts
+            .into_iter()
+            .map(|(k, n)| (String::from_utf8_lossy(&k).into_owned(), n))
+            .collect();
+        tags.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        tags.truncate(limit);
+        Ok(tags)
+    }
+
+    /// Return all time-lock contracts where `recipient_id` is the registered
+    /// recipient, in lock id order.
+    pub fn iter_timelocks_for_recipient(
+        &self,
+        recipient_id: &AccountId,
+    ) -> Result<Vec<TimeLockContract>, ChronxError> {
+        self.timelocks_in_party_index(&self.timelocks_by_recipient, recipient_id)
+    }
+
+    /// Return all time-lock contracts where `sender_id` is the originating
+    /// sender, in lock id order.
+    pub fn iter_timelocks_for_sender(
+        &self,
+        sender_id: &AccountId,
+    ) -> Result<Vec<TimeLockContract>, ChronxError> {
+        self.timelocks_in_party_index(&self.timelocks_by_sender, sender_id)
+    }
+
     /// Return every time-lock contract in the DB (no filter).
     pub fn iter_all_timelocks(&self) -> Result<Vec<TimeLockContract>, ChronxError> {
         let mut result = Vec::new();
@@ -920,6 +2292,20 @@ impl StateDb {
         Ok(result)
     }
 
+    /// Ids of the time-locks whose id bytes start with `prefix`, in key order.
+    pub fn find_lock_ids_by_prefix(&self, prefix: &[u8]) -> Result<Vec<TxId>, ChronxError> {
+        let mut result = Vec::new();
+        for key in self.timelocks.scan_prefix(prefix).keys() {
+            let key = key.map_err(|e| ChronxError::Storage(e.to_string()))?;
+            let bytes: [u8; 32] = key
+                .as_ref()
+                .try_into()
+                .map_err(|_| ChronxError::Serialization("malformed time-lock key".into()))?;
+            result.push(TxId::from_bytes(bytes));
+        }
+        Ok(result)
+    }
+
     /// Sum the `amount` field of all active (non-terminal) timelocks.
     /// Tolerates deserialization errors from schema evolution — skips bad entries.
     pub fn sum_active_lock_amounts(&self) -> u128 {
@@ -972,6 +2358,9 @@ impl StateDb {
     pub fn put_account_raw(&self, key: &[u8], value: &[u8]) -> Result<(), ChronxError> {
         self.accounts.insert(key, value)
             .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        self.meta
+            .remove(MERKLE_LEAF_COUNT_KEY)
+            .map_err(|e| ChronxError::Storage(e.to_string()))?;
         Ok(())
     }
 
@@ -1005,6 +2394,24 @@ impl StateDb {
         Ok(())
     }
 
+    /// Make `tx_id` a tip in place of `parents`, in `batch`.
+    pub fn stage_tip<'a>(&'a self, batch: &mut WriteBatch<'a>, tx_id: &TxId, parents: &[TxId]) {
+        for parent in parents {
+            batch.remove(&self.dag_tips, parent.as_bytes());
+        }
+        batch.insert(&self.dag_tips, tx_id.as_bytes(), []);
+    }
+
+    pub fn is_tip(&self, tx_id: &TxId) -> Result<bool, ChronxError> {
+        self.dag_tips
+            .contains_key(tx_id.as_bytes())
+            .map_err(|e| ChronxError::Storage(e.to_string()))
+    }
+
+    pub fn tip_count(&self) -> usize {
+        self.dag_tips.len()
+    }
+
     pub fn get_tips(&self) -> Result<Vec<TxId>, ChronxError> {
         let mut tips = Vec::new();
         for item in self.dag_tips.iter() {
@@ -1025,6 +2432,11 @@ impl StateDb {
         Ok(())
     }
 
+    /// [`put_meta`](Self::put_meta) into `batch`.
+    pub fn stage_meta<'a>(&'a self, batch: &mut WriteBatch<'a>, key: &str, value: &[u8]) {
+        batch.insert(&self.meta, key.as_bytes(), value);
+    }
+
     pub fn get_meta(&self, key: &str) -> Result<Option<Vec<u8>>, ChronxError> {
         self.meta
             .get(key.as_bytes())
@@ -1032,6 +2444,328 @@ impl StateDb {
             .map_err(|e| ChronxError::Storage(e.to_string()))
     }
 
+    /// [`get_meta`](Self::get_meta) as of the end of `batch`.
+    pub fn get_meta_staged(&self, batch: &WriteBatch<'_>, key: &str) -> Result<Option<Vec<u8>>, ChronxError> {
+        batch.get(&self.meta, key.as_bytes())
+    }
+
+    pub fn get_timeline_cache(&self) -> Result<Option<TimelineCache>, ChronxError> {
+        match self.get_meta(TIMELINE_CACHE_KEY)? {
+            Some(bytes) => bincode::deserialize(&bytes)
+                .map(Some)
+                .map_err(|e| ChronxError::Serialization(e.to_string())),
+            None => Ok(None),
+        }
+    }
+
+    pub fn put_timeline_cache(&self, cache: &TimelineCache) -> Result<(), ChronxError> {
+        let bytes = bincode::serialize(cache).map_err(|e| ChronxError::Serialization(e.to_string()))?;
+        self.put_meta(TIMELINE_CACHE_KEY, &bytes)
+    }
+
+    // ── Supply accounting ─────────────────────────────────────────────────────
+
+    /// Chronos removed from circulation with no sink to credit (slashed and
+    /// forfeited bonds, locks burned under `ExpiryPolicy::Burn`).
+    pub fn get_burned_chronos(&self) -> Result<u128, ChronxError> {
+        Ok(self
+            .get_meta(BURNED_CHRONOS_KEY)?
+            .and_then(|b| <[u8; 16]>::try_from(b.as_slice()).ok())
+            .map(u128::from_be_bytes)
+            .unwrap_or(0))
+    }
+
+    pub fn add_burned_chronos(&self, amount: u128) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_burned_chronos(batch, amount))
+    }
+
+    /// [`add_burned_chronos`](Self::add_burned_chronos) into `batch`.
+    pub fn stage_burned_chronos<'a>(&'a self, batch: &mut WriteBatch<'a>, amount: u128) -> Result<(), ChronxError> {
+        let total = batch
+            .get(&self.meta, BURNED_CHRONOS_KEY)?
+            .and_then(|b| <[u8; 16]>::try_from(b.as_slice()).ok())
+            .map_or(0, u128::from_be_bytes)
+            .saturating_add(amount);
+        self.stage_meta(batch, BURNED_CHRONOS_KEY, &total.to_be_bytes());
+        Ok(())
+    }
+
+    /// Account credited with every transaction's `fee_chronos`: the one set
+    /// under [`FEE_COLLECTOR_KEY`], else the genesis null address (all zero
+    /// bytes).
+    pub fn get_fee_collector(&self) -> Result<AccountId, ChronxError> {
+        Ok(self
+            .get_meta(FEE_COLLECTOR_KEY)?
+            .and_then(|b| AccountId::from_b58(&String::from_utf8_lossy(&b)).ok())
+            .unwrap_or_else(|| AccountId::from_bytes([0u8; 32])))
+    }
+
+    pub fn set_fee_collector(&self, account: &AccountId) -> Result<(), ChronxError> {
+        self.put_meta(FEE_COLLECTOR_KEY, account.to_b58().as_bytes())
+    }
+
+    /// Chronos charged per serialized action byte by `chronx_estimateFee`;
+    /// 0 if unset.
+    pub fn get_fee_rate_per_byte(&self) -> Result<u128, ChronxError> {
+        Ok(self
+            .get_meta(FEE_RATE_PER_BYTE_KEY)?
+            .and_then(|b| <[u8; 16]>::try_from(b.as_slice()).ok())
+            .map_or(0, u128::from_be_bytes))
+    }
+
+    pub fn set_fee_rate_per_byte(&self, rate: u128) -> Result<(), ChronxError> {
+        self.put_meta(FEE_RATE_PER_BYTE_KEY, &rate.to_be_bytes())
+    }
+
+    /// Recent PoW difficulty adjustments as `(depth, difficulty, timestamp)`,
+    /// oldest first; at most [`DIFFICULTY_HISTORY_LEN`].
+    pub fn get_difficulty_history(&self) -> Result<Vec<(u64, u8, i64)>, ChronxError> {
+        match self.get_meta(DIFFICULTY_HISTORY_KEY)? {
+            Some(b) => bincode::deserialize(&b).map_err(|e| ChronxError::Serialization(e.to_string())),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Append a difficulty adjustment, dropping the oldest beyond
+    /// [`DIFFICULTY_HISTORY_LEN`].
+    pub fn push_difficulty_adjustment(&self, depth: u64, difficulty: u8, at: i64) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_difficulty_adjustment(batch, depth, difficulty, at))
+    }
+
+    /// [`push_difficulty_adjustment`](Self::push_difficulty_adjustment) into `batch`.
+    pub fn stage_difficulty_adjustment<'a>(
+        &'a self,
+        batch: &mut WriteBatch<'a>,
+        depth: u64,
+        difficulty: u8,
+        at: i64,
+    ) -> Result<(), ChronxError> {
+        let mut history: Vec<(u64, u8, i64)> = match batch.get(&self.meta, DIFFICULTY_HISTORY_KEY)? {
+            Some(b) => bincode::deserialize(&b).map_err(|e| ChronxError::Serialization(e.to_string()))?,
+            None => Vec::new(),
+        };
+        history.push((depth, difficulty, at));
+        let excess = history.len().saturating_sub(DIFFICULTY_HISTORY_LEN);
+        history.drain(..excess);
+        let b = bincode::serialize(&history).map_err(|e| ChronxError::Serialization(e.to_string()))?;
+        self.stage_meta(batch, DIFFICULTY_HISTORY_KEY, &b);
+        Ok(())
+    }
+
+    // ── Feature flags ─────────────────────────────────────────────────────────
+
+    /// Stored feature flags, or `None` if governance has never set them.
+    pub fn get_feature_flags(&self) -> Result<Option<FeatureFlags>, ChronxError> {
+        match self
+            .governance_params
+            .get(FEATURE_FLAGS_KEY)
+            .map_err(|e| ChronxError::Storage(e.to_string()))?
+        {
+            Some(bytes) => serde_json::from_slice(&bytes)
+                .map(Some)
+                .map_err(|e| ChronxError::Serialization(e.to_string())),
+            None => Ok(None),
+        }
+    }
+
+    pub fn put_feature_flags(&self, flags: &FeatureFlags) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_feature_flags(batch, flags))
+    }
+
+    /// [`put_feature_flags`](Self::put_feature_flags) into `batch`.
+    pub fn stage_feature_flags<'a>(&'a self, batch: &mut WriteBatch<'a>, flags: &FeatureFlags) -> Result<(), ChronxError> {
+        let bytes = serde_json::to_vec(flags).map_err(|e| ChronxError::Serialization(e.to_string()))?;
+        batch.insert(&self.governance_params, FEATURE_FLAGS_KEY, bytes);
+        Ok(())
+    }
+
+    // ── Governance params ─────────────────────────────────────────────────────
+
+    /// Stored governance parameters, or `None` if none have been set.
+    pub fn get_governance_params(&self) -> Result<Option<GovernanceParams>, ChronxError> {
+        match self
+            .governance_params
+            .get(GOVERNANCE_PARAMS_KEY)
+            .map_err(|e| ChronxError::Storage(e.to_string()))?
+        {
+            Some(bytes) => serde_json::from_slice(&bytes)
+                .map(Some)
+                .map_err(|e| ChronxError::Serialization(e.to_string())),
+            None => Ok(None),
+        }
+    }
+
+    /// Minimum verifier stake: the value approved by a governance proposal,
+    /// else the governance-raised value, else `MIN_VERIFIER_STAKE_CHRONOS`.
+    pub fn verifier_min_stake(&self) -> u128 {
+        use chronx_core::constants::MIN_VERIFIER_STAKE_CHRONOS;
+        if let Some(approved) = self.get_governed_u128(GovernanceTarget::MinVerifierStake) {
+            return approved;
+        }
+        self.get_governance_params()
+            .ok()
+            .flatten()
+            .and_then(|p| p.min_verifier_stake_chronos)
+            .map_or(MIN_VERIFIER_STAKE_CHRONOS, |m| m.max(MIN_VERIFIER_STAKE_CHRONOS))
+    }
+
+    /// Value an approved governance proposal set for an integer `target`.
+    pub fn get_governed_u128(&self, target: GovernanceTarget) -> Option<u128> {
+        let bytes = self.get_meta(target.meta_key()).ok().flatten()?;
+        <[u8; 16]>::try_from(bytes.as_slice()).ok().map(u128::from_be_bytes)
+    }
+
+    /// Claim lane thresholds: the governance-approved set if any, else the
+    /// protocol defaults.
+    pub fn lane_thresholds(&self) -> LaneThresholds {
+        self.get_meta(GovernanceTarget::LaneThresholds.meta_key())
+            .ok()
+            .flatten()
+            .and_then(|b| bincode::deserialize(&b).ok())
+            .unwrap_or_else(LaneThresholds::default_thresholds)
+    }
+
+    /// Base PoW difficulty approved by a governance proposal, if any.
+    pub fn get_governed_pow_difficulty(&self) -> Option<u8> {
+        match self.get_meta(GovernanceTarget::PoWDifficulty.meta_key()).ok().flatten()?.as_slice() {
+            [d] => Some(*d),
+            _ => None,
+        }
+    }
+
+    pub fn put_governance_params(&self, params: &GovernanceParams) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_governance_params(batch, params))
+    }
+
+    /// [`put_governance_params`](Self::put_governance_params) into `batch`.
+    pub fn stage_governance_params<'a>(
+        &'a self,
+        batch: &mut WriteBatch<'a>,
+        params: &GovernanceParams,
+    ) -> Result<(), ChronxError> {
+        let bytes = serde_json::to_vec(params).map_err(|e| ChronxError::Serialization(e.to_string()))?;
+        batch.insert(&self.governance_params, GOVERNANCE_PARAMS_KEY, bytes);
+        Ok(())
+    }
+
+    // ── Health ────────────────────────────────────────────────────────────────
+
+    /// Round-trip a canary value through the `meta` tree to prove the database
+    /// is readable and writable.
+    pub fn ping(&self) -> Result<(), ChronxError> {
+        const KEY: &str = "ping_canary";
+        let canary = blake3::hash(b"ping");
+        self.put_meta(KEY, canary.as_bytes())?;
+        let read = self.get_meta(KEY)?;
+        self.meta
+            .remove(KEY.as_bytes())
+            .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        match read {
+            Some(v) if v == canary.as_bytes() => Ok(()),
+            Some(_) => Err(ChronxError::Storage("ping canary mismatch".into())),
+            None => Err(ChronxError::Storage("ping canary missing after write".into())),
+        }
+    }
+
+    /// Check cross-tree references: tips → vertices, lock senders → accounts,
+    /// claims → locks, and that at least one account exists.
+    pub fn validate_consistency(&self) -> Result<ConsistencyReport, ChronxError> {
+        let mut report = ConsistencyReport::default();
+
+        for tip in self.get_tips()? {
+            if !self.vertex_exists(&tip) {
+                report.errors.push(format!("tip {} has no vertex", tip));
+            }
+        }
+
+        for item in self.timelocks.iter() {
+            let (key, value) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
+            match bincode::deserialize::<TimeLockContract>(&value) {
+                Ok(tlc) => {
+                    if !self.account_exists(&tlc.sender) {
+                        report.errors.push(format!(
+                            "lock {} sender {} has no account",
+                            tlc.id, tlc.sender
+                        ));
+                    }
+                }
+                Err(e) => report
+                    .warnings
+                    .push(format!("undecodable lock {}: {e}", hex::encode(&key))),
+            }
+        }
+
+        for item in self.claims.iter() {
+            let (key, value) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
+            match bincode::deserialize::<ClaimState>(&value) {
+                Ok(cs) => {
+                    if !self.timelocks.contains_key(cs.lock_id.as_bytes()).unwrap_or(false) {
+                        report
+                            .errors
+                            .push(format!("claim for lock {} has no lock", cs.lock_id));
+                    }
+                }
+                Err(e) => report
+                    .warnings
+                    .push(format!("undecodable claim {}: {e}", hex::encode(&key))),
+            }
+        }
+
+        if self.count_accounts() == 0 {
+            report.errors.push("no accounts in database".into());
+        }
+
+        Ok(report)
+    }
+
+    /// Sum the amounts of every `Pending` lock and compare the total to
+    /// `expected_total`. Also counts claimed and cancelled locks. Walks the
+    /// whole timelock tree.
+    pub fn audit_lock_amounts(&self, expected_total: u128) -> Result<AuditReport, ChronxError> {
+        let mut report = AuditReport::default();
+        for item in self.timelocks.iter() {
+            let (_, bytes) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
+            let tlc: TimeLockContract = bincode::deserialize(&bytes)
+                .map_err(|e| ChronxError::Serialization(e.to_string()))?;
+            match tlc.status {
+                TimeLockStatus::Pending => {
+                    report.pending_count += 1;
+                    report.total_locked += tlc.amount;
+                }
+                TimeLockStatus::Claimed { .. } => report.claimed_count += 1,
+                TimeLockStatus::Cancelled { .. } => report.cancelled_count += 1,
+                _ => {}
+            }
+        }
+        report.discrepancy = report.total_locked as i128 - expected_total as i128;
+        Ok(report)
+    }
+
+    /// The `Pending` principal implied by the senders' books: the sum of every
+    /// account's `total_locked_outgoing_chronos`, less the amounts of open
+    /// locks that have moved past `Pending` (claims in progress, partial
+    /// releases, executor withdrawals). The expected value for
+    /// [`Self::audit_lock_amounts`].
+    pub fn expected_pending_lock_total(&self) -> Result<u128, ChronxError> {
+        let mut outgoing: u128 = 0;
+        for item in self.accounts.iter() {
+            let (_, value) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
+            let acc: Account = bincode::deserialize(&value)
+                .map_err(|e| ChronxError::Serialization(e.to_string()))?;
+            outgoing = outgoing.saturating_add(acc.total_locked_outgoing_chronos);
+        }
+        let mut past_pending: u128 = 0;
+        for item in self.timelocks.iter() {
+            let (_, bytes) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
+            let tlc: TimeLockContract = bincode::deserialize(&bytes)
+                .map_err(|e| ChronxError::Serialization(e.to_string()))?;
+            if tlc.status != TimeLockStatus::Pending && !tlc.status.is_terminal() {
+                past_pending = past_pending.saturating_add(tlc.amount);
+            }
+        }
+        Ok(outgoing.saturating_sub(past_pending))
+    }
+
     // ── State Root (Merkle tree) ─────────────────────────────────────────────
 
     /// Store the latest balance Merkle state root in meta.
@@ -1039,6 +2773,11 @@ impl StateDb {
         self.put_meta("latest_state_root", root)
     }
 
+    /// [`put_latest_state_root`](Self::put_latest_state_root) into `batch`.
+    pub fn stage_latest_state_root<'a>(&'a self, batch: &mut WriteBatch<'a>, root: &[u8; 32]) {
+        self.stage_meta(batch, "latest_state_root", root)
+    }
+
     /// Retrieve the latest balance Merkle state root.
     pub fn get_latest_state_root(&self) -> Result<Option<[u8; 32]>, ChronxError> {
         match self.get_meta("latest_state_root")? {
@@ -1051,22 +2790,202 @@ impl StateDb {
         }
     }
 
+    /// Record the DAG depth and timestamp the latest state root was computed at.
+    pub fn stage_latest_state_root_at<'a>(&'a self, batch: &mut WriteBatch<'a>, depth: u64, at: Timestamp) {
+        let mut value = depth.to_be_bytes().to_vec();
+        value.extend_from_slice(&at.to_be_bytes());
+        self.stage_meta(batch, LATEST_STATE_ROOT_AT_KEY, &value)
+    }
+
+    /// The `(depth, timestamp)` recorded by
+    /// [`stage_latest_state_root_at`](Self::stage_latest_state_root_at).
+    pub fn get_latest_state_root_at(&self) -> Result<Option<(u64, Timestamp)>, ChronxError> {
+        Ok(self.get_meta(LATEST_STATE_ROOT_AT_KEY)?.and_then(|bytes| {
+            let depth = u64::from_be_bytes(bytes.get(..8)?.try_into().ok()?);
+            let at = Timestamp::from_be_bytes(bytes.get(8..16)?.try_into().ok()?);
+            Some((depth, at))
+        }))
+    }
+
+    /// Balance Merkle root over every account, built from scratch.
+    ///
+    /// Leaves are `(account_id, balance)` hashed and sorted by account ID as
+    /// in [`BalanceMerkleTree`](chronx_core::merkle::BalanceMerkleTree), so
+    /// the root is the one `BalanceMerkleTree::from_accounts` gives.
+    pub fn compute_state_root(&self) -> Result<[u8; 32], ChronxError> {
+        use chronx_core::merkle::BalanceMerkleTree;
+
+        let leaves: Vec<[u8; 32]> = self
+            .merkle_leaves_staged(&self.batch())?
+            .iter()
+            .map(|(id, balance)| BalanceMerkleTree::compute_leaf(id, *balance))
+            .collect();
+        Ok(BalanceMerkleTree::compute_root(&leaves))
+    }
+
+    /// Every account's `(id, balance)` as of `batch`, in Merkle leaf order:
+    /// sorted by account ID.
+    fn merkle_leaves_staged(&self, batch: &WriteBatch<'_>) -> Result<Vec<(AccountId, u128)>, ChronxError> {
+        let mut leaves = self.get_all_accounts_staged(batch)?;
+        leaves.sort_by_key(|(id, _)| id.0);
+        Ok(leaves)
+    }
+
+    /// Move `id`'s leaf from `old_balance` to `new_balance` and rehash only
+    /// its path to the root, using the sibling hashes in `merkle_cache`.
+    /// Returns the new root.
+    ///
+    /// The cache is rebuilt from every account instead when it is missing,
+    /// the cached leaf matches neither balance, or `id` is not yet a leaf:
+    /// a new leaf shifts every later account's position along.
+    pub fn update_state_root_for_account(
+        &self,
+        id: &AccountId,
+        old_balance: u128,
+        new_balance: u128,
+    ) -> Result<[u8; 32], ChronxError> {
+        let mut root = [0u8; 32];
+        self.write_atomic(|batch| {
+            root = self.stage_state_root_for_account(batch, id, old_balance, new_balance)?;
+            Ok(())
+        })?;
+        Ok(root)
+    }
+
+    /// [`update_state_root_for_account`](Self::update_state_root_for_account)
+    /// into `batch`, reading accounts and cached nodes as of `batch`.
+    pub fn stage_state_root_for_account<'a>(
+        &'a self,
+        batch: &mut WriteBatch<'a>,
+        id: &AccountId,
+        old_balance: u128,
+        new_balance: u128,
+    ) -> Result<[u8; 32], ChronxError> {
+        use chronx_core::merkle::BalanceMerkleTree;
+
+        let leaf_count = batch
+            .get(&self.meta, MERKLE_LEAF_COUNT_KEY)?
+            .and_then(|b| Some(u64::from_be_bytes(b.as_slice().try_into().ok()?)));
+        let position = batch
+            .get(&self.merkle_leaf_index, id.as_bytes())?
+            .and_then(|b| Some(u64::from_be_bytes(b.as_slice().try_into().ok()?)));
+        let (Some(leaf_count), Some(mut position)) = (leaf_count, position) else {
+            return self.stage_merkle_rebuild(batch);
+        };
+        let new_leaf = BalanceMerkleTree::compute_leaf(id, new_balance);
+        let cached = self.merkle_node_staged(batch, 0, position)?;
+        if cached == Some(new_leaf) {
+            return self.merkle_root_staged(batch, leaf_count);
+        }
+        if cached != Some(BalanceMerkleTree::compute_leaf(id, old_balance)) {
+            return self.stage_merkle_rebuild(batch);
+        }
+
+        let mut node = new_leaf;
+        let mut level = 0u8;
+        let mut width = leaf_count;
+        batch.insert(&self.merkle_cache, merkle_cache_key(level, position), node);
+        while width > 1 {
+            let sibling = position ^ 1;
+            if sibling < width {
+                let Some(sibling_hash) = self.merkle_node_staged(batch, level, sibling)? else {
+                    return self.stage_merkle_rebuild(batch);
+                };
+                node = if position % 2 == 0 {
+                    BalanceMerkleTree::compute_node(&node, &sibling_hash)
+                } else {
+                    BalanceMerkleTree::compute_node(&sibling_hash, &node)
+                };
+            }
+            level += 1;
+            position /= 2;
+            width = width.div_ceil(2);
+            batch.insert(&self.merkle_cache, merkle_cache_key(level, position), node);
+        }
+        Ok(node)
+    }
+
+    /// Rewrite `merkle_cache` and `merkle_leaf_index` from every account as
+    /// of `batch`, in account ID order, and return the root.
+    fn stage_merkle_rebuild<'a>(&'a self, batch: &mut WriteBatch<'a>) -> Result<[u8; 32], ChronxError> {
+        use chronx_core::merkle::BalanceMerkleTree;
+
+        let accounts = self.merkle_leaves_staged(batch)?;
+        let mut nodes: Vec<[u8; 32]> = accounts
+            .iter()
+            .map(|(id, balance)| BalanceMerkleTree::compute_leaf(id, *balance))
+            .collect();
+        for (position, (id, _)) in accounts.iter().enumerate() {
+            batch.insert(&self.merkle_leaf_index, id.as_bytes(), (position as u64).to_be_bytes());
+        }
+        batch.insert(&self.meta, MERKLE_LEAF_COUNT_KEY, (nodes.len() as u64).to_be_bytes());
+        if nodes.is_empty() {
+            return Ok([0u8; 32]);
+        }
+        let mut level = 0u8;
+        loop {
+            for (position, node) in nodes.iter().enumerate() {
+                batch.insert(&self.merkle_cache, merkle_cache_key(level, position as u64), node);
+            }
+            if nodes.len() == 1 {
+                return Ok(nodes[0]);
+            }
+            nodes = nodes
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => BalanceMerkleTree::compute_node(left, right),
+                    [odd] => *odd,
+                    _ => unreachable!("chunks(2) yields one or two nodes"),
+                })
+                .collect();
+            level += 1;
+        }
+    }
+
+    fn merkle_node_staged(&self, batch: &WriteBatch<'_>, level: u8, position: u64) -> Result<Option<[u8; 32]>, ChronxError> {
+        Ok(batch
+            .get(&self.merkle_cache, merkle_cache_key(level, position))?
+            .and_then(|b| b.as_slice().try_into().ok()))
+    }
+
+    /// The cached root of a tree with `leaf_count` leaves.
+    fn merkle_root_staged(&self, batch: &WriteBatch<'_>, leaf_count: u64) -> Result<[u8; 32], ChronxError> {
+        let mut level = 0u8;
+        let mut width = leaf_count;
+        while width > 1 {
+            level += 1;
+            width = width.div_ceil(2);
+        }
+        match self.merkle_node_staged(batch, level, 0)? {
+            Some(root) => Ok(root),
+            None if leaf_count == 0 => Ok([0u8; 32]),
+            None => Err(ChronxError::Storage("merkle_cache is missing its root".into())),
+        }
+    }
+
     // ── Authority Grants ─────────────────────────────────────────────────────
 
     /// Save an authority grant keyed by its vertex/tx ID.
     pub fn save_authority_grant(&self, grant_id: &[u8; 32], data: &[u8]) -> Result<(), ChronxError> {
-        self.authority_grants.insert(grant_id.as_ref(), data)
-            .map_err(|e| ChronxError::Storage(e.to_string()))?;
-        Ok(())
+        self.write_atomic(|batch| {
+            self.stage_authority_grant(batch, grant_id, data);
+            Ok(())
+        })
+    }
+
+    /// [`save_authority_grant`](Self::save_authority_grant) into `batch`.
+    pub fn stage_authority_grant<'a>(&'a self, batch: &mut WriteBatch<'a>, grant_id: &[u8; 32], data: &[u8]) {
+        batch.insert(&self.authority_grants, grant_id, data);
     }
 
     /// Get an authority grant by its vertex/tx ID.
-    pub fn get_authority_grant(&self, grant_id: &[u8; 32]) -> Result<Option<Vec<u8>>, ChronxError> {
-        match self.authority_grants.get(grant_id.as_ref()) {
-            Ok(Some(bytes)) => Ok(Some(bytes.to_vec())),
-            Ok(None) => Ok(None),
-            Err(e) => Err(ChronxError::Storage(e.to_string())),
-        }
+    pub fn get_authority_grant(&self, grant_id: &[u8; 32]) -> Result<Option<Vec<u8>>, ChronxError> {
+        self.get_authority_grant_staged(&self.batch(), grant_id)
+    }
+
+    /// [`get_authority_grant`](Self::get_authority_grant) as of the end of `batch`.
+    pub fn get_authority_grant_staged(&self, batch: &WriteBatch<'_>, grant_id: &[u8; 32]) -> Result<Option<Vec<u8>>, ChronxError> {
+        batch.get(&self.authority_grants, grant_id)
     }
 
     /// Iterate all authority grants.
@@ -1076,6 +2995,11 @@ impl StateDb {
         })
     }
 
+    /// [`iter_authority_grants`](Self::iter_authority_grants) as of the end of `batch`.
+    pub fn iter_authority_grants_staged(&self, batch: &WriteBatch<'_>) -> Result<crate::batch::Entries, ChronxError> {
+        batch.scan_prefix(&self.authority_grants, &[])
+    }
+
     /// Flush all pending writes to disk.
     pub fn flush(&self) -> Result<(), ChronxError> {
         self._db
@@ -1084,6 +3008,202 @@ impl StateDb {
         Ok(())
     }
 
+    /// Start a batch of writes that [`WriteBatch::commit`] applies in one
+    /// transaction. The `stage_*` methods add to it.
+    pub fn batch(&self) -> WriteBatch<'_> {
+        #[cfg(test)]
+        let fail_on_write = *self.fail_on_write.lock().unwrap_or_else(|e| e.into_inner());
+        #[cfg(not(test))]
+        let fail_on_write = None;
+        WriteBatch::new(fail_on_write)
+    }
+
+    /// Every entry of every tree, by tree name; for comparing whole-database
+    /// state in tests.
+    #[cfg(test)]
+    pub(crate) fn snapshot(&self) -> std::collections::BTreeMap<Vec<u8>, crate::batch::Entries> {
+        self._db
+            .tree_names()
+            .into_iter()
+            .map(|name| {
+                let tree = self._db.open_tree(&name).expect("open tree");
+                let entries = tree
+                    .iter()
+                    .map(|item| item.map(|(k, v)| (k.to_vec(), v.to_vec())).expect("read entry"))
+                    .collect();
+                (name.to_vec(), entries)
+            })
+            .collect()
+    }
+
+    /// Apply the writes made by `stage` atomically, on their own.
+    fn write_atomic<'a>(
+        &'a self,
+        stage: impl FnOnce(&mut WriteBatch<'a>) -> Result<(), ChronxError>,
+    ) -> Result<(), ChronxError> {
+        let mut batch = self.batch();
+        stage(&mut batch)?;
+        batch.commit()
+    }
+
+    /// Directory the database was opened from.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Bytes the database occupies on disk, as reported by sled.
+    pub fn size_on_disk(&self) -> Result<u64, ChronxError> {
+        self._db
+            .size_on_disk()
+            .map_err(|e| ChronxError::Storage(e.to_string()))
+    }
+
+    /// Names of every tree in the database, including sled's default tree
+    /// and any this version does not open.
+    pub fn list_trees(&self) -> Result<Vec<String>, ChronxError> {
+        Ok(self
+            ._db
+            .tree_names()
+            .into_iter()
+            .map(|name| String::from_utf8_lossy(&name).into_owned())
+            .collect())
+    }
+
+    /// Entry count and approximate size of every tree. Reads every entry,
+    /// so this is for operator diagnostics, not regular use.
+    pub fn tree_diagnostics(&self) -> Result<Vec<TreeDiagnostic>, ChronxError> {
+        let mut out = Vec::new();
+        for name in self._db.tree_names() {
+            let tree = self._db.open_tree(&name).map_err(|e| ChronxError::Storage(e.to_string()))?;
+            let mut entry_count = 0u64;
+            let mut approx_size_bytes = 0u64;
+            for item in tree.iter() {
+                let (k, v) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
+                entry_count += 1;
+                approx_size_bytes += (k.len() + v.len()) as u64;
+            }
+            let name = String::from_utf8_lossy(&name).into_owned();
+            let known = KNOWN_TREES.contains(&name.as_str());
+            out.push(TreeDiagnostic { name, entry_count, approx_size_bytes, known });
+        }
+        Ok(out)
+    }
+
+    /// Flush, then copy the whole database directory to `dest`.
+    pub fn create_checkpoint(&self, dest: &Path) -> Result<(), ChronxError> {
+        self.flush()?;
+        if dest.exists() {
+            return Err(ChronxError::Storage(format!(
+                "checkpoint {} already exists",
+                dest.display()
+            )));
+        }
+        crate::checkpoint::copy_dir(&self.path, dest)
+    }
+
+    /// Flush, then copy the database to `<data_dir>/snapshots/<snapshot_id>/`
+    /// with a `snapshot_meta.json` recording its tips and counts. The copy is
+    /// assembled under a hidden name and renamed into place, so a listed
+    /// snapshot is always complete. Callers must hold off writes until this
+    /// returns or the metadata may not match the files.
+    pub fn take_snapshot(
+        &self,
+        snapshot_id: u64,
+        data_dir: &Path,
+    ) -> Result<std::path::PathBuf, ChronxError> {
+        use crate::snapshot::{copy_db_files, snapshot_path, write_snapshot_meta, SnapshotMeta};
+
+        self.flush()?;
+        let dest = snapshot_path(data_dir, snapshot_id);
+        if dest.exists() {
+            return Err(ChronxError::Storage(format!("snapshot {} already exists", dest.display())));
+        }
+        let mut tip_tx_ids: Vec<String> = self.get_tips()?.iter().map(TxId::to_hex).collect();
+        tip_tx_ids.sort();
+        let meta = SnapshotMeta {
+            snapshot_id,
+            created_at: crate::checkpoint::unix_now(),
+            vertex_count: self.count_vertices(),
+            account_count: self.count_accounts(),
+            tip_tx_ids,
+        };
+
+        let partial = dest.with_file_name(format!(".{snapshot_id}.partial"));
+        if partial.exists() {
+            std::fs::remove_dir_all(&partial).map_err(|e| ChronxError::Storage(e.to_string()))?;
+        }
+        copy_db_files(&self.path, &partial)?;
+        write_snapshot_meta(&partial, &meta)?;
+        std::fs::rename(&partial, &dest).map_err(|e| ChronxError::Storage(e.to_string()))?;
+        Ok(dest)
+    }
+
+    /// Copy the snapshot at `snapshot_path` into `target_dir` and open it,
+    /// failing unless the opened database has the vertex count, account
+    /// count and tips its metadata records. An occupied `target_dir` is moved
+    /// aside to `<target_dir>.pre-restore-<unix_ts>` rather than deleted, and
+    /// its snapshots are carried over to the restored directory.
+    pub fn restore_from_snapshot(snapshot_path: &Path, target_dir: &Path) -> Result<Self, ChronxError> {
+        use crate::snapshot::{copy_db_files, read_snapshot_meta, sibling, SNAPSHOTS_DIR};
+        let io_err = |e: std::io::Error| ChronxError::Storage(e.to_string());
+
+        let meta = read_snapshot_meta(snapshot_path)?;
+        let staging = sibling(target_dir, ".restoring");
+        if staging.exists() {
+            std::fs::remove_dir_all(&staging).map_err(io_err)?;
+        }
+        copy_db_files(snapshot_path, &staging)?;
+
+        // Check the copy before it replaces anything.
+        let (vertex_count, account_count, mut tips) = {
+            let db = Self::open(&staging)?;
+            let tips: Vec<String> = db.get_tips()?.iter().map(TxId::to_hex).collect();
+            (db.count_vertices(), db.count_accounts(), tips)
+        };
+        tips.sort();
+        let mut expected = meta.tip_tx_ids.clone();
+        expected.sort();
+        if vertex_count != meta.vertex_count || account_count != meta.account_count || tips != expected {
+            let _ = std::fs::remove_dir_all(&staging);
+            return Err(ChronxError::Storage(format!(
+                "snapshot {} does not match its metadata: {vertex_count} vertices, \
+                 {account_count} accounts, {} tips (metadata says {}, {}, {})",
+                snapshot_path.display(),
+                tips.len(),
+                meta.vertex_count,
+                meta.account_count,
+                expected.len()
+            )));
+        }
+
+        let occupied = std::fs::read_dir(target_dir).map(|mut d| d.next().is_some()).unwrap_or(false);
+        if occupied {
+            let aside = sibling(target_dir, &format!(".pre-restore-{}", crate::checkpoint::unix_now()));
+            std::fs::rename(target_dir, &aside).map_err(io_err)?;
+            if aside.join(SNAPSHOTS_DIR).is_dir() {
+                std::fs::rename(aside.join(SNAPSHOTS_DIR), staging.join(SNAPSHOTS_DIR)).map_err(io_err)?;
+            }
+        } else if target_dir.exists() {
+            std::fs::remove_dir(target_dir).map_err(io_err)?;
+        }
+        std::fs::rename(&staging, target_dir).map_err(io_err)?;
+        Self::open(target_dir)
+    }
+
+    /// Copy every tree into a new database at `dest`, which must not exist,
+    /// and return the copy's size on disk. The copy holds only live entries,
+    /// so it leaves behind the space sled still keeps for deleted and
+    /// overwritten ones.
+    pub fn export_compacted(&self, dest: &Path) -> Result<u64, ChronxError> {
+        if dest.exists() {
+            return Err(ChronxError::Storage(format!("{} already exists", dest.display())));
+        }
+        let copy = sled::open(dest).map_err(|e| ChronxError::Storage(e.to_string()))?;
+        copy.import(self._db.export());
+        copy.flush().map_err(|e| ChronxError::Storage(e.to_string()))?;
+        copy.size_on_disk().map_err(|e| ChronxError::Storage(e.to_string()))
+    }
+
     // ── V2 Claims: Provider registry ─────────────────────────────────────────
 
     pub fn get_provider(&self, id: &AccountId) -> Result<Option<ProviderRecord>, ChronxError> {
@@ -1099,14 +3219,50 @@ impl StateDb {
         }
     }
 
+    /// Store a provider record and refresh the cached jurisdiction set, since
+    /// registration and revocation both come through here.
     pub fn put_provider(&self, p: &ProviderRecord) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_provider(batch, p))
+    }
+
+    /// [`put_provider`](Self::put_provider) into `batch`.
+    pub fn stage_provider<'a>(&'a self, batch: &mut WriteBatch<'a>, p: &ProviderRecord) -> Result<(), ChronxError> {
         let b = bincode::serialize(p).map_err(|e| ChronxError::Serialization(e.to_string()))?;
-        self.providers
-            .insert(p.provider_id.as_bytes(), b)
-            .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        batch.insert(&self.providers, p.provider_id.as_bytes(), b);
+        let mut covered = HashSet::new();
+        for (_, b) in batch.scan_prefix(&self.providers, &[])? {
+            let provider: ProviderRecord =
+                bincode::deserialize(&b).map_err(|e| ChronxError::Serialization(e.to_string()))?;
+            if provider.status == ProviderStatus::Active {
+                covered.extend(provider.jurisdictions);
+            }
+        }
+        let bytes = bincode::serialize(&covered).map_err(|e| ChronxError::Serialization(e.to_string()))?;
+        self.stage_meta(batch, JURISDICTIONS_COVERED_KEY, &bytes);
         Ok(())
     }
 
+    /// Jurisdiction codes covered by at least one active provider. Read from
+    /// the `meta` cache, which is rebuilt from `providers` if absent.
+    pub fn get_jurisdictions_covered(&self) -> Result<HashSet<String>, ChronxError> {
+        match self.get_meta(JURISDICTIONS_COVERED_KEY)? {
+            Some(bytes) => bincode::deserialize(&bytes).map_err(|e| ChronxError::Serialization(e.to_string())),
+            None => self.refresh_jurisdictions_covered(),
+        }
+    }
+
+    fn refresh_jurisdictions_covered(&self) -> Result<HashSet<String>, ChronxError> {
+        let covered: HashSet<String> = self
+            .iter_providers()?
+            .into_iter()
+            .filter(|p| p.status == ProviderStatus::Active)
+            .flat_map(|p| p.jurisdictions)
+            .collect();
+        let bytes = bincode::serialize(&covered).map_err(|e| ChronxError::Serialization(e.to_string()))?;
+        self.put_meta(JURISDICTIONS_COVERED_KEY, &bytes)?;
+        Ok(covered)
+    }
+
     pub fn iter_providers(&self) -> Result<Vec<ProviderRecord>, ChronxError> {
         let mut out = Vec::new();
         for item in self.providers.iter() {
@@ -1135,10 +3291,13 @@ impl StateDb {
     }
 
     pub fn put_schema(&self, s: &CertificateSchema) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_schema(batch, s))
+    }
+
+    /// [`put_schema`](Self::put_schema) into `batch`.
+    pub fn stage_schema<'a>(&'a self, batch: &mut WriteBatch<'a>, s: &CertificateSchema) -> Result<(), ChronxError> {
         let b = bincode::serialize(s).map_err(|e| ChronxError::Serialization(e.to_string()))?;
-        self.schemas
-            .insert(s.schema_id.to_be_bytes(), b)
-            .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        batch.insert(&self.schemas, s.schema_id.to_be_bytes(), b);
         Ok(())
     }
 
@@ -1155,16 +3314,26 @@ impl StateDb {
 
     /// Allocate the next sequential schema ID (stored in meta tree).
     pub fn next_schema_id(&self) -> Result<u64, ChronxError> {
+        let mut id = 0;
+        self.write_atomic(|batch| {
+            id = self.stage_next_schema_id(batch)?;
+            Ok(())
+        })?;
+        Ok(id)
+    }
+
+    /// [`next_schema_id`](Self::next_schema_id) into `batch`.
+    pub fn stage_next_schema_id<'a>(&'a self, batch: &mut WriteBatch<'a>) -> Result<u64, ChronxError> {
         let key = "next_schema_id";
         let current = self
-            .get_meta(key)?
+            .get_meta_staged(batch, key)?
             .map(|b| {
                 let mut arr = [0u8; 8];
                 arr.copy_from_slice(&b[..8]);
                 u64::from_be_bytes(arr)
             })
             .unwrap_or(1);
-        self.put_meta(key, &(current + 1).to_be_bytes())?;
+        self.stage_meta(batch, key, &(current + 1).to_be_bytes());
         Ok(current)
     }
 
@@ -1184,13 +3353,27 @@ impl StateDb {
     }
 
     pub fn put_claim(&self, cs: &ClaimState) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_claim(batch, cs))
+    }
+
+    /// [`put_claim`](Self::put_claim) into `batch`.
+    pub fn stage_claim<'a>(&'a self, batch: &mut WriteBatch<'a>, cs: &ClaimState) -> Result<(), ChronxError> {
         let b = bincode::serialize(cs).map_err(|e| ChronxError::Serialization(e.to_string()))?;
-        self.claims
-            .insert(cs.lock_id.as_bytes(), b)
-            .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        batch.insert(&self.claims, cs.lock_id.as_bytes(), b);
         Ok(())
     }
 
+    /// Every claim record.
+    pub fn iter_all_claims(&self) -> Result<Vec<ClaimState>, ChronxError> {
+        self.claims
+            .iter()
+            .map(|item| {
+                let (_, value) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
+                bincode::deserialize(&value).map_err(|e| ChronxError::Serialization(e.to_string()))
+            })
+            .collect()
+    }
+
     // ── V2 Claims: Oracle ─────────────────────────────────────────────────────
 
     pub fn get_oracle_snapshot(&self, pair: &str) -> Result<Option<OracleSnapshot>, ChronxError> {
@@ -1206,11 +3389,25 @@ impl StateDb {
         }
     }
 
+    /// Every stored oracle snapshot, ordered by pair.
+    pub fn iter_oracle_snapshots(&self) -> Result<Vec<OracleSnapshot>, ChronxError> {
+        self.oracle_snapshots
+            .iter()
+            .map(|item| {
+                let (_, value) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
+                bincode::deserialize(&value).map_err(|e| ChronxError::Serialization(e.to_string()))
+            })
+            .collect()
+    }
+
     pub fn put_oracle_snapshot(&self, snap: &OracleSnapshot) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_oracle_snapshot(batch, snap))
+    }
+
+    /// [`put_oracle_snapshot`](Self::put_oracle_snapshot) into `batch`.
+    pub fn stage_oracle_snapshot<'a>(&'a self, batch: &mut WriteBatch<'a>, snap: &OracleSnapshot) -> Result<(), ChronxError> {
         let b = bincode::serialize(snap).map_err(|e| ChronxError::Serialization(e.to_string()))?;
-        self.oracle_snapshots
-            .insert(snap.pair.as_bytes(), b)
-            .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        batch.insert(&self.oracle_snapshots, snap.pair.as_bytes(), b);
         Ok(())
     }
 
@@ -1218,13 +3415,20 @@ impl StateDb {
     pub fn put_oracle_submission(
         &self,
         sub: &chronx_core::claims::OracleSubmission,
+    ) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_oracle_submission(batch, sub))
+    }
+
+    /// [`put_oracle_submission`](Self::put_oracle_submission) into `batch`.
+    pub fn stage_oracle_submission<'a>(
+        &'a self,
+        batch: &mut WriteBatch<'a>,
+        sub: &chronx_core::claims::OracleSubmission,
     ) -> Result<(), ChronxError> {
         let mut key = sub.pair.as_bytes().to_vec();
         key.extend_from_slice(sub.submitter.as_bytes());
         let b = bincode::serialize(sub).map_err(|e| ChronxError::Serialization(e.to_string()))?;
-        self.oracle_submissions
-            .insert(key, b)
-            .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        batch.insert(&self.oracle_submissions, key, b);
         Ok(())
     }
 
@@ -1239,6 +3443,11 @@ impl StateDb {
         Ok(())
     }
 
+    /// [`put_email_claim_hash`](Self::put_email_claim_hash) into `batch`.
+    pub fn stage_email_claim_hash<'a>(&'a self, batch: &mut WriteBatch<'a>, lock_id: &TxId, hash: [u8; 32]) {
+        batch.insert(&self.email_claim_hashes, lock_id.as_bytes(), hash);
+    }
+
     /// Retrieve the BLAKE3 claim-secret hash for an email lock.
     /// Returns None if this lock has no claim secret (i.e. it is not an email lock).
     pub fn get_email_claim_hash(&self, lock_id: &TxId) -> Result<Option<[u8; 32]>, ChronxError> {
@@ -1278,17 +3487,90 @@ impl StateDb {
         &self,
         pair: &str,
     ) -> Result<Vec<chronx_core::claims::OracleSubmission>, ChronxError> {
-        let prefix = pair.as_bytes();
+        self.iter_oracle_submissions_for_pair_staged(&self.batch(), pair)
+    }
+
+    /// [`iter_oracle_submissions_for_pair`](Self::iter_oracle_submissions_for_pair)
+    /// as of the end of `batch`.
+    pub fn iter_oracle_submissions_for_pair_staged(
+        &self,
+        batch: &WriteBatch<'_>,
+        pair: &str,
+    ) -> Result<Vec<chronx_core::claims::OracleSubmission>, ChronxError> {
+        batch
+            .scan_prefix(&self.oracle_submissions, pair.as_bytes())?
+            .into_iter()
+            .map(|(_, b)| bincode::deserialize(&b).map_err(|e| ChronxError::Serialization(e.to_string())))
+            .collect()
+    }
+
+    /// Append one point to `pair`'s price history.
+    pub fn put_oracle_history(
+        &self,
+        pair: &str,
+        entry: &OracleHistoryEntry,
+    ) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_oracle_history(batch, pair, entry))
+    }
+
+    /// [`put_oracle_history`](Self::put_oracle_history) into `batch`.
+    pub fn stage_oracle_history<'a>(
+        &'a self,
+        batch: &mut WriteBatch<'a>,
+        pair: &str,
+        entry: &OracleHistoryEntry,
+    ) -> Result<(), ChronxError> {
+        let mut key = pair.as_bytes().to_vec();
+        key.extend_from_slice(&entry.updated_at.to_be_bytes());
+        let b = bincode::serialize(entry).map_err(|e| ChronxError::Serialization(e.to_string()))?;
+        batch.insert(&self.oracle_history, key, b);
+        Ok(())
+    }
+
+    /// `pair`'s history points with `from_ts <= updated_at <= to_ts`, oldest first.
+    pub fn iter_oracle_history(
+        &self,
+        pair: &str,
+        from_ts: i64,
+        to_ts: i64,
+    ) -> Result<Vec<OracleHistoryEntry>, ChronxError> {
+        let key_at = |ts: i64| {
+            let mut key = pair.as_bytes().to_vec();
+            key.extend_from_slice(&ts.to_be_bytes());
+            key
+        };
         let mut out = Vec::new();
-        for item in self.oracle_submissions.scan_prefix(prefix) {
-            let (_, b) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
-            let sub: chronx_core::claims::OracleSubmission =
-                bincode::deserialize(&b).map_err(|e| ChronxError::Serialization(e.to_string()))?;
-            out.push(sub);
+        for item in self.oracle_history.range(key_at(from_ts)..=key_at(to_ts)) {
+            let (key, b) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
+            // Skip pairs that merely extend this one (e.g. "KX/USDC" for "KX/USD").
+            if key.len() != pair.len() + 8 {
+                continue;
+            }
+            out.push(
+                bincode::deserialize(&b).map_err(|e| ChronxError::Serialization(e.to_string()))?,
+            );
         }
         Ok(out)
     }
 
+    /// Drop history points recorded before `cutoff`, across all pairs.
+    /// Returns how many were removed.
+    pub fn prune_oracle_history(&self, cutoff: i64) -> Result<usize, ChronxError> {
+        let mut removed = 0;
+        for item in self.oracle_history.iter() {
+            let (key, b) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
+            let entry: OracleHistoryEntry =
+                bincode::deserialize(&b).map_err(|e| ChronxError::Serialization(e.to_string()))?;
+            if entry.updated_at < cutoff {
+                self.oracle_history
+                    .remove(key)
+                    .map_err(|e| ChronxError::Storage(e.to_string()))?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+
     // ── protocol — Promise packages ──────────────────────────────────────────
 
     /// Store the package created at promise time.
@@ -1351,49 +3633,316 @@ impl StateDb {
             .map_err(|e| ChronxError::Storage(e.to_string()))?
         {
             Some(b) => Ok(Some(
-                bincode::deserialize(&b)
-                    .map_err(|e| ChronxError::Serialization(e.to_string()))?,
+                bincode::deserialize(&b)
+                    .map_err(|e| ChronxError::Serialization(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Check if a trigger has already fired for a lock (avoids double-firing).
+    pub fn has_promise_trigger(&self, lock_id: &TxId) -> bool {
+        self.promise_triggers
+            .contains_key(lock_id.as_bytes())
+            .unwrap_or(false)
+    }
+
+    /// Iterate all trigger records (used by 100-year expiry sweep).
+    pub fn iter_all_promise_triggers(&self) -> Result<Vec<PromiseTriggerRecord>, ChronxError> {
+        let mut out = Vec::new();
+        for item in self.promise_triggers.iter() {
+            let (_, b) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
+            out.push(
+                bincode::deserialize(&b)
+                    .map_err(|e| ChronxError::Serialization(e.to_string()))?,
+            );
+        }
+        Ok(out)
+    }
+
+    // ── protocol — Verifier registry ─────────────────────────────────────────
+
+    /// Register or update a verifier entry.
+    pub fn put_verifier(
+        &self,
+        wallet_address: &str,
+        record: &VerifierRecord,
+    ) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_verifier(batch, wallet_address, record))
+    }
+
+    /// [`put_verifier`](Self::put_verifier) into `batch`.
+    pub fn stage_verifier<'a>(
+        &'a self,
+        batch: &mut WriteBatch<'a>,
+        wallet_address: &str,
+        record: &VerifierRecord,
+    ) -> Result<(), ChronxError> {
+        let b = bincode::serialize(record)
+            .map_err(|e| ChronxError::Serialization(e.to_string()))?;
+        batch.insert(&self.verifier_registry, wallet_address.as_bytes(), b);
+        Ok(())
+    }
+
+    /// Insert or replace one verifier vote record.
+    pub fn put_verifier_vote(&self, record: &VerifierVoteRecord) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_verifier_vote(batch, record))
+    }
+
+    /// [`put_verifier_vote`](Self::put_verifier_vote) into `batch`.
+    pub fn stage_verifier_vote<'a>(&'a self, batch: &mut WriteBatch<'a>, record: &VerifierVoteRecord) -> Result<(), ChronxError> {
+        let mut key = record.verifier.as_bytes().to_vec();
+        key.extend_from_slice(record.vote_tx_id.as_bytes());
+        let b = bincode::serialize(record)
+            .map_err(|e| ChronxError::Serialization(e.to_string()))?;
+        batch.insert(&self.verifier_vote_history, key, b);
+        Ok(())
+    }
+
+    /// Every recovery vote cast by `verifier`, newest first.
+    pub fn iter_verifier_votes(
+        &self,
+        verifier: &AccountId,
+    ) -> Result<Vec<VerifierVoteRecord>, ChronxError> {
+        let mut result = Vec::new();
+        for item in self.verifier_vote_history.scan_prefix(verifier.as_bytes()) {
+            let (_, b) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
+            let record: VerifierVoteRecord = bincode::deserialize(&b)
+                .map_err(|e| ChronxError::Serialization(e.to_string()))?;
+            result.push(record);
+        }
+        result.sort_by_key(|r| std::cmp::Reverse(r.voted_at));
+        Ok(result)
+    }
+
+    // ── Verifier registrations ────────────────────────────────────────────────
+
+    pub fn put_verifier_registration(&self, registration: &VerifierRegistration) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_verifier_registration(batch, registration))
+    }
+
+    /// [`put_verifier_registration`](Self::put_verifier_registration) into `batch`.
+    pub fn stage_verifier_registration<'a>(
+        &'a self,
+        batch: &mut WriteBatch<'a>,
+        registration: &VerifierRegistration,
+    ) -> Result<(), ChronxError> {
+        let b = bincode::serialize(registration)
+            .map_err(|e| ChronxError::Serialization(e.to_string()))?;
+        batch.insert(&self.verifier_registrations, registration.account_id.as_bytes(), b);
+        Ok(())
+    }
+
+    pub fn get_verifier_registration(&self, id: &AccountId) -> Result<Option<VerifierRegistration>, ChronxError> {
+        match self
+            .verifier_registrations
+            .get(id.as_bytes())
+            .map_err(|e| ChronxError::Storage(e.to_string()))?
+        {
+            Some(b) => Ok(Some(
+                bincode::deserialize(&b).map_err(|e| ChronxError::Serialization(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    pub fn remove_verifier_registration(&self, id: &AccountId) -> Result<(), ChronxError> {
+        self.verifier_registrations
+            .remove(id.as_bytes())
+            .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        Ok(())
+    }
+
+    /// [`remove_verifier_registration`](Self::remove_verifier_registration) into `batch`.
+    pub fn stage_remove_verifier_registration<'a>(&'a self, batch: &mut WriteBatch<'a>, id: &AccountId) {
+        batch.remove(&self.verifier_registrations, id.as_bytes());
+    }
+
+    /// Registered verifiers staking at least `min_stake`, in AccountId order.
+    /// Reads only the registrations, not every account.
+    pub fn get_active_verifiers(&self, min_stake: u128) -> Result<Vec<VerifierRegistration>, ChronxError> {
+        let mut result = Vec::new();
+        for item in self.verifier_registrations.scan_prefix([]) {
+            let (_, b) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
+            let registration: VerifierRegistration = bincode::deserialize(&b)
+                .map_err(|e| ChronxError::Serialization(e.to_string()))?;
+            if registration.stake >= min_stake {
+                result.push(registration);
+            }
+        }
+        Ok(result)
+    }
+
+    // ── Governance proposals ──────────────────────────────────────────────────
+
+    pub fn put_governance_proposal(&self, proposal: &GovernanceProposal) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_governance_proposal(batch, proposal))
+    }
+
+    /// [`put_governance_proposal`](Self::put_governance_proposal) into `batch`.
+    pub fn stage_governance_proposal<'a>(
+        &'a self,
+        batch: &mut WriteBatch<'a>,
+        proposal: &GovernanceProposal,
+    ) -> Result<(), ChronxError> {
+        let b = bincode::serialize(proposal).map_err(|e| ChronxError::Serialization(e.to_string()))?;
+        batch.insert(&self.governance_proposals, proposal.proposal_id.as_bytes(), b);
+        Ok(())
+    }
+
+    pub fn get_governance_proposal(&self, id: &TxId) -> Result<Option<GovernanceProposal>, ChronxError> {
+        match self
+            .governance_proposals
+            .get(id.as_bytes())
+            .map_err(|e| ChronxError::Storage(e.to_string()))?
+        {
+            Some(b) => Ok(Some(
+                bincode::deserialize(&b).map_err(|e| ChronxError::Serialization(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Every governance proposal, oldest submission first.
+    pub fn get_governance_proposals(&self) -> Result<Vec<GovernanceProposal>, ChronxError> {
+        let mut result = Vec::new();
+        for item in self.governance_proposals.iter() {
+            let (_, b) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
+            let proposal: GovernanceProposal =
+                bincode::deserialize(&b).map_err(|e| ChronxError::Serialization(e.to_string()))?;
+            result.push(proposal);
+        }
+        result.sort_by(|a, b| a.submitted_at.cmp(&b.submitted_at).then_with(|| a.proposal_id.cmp(&b.proposal_id)));
+        Ok(result)
+    }
+
+    // ── Recurring schedules ───────────────────────────────────────────────────
+
+    pub fn put_recurring_schedule(&self, schedule: &RecurringSchedule) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_recurring_schedule(batch, schedule))
+    }
+
+    /// [`put_recurring_schedule`](Self::put_recurring_schedule) into `batch`.
+    pub fn stage_recurring_schedule<'a>(
+        &'a self,
+        batch: &mut WriteBatch<'a>,
+        schedule: &RecurringSchedule,
+    ) -> Result<(), ChronxError> {
+        let b = bincode::serialize(schedule).map_err(|e| ChronxError::Serialization(e.to_string()))?;
+        batch.insert(&self.recurring_schedules, schedule.series_id.as_bytes(), b);
+        Ok(())
+    }
+
+    pub fn get_recurring_schedule(&self, series_id: &TxId) -> Result<Option<RecurringSchedule>, ChronxError> {
+        match self
+            .recurring_schedules
+            .get(series_id.as_bytes())
+            .map_err(|e| ChronxError::Storage(e.to_string()))?
+        {
+            Some(b) => Ok(Some(
+                bincode::deserialize(&b).map_err(|e| ChronxError::Serialization(e.to_string()))?,
             )),
             None => Ok(None),
         }
     }
 
-    /// Check if a trigger has already fired for a lock (avoids double-firing).
-    pub fn has_promise_trigger(&self, lock_id: &TxId) -> bool {
-        self.promise_triggers
-            .contains_key(lock_id.as_bytes())
-            .unwrap_or(false)
+    /// The installments of `schedule` created so far, first to last, found by
+    /// following `recurring_child_lock_id` from the first lock.
+    pub fn get_recurring_installments(
+        &self,
+        schedule: &RecurringSchedule,
+    ) -> Result<Vec<TimeLockContract>, ChronxError> {
+        let mut installments: Vec<TimeLockContract> = Vec::new();
+        let mut next_id = schedule.series_id.clone();
+        while let Some(lock) = self.get_timelock(&next_id)? {
+            let index = installments.len() as u32 + 1;
+            installments.push(lock);
+            if index >= schedule.policy.count() {
+                break;
+            }
+            next_id = recurring_child_lock_id(&next_id, index);
+        }
+        Ok(installments)
     }
 
-    /// Iterate all trigger records (used by 100-year expiry sweep).
-    pub fn iter_all_promise_triggers(&self) -> Result<Vec<PromiseTriggerRecord>, ChronxError> {
-        let mut out = Vec::new();
-        for item in self.promise_triggers.iter() {
+    // ── Claim history ─────────────────────────────────────────────────────────
+
+    /// Append `record` to the history of its lock.
+    pub fn put_claim_history(&self, record: &ClaimHistoryRecord) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_claim_history(batch, record))
+    }
+
+    /// [`put_claim_history`](Self::put_claim_history) into `batch`.
+    pub fn stage_claim_history<'a>(&'a self, batch: &mut WriteBatch<'a>, record: &ClaimHistoryRecord) -> Result<(), ChronxError> {
+        let seq = batch.scan_prefix(&self.claim_history, record.lock_id.as_bytes())?.len() as u32;
+        let mut key = record.lock_id.as_bytes().to_vec();
+        key.extend_from_slice(&seq.to_be_bytes());
+        let b = bincode::serialize(record)
+            .map_err(|e| ChronxError::Serialization(e.to_string()))?;
+        batch.insert(&self.claim_history, key, b);
+        Ok(())
+    }
+
+    /// Every recorded transition of the claim on `lock_id`, oldest first.
+    pub fn get_claim_history(&self, lock_id: &TxId) -> Result<Vec<ClaimHistoryRecord>, ChronxError> {
+        let mut result = Vec::new();
+        for item in self.claim_history.scan_prefix(lock_id.as_bytes()) {
             let (_, b) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
-            out.push(
-                bincode::deserialize(&b)
-                    .map_err(|e| ChronxError::Serialization(e.to_string()))?,
-            );
+            let record: ClaimHistoryRecord = bincode::deserialize(&b)
+                .map_err(|e| ChronxError::Serialization(e.to_string()))?;
+            result.push(record);
         }
-        Ok(out)
+        Ok(result)
     }
 
-    // ── protocol — Verifier registry ─────────────────────────────────────────
+    // ── Event log ─────────────────────────────────────────────────────────────
 
-    /// Register or update a verifier entry.
-    pub fn put_verifier(
+    /// Append the events of one applied transaction, in action order.
+    pub fn put_events(
         &self,
-        wallet_address: &str,
-        record: &VerifierRecord,
+        timestamp: i64,
+        tx_id: &TxId,
+        events: &[ChronxEvent],
     ) -> Result<(), ChronxError> {
-        let b = bincode::serialize(record)
-            .map_err(|e| ChronxError::Serialization(e.to_string()))?;
-        self.verifier_registry
-            .insert(wallet_address.as_bytes(), b)
-            .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        self.write_atomic(|batch| self.stage_events(batch, timestamp, tx_id, events))
+    }
+
+    /// [`put_events`](Self::put_events) into `batch`.
+    pub fn stage_events<'a>(
+        &'a self,
+        batch: &mut WriteBatch<'a>,
+        timestamp: i64,
+        tx_id: &TxId,
+        events: &[ChronxEvent],
+    ) -> Result<(), ChronxError> {
+        for (i, event) in events.iter().enumerate() {
+            let mut key = timestamp.to_be_bytes().to_vec();
+            key.extend_from_slice(tx_id.as_bytes());
+            key.extend_from_slice(&(i as u32).to_be_bytes());
+            let record = EventRecord {
+                timestamp,
+                tx_id: tx_id.clone(),
+                event: event.clone(),
+            };
+            let b = bincode::serialize(&record)
+                .map_err(|e| ChronxError::Serialization(e.to_string()))?;
+            batch.insert(&self.event_log, key, b);
+        }
         Ok(())
     }
 
+    /// Events applied at or after `from_ts`, oldest first.
+    pub fn iter_events_since(&self, from_ts: i64) -> Result<Vec<EventRecord>, ChronxError> {
+        let mut result = Vec::new();
+        for item in self.event_log.range(from_ts.to_be_bytes().to_vec()..) {
+            let (_, b) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
+            let record: EventRecord = bincode::deserialize(&b)
+                .map_err(|e| ChronxError::Serialization(e.to_string()))?;
+            result.push(record);
+        }
+        Ok(result)
+    }
+
     /// Retrieve a verifier entry by wallet address.
     pub fn get_verifier(
         &self,
@@ -1537,12 +4086,20 @@ impl StateDb {
         &self,
         lock_id_hex: &str,
         record: &ExecutorWithdrawalRecord,
+    ) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_executor_withdrawal(batch, lock_id_hex, record))
+    }
+
+    /// [`put_executor_withdrawal`](Self::put_executor_withdrawal) into `batch`.
+    pub fn stage_executor_withdrawal<'a>(
+        &'a self,
+        batch: &mut WriteBatch<'a>,
+        lock_id_hex: &str,
+        record: &ExecutorWithdrawalRecord,
     ) -> Result<(), ChronxError> {
         let b = bincode::serialize(record)
             .map_err(|e| ChronxError::Serialization(e.to_string()))?;
-        self.executor_withdrawals
-            .insert(lock_id_hex.as_bytes(), b)
-            .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        batch.insert(&self.executor_withdrawals, lock_id_hex.as_bytes(), b);
         Ok(())
     }
 
@@ -1566,10 +4123,20 @@ impl StateDb {
 
     /// Count executor withdrawals submitted in the last `window_secs` seconds.
     pub fn count_recent_executor_withdrawals(&self, now: i64, window_secs: i64) -> Result<u32, ChronxError> {
+        self.count_recent_executor_withdrawals_staged(&self.batch(), now, window_secs)
+    }
+
+    /// [`count_recent_executor_withdrawals`](Self::count_recent_executor_withdrawals)
+    /// as of the end of `batch`.
+    pub fn count_recent_executor_withdrawals_staged(
+        &self,
+        batch: &WriteBatch<'_>,
+        now: i64,
+        window_secs: i64,
+    ) -> Result<u32, ChronxError> {
         let mut count = 0u32;
         let cutoff = now - window_secs;
-        for item in self.executor_withdrawals.iter() {
-            let (_, b) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
+        for (_, b) in batch.scan_prefix(&self.executor_withdrawals, &[])? {
             let record: ExecutorWithdrawalRecord = bincode::deserialize(&b)
                 .map_err(|e| ChronxError::Serialization(e.to_string()))?;
             if record.submitted_at >= cutoff {
@@ -1614,29 +4181,39 @@ impl StateDb {
     // ── protocol — Invoice accessors ─────────────────────────────────────
 
     pub fn get_invoice(&self, invoice_id: &[u8; 32]) -> Result<Option<InvoiceRecord>, ChronxError> {
-        match self.invoices.get(invoice_id) {
-            Ok(Some(bytes)) => {
-                let record: InvoiceRecord =
-                    bincode::deserialize(&bytes).map_err(|e| ChronxError::Serialization(e.to_string()))?;
-                Ok(Some(record))
-            }
-            Ok(None) => Ok(None),
-            Err(e) => Err(ChronxError::Storage(e.to_string())),
-        }
+        self.get_invoice_staged(&self.batch(), invoice_id)
+    }
+
+    /// [`get_invoice`](Self::get_invoice) as of the end of `batch`.
+    pub fn get_invoice_staged(&self, batch: &WriteBatch<'_>, invoice_id: &[u8; 32]) -> Result<Option<InvoiceRecord>, ChronxError> {
+        batch
+            .get(&self.invoices, invoice_id)?
+            .map(|b| bincode::deserialize(&b).map_err(|e| ChronxError::Serialization(e.to_string())))
+            .transpose()
     }
 
     pub fn put_invoice(&self, record: &InvoiceRecord) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_invoice(batch, record))
+    }
+
+    /// [`put_invoice`](Self::put_invoice) into `batch`.
+    pub fn stage_invoice<'a>(&'a self, batch: &mut WriteBatch<'a>, record: &InvoiceRecord) -> Result<(), ChronxError> {
         let bytes = bincode::serialize(record).map_err(|e| ChronxError::Serialization(e.to_string()))?;
-        self.invoices.insert(record.invoice_id, bytes).map_err(|e| ChronxError::Storage(e.to_string()))?;
+        batch.insert(&self.invoices, record.invoice_id, bytes);
         Ok(())
     }
 
     pub fn update_invoice_status(&self, invoice_id: &[u8; 32], status: InvoiceStatus, fulfilled_at: Option<u64>, fulfilled_by: Option<Vec<u8>>) -> Result<(), ChronxError> {
-        if let Some(mut record) = self.get_invoice(invoice_id)? {
+        self.write_atomic(|batch| self.stage_invoice_status(batch, invoice_id, status, fulfilled_at, fulfilled_by))
+    }
+
+    /// [`update_invoice_status`](Self::update_invoice_status) into `batch`.
+    pub fn stage_invoice_status<'a>(&'a self, batch: &mut WriteBatch<'a>, invoice_id: &[u8; 32], status: InvoiceStatus, fulfilled_at: Option<u64>, fulfilled_by: Option<Vec<u8>>) -> Result<(), ChronxError> {
+        if let Some(mut record) = self.get_invoice_staged(batch, invoice_id)? {
             record.status = status;
             record.fulfilled_at = fulfilled_at;
             record.fulfilled_by = fulfilled_by;
-            self.put_invoice(&record)
+            self.stage_invoice(batch, &record)
         } else {
             Err(ChronxError::Other(format!("invoice not found: {}", hex::encode(invoice_id))))
         }
@@ -1676,30 +4253,45 @@ impl StateDb {
     // ── protocol — Credit accessors ──────────────────────────────────────
 
     pub fn get_credit(&self, credit_id: &[u8; 32]) -> Result<Option<CreditRecord>, ChronxError> {
-        match self.credits.get(credit_id) {
-            Ok(Some(bytes)) => {
-                let record: CreditRecord =
-                    bincode::deserialize(&bytes).map_err(|e| ChronxError::Serialization(e.to_string()))?;
-                Ok(Some(record))
-            }
-            Ok(None) => Ok(None),
-            Err(e) => Err(ChronxError::Storage(e.to_string())),
-        }
+        self.get_credit_staged(&self.batch(), credit_id)
+    }
+
+    /// [`get_credit`](Self::get_credit) as of the end of `batch`.
+    pub fn get_credit_staged(&self, batch: &WriteBatch<'_>, credit_id: &[u8; 32]) -> Result<Option<CreditRecord>, ChronxError> {
+        batch
+            .get(&self.credits, credit_id)?
+            .map(|b| bincode::deserialize(&b).map_err(|e| ChronxError::Serialization(e.to_string())))
+            .transpose()
     }
 
     pub fn put_credit(&self, record: &CreditRecord) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_credit(batch, record))
+    }
+
+    /// [`put_credit`](Self::put_credit) into `batch`.
+    pub fn stage_credit<'a>(&'a self, batch: &mut WriteBatch<'a>, record: &CreditRecord) -> Result<(), ChronxError> {
         let bytes = bincode::serialize(record).map_err(|e| ChronxError::Serialization(e.to_string()))?;
-        self.credits.insert(record.credit_id, bytes).map_err(|e| ChronxError::Storage(e.to_string()))?;
+        batch.insert(&self.credits, record.credit_id, bytes);
         Ok(())
     }
 
     pub fn update_credit_drawn(&self, credit_id: &[u8; 32], additional: u64) -> Result<CreditRecord, ChronxError> {
-        if let Some(mut record) = self.get_credit(credit_id)? {
+        let mut record = None;
+        self.write_atomic(|batch| {
+            record = Some(self.stage_credit_drawn(batch, credit_id, additional)?);
+            Ok(())
+        })?;
+        Ok(record.expect("staged on success"))
+    }
+
+    /// [`update_credit_drawn`](Self::update_credit_drawn) into `batch`.
+    pub fn stage_credit_drawn<'a>(&'a self, batch: &mut WriteBatch<'a>, credit_id: &[u8; 32], additional: u64) -> Result<CreditRecord, ChronxError> {
+        if let Some(mut record) = self.get_credit_staged(batch, credit_id)? {
             record.drawn_chronos += additional;
             if record.drawn_chronos >= record.ceiling_chronos {
                 record.status = CreditStatus::Closed;
             }
-            self.put_credit(&record)?;
+            self.stage_credit(batch, &record)?;
             Ok(record)
         } else {
             Err(ChronxError::Other(format!("credit not found: {}", hex::encode(credit_id))))
@@ -1707,9 +4299,14 @@ impl StateDb {
     }
 
     pub fn update_credit_status(&self, credit_id: &[u8; 32], status: CreditStatus) -> Result<(), ChronxError> {
-        if let Some(mut record) = self.get_credit(credit_id)? {
+        self.write_atomic(|batch| self.stage_credit_status(batch, credit_id, status))
+    }
+
+    /// [`update_credit_status`](Self::update_credit_status) into `batch`.
+    pub fn stage_credit_status<'a>(&'a self, batch: &mut WriteBatch<'a>, credit_id: &[u8; 32], status: CreditStatus) -> Result<(), ChronxError> {
+        if let Some(mut record) = self.get_credit_staged(batch, credit_id)? {
             record.status = status;
-            self.put_credit(&record)
+            self.stage_credit(batch, &record)
         } else {
             Err(ChronxError::Other(format!("credit not found: {}", hex::encode(credit_id))))
         }
@@ -1743,28 +4340,38 @@ impl StateDb {
     // ── protocol — Deposit accessors ─────────────────────────────────────
 
     pub fn get_deposit(&self, deposit_id: &[u8; 32]) -> Result<Option<DepositRecord>, ChronxError> {
-        match self.deposits.get(deposit_id) {
-            Ok(Some(bytes)) => {
-                let record: DepositRecord =
-                    serde_json::from_slice(&bytes).map_err(|e| ChronxError::Serialization(e.to_string()))?;
-                Ok(Some(record))
-            }
-            Ok(None) => Ok(None),
-            Err(e) => Err(ChronxError::Storage(e.to_string())),
-        }
+        self.get_deposit_staged(&self.batch(), deposit_id)
+    }
+
+    /// [`get_deposit`](Self::get_deposit) as of the end of `batch`.
+    pub fn get_deposit_staged(&self, batch: &WriteBatch<'_>, deposit_id: &[u8; 32]) -> Result<Option<DepositRecord>, ChronxError> {
+        batch
+            .get(&self.deposits, deposit_id)?
+            .map(|b| serde_json::from_slice(&b).map_err(|e| ChronxError::Serialization(e.to_string())))
+            .transpose()
     }
 
     pub fn put_deposit(&self, record: &DepositRecord) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_deposit(batch, record))
+    }
+
+    /// [`put_deposit`](Self::put_deposit) into `batch`.
+    pub fn stage_deposit<'a>(&'a self, batch: &mut WriteBatch<'a>, record: &DepositRecord) -> Result<(), ChronxError> {
         let bytes = serde_json::to_vec(record).map_err(|e| ChronxError::Serialization(e.to_string()))?;
-        self.deposits.insert(record.deposit_id, bytes).map_err(|e| ChronxError::Storage(e.to_string()))?;
+        batch.insert(&self.deposits, record.deposit_id, bytes);
         Ok(())
     }
 
     pub fn update_deposit_status(&self, deposit_id: &[u8; 32], status: DepositStatus, settled_at: Option<u64>) -> Result<(), ChronxError> {
-        if let Some(mut record) = self.get_deposit(deposit_id)? {
+        self.write_atomic(|batch| self.stage_deposit_status(batch, deposit_id, status, settled_at))
+    }
+
+    /// [`update_deposit_status`](Self::update_deposit_status) into `batch`.
+    pub fn stage_deposit_status<'a>(&'a self, batch: &mut WriteBatch<'a>, deposit_id: &[u8; 32], status: DepositStatus, settled_at: Option<u64>) -> Result<(), ChronxError> {
+        if let Some(mut record) = self.get_deposit_staged(batch, deposit_id)? {
             record.status = status;
             record.settled_at = settled_at;
-            self.put_deposit(&record)
+            self.stage_deposit(batch, &record)
         } else {
             Err(ChronxError::Other(format!("deposit not found: {}", hex::encode(deposit_id))))
         }
@@ -1797,20 +4404,25 @@ impl StateDb {
 
     // ── Friendly Loan accessors ───────────────────────────────────────
     pub fn get_friendly_loan(&self, loan_id: &[u8; 32]) -> Result<Option<FriendlyLoanRecord>, ChronxError> {
-        match self.friendly_loans.get(loan_id) {
-            Ok(Some(bytes)) => {
-                let record: FriendlyLoanRecord =
-                    serde_json::from_slice(&bytes).map_err(|e| ChronxError::Serialization(e.to_string()))?;
-                Ok(Some(record))
-            }
-            Ok(None) => Ok(None),
-            Err(e) => Err(ChronxError::Storage(e.to_string())),
-        }
+        self.get_friendly_loan_staged(&self.batch(), loan_id)
+    }
+
+    /// [`get_friendly_loan`](Self::get_friendly_loan) as of the end of `batch`.
+    pub fn get_friendly_loan_staged(&self, batch: &WriteBatch<'_>, loan_id: &[u8; 32]) -> Result<Option<FriendlyLoanRecord>, ChronxError> {
+        batch
+            .get(&self.friendly_loans, loan_id)?
+            .map(|b| serde_json::from_slice(&b).map_err(|e| ChronxError::Serialization(e.to_string())))
+            .transpose()
     }
 
     pub fn put_friendly_loan(&self, record: &FriendlyLoanRecord) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_friendly_loan(batch, record))
+    }
+
+    /// [`put_friendly_loan`](Self::put_friendly_loan) into `batch`.
+    pub fn stage_friendly_loan<'a>(&'a self, batch: &mut WriteBatch<'a>, record: &FriendlyLoanRecord) -> Result<(), ChronxError> {
         let bytes = serde_json::to_vec(record).map_err(|e| ChronxError::Serialization(e.to_string()))?;
-        self.friendly_loans.insert(&record.loan_id, bytes).map_err(|e| ChronxError::Storage(e.to_string()))?;
+        batch.insert(&self.friendly_loans, record.loan_id, bytes);
         Ok(())
     }
 
@@ -1856,27 +4468,42 @@ impl StateDb {
     // ── protocol — Conditional accessors ─────────────────────────────────
 
     pub fn get_conditional(&self, type_v_id: &[u8; 32]) -> Result<Option<ConditionalRecord>, ChronxError> {
-        match self.conditionals.get(type_v_id) {
-            Ok(Some(bytes)) => {
-                let record: ConditionalRecord =
-                    bincode::deserialize(&bytes).map_err(|e| ChronxError::Serialization(e.to_string()))?;
-                Ok(Some(record))
-            }
-            Ok(None) => Ok(None),
-            Err(e) => Err(ChronxError::Storage(e.to_string())),
-        }
+        self.get_conditional_staged(&self.batch(), type_v_id)
+    }
+
+    /// [`get_conditional`](Self::get_conditional) as of the end of `batch`.
+    pub fn get_conditional_staged(&self, batch: &WriteBatch<'_>, type_v_id: &[u8; 32]) -> Result<Option<ConditionalRecord>, ChronxError> {
+        batch
+            .get(&self.conditionals, type_v_id)?
+            .map(|b| bincode::deserialize(&b).map_err(|e| ChronxError::Serialization(e.to_string())))
+            .transpose()
     }
 
     pub fn put_conditional(&self, record: &ConditionalRecord) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_conditional(batch, record))
+    }
+
+    /// [`put_conditional`](Self::put_conditional) into `batch`.
+    pub fn stage_conditional<'a>(&'a self, batch: &mut WriteBatch<'a>, record: &ConditionalRecord) -> Result<(), ChronxError> {
         let bytes = bincode::serialize(record).map_err(|e| ChronxError::Serialization(e.to_string()))?;
-        self.conditionals.insert(record.type_v_id, bytes).map_err(|e| ChronxError::Storage(e.to_string()))?;
+        batch.insert(&self.conditionals, record.type_v_id, bytes);
         Ok(())
     }
 
     pub fn add_attestation(&self, type_v_id: &[u8; 32], attestor_pubkey: Vec<u8>, timestamp: u64) -> Result<ConditionalRecord, ChronxError> {
-        if let Some(mut record) = self.get_conditional(type_v_id)? {
+        let mut record = None;
+        self.write_atomic(|batch| {
+            record = Some(self.stage_attestation(batch, type_v_id, attestor_pubkey, timestamp)?);
+            Ok(())
+        })?;
+        Ok(record.expect("staged on success"))
+    }
+
+    /// [`add_attestation`](Self::add_attestation) into `batch`.
+    pub fn stage_attestation<'a>(&'a self, batch: &mut WriteBatch<'a>, type_v_id: &[u8; 32], attestor_pubkey: Vec<u8>, timestamp: u64) -> Result<ConditionalRecord, ChronxError> {
+        if let Some(mut record) = self.get_conditional_staged(batch, type_v_id)? {
             record.attestations_received.push((attestor_pubkey, timestamp));
-            self.put_conditional(&record)?;
+            self.stage_conditional(batch, &record)?;
             Ok(record)
         } else {
             Err(ChronxError::Other(format!("conditional not found: {}", hex::encode(type_v_id))))
@@ -1884,9 +4511,14 @@ impl StateDb {
     }
 
     pub fn update_conditional_status(&self, type_v_id: &[u8; 32], status: ConditionalStatus) -> Result<(), ChronxError> {
-        if let Some(mut record) = self.get_conditional(type_v_id)? {
+        self.write_atomic(|batch| self.stage_conditional_status(batch, type_v_id, status))
+    }
+
+    /// [`update_conditional_status`](Self::update_conditional_status) into `batch`.
+    pub fn stage_conditional_status<'a>(&'a self, batch: &mut WriteBatch<'a>, type_v_id: &[u8; 32], status: ConditionalStatus) -> Result<(), ChronxError> {
+        if let Some(mut record) = self.get_conditional_staged(batch, type_v_id)? {
             record.status = status;
-            self.put_conditional(&record)
+            self.stage_conditional(batch, &record)
         } else {
             Err(ChronxError::Other(format!("conditional not found: {}", hex::encode(type_v_id))))
         }
@@ -1906,18 +4538,23 @@ impl StateDb {
     // ── protocol — Ledger Entry accessors ────────────────────────────────
 
     pub fn put_ledger_entry(&self, record: &LedgerEntryRecord) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_ledger_entry(batch, record))
+    }
+
+    /// [`put_ledger_entry`](Self::put_ledger_entry) into `batch`.
+    pub fn stage_ledger_entry<'a>(&'a self, batch: &mut WriteBatch<'a>, record: &LedgerEntryRecord) -> Result<(), ChronxError> {
         let bytes = bincode::serialize(record).map_err(|e| ChronxError::Serialization(e.to_string()))?;
-        self.ledger_entries.insert(record.entry_id, bytes).map_err(|e| ChronxError::Storage(e.to_string()))?;
+        batch.insert(&self.ledger_entries, record.entry_id, bytes);
 
         // Update promise_id secondary index
         if let Some(promise_id) = &record.promise_id {
-            let mut entry_ids: Vec<[u8; 32]> = match self.ledger_promise_index.get(promise_id) {
+            let mut entry_ids: Vec<[u8; 32]> = match batch.get(&self.ledger_promise_index, promise_id) {
                 Ok(Some(bytes)) => bincode::deserialize(&bytes).unwrap_or_default(),
                 _ => Vec::new(),
             };
             entry_ids.push(record.entry_id);
             let idx_bytes = bincode::serialize(&entry_ids).map_err(|e| ChronxError::Serialization(e.to_string()))?;
-            self.ledger_promise_index.insert(promise_id.as_ref(), idx_bytes).map_err(|e| ChronxError::Storage(e.to_string()))?;
+            batch.insert(&self.ledger_promise_index, promise_id, idx_bytes);
         }
         Ok(())
     }
@@ -1949,21 +4586,31 @@ impl StateDb {
     }
 
     pub fn ledger_entry_exists(&self, entry_id: &[u8; 32]) -> bool {
-        self.ledger_entries.contains_key(entry_id).unwrap_or(false)
+        self.ledger_entry_exists_staged(&self.batch(), entry_id)
+    }
+
+    /// [`ledger_entry_exists`](Self::ledger_entry_exists) as of the end of `batch`.
+    pub fn ledger_entry_exists_staged(&self, batch: &WriteBatch<'_>, entry_id: &[u8; 32]) -> bool {
+        batch.get(&self.ledger_entries, entry_id).ok().flatten().is_some()
     }
 
 
     // ── Identity verification accessors ───────────────────────────────
 
     pub fn add_identity_entry(&self, wallet_b58: &str, entry_id: [u8; 32]) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_identity_entry(batch, wallet_b58, entry_id))
+    }
+
+    /// [`add_identity_entry`](Self::add_identity_entry) into `batch`.
+    pub fn stage_identity_entry<'a>(&'a self, batch: &mut WriteBatch<'a>, wallet_b58: &str, entry_id: [u8; 32]) -> Result<(), ChronxError> {
         let key = wallet_b58.as_bytes();
-        let mut entry_ids: Vec<[u8; 32]> = match self.identity_index.get(key) {
+        let mut entry_ids: Vec<[u8; 32]> = match batch.get(&self.identity_index, key) {
             Ok(Some(bytes)) => bincode::deserialize(&bytes).unwrap_or_default(),
             _ => Vec::new(),
         };
         entry_ids.push(entry_id);
         let bytes = bincode::serialize(&entry_ids).map_err(|e| ChronxError::Serialization(e.to_string()))?;
-        self.identity_index.insert(key, bytes).map_err(|e| ChronxError::Storage(e.to_string()))?;
+        batch.insert(&self.identity_index, key, bytes);
         Ok(())
     }
 
@@ -1984,9 +4631,15 @@ impl StateDb {
     }
 
     pub fn badge_blackouts_insert(&self, wallet_b58: &str, data: &[u8]) -> Result<(), ChronxError> {
-        self.badge_blackouts.insert(wallet_b58.as_bytes(), data)
-            .map_err(|e| ChronxError::Storage(e.to_string()))?;
-        Ok(())
+        self.write_atomic(|batch| {
+            self.stage_badge_blackout(batch, wallet_b58, data);
+            Ok(())
+        })
+    }
+
+    /// [`badge_blackouts_insert`](Self::badge_blackouts_insert) into `batch`.
+    pub fn stage_badge_blackout<'a>(&'a self, batch: &mut WriteBatch<'a>, wallet_b58: &str, data: &[u8]) {
+        batch.insert(&self.badge_blackouts, wallet_b58.as_bytes(), data);
     }
 
     pub fn get_badge_blackout(&self, wallet_b58: &str) -> Result<Option<serde_json::Value>, ChronxError> {
@@ -2044,9 +4697,15 @@ impl StateDb {
     // ── convert_to field accessors ────────────────────────────────────
 
     pub fn put_convert_to_suggestion(&self, lock_id: &chronx_core::types::TxId, value: &str) -> Result<(), ChronxError> {
-        self.convert_to_suggestion.insert(lock_id.as_bytes(), value.as_bytes())
-            .map_err(|e| ChronxError::Storage(e.to_string()))?;
-        Ok(())
+        self.write_atomic(|batch| {
+            self.stage_convert_to_suggestion(batch, lock_id, value);
+            Ok(())
+        })
+    }
+
+    /// [`put_convert_to_suggestion`](Self::put_convert_to_suggestion) into `batch`.
+    pub fn stage_convert_to_suggestion<'a>(&'a self, batch: &mut WriteBatch<'a>, lock_id: &chronx_core::types::TxId, value: &str) {
+        batch.insert(&self.convert_to_suggestion, lock_id.as_bytes(), value.as_bytes());
     }
 
     pub fn get_convert_to_suggestion(&self, lock_id: &chronx_core::types::TxId) -> Result<Option<String>, ChronxError> {
@@ -2125,19 +4784,25 @@ impl StateDb {
     // ── protocol: Wallet Group accessors ────────────────────────────────────
 
     pub fn get_group(&self, group_id: &[u8; 32]) -> Result<Option<chronx_core::transaction::GroupRecord>, ChronxError> {
-        match self.groups.get(group_id).map_err(|e| ChronxError::Storage(e.to_string()))? {
-            Some(bytes) => {
-                let record: chronx_core::transaction::GroupRecord =
-                    bincode::deserialize(&bytes).map_err(|e| ChronxError::Serialization(e.to_string()))?;
-                Ok(Some(record))
-            }
-            None => Ok(None),
-        }
+        self.get_group_staged(&self.batch(), group_id)
+    }
+
+    /// [`get_group`](Self::get_group) as of the end of `batch`.
+    pub fn get_group_staged(&self, batch: &WriteBatch<'_>, group_id: &[u8; 32]) -> Result<Option<chronx_core::transaction::GroupRecord>, ChronxError> {
+        batch
+            .get(&self.groups, group_id)?
+            .map(|b| bincode::deserialize(&b).map_err(|e| ChronxError::Serialization(e.to_string())))
+            .transpose()
     }
 
     pub fn put_group(&self, record: &chronx_core::transaction::GroupRecord) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_group(batch, record))
+    }
+
+    /// [`put_group`](Self::put_group) into `batch`.
+    pub fn stage_group<'a>(&'a self, batch: &mut WriteBatch<'a>, record: &chronx_core::transaction::GroupRecord) -> Result<(), ChronxError> {
         let bytes = bincode::serialize(record).map_err(|e| ChronxError::Serialization(e.to_string()))?;
-        self.groups.insert(record.group_id, bytes).map_err(|e| ChronxError::Storage(e.to_string()))?;
+        batch.insert(&self.groups, record.group_id, bytes);
         Ok(())
     }
 
@@ -2243,18 +4908,25 @@ pub struct OraclePriceRecord {
 impl StateDb {
     /// Store JSON loan data by loan_id
     pub fn save_loan(&self, loan_id: &[u8; 32], data: &[u8]) -> Result<(), ChronxError> {
-        self.loans.insert(loan_id.as_ref(), data)
-            .map_err(|e| ChronxError::Storage(e.to_string()))?;
-        Ok(())
+        self.write_atomic(|batch| {
+            self.stage_loan(batch, loan_id, data);
+            Ok(())
+        })
+    }
+
+    /// [`save_loan`](Self::save_loan) into `batch`.
+    pub fn stage_loan<'a>(&'a self, batch: &mut WriteBatch<'a>, loan_id: &[u8; 32], data: &[u8]) {
+        batch.insert(&self.loans, loan_id, data);
     }
 
     /// Get JSON loan data by loan_id
     pub fn get_loan(&self, loan_id: &[u8; 32]) -> Result<Option<Vec<u8>>, ChronxError> {
-        match self.loans.get(loan_id.as_ref()) {
-            Ok(Some(bytes)) => Ok(Some(bytes.to_vec())),
-            Ok(None) => Ok(None),
-            Err(e) => Err(ChronxError::Storage(e.to_string())),
-        }
+        self.get_loan_staged(&self.batch(), loan_id)
+    }
+
+    /// [`get_loan`](Self::get_loan) as of the end of `batch`.
+    pub fn get_loan_staged(&self, batch: &WriteBatch<'_>, loan_id: &[u8; 32]) -> Result<Option<Vec<u8>>, ChronxError> {
+        batch.get(&self.loans, loan_id)
     }
 
     /// Iterate all raw loan entries (for RPC scan)
@@ -2266,6 +4938,11 @@ impl StateDb {
         Ok(())
     }
 
+    /// [`save_escalation`](Self::save_escalation) into `batch`.
+    pub fn stage_escalation<'a>(&'a self, batch: &mut WriteBatch<'a>, id: &str, data: &[u8]) {
+        batch.insert(&self.escalations, id.as_bytes(), data);
+    }
+
     pub fn get_escalation(&self, id: &str) -> Result<Option<Vec<u8>>, ChronxError> {
         self.escalations.get(id.as_bytes()).map(|opt| opt.map(|v| v.to_vec())).map_err(|_| ChronxError::DatabaseError)
     }
@@ -2275,14 +4952,24 @@ impl StateDb {
         Ok(())
     }
 
+    /// [`save_attestor_failure`](Self::save_attestor_failure) into `batch`.
+    pub fn stage_attestor_failure<'a>(&'a self, batch: &mut WriteBatch<'a>, id: &str, data: &[u8]) {
+        batch.insert(&self.attestor_failures, id.as_bytes(), data);
+    }
+
     pub fn save_partial_release(&self, type_v_id: &[u8; 32], data: &[u8]) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_partial_release(batch, type_v_id, data))
+    }
+
+    /// [`save_partial_release`](Self::save_partial_release) into `batch`.
+    pub fn stage_partial_release<'a>(&'a self, batch: &mut WriteBatch<'a>, type_v_id: &[u8; 32], data: &[u8]) -> Result<(), ChronxError> {
         let key = hex::encode(type_v_id);
-        let existing = self.partial_release_history.get(key.as_bytes()).map_err(|_| ChronxError::DatabaseError)?.map(|v| v.to_vec()).unwrap_or_default();
+        let existing = batch.get(&self.partial_release_history, key.as_bytes()).map_err(|_| ChronxError::DatabaseError)?.unwrap_or_default();
         let mut history: Vec<serde_json::Value> = if existing.is_empty() { vec![] } else { serde_json::from_slice(&existing).unwrap_or_default() };
         let entry: serde_json::Value = serde_json::from_slice(data).unwrap_or_default();
         history.push(entry);
         let serialized = serde_json::to_vec(&history).map_err(|_| ChronxError::SerializationError)?;
-        self.partial_release_history.insert(key.as_bytes(), serialized).map_err(|_| ChronxError::DatabaseError)?;
+        batch.insert(&self.partial_release_history, key.as_bytes(), serialized);
         Ok(())
     }
 
@@ -2310,6 +4997,11 @@ impl StateDb {
         Ok(())
     }
 
+    /// [`save_pending_drawrequest`](Self::save_pending_drawrequest) into `batch`.
+    pub fn stage_pending_drawrequest<'a>(&'a self, batch: &mut WriteBatch<'a>, key: &str, data: &[u8]) {
+        batch.insert(&self.pending_drawrequests, key.as_bytes(), data);
+    }
+
     pub fn iter_pending_drawrequests(&self) -> Result<Vec<(String, serde_json::Value)>, ChronxError> {
         let mut results = vec![];
         for item in self.pending_drawrequests.iter() {
@@ -2329,6 +5021,11 @@ impl StateDb {
         Ok(())
     }
 
+    /// [`save_escalation_error`](Self::save_escalation_error) into `batch`.
+    pub fn stage_escalation_error<'a>(&'a self, batch: &mut WriteBatch<'a>, lock_id: &str, data: &[u8]) {
+        batch.insert(&self.escalation_errors, lock_id.as_bytes(), data);
+    }
+
     pub fn get_conditionals_by_attestor_group(&self, _group_id: &str) -> Result<Vec<ConditionalRecord>, ChronxError> {
         let mut results = vec![];
         for item in self.conditionals.iter() {
@@ -2343,32 +5040,41 @@ impl StateDb {
     }
 
     pub fn set_conditional_attestors_suspended(&self, type_v_id: &[u8; 32], suspended: bool) -> Result<(), ChronxError> {
-        let key = hex::encode(type_v_id);
-        if let Some(data) = self.conditionals.get(key.as_bytes()).map_err(|_| ChronxError::DatabaseError)? {
-            let mut record: ConditionalRecord = serde_json::from_slice(&data).map_err(|_| ChronxError::SerializationError)?;
-            record.attestors_suspended = suspended;
-            let serialized = serde_json::to_vec(&record).map_err(|_| ChronxError::SerializationError)?;
-            self.conditionals.insert(key.as_bytes(), serialized).map_err(|_| ChronxError::DatabaseError)?;
-        }
-        Ok(())
+        self.write_atomic(|batch| self.stage_conditional_flags(batch, type_v_id, |r| r.attestors_suspended = suspended))
     }
 
     pub fn set_conditional_escalation_active(&self, type_v_id: &[u8; 32], active: bool) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_conditional_flags(batch, type_v_id, |r| r.escalation_active = active))
+    }
+
+    /// [`set_conditional_attestors_suspended`](Self::set_conditional_attestors_suspended)
+    /// and [`set_conditional_escalation_active`](Self::set_conditional_escalation_active)
+    /// into `batch`: apply `update` to the hex-keyed JSON record, if any.
+    pub fn stage_conditional_flags<'a>(
+        &'a self,
+        batch: &mut WriteBatch<'a>,
+        type_v_id: &[u8; 32],
+        update: impl FnOnce(&mut ConditionalRecord),
+    ) -> Result<(), ChronxError> {
         let key = hex::encode(type_v_id);
-        if let Some(data) = self.conditionals.get(key.as_bytes()).map_err(|_| ChronxError::DatabaseError)? {
+        if let Some(data) = batch.get(&self.conditionals, key.as_bytes()).map_err(|_| ChronxError::DatabaseError)? {
             let mut record: ConditionalRecord = serde_json::from_slice(&data).map_err(|_| ChronxError::SerializationError)?;
-            record.escalation_active = active;
-            let serialized = serde_json::to_vec(&record).map_err(|_| ChronxError::SerializationError)?;
-            self.conditionals.insert(key.as_bytes(), serialized).map_err(|_| ChronxError::DatabaseError)?;
+            update(&mut record);
+            self.stage_conditional_raw(batch, type_v_id, &record)?;
         }
         Ok(())
     }
 
     /// Write a ConditionalRecord directly (for engine partial release updates).
     pub fn put_conditional_raw(&self, type_v_id: &[u8; 32], record: &ConditionalRecord) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_conditional_raw(batch, type_v_id, record))
+    }
+
+    /// [`put_conditional_raw`](Self::put_conditional_raw) into `batch`.
+    pub fn stage_conditional_raw<'a>(&'a self, batch: &mut WriteBatch<'a>, type_v_id: &[u8; 32], record: &ConditionalRecord) -> Result<(), ChronxError> {
         let key = hex::encode(type_v_id);
         let serialized = serde_json::to_vec(record).map_err(|_| ChronxError::SerializationError)?;
-        self.conditionals.insert(key.as_bytes(), serialized).map_err(|_| ChronxError::DatabaseError)?;
+        batch.insert(&self.conditionals, key.as_bytes(), serialized);
         Ok(())
     }
 
@@ -2387,6 +5093,11 @@ impl StateDb {
         Ok(())
     }
 
+    /// [`save_bond_slash`](Self::save_bond_slash) into `batch`.
+    pub fn stage_bond_slash<'a>(&'a self, batch: &mut WriteBatch<'a>, id: &str, data: &[u8]) {
+        batch.insert(&self.bond_slash_cascade, id.as_bytes(), data);
+    }
+
     pub fn get_bond_slash(&self, id: &str) -> Result<Option<Vec<u8>>, ChronxError> {
         self.bond_slash_cascade.get(id.as_bytes()).map(|opt| opt.map(|v| v.to_vec())).map_err(|_| ChronxError::DatabaseError)
     }
@@ -2481,10 +5192,14 @@ impl StateDb {
 
     /// Save a default record for a loan into the loan_defaults tree.
     pub fn save_loan_default(&self, loan_id: &[u8; 32], record: &LoanDefaultRecord) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_loan_default(batch, loan_id, record))
+    }
+
+    /// [`save_loan_default`](Self::save_loan_default) into `batch`.
+    pub fn stage_loan_default<'a>(&'a self, batch: &mut WriteBatch<'a>, loan_id: &[u8; 32], record: &LoanDefaultRecord) -> Result<(), ChronxError> {
         let bytes = bincode::serialize(record)
             .map_err(|e| ChronxError::Serialization(e.to_string()))?;
-        self.loan_defaults.insert(loan_id, bytes)
-            .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        batch.insert(&self.loan_defaults, loan_id, bytes);
         Ok(())
     }
 
@@ -2506,6 +5221,11 @@ impl StateDb {
 
     /// Store a loan escrow deposit (lender funds held during rescission window).
     pub fn put_loan_escrow(&self, loan_id: &[u8; 32], lender_wallet: &str, amount_chronos: u128, expires_at: i64) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_loan_escrow(batch, loan_id, lender_wallet, amount_chronos, expires_at))
+    }
+
+    /// [`put_loan_escrow`](Self::put_loan_escrow) into `batch`.
+    pub fn stage_loan_escrow<'a>(&'a self, batch: &mut WriteBatch<'a>, loan_id: &[u8; 32], lender_wallet: &str, amount_chronos: u128, expires_at: i64) -> Result<(), ChronxError> {
         let record = serde_json::json!({
             "lender_wallet": lender_wallet,
             "amount_chronos": amount_chronos.to_string(),
@@ -2513,29 +5233,34 @@ impl StateDb {
         });
         let val = serde_json::to_vec(&record)
             .map_err(|e| ChronxError::Serialization(e.to_string()))?;
-        self.escrow_deposits.insert(loan_id.as_ref(), val)
-            .map_err(|e| ChronxError::Storage(e.to_string()))?;
+        batch.insert(&self.escrow_deposits, loan_id, val);
         Ok(())
     }
 
     /// Get a loan escrow deposit by loan_id.
     pub fn get_loan_escrow(&self, loan_id: &[u8; 32]) -> Result<Option<serde_json::Value>, ChronxError> {
-        match self.escrow_deposits.get(loan_id.as_ref()) {
-            Ok(Some(bytes)) => {
-                let val: serde_json::Value = serde_json::from_slice(&bytes)
-                    .map_err(|e| ChronxError::Serialization(e.to_string()))?;
-                Ok(Some(val))
-            }
-            Ok(None) => Ok(None),
-            Err(e) => Err(ChronxError::Storage(e.to_string())),
-        }
+        self.get_loan_escrow_staged(&self.batch(), loan_id)
+    }
+
+    /// [`get_loan_escrow`](Self::get_loan_escrow) as of the end of `batch`.
+    pub fn get_loan_escrow_staged(&self, batch: &WriteBatch<'_>, loan_id: &[u8; 32]) -> Result<Option<serde_json::Value>, ChronxError> {
+        batch
+            .get(&self.escrow_deposits, loan_id)?
+            .map(|b| serde_json::from_slice(&b).map_err(|e| ChronxError::Serialization(e.to_string())))
+            .transpose()
     }
 
     /// Remove a loan escrow deposit (after release to borrower or return to lender).
     pub fn remove_loan_escrow(&self, loan_id: &[u8; 32]) -> Result<(), ChronxError> {
-        self.escrow_deposits.remove(loan_id.as_ref())
-            .map_err(|e| ChronxError::Storage(e.to_string()))?;
-        Ok(())
+        self.write_atomic(|batch| {
+            self.stage_remove_loan_escrow(batch, loan_id);
+            Ok(())
+        })
+    }
+
+    /// [`remove_loan_escrow`](Self::remove_loan_escrow) into `batch`.
+    pub fn stage_remove_loan_escrow<'a>(&'a self, batch: &mut WriteBatch<'a>, loan_id: &[u8; 32]) {
+        batch.remove(&self.escrow_deposits, loan_id);
     }
 
     /// Iterate all loan escrow deposits. Returns (loan_id_bytes, JSON value).
@@ -2569,8 +5294,13 @@ impl StateDb {
     // ── Lock Extension Offers ────────────────────────────────────────────
 
     pub fn put_lock_extension_offer(&self, record: &chronx_core::transaction::LockExtensionOfferRecord) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_lock_extension_offer(batch, record))
+    }
+
+    /// [`put_lock_extension_offer`](Self::put_lock_extension_offer) into `batch`.
+    pub fn stage_lock_extension_offer<'a>(&'a self, batch: &mut WriteBatch<'a>, record: &chronx_core::transaction::LockExtensionOfferRecord) -> Result<(), ChronxError> {
         let bytes = bincode::serialize(record).map_err(|e| ChronxError::Serialization(e.to_string()))?;
-        self.lock_extension_offers.insert(&record.lock_id, bytes).map_err(|e| ChronxError::Storage(e.to_string()))?;
+        batch.insert(&self.lock_extension_offers, record.lock_id, bytes);
         Ok(())
     }
 
@@ -2589,8 +5319,13 @@ impl StateDb {
     // ── Lock Extension Requests ──────────────────────────────────────────
 
     pub fn put_lock_extension_request(&self, record: &chronx_core::transaction::LockExtensionRequestRecord) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_lock_extension_request(batch, record))
+    }
+
+    /// [`put_lock_extension_request`](Self::put_lock_extension_request) into `batch`.
+    pub fn stage_lock_extension_request<'a>(&'a self, batch: &mut WriteBatch<'a>, record: &chronx_core::transaction::LockExtensionRequestRecord) -> Result<(), ChronxError> {
         let bytes = bincode::serialize(record).map_err(|e| ChronxError::Serialization(e.to_string()))?;
-        self.lock_extension_requests.insert(&record.lock_id, bytes).map_err(|e| ChronxError::Storage(e.to_string()))?;
+        batch.insert(&self.lock_extension_requests, record.lock_id, bytes);
         Ok(())
     }
 
@@ -2609,8 +5344,13 @@ impl StateDb {
     // ── Charge-Off Records ───────────────────────────────────────────────
 
     pub fn put_charge_off(&self, record: &chronx_core::transaction::ChargeOffRecord) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_charge_off(batch, record))
+    }
+
+    /// [`put_charge_off`](Self::put_charge_off) into `batch`.
+    pub fn stage_charge_off<'a>(&'a self, batch: &mut WriteBatch<'a>, record: &chronx_core::transaction::ChargeOffRecord) -> Result<(), ChronxError> {
         let bytes = bincode::serialize(record).map_err(|e| ChronxError::Serialization(e.to_string()))?;
-        self.charge_offs.insert(&record.loan_id, bytes).map_err(|e| ChronxError::Storage(e.to_string()))?;
+        batch.insert(&self.charge_offs, record.loan_id, bytes);
         Ok(())
     }
 
@@ -2721,21 +5461,26 @@ impl StateDb {
     // ── Credit Facility Records ──────────────────────────────────────────
 
     pub fn put_credit_facility(&self, record: &chronx_core::transaction::CreditFacilityRecord) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_credit_facility(batch, record))
+    }
+
+    /// [`put_credit_facility`](Self::put_credit_facility) into `batch`.
+    pub fn stage_credit_facility<'a>(&'a self, batch: &mut WriteBatch<'a>, record: &chronx_core::transaction::CreditFacilityRecord) -> Result<(), ChronxError> {
         let bytes = bincode::serialize(record).map_err(|e| ChronxError::Serialization(e.to_string()))?;
-        self.credit_facilities.insert(&record.facility_id, bytes).map_err(|e| ChronxError::Storage(e.to_string()))?;
+        batch.insert(&self.credit_facilities, record.facility_id, bytes);
         Ok(())
     }
 
     pub fn get_credit_facility(&self, facility_id: &[u8; 32]) -> Result<Option<chronx_core::transaction::CreditFacilityRecord>, ChronxError> {
-        match self.credit_facilities.get(facility_id) {
-            Ok(Some(bytes)) => {
-                let record: chronx_core::transaction::CreditFacilityRecord =
-                    bincode::deserialize(&bytes).map_err(|e| ChronxError::Serialization(e.to_string()))?;
-                Ok(Some(record))
-            }
-            Ok(None) => Ok(None),
-            Err(e) => Err(ChronxError::Storage(e.to_string())),
-        }
+        self.get_credit_facility_staged(&self.batch(), facility_id)
+    }
+
+    /// [`get_credit_facility`](Self::get_credit_facility) as of the end of `batch`.
+    pub fn get_credit_facility_staged(&self, batch: &WriteBatch<'_>, facility_id: &[u8; 32]) -> Result<Option<chronx_core::transaction::CreditFacilityRecord>, ChronxError> {
+        batch
+            .get(&self.credit_facilities, facility_id)?
+            .map(|b| bincode::deserialize(&b).map_err(|e| ChronxError::Serialization(e.to_string())))
+            .transpose()
     }
 
     pub fn iter_credit_facilities_by_wallet(&self, wallet: &str) -> Result<Vec<chronx_core::transaction::CreditFacilityRecord>, ChronxError> {
@@ -2754,12 +5499,17 @@ impl StateDb {
     // ── Child Chain methods ──────────────────────────────────────────────────
 
     pub fn put_child_record(&self, record: &ChildChainRecordEntry) -> Result<(), ChronxError> {
+        self.write_atomic(|batch| self.stage_child_record(batch, record))
+    }
+
+    /// [`put_child_record`](Self::put_child_record) into `batch`.
+    pub fn stage_child_record<'a>(&'a self, batch: &mut WriteBatch<'a>, record: &ChildChainRecordEntry) -> Result<(), ChronxError> {
         let key = format!("child:{}:{}", record.namespace, record.record_id);
         let bytes = serde_json::to_vec(record).map_err(|e| ChronxError::Serialization(e.to_string()))?;
-        self.child_records.insert(key.as_bytes(), bytes).map_err(|e| ChronxError::Storage(e.to_string()))?;
+        batch.insert(&self.child_records, key.as_bytes(), bytes);
         // Also write to the time index
         let idx_key = format!("child_idx:{}:{}:{}", record.namespace, record.stored_at, record.record_id);
-        self.child_index.insert(idx_key.as_bytes(), record.record_id.as_bytes()).map_err(|e| ChronxError::Storage(e.to_string()))?;
+        batch.insert(&self.child_index, idx_key.as_bytes(), record.record_id.as_bytes());
         Ok(())
     }
 
@@ -2839,18 +5589,25 @@ impl StateDb {
     }
 
     pub fn get_child_records_count_since(&self, namespace: &str, since_timestamp: u64) -> Result<u64, ChronxError> {
+        self.get_child_records_count_since_staged(&self.batch(), namespace, since_timestamp)
+    }
+
+    /// [`get_child_records_count_since`](Self::get_child_records_count_since)
+    /// as of the end of `batch`.
+    pub fn get_child_records_count_since_staged(
+        &self,
+        batch: &WriteBatch<'_>,
+        namespace: &str,
+        since_timestamp: u64,
+    ) -> Result<u64, ChronxError> {
         let from_key = format!("child_idx:{}:{}", namespace, since_timestamp);
         let prefix = format!("child_idx:{}:", namespace);
-        let mut count = 0u64;
-        for item in self.child_index.range(from_key.as_bytes()..) {
-            let (key, _) = item.map_err(|e| ChronxError::Storage(e.to_string()))?;
-            let key_str = std::str::from_utf8(&key).unwrap_or("");
-            if !key_str.starts_with(&prefix) {
-                break;
-            }
-            count += 1;
-        }
-        Ok(count)
+        let count = batch
+            .scan_prefix(&self.child_index, prefix.as_bytes())?
+            .into_iter()
+            .filter(|(key, _)| key.as_slice() >= from_key.as_bytes())
+            .count();
+        Ok(count as u64)
     }
 
     pub fn get_child_oldest_newest(&self, namespace: &str) -> Result<(u64, u64), ChronxError> {
@@ -2872,3 +5629,42 @@ impl StateDb {
         Ok((oldest, newest))
     }
 }
+
+/// Tags are indexed trimmed and lower-cased so lookups are case-insensitive.
+fn normalize_tag(tag: &str) -> String {
+    tag.trim().to_lowercase()
+}
+
+fn unlock_index_key(unlock_at: i64, lock_id: &TxId) -> Vec<u8> {
+    let mut key = unlock_at.to_be_bytes().to_vec();
+    key.extend_from_slice(lock_id.as_bytes());
+    key
+}
+
+fn party_index_key(account: &AccountId, lock_id: &TxId) -> Vec<u8> {
+    let mut key = account.as_bytes().to_vec();
+    key.extend_from_slice(lock_id.as_bytes());
+    key
+}
+
+fn locked_incoming_key(amount: u128, id: &AccountId) -> Vec<u8> {
+    let mut key = amount.to_be_bytes().to_vec();
+    key.extend_from_slice(id.as_bytes());
+    key
+}
+
+fn creation_index_key(created_at: i64, id: &AccountId) -> Vec<u8> {
+    let mut key = created_at.to_be_bytes().to_vec();
+    key.extend_from_slice(id.as_bytes());
+    key
+}
+
+fn client_ref_search_key(client_ref: &[u8; 16]) -> Vec<u8> {
+    [CLIENT_REF_SEARCH_PREFIX, client_ref.as_slice()].concat()
+}
+
+fn tag_index_key(tag: &str, lock_id: &TxId) -> Vec<u8> {
+    let mut key = normalize_tag(tag).into_bytes();
+    key.extend_from_slice(lock_id.as_bytes());
+    key
+}
@@ -1,5 +1,13 @@
+pub mod batch;
+pub mod checkpoint;
+pub mod compact;
 pub mod db;
 pub mod engine;
+pub mod metrics;
+pub mod migrate;
+pub mod snapshot;
 
-pub use db::StateDb;
-pub use engine::StateEngine;
+pub use batch::WriteBatch;
+pub use db::{AuditReport, ConsistencyReport, StateDb};
+pub use engine::{Simulation, StateEngine};
+pub use metrics::MetricsHandle;
@@ -0,0 +1,92 @@
+//! Prometheus metrics for the state engine.
+//!
+//! A [`MetricsHandle`] registers its collectors with a registry (the node
+//! uses `prometheus::default_registry()`, which its `/metrics` endpoint
+//! serves). `StateEngine::apply` records into it when the engine was built
+//! with `StateEngine::new_with_metrics`; the gauges are set by the node's
+//! periodic metrics task.
+
+use std::time::Duration;
+
+use chronx_core::error::ChronxError;
+use prometheus::{exponential_buckets, Counter, CounterVec, Gauge, Histogram, HistogramOpts, Opts, Registry};
+
+/// Number of `apply_duration_us` buckets, spaced exponentially from
+/// 100 µs to 10 s.
+const APPLY_DURATION_BUCKETS: usize = 20;
+
+#[derive(Clone)]
+pub struct MetricsHandle {
+    /// Time spent in `StateEngine::apply`, in microseconds.
+    pub apply_duration_us: Histogram,
+    pub apply_success_total: Counter,
+    /// Rejected transactions, labelled by `ChronxError` variant.
+    pub apply_error_total: CounterVec,
+    pub dag_depth: Gauge,
+    pub account_count: Gauge,
+}
+
+impl MetricsHandle {
+    /// Create the collectors and register them with `registry`. Fails if
+    /// they are already registered there.
+    pub fn new(registry: &Registry) -> prometheus::Result<Self> {
+        let factor = 10f64.powf(5.0 / (APPLY_DURATION_BUCKETS - 1) as f64);
+        let apply_duration_us = Histogram::with_opts(
+            HistogramOpts::new("chronx_apply_duration_us", "Time to apply one transaction, in microseconds")
+                .buckets(exponential_buckets(100.0, factor, APPLY_DURATION_BUCKETS)?),
+        )?;
+        let apply_success_total =
+            Counter::new("chronx_apply_success_total", "Transactions applied successfully")?;
+        let apply_error_total = CounterVec::new(
+            Opts::new("chronx_apply_error_total", "Transactions rejected by the state engine"),
+            &["error_type"],
+        )?;
+        let dag_depth = Gauge::new("chronx_dag_depth", "Greatest depth among the DAG tips")?;
+        let account_count = Gauge::new("chronx_account_count", "Accounts in the state database")?;
+
+        registry.register(Box::new(apply_duration_us.clone()))?;
+        registry.register(Box::new(apply_success_total.clone()))?;
+        registry.register(Box::new(apply_error_total.clone()))?;
+        registry.register(Box::new(dag_depth.clone()))?;
+        registry.register(Box::new(account_count.clone()))?;
+        Ok(Self { apply_duration_us, apply_success_total, apply_error_total, dag_depth, account_count })
+    }
+
+    /// Record one `apply` call that took `elapsed` and failed with `error`,
+    /// if it failed.
+    pub fn record_apply(&self, elapsed: Duration, error: Option<&ChronxError>) {
+        self.apply_duration_us.observe(elapsed.as_micros() as f64);
+        match error {
+            None => self.apply_success_total.inc(),
+            Some(e) => self.apply_error_total.with_label_values(&[&e.variant_name()]).inc(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buckets_span_100us_to_10s() {
+        let registry = Registry::new();
+        MetricsHandle::new(&registry).unwrap();
+        let family = registry.gather().into_iter().find(|f| f.get_name() == "chronx_apply_duration_us").unwrap();
+        let bounds: Vec<f64> = family.get_metric()[0]
+            .get_histogram()
+            .get_bucket()
+            .iter()
+            .map(|b| b.get_upper_bound())
+            .collect();
+        assert_eq!(bounds.len(), APPLY_DURATION_BUCKETS);
+        assert!((bounds[0] - 100.0).abs() < 1e-6);
+        assert!((bounds[APPLY_DURATION_BUCKETS - 1] - 10_000_000.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn error_type_is_the_variant_name() {
+        assert_eq!(ChronxError::ZeroAmount.variant_name(), "ZeroAmount");
+        assert_eq!(ChronxError::LockAmountTooSmall { min: 1 }.variant_name(), "LockAmountTooSmall");
+        assert_eq!(ChronxError::Storage("x".into()).variant_name(), "Storage");
+    }
+}
@@ -0,0 +1,323 @@
+//! Storage layout migrations.
+//!
+//! Accounts, vertices, locks and claim states are stored with bincode, which
+//! writes fields back to back with no names or lengths. Adding a field with
+//! `#[serde(default)]` therefore does not keep old records readable: bincode
+//! runs out of bytes, or misreads the next field, instead of falling back to
+//! the default. Each layout change is a numbered step here instead, and
+//! [`StateDb::open`](crate::StateDb::open) runs the steps a database has not
+//! had yet, recording the last one under [`STORAGE_LAYOUT_KEY`].
+
+use bincode::Options;
+use chronx_core::account::{AuthPolicy, RecoveryDecisionStatus};
+use chronx_core::error::ChronxError;
+use chronx_core::transaction::Action;
+use chronx_core::types::{AccountId, Balance, DilithiumPublicKey, EvidenceHash, Nonce, Timestamp, TxId};
+use serde::de::DeserializeOwned;
+
+/// Meta key of the layout version the stored records are in, 4 big-endian
+/// bytes. Missing on databases written before versioning, which are treated
+/// as version 0.
+pub const STORAGE_LAYOUT_KEY: &str = "storage_layout_version";
+
+/// Layout version written by this build:
+///
+/// 1. `Vertex` gained `balance_deltas` and `difficulty`, and the
+///    `StartRecovery`/`ChallengeRecovery` actions in its transaction gained
+///    an evidence URI.
+/// 2. `RecoveryState` gained `initiator`, `challenger`, `voters` and the two
+///    evidence URIs, and `Account` gained `nonce_bitmap`, `votes_cast`,
+///    `verifier_last_vote_at` and `verifier_active_vote_count`.
+/// 3. `TimeLockContract` gained `next_recurring_index`,
+///    `recurring_series_id`, `split_policy_validated` and
+///    `cancellation_reason`, and `ClaimState` gained `claim_attempts`,
+///    `upgraded_lane` and `lane_upgraded_at`. Variants appended to
+///    `TimeLockStatus`, such as `Expired`, need no step of their own: bincode
+///    stores a variant by index, and the existing indices did not move.
+pub const STORAGE_LAYOUT_VERSION: u32 = 3;
+
+/// Bincode variant indices of the actions whose fields changed.
+const START_RECOVERY_INDEX: u32 = 5;
+const CHALLENGE_RECOVERY_INDEX: u32 = 6;
+
+/// Decode `bytes` as `T`, failing if any are left over. Plain
+/// `bincode::deserialize` ignores trailing bytes, which would let a record in
+/// an older layout pass for the current one.
+fn decode_exact<T: DeserializeOwned>(bytes: &[u8]) -> bincode::Result<T> {
+    bincode::DefaultOptions::new()
+        .with_fixint_encoding()
+        .reject_trailing_bytes()
+        .deserialize(bytes)
+}
+
+/// Skip one `T` at the front of `rest`, returning the bytes it took up.
+fn skip<'a, T: DeserializeOwned>(rest: &mut &'a [u8]) -> bincode::Result<&'a [u8]> {
+    let start = *rest;
+    bincode::deserialize_from::<_, T>(&mut *rest)?;
+    Ok(&start[..start.len() - rest.len()])
+}
+
+fn encode<T: serde::Serialize>(value: &T) -> Vec<u8> {
+    bincode::serialize(value).expect("serializing plain values is infallible")
+}
+
+/// Rewrite every record in `tree` that does not decode as `T` with
+/// `upgrade`, in one batch. Records already in the current layout are left
+/// alone, so a step interrupted part way can simply run again. Returns how
+/// many records were rewritten.
+pub(crate) fn rewrite_tree<T: DeserializeOwned>(
+    tree: &sled::Tree,
+    name: &str,
+    upgrade: fn(&[u8]) -> bincode::Result<Vec<u8>>,
+) -> Result<usize, ChronxError> {
+    let mut batch = sled::Batch::default();
+    let mut rewritten = 0;
+    for entry in tree.iter() {
+        let (key, value) = entry.map_err(|e| ChronxError::Storage(e.to_string()))?;
+        if decode_exact::<T>(&value).is_ok() {
+            continue;
+        }
+        let upgraded = upgrade(&value)
+            .and_then(|bytes| decode_exact::<T>(&bytes).map(|_| bytes))
+            .map_err(|e| {
+                ChronxError::Serialization(format!("{name} record {} has an unknown layout: {e}", hex::encode(&key)))
+            })?;
+        batch.insert(key, upgraded);
+        rewritten += 1;
+    }
+    tree.apply_batch(batch).map_err(|e| ChronxError::Storage(e.to_string()))?;
+    Ok(rewritten)
+}
+
+/// Version 1 of a stored `Vertex`: copy the transaction, adding a `None`
+/// evidence URI to each `StartRecovery` and `ChallengeRecovery`, then append
+/// empty `balance_deltas` and no `difficulty`.
+pub(crate) fn upgrade_vertex_v1(old: &[u8]) -> bincode::Result<Vec<u8>> {
+    let mut rest = old;
+    // tx_id, parents, timestamp, nonce, from
+    let mut out = skip::<(TxId, Vec<TxId>, Timestamp, Nonce, AccountId)>(&mut rest)?.to_vec();
+    let count: u64 = bincode::deserialize_from(&mut rest)?;
+    out.extend(encode(&count));
+    for _ in 0..count {
+        let index = rest
+            .get(..4)
+            .map(|b| u32::from_le_bytes(b.try_into().expect("four bytes")))
+            .ok_or_else(|| Box::new(bincode::ErrorKind::Custom("truncated action".into())))?;
+        match index {
+            START_RECOVERY_INDEX => {
+                out.extend(skip::<(u32, AccountId, DilithiumPublicKey, EvidenceHash, Balance)>(&mut rest)?);
+                out.extend(encode(&None::<String>));
+            }
+            CHALLENGE_RECOVERY_INDEX => {
+                out.extend(skip::<(u32, AccountId, EvidenceHash, Balance)>(&mut rest)?);
+                out.extend(encode(&None::<String>));
+            }
+            _ => out.extend(skip::<Action>(&mut rest)?),
+        }
+    }
+    // The rest of the transaction and the vertex are unchanged.
+    out.extend_from_slice(rest);
+    out.extend(encode(&(Vec::<(AccountId, i128)>::new(), None::<u8>)));
+    Ok(out)
+}
+
+/// The `RecoveryState` fields a version 1 account was written with.
+type RecoveryStateV1 = (
+    bool,
+    Option<DilithiumPublicKey>,
+    Option<Timestamp>,
+    Option<Timestamp>,
+    Balance,
+    Balance,
+    RecoveryDecisionStatus,
+    Option<EvidenceHash>,
+    Option<EvidenceHash>,
+    Vec<TxId>,
+    Vec<TxId>,
+    bool,
+);
+
+/// Version 2 of a stored `Account`: insert the new `RecoveryState` fields
+/// after the old ones and append the new account fields, all at their
+/// defaults.
+pub(crate) fn upgrade_account_v2(old: &[u8]) -> bincode::Result<Vec<u8>> {
+    let mut rest = old;
+    // account_id, balance, auth_policy, nonce, recovery_state
+    let mut out = skip::<(AccountId, Balance, AuthPolicy, Nonce, RecoveryStateV1)>(&mut rest)?.to_vec();
+    out.extend(encode(&(None::<AccountId>, None::<AccountId>, Vec::<AccountId>::new(), None::<String>, None::<String>)));
+    out.extend_from_slice(rest);
+    out.extend(encode(&(0u64, 0u64, None::<Timestamp>, 0u32)));
+    Ok(out)
+}
+
+/// Version 3 of a stored `TimeLockContract`: append the new fields at their
+/// defaults.
+pub(crate) fn upgrade_timelock_v3(old: &[u8]) -> bincode::Result<Vec<u8>> {
+    let mut out = old.to_vec();
+    out.extend(encode(&(0u32, None::<TxId>, false, None::<String>)));
+    Ok(out)
+}
+
+/// Version 3 of a stored `ClaimState`: append the new fields at their
+/// defaults.
+pub(crate) fn upgrade_claim_state_v3(old: &[u8]) -> bincode::Result<Vec<u8>> {
+    let mut out = old.to_vec();
+    out.extend(encode(&(0u8, false, None::<Timestamp>)));
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StateDb;
+    use chronx_dag::vertex::Vertex;
+    use std::path::PathBuf;
+
+    /// Records encoded by the code at the baseline commit, before any of the
+    /// layout changes above.
+    const BASELINE_ACCOUNT: &str = "0101010101010101010101010101010101010101010101010101010101010101881300000000000000000000000000000000000003000000000000000707070300000000000000010102000000000000000909016400000000000000002a0000000000000000000000000000000000000000000000000000000000000000000000000001000000000000000303030303030303030303030303030303030303030303030303030303030303000000000000000000000b000000000000000000000000000000010100013200000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001030000000000000045555200080000000000000000000000000000000000";
+    const BASELINE_TIMELOCK: &str = "0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0101010101010101010101010101010101010101010101010101010101010101030000000000000007070702020202020202020202020202020202020202020202020202020202020202024d000000000000000000000000000000d007000000000000e8030000000000000100000034080000000000000101000000000000006d000000000000010101000000000000000100000000000000740001010000000000000000000000000000000000000000000000000000000000000000000000000000000000";
+    const BASELINE_CLAIM: &str = "0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a01f401000000000000020800000000000000000300000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000";
+    const BASELINE_VERTEX: &str = "060606060606060606060606060606060606060606060606060606060606060601000000000000000707070707070707070707070707070707070707070707070707070707070707e8030000000000000200000000000000010101010101010101010101010101010101010101010101010101010101010104000000000000000000000002020202020202020202020202020202020202020202020202020202020202020a00000000000000000000000000000000000000050000000202020202020202020202020202020202020202020202020202020202020202030000000000000007070704040404040404040404040404040404040404040404040404040404040404040c00000000000000000000000000000006000000020202020202020202020202020202020202020202020202020202020202020205050505050505050505050505050505050505050505050505050505050505050d00000000000000000000000000000009000000020202020202020202020202020202020202020202020202020202020202020201010000000000000000000000000000006300000000000000010000000000000003000000000000000102030000000001000000000000000000000000000000000000000103000000000000000707070400000000000000e903000000000000000000000100000000000000080808080808080808080808080808080808080808080808080808080808080800000000010909090909090909090909090909090909090909090909090909090909090909";
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("chronx_migrate_test_{name}"));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    /// A sled database holding `records` as written by the baseline code.
+    fn baseline_db(name: &str, records: &[(&str, &[u8], &str)]) -> PathBuf {
+        let dir = temp_dir(name);
+        let db = sled::open(&dir).unwrap();
+        for (tree, key, hex_value) in records {
+            db.open_tree(tree).unwrap().insert(key, hex::decode(hex_value).unwrap()).unwrap();
+        }
+        db.flush().unwrap();
+        dir
+    }
+
+    #[test]
+    fn changed_actions_keep_their_baseline_indices() {
+        let index = |a: &Action| u32::from_le_bytes(encode(a)[..4].try_into().unwrap());
+        let start = Action::StartRecovery {
+            target_account: AccountId::from_bytes([0; 32]),
+            proposed_owner_key: DilithiumPublicKey(vec![]),
+            evidence_hash: EvidenceHash([0; 32]),
+            bond_amount: 0,
+            evidence_uri: None,
+        };
+        let challenge = Action::ChallengeRecovery {
+            target_account: AccountId::from_bytes([0; 32]),
+            counter_evidence_hash: EvidenceHash([0; 32]),
+            bond_amount: 0,
+            counter_evidence_uri: None,
+        };
+        assert_eq!(index(&start), START_RECOVERY_INDEX);
+        assert_eq!(index(&challenge), CHALLENGE_RECOVERY_INDEX);
+        assert_eq!(index(&Action::RegisterVerifier { stake_amount: 0 }), 8);
+    }
+
+    #[test]
+    fn opening_a_baseline_database_migrates_its_vertices() {
+        let tx_id = TxId::from_bytes([6u8; 32]);
+        let raw = hex::decode(BASELINE_VERTEX).unwrap();
+        assert!(decode_exact::<Vertex>(&raw).is_err());
+        let dir = baseline_db("vertex", &[("vertices", tx_id.as_bytes(), BASELINE_VERTEX)]);
+
+        let db = StateDb::open(&dir).unwrap();
+        let v = db.get_vertex(&tx_id).unwrap().unwrap();
+        assert_eq!((v.depth, v.received_at, v.state_root), (4, 1_001, Some([9u8; 32])));
+        assert!(v.balance_deltas.is_empty());
+        assert_eq!(v.difficulty, None);
+        let names: Vec<_> = v.transaction.actions.iter().map(Action::name).collect();
+        assert_eq!(names, ["Transfer", "StartRecovery", "ChallengeRecovery", "VoteRecovery"]);
+        assert!(matches!(
+            &v.transaction.actions[1],
+            Action::StartRecovery { bond_amount: 12, evidence_uri: None, .. }
+        ));
+        assert!(matches!(
+            &v.transaction.actions[2],
+            Action::ChallengeRecovery { bond_amount: 13, counter_evidence_uri: None, .. }
+        ));
+        assert_eq!(v.transaction.pow_nonce, 99);
+        assert_eq!(db.get_meta(STORAGE_LAYOUT_KEY).unwrap(), Some(STORAGE_LAYOUT_VERSION.to_be_bytes().to_vec()));
+        drop(db);
+
+        // Reopening finds nothing left to do.
+        let db = StateDb::open(&dir).unwrap();
+        assert_eq!(db.get_vertex(&tx_id).unwrap().unwrap().depth, 4);
+        drop(db);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn opening_a_baseline_database_migrates_accounts_with_recovery_state() {
+        use chronx_core::account::Account;
+        let id = AccountId::from_bytes([1u8; 32]);
+        assert!(decode_exact::<Account>(&hex::decode(BASELINE_ACCOUNT).unwrap()).is_err());
+        let dir = baseline_db("account", &[("accounts", id.as_bytes(), BASELINE_ACCOUNT)]);
+
+        let db = StateDb::open(&dir).unwrap();
+        let acc = db.get_account(&id).unwrap().unwrap();
+        assert_eq!((acc.balance, acc.nonce, acc.verifier_stake, acc.is_verifier), (5_000, 3, 11, true));
+        let rs = &acc.recovery_state;
+        assert!(rs.active);
+        assert_eq!(rs.proposed_owner_key, Some(DilithiumPublicKey(vec![9, 9])));
+        assert_eq!((rs.recovery_start_time, rs.recovery_bond), (Some(100), 42));
+        assert_eq!(rs.votes_approve, vec![TxId::from_bytes([3u8; 32])]);
+        assert_eq!((rs.initiator.as_ref(), rs.voters.len(), rs.evidence_uri.as_ref()), (None, 0, None));
+        assert_eq!((acc.created_at, acc.savings_balance), (Some(50), 8));
+        assert_eq!(acc.preferred_fiat_currency.as_deref(), Some("EUR"));
+        assert_eq!((acc.nonce_bitmap, acc.votes_cast, acc.verifier_active_vote_count), (0, 0, 0));
+        assert_eq!(acc.verifier_last_vote_at, None);
+
+        // The migrated record round-trips through the current layout.
+        db.put_account(&acc).unwrap();
+        let again = db.get_account(&id).unwrap().unwrap();
+        assert_eq!(encode(&again), encode(&acc));
+        drop(db);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn opening_a_baseline_database_migrates_locks_and_claim_states() {
+        use chronx_core::account::{ExpiryPolicy, TimeLockStatus};
+        let id = TxId::from_bytes([10u8; 32]);
+        let dir = baseline_db(
+            "timelock",
+            &[
+                ("timelocks", id.as_bytes(), BASELINE_TIMELOCK),
+                ("claims", id.as_bytes(), BASELINE_CLAIM),
+            ],
+        );
+
+        let db = StateDb::open(&dir).unwrap();
+        let lock = db.get_timelock(&id).unwrap().unwrap();
+        assert_eq!((lock.amount, lock.unlock_at), (77, 2_000));
+        assert_eq!(lock.status, TimeLockStatus::Claimed { claimed_at: 2_100 });
+        assert_eq!(lock.expiry_policy, Some(ExpiryPolicy::Burn));
+        assert_eq!(lock.tags, Some(vec!["t".to_string()]));
+        assert_eq!(lock.next_recurring_index, 0);
+        assert_eq!(lock.recurring_series_id, None);
+        assert!(!lock.split_policy_validated);
+        assert_eq!(lock.cancellation_reason, None);
+
+        let claim = db.get_claim(&id).unwrap().unwrap();
+        assert_eq!((claim.lane, claim.v_claim_snapshot, claim.opened_at), (1, 500, 2_050));
+        assert_eq!(claim.commit_bond, 3);
+        assert_eq!((claim.claim_attempts, claim.upgraded_lane, claim.lane_upgraded_at), (0, false, None));
+        drop(db);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn current_records_are_left_as_they_are() {
+        let tree = sled::Config::new().temporary(true).open().unwrap().open_tree("t").unwrap();
+        tree.insert(b"ok", encode(&7u64)).unwrap();
+        assert_eq!(rewrite_tree::<u64>(&tree, "t", |_| unreachable!()).unwrap(), 0);
+        tree.insert(b"bad", vec![1u8]).unwrap();
+        let err = rewrite_tree::<u64>(&tree, "t", |b| Ok(b.to_vec())).unwrap_err();
+        assert!(err.to_string().contains("unknown layout"), "{err}");
+    }
+}
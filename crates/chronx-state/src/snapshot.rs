@@ -0,0 +1,256 @@
+//! Numbered snapshots of the state database.
+//!
+//! A snapshot is a copy of the sled directory taken right after a flush,
+//! stored as `<data_dir>/snapshots/<snapshot_id>/` next to a
+//! `snapshot_meta.json` describing what it holds. Unlike a checkpoint it is
+//! keyed by DAG progress rather than wall-clock time, and a restore checks
+//! the copy against its metadata before handing it back. Created by
+//! [`StateDb::take_snapshot`](crate::StateDb::take_snapshot) and restored by
+//! [`StateDb::restore_from_snapshot`](crate::StateDb::restore_from_snapshot);
+//! this module lists and prunes them.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chronx_core::error::ChronxError;
+use serde::{Deserialize, Serialize};
+
+/// Directory under a data dir that holds its snapshots.
+pub const SNAPSHOTS_DIR: &str = "snapshots";
+
+/// File inside each snapshot describing its contents.
+pub const SNAPSHOT_META_FILE: &str = "snapshot_meta.json";
+
+/// Contents of `snapshot_meta.json`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotMeta {
+    pub snapshot_id: u64,
+    /// Unix timestamp the snapshot was taken.
+    pub created_at: i64,
+    pub vertex_count: u64,
+    pub account_count: u64,
+    /// DAG tips at the time of the snapshot, hex-encoded and sorted.
+    pub tip_tx_ids: Vec<String>,
+}
+
+/// One snapshot directory.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SnapshotInfo {
+    pub path: PathBuf,
+    pub meta: SnapshotMeta,
+}
+
+fn io_err(e: std::io::Error) -> ChronxError {
+    ChronxError::Storage(e.to_string())
+}
+
+/// Directory for snapshot `snapshot_id` of the database at `data_dir`.
+pub fn snapshot_path(data_dir: &Path, snapshot_id: u64) -> PathBuf {
+    data_dir.join(SNAPSHOTS_DIR).join(snapshot_id.to_string())
+}
+
+/// Read and check the metadata of the snapshot at `path`.
+pub fn read_snapshot_meta(path: &Path) -> Result<SnapshotMeta, ChronxError> {
+    let bytes = fs::read(path.join(SNAPSHOT_META_FILE))
+        .map_err(|e| ChronxError::Storage(format!("{} is not a snapshot: {e}", path.display())))?;
+    let meta: SnapshotMeta = serde_json::from_slice(&bytes)
+        .map_err(|e| ChronxError::Serialization(format!("{SNAPSHOT_META_FILE} in {}: {e}", path.display())))?;
+    if let Some(bad) = meta.tip_tx_ids.iter().find(|t| chronx_core::types::TxId::from_hex(t).is_err()) {
+        return Err(ChronxError::Storage(format!("snapshot {} lists invalid tip {bad}", path.display())));
+    }
+    if !path.join("db").is_file() {
+        return Err(ChronxError::Storage(format!("snapshot {} holds no database", path.display())));
+    }
+    Ok(meta)
+}
+
+pub(crate) fn write_snapshot_meta(path: &Path, meta: &SnapshotMeta) -> Result<(), ChronxError> {
+    let bytes = serde_json::to_vec_pretty(meta).map_err(|e| ChronxError::Serialization(e.to_string()))?;
+    fs::write(path.join(SNAPSHOT_META_FILE), bytes).map_err(io_err)
+}
+
+/// Every snapshot under `<data_dir>/snapshots`, oldest first. Directories
+/// without readable metadata, including half-written ones, are skipped. A
+/// missing directory yields an empty list.
+pub fn list_snapshots(data_dir: &Path) -> Result<Vec<SnapshotInfo>, ChronxError> {
+    let entries = match fs::read_dir(data_dir.join(SNAPSHOTS_DIR)) {
+        Ok(e) => e,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(io_err(e)),
+    };
+    let mut out = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(io_err)?;
+        let is_numbered = entry.file_name().to_str().is_some_and(|n| n.parse::<u64>().is_ok());
+        if !is_numbered || !entry.file_type().map_err(io_err)?.is_dir() {
+            continue;
+        }
+        let path = entry.path();
+        if let Ok(meta) = read_snapshot_meta(&path) {
+            out.push(SnapshotInfo { path, meta });
+        }
+    }
+    out.sort_by_key(|s| s.meta.snapshot_id);
+    Ok(out)
+}
+
+/// Delete all but the newest `keep` snapshots. Returns how many were removed.
+pub fn prune_snapshots(data_dir: &Path, keep: usize) -> Result<usize, ChronxError> {
+    let all = list_snapshots(data_dir)?;
+    let excess = all.len().saturating_sub(keep);
+    for snapshot in &all[..excess] {
+        fs::remove_dir_all(&snapshot.path).map_err(io_err)?;
+    }
+    Ok(excess)
+}
+
+/// `src` itself if it is a snapshot, otherwise the newest snapshot under
+/// `<src>/snapshots`.
+pub fn resolve_snapshot(src: &Path) -> Result<PathBuf, ChronxError> {
+    if src.join(SNAPSHOT_META_FILE).is_file() {
+        return Ok(src.to_path_buf());
+    }
+    list_snapshots(src)?
+        .pop()
+        .map(|s| s.path)
+        .ok_or_else(|| ChronxError::Storage(format!("no snapshot found in {}", src.display())))
+}
+
+/// Copy the database files in `src` into `dest`, leaving out the snapshots
+/// directory and snapshot metadata so a snapshot never contains another.
+pub(crate) fn copy_db_files(src: &Path, dest: &Path) -> Result<(), ChronxError> {
+    fs::create_dir_all(dest).map_err(io_err)?;
+    for entry in fs::read_dir(src).map_err(io_err)? {
+        let entry = entry.map_err(io_err)?;
+        let name = entry.file_name();
+        if name == SNAPSHOTS_DIR || name == SNAPSHOT_META_FILE {
+            continue;
+        }
+        let target = dest.join(&name);
+        if entry.file_type().map_err(io_err)?.is_dir() {
+            crate::checkpoint::copy_dir(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), &target).map_err(io_err)?;
+        }
+    }
+    Ok(())
+}
+
+/// `path` with `suffix` appended to its final component.
+pub(crate) fn sibling(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StateDb;
+    use chronx_core::account::{Account, AuthPolicy};
+    use chronx_core::types::{AccountId, DilithiumPublicKey, TxId};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("chronx_snapshot_test_{name}"));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn seed(db: &StateDb, n: u8) {
+        let policy = AuthPolicy::SingleSig { public_key: DilithiumPublicKey(vec![n]) };
+        db.put_account(&Account::new(AccountId::from_bytes([n; 32]), policy)).unwrap();
+        db.add_tip(&TxId::from_bytes([n; 32])).unwrap();
+    }
+
+    #[test]
+    fn snapshot_round_trips_into_a_fresh_dir() {
+        let root = temp_dir("roundtrip");
+        let data = root.join("data");
+        let path = {
+            let db = StateDb::open(&data).unwrap();
+            seed(&db, 1);
+            seed(&db, 2);
+            let path = db.take_snapshot(7, &data).unwrap();
+            seed(&db, 3);
+            db.flush().unwrap();
+            path
+        };
+        assert_eq!(path, snapshot_path(&data, 7));
+
+        let meta = read_snapshot_meta(&path).unwrap();
+        assert_eq!(meta.snapshot_id, 7);
+        assert_eq!(meta.account_count, 2);
+        assert_eq!(meta.tip_tx_ids, vec![hex::encode([1u8; 32]), hex::encode([2u8; 32])]);
+
+        let restored = StateDb::restore_from_snapshot(&path, &root.join("restored")).unwrap();
+        assert_eq!(restored.count_accounts(), 2);
+        assert!(restored.get_account(&AccountId::from_bytes([3; 32])).unwrap().is_none());
+        // The restored copy carries neither the snapshot tree nor its metadata.
+        assert!(!root.join("restored").join(SNAPSHOTS_DIR).exists());
+        assert!(!root.join("restored").join(SNAPSHOT_META_FILE).exists());
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn restore_over_the_live_dir_keeps_it_and_its_snapshots() {
+        let root = temp_dir("in_place");
+        let data = root.join("data");
+        {
+            let db = StateDb::open(&data).unwrap();
+            seed(&db, 1);
+            db.take_snapshot(1, &data).unwrap();
+            seed(&db, 2);
+            db.flush().unwrap();
+        }
+
+        let db = StateDb::restore_from_snapshot(&resolve_snapshot(&data).unwrap(), &data).unwrap();
+        assert_eq!(db.count_accounts(), 1);
+        assert_eq!(list_snapshots(&data).unwrap().len(), 1);
+        let aside = fs::read_dir(&root)
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_name().to_string_lossy().starts_with("data.pre-restore-"))
+            .count();
+        assert_eq!(aside, 1);
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn restore_rejects_a_snapshot_that_disagrees_with_its_metadata() {
+        let root = temp_dir("mismatch");
+        let data = root.join("data");
+        let path = {
+            let db = StateDb::open(&data).unwrap();
+            seed(&db, 1);
+            db.take_snapshot(1, &data).unwrap()
+        };
+        let mut meta = read_snapshot_meta(&path).unwrap();
+        meta.account_count = 5;
+        write_snapshot_meta(&path, &meta).unwrap();
+
+        let err = StateDb::restore_from_snapshot(&path, &root.join("restored")).err().unwrap();
+        assert!(err.to_string().contains("does not match"), "{err}");
+        assert!(!root.join("restored").exists());
+
+        fs::remove_file(path.join(SNAPSHOT_META_FILE)).unwrap();
+        assert!(StateDb::restore_from_snapshot(&path, &root.join("restored")).is_err());
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn prune_keeps_newest_and_skips_partial_snapshots() {
+        let root = temp_dir("prune");
+        let data = root.join("data");
+        let db = StateDb::open(&data).unwrap();
+        for id in [5, 1, 3, 2, 4] {
+            db.take_snapshot(id, &data).unwrap();
+        }
+        fs::create_dir_all(data.join(SNAPSHOTS_DIR).join("6")).unwrap();
+
+        assert_eq!(prune_snapshots(&data, 3).unwrap(), 2);
+        let left: Vec<u64> = list_snapshots(&data).unwrap().iter().map(|s| s.meta.snapshot_id).collect();
+        assert_eq!(left, vec![3, 4, 5]);
+        assert!(data.join(SNAPSHOTS_DIR).join("6").exists());
+        let _ = fs::remove_dir_all(&root);
+    }
+}
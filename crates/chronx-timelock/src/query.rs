@@ -112,6 +112,9 @@ impl<'a> TimeLockQuery<'a> {
             TimeLockStatus::AttestorFailed { ref failed_group_id, ref escalated_to } => {
                 format!("AttestorFailed group={} escalated_to={:?}", failed_group_id, escalated_to)
             }
+            TimeLockStatus::Expired { action_taken, expired_at } => {
+                format!("Expired at Unix timestamp {} — {:?}", expired_at, action_taken)
+            }
         };
 
         Ok(format!(
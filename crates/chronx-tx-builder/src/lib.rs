@@ -0,0 +1,346 @@
+//! chronx-tx-builder
+//!
+//! Builds ready-to-submit ChronX transactions for wallets and external Rust
+//! integrations. [`TxBuilder`] fetches the sender's nonce and the DAG tips
+//! from a node over JSON-RPC, mines the proof of work, signs, and can submit
+//! the result with `chronx_sendTransaction`. [`sign_transaction`] does the
+//! same offline for callers that already know the nonce and parents.
+//!
+//! Mining runs on the calling thread; at the default difficulty it takes a
+//! noticeable fraction of a second.
+
+use chronx_core::constants::POW_INITIAL_DIFFICULTY;
+use chronx_core::transaction::{Action, AuthScheme, Transaction, TransactionBody};
+use chronx_core::types::{AccountId, Balance, DilithiumPublicKey, TimeLockId, Timestamp, TxId};
+use chronx_crypto::{hash::tx_id_from_body, mine_pow, KeyPair};
+use thiserror::Error;
+use tracing::info;
+
+#[derive(Debug, Error)]
+pub enum TxBuilderError {
+    #[error("connecting to node: {0}")]
+    Http(#[from] reqwest::Error),
+
+    #[error("RPC error: {0}")]
+    Rpc(serde_json::Value),
+
+    #[error("unexpected RPC response: {0}")]
+    Response(String),
+
+    #[error("encoding transaction: {0}")]
+    Encoding(#[from] bincode::Error),
+}
+
+pub type Result<T> = std::result::Result<T, TxBuilderError>;
+
+/// Builds transactions signed by `keypair` against the node at `rpc_url`.
+pub struct TxBuilder {
+    pub rpc_url: String,
+    pub keypair: KeyPair,
+    /// Leading zero bits the proof of work must have; nodes reject less
+    /// than their configured difficulty.
+    pub pow_difficulty: u8,
+    http: reqwest::Client,
+}
+
+impl TxBuilder {
+    /// A builder mining at the protocol's initial difficulty.
+    pub fn new(rpc_url: impl Into<String>, keypair: KeyPair) -> Self {
+        Self {
+            rpc_url: rpc_url.into(),
+            keypair,
+            pow_difficulty: POW_INITIAL_DIFFICULTY,
+            http: reqwest::Client::new(),
+        }
+    }
+
+    pub fn with_pow_difficulty(mut self, pow_difficulty: u8) -> Self {
+        self.pow_difficulty = pow_difficulty;
+        self
+    }
+
+    /// Send `amount` Chronos to `to`.
+    pub async fn transfer(&self, to: AccountId, amount: Balance) -> Result<Transaction> {
+        self.custom(vec![transfer_action(to, amount, None)]).await
+    }
+
+    /// Lock `amount` Chronos for the holder of `recipient_key` until `unlock_at`.
+    pub async fn timelock(
+        &self,
+        recipient_key: DilithiumPublicKey,
+        amount: Balance,
+        unlock_at: Timestamp,
+    ) -> Result<Transaction> {
+        self.custom(vec![timelock_action(recipient_key, amount, unlock_at, None)]).await
+    }
+
+    /// Claim a matured lock through the direct (V0) path.
+    pub async fn claim(&self, lock_id: TimeLockId) -> Result<Transaction> {
+        self.custom(vec![Action::TimeLockClaim { lock_id }]).await
+    }
+
+    /// A transaction carrying `actions`, using the node's current nonce for
+    /// the sender and its DAG tips as parents.
+    pub async fn custom(&self, actions: Vec<Action>) -> Result<Transaction> {
+        let nonce = self.get_nonce().await?;
+        let tips = self.get_dag_tips().await?;
+        sign_transaction(
+            &self.keypair,
+            actions,
+            tips,
+            nonce,
+            chrono::Utc::now().timestamp(),
+            self.pow_difficulty,
+        )
+    }
+
+    /// Submit `tx` with `chronx_sendTransaction`; returns the id the node
+    /// applied it under, or queued it under if it was still pending when
+    /// the node stopped waiting. A rejection comes back as an RPC error.
+    pub async fn submit(&self, tx: Transaction) -> Result<TxId> {
+        let tx_hex = hex::encode(bincode::serialize(&tx)?);
+        let result = self.call("chronx_sendTransaction", serde_json::json!([tx_hex])).await?;
+        let tx_id = result
+            .as_str()
+            .ok_or_else(|| TxBuilderError::Response(format!("expected a tx id, got {result}")))?;
+        TxId::from_hex(tx_id).map_err(|e| TxBuilderError::Response(format!("invalid tx id {tx_id}: {e}")))
+    }
+
+    async fn get_nonce(&self) -> Result<u64> {
+        let account = self
+            .call("chronx_getAccount", serde_json::json!([self.keypair.account_id.to_b58()]))
+            .await?;
+        if account.is_null() {
+            // The account does not exist yet.
+            return Ok(0);
+        }
+        account["nonce"]
+            .as_u64()
+            .ok_or_else(|| TxBuilderError::Response("missing nonce in account response".into()))
+    }
+
+    async fn get_dag_tips(&self) -> Result<Vec<TxId>> {
+        let result = self.call("chronx_getDagTips", serde_json::json!([])).await?;
+        let tips: Vec<String> =
+            serde_json::from_value(result).map_err(|e| TxBuilderError::Response(format!("DAG tips: {e}")))?;
+        tips.iter()
+            .map(|h| TxId::from_hex(h).map_err(|e| TxBuilderError::Response(format!("invalid tip {h}: {e}"))))
+            .collect()
+    }
+
+    /// Call a JSON-RPC method and return its `result`.
+    async fn call(&self, method: &str, params: serde_json::Value) -> Result<serde_json::Value> {
+        let body = serde_json::json!({ "jsonrpc": "2.0", "method": method, "params": params, "id": 1 });
+        let mut response: serde_json::Value = self.http.post(&self.rpc_url).json(&body).send().await?.json().await?;
+        if let Some(err) = response.get("error") {
+            return Err(TxBuilderError::Rpc(err.clone()));
+        }
+        Ok(response["result"].take())
+    }
+}
+
+/// Build, mine and sign a transaction from `keypair` with `nonce` and
+/// `parents`, without talking to a node.
+pub fn sign_transaction(
+    keypair: &KeyPair,
+    actions: Vec<Action>,
+    parents: Vec<TxId>,
+    nonce: u64,
+    timestamp: Timestamp,
+    pow_difficulty: u8,
+) -> Result<Transaction> {
+    // The body excludes pow_nonce, so it stays stable while mining.
+    let body_bytes = bincode::serialize(&TransactionBody {
+        parents: &parents,
+        timestamp,
+        nonce,
+        from: &keypair.account_id,
+        actions: &actions,
+        auth_scheme: &AuthScheme::SingleSig,
+    })?;
+
+    info!("Mining PoW (difficulty={})...", pow_difficulty);
+    let pow_nonce = mine_pow(&body_bytes, pow_difficulty);
+    info!("PoW solved: nonce={}", pow_nonce);
+
+    Ok(Transaction {
+        tx_id: tx_id_from_body(&body_bytes),
+        parents,
+        timestamp,
+        nonce,
+        from: keypair.account_id.clone(),
+        actions,
+        pow_nonce,
+        signatures: vec![keypair.sign(&body_bytes)],
+        auth_scheme: AuthScheme::SingleSig,
+        tx_version: 1,
+        client_ref: None,
+        fee_chronos: 0,
+        expires_at: None,
+        sender_public_key: Some(keypair.public_key.clone()),
+    })
+}
+
+/// A plain transfer with an optional (encrypted, private) memo.
+pub fn transfer_action(to: AccountId, amount: Balance, memo: Option<String>) -> Action {
+    Action::Transfer {
+        to,
+        amount,
+        memo,
+        memo_encrypted: true,
+        memo_public: false,
+        pay_as_amount: None,
+    }
+}
+
+/// A standard V0 time-lock with every optional feature left off.
+pub fn timelock_action(
+    recipient: DilithiumPublicKey,
+    amount: Balance,
+    unlock_at: Timestamp,
+    memo: Option<String>,
+) -> Action {
+    Action::TimeLockCreate {
+            recipient,
+            amount,
+            unlock_at,
+            memo,
+            cancellation_window_secs: None,
+            notify_recipient: None,
+            tags: None,
+            private: None,
+            expiry_policy: None,
+            split_policy: None,
+            claim_attempts_max: None,
+            recurring: None,
+            lock_marker: None,
+            oracle_hint: None,
+            jurisdiction_hint: None,
+            governance_proposal_id: None,
+            client_ref: None,
+            email_recipient_hash: None,
+            claim_window_secs: None,
+            unclaimed_action: None,
+            lock_type: None,
+            yield_opt_out: None,
+            lock_metadata: None,
+            agent_managed: None,
+            grantor_axiom_consent_hash: None,
+            investable_fraction: None,
+            risk_level: None,
+            investment_exclusions: None,
+            grantor_intent: None,
+            sign_of_life_interval_days: None,
+            sign_of_life_grace_days: None,
+            guardian_pubkey: None,
+            guardian_until: None,
+            alt_guardian_pubkey: None,
+            beneficiary_description: None,
+            beneficiary_description_hash: None,
+            convert_to: None,
+            authorized_claimants: None,
+            succession_group: None,
+            backup_executors: None,
+            executor_threshold: None,
+            memo_encrypted: true,
+            memo_public: false,
+            pay_as_amount: None,
+            beneficiary_package: None,
+            transferable: None,
+            current_owner_account: None,
+            transfer_history: None,
+            terms_visibility: None,
+            tranche_info: None,
+            retirement_status: None,
+            retired_fraction: None,
+            escalation_wallet: None,
+            escalation_lock_seconds: None,
+            min_attestors_pct: None,
+            required_hedge_ids: None,
+            success_payment_wallet: None,
+            success_payment_chronos: None,
+            condition_type: None,
+            oracle_pair: None,
+            oracle_trigger_threshold: None,
+            oracle_trigger_direction: None,
+            linked_instrument_id: None,
+            extension_right: None,
+            max_extensions: None,
+            pay_as_execution: None,
+        }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chronx_crypto::{verify_pow, verify_signature};
+
+    #[test]
+    fn signed_transaction_verifies() {
+        let kp = KeyPair::generate();
+        let parents = vec![TxId::from_bytes([1; 32])];
+        let tx = sign_transaction(&kp, vec![transfer_action(kp.account_id.clone(), 5, None)], parents.clone(), 3, 1_000, 8)
+            .unwrap();
+
+        let body = tx.body_bytes();
+        assert_eq!(tx.tx_id, tx_id_from_body(&body));
+        assert!(verify_pow(&body, tx.pow_nonce, 8));
+        verify_signature(&kp.public_key, &body, &tx.signatures[0]).unwrap();
+        assert_eq!((tx.parents, tx.nonce, tx.timestamp), (parents, 3, 1_000));
+        assert_eq!(tx.sender_public_key, Some(kp.public_key.clone()));
+    }
+
+    /// Serve `nonce` and `tips`, and accept any transaction.
+    async fn mock_node(nonce: u64, tips: Vec<TxId>) -> (String, jsonrpsee::server::ServerHandle) {
+        use jsonrpsee::server::{RpcModule, Server};
+
+        let tips: Vec<String> = tips.iter().map(TxId::to_hex).collect();
+        let mut module = RpcModule::new(());
+        module
+            .register_method("chronx_getAccount", move |_, _, _| serde_json::json!({ "nonce": nonce }))
+            .unwrap();
+        module.register_method("chronx_getDagTips", move |_, _, _| tips.clone()).unwrap();
+        module
+            .register_method("chronx_sendTransaction", |params, _, _| {
+                let tx_hex: String = params.one().unwrap();
+                let tx: Transaction = bincode::deserialize(&hex::decode(tx_hex).unwrap()).unwrap();
+                tx.tx_id.to_hex()
+            })
+            .unwrap();
+        let server = Server::builder().build("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", server.local_addr().unwrap());
+        (url, server.start(module))
+    }
+
+    #[tokio::test]
+    async fn builder_uses_node_nonce_and_tips() {
+        let tip = TxId::from_bytes([9; 32]);
+        let (url, _handle) = mock_node(7, vec![tip.clone()]).await;
+        let builder = TxBuilder::new(url, KeyPair::generate()).with_pow_difficulty(0);
+
+        let lock_id = TimeLockId(TxId::from_bytes([4; 32]));
+        let tx = builder.claim(lock_id.clone()).await.unwrap();
+        assert_eq!((tx.nonce, tx.parents.clone()), (7, vec![tip]));
+        assert!(matches!(&tx.actions[..], [Action::TimeLockClaim { lock_id: id }] if *id == lock_id));
+
+        let tx_id = tx.tx_id.clone();
+        assert_eq!(builder.submit(tx).await.unwrap(), tx_id);
+    }
+
+    #[tokio::test]
+    async fn rpc_errors_are_reported() {
+        let builder = TxBuilder::new("http://127.0.0.1:1", KeyPair::generate());
+        assert!(matches!(builder.transfer(AccountId::from_bytes([0; 32]), 1).await, Err(TxBuilderError::Http(_))));
+
+        let (url, _handle) = {
+            use jsonrpsee::server::{RpcModule, Server};
+            let mut module = RpcModule::new(());
+            module.register_method("chronx_getAccount", |_, _, _| serde_json::Value::Null).unwrap();
+            let server = Server::builder().build("127.0.0.1:0").await.unwrap();
+            (format!("http://{}", server.local_addr().unwrap()), server.start(module))
+        };
+        // chronx_getDagTips is not served.
+        let builder = TxBuilder::new(url, KeyPair::generate());
+        assert!(matches!(builder.custom(vec![]).await, Err(TxBuilderError::Rpc(_))));
+    }
+}
@@ -0,0 +1,58 @@
+//! Check the embedded locales: every locale must define the same message
+//! keys, and every `t!("key")` in the sources must exist in English.
+
+use std::collections::BTreeSet;
+use std::fs;
+
+const LOCALES: [&str; 2] = ["en", "es"];
+
+fn message_ids(source: &str) -> BTreeSet<String> {
+    source
+        .lines()
+        .filter(|l| l.starts_with(|c: char| c.is_ascii_alphabetic()))
+        .filter_map(|l| l.split_once('=').map(|(id, _)| id.trim().to_string()))
+        .collect()
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=locales");
+    println!("cargo:rerun-if-changed=src");
+
+    let ids: Vec<BTreeSet<String>> = LOCALES
+        .iter()
+        .map(|lang| {
+            let path = format!("locales/{lang}.ftl");
+            message_ids(&fs::read_to_string(&path).unwrap_or_else(|e| panic!("{path}: {e}")))
+        })
+        .collect();
+    for (lang, lang_ids) in LOCALES.iter().zip(&ids).skip(1) {
+        let missing: Vec<_> = ids[0].difference(lang_ids).collect();
+        let extra: Vec<_> = lang_ids.difference(&ids[0]).collect();
+        if !missing.is_empty() || !extra.is_empty() {
+            panic!("locales/{lang}.ftl: missing keys {missing:?}, keys not in en.ftl {extra:?}");
+        }
+    }
+
+    for entry in fs::read_dir("src").expect("src dir") {
+        let path = entry.expect("src entry").path();
+        let source = fs::read_to_string(&path).expect("read source");
+        for (at, _) in source.match_indices("t!(") {
+            // Skip `format!(` and friends.
+            if source[..at].ends_with(|c: char| c.is_alphanumeric() || c == '_') {
+                continue;
+            }
+            let call = &source[at + 3..];
+            let Some(key) = call
+                .split_once('"')
+                .filter(|(before, _)| !before.contains([')', ';']))
+                .and_then(|(_, rest)| rest.split_once('"'))
+                .map(|(key, _)| key)
+            else {
+                continue;
+            };
+            if !ids[0].contains(key) {
+                panic!("{}: t!(\"{key}\") is not defined in locales/en.ftl", path.display());
+            }
+        }
+    }
+}
@@ -0,0 +1,264 @@
+## chronx-wallet — English (default).
+## Every key here must also be defined in es.ftl; build.rs checks this.
+
+## Help text
+
+help-about = ChronX wallet — sign and submit transactions
+help-arg-keyfile = Path to the keyfile (JSON).
+help-arg-rpc = Node RPC endpoint.
+help-arg-short = Accept account and lock ids as hex prefixes (at least 8 characters) and resolve them through the node.
+help-arg-lang = Output language (defaults to $LANG, then English).
+help-keygen = Generate a new Dilithium2 keypair and save to the keyfile.
+help-balance = Print the account ID and balance.
+help-interactive = Start an interactive wallet shell.
+help-qr-code = Show a QR code for an account, or for a payment request to it.
+help-transfer = Transfer KX to another account.
+help-timelock = Create a time-lock sending KX to a recipient key.
+help-claim = Claim a matured time-lock.
+help-claim-v2-open = Open a claim on a matured V1 lock: the first step of the claims workflow for locks with a claim policy.
+help-update-lock-memo = Replace the memo on a pending lock you sent (within its cancellation window).
+help-update-lock-tags = Replace the tags on a pending lock you sent (within its cancellation window).
+help-set-currency = Set the fiat currency your incoming locks are valued in at claim time.
+help-watch-lock = Watch a time-lock and print each status change until it is final.
+help-view-private-lock = Show a private lock you sent or received, signing the node's hourly view challenge with your key.
+help-estimate-lock-value = Value a time-lock in USD from the oracle and show its claim lane.
+help-recover = Initiate account recovery for a target account.
+help-check-recovery = Show the status of an in-progress recovery for an account.
+help-challenge-recovery = Challenge an in-progress recovery for a target account.
+help-vote-recovery = Cast a verifier vote on an active recovery.
+help-finalize-recovery = Finalize an approved recovery after the delay has elapsed.
+help-recover-account = Start a recovery with a new key and follow it until it can be finalized (or challenge one with --challenge).
+help-email-timelock = Create an email time-lock (uses sender's own pubkey; claimable via code).
+help-cascade = Send a cascade of time-locked email payments with one shared claim code.
+help-verifier-register = Register a verifier (governance-only).
+help-claim-by-code = Claim email locks using a claim code (used by relay auto-delivery).
+help-executor-withdraw = MISAI executor withdraws KX from a live Type M lock for AI-managed trading. The executor wallet keyfile must be used to sign the transaction.
+help-info = Print genesis/protocol info from the node.
+help-lock-status = Show status, amount and maturity of one or more locks.
+help-portfolio = Summarize this wallet's locks by maturity, with an unlock timeline.
+help-search = Look up a transaction id, account id, lock id or lock client_ref.
+help-tree = Show the sub-accounts derived from the keyfile's key, with balances.
+help-create-invoice = Create an invoice requesting payment.
+help-create-credit = Create a credit authorization for a beneficiary.
+help-draw-credit = Draw from a credit authorization.
+help-create-deposit = Create an interest-bearing deposit.
+help-create-conditional = Create a conditional payment requiring attestor approval.
+help-attest-conditional = Attest (approve) a conditional payment.
+help-create-ledger-entry = Create a ledger entry (bonded agents only).
+help-genesis-params = Generate the genesis keypairs and write genesis-params.json to the output directory. Run this ONCE before launching a new chain, then store the private keys in cold storage.
+help-validate-genesis-params = Check a genesis-params.json file before launching a chain: keys must be well-formed and custom allocations must fit inside the public sale.
+help-export-genesis-params = Write the genesis-params.json of the network a running node belongs to, so a new node can join it with --genesis-params.
+
+## Balance and transactions
+
+balance-account = Account:  { $account }
+balance-amount = Balance:  { $amount }
+tx-submitted = Submitted: { $tx_id }
+timelock-created = TimeLock created: { $tx_id }
+claim-code = ClaimCode: { $code }
+claim-submitted = Claim submitted: { $tx_id }
+claim-selected = Claiming { $lock_id } ({ $amount_kx } KX)
+claim-dry-run = Dry run: nothing submitted.
+claim-needs-workflow = { $lock_id } has a claim policy; start its claim with `chronx-wallet claim-v2-open --lock-id { $lock_id }`
+claim-open-submitted = Claim opened: { $tx_id }
+claimed-locks = Claimed { $count } lock(s): { $tx_id }
+memo-update-submitted = Memo update submitted: { $tx_id }
+currency-update-submitted = Currency update submitted: { $tx_id }
+tag-update-submitted = Tag update submitted: { $tx_id }
+watch-lock-started = Watching lock { $lock_id } (Ctrl-C to stop)...
+watch-lock-final = Lock reached a final state.
+verifier-register-submitted = VerifierRegister submitted: { $tx_id }
+locks-none = No locks.
+locks-row = { $lock_id }  { $amount_kx } KX  { $status }  unlock_at { $unlock_at }
+lock-status-row = { $lock_id }  { $amount }  { $status }  unlock_at { $unlock_at } ({ $seconds }s)
+lock-status-private = (private)
+lock-status-not-found = { $lock_id }  not found
+portfolio-col-maturity = Maturity
+portfolio-col-count = Locks
+portfolio-col-total = Total
+portfolio-col-share = Share
+portfolio-col-usd = USD (est.)
+portfolio-timeline-title = Unlocks over the next { $years } years:
+portfolio-lock-timeline-day = { $date }  total { $total }, locks: { $count }
+portfolio-lock-timeline-empty = No pending locks.
+tree-child-written = Derived sub-account { $path }.
+
+cascade-building = Building cascade: { $stages } stages, { $total_kx } KX total...
+cascade-submitted = Submitted:  { $tx_id }
+cascade-claim-code = ClaimCode:  { $code }
+cascade-email = Email:      { $email }
+cascade-stage = Stage { $stage }: { $amount_kx } KX — unlock_at { $unlock_at } (lock { $lock_secs } sec)
+
+executor-withdraw-submitted = ExecutorWithdraw submitted: { $tx_id }
+executor-withdraw-lock = Lock:        { $lock_id }
+executor-withdraw-destination = Destination: { $destination }
+executor-withdraw-status = Status: PendingExecutor — will finalize after configured delay.
+executor-withdraw-alert = An alert email will be sent to alerts@misai.io.
+
+invoice-created = Invoice created. TxId: { $tx_id }
+invoice-id = Invoice ID: { $id }
+credit-created = Credit authorization created. TxId: { $tx_id }
+credit-id = Credit ID: { $id }
+credit-drawn = Credit drawn. TxId: { $tx_id }
+deposit-created = Deposit created. TxId: { $tx_id }
+deposit-id = Deposit ID: { $id }
+conditional-created = Conditional payment created. TxId: { $tx_id }
+conditional-id = Type V ID: { $id }
+conditional-attested = Conditional attested. TxId: { $tx_id }
+ledger-entry-created = Ledger entry created. TxId: { $tx_id }
+ledger-entry-id = Entry ID: { $id }
+
+## Recovery
+
+recovery-started = Recovery started: { $tx_id }
+challenge-submitted = Challenge submitted: { $tx_id }
+vote-submitted = Vote submitted (approve={ $approve }): { $tx_id }
+recovery-finalized = Recovery finalized: { $tx_id }
+recovery-progress = Votes: { $approve }/{ $threshold } approve, { $secs }s until the recovery can execute.
+recovery-ready = Recovery approved. Finalize it with: chronx-wallet finalize-recovery --target { $target }
+recovery-new-owner = Account { $account } is now controlled by { $path }.
+recovery-none = No active recovery.
+recovery-executable-now = executable now
+recovery-challenged = CHALLENGED
+recovery-on-track = ON TRACK
+recovery-status-for = Recovery status for { $account }
+recovery-label-state = State:
+recovery-label-initiator = Initiator:
+recovery-label-proposed-key = Proposed key:
+recovery-label-bond = Bond:
+recovery-label-started = Started:
+recovery-label-execute-after = Execute after:
+recovery-label-challenger = Challenger:
+recovery-label-challenge-bond = Challenge bond:
+recovery-label-challenge = Challenge:
+recovery-no-challenge = none
+recovery-label-votes = Votes:
+recovery-votes = { $approve } approve / { $reject } reject (threshold { $threshold })
+recovery-label-decision = Decision:
+recovery-label-evidence = Evidence:
+recovery-label-counter-evidence = Counter-evidence:
+
+## Info
+
+info-protocol = Protocol:     { $value }
+info-ticker = Ticker:       { $value }
+info-base-unit = Base unit:    { $value }
+info-total-supply = Total supply: { $amount } { $ticker }
+info-pow-difficulty = PoW difficulty: { $bits } bits
+info-node-version = Node version: { $value }
+info-rust-version = Rust version: { $value }
+info-platform = Platform:     { $os }/{ $arch }
+info-data-dir = Data dir:     { $value }
+info-db-size = DB size:      { $bytes } bytes
+info-uptime = Uptime:       { $secs }s
+info-genesis-hash = Genesis hash: { $value }
+info-network-id = Network id:   { $value }
+info-p2p-listen = P2P listen:   { $value }
+info-rpc-address = RPC address:  { $value }
+info-features = Features:
+info-difficulty-current = PoW difficulty: { $bits } bits (range { $min }-{ $max })
+info-difficulty-ema = Solve interval: { $ms } ms average, { $target } ms target
+info-difficulty-last = Last adjustment: { $direction ->
+        [increased] increased
+        [decreased] decreased
+       *[none] unchanged
+    } at depth { $depth }
+info-difficulty-history = Difficulty history (newest first):
+info-difficulty-history-row = depth { $depth }: { $bits } bits at { $at }
+info-difficulty-history-none = No difficulty adjustments recorded.
+
+## Search
+
+search-result = Found { $kind }:
+
+## Lock valuation
+
+estimate-col-lock-id = Lock ID
+estimate-col-amount = Amount KX
+estimate-col-price = Oracle Price
+estimate-col-value = USD Value
+estimate-col-age = Oracle Age
+estimate-col-lane = Lane
+estimate-stale-oracle = WARNING: { $pair } oracle snapshot is { $age }s old (max { $max_age }s); the value may be out of date.
+
+## Keys and genesis
+
+keygen-done = Generated new keypair.
+keygen-account = Account ID: { $account }
+keygen-public-key = Public key: { $key }
+keygen-keyfile = Keyfile:    { $path }
+keygen-backup-warning = BACK UP YOUR KEYFILE. Loss = permanent loss of funds.
+
+genesis-params-valid = { $path } is valid.
+genesis-custom-count = Custom allocations: { $count }
+genesis-custom-total = Custom total:       { $kx } KX
+genesis-public-sale-after = Public sale after:  { $kx } KX
+genesis-ceremony-done = Genesis keypair ceremony complete.
+genesis-public-sale = Public sale
+genesis-treasury = Treasury
+genesis-humanity = Humanity stake
+genesis-node-rewards = Node Rewards
+genesis-key-account = Account:  { $account }
+genesis-key-pubkey = PubKey:   { $key }
+genesis-key-keyfile = Keyfile:  { $path }
+genesis-params-written = genesis-params.json written to: { $path }
+genesis-cold-storage-warning = CRITICAL: Move private keyfiles off this machine and into cold storage NOW.
+genesis-params-only = Only genesis-params.json is needed by the node (--genesis-params flag).
+genesis-supply-matches = Total supply matches: { $kx } KX
+
+## Errors
+
+err-invalid-account = invalid account: { $error }
+err-invalid-lock-id = invalid lock id: { $error }
+err-no-matured-locks = No matured incoming locks to claim.
+err-invalid-target-account = invalid target account: { $error }
+err-invalid-beneficiary = invalid beneficiary address: { $error }
+err-invalid-obligor = invalid obligor address: { $error }
+err-invalid-recipient = invalid recipient address: { $error }
+err-invalid-hex = invalid { $field } hex
+err-decode-recipient-key = decoding recipient public key hex
+err-decode-owner-key = decoding proposed owner key hex
+err-decode-evidence = decoding evidence hash hex
+err-evidence-length = evidence hash must be 32 bytes (64 hex chars)
+err-decode-counter-evidence = decoding counter-evidence hash hex
+err-counter-evidence-length = counter-evidence hash must be 32 bytes (64 hex chars)
+err-recovery-ended = recovery is no longer active (decision: { $decision })
+err-parse-stages = parsing stages JSON
+err-empty-stages = stages array cannot be empty
+err-lookup-claim-code = looking up claim code
+err-claim-code-not-found = claim code not found
+err-no-claimable-locks = No claimable locks found for this code (may be immature or already claimed)
+err-account-not-found = account not found: { $account }
+err-lock-not-found = lock not found: { $lock_id }
+err-no-oracle-snapshot = no oracle snapshot for { $pair }
+err-parse-lock-amount = parsing lock amount
+err-invalid-dust-limit = invalid dust limit: { $value } KX
+err-below-dust-limit = transfer of { $amount } KX is below the dust limit of { $min } KX
+err-payment-request-scheme = not a chronx: payment request
+err-payment-request-amount = invalid payment request amount: { $value }
+err-payment-request-memo = payment request memo is not valid UTF-8
+err-payment-request-no-amount = payment request has no amount
+err-search-not-found = nothing matches { $query }
+err-unknown-shell-command = unknown command: { $command } (commands: { $commands })
+err-keyfile-exists = Keyfile { $path } already exists. Delete it first to generate a new key.
+err-write-keyfile = writing keyfile to { $path }
+err-bad-key-path = Invalid sub-account { $path }: give up to { $depth } indices below { $children }, separated by "/".
+err-read-keyfile = reading keyfile { $path }
+err-parse-keyfile = parsing keyfile — is it a valid ChronX keyfile?
+err-read-file = reading { $path }
+err-write-file = writing { $path }
+err-parse-genesis-params = parsing { $path } (see genesis-params.schema.json)
+err-bad-key-length = { $field }: expected 1312-byte Dilithium2 key, got { $got } bytes
+err-out-dir-exists = Output directory { $path } already exists. Delete it first to avoid overwriting a previous genesis ceremony.
+err-create-dir = creating output directory { $path }
+err-output-exists = { $path } already exists. Delete it first.
+err-supply-mismatch = node reports a total supply of { $node_kx } KX, this wallet expects { $local_kx } KX
+err-genesis-account-missing = node does not report the genesis { $name } account
+err-genesis-account-not-found = { $name } account { $account } not found
+err-genesis-key-missing = node does not report a public key for { $name } account { $account }
+err-genesis-key-mismatch = { $name } public key does not hash to account { $account }
+err-genesis-lock-not-found = genesis { $name } lock { $lock_id } not found
+err-genesis-lock-foreign = genesis { $name } lock { $lock_id } belongs to { $owner }, not { $account }
+err-build-genesis-params = building genesis params
+err-no-id-match = no { $kind } id starts with { $prefix }
+err-ambiguous-id = { $kind } prefix { $prefix } is ambiguous; candidates:
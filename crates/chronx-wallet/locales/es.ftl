@@ -0,0 +1,264 @@
+## chronx-wallet — Español.
+## Debe definir exactamente las mismas claves que en.ftl; build.rs lo comprueba.
+
+## Ayuda
+
+help-about = Cartera ChronX — firma y envía transacciones
+help-arg-keyfile = Ruta del archivo de claves (JSON).
+help-arg-rpc = Punto de acceso RPC del nodo.
+help-arg-short = Acepta ids de cuenta y de bloqueo como prefijos hexadecimales (al menos 8 caracteres) y los resuelve a través del nodo.
+help-arg-lang = Idioma de salida (por defecto $LANG y, si no, inglés).
+help-keygen = Genera un nuevo par de claves Dilithium2 y lo guarda en el archivo de claves.
+help-balance = Muestra el ID de cuenta y el saldo.
+help-interactive = Inicia una consola interactiva del monedero.
+help-qr-code = Muestra un código QR de una cuenta o de una solicitud de pago a ella.
+help-transfer = Transfiere KX a otra cuenta.
+help-timelock = Crea un bloqueo temporal que envía KX a la clave de un destinatario.
+help-claim = Reclama un bloqueo temporal vencido.
+help-claim-v2-open = Abre una reclamación sobre un bloqueo V1 vencido: el primer paso del flujo de reclamaciones para bloqueos con política de reclamación.
+help-update-lock-memo = Reemplaza la nota de un bloqueo pendiente que enviaste (dentro de su plazo de cancelación).
+help-update-lock-tags = Reemplaza las etiquetas de un bloqueo pendiente que enviaste (dentro de su plazo de cancelación).
+help-set-currency = Fija la moneda fiduciaria en que se valoran tus bloqueos entrantes al reclamarlos.
+help-watch-lock = Vigila un bloqueo temporal e imprime cada cambio de estado hasta que sea definitivo.
+help-view-private-lock = Muestra un bloqueo privado que enviaste o recibiste, firmando con tu clave el desafío horario del nodo.
+help-estimate-lock-value = Valora un bloqueo temporal en USD según el oráculo y muestra su carril de reclamación.
+help-recover = Inicia la recuperación de una cuenta objetivo.
+help-check-recovery = Muestra el estado de una recuperación en curso de una cuenta.
+help-challenge-recovery = Impugna una recuperación en curso de una cuenta objetivo.
+help-vote-recovery = Emite un voto de verificador sobre una recuperación activa.
+help-finalize-recovery = Finaliza una recuperación aprobada una vez transcurrido el plazo.
+help-recover-account = Inicia una recuperación con una clave nueva y la sigue hasta que pueda finalizarse (o impugna una con --challenge).
+help-email-timelock = Crea un bloqueo temporal por correo (usa la clave pública del remitente; se reclama con un código).
+help-cascade = Envía una cascada de pagos por correo con bloqueo temporal y un único código de reclamación.
+help-verifier-register = Registra un verificador (solo gobernanza).
+help-claim-by-code = Reclama bloqueos por correo con un código de reclamación (usado por la entrega automática del relé).
+help-executor-withdraw = El ejecutor MISAI retira KX de un bloqueo Tipo M activo para operaciones gestionadas por IA. La transacción debe firmarse con el archivo de claves del ejecutor.
+help-info = Muestra la información de génesis y del protocolo del nodo.
+help-lock-status = Muestra el estado, el importe y el vencimiento de uno o más bloqueos.
+help-portfolio = Resume los bloqueos de esta cartera por vencimiento, con una cronología de desbloqueos.
+help-search = Busca un id de transacción, de cuenta o de bloqueo, o el client_ref de un bloqueo.
+help-tree = Muestra las subcuentas derivadas de la clave del archivo de claves, con sus saldos.
+help-create-invoice = Crea una factura que solicita un pago.
+help-create-credit = Crea una autorización de crédito para un beneficiario.
+help-draw-credit = Dispone de una autorización de crédito.
+help-create-deposit = Crea un depósito con intereses.
+help-create-conditional = Crea un pago condicional que requiere la aprobación de un certificador.
+help-attest-conditional = Certifica (aprueba) un pago condicional.
+help-create-ledger-entry = Crea un asiento contable (solo agentes con fianza).
+help-genesis-params = Genera los pares de claves de génesis y escribe genesis-params.json en el directorio de salida. Ejecútalo UNA VEZ antes de lanzar una cadena nueva y guarda las claves privadas en almacenamiento en frío.
+help-validate-genesis-params = Comprueba un archivo genesis-params.json antes de lanzar una cadena: las claves deben estar bien formadas y las asignaciones personalizadas deben caber en la venta pública.
+help-export-genesis-params = Escribe el genesis-params.json de la red a la que pertenece un nodo en marcha, para que un nodo nuevo pueda unirse con --genesis-params.
+
+## Saldo y transacciones
+
+balance-account = Cuenta:   { $account }
+balance-amount = Saldo:    { $amount }
+tx-submitted = Enviada: { $tx_id }
+timelock-created = Bloqueo temporal creado: { $tx_id }
+claim-code = Código de reclamación: { $code }
+claim-submitted = Reclamación enviada: { $tx_id }
+claim-selected = Reclamando { $lock_id } ({ $amount_kx } KX)
+claim-dry-run = Simulación: no se ha enviado nada.
+claim-needs-workflow = { $lock_id } tiene una política de reclamación; inicia su reclamación con `chronx-wallet claim-v2-open --lock-id { $lock_id }`
+claim-open-submitted = Reclamación abierta: { $tx_id }
+claimed-locks = { $count } bloqueo(s) reclamado(s): { $tx_id }
+memo-update-submitted = Actualización de nota enviada: { $tx_id }
+currency-update-submitted = Actualización de moneda enviada: { $tx_id }
+tag-update-submitted = Actualización de etiquetas enviada: { $tx_id }
+watch-lock-started = Vigilando el bloqueo { $lock_id } (Ctrl-C para detener)...
+watch-lock-final = El bloqueo alcanzó un estado definitivo.
+verifier-register-submitted = Registro de verificador enviado: { $tx_id }
+locks-none = No hay bloqueos.
+locks-row = { $lock_id }  { $amount_kx } KX  { $status }  desbloqueo { $unlock_at }
+lock-status-row = { $lock_id }  { $amount }  { $status }  desbloqueo { $unlock_at } ({ $seconds }s)
+lock-status-private = (privado)
+lock-status-not-found = { $lock_id }  no encontrado
+portfolio-col-maturity = Vencimiento
+portfolio-col-count = Bloqueos
+portfolio-col-total = Total
+portfolio-col-share = Parte
+portfolio-col-usd = USD (est.)
+portfolio-timeline-title = Desbloqueos en los próximos { $years } años:
+portfolio-lock-timeline-day = { $date }  total { $total }, bloqueos: { $count }
+portfolio-lock-timeline-empty = No hay bloqueos pendientes.
+tree-child-written = Subcuenta { $path } derivada.
+
+cascade-building = Preparando cascada: { $stages } etapas, { $total_kx } KX en total...
+cascade-submitted = Enviada:    { $tx_id }
+cascade-claim-code = Código:     { $code }
+cascade-email = Correo:     { $email }
+cascade-stage = Etapa { $stage }: { $amount_kx } KX — se desbloquea en { $unlock_at } (bloqueo de { $lock_secs } s)
+
+executor-withdraw-submitted = Retiro del ejecutor enviado: { $tx_id }
+executor-withdraw-lock = Bloqueo:     { $lock_id }
+executor-withdraw-destination = Destino:     { $destination }
+executor-withdraw-status = Estado: PendingExecutor — se finalizará tras el plazo configurado.
+executor-withdraw-alert = Se enviará un correo de alerta a alerts@misai.io.
+
+invoice-created = Factura creada. TxId: { $tx_id }
+invoice-id = ID de factura: { $id }
+credit-created = Autorización de crédito creada. TxId: { $tx_id }
+credit-id = ID de crédito: { $id }
+credit-drawn = Crédito dispuesto. TxId: { $tx_id }
+deposit-created = Depósito creado. TxId: { $tx_id }
+deposit-id = ID de depósito: { $id }
+conditional-created = Pago condicional creado. TxId: { $tx_id }
+conditional-id = ID Tipo V: { $id }
+conditional-attested = Pago condicional certificado. TxId: { $tx_id }
+ledger-entry-created = Asiento contable creado. TxId: { $tx_id }
+ledger-entry-id = ID de asiento: { $id }
+
+## Recuperación
+
+recovery-started = Recuperación iniciada: { $tx_id }
+challenge-submitted = Impugnación enviada: { $tx_id }
+vote-submitted = Voto enviado (aprobar={ $approve }): { $tx_id }
+recovery-finalized = Recuperación finalizada: { $tx_id }
+recovery-progress = Votos: { $approve }/{ $threshold } a favor, faltan { $secs }s para poder ejecutar la recuperación.
+recovery-ready = Recuperación aprobada. Finalízala con: chronx-wallet finalize-recovery --target { $target }
+recovery-new-owner = La cuenta { $account } ahora está controlada por { $path }.
+recovery-none = No hay ninguna recuperación activa.
+recovery-executable-now = ejecutable ya
+recovery-challenged = IMPUGNADA
+recovery-on-track = EN CURSO
+recovery-status-for = Estado de recuperación de { $account }
+recovery-label-state = Estado:
+recovery-label-initiator = Iniciador:
+recovery-label-proposed-key = Clave propuesta:
+recovery-label-bond = Fianza:
+recovery-label-started = Inicio:
+recovery-label-execute-after = Ejecutable desde:
+recovery-label-challenger = Impugnante:
+recovery-label-challenge-bond = Fianza de impugnación:
+recovery-label-challenge = Impugnación:
+recovery-no-challenge = ninguna
+recovery-label-votes = Votos:
+recovery-votes = { $approve } a favor / { $reject } en contra (umbral { $threshold })
+recovery-label-decision = Decisión:
+recovery-label-evidence = Pruebas:
+recovery-label-counter-evidence = Contrapruebas:
+
+## Información
+
+info-protocol = Protocolo:    { $value }
+info-ticker = Símbolo:      { $value }
+info-base-unit = Unidad base:  { $value }
+info-total-supply = Suministro total: { $amount } { $ticker }
+info-pow-difficulty = Dificultad PoW: { $bits } bits
+info-node-version = Versión del nodo: { $value }
+info-rust-version = Versión de Rust: { $value }
+info-platform = Plataforma:   { $os }/{ $arch }
+info-data-dir = Directorio de datos: { $value }
+info-db-size = Tamaño de la BD: { $bytes } bytes
+info-uptime = Tiempo activo: { $secs }s
+info-genesis-hash = Hash de génesis: { $value }
+info-network-id = Id de red:    { $value }
+info-p2p-listen = Escucha P2P:  { $value }
+info-rpc-address = Dirección RPC: { $value }
+info-features = Funciones:
+info-difficulty-current = Dificultad PoW: { $bits } bits (rango { $min }-{ $max })
+info-difficulty-ema = Intervalo de resolución: { $ms } ms de media, objetivo { $target } ms
+info-difficulty-last = Último ajuste: { $direction ->
+        [increased] subió
+        [decreased] bajó
+       *[none] sin cambios
+    } en la profundidad { $depth }
+info-difficulty-history = Historial de dificultad (más reciente primero):
+info-difficulty-history-row = profundidad { $depth }: { $bits } bits el { $at }
+info-difficulty-history-none = No hay ajustes de dificultad registrados.
+
+## Búsqueda
+
+search-result = Encontrado ({ $kind }):
+
+## Valoración de bloqueos
+
+estimate-col-lock-id = ID de bloqueo
+estimate-col-amount = Cantidad KX
+estimate-col-price = Precio oráculo
+estimate-col-value = Valor USD
+estimate-col-age = Antigüedad
+estimate-col-lane = Carril
+estimate-stale-oracle = AVISO: la instantánea del oráculo { $pair } tiene { $age }s (máx. { $max_age }s); el valor puede estar desactualizado.
+
+## Claves y génesis
+
+keygen-done = Nuevo par de claves generado.
+keygen-account = ID de cuenta: { $account }
+keygen-public-key = Clave pública: { $key }
+keygen-keyfile = Archivo de claves: { $path }
+keygen-backup-warning = HAZ UNA COPIA DE SEGURIDAD DEL ARCHIVO DE CLAVES. Perderlo = perder los fondos para siempre.
+
+genesis-params-valid = { $path } es válido.
+genesis-custom-count = Asignaciones personalizadas: { $count }
+genesis-custom-total = Total personalizado:         { $kx } KX
+genesis-public-sale-after = Venta pública restante:      { $kx } KX
+genesis-ceremony-done = Ceremonia de claves de génesis completada.
+genesis-public-sale = Venta pública
+genesis-treasury = Tesorería
+genesis-humanity = Participación de la humanidad
+genesis-node-rewards = Recompensas de nodos
+genesis-key-account = Cuenta:   { $account }
+genesis-key-pubkey = Clave:    { $key }
+genesis-key-keyfile = Archivo:  { $path }
+genesis-params-written = genesis-params.json escrito en: { $path }
+genesis-cold-storage-warning = CRÍTICO: saca YA los archivos de claves privadas de esta máquina y guárdalos en almacenamiento en frío.
+genesis-params-only = El nodo solo necesita genesis-params.json (opción --genesis-params).
+genesis-supply-matches = El suministro total coincide: { $kx } KX
+
+## Errores
+
+err-invalid-account = cuenta no válida: { $error }
+err-invalid-lock-id = id de bloqueo no válido: { $error }
+err-no-matured-locks = No hay bloqueos entrantes vencidos que reclamar.
+err-invalid-target-account = cuenta objetivo no válida: { $error }
+err-invalid-beneficiary = dirección de beneficiario no válida: { $error }
+err-invalid-obligor = dirección de obligado no válida: { $error }
+err-invalid-recipient = dirección de destinatario no válida: { $error }
+err-invalid-hex = hexadecimal de { $field } no válido
+err-decode-recipient-key = decodificando el hexadecimal de la clave pública del destinatario
+err-decode-owner-key = decodificando el hexadecimal de la clave de propietario propuesta
+err-decode-evidence = decodificando el hexadecimal del hash de pruebas
+err-evidence-length = el hash de pruebas debe tener 32 bytes (64 caracteres hexadecimales)
+err-decode-counter-evidence = decodificando el hexadecimal del hash de contrapruebas
+err-counter-evidence-length = el hash de contrapruebas debe tener 32 bytes (64 caracteres hexadecimales)
+err-recovery-ended = la recuperación ya no está activa (decisión: { $decision })
+err-parse-stages = analizando el JSON de etapas
+err-empty-stages = la lista de etapas no puede estar vacía
+err-lookup-claim-code = buscando el código de reclamación
+err-claim-code-not-found = código de reclamación no encontrado
+err-no-claimable-locks = No hay bloqueos reclamables para este código (pueden no haber vencido o estar ya reclamados)
+err-account-not-found = cuenta no encontrada: { $account }
+err-lock-not-found = bloqueo no encontrado: { $lock_id }
+err-no-oracle-snapshot = no hay instantánea del oráculo para { $pair }
+err-parse-lock-amount = analizando la cantidad del bloqueo
+err-invalid-dust-limit = importe mínimo no válido: { $value } KX
+err-below-dust-limit = la transferencia de { $amount } KX está por debajo del importe mínimo de { $min } KX
+err-payment-request-scheme = no es una solicitud de pago chronx:
+err-payment-request-amount = importe de la solicitud de pago no válido: { $value }
+err-payment-request-memo = el memo de la solicitud de pago no es UTF-8 válido
+err-payment-request-no-amount = la solicitud de pago no indica un importe
+err-search-not-found = nada coincide con { $query }
+err-unknown-shell-command = comando desconocido: { $command } (comandos: { $commands })
+err-keyfile-exists = El archivo de claves { $path } ya existe. Bórralo antes de generar una clave nueva.
+err-write-keyfile = escribiendo el archivo de claves en { $path }
+err-bad-key-path = Subcuenta { $path } no válida: indica hasta { $depth } índices menores que { $children }, separados por "/".
+err-read-keyfile = leyendo el archivo de claves { $path }
+err-parse-keyfile = analizando el archivo de claves — ¿es un archivo de claves ChronX válido?
+err-read-file = leyendo { $path }
+err-write-file = escribiendo { $path }
+err-parse-genesis-params = analizando { $path } (ver genesis-params.schema.json)
+err-bad-key-length = { $field }: se esperaba una clave Dilithium2 de 1312 bytes, se obtuvieron { $got } bytes
+err-out-dir-exists = El directorio de salida { $path } ya existe. Bórralo antes para no sobrescribir una ceremonia de génesis anterior.
+err-create-dir = creando el directorio de salida { $path }
+err-output-exists = { $path } ya existe. Bórralo antes.
+err-supply-mismatch = el nodo indica un suministro total de { $node_kx } KX, esta cartera espera { $local_kx } KX
+err-genesis-account-missing = el nodo no indica la cuenta de génesis { $name }
+err-genesis-account-not-found = cuenta { $name } { $account } no encontrada
+err-genesis-key-missing = el nodo no indica una clave pública para la cuenta { $name } { $account }
+err-genesis-key-mismatch = la clave pública de { $name } no corresponde a la cuenta { $account }
+err-genesis-lock-not-found = bloqueo de génesis { $name } { $lock_id } no encontrado
+err-genesis-lock-foreign = el bloqueo de génesis { $name } { $lock_id } pertenece a { $owner }, no a { $account }
+err-build-genesis-params = generando los parámetros de génesis
+err-no-id-match = ningún id de { $kind } empieza por { $prefix }
+err-ambiguous-id = el prefijo de { $kind } { $prefix } es ambiguo; candidatos:
@@ -0,0 +1,127 @@
+//! Localized wallet output.
+//!
+//! Messages live in `locales/<lang>.ftl` (Fluent syntax) and are embedded in
+//! the binary. `build.rs` fails the build if the locales do not define the
+//! same keys, or if a `t!` call names a key English does not define.
+
+use std::sync::OnceLock;
+
+use chronx_core::units::Locale;
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource};
+
+/// Look up a message in the current language (or `lang => "key"` for an
+/// explicit one), filling `{ $name }` placeables from `name = value` pairs.
+macro_rules! t {
+    ($key:literal $(, $name:ident = $value:expr)* $(,)?) => {
+        $crate::i18n::tr(
+            $crate::i18n::current(),
+            $key,
+            &[$((stringify!($name), ($value).to_string())),*],
+        )
+    };
+    ($lang:expr => $key:literal $(, $name:ident = $value:expr)* $(,)?) => {
+        $crate::i18n::tr($lang, $key, &[$((stringify!($name), ($value).to_string())),*])
+    };
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Lang {
+    En,
+    Es,
+}
+
+impl Lang {
+    /// Parse a `--lang` value or a POSIX locale such as `es_ES.UTF-8`.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.split(['_', '-', '.']).next()?.to_ascii_lowercase().as_str() {
+            "en" => Some(Lang::En),
+            "es" => Some(Lang::Es),
+            _ => None,
+        }
+    }
+
+    fn source(self) -> &'static str {
+        match self {
+            Lang::En => include_str!("../locales/en.ftl"),
+            Lang::Es => include_str!("../locales/es.ftl"),
+        }
+    }
+
+    /// Digit grouping and decimal separator for amounts.
+    pub fn number_locale(self) -> Locale {
+        match self {
+            Lang::En => Locale::en,
+            Lang::Es => Locale::es,
+        }
+    }
+
+    fn code(self) -> &'static str {
+        match self {
+            Lang::En => "en",
+            Lang::Es => "es",
+        }
+    }
+}
+
+/// The language to print in: `--lang` if given on the command line, else
+/// `$LANG`, else English. Runs before clap so help text is localized too.
+pub fn detect(args: &[String]) -> Lang {
+    let flag = args.iter().enumerate().find_map(|(i, a)| match a.strip_prefix("--lang") {
+        Some("") => args.get(i + 1).map(String::as_str),
+        Some(v) => v.strip_prefix('='),
+        None => None,
+    });
+    flag.and_then(Lang::parse)
+        .or_else(|| std::env::var("LANG").ok().as_deref().and_then(Lang::parse))
+        .unwrap_or(Lang::En)
+}
+
+static CURRENT: OnceLock<Lang> = OnceLock::new();
+
+/// Set the language for the rest of the process. Only the first call counts.
+pub fn set(lang: Lang) {
+    let _ = CURRENT.set(lang);
+}
+
+/// The language set by `set`, English if none was.
+pub fn current() -> Lang {
+    CURRENT.get().copied().unwrap_or(Lang::En)
+}
+
+fn bundle(lang: Lang) -> &'static FluentBundle<FluentResource> {
+    static BUNDLES: [OnceLock<FluentBundle<FluentResource>>; 2] = [OnceLock::new(), OnceLock::new()];
+    BUNDLES[lang as usize].get_or_init(|| {
+        let id = lang.code().parse().expect("static language id");
+        let mut bundle = FluentBundle::new_concurrent(vec![id]);
+        // Unicode isolation marks show up as stray bytes in most terminals.
+        bundle.set_use_isolating(false);
+        let resource = FluentResource::try_new(lang.source().to_string())
+            .unwrap_or_else(|(_, errors)| panic!("{} locale: {errors:?}", lang.code()));
+        bundle
+            .add_resource(resource)
+            .unwrap_or_else(|errors| panic!("{} locale: {errors:?}", lang.code()));
+        bundle
+    })
+}
+
+/// Whether `lang` defines `key`.
+pub fn has(lang: Lang, key: &str) -> bool {
+    bundle(lang).has_message(key)
+}
+
+/// Format `key` in `lang`. Unknown keys come back verbatim.
+pub fn tr(lang: Lang, key: &str, args: &[(&str, String)]) -> String {
+    let bundle = bundle(lang);
+    let Some(pattern) = bundle.get_message(key).and_then(|m| m.value()) else {
+        return key.to_string();
+    };
+    let mut fluent_args = FluentArgs::new();
+    for (name, value) in args {
+        fluent_args.set(*name, value.clone());
+    }
+    let mut errors = Vec::new();
+    bundle
+        .format_pattern(pattern, Some(&fluent_args), &mut errors)
+        .into_owned()
+}
@@ -0,0 +1,312 @@
+//! `chronx-wallet interactive`: a readline shell over the wallet commands.
+//!
+//! Each line is a subcommand without the `chronx-wallet` prefix, with its
+//! main arguments given positionally (`transfer <to> <amount>`). The line is
+//! turned back into CLI arguments and parsed by clap, so defaults and
+//! validation match the CLI, then run through `execute`.
+//!
+//! Tab completes command names, and base-58 addresses from
+//! `~/.chronx/contacts.json` (an object of `"name": "<address>"` pairs).
+//! History is kept in `~/.chronx/wallet_history`.
+
+use std::path::{Path, PathBuf};
+
+use clap::Parser;
+use rustyline::completion::Completer;
+use rustyline::error::ReadlineError;
+use rustyline::history::DefaultHistory;
+use rustyline::{Editor, Helper, Highlighter, Hinter, Validator};
+
+use chronx_core::units::Amount;
+
+use crate::rpc_client::WalletRpcClient;
+use crate::{expand_tilde, load_keypair, Args, Command};
+
+const HISTORY_FILE: &str = "~/.chronx/wallet_history";
+const CONTACTS_FILE: &str = "~/.chronx/contacts.json";
+
+/// Shell commands, with the CLI flags their positional arguments fill in.
+const COMMANDS: [(&str, &[&str]); 7] = [
+    ("balance", &["--account"]),
+    ("transfer", &["--to", "--amount"]),
+    ("timelock", &["--to-pubkey", "--amount", "--unlock"]),
+    ("claim", &["--lock-id"]),
+    ("locks", &[]),
+    ("info", &[]),
+    ("quit", &[]),
+];
+
+/// One parsed shell line.
+#[derive(Debug)]
+enum Line {
+    Empty,
+    Quit,
+    /// List the wallet account's locks.
+    Locks,
+    Run(Box<Command>),
+}
+
+fn parse_line(line: &str) -> anyhow::Result<Line> {
+    let mut words = line.split_whitespace();
+    let Some(name) = words.next() else {
+        return Ok(Line::Empty);
+    };
+    let Some((_, flags)) = COMMANDS.iter().find(|(c, _)| *c == name) else {
+        let commands = COMMANDS.map(|(c, _)| c).join(", ");
+        anyhow::bail!(t!("err-unknown-shell-command", command = name, commands = commands));
+    };
+    match name {
+        "quit" => return Ok(Line::Quit),
+        "locks" => return Ok(Line::Locks),
+        _ => {}
+    }
+
+    // Positional words fill the command's flags in order; anything from the
+    // first `--flag` on is passed through unchanged.
+    let mut argv = vec!["chronx-wallet".to_string(), name.to_string()];
+    let mut flags = flags.iter();
+    let mut words = words.peekable();
+    while let Some(word) = words.next_if(|w| !w.starts_with("--")) {
+        if let Some(flag) = flags.next() {
+            argv.push(flag.to_string());
+        }
+        argv.push(word.to_string());
+    }
+    argv.extend(words.map(str::to_string));
+    Ok(Line::Run(Box::new(Args::try_parse_from(argv)?.command)))
+}
+
+/// Completions for the word ending at `pos`: command names for the first
+/// word, contact addresses after it.
+fn completions(line: &str, pos: usize, addresses: &[String]) -> (usize, Vec<String>) {
+    let start = line[..pos].rfind(char::is_whitespace).map_or(0, |i| i + 1);
+    let word = &line[start..pos];
+    let candidates: Vec<&str> = if line[..start].trim().is_empty() {
+        COMMANDS.iter().map(|(c, _)| *c).collect()
+    } else {
+        addresses.iter().map(String::as_str).collect()
+    };
+    (start, candidates.into_iter().filter(|c| c.starts_with(word)).map(str::to_string).collect())
+}
+
+#[derive(Helper, Hinter, Highlighter, Validator)]
+struct WalletHelper {
+    addresses: Vec<String>,
+}
+
+impl Completer for WalletHelper {
+    type Candidate = String;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &rustyline::Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        Ok(completions(line, pos, &self.addresses))
+    }
+}
+
+/// Addresses in the contacts file. A missing or unreadable file gives none.
+fn load_contact_addresses(path: &Path) -> Vec<String> {
+    let contacts: std::collections::BTreeMap<String, String> = std::fs::read_to_string(path)
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default();
+    contacts.into_values().collect()
+}
+
+/// Where the shell reads its lines: the terminal, or a script in tests.
+trait LineReader {
+    fn readline(&mut self, prompt: &str) -> rustyline::Result<String>;
+    fn add_history_entry(&mut self, line: &str);
+}
+
+impl LineReader for Editor<WalletHelper, DefaultHistory> {
+    fn readline(&mut self, prompt: &str) -> rustyline::Result<String> {
+        Editor::readline(self, prompt)
+    }
+
+    fn add_history_entry(&mut self, line: &str) {
+        let _ = Editor::add_history_entry(self, line);
+    }
+}
+
+/// `[wallet:1234 KX]> `, named after the keyfile. The balance is left out
+/// when the keyfile or the node cannot be read.
+async fn prompt(keyfile: &PathBuf, client: &WalletRpcClient) -> String {
+    let name = keyfile.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default();
+    let balance = match load_keypair(keyfile) {
+        Ok(kp) => client.get_balance(&kp.account_id.to_b58()).await.ok(),
+        Err(_) => None,
+    };
+    match balance {
+        Some(chronos) => format!("[{name}:{}]> ", Amount(chronos).kx_in(&crate::i18n::current().number_locale())),
+        None => format!("[{name}]> "),
+    }
+}
+
+async fn print_locks(keyfile: &PathBuf, client: &WalletRpcClient) -> anyhow::Result<()> {
+    let account = load_keypair(keyfile)?.account_id.to_b58();
+    let locks = client.get_locks(&account).await?;
+    if locks.is_empty() {
+        println!("{}", t!("locks-none"));
+    }
+    for lock in locks {
+        println!(
+            "{}",
+            t!(
+                "locks-row",
+                lock_id = lock.lock_id,
+                amount_kx = lock.amount_kx,
+                status = lock.status,
+                unlock_at = lock.unlock_at
+            )
+        );
+    }
+    Ok(())
+}
+
+/// Read and run lines until `quit` or end of input. A failing command is
+/// reported and the shell carries on.
+async fn repl(
+    reader: &mut impl LineReader,
+    keyfile: &PathBuf,
+    client: &WalletRpcClient,
+    short: bool,
+) -> anyhow::Result<()> {
+    loop {
+        let line = match reader.readline(&prompt(keyfile, client).await) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+        if !line.trim().is_empty() {
+            reader.add_history_entry(line.trim());
+        }
+        let result = match parse_line(&line) {
+            Ok(Line::Empty) => Ok(()),
+            Ok(Line::Quit) => return Ok(()),
+            Ok(Line::Locks) => print_locks(keyfile, client).await,
+            Ok(Line::Run(command)) => crate::execute(*command, keyfile, client, short).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            eprintln!("{e:#}");
+        }
+    }
+}
+
+pub async fn run(keyfile: &PathBuf, client: &WalletRpcClient, short: bool) -> anyhow::Result<()> {
+    let history = expand_tilde(Path::new(HISTORY_FILE));
+    let mut editor = Editor::<WalletHelper, DefaultHistory>::new()?;
+    editor.set_helper(Some(WalletHelper {
+        addresses: load_contact_addresses(&expand_tilde(Path::new(CONTACTS_FILE))),
+    }));
+    let _ = editor.load_history(&history);
+
+    let result = repl(&mut editor, keyfile, client, short).await;
+
+    if let Some(dir) = history.parent() {
+        let _ = std::fs::create_dir_all(dir);
+    }
+    let _ = editor.save_history(&history);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    /// Replays `lines`, then reports end of input.
+    #[derive(Default)]
+    struct Script {
+        lines: VecDeque<&'static str>,
+        prompts: Vec<String>,
+        history: Vec<String>,
+    }
+
+    impl LineReader for Script {
+        fn readline(&mut self, prompt: &str) -> rustyline::Result<String> {
+            self.prompts.push(prompt.to_string());
+            self.lines.pop_front().map(str::to_string).ok_or(ReadlineError::Eof)
+        }
+
+        fn add_history_entry(&mut self, line: &str) {
+            self.history.push(line.to_string());
+        }
+    }
+
+    fn run_line(line: &str) -> Box<Command> {
+        match parse_line(line).unwrap() {
+            Line::Run(command) => command,
+            other => panic!("expected a command, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn positional_arguments_fill_cli_flags() {
+        match *run_line("transfer 5Hx9abc 2.5") {
+            Command::Transfer { to, amount, payment_request_qr, dust_limit_kx } => {
+                assert_eq!(to.as_deref(), Some("5Hx9abc"));
+                assert_eq!(amount, Some(2.5));
+                assert_eq!(payment_request_qr, None);
+                assert_eq!(dust_limit_kx, 0.001);
+            }
+            other => panic!("expected a transfer, got {other:?}"),
+        }
+        match *run_line("  timelock abcd 1 1900000000 --memo hi") {
+            Command::Timelock { to_pubkey, amount, unlock, memo } => {
+                assert_eq!((to_pubkey.as_str(), amount, unlock), ("abcd", 1.0, 1_900_000_000));
+                assert_eq!(memo.as_deref(), Some("hi"));
+            }
+            other => panic!("expected a timelock, got {other:?}"),
+        }
+        assert!(matches!(*run_line("balance"), Command::Balance { account: None }));
+        assert!(matches!(*run_line("info --verbose"), Command::Info { verbose: true, .. }));
+        assert!(matches!(parse_line("locks").unwrap(), Line::Locks));
+        assert!(matches!(parse_line("   ").unwrap(), Line::Empty));
+    }
+
+    #[test]
+    fn bad_lines_are_rejected() {
+        assert!(parse_line("keygen").is_err());
+        assert!(parse_line("transfer 5Hx9abc").is_err());
+        assert!(parse_line("transfer 5Hx9abc lots").is_err());
+    }
+
+    #[test]
+    fn completes_commands_then_addresses() {
+        let addresses = vec!["5Hx9abc".to_string(), "7Qp2xyz".to_string()];
+        assert_eq!(completions("ti", 2, &addresses), (0, vec!["timelock".to_string()]));
+        assert_eq!(completions("transfer 5H", 11, &addresses), (9, vec!["5Hx9abc".to_string()]));
+        assert_eq!(completions("transfer ", 9, &addresses).1, addresses);
+    }
+
+    #[test]
+    fn contacts_file_supplies_addresses() {
+        let path = std::env::temp_dir().join("chronx_wallet_contacts_test.json");
+        std::fs::write(&path, r#"{"alice": "5Hx9abc", "bob": "7Qp2xyz"}"#).unwrap();
+        assert_eq!(load_contact_addresses(&path), vec!["5Hx9abc", "7Qp2xyz"]);
+        std::fs::remove_file(&path).unwrap();
+        assert!(load_contact_addresses(&path).is_empty());
+    }
+
+    #[tokio::test]
+    async fn shell_keeps_going_after_errors_and_stops_at_quit() {
+        let mut script = Script {
+            lines: VecDeque::from(["", "frobnicate", "claim", "quit", "info"]),
+            ..Script::default()
+        };
+        let keyfile = PathBuf::from("/nonexistent/alice.json");
+        let client = WalletRpcClient::new("http://127.0.0.1:1");
+        repl(&mut script, &keyfile, &client, false).await.unwrap();
+
+        assert_eq!(script.lines, ["info"]);
+        assert_eq!(script.history, ["frobnicate", "claim", "quit"]);
+        assert_eq!(script.prompts.len(), 4);
+        assert!(script.prompts.iter().all(|p| p == "[alice]> "));
+    }
+}
@@ -8,19 +8,36 @@
 //!   chronx-wallet transfer  --to <account> --amount <kx> [--rpc <url>] [--keyfile <path>]
 //!   chronx-wallet timelock  --to-pubkey <hex> --amount <kx> --unlock <unix_ts> [--rpc <url>] [--keyfile <path>]
 //!   chronx-wallet claim     --lock-id <hex> [--rpc <url>] [--keyfile <path>]
+//!   chronx-wallet update-lock-memo --lock-id <hex> [--memo <text>]
+//!   chronx-wallet update-lock-tags --lock-id <hex> --tags "a,b,c"
+//!   chronx-wallet watch-lock --lock-id <hex> [--rpc <url>]
+//!   chronx-wallet estimate-lock-value --lock-id <hex> [--rpc <url>]
 //!   chronx-wallet balance   --account <b58> [--rpc <url>]
+//!   chronx-wallet qr-code   [--account <b58>] [--payment-request --amount-kx <kx> [--memo <text>]]
+//!   chronx-wallet check-recovery --account <b58> [--rpc <url>]
 //!   chronx-wallet info      [--rpc <url>]
+//!   chronx-wallet search    <txid|account|lock-id|client-ref> [--rpc <url>]
+//!   chronx-wallet tree      [--depth <1-3>] [--children <1-10>] [--keyfile <path>]
+//!   chronx-wallet tree      --generate-child --index <n[/n...]> --out <path> [--keyfile <path>]
+//!   chronx-wallet interactive [--rpc <url>] [--keyfile <path>]
+//!   chronx-wallet validate-genesis-params --params <path>
+//!   chronx-wallet export-genesis-params --output <path> [--rpc <url>]
+//!
+//! Output is localized: pass `--lang es` (or set `LANG=es_ES.UTF-8`) for
+//! Spanish. English is the default.
+//!
+//! Pass `--short` to give account and lock ids as hex prefixes (8+ chars),
+//! e.g. `chronx-wallet --short claim --lock-id 3fa9c01e`.
 
 use std::path::{Path, PathBuf};
 
 use anyhow::{bail, Context};
-use clap::{Parser, Subcommand};
-use tracing::info;
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand};
 
 use chronx_core::{
     constants::{CHRONOS_PER_KX, POW_INITIAL_DIFFICULTY},
     transaction::{
-        Action, AuthScheme, Transaction,
+        Action, Transaction,
         CreateInvoiceAction,
         CreateCreditAction, DrawCreditAction,
         CreateDepositAction, Compounding,
@@ -28,11 +45,21 @@ use chronx_core::{
         CreateLedgerEntryAction, LedgerEntryType,
     },
     types::{AccountId, DilithiumPublicKey, TimeLockId, TxId},
+    units::{self, kx_to_chronos},
 };
-use chronx_crypto::{hash::tx_id_from_body, mine_pow, KeyPair};
+use chronx_crypto::{private_lock_view_challenge, KeyPair};
 use chronx_genesis::GenesisParams;
+use chronx_tx_builder::{sign_transaction, timelock_action, transfer_action};
 
+#[macro_use]
+mod i18n;
+mod interactive;
+mod payment_request;
+mod portfolio;
 mod rpc_client;
+mod tree;
+use i18n::Lang;
+use payment_request::PaymentRequest;
 use rpc_client::WalletRpcClient;
 
 // ── CLI definition ────────────────────────────────────────────────────────────
@@ -52,6 +79,15 @@ struct Args {
     #[arg(long, global = true, default_value = "http://127.0.0.1:8545")]
     rpc: String,
 
+    /// Accept account and lock ids as hex prefixes (at least 8 characters)
+    /// and resolve them through the node.
+    #[arg(long, global = true)]
+    short: bool,
+
+    /// Output language (defaults to `$LANG`, then English).
+    #[arg(long, global = true, value_parser = ["en", "es"])]
+    lang: Option<String>,
+
     #[command(subcommand)]
     command: Command,
 }
@@ -61,6 +97,25 @@ enum Command {
     /// Generate a new Dilithium2 keypair and save to the keyfile.
     Keygen,
 
+    /// Start an interactive wallet shell.
+    Interactive,
+
+    /// Show a QR code for an account, or for a payment request to it.
+    QrCode {
+        /// Account to encode (base-58); defaults to the keyfile's account.
+        #[arg(long)]
+        account: Option<String>,
+        /// Encode a payment request rather than just the account.
+        #[arg(long, requires = "amount_kx")]
+        payment_request: bool,
+        /// Requested amount in KX.
+        #[arg(long, requires = "payment_request")]
+        amount_kx: Option<f64>,
+        /// Memo to attach to the payment.
+        #[arg(long, requires = "payment_request")]
+        memo: Option<String>,
+    },
+
     /// Print the account ID and balance.
     Balance {
         /// Account to query (base-58). Defaults to the local keypair's account.
@@ -71,11 +126,19 @@ enum Command {
     /// Transfer KX to another account.
     Transfer {
         /// Recipient account ID (base-58).
-        #[arg(long)]
-        to: String,
+        #[arg(long, required_unless_present = "payment_request_qr")]
+        to: Option<String>,
         /// Amount in KX (will be converted to Chronos internally).
+        #[arg(long, required_unless_present = "payment_request_qr")]
+        amount: Option<f64>,
+        /// A scanned `chronx:` payment request; fills in the recipient,
+        /// amount and memo. --to and --amount override it.
         #[arg(long)]
-        amount: f64,
+        payment_request_qr: Option<String>,
+        /// Refuse amounts below this many KX. The node rejects transfers
+        /// under 0.001 KX unless governance has lowered its dust limit.
+        #[arg(long, default_value_t = 0.001)]
+        dust_limit_kx: f64,
     },
 
     /// Create a time-lock sending KX to a recipient key.
@@ -96,6 +159,72 @@ enum Command {
 
     /// Claim a matured time-lock.
     Claim {
+        /// Lock ID (TxId hex of the creating transaction).
+        #[arg(long, required_unless_present_any = ["auto", "all"], conflicts_with_all = ["auto", "all"])]
+        lock_id: Option<String>,
+        /// Claim the largest matured incoming lock.
+        #[arg(long, conflicts_with = "all")]
+        auto: bool,
+        /// Claim every matured incoming lock in one transaction.
+        #[arg(long)]
+        all: bool,
+        /// With --auto or --all, print what would be claimed without submitting.
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Open a claim on a matured V1 lock: the first step of the claims
+    /// workflow for locks with a claim policy.
+    ClaimV2Open {
+        /// Lock ID (TxId hex of the creating transaction).
+        #[arg(long)]
+        lock_id: String,
+    },
+
+    /// Replace the memo on a pending lock you sent (within its cancellation window).
+    UpdateLockMemo {
+        /// Lock ID (TxId hex of the creating transaction).
+        #[arg(long)]
+        lock_id: String,
+        /// New memo (max 256 chars). Omit to clear the memo.
+        #[arg(long)]
+        memo: Option<String>,
+    },
+
+    /// Replace the tags on a pending lock you sent (within its cancellation window).
+    UpdateLockTags {
+        /// Lock ID (TxId hex of the creating transaction).
+        #[arg(long)]
+        lock_id: String,
+        /// Comma-separated tags, e.g. "a,b,c". Pass "" to clear.
+        #[arg(long)]
+        tags: String,
+    },
+
+    /// Set the fiat currency your incoming locks are valued in at claim time.
+    SetCurrency {
+        /// ISO currency code with an oracle feed, e.g. "EUR". Omit to clear.
+        #[arg(long)]
+        currency: Option<String>,
+    },
+
+    /// Watch a time-lock and print each status change until it is final.
+    WatchLock {
+        /// Lock ID (TxId hex of the creating transaction).
+        #[arg(long)]
+        lock_id: String,
+    },
+
+    /// Show a private lock you sent or received, signing the node's hourly
+    /// view challenge with your key.
+    ViewPrivateLock {
+        /// Lock ID (TxId hex of the creating transaction).
+        #[arg(long)]
+        lock_id: String,
+    },
+
+    /// Value a time-lock in USD from the oracle and show its claim lane.
+    EstimateLockValue {
         /// Lock ID (TxId hex of the creating transaction).
         #[arg(long)]
         lock_id: String,
@@ -112,11 +241,21 @@ enum Command {
         /// Blake3 hash of off-chain evidence (hex, 32 bytes).
         #[arg(long)]
         evidence: String,
+        /// Where the evidence can be fetched (ipfs://… or https://…).
+        #[arg(long)]
+        evidence_uri: Option<String>,
         /// Bond amount in KX.
         #[arg(long)]
         bond: f64,
     },
 
+    /// Show the status of an in-progress recovery for an account.
+    CheckRecovery {
+        /// Account to inspect (base-58).
+        #[arg(long)]
+        account: String,
+    },
+
     /// Challenge an in-progress recovery for a target account.
     ChallengeRecovery {
         /// Target account whose recovery you are challenging (base-58).
@@ -125,6 +264,9 @@ enum Command {
         /// Blake3 hash of off-chain counter-evidence (hex, 32 bytes).
         #[arg(long)]
         counter_evidence: String,
+        /// Where the counter-evidence can be fetched (ipfs://… or https://…).
+        #[arg(long)]
+        counter_evidence_uri: Option<String>,
         /// Challenge bond amount in KX.
         #[arg(long)]
         bond: f64,
@@ -150,6 +292,33 @@ enum Command {
         target: String,
     },
 
+    /// Start a recovery with a new key and follow it until it can be finalized.
+    RecoverAccount {
+        /// Target account (base-58).
+        #[arg(long)]
+        target: String,
+        /// Keyfile holding the proposed new owner key; generated if missing.
+        #[arg(long, required_unless_present = "challenge")]
+        new_keyfile: Option<PathBuf>,
+        /// Blake3 hash of off-chain evidence (hex, 32 bytes). With
+        /// --challenge, the counter-evidence.
+        #[arg(long)]
+        evidence: String,
+        /// Where the evidence can be fetched (ipfs://… or https://…).
+        #[arg(long)]
+        evidence_uri: Option<String>,
+        /// Recovery (or challenge) bond in KX.
+        #[arg(long)]
+        bond_kx: f64,
+        /// Submit FinalizeRecovery once the verifiers approve and the delay
+        /// has elapsed, instead of stopping there.
+        #[arg(long, conflicts_with = "challenge")]
+        auto_finalize: bool,
+        /// Challenge the target's in-progress recovery instead of starting one.
+        #[arg(long)]
+        challenge: bool,
+    },
+
     /// Create an email time-lock (uses sender's own pubkey; claimable via code).
     EmailTimelock {
         /// Recipient email address.
@@ -217,8 +386,63 @@ enum Command {
         lock_id: String,
     },
 
+    /// Show status, amount and maturity of one or more locks.
+    LockStatus {
+        /// Lock IDs (TxId hex).
+        #[arg(required = true)]
+        lock_ids: Vec<String>,
+    },
+
+    /// Summarize this wallet's locks by maturity, with an unlock timeline.
+    Portfolio {
+        /// Output format: "table", "csv" or "json".
+        #[arg(long, default_value = "table", value_parser = ["table", "csv", "json"])]
+        format: String,
+
+        /// List every pending lock by unlock day instead of the maturity summary.
+        #[arg(long)]
+        timeline: bool,
+    },
+
+    /// Look up a transaction id, account id, lock id or lock client_ref.
+    Search {
+        /// The id to look up.
+        query: String,
+    },
+
+    /// Show the sub-accounts derived from the keyfile's key, with balances.
+    Tree {
+        /// Levels of sub-accounts to show.
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=tree::MAX_DEPTH as i64))]
+        depth: u8,
+
+        /// Sub-accounts to show under each key.
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u8).range(1..=tree::MAX_CHILDREN as i64))]
+        children: u8,
+
+        /// Write one sub-account's keypair to its own keyfile instead.
+        #[arg(long, requires_all = ["index", "out"])]
+        generate_child: bool,
+
+        /// Sub-account to export: a child index, or a path such as `2/0`.
+        #[arg(long, requires = "generate_child")]
+        index: Option<tree::KeyPath>,
+
+        /// Keyfile to write the sub-account to.
+        #[arg(long, requires = "generate_child")]
+        out: Option<PathBuf>,
+    },
+
     /// Print genesis/protocol info from the node.
-    Info,
+    Info {
+        /// Also print the node's build, host, storage and network details.
+        #[arg(long)]
+        verbose: bool,
+
+        /// Also print the current PoW difficulty and its recent adjustments.
+        #[arg(long)]
+        show_difficulty_history: bool,
+    },
 
     /// Create an invoice requesting payment.
     CreateInvoice {
@@ -357,6 +581,49 @@ enum Command {
         #[arg(long, default_value = "~/.chronx/genesis")]
         out_dir: PathBuf,
     },
+
+    /// Check a genesis-params.json file before launching a chain: keys must be
+    /// well-formed and custom allocations must fit inside the public sale.
+    ValidateGenesisParams {
+        /// Path to the genesis params file.
+        #[arg(long)]
+        params: PathBuf,
+    },
+
+    /// Write the genesis-params.json of the network a running node belongs
+    /// to, so a new node can join it with `--genesis-params`.
+    ExportGenesisParams {
+        /// Where to write the params file.
+        #[arg(long)]
+        output: PathBuf,
+    },
+}
+
+/// The clap command with `--help` text in `lang`.
+fn localized_command(lang: Lang) -> clap::Command {
+    let mut cmd = Args::command().about(i18n::tr(lang, "help-about", &[]));
+    for arg in ["keyfile", "rpc", "short", "lang"] {
+        let help = i18n::tr(lang, &format!("help-arg-{arg}"), &[]);
+        cmd = cmd.mut_arg(arg, |a| a.help(help).long_help(None));
+    }
+    let names: Vec<String> = cmd.get_subcommands().map(|s| s.get_name().to_string()).collect();
+    for name in names {
+        let key = format!("help-{name}");
+        if i18n::has(lang, &key) {
+            let about = i18n::tr(lang, &key, &[]);
+            cmd = cmd.mut_subcommand(name, |s| s.about(about).long_about(None));
+        }
+    }
+    cmd
+}
+
+/// The `balance` command's output.
+fn balance_report(lang: Lang, account: &str, chronos: u128) -> String {
+    format!(
+        "{}\n{}",
+        t!(lang => "balance-account", account = account),
+        t!(lang => "balance-amount", amount = units::format_chronos_in(chronos, &lang.number_locale())),
+    )
 }
 
 // ── Main ─────────────────────────────────────────────────────────────────────
@@ -367,48 +634,79 @@ async fn main() -> anyhow::Result<()> {
         .with_env_filter("warn,chronx_wallet=info")
         .init();
 
-    let args = Args::parse();
+    let lang = i18n::detect(&std::env::args().collect::<Vec<_>>());
+    i18n::set(lang);
+    let matches = localized_command(lang).get_matches();
+    let args = Args::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
     let keyfile = expand_tilde(&args.keyfile);
     let client = WalletRpcClient::new(&args.rpc);
+    let short = args.short;
 
     match args.command {
-        Command::Keygen => cmd_keygen(&keyfile),
+        Command::Interactive => interactive::run(&keyfile, &client, short).await,
+        command => execute(command, &keyfile, &client, short).await,
+    }
+}
+
+/// Run one command. The CLI and `interactive` both dispatch through here.
+async fn execute(command: Command, keyfile: &PathBuf, client: &WalletRpcClient, short: bool) -> anyhow::Result<()> {
+    match command {
+        Command::Interactive => unreachable!("interactive mode is started from main"),
+
+        Command::Keygen => cmd_keygen(keyfile),
 
         Command::Balance { account } => {
             let addr = match account {
-                Some(a) => a,
+                Some(a) => resolve_account(client, short, a).await?,
                 None => {
-                    let kp = load_keypair(&keyfile)?;
+                    let kp = load_keypair(keyfile)?;
                     kp.account_id.to_b58()
                 }
             };
             let bal = client.get_balance(&addr).await?;
-            let bal_kx = bal / CHRONOS_PER_KX;
-            println!("Account:  {}", addr);
-            println!("Balance:  {} KX  ({} Chronos)", bal_kx, bal);
+            println!("{}", balance_report(i18n::current(), &addr, bal));
+            Ok(())
+        }
+
+        Command::QrCode { account, payment_request, amount_kx, memo } => {
+            let account = match account {
+                Some(a) => resolve_account(client, short, a).await?,
+                None => load_keypair(keyfile)?.account_id.to_b58(),
+            };
+            let to = AccountId::from_b58(&account)
+                .map_err(|e| anyhow::anyhow!(t!("err-invalid-account", error = e)))?;
+            let uri = match amount_kx.filter(|_| payment_request) {
+                Some(amount_kx) => PaymentRequest { to, amount_kx, memo }.to_uri(),
+                None => payment_request::account_uri(&to),
+            };
+            println!("{}", payment_request::render_qr(&uri)?);
+            println!("{uri}");
             Ok(())
         }
 
-        Command::Transfer { to, amount } => {
-            let kp = load_keypair(&keyfile)?;
+        Command::Transfer { to, amount, payment_request_qr, dust_limit_kx } => {
+            let request = payment_request_qr.as_deref().map(PaymentRequest::from_qr).transpose()?;
+            let to = match (to, &request) {
+                (Some(to), _) => resolve_account(client, short, to).await?,
+                (None, Some(r)) => r.to.to_b58(),
+                (None, None) => unreachable!("clap requires --to without --payment-request-qr"),
+            };
+            let amount = amount
+                .or(request.as_ref().map(|r| r.amount_kx))
+                .expect("clap requires --amount without --payment-request-qr");
+            let memo = request.and_then(|r| r.memo);
+            let chronos = transfer_chronos(amount, dust_limit_kx)?;
+            let kp = load_keypair(keyfile)?;
             let to_id =
-                AccountId::from_b58(&to).map_err(|e| anyhow::anyhow!("invalid account: {e}"))?;
-            let chronos = kx_to_chronos(amount);
+                AccountId::from_b58(&to).map_err(|e| anyhow::anyhow!(t!("err-invalid-account", error = e)))?;
             let tx = build_and_sign(
                 &kp,
-                vec![Action::Transfer {
-                    to: to_id,
-                    amount: chronos,
-                    memo: None,
-                    memo_encrypted: true,
-                    memo_public: false,
-                    pay_as_amount: None,
-                }],
-                &client,
+                vec![transfer_action(to_id, chronos, memo)],
+                client,
             )
             .await?;
             let tx_id = client.send_transaction(&tx).await?;
-            println!("Submitted: {}", tx_id);
+            println!("{}", t!("tx-submitted", tx_id = tx_id));
             Ok(())
         }
 
@@ -418,82 +716,17 @@ async fn main() -> anyhow::Result<()> {
             unlock,
             memo,
         } => {
-            let kp = load_keypair(&keyfile)?;
-            let pk_bytes = hex::decode(&to_pubkey).context("decoding recipient public key hex")?;
+            let kp = load_keypair(keyfile)?;
+            let pk_bytes = hex::decode(&to_pubkey).context(t!("err-decode-recipient-key"))?;
             let chronos = kx_to_chronos(amount);
             let tx = build_and_sign(
                 &kp,
-                vec![Action::TimeLockCreate {
-                    recipient: DilithiumPublicKey(pk_bytes),
-                    amount: chronos,
-                    unlock_at: unlock,
-                    memo,
-                    cancellation_window_secs: None,
-                    notify_recipient: None,
-                    tags: None,
-                    private: None,
-                    expiry_policy: None,
-                    split_policy: None,
-                    claim_attempts_max: None,
-                    recurring: None,
-                    lock_marker: None,
-                    oracle_hint: None,
-                    jurisdiction_hint: None,
-                    governance_proposal_id: None,
-                    client_ref: None,
-                    email_recipient_hash: None,
-                    claim_window_secs: None,
-                    unclaimed_action: None,
-                lock_type: None,
-                yield_opt_out: None,
-                lock_metadata: None,
-                    agent_managed: None,
-                    grantor_axiom_consent_hash: None,
-                    investable_fraction: None,
-                    risk_level: None,
-                    investment_exclusions: None,
-                    grantor_intent: None,
-                    sign_of_life_interval_days: None,
-                    sign_of_life_grace_days: None,
-                    guardian_pubkey: None,
-                    guardian_until: None,
-                    alt_guardian_pubkey: None,
-                    beneficiary_description: None,
-                    beneficiary_description_hash: None,
-                    convert_to: None, authorized_claimants: None, succession_group: None, backup_executors: None, executor_threshold: None,
-                    memo_encrypted: true,
-                    memo_public: false,
-                    pay_as_amount: None,
-                    beneficiary_package: None,
-                
-                    transferable: None,
-                    current_owner_account: None,
-                    transfer_history: None,
-                    terms_visibility: None,
-                    tranche_info: None,
-                    retirement_status: None,
-                    retired_fraction: None,
-
-                    escalation_wallet: None,
-                    escalation_lock_seconds: None,
-                    min_attestors_pct: None,
-                    required_hedge_ids: None,
-                    success_payment_wallet: None,
-                    success_payment_chronos: None,
-                    condition_type: None,
-                    oracle_pair: None,
-                    oracle_trigger_threshold: None,
-                    oracle_trigger_direction: None,
-                    linked_instrument_id: None,
-                    extension_right: None,
-                    max_extensions: None,
-                    pay_as_execution: None,
-}],
-                &client,
+                vec![timelock_action(DilithiumPublicKey(pk_bytes), chronos, unlock, memo)],
+                client,
             )
             .await?;
             let tx_id = client.send_transaction(&tx).await?;
-            println!("TimeLock created: {}", tx_id);
+            println!("{}", t!("timelock-created", tx_id = tx_id));
             Ok(())
         }
 
@@ -506,7 +739,7 @@ async fn main() -> anyhow::Result<()> {
             use chronx_core::UnclaimedAction;
             use rand::Rng;
 
-            let kp = load_keypair(&keyfile)?;
+            let kp = load_keypair(keyfile)?;
             let chronos = kx_to_chronos(amount);
 
             // Generate claim code: KX-XXXX-XXXX-XXXX-XXXX
@@ -602,29 +835,118 @@ async fn main() -> anyhow::Result<()> {
                     max_extensions: None,
                     pay_as_execution: None,
 }],
-                &client,
+                client,
             )
             .await?;
             let tx_id = client.send_transaction(&tx).await?;
-            println!("Submitted: {}", tx_id);
-            println!("ClaimCode: {}", claim_code);
+            println!("{}", t!("tx-submitted", tx_id = tx_id));
+            println!("{}", t!("claim-code", code = claim_code));
             Ok(())
         }
 
-        Command::Claim { lock_id } => {
-            let kp = load_keypair(&keyfile)?;
+        Command::Claim { lock_id: None, all, dry_run, .. } => {
+            let kp = load_keypair(keyfile)?;
+            cmd_claim_matured(client, &kp, all, dry_run, chrono::Utc::now().timestamp()).await
+        }
+
+        Command::Claim { lock_id: Some(lock_id), .. } => {
+            let kp = load_keypair(keyfile)?;
+            let lock_id = resolve_lock_id(client, short, lock_id).await?;
             let lock_txid =
-                TxId::from_hex(&lock_id).map_err(|e| anyhow::anyhow!("invalid lock id: {e}"))?;
+                TxId::from_hex(&lock_id).map_err(|e| anyhow::anyhow!(t!("err-invalid-lock-id", error = e)))?;
             let tx = build_and_sign(
                 &kp,
                 vec![Action::TimeLockClaim {
                     lock_id: TimeLockId(lock_txid),
                 }],
-                &client,
+                client,
             )
             .await?;
             let tx_id = client.send_transaction(&tx).await?;
-            println!("Claim submitted: {}", tx_id);
+            println!("{}", t!("claim-submitted", tx_id = tx_id));
+            Ok(())
+        }
+
+        Command::ClaimV2Open { lock_id } => {
+            let kp = load_keypair(keyfile)?;
+            let lock_id = resolve_lock_id(client, short, lock_id).await?;
+            let lock_txid =
+                TxId::from_hex(&lock_id).map_err(|e| anyhow::anyhow!(t!("err-invalid-lock-id", error = e)))?;
+            let tx = build_and_sign(&kp, vec![Action::OpenClaim { lock_id: TimeLockId(lock_txid) }], client).await?;
+            let tx_id = client.send_transaction(&tx).await?;
+            println!("{}", t!("claim-open-submitted", tx_id = tx_id));
+            Ok(())
+        }
+
+        Command::UpdateLockMemo { lock_id, memo } => {
+            let kp = load_keypair(keyfile)?;
+            let lock_id = resolve_lock_id(client, short, lock_id).await?;
+            let lock_txid =
+                TxId::from_hex(&lock_id).map_err(|e| anyhow::anyhow!(t!("err-invalid-lock-id", error = e)))?;
+            let tx = build_and_sign(
+                &kp,
+                vec![Action::UpdateLockMemo {
+                    lock_id: TimeLockId(lock_txid),
+                    new_memo: memo,
+                }],
+                client,
+            )
+            .await?;
+            let tx_id = client.send_transaction(&tx).await?;
+            println!("{}", t!("memo-update-submitted", tx_id = tx_id));
+            Ok(())
+        }
+
+        Command::SetCurrency { currency } => {
+            let kp = load_keypair(keyfile)?;
+            let action = match currency {
+                Some(c) => Action::SetPreferredFiatCurrency { currency: c.trim().to_uppercase() },
+                None => Action::ClearPreferredFiatCurrency,
+            };
+            let tx = build_and_sign(&kp, vec![action], client).await?;
+            let tx_id = client.send_transaction(&tx).await?;
+            println!("{}", t!("currency-update-submitted", tx_id = tx_id));
+            Ok(())
+        }
+
+        Command::UpdateLockTags { lock_id, tags } => {
+            let kp = load_keypair(keyfile)?;
+            let lock_id = resolve_lock_id(client, short, lock_id).await?;
+            let lock_txid =
+                TxId::from_hex(&lock_id).map_err(|e| anyhow::anyhow!(t!("err-invalid-lock-id", error = e)))?;
+            let tags: Vec<String> = tags
+                .split(',')
+                .map(|t| t.trim().to_string())
+                .filter(|t| !t.is_empty())
+                .collect();
+            let tx = build_and_sign(
+                &kp,
+                vec![Action::UpdateLockTags {
+                    lock_id: TimeLockId(lock_txid),
+                    tags,
+                }],
+                client,
+            )
+            .await?;
+            let tx_id = client.send_transaction(&tx).await?;
+            println!("{}", t!("tag-update-submitted", tx_id = tx_id));
+            Ok(())
+        }
+
+        Command::WatchLock { lock_id } => {
+            let lock_id = resolve_lock_id(client, short, lock_id).await?;
+            let mut events = client.subscribe_lock_status(&lock_id).await?;
+            println!("{}", t!("watch-lock-started", lock_id = lock_id));
+            while let Some(ev) = events.recv().await {
+                let when = chrono::DateTime::from_timestamp(ev.timestamp, 0)
+                    .map(|d| d.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+                    .unwrap_or_else(|| ev.timestamp.to_string());
+                println!("{when}  {}", ev.new_status);
+                if ev.is_terminal {
+                    println!("{}", t!("watch-lock-final"));
+                    break;
+                }
+            }
             Ok(())
         }
 
@@ -632,15 +954,16 @@ async fn main() -> anyhow::Result<()> {
             target,
             new_key,
             evidence,
+            evidence_uri,
             bond,
         } => {
-            let kp = load_keypair(&keyfile)?;
+            let kp = load_keypair(keyfile)?;
             let target_id = AccountId::from_b58(&target)
-                .map_err(|e| anyhow::anyhow!("invalid target account: {e}"))?;
-            let new_pk_bytes = hex::decode(&new_key).context("decoding proposed owner key hex")?;
-            let ev_bytes = hex::decode(&evidence).context("decoding evidence hash hex")?;
+                .map_err(|e| anyhow::anyhow!(t!("err-invalid-target-account", error = e)))?;
+            let new_pk_bytes = hex::decode(&new_key).context(t!("err-decode-owner-key"))?;
+            let ev_bytes = hex::decode(&evidence).context(t!("err-decode-evidence"))?;
             if ev_bytes.len() != 32 {
-                bail!("evidence hash must be 32 bytes (64 hex chars)");
+                bail!(t!("err-evidence-length"));
             }
             let mut ev_arr = [0u8; 32];
             ev_arr.copy_from_slice(&ev_bytes);
@@ -653,27 +976,29 @@ async fn main() -> anyhow::Result<()> {
                     proposed_owner_key: DilithiumPublicKey(new_pk_bytes),
                     evidence_hash: chronx_core::types::EvidenceHash(ev_arr),
                     bond_amount: bond_chronos,
+                    evidence_uri,
                 }],
-                &client,
+                client,
             )
             .await?;
             let tx_id = client.send_transaction(&tx).await?;
-            println!("Recovery started: {}", tx_id);
+            println!("{}", t!("recovery-started", tx_id = tx_id));
             Ok(())
         }
 
         Command::ChallengeRecovery {
             target,
             counter_evidence,
+            counter_evidence_uri,
             bond,
         } => {
-            let kp = load_keypair(&keyfile)?;
+            let kp = load_keypair(keyfile)?;
             let target_id = AccountId::from_b58(&target)
-                .map_err(|e| anyhow::anyhow!("invalid target account: {e}"))?;
+                .map_err(|e| anyhow::anyhow!(t!("err-invalid-target-account", error = e)))?;
             let ev_bytes =
-                hex::decode(&counter_evidence).context("decoding counter-evidence hash hex")?;
+                hex::decode(&counter_evidence).context(t!("err-decode-counter-evidence"))?;
             if ev_bytes.len() != 32 {
-                bail!("counter-evidence hash must be 32 bytes (64 hex chars)");
+                bail!(t!("err-counter-evidence-length"));
             }
             let mut ev_arr = [0u8; 32];
             ev_arr.copy_from_slice(&ev_bytes);
@@ -685,12 +1010,13 @@ async fn main() -> anyhow::Result<()> {
                     target_account: target_id,
                     counter_evidence_hash: chronx_core::types::EvidenceHash(ev_arr),
                     bond_amount: bond_chronos,
+                    counter_evidence_uri,
                 }],
-                &client,
+                client,
             )
             .await?;
             let tx_id = client.send_transaction(&tx).await?;
-            println!("Challenge submitted: {}", tx_id);
+            println!("{}", t!("challenge-submitted", tx_id = tx_id));
             Ok(())
         }
 
@@ -699,9 +1025,9 @@ async fn main() -> anyhow::Result<()> {
             approve,
             fee_bid,
         } => {
-            let kp = load_keypair(&keyfile)?;
+            let kp = load_keypair(keyfile)?;
             let target_id = AccountId::from_b58(&target)
-                .map_err(|e| anyhow::anyhow!("invalid target account: {e}"))?;
+                .map_err(|e| anyhow::anyhow!(t!("err-invalid-target-account", error = e)))?;
             let fee_chronos = kx_to_chronos(fee_bid);
 
             let tx = build_and_sign(
@@ -711,29 +1037,120 @@ async fn main() -> anyhow::Result<()> {
                     approve,
                     fee_bid: fee_chronos,
                 }],
-                &client,
+                client,
             )
             .await?;
             let tx_id = client.send_transaction(&tx).await?;
-            println!("Vote submitted (approve={}): {}", approve, tx_id);
+            println!("{}", t!("vote-submitted", approve = approve, tx_id = tx_id));
             Ok(())
         }
 
         Command::FinalizeRecovery { target } => {
-            let kp = load_keypair(&keyfile)?;
+            let kp = load_keypair(keyfile)?;
             let target_id = AccountId::from_b58(&target)
-                .map_err(|e| anyhow::anyhow!("invalid target account: {e}"))?;
+                .map_err(|e| anyhow::anyhow!(t!("err-invalid-target-account", error = e)))?;
 
             let tx = build_and_sign(
                 &kp,
                 vec![Action::FinalizeRecovery {
                     target_account: target_id,
                 }],
-                &client,
+                client,
             )
             .await?;
             let tx_id = client.send_transaction(&tx).await?;
-            println!("Recovery finalized: {}", tx_id);
+            println!("{}", t!("recovery-finalized", tx_id = tx_id));
+            Ok(())
+        }
+
+        Command::RecoverAccount {
+            target,
+            new_keyfile,
+            evidence,
+            evidence_uri,
+            bond_kx,
+            auto_finalize,
+            challenge,
+        } => {
+            let kp = load_keypair(keyfile)?;
+            let target = resolve_account(client, short, target).await?;
+            let target_id = AccountId::from_b58(&target)
+                .map_err(|e| anyhow::anyhow!(t!("err-invalid-target-account", error = e)))?;
+            let ev_bytes = hex::decode(&evidence).context(t!("err-decode-evidence"))?;
+            let ev_arr: [u8; 32] = ev_bytes.try_into().map_err(|_| anyhow::anyhow!(t!("err-evidence-length")))?;
+            let evidence_hash = chronx_core::types::EvidenceHash(ev_arr);
+            let bond_amount = kx_to_chronos(bond_kx);
+
+            if challenge {
+                let tx = build_and_sign(
+                    &kp,
+                    vec![Action::ChallengeRecovery {
+                        target_account: target_id,
+                        counter_evidence_hash: evidence_hash,
+                        bond_amount,
+                        counter_evidence_uri: evidence_uri,
+                    }],
+                    client,
+                )
+                .await?;
+                let tx_id = client.send_transaction(&tx).await?;
+                println!("{}", t!("challenge-submitted", tx_id = tx_id));
+                return Ok(());
+            }
+
+            let new_keyfile = new_keyfile.expect("clap requires --new-keyfile without --challenge");
+            if !new_keyfile.exists() {
+                cmd_keygen(&new_keyfile)?;
+            }
+            let new_kp = load_keypair(&new_keyfile)?;
+            let tx = build_and_sign(
+                &kp,
+                vec![Action::StartRecovery {
+                    target_account: target_id.clone(),
+                    proposed_owner_key: new_kp.public_key.clone(),
+                    evidence_hash,
+                    bond_amount,
+                    evidence_uri,
+                }],
+                client,
+            )
+            .await?;
+            let tx_id = client.send_transaction(&tx).await?;
+            println!("{}", t!("recovery-started", tx_id = tx_id));
+
+            loop {
+                tokio::time::sleep(RECOVERY_POLL_INTERVAL).await;
+                let Some(rs) = client.get_recovery_status(&target).await? else {
+                    bail!(t!("err-account-not-found", account = target));
+                };
+                match recovery_progress(&rs) {
+                    RecoveryProgress::Ended => bail!(t!("err-recovery-ended", decision = rs.decision_status)),
+                    RecoveryProgress::Ready => break,
+                    RecoveryProgress::Waiting => println!(
+                        "{}",
+                        t!(
+                            "recovery-progress",
+                            approve = rs.votes_approve,
+                            threshold = rs.verifier_threshold,
+                            secs = rs.seconds_until_execution.unwrap_or(0),
+                        )
+                    ),
+                }
+            }
+
+            if !auto_finalize {
+                println!("{}", t!("recovery-ready", target = target));
+                return Ok(());
+            }
+            let tx = build_and_sign(
+                &kp,
+                vec![Action::FinalizeRecovery { target_account: target_id }],
+                client,
+            )
+            .await?;
+            let tx_id = client.send_transaction(&tx).await?;
+            println!("{}", t!("recovery-finalized", tx_id = tx_id));
+            println!("{}", t!("recovery-new-owner", account = target, path = new_keyfile.display()));
             Ok(())
         }
 
@@ -745,7 +1162,7 @@ async fn main() -> anyhow::Result<()> {
             use chronx_core::UnclaimedAction;
             use rand::Rng;
 
-            let kp = load_keypair(&keyfile)?;
+            let kp = load_keypair(keyfile)?;
 
             // Parse stages JSON
             #[derive(serde::Deserialize)]
@@ -754,10 +1171,10 @@ async fn main() -> anyhow::Result<()> {
                 lock_seconds: i64,
             }
             let parsed: Vec<Stage> =
-                serde_json::from_str(&stages).context("parsing stages JSON")?;
+                serde_json::from_str(&stages).context(t!("err-parse-stages"))?;
 
             if parsed.is_empty() {
-                bail!("stages array cannot be empty");
+                bail!(t!("err-empty-stages"));
             }
 
             // Generate claim code: KX-XXXX-XXXX-XXXX-XXXX
@@ -874,17 +1291,14 @@ async fn main() -> anyhow::Result<()> {
 
             let n_stages = parsed.len();
             let total_kx: f64 = parsed.iter().map(|s| s.amount_kx).sum();
-            println!(
-                "Building cascade: {} stages, {} KX total...",
-                n_stages, total_kx
-            );
+            println!("{}", t!("cascade-building", stages = n_stages, total_kx = total_kx));
 
-            let tx = build_and_sign(&kp, actions, &client).await?;
+            let tx = build_and_sign(&kp, actions, client).await?;
             let tx_id = client.send_transaction(&tx).await?;
 
-            println!("Submitted:  {}", tx_id);
-            println!("ClaimCode:  {}", claim_code);
-            println!("Email:      {}", email);
+            println!("{}", t!("cascade-submitted", tx_id = tx_id));
+            println!("{}", t!("cascade-claim-code", code = claim_code));
+            println!("{}", t!("cascade-email", email = email));
             for (i, s) in parsed.iter().enumerate() {
                 let unlock = if s.lock_seconds <= 0 {
                     now
@@ -892,18 +1306,21 @@ async fn main() -> anyhow::Result<()> {
                     now + s.lock_seconds
                 };
                 println!(
-                    "  Stage {}: {:>8.2} KX — unlock_at {} (lock {} sec)",
-                    i + 1,
-                    s.amount_kx,
-                    unlock,
-                    s.lock_seconds
+                    "  {}",
+                    t!(
+                        "cascade-stage",
+                        stage = i + 1,
+                        amount_kx = format!("{:>8.2}", s.amount_kx),
+                        unlock_at = unlock,
+                        lock_secs = s.lock_seconds,
+                    )
                 );
             }
             Ok(())
         }
 
         Command::VerifierRegister { name, wallet, bond, pubkey, jurisdiction, role } => {
-            let kp = load_keypair(&keyfile)?;
+            let kp = load_keypair(keyfile)?;
             let tx = build_and_sign(
                 &kp,
                 vec![Action::VerifierRegister {
@@ -914,25 +1331,25 @@ async fn main() -> anyhow::Result<()> {
                     jurisdiction,
                     role,
                 }],
-                &client,
+                client,
             )
             .await?;
             let tx_id = client.send_transaction(&tx).await?;
-            println!("VerifierRegister submitted: {}", tx_id);
+            println!("{}", t!("verifier-register-submitted", tx_id = tx_id));
             Ok(())
         }
 
 
         Command::ClaimByCode { claim_code } => {
-            let kp = load_keypair(&keyfile)?;
+            let kp = load_keypair(keyfile)?;
             let code = claim_code.trim().to_uppercase();
             let target_hash = hex::encode(blake3::hash(code.as_bytes()).as_bytes());
 
             // Look up locks by claim_secret_hash
             let cascade = client.get_cascade_details(&target_hash).await
-                .context("looking up claim code")?;
+                .context(t!("err-lookup-claim-code"))?;
             let locks = cascade["locks"].as_array()
-                .ok_or_else(|| anyhow::anyhow!("claim code not found"))?;
+                .ok_or_else(|| anyhow::anyhow!(t!("err-claim-code-not-found")))?;
 
             let now = chrono::Utc::now().timestamp();
             let actions: Vec<Action> = locks.iter()
@@ -949,18 +1366,19 @@ async fn main() -> anyhow::Result<()> {
                 .collect();
 
             if actions.is_empty() {
-                bail!("No claimable locks found for this code (may be immature or already claimed)");
+                bail!(t!("err-no-claimable-locks"));
             }
             let count = actions.len();
-            let tx = build_and_sign(&kp, actions, &client).await?;
+            let tx = build_and_sign(&kp, actions, client).await?;
             let tx_id = client.send_transaction(&tx).await?;
-            println!("Claimed {} lock(s): {}", count, tx_id);
+            println!("{}", t!("claimed-locks", count = count, tx_id = tx_id));
             Ok(())
         }
         Command::ExecutorWithdraw { lock_id } => {
-            let kp = load_keypair(&keyfile)?;
+            let kp = load_keypair(keyfile)?;
+            let lock_id = resolve_lock_id(client, short, lock_id).await?;
             let lock_txid =
-                TxId::from_hex(&lock_id).map_err(|e| anyhow::anyhow!("invalid lock id: {e}"))?;
+                TxId::from_hex(&lock_id).map_err(|e| anyhow::anyhow!(t!("err-invalid-lock-id", error = e)))?;
 
             // The destination is the executor's own wallet (the keyfile being used).
             let destination = kp.account_id.clone();
@@ -974,21 +1392,21 @@ async fn main() -> anyhow::Result<()> {
                     destination: destination.clone(),
                     executor_pubkey,
                 }],
-                &client,
+                client,
             )
             .await?;
             let tx_id = client.send_transaction(&tx).await?;
-            println!("ExecutorWithdraw submitted: {}", tx_id);
-            println!("Lock:        {}", lock_id);
-            println!("Destination: {}", destination.to_b58());
-            println!("Status: PendingExecutor — will finalize after configured delay.");
-            println!("An alert email will be sent to alerts@misai.io.");
+            println!("{}", t!("executor-withdraw-submitted", tx_id = tx_id));
+            println!("{}", t!("executor-withdraw-lock", lock_id = lock_id));
+            println!("{}", t!("executor-withdraw-destination", destination = destination.to_b58()));
+            println!("{}", t!("executor-withdraw-status"));
+            println!("{}", t!("executor-withdraw-alert"));
             Ok(())
         }
 
 
         Command::CreateInvoice { amount, expiry_days, payer: _, memo } => {
-            let kp = load_keypair(&keyfile)?;
+            let kp = load_keypair(keyfile)?;
             let amount_chronos = kx_to_chronos(amount) as u64;
             let now = std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH).unwrap().as_secs();
@@ -1010,15 +1428,15 @@ async fn main() -> anyhow::Result<()> {
                 authorized_payers: None,
             });
 
-            let tx = build_and_sign(&kp, vec![action], &client).await?;
+            let tx = build_and_sign(&kp, vec![action], client).await?;
             let tx_id = client.send_transaction(&tx).await?;
-            println!("Invoice created. TxId: {}", tx_id);
-            println!("Invoice ID: {}", hex::encode(invoice_id));
+            println!("{}", t!("invoice-created", tx_id = tx_id));
+            println!("{}", t!("invoice-id", id = hex::encode(invoice_id)));
             Ok(())
         }
 
         Command::CreateCredit { beneficiary, ceiling, expiry_days, per_draw } => {
-            let kp = load_keypair(&keyfile)?;
+            let kp = load_keypair(keyfile)?;
             let ceiling_chronos = kx_to_chronos(ceiling) as u64;
             let per_draw_chronos = per_draw.map(|v| kx_to_chronos(v) as u64);
             let now = std::time::SystemTime::now()
@@ -1030,7 +1448,7 @@ async fn main() -> anyhow::Result<()> {
             // Note: beneficiary pubkey resolution requires account lookup
             // For CLI, use an empty placeholder — full resolution in wallet GUI
             let _beneficiary_id = AccountId::from_b58(&beneficiary)
-                .map_err(|e| anyhow::anyhow!("invalid beneficiary address: {e}"))?;
+                .map_err(|e| anyhow::anyhow!(t!("err-invalid-beneficiary", error = e)))?;
 
             let action = Action::CreateCredit(CreateCreditAction {
                 grantor_pubkey: kp.public_key.clone(),
@@ -1043,17 +1461,17 @@ async fn main() -> anyhow::Result<()> {
                 beneficiary_group: None,
             });
 
-            let tx = build_and_sign(&kp, vec![action], &client).await?;
+            let tx = build_and_sign(&kp, vec![action], client).await?;
             let tx_id = client.send_transaction(&tx).await?;
-            println!("Credit authorization created. TxId: {}", tx_id);
-            println!("Credit ID: {}", hex::encode(credit_id));
+            println!("{}", t!("credit-created", tx_id = tx_id));
+            println!("{}", t!("credit-id", id = hex::encode(credit_id)));
             Ok(())
         }
 
         Command::DrawCredit { credit_id, amount } => {
-            let kp = load_keypair(&keyfile)?;
+            let kp = load_keypair(keyfile)?;
             let amount_chronos = kx_to_chronos(amount) as u64;
-            let id_bytes = hex::decode(&credit_id).context("invalid credit_id hex")?;
+            let id_bytes = hex::decode(&credit_id).context(t!("err-invalid-hex", field = "credit_id"))?;
             let mut cid = [0u8; 32];
             cid.copy_from_slice(&id_bytes);
 
@@ -1063,14 +1481,14 @@ async fn main() -> anyhow::Result<()> {
                 amount_chronos,
             });
 
-            let tx = build_and_sign(&kp, vec![action], &client).await?;
+            let tx = build_and_sign(&kp, vec![action], client).await?;
             let tx_id = client.send_transaction(&tx).await?;
-            println!("Credit drawn. TxId: {}", tx_id);
+            println!("{}", t!("credit-drawn", tx_id = tx_id));
             Ok(())
         }
 
         Command::CreateDeposit { obligor, amount, rate_bps, term_days, compounding } => {
-            let kp = load_keypair(&keyfile)?;
+            let kp = load_keypair(keyfile)?;
             let principal_chronos = kx_to_chronos(amount) as u64;
             let term_seconds = term_days * 86400;
             let now = std::time::SystemTime::now()
@@ -1086,7 +1504,7 @@ async fn main() -> anyhow::Result<()> {
             };
 
             let _obligor_id = AccountId::from_b58(&obligor)
-                .map_err(|e| anyhow::anyhow!("invalid obligor address: {e}"))?;
+                .map_err(|e| anyhow::anyhow!(t!("err-invalid-obligor", error = e)))?;
 
             let action = Action::CreateDeposit(CreateDepositAction {
                 depositor_pubkey: kp.public_key.clone(),
@@ -1099,15 +1517,15 @@ async fn main() -> anyhow::Result<()> {
                 deposit_id,
             });
 
-            let tx = build_and_sign(&kp, vec![action], &client).await?;
+            let tx = build_and_sign(&kp, vec![action], client).await?;
             let tx_id = client.send_transaction(&tx).await?;
-            println!("Deposit created. TxId: {}", tx_id);
-            println!("Deposit ID: {}", hex::encode(deposit_id));
+            println!("{}", t!("deposit-created", tx_id = tx_id));
+            println!("{}", t!("deposit-id", id = hex::encode(deposit_id)));
             Ok(())
         }
 
         Command::CreateConditional { recipient, amount, attestor: _, min_attestors, expiry_days, fallback, condition_type, oracle_pair, oracle_trigger_threshold, oracle_trigger_direction, success_payment_wallet, success_payment_kx, expiry_seconds } => {
-            let kp = load_keypair(&keyfile)?;
+            let kp = load_keypair(keyfile)?;
             let amount_chronos = kx_to_chronos(amount) as u64;
             let now = std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH).unwrap().as_secs();
@@ -1122,7 +1540,7 @@ async fn main() -> anyhow::Result<()> {
             };
 
             let _recipient_id = AccountId::from_b58(&recipient)
-                .map_err(|e| anyhow::anyhow!("invalid recipient address: {e}"))?;
+                .map_err(|e| anyhow::anyhow!(t!("err-invalid-recipient", error = e)))?;
 
             let valid_until_final = if let Some(secs) = expiry_seconds {
                 now + secs
@@ -1152,16 +1570,16 @@ async fn main() -> anyhow::Result<()> {
                 hedge_execution: None,
             });
 
-            let tx = build_and_sign(&kp, vec![action], &client).await?;
+            let tx = build_and_sign(&kp, vec![action], client).await?;
             let tx_id = client.send_transaction(&tx).await?;
-            println!("Conditional payment created. TxId: {}", tx_id);
-            println!("Type V ID: {}", hex::encode(type_v_id));
+            println!("{}", t!("conditional-created", tx_id = tx_id));
+            println!("{}", t!("conditional-id", id = hex::encode(type_v_id)));
             Ok(())
         }
 
         Command::AttestConditional { type_v_id } => {
-            let kp = load_keypair(&keyfile)?;
-            let id_bytes = hex::decode(&type_v_id).context("invalid type_v_id hex")?;
+            let kp = load_keypair(keyfile)?;
+            let id_bytes = hex::decode(&type_v_id).context(t!("err-invalid-hex", field = "type_v_id"))?;
             let mut vid = [0u8; 32];
             vid.copy_from_slice(&id_bytes);
 
@@ -1172,18 +1590,18 @@ async fn main() -> anyhow::Result<()> {
                 release_amount_chronos: None,
             });
 
-            let tx = build_and_sign(&kp, vec![action], &client).await?;
+            let tx = build_and_sign(&kp, vec![action], client).await?;
             let tx_id = client.send_transaction(&tx).await?;
-            println!("Conditional attested. TxId: {}", tx_id);
+            println!("{}", t!("conditional-attested", tx_id = tx_id));
             Ok(())
         }
 
         Command::CreateLedgerEntry { promise_id, entry_type, content_hash, summary } => {
-            let kp = load_keypair(&keyfile)?;
-            let pid_bytes = hex::decode(&promise_id).context("invalid promise_id hex")?;
+            let kp = load_keypair(keyfile)?;
+            let pid_bytes = hex::decode(&promise_id).context(t!("err-invalid-hex", field = "promise_id"))?;
             let mut pid = [0u8; 32];
             pid.copy_from_slice(&pid_bytes);
-            let ch_bytes = hex::decode(&content_hash).context("invalid content_hash hex")?;
+            let ch_bytes = hex::decode(&content_hash).context(t!("err-invalid-hex", field = "content_hash"))?;
             let mut ch = [0u8; 32];
             ch.copy_from_slice(&ch_bytes);
             let now = std::time::SystemTime::now()
@@ -1215,21 +1633,194 @@ async fn main() -> anyhow::Result<()> {
                 entry_id,
             });
 
-            let tx = build_and_sign(&kp, vec![action], &client).await?;
+            let tx = build_and_sign(&kp, vec![action], client).await?;
             let tx_id = client.send_transaction(&tx).await?;
-            println!("Ledger entry created. TxId: {}", tx_id);
-            println!("Entry ID: {}", hex::encode(entry_id));
+            println!("{}", t!("ledger-entry-created", tx_id = tx_id));
+            println!("{}", t!("ledger-entry-id", id = hex::encode(entry_id)));
+            Ok(())
+        }
+
+
+        Command::CheckRecovery { account } => {
+            let account = resolve_account(client, short, account).await?;
+            cmd_check_recovery(client, &account).await
+        }
+
+        Command::ViewPrivateLock { lock_id } => {
+            let kp = load_keypair(keyfile)?;
+            let lock_id = resolve_lock_id(client, short, lock_id).await?;
+            let lock_txid =
+                TxId::from_hex(&lock_id).map_err(|e| anyhow::anyhow!(t!("err-invalid-lock-id", error = e)))?;
+            let challenge =
+                private_lock_view_challenge(&lock_txid, chrono::Utc::now().timestamp());
+            let sig = kp.sign(&challenge);
+            let lock = client
+                .get_private_timelock_details(&lock_id, &hex::encode(&sig.0))
+                .await?;
+            println!("{}", serde_json::to_string_pretty(&lock)?);
             Ok(())
         }
 
+        Command::EstimateLockValue { lock_id } => {
+            let lock_id = resolve_lock_id(client, short, lock_id).await?;
+            cmd_estimate_lock_value(client, &lock_id).await
+        }
 
-        Command::Info => {
+        Command::Info { verbose, show_difficulty_history } => {
             let info = client.get_genesis_info().await?;
-            println!("Protocol:     {}", info.protocol);
-            println!("Ticker:       {}", info.ticker);
-            println!("Base unit:    {}", info.base_unit);
-            println!("Total supply: {} {}", info.total_supply_kx, info.ticker);
-            println!("PoW difficulty: {} bits", info.pow_difficulty);
+            println!("{}", t!("info-protocol", value = info.protocol));
+            println!("{}", t!("info-ticker", value = info.ticker));
+            println!("{}", t!("info-base-unit", value = info.base_unit));
+            println!("{}", t!("info-total-supply", amount = info.total_supply_kx, ticker = info.ticker));
+            println!("{}", t!("info-pow-difficulty", bits = info.pow_difficulty));
+            if verbose {
+                let sys = client.get_system_info().await?;
+                println!();
+                println!("{}", t!("info-node-version", value = sys.node_version));
+                println!("{}", t!("info-rust-version", value = sys.rust_version));
+                println!("{}", t!("info-platform", os = sys.os, arch = sys.arch));
+                println!("{}", t!("info-data-dir", value = sys.data_dir));
+                println!("{}", t!("info-db-size", bytes = sys.db_size_bytes));
+                println!("{}", t!("info-uptime", secs = sys.uptime_secs));
+                println!("{}", t!("info-genesis-hash", value = sys.genesis_hash));
+                println!("{}", t!("info-network-id", value = sys.network_id));
+                println!("{}", t!("info-p2p-listen", value = sys.p2p_listen_addr));
+                println!("{}", t!("info-rpc-address", value = sys.rpc_addr));
+                let flags = &sys.feature_flags;
+                println!(
+                    "{} recurring={} split={} secondary_market={} transfer_lock={} email_lock={} compound_lock={}",
+                    t!("info-features"),
+                    flags.recurring_enabled,
+                    flags.split_enabled,
+                    flags.secondary_market_enabled,
+                    flags.transfer_lock_enabled,
+                    flags.email_lock_enabled,
+                    flags.compound_lock_enabled,
+                );
+            }
+            if show_difficulty_history {
+                let difficulty = client.get_difficulty().await?;
+                println!();
+                println!(
+                    "{}",
+                    t!(
+                        "info-difficulty-current",
+                        bits = difficulty.current_difficulty,
+                        min = difficulty.min_difficulty,
+                        max = difficulty.max_difficulty
+                    )
+                );
+                println!(
+                    "{}",
+                    t!(
+                        "info-difficulty-ema",
+                        ms = format!("{:.0}", difficulty.current_ema_ms),
+                        target = difficulty.target_interval_ms
+                    )
+                );
+                let history = client.get_difficulty_history(100).await?;
+                if history.is_empty() {
+                    println!("{}", t!("info-difficulty-history-none"));
+                    return Ok(());
+                }
+                println!(
+                    "{}",
+                    t!(
+                        "info-difficulty-last",
+                        direction = difficulty.last_adjustment_direction,
+                        depth = difficulty.last_adjustment_depth
+                    )
+                );
+                println!("{}", t!("info-difficulty-history"));
+                for adj in history.iter().rev() {
+                    let when = chrono::DateTime::from_timestamp(adj.at, 0)
+                        .map(|d| d.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+                        .unwrap_or_else(|| adj.at.to_string());
+                    println!(
+                        "{}",
+                        t!("info-difficulty-history-row", depth = adj.depth, bits = adj.difficulty, at = when)
+                    );
+                }
+            }
+            Ok(())
+        }
+
+        Command::LockStatus { lock_ids } => {
+            for summary in client.get_lock_summaries(&lock_ids).await? {
+                match summary {
+                    chronx_rpc::RpcLockSummary::Found {
+                        lock_id,
+                        status,
+                        amount_kx,
+                        unlock_at,
+                        seconds_until_unlock,
+                        ..
+                    } => {
+                        let amount = match amount_kx {
+                            Some(kx) => format!("{kx} KX"),
+                            None => t!("lock-status-private"),
+                        };
+                        println!(
+                            "{}",
+                            t!(
+                                "lock-status-row",
+                                lock_id = lock_id,
+                                amount = amount,
+                                status = status,
+                                unlock_at = unlock_at,
+                                seconds = seconds_until_unlock
+                            )
+                        );
+                    }
+                    chronx_rpc::RpcLockSummary::NotFound { lock_id, .. } => {
+                        println!("{}", t!("lock-status-not-found", lock_id = lock_id));
+                    }
+                }
+            }
+            Ok(())
+        }
+
+        Command::Portfolio { format, timeline: true } => {
+            let account = load_keypair(keyfile)?.account_id.to_b58();
+            let timeline = portfolio::LockTimeline::fetch(client, &account).await?;
+            match format.as_str() {
+                "csv" => print!("{}", timeline.to_csv()),
+                "json" => println!("{}", serde_json::to_string_pretty(&timeline)?),
+                _ => print!("{}", timeline.to_table()),
+            }
+            Ok(())
+        }
+
+        Command::Portfolio { format, timeline: false } => {
+            let account = load_keypair(keyfile)?.account_id.to_b58();
+            let portfolio = portfolio::Portfolio::fetch(client, &account, chrono::Utc::now().timestamp()).await?;
+            match format.as_str() {
+                "csv" => print!("{}", portfolio.to_csv()),
+                "json" => println!("{}", serde_json::to_string_pretty(&portfolio)?),
+                _ => print!("{}", portfolio.to_table()),
+            }
+            Ok(())
+        }
+
+        Command::Tree { generate_child: true, index, out, .. } => {
+            let (index, out) = index.zip(out).expect("clap requires --index and --out with --generate-child");
+            cmd_generate_child(&load_keypair(keyfile)?, &index, &expand_tilde(&out))
+        }
+
+        Command::Tree { depth, children, generate_child: false, .. } => {
+            let mut tree = tree::KeyNode::derive(&load_keypair(keyfile)?, depth.into(), children.into());
+            tree.fetch_balances(client).await?;
+            print!("{}", tree.to_tree()?);
+            Ok(())
+        }
+
+        Command::Search { query } => {
+            let result = client.search(&query).await?;
+            if result.result_type == "not_found" {
+                bail!(t!("err-search-not-found", query = query));
+            }
+            println!("{}", t!("search-result", kind = result.result_type));
+            println!("{}", serde_json::to_string_pretty(&result.data)?);
             Ok(())
         }
 
@@ -1237,17 +1828,237 @@ async fn main() -> anyhow::Result<()> {
             let dir = expand_tilde(&out_dir);
             cmd_genesis_params(&dir)
         }
+
+        Command::ValidateGenesisParams { params } => {
+            cmd_validate_genesis_params(&expand_tilde(&params))
+        }
+
+        Command::ExportGenesisParams { output } => {
+            cmd_export_genesis_params(client, &expand_tilde(&output)).await
+        }
     }
 }
 
 // ── Commands ──────────────────────────────────────────────────────────────────
 
+/// Whether `lock` has a claim policy that rules out a direct `TimeLockClaim`.
+fn needs_claims_workflow(lock: &chronx_rpc::RpcTimeLock) -> bool {
+    lock.lock_version >= 1 && lock.claim_policy.is_some()
+}
+
+/// Matured pending locks among `locks`: those `TimeLockClaim` accepts,
+/// largest first, and those that need the claims workflow.
+fn matured_locks(
+    locks: Vec<chronx_rpc::RpcTimeLock>,
+    now: i64,
+) -> (Vec<chronx_rpc::RpcTimeLock>, Vec<chronx_rpc::RpcTimeLock>) {
+    let (mut direct, workflow): (Vec<_>, Vec<_>) = locks
+        .into_iter()
+        .filter(|l| l.status == "Pending" && l.unlock_at < now)
+        .partition(|l| !needs_claims_workflow(l));
+    direct.sort_by_key(|l| std::cmp::Reverse(l.amount_chronos.parse::<u128>().unwrap_or(0)));
+    (direct, workflow)
+}
+
+/// `claim --auto` / `claim --all`: claim the largest matured incoming lock,
+/// or all of them as one transaction of `TimeLockClaim` actions.
+async fn cmd_claim_matured(
+    client: &WalletRpcClient,
+    kp: &KeyPair,
+    all: bool,
+    dry_run: bool,
+    now: i64,
+) -> anyhow::Result<()> {
+    let actions = matured_claim_actions(client, &kp.account_id.to_b58(), all, now).await?;
+    if dry_run {
+        println!("{}", t!("claim-dry-run"));
+        return Ok(());
+    }
+    let tx = build_and_sign(kp, actions, client).await?;
+    let tx_id = client.send_transaction(&tx).await?;
+    println!("{}", t!("claim-submitted", tx_id = tx_id));
+    Ok(())
+}
+
+/// Claims for the largest matured incoming lock of `account` (or all of
+/// them with `all`), printing each one. Matured locks that need the claims
+/// workflow are pointed at `claim-v2-open` instead.
+async fn matured_claim_actions(
+    client: &WalletRpcClient,
+    account: &str,
+    all: bool,
+    now: i64,
+) -> anyhow::Result<Vec<Action>> {
+    let (mut direct, workflow) = matured_locks(client.get_pending_incoming(account).await?, now);
+    for lock in &workflow {
+        println!("{}", t!("claim-needs-workflow", lock_id = lock.lock_id));
+    }
+    if direct.is_empty() {
+        bail!(t!("err-no-matured-locks"));
+    }
+    if !all {
+        direct.truncate(1);
+    }
+    direct
+        .iter()
+        .map(|lock| {
+            println!("{}", t!("claim-selected", lock_id = lock.lock_id, amount_kx = lock.amount_kx));
+            let lock_txid =
+                TxId::from_hex(&lock.lock_id).map_err(|e| anyhow::anyhow!(t!("err-invalid-lock-id", error = e)))?;
+            Ok(Action::TimeLockClaim { lock_id: TimeLockId(lock_txid) })
+        })
+        .collect()
+}
+
+async fn cmd_check_recovery(client: &WalletRpcClient, account: &str) -> anyhow::Result<()> {
+    const GREEN: &str = "\x1b[32m";
+    const RED: &str = "\x1b[31m";
+    const RESET: &str = "\x1b[0m";
+
+    match client.is_recovery_active(account).await? {
+        None => bail!(t!("err-account-not-found", account = account)),
+        Some(false) => {
+            println!("{}", t!("recovery-none"));
+            return Ok(());
+        }
+        Some(true) => {}
+    }
+    let Some(rs) = client.get_recovery_status(account).await? else {
+        bail!(t!("err-account-not-found", account = account));
+    };
+    if !rs.active {
+        println!("{}", t!("recovery-none"));
+        return Ok(());
+    }
+
+    let fmt_ts = |ts: Option<i64>| {
+        ts.and_then(|t| chrono::DateTime::from_timestamp(t, 0))
+            .map(|d| d.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+            .unwrap_or_else(|| "-".to_string())
+    };
+    let fmt_kx = |chronos: &str| {
+        let c: u128 = chronos.parse().unwrap_or(0);
+        units::Amount(c).kx_in(&i18n::current().number_locale())
+    };
+    let countdown = match rs.seconds_until_execution {
+        Some(0) => t!("recovery-executable-now"),
+        Some(s) => format!("{}d {}h {}m", s / 86_400, (s % 86_400) / 3600, (s % 3600) / 60),
+        None => "-".to_string(),
+    };
+    let (color, state) = if rs.challenge_active {
+        (RED, t!("recovery-challenged"))
+    } else {
+        (GREEN, t!("recovery-on-track"))
+    };
+
+    println!("{}", t!("recovery-status-for", account = rs.account_id));
+    println!("  {:<20} {color}{state}{RESET}", t!("recovery-label-state"));
+    println!("  {:<20} {}", t!("recovery-label-initiator"), rs.initiator.as_deref().unwrap_or("-"));
+    println!("  {:<20} {}", t!("recovery-label-proposed-key"), rs.proposed_owner_key.as_deref().unwrap_or("-"));
+    println!("  {:<20} {}", t!("recovery-label-bond"), fmt_kx(&rs.recovery_bond_chronos));
+    println!("  {:<20} {}", t!("recovery-label-started"), fmt_ts(rs.recovery_start_time));
+    println!("  {:<20} {} ({})", t!("recovery-label-execute-after"), fmt_ts(rs.recovery_execute_after), countdown);
+    if rs.challenge_active {
+        println!("  {:<20} {color}{}{RESET}", t!("recovery-label-challenger"), rs.challenger.as_deref().unwrap_or("-"));
+        println!("  {:<20} {}", t!("recovery-label-challenge-bond"), fmt_kx(&rs.challenge_bond_chronos));
+    } else {
+        println!("  {:<20} {}", t!("recovery-label-challenge"), t!("recovery-no-challenge"));
+    }
+    println!(
+        "  {:<20} {}",
+        t!("recovery-label-votes"),
+        t!(
+            "recovery-votes",
+            approve = rs.votes_approve,
+            reject = rs.votes_reject,
+            threshold = rs.verifier_threshold,
+        )
+    );
+    println!("  {:<20} {}", t!("recovery-label-decision"), rs.decision_status);
+    if let Some(ev) = client.get_recovery_evidence(account).await? {
+        println!("  {:<20} {}", t!("recovery-label-evidence"), ev.evidence_uri.as_deref().unwrap_or("-"));
+        if rs.challenge_active {
+            println!(
+                "  {:<20} {}",
+                t!("recovery-label-counter-evidence"),
+                ev.counter_evidence_uri.as_deref().unwrap_or("-")
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Oracle pair used to value a lock: the lock's `oracle_hint` as `KX/<hint>`
+/// (or verbatim if it already names a pair), defaulting to KX/USD.
+fn oracle_pair_for(lock: &chronx_rpc::RpcTimeLock) -> String {
+    match lock.oracle_hint.as_deref().map(str::trim) {
+        Some(h) if h.contains('/') => h.to_uppercase(),
+        Some(h) if !h.is_empty() => format!("KX/{}", h.to_uppercase()),
+        _ => "KX/USD".to_string(),
+    }
+}
+
+/// USD-cents value of `amount_chronos` at `price_cents` per KX (saturating).
+fn lock_value_usd_cents(amount_chronos: u128, price_cents: u64) -> u64 {
+    let cents = amount_chronos.saturating_mul(price_cents as u128) / CHRONOS_PER_KX;
+    u64::try_from(cents).unwrap_or(u64::MAX)
+}
+
+fn oracle_is_stale(snapshot: &chronx_rpc::RpcOracleSnapshot, now: i64) -> bool {
+    now - snapshot.updated_at > chronx_core::constants::ORACLE_MAX_AGE_SECS
+}
+
+async fn cmd_estimate_lock_value(client: &WalletRpcClient, lock_id: &str) -> anyhow::Result<()> {
+    use chronx_core::claims::LaneThresholds;
+
+    let Some(lock) = client.get_lock_by_id(lock_id).await? else {
+        bail!(t!("err-lock-not-found", lock_id = lock_id));
+    };
+    let pair = oracle_pair_for(&lock);
+    let Some(snapshot) = client.get_oracle_snapshot(&pair).await? else {
+        bail!(t!("err-no-oracle-snapshot", pair = pair));
+    };
+    let amount: u128 = lock.amount_chronos.parse().context(t!("err-parse-lock-amount"))?;
+    let value_cents = lock_value_usd_cents(amount, snapshot.price_cents);
+    let lane = LaneThresholds::default_thresholds().lane_for(value_cents);
+    let now = chrono::Utc::now().timestamp();
+    let age = now - snapshot.updated_at;
+
+    println!(
+        "{:<66} {:>16} {:>14} {:>16} {:>10} {:<8}",
+        t!("estimate-col-lock-id"),
+        t!("estimate-col-amount"),
+        t!("estimate-col-price"),
+        t!("estimate-col-value"),
+        t!("estimate-col-age"),
+        t!("estimate-col-lane"),
+    );
+    println!(
+        "{:<66} {:>16} {:>14} {:>16} {:>10} {:<8}",
+        lock.lock_id,
+        units::kx_string(amount),
+        format!("${}.{:02}", snapshot.price_cents / 100, snapshot.price_cents % 100),
+        format!("${}.{:02}", value_cents / 100, value_cents % 100),
+        format!("{}s", age),
+        format!("{:?}", lane),
+    );
+    if oracle_is_stale(&snapshot, now) {
+        println!(
+            "{}",
+            t!(
+                "estimate-stale-oracle",
+                pair = pair,
+                age = age,
+                max_age = chronx_core::constants::ORACLE_MAX_AGE_SECS,
+            )
+        );
+    }
+    Ok(())
+}
+
 fn cmd_keygen(keyfile: &PathBuf) -> anyhow::Result<()> {
     if keyfile.exists() {
-        bail!(
-            "Keyfile {} already exists. Delete it first to generate a new key.",
-            keyfile.display()
-        );
+        bail!(t!("err-keyfile-exists", path = keyfile.display()));
     }
     if let Some(parent) = keyfile.parent() {
         std::fs::create_dir_all(parent)?;
@@ -1255,25 +2066,90 @@ fn cmd_keygen(keyfile: &PathBuf) -> anyhow::Result<()> {
     let kp = KeyPair::generate();
     let json = serde_json::to_string_pretty(&kp)?;
     std::fs::write(keyfile, &json)
-        .with_context(|| format!("writing keyfile to {}", keyfile.display()))?;
+        .with_context(|| t!("err-write-keyfile", path = keyfile.display()))?;
 
-    println!("Generated new keypair.");
-    println!("Account ID: {}", kp.account_id.to_b58());
-    println!("Public key: {}", hex::encode(&kp.public_key.0));
-    println!("Keyfile:    {}", keyfile.display());
-    println!("\nBACK UP YOUR KEYFILE. Loss = permanent loss of funds.");
+    println!("{}", t!("keygen-done"));
+    println!("{}", t!("keygen-account", account = kp.account_id.to_b58()));
+    println!("{}", t!("keygen-public-key", key = hex::encode(&kp.public_key.0)));
+    println!("{}", t!("keygen-keyfile", path = keyfile.display()));
+    println!();
+    println!("{}", t!("keygen-backup-warning"));
+    Ok(())
+}
+
+/// Write the sub-account at `path` below `master` to the keyfile `out`.
+fn cmd_generate_child(master: &KeyPair, path: &tree::KeyPath, out: &Path) -> anyhow::Result<()> {
+    if out.exists() {
+        bail!(t!("err-keyfile-exists", path = out.display()));
+    }
+    if let Some(parent) = out.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let kp = path.derive(master);
+    let json = serde_json::to_string_pretty(&kp)?;
+    std::fs::write(out, &json).with_context(|| t!("err-write-keyfile", path = out.display()))?;
+
+    println!("{}", t!("tree-child-written", path = path));
+    println!("{}", t!("keygen-account", account = kp.account_id.to_b58()));
+    println!("{}", t!("keygen-keyfile", path = out.display()));
+    Ok(())
+}
+
+fn cmd_validate_genesis_params(path: &Path) -> anyhow::Result<()> {
+    let raw = std::fs::read_to_string(path)
+        .with_context(|| t!("err-read-file", path = path.display()))?;
+    let params: GenesisParams = serde_json::from_str(&raw)
+        .with_context(|| t!("err-parse-genesis-params", path = path.display()))?;
+
+    let named_keys = [
+        ("public_sale_key", &params.public_sale_key),
+        ("treasury_key", &params.treasury_key),
+        ("humanity_key", &params.humanity_key),
+        ("node_rewards_key", &params.node_rewards_key),
+        ("founder_key", &params.founder_key),
+        ("misai_key", &params.misai_key),
+        ("verifas_key", &params.verifas_key),
+        ("milestone_key", &params.milestone_key),
+        ("reserve_key", &params.reserve_key),
+        ("faucet_key", &params.faucet_key),
+    ];
+    for (name, key) in named_keys {
+        if key.0.len() != 1312 {
+            bail!(t!("err-bad-key-length", field = name, got = key.0.len()));
+        }
+    }
+    for (i, alloc) in params.custom_allocations.iter().enumerate() {
+        if alloc.recipient_key.0.len() != 1312 {
+            bail!(t!(
+                "err-bad-key-length",
+                field = format!("custom_allocations[{i}].recipient_key"),
+                got = alloc.recipient_key.0.len(),
+            ));
+        }
+    }
+
+    let custom_total = chronx_genesis::custom_allocations_total(&params)
+        .map_err(|e| anyhow::anyhow!("custom_allocations: {e}"))?;
+
+    println!("{}", t!("genesis-params-valid", path = path.display()));
+    println!("{}", t!("genesis-custom-count", count = params.custom_allocations.len()));
+    println!("{}", t!("genesis-custom-total", kx = custom_total / CHRONOS_PER_KX));
+    println!(
+        "{}",
+        t!(
+            "genesis-public-sale-after",
+            kx = chronx_core::constants::PUBLIC_SALE_KX - custom_total / CHRONOS_PER_KX,
+        )
+    );
     Ok(())
 }
 
 fn cmd_genesis_params(out_dir: &PathBuf) -> anyhow::Result<()> {
     if out_dir.exists() {
-        bail!(
-            "Output directory {} already exists. Delete it first to avoid overwriting a previous genesis ceremony.",
-            out_dir.display()
-        );
+        bail!(t!("err-out-dir-exists", path = out_dir.display()));
     }
     std::fs::create_dir_all(out_dir)
-        .with_context(|| format!("creating output directory {}", out_dir.display()))?;
+        .with_context(|| t!("err-create-dir", path = out_dir.display()))?;
 
     // Generate all four keypairs.
     let public_sale_kp = KeyPair::generate();
@@ -1288,13 +2164,13 @@ fn cmd_genesis_params(out_dir: &PathBuf) -> anyhow::Result<()> {
     let nr_path = out_dir.join("node_rewards.json");
 
     std::fs::write(&ps_path, serde_json::to_string_pretty(&public_sale_kp)?)
-        .with_context(|| format!("writing {}", ps_path.display()))?;
+        .with_context(|| t!("err-write-file", path = ps_path.display()))?;
     std::fs::write(&tr_path, serde_json::to_string_pretty(&treasury_kp)?)
-        .with_context(|| format!("writing {}", tr_path.display()))?;
+        .with_context(|| t!("err-write-file", path = tr_path.display()))?;
     std::fs::write(&hu_path, serde_json::to_string_pretty(&humanity_kp)?)
-        .with_context(|| format!("writing {}", hu_path.display()))?;
+        .with_context(|| t!("err-write-file", path = hu_path.display()))?;
     std::fs::write(&nr_path, serde_json::to_string_pretty(&node_rewards_kp)?)
-        .with_context(|| format!("writing {}", nr_path.display()))?;
+        .with_context(|| t!("err-write-file", path = nr_path.display()))?;
 
     // Build GenesisParams (public keys only) and write genesis-params.json.
     // Generate milestone and reserve keypairs (v8.0)
@@ -1326,38 +2202,102 @@ fn cmd_genesis_params(out_dir: &PathBuf) -> anyhow::Result<()> {
         sweep_executor_interval_seconds: 60,
         pay_as_max_usd: 100.0,
         pay_as_enabled: true,
+        custom_allocations: Vec::new(),
     };
     let params_path = out_dir.join("genesis-params.json");
     std::fs::write(&params_path, serde_json::to_string_pretty(&params)?)
-        .with_context(|| format!("writing {}", params_path.display()))?;
-
-    println!("Genesis keypair ceremony complete.");
-    println!();
-    println!("Public sale");
-    println!("  Account:  {}", public_sale_kp.account_id.to_b58());
-    println!("  PubKey:   {}", hex::encode(&public_sale_kp.public_key.0));
-    println!("  Keyfile:  {}", ps_path.display());
-    println!();
-    println!("Treasury");
-    println!("  Account:  {}", treasury_kp.account_id.to_b58());
-    println!("  PubKey:   {}", hex::encode(&treasury_kp.public_key.0));
-    println!("  Keyfile:  {}", tr_path.display());
-    println!();
-    println!("Humanity stake");
-    println!("  Account:  {}", humanity_kp.account_id.to_b58());
-    println!("  PubKey:   {}", hex::encode(&humanity_kp.public_key.0));
-    println!("  Keyfile:  {}", hu_path.display());
-    println!();
-    println!("Node Rewards");
-    println!("  Account:  {}", node_rewards_kp.account_id.to_b58());
-    println!("  PubKey:   {}", hex::encode(&node_rewards_kp.public_key.0));
-    println!("  Keyfile:  {}", nr_path.display());
+        .with_context(|| t!("err-write-file", path = params_path.display()))?;
+
+    println!("{}", t!("genesis-ceremony-done"));
+    for (title, kp, path) in [
+        (t!("genesis-public-sale"), &public_sale_kp, &ps_path),
+        (t!("genesis-treasury"), &treasury_kp, &tr_path),
+        (t!("genesis-humanity"), &humanity_kp, &hu_path),
+        (t!("genesis-node-rewards"), &node_rewards_kp, &nr_path),
+    ] {
+        println!();
+        println!("{title}");
+        println!("  {}", t!("genesis-key-account", account = kp.account_id.to_b58()));
+        println!("  {}", t!("genesis-key-pubkey", key = hex::encode(&kp.public_key.0)));
+        println!("  {}", t!("genesis-key-keyfile", path = path.display()));
+    }
     println!();
-    println!("genesis-params.json written to: {}", params_path.display());
+    println!("{}", t!("genesis-params-written", path = params_path.display()));
     println!();
-    println!("CRITICAL: Move private keyfiles off this machine and into cold storage NOW.");
-    println!("Only genesis-params.json is needed by the node (--genesis-params flag).");
+    println!("{}", t!("genesis-cold-storage-warning"));
+    println!("{}", t!("genesis-params-only"));
+
+    Ok(())
+}
+
+/// Download the live public sale, treasury and humanity keys from a node and
+/// write them as genesis params. The genesis treasury and humanity locks must
+/// belong to the reported accounts, and the node must report the same total
+/// supply as this build. Keys the node does not report keep their defaults.
+async fn cmd_export_genesis_params(client: &WalletRpcClient, output: &Path) -> anyhow::Result<()> {
+    use chronx_crypto::hash::account_id_from_pubkey;
+    use chronx_genesis::{humanity_lock_id, treasury_lock_id};
 
+    if output.exists() {
+        bail!(t!("err-output-exists", path = output.display()));
+    }
+
+    let info = client.get_genesis_info().await?;
+    let local_supply_kx = units::kx_string(chronx_core::constants::TOTAL_SUPPLY_CHRONOS);
+    if info.total_supply_kx != local_supply_kx {
+        bail!(t!(
+            "err-supply-mismatch",
+            node_kx = info.total_supply_kx,
+            local_kx = local_supply_kx,
+        ));
+    }
+
+    let genesis_accounts = [
+        ("public_sale", info.public_sale_account, None),
+        ("treasury", info.treasury_account, Some(treasury_lock_id(1))),
+        ("humanity", info.humanity_account, Some(humanity_lock_id())),
+    ];
+    let mut keys = serde_json::Map::new();
+    for (name, account_id, lock_id) in genesis_accounts {
+        let account_id = account_id
+            .with_context(|| t!("err-genesis-account-missing", name = name))?;
+        let account = client
+            .get_account(&account_id)
+            .await?
+            .with_context(|| t!("err-genesis-account-not-found", name = name, account = account_id))?;
+        let key_hex = account
+            .public_key
+            .with_context(|| t!("err-genesis-key-missing", name = name, account = account_id))?;
+        let key = DilithiumPublicKey(hex::decode(&key_hex).context(t!("err-invalid-hex", field = "public_key"))?);
+        if account_id_from_pubkey(&key.0).to_b58() != account_id {
+            bail!(t!("err-genesis-key-mismatch", name = name, account = account_id));
+        }
+
+        if let Some(lock_id) = lock_id {
+            let lock = client
+                .get_lock_by_id(&lock_id.to_hex())
+                .await?
+                .with_context(|| t!("err-genesis-lock-not-found", name = name, lock_id = lock_id.to_hex()))?;
+            if lock.sender != account_id {
+                bail!(t!(
+                    "err-genesis-lock-foreign",
+                    name = name,
+                    lock_id = lock_id.to_hex(),
+                    owner = lock.sender,
+                    account = account_id,
+                ));
+            }
+        }
+        keys.insert(format!("{name}_key"), serde_json::to_value(&key)?);
+    }
+
+    let params: GenesisParams = serde_json::from_value(serde_json::Value::Object(keys))
+        .context(t!("err-build-genesis-params"))?;
+    std::fs::write(output, serde_json::to_string_pretty(&params)?)
+        .with_context(|| t!("err-write-file", path = output.display()))?;
+
+    println!("{}", t!("genesis-params-written", path = output.display()));
+    println!("{}", t!("genesis-supply-matches", kx = local_supply_kx));
     Ok(())
 }
 
@@ -1371,57 +2311,82 @@ async fn build_and_sign(
     // Fetch current nonce and DAG tips from the node.
     let nonce = client.get_nonce(&kp.account_id.to_b58()).await?;
     let tips = client.get_dag_tips().await?;
-
     let timestamp = chrono::Utc::now().timestamp();
-
-    // Serialize body (does NOT include pow_nonce — stable for mining).
-    let body_fields = chronx_core::transaction::TransactionBody {
-        parents: &tips,
-        timestamp,
-        nonce,
-        from: &kp.account_id,
-        actions: &actions,
-        auth_scheme: &AuthScheme::SingleSig,
-    };
-    let body_bytes = bincode::serialize(&body_fields)?;
-
-    info!("Mining PoW (difficulty={})...", POW_INITIAL_DIFFICULTY);
-    let pow_nonce = mine_pow(&body_bytes, POW_INITIAL_DIFFICULTY);
-    info!("PoW solved: nonce={}", pow_nonce);
-
-    let signature = kp.sign(&body_bytes);
-    let tx_id = tx_id_from_body(&body_bytes);
-
-    Ok(Transaction {
-        tx_id,
-        parents: tips,
-        timestamp,
-        nonce,
-        from: kp.account_id.clone(),
-        actions,
-        pow_nonce,
-        signatures: vec![signature],
-        auth_scheme: AuthScheme::SingleSig,
-        tx_version: 1,
-        client_ref: None,
-        fee_chronos: 0,
-        expires_at: None,
-        sender_public_key: Some(kp.public_key.clone()),
-    })
+    Ok(sign_transaction(kp, actions, tips, nonce, timestamp, POW_INITIAL_DIFFICULTY)?)
 }
 
 // ── Helpers ───────────────────────────────────────────────────────────────────
 
 fn load_keypair(keyfile: &PathBuf) -> anyhow::Result<KeyPair> {
     let json = std::fs::read_to_string(keyfile)
-        .with_context(|| format!("reading keyfile {}", keyfile.display()))?;
+        .with_context(|| t!("err-read-keyfile", path = keyfile.display()))?;
     let kp: KeyPair =
-        serde_json::from_str(&json).context("parsing keyfile — is it a valid ChronX keyfile?")?;
+        serde_json::from_str(&json).context(t!("err-parse-keyfile"))?;
     Ok(kp)
 }
 
-fn kx_to_chronos(kx: f64) -> u128 {
-    (kx * CHRONOS_PER_KX as f64) as u128
+/// With `--short`, resolve an account id prefix to the one account it matches.
+async fn resolve_account(client: &WalletRpcClient, short: bool, id: String) -> anyhow::Result<String> {
+    if !short {
+        return Ok(id);
+    }
+    unique_match("account", &id, client.find_account_by_prefix(&id).await?)
+}
+
+/// With `--short`, resolve a lock id prefix to the one lock it matches.
+async fn resolve_lock_id(client: &WalletRpcClient, short: bool, id: String) -> anyhow::Result<String> {
+    if !short {
+        return Ok(id);
+    }
+    unique_match("lock", &id, client.find_lock_by_prefix(&id).await?)
+}
+
+fn unique_match(kind: &str, prefix: &str, mut matches: Vec<String>) -> anyhow::Result<String> {
+    match matches.len() {
+        0 => bail!(t!("err-no-id-match", kind = kind, prefix = prefix)),
+        1 => Ok(matches.remove(0)),
+        _ => bail!(
+            "{}\n  {}",
+            t!("err-ambiguous-id", kind = kind, prefix = prefix),
+            matches.join("\n  ")
+        ),
+    }
+}
+
+/// How often `recover-account` polls the node for vote progress.
+const RECOVERY_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+
+#[derive(Debug, PartialEq, Eq)]
+enum RecoveryProgress {
+    /// Still collecting votes or waiting out the execution delay.
+    Waiting,
+    /// Approved and past the delay: `FinalizeRecovery` will be accepted.
+    Ready,
+    /// No recovery is active any more (rejected, finalized or cancelled).
+    Ended,
+}
+
+fn recovery_progress(rs: &chronx_rpc::RpcRecoveryStatus) -> RecoveryProgress {
+    if !rs.active {
+        RecoveryProgress::Ended
+    } else if rs.votes_approve >= rs.verifier_threshold && rs.seconds_until_execution == Some(0) {
+        RecoveryProgress::Ready
+    } else {
+        RecoveryProgress::Waiting
+    }
+}
+
+/// Convert a transfer amount to Chronos, refusing it if it falls below
+/// `dust_limit_kx`.
+fn transfer_chronos(amount_kx: f64, dust_limit_kx: f64) -> anyhow::Result<u128> {
+    if !dust_limit_kx.is_finite() || dust_limit_kx < 0.0 {
+        bail!(t!("err-invalid-dust-limit", value = dust_limit_kx));
+    }
+    let chronos = kx_to_chronos(amount_kx);
+    if chronos < kx_to_chronos(dust_limit_kx) {
+        bail!(t!("err-below-dust-limit", amount = amount_kx, min = dust_limit_kx));
+    }
+    Ok(chronos)
 }
 
 fn expand_tilde(path: &Path) -> PathBuf {
@@ -1432,3 +2397,362 @@ fn expand_tilde(path: &Path) -> PathBuf {
     }
     path.to_path_buf()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chronx_core::claims::{ClaimLane, LaneThresholds};
+    use chronx_rpc::{RpcOracleSnapshot, RpcTimeLock};
+
+    fn mock_lock(amount_kx: u128, oracle_hint: Option<&str>) -> RpcTimeLock {
+        serde_json::from_value(serde_json::json!({
+            "lock_id": "00".repeat(32),
+            "sender": "sender",
+            "recipient_account_id": "recipient",
+            "amount_chronos": (amount_kx * CHRONOS_PER_KX).to_string(),
+            "amount_kx": amount_kx.to_string(),
+            "unlock_at": 0,
+            "created_at": 0,
+            "status": "Pending",
+            "private": false,
+            "lock_version": 0,
+            "oracle_hint": oracle_hint,
+        }))
+        .unwrap()
+    }
+
+    fn mock_snapshot(price_cents: u64, updated_at: i64) -> RpcOracleSnapshot {
+        RpcOracleSnapshot {
+            pair: "KX/USD".into(),
+            price_cents,
+            num_submissions: 3,
+            updated_at,
+        }
+    }
+
+    #[test]
+    fn estimate_lock_value_lanes() {
+        let lanes = LaneThresholds::default_thresholds();
+        // $2.50 per KX.
+        let snap = mock_snapshot(250, 0);
+        let value = |kx: u128| {
+            let lock = mock_lock(kx, None);
+            lock_value_usd_cents(lock.amount_chronos.parse().unwrap(), snap.price_cents)
+        };
+        assert_eq!(value(100), 25_000);
+        assert_eq!(lanes.lane_for(value(100)), ClaimLane::Trivial);
+        assert_eq!(lanes.lane_for(value(1_000)), ClaimLane::Standard);
+        assert_eq!(lanes.lane_for(value(100_000)), ClaimLane::Elevated);
+        // Fractional KX is valued, not truncated.
+        assert_eq!(lock_value_usd_cents(CHRONOS_PER_KX / 2, 250), 125);
+        assert_eq!(lock_value_usd_cents(u128::MAX, 250), u64::MAX);
+    }
+
+    #[test]
+    fn estimate_lock_value_pair_and_staleness() {
+        assert_eq!(oracle_pair_for(&mock_lock(1, None)), "KX/USD");
+        assert_eq!(oracle_pair_for(&mock_lock(1, Some("eur"))), "KX/EUR");
+        assert_eq!(oracle_pair_for(&mock_lock(1, Some("KX/GBP"))), "KX/GBP");
+
+        let max_age = chronx_core::constants::ORACLE_MAX_AGE_SECS;
+        let snap = mock_snapshot(100, 1_000);
+        assert!(!oracle_is_stale(&snap, 1_000 + max_age));
+        assert!(oracle_is_stale(&snap, 1_000 + max_age + 1));
+    }
+
+    #[test]
+    fn short_id_must_match_exactly_one() {
+        let one = unique_match("lock", "abcd0102", vec!["abcd0102ff".into()]).unwrap();
+        assert_eq!(one, "abcd0102ff");
+        assert!(unique_match("lock", "abcd0102", vec![]).is_err());
+        let err = unique_match("account", "abcd0102", vec!["a".into(), "b".into()]).unwrap_err();
+        assert!(err.to_string().contains("ambiguous"));
+    }
+
+    fn mock_recovery(active: bool, votes_approve: u32, seconds_until_execution: i64) -> chronx_rpc::RpcRecoveryStatus {
+        serde_json::from_value(serde_json::json!({
+            "account_id": "target",
+            "active": active,
+            "initiator": "initiator",
+            "challenger": null,
+            "proposed_owner_key": "00",
+            "recovery_bond_chronos": "0",
+            "challenge_bond_chronos": "0",
+            "recovery_start_time": 0,
+            "recovery_execute_after": seconds_until_execution,
+            "seconds_until_execution": seconds_until_execution,
+            "challenge_active": false,
+            "votes_approve": votes_approve,
+            "votes_reject": 0,
+            "verifier_threshold": 3,
+            "decision_status": if active { "Pending" } else { "Rejected" },
+            "evidence_hash": null,
+            "counter_evidence_hash": null,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn recovery_is_ready_once_approved_and_delay_elapsed() {
+        assert_eq!(recovery_progress(&mock_recovery(true, 2, 0)), RecoveryProgress::Waiting);
+        assert_eq!(recovery_progress(&mock_recovery(true, 3, 60)), RecoveryProgress::Waiting);
+        assert_eq!(recovery_progress(&mock_recovery(true, 3, 0)), RecoveryProgress::Ready);
+        assert_eq!(recovery_progress(&mock_recovery(false, 3, 0)), RecoveryProgress::Ended);
+    }
+
+    #[test]
+    fn recover_account_needs_a_new_keyfile_unless_challenging() {
+        let parse = |args: &[&str]| {
+            let mut argv = vec!["chronx-wallet", "recover-account", "--target", "t", "--evidence", "00", "--bond-kx", "1"];
+            argv.extend_from_slice(args);
+            Args::try_parse_from(argv)
+        };
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["--new-keyfile", "new.json"]).is_ok());
+        assert!(parse(&["--challenge"]).is_ok());
+        assert!(parse(&["--challenge", "--auto-finalize"]).is_err());
+    }
+
+    #[test]
+    fn transfer_below_dust_limit_is_refused() {
+        assert_eq!(transfer_chronos(1.0, 0.001).unwrap(), CHRONOS_PER_KX);
+        assert_eq!(transfer_chronos(0.001, 0.001).unwrap(), 1_000);
+        assert!(transfer_chronos(0.000999, 0.001).is_err());
+        assert_eq!(transfer_chronos(0.000999, 0.0).unwrap(), 999);
+        assert!(transfer_chronos(1.0, -1.0).is_err());
+        assert!(transfer_chronos(1.0, f64::NAN).is_err());
+    }
+
+    #[test]
+    fn balance_report_in_spanish() {
+        let report = balance_report(Lang::Es, "acct", 12 * CHRONOS_PER_KX + 5);
+        assert_eq!(
+            report,
+            "Cuenta:   acct\nSaldo:    12.000.005 Chronos = 12,000005 KX"
+        );
+        assert!(balance_report(Lang::En, "acct", 0).starts_with("Account:  acct\n"));
+        assert_eq!(Lang::parse("es_ES.UTF-8"), Some(Lang::Es));
+        assert_eq!(i18n::detect(&["chronx-wallet".into(), "--lang=es".into()]), Lang::Es);
+    }
+
+    #[test]
+    fn every_subcommand_has_localized_help() {
+        for lang in [Lang::En, Lang::Es] {
+            for sub in localized_command(lang).get_subcommands() {
+                let key = format!("help-{}", sub.get_name());
+                assert!(i18n::has(lang, &key), "{lang:?} is missing {key}");
+            }
+        }
+        let es = localized_command(Lang::Es);
+        let balance = es.find_subcommand("balance").unwrap();
+        assert_eq!(balance.get_about().unwrap().to_string(), "Muestra el ID de cuenta y el saldo.");
+    }
+
+    fn mock_account(kp: &KeyPair) -> serde_json::Value {
+        serde_json::json!({
+            "account_id": kp.account_id.to_b58(),
+            "balance_chronos": "0", "balance_kx": "0",
+            "spendable_chronos": "0", "spendable_kx": "0",
+            "locked_chronos": "0", "locked_kx": "0",
+            "verifier_stake_chronos": "0",
+            "nonce": 0, "is_verifier": false, "recovery_active": false, "tip_height": 0,
+            "account_version": 0, "created_at": null,
+            "incoming_locks_count": 0, "outgoing_locks_count": 0,
+            "incoming_locked_chronos": "0", "outgoing_locked_chronos": "0",
+            "public_key": hex::encode(&kp.public_key.0),
+        })
+    }
+
+    /// Serve the three genesis accounts and their locks over JSON-RPC, the way
+    /// a live node would. `humanity_lock_sender` lets a test break the lock check.
+    async fn mock_node(
+        public_sale: &KeyPair,
+        treasury: &KeyPair,
+        humanity: &KeyPair,
+        humanity_lock_sender: &AccountId,
+    ) -> (String, jsonrpsee::server::ServerHandle) {
+        use chronx_genesis::{humanity_lock_id, treasury_lock_id};
+        use jsonrpsee::server::{RpcModule, Server};
+
+        let mut info = chronx_rpc::RpcGenesisInfo::current(0);
+        info.public_sale_account = Some(public_sale.account_id.to_b58());
+        info.treasury_account = Some(treasury.account_id.to_b58());
+        info.humanity_account = Some(humanity.account_id.to_b58());
+        let accounts: std::collections::HashMap<String, serde_json::Value> = [public_sale, treasury, humanity]
+            .into_iter()
+            .map(|kp| (kp.account_id.to_b58(), mock_account(kp)))
+            .collect();
+        let mut treasury_lock = mock_lock(1, None);
+        treasury_lock.sender = treasury.account_id.to_b58();
+        let mut humanity_lock = mock_lock(1, None);
+        humanity_lock.sender = humanity_lock_sender.to_b58();
+        let locks: std::collections::HashMap<String, serde_json::Value> = [
+            (treasury_lock_id(1).to_hex(), serde_json::to_value(treasury_lock).unwrap()),
+            (humanity_lock_id().to_hex(), serde_json::to_value(humanity_lock).unwrap()),
+        ]
+        .into_iter()
+        .collect();
+
+        let info = serde_json::to_value(info).unwrap();
+        let mut module = RpcModule::new(());
+        module
+            .register_method("chronx_getGenesisInfo", move |_, _, _| info.clone())
+            .unwrap();
+        module
+            .register_method("chronx_getAccount", move |params, _, _| {
+                let id: String = params.one().unwrap();
+                accounts.get(&id).cloned()
+            })
+            .unwrap();
+        module
+            .register_method("chronx_getLockById", move |params, _, _| {
+                let id: String = params.one().unwrap();
+                locks.get(&id).cloned()
+            })
+            .unwrap();
+        let server = Server::builder().build("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", server.local_addr().unwrap());
+        (url, server.start(module))
+    }
+
+    #[tokio::test]
+    async fn export_genesis_params_from_live_node() {
+        let (public_sale, treasury, humanity) = (KeyPair::generate(), KeyPair::generate(), KeyPair::generate());
+        let (url, _handle) = mock_node(&public_sale, &treasury, &humanity, &humanity.account_id).await;
+        let output = std::env::temp_dir().join("chronx_wallet_export_genesis_params.json");
+        let _ = std::fs::remove_file(&output);
+
+        cmd_export_genesis_params(&WalletRpcClient::new(&url), &output).await.unwrap();
+        let params: GenesisParams =
+            serde_json::from_str(&std::fs::read_to_string(&output).unwrap()).unwrap();
+        assert_eq!(params.public_sale_key, public_sale.public_key);
+        assert_eq!(params.treasury_key, treasury.public_key);
+        assert_eq!(params.humanity_key, humanity.public_key);
+
+        let err = cmd_export_genesis_params(&WalletRpcClient::new(&url), &output).await.unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+        std::fs::remove_file(&output).unwrap();
+    }
+
+    #[tokio::test]
+    async fn export_genesis_params_rejects_foreign_genesis_lock() {
+        let (public_sale, treasury, humanity) = (KeyPair::generate(), KeyPair::generate(), KeyPair::generate());
+        let (url, _handle) = mock_node(&public_sale, &treasury, &humanity, &treasury.account_id).await;
+        let output = std::env::temp_dir().join("chronx_wallet_export_genesis_foreign.json");
+        let _ = std::fs::remove_file(&output);
+
+        let err = cmd_export_genesis_params(&WalletRpcClient::new(&url), &output).await.unwrap_err();
+        assert!(err.to_string().contains("genesis humanity lock"), "{err}");
+        assert!(!output.exists());
+    }
+
+    /// A pending lock `i` to `recipient` holding `amount_kx`, matured at
+    /// `unlock_at`, with a claim policy if `policy` is set.
+    fn incoming_lock(i: u8, recipient: &str, amount_kx: u128, unlock_at: i64, policy: Option<u64>) -> RpcTimeLock {
+        let mut lock = mock_lock(amount_kx, None);
+        lock.lock_id = hex::encode([i; 32]);
+        lock.recipient_account_id = recipient.to_string();
+        lock.unlock_at = unlock_at;
+        if policy.is_some() {
+            lock.lock_version = 1;
+            lock.claim_policy = policy;
+        }
+        lock
+    }
+
+    /// Serve `locks` as every account's pending incoming locks.
+    async fn mock_claim_node(locks: Vec<RpcTimeLock>) -> (String, jsonrpsee::server::ServerHandle) {
+        use jsonrpsee::server::{RpcModule, Server};
+
+        let locks = serde_json::to_value(locks).unwrap();
+        let mut module = RpcModule::new(());
+        module.register_method("chronx_getPendingIncoming", move |_, _, _| locks.clone()).unwrap();
+        let server = Server::builder().build("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", server.local_addr().unwrap());
+        (url, server.start(module))
+    }
+
+    fn claimed_ids(actions: &[Action]) -> Vec<String> {
+        actions
+            .iter()
+            .map(|a| match a {
+                Action::TimeLockClaim { lock_id } => lock_id.0.to_hex(),
+                other => panic!("expected a claim, got {other:?}"),
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn claim_auto_and_all_pick_matured_direct_locks() {
+        const NOW: i64 = 1_800_000_000;
+        let kp = KeyPair::generate();
+        let me = kp.account_id.to_b58();
+        let locks = vec![
+            incoming_lock(1, &me, 5, NOW - 10, None),
+            incoming_lock(2, &me, 50, NOW - 10, Some(7)),
+            incoming_lock(3, &me, 20, NOW - 1, None),
+            incoming_lock(4, &me, 100, NOW + 60, None),
+            incoming_lock(5, &me, 8, NOW - 3_600, None),
+        ];
+        let (direct, workflow) = matured_locks(locks.clone(), NOW);
+        let ids = |locks: &[RpcTimeLock]| locks.iter().map(|l| l.lock_id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(&direct), [hex::encode([3; 32]), hex::encode([5; 32]), hex::encode([1; 32])]);
+        assert_eq!(ids(&workflow), [hex::encode([2; 32])]);
+
+        let (url, _handle) = mock_claim_node(locks).await;
+        let client = WalletRpcClient::new(&url);
+        let auto = matured_claim_actions(&client, &me, false, NOW).await.unwrap();
+        assert_eq!(claimed_ids(&auto), [hex::encode([3; 32])]);
+        let all = matured_claim_actions(&client, &me, true, NOW).await.unwrap();
+        assert_eq!(claimed_ids(&all), ids(&direct));
+        // A dry run stops before building a transaction, so never needs
+        // the nonce or DAG tips this node does not serve.
+        cmd_claim_matured(&client, &kp, true, true, NOW).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn claim_auto_fails_without_matured_locks() {
+        let kp = KeyPair::generate();
+        let me = kp.account_id.to_b58();
+        let (url, _handle) =
+            mock_claim_node(vec![incoming_lock(1, &me, 5, 2_000, None), incoming_lock(2, &me, 9, 0, Some(1))]).await;
+        let err = cmd_claim_matured(&WalletRpcClient::new(&url), &kp, false, false, 1_000).await.unwrap_err();
+        assert_eq!(err.to_string(), t!("err-no-matured-locks"));
+    }
+
+    #[test]
+    fn claim_needs_a_lock_id_or_auto() {
+        let parse = |args: &[&str]| Args::try_parse_from([&["chronx-wallet", "claim"], args].concat());
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["--auto", "--all"]).is_err());
+        assert!(parse(&["--lock-id", "ab", "--auto"]).is_err());
+        match parse(&["--all", "--dry-run"]).unwrap().command {
+            Command::Claim { lock_id, auto, all, dry_run } => assert!(lock_id.is_none() && !auto && all && dry_run),
+            other => panic!("expected a claim, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn tree_generate_child_writes_the_derived_keyfile() {
+        let parse = |args: &[&str]| Args::try_parse_from([&["chronx-wallet", "tree"], args].concat());
+        assert!(parse(&["--depth", "4"]).is_err());
+        assert!(parse(&["--children", "11"]).is_err());
+        assert!(parse(&["--generate-child", "--index", "1"]).is_err());
+        assert!(parse(&["--index", "1", "--out", "child.json"]).is_err());
+        assert!(parse(&["--generate-child", "--index", "10", "--out", "child.json"]).is_err());
+        let Command::Tree { index: Some(index), .. } =
+            parse(&["--generate-child", "--index", "2/1", "--out", "child.json"]).unwrap().command
+        else {
+            panic!("expected a tree command with an index");
+        };
+
+        let master = KeyPair::generate();
+        let out = std::env::temp_dir().join(format!("chronx_wallet_tree_child_{}.json", master.account_id.to_b58()));
+        cmd_generate_child(&master, &index, &out).unwrap();
+        let child = load_keypair(&out).unwrap();
+        assert_eq!(child.account_id, master.derive_child(2).derive_child(1).account_id);
+        let err = cmd_generate_child(&master, &index, &out).unwrap_err();
+        assert!(err.to_string().contains("already exists"), "{err}");
+        std::fs::remove_file(&out).unwrap();
+    }
+}
@@ -0,0 +1,131 @@
+//! `chronx:` URIs for QR codes.
+//!
+//! A bare account is `chronx:<account_b58>`; a payment request adds the
+//! amount in KX and an optional percent-encoded memo:
+//! `chronx:<account_b58>?amount=<kx>&memo=<memo>`.
+
+use anyhow::{bail, Context};
+use chronx_core::types::AccountId;
+use percent_encoding::{percent_decode_str, utf8_percent_encode, NON_ALPHANUMERIC};
+use qrcode::render::unicode::Dense1x2;
+use qrcode::QrCode;
+
+const SCHEME: &str = "chronx:";
+
+/// A request for `amount_kx` KX to `to`, as carried by a QR code.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PaymentRequest {
+    pub to: AccountId,
+    pub amount_kx: f64,
+    pub memo: Option<String>,
+}
+
+impl PaymentRequest {
+    pub fn to_uri(&self) -> String {
+        let mut uri = format!("{}?amount={}", account_uri(&self.to), self.amount_kx);
+        if let Some(memo) = &self.memo {
+            uri.push_str("&memo=");
+            uri.extend(utf8_percent_encode(memo, NON_ALPHANUMERIC));
+        }
+        uri
+    }
+
+    /// Parse a scanned `chronx:` payment request. Unknown query parameters
+    /// are ignored; the amount is required.
+    pub fn from_qr(data: &str) -> anyhow::Result<Self> {
+        let Some(rest) = data.trim().strip_prefix(SCHEME) else {
+            bail!(t!("err-payment-request-scheme"));
+        };
+        let (account, query) = rest.split_once('?').unwrap_or((rest, ""));
+        let to = AccountId::from_b58(account)
+            .map_err(|e| anyhow::anyhow!(t!("err-invalid-account", error = e)))?;
+        let mut amount_kx = None;
+        let mut memo = None;
+        for (key, value) in query.split('&').filter_map(|pair| pair.split_once('=')) {
+            match key {
+                "amount" => {
+                    let amount: f64 = value
+                        .parse()
+                        .with_context(|| t!("err-payment-request-amount", value = value))?;
+                    if !amount.is_finite() || amount <= 0.0 {
+                        bail!(t!("err-payment-request-amount", value = value));
+                    }
+                    amount_kx = Some(amount);
+                }
+                "memo" => {
+                    let decoded = percent_decode_str(value)
+                        .decode_utf8()
+                        .context(t!("err-payment-request-memo"))?;
+                    memo = Some(decoded.into_owned());
+                }
+                _ => {}
+            }
+        }
+        let Some(amount_kx) = amount_kx else {
+            bail!(t!("err-payment-request-no-amount"));
+        };
+        Ok(Self { to, amount_kx, memo })
+    }
+}
+
+/// `chronx:<account_b58>`.
+pub fn account_uri(account: &AccountId) -> String {
+    format!("{SCHEME}{}", account.to_b58())
+}
+
+/// Render `data` as a QR code with Unicode half blocks, light on dark so it
+/// scans from a dark terminal.
+pub fn render_qr(data: &str) -> anyhow::Result<String> {
+    let code = QrCode::new(data.as_bytes())?;
+    Ok(code
+        .render::<Dense1x2>()
+        .dark_color(Dense1x2::Light)
+        .light_color(Dense1x2::Dark)
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account() -> AccountId {
+        AccountId::from_bytes([7u8; 32])
+    }
+
+    #[test]
+    fn payment_request_round_trips() {
+        let request = PaymentRequest {
+            to: account(),
+            amount_kx: 12.5,
+            memo: Some("Invoice #42 — café & tea".to_string()),
+        };
+        let uri = request.to_uri();
+        assert!(uri.starts_with(&format!("chronx:{}?amount=12.5&memo=", account().to_b58())));
+        // The memo's own `&` and spaces are encoded.
+        assert_eq!(uri.matches('&').count(), 1);
+        assert!(!uri.contains(' '));
+        assert_eq!(PaymentRequest::from_qr(&uri).unwrap(), request);
+
+        let no_memo = PaymentRequest { memo: None, ..request };
+        assert_eq!(no_memo.to_uri(), format!("chronx:{}?amount=12.5", account().to_b58()));
+        assert_eq!(PaymentRequest::from_qr(&no_memo.to_uri()).unwrap(), no_memo);
+    }
+
+    #[test]
+    fn malformed_payment_requests_are_rejected() {
+        let b58 = account().to_b58();
+        assert!(PaymentRequest::from_qr(&format!("bitcoin:{b58}?amount=1")).is_err());
+        assert!(PaymentRequest::from_qr(&account_uri(&account())).is_err());
+        assert!(PaymentRequest::from_qr(&format!("chronx:{b58}?amount=-1")).is_err());
+        assert!(PaymentRequest::from_qr(&format!("chronx:{b58}?amount=lots")).is_err());
+        assert!(PaymentRequest::from_qr("chronx:not-an-account?amount=1").is_err());
+        assert!(PaymentRequest::from_qr(&format!("chronx:{b58}?amount=1&memo=%FF")).is_err());
+        assert_eq!(PaymentRequest::from_qr(&format!(" chronx:{b58}?label=x&amount=3 ")).unwrap().amount_kx, 3.0);
+    }
+
+    #[test]
+    fn qr_code_renders() {
+        let rendered = render_qr(&account_uri(&account())).unwrap();
+        assert!(rendered.lines().count() > 10);
+    }
+}
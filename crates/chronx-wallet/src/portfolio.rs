@@ -0,0 +1,442 @@
+//! `chronx-wallet portfolio`: the wallet account's locks grouped by maturity.
+//!
+//! Every lock the account sent or receives is listed; the maturity summary
+//! and the unlock timeline only count `Pending` locks, since the rest have
+//! already been claimed, cancelled or otherwise settled. USD estimates use
+//! the node's KX/USD oracle snapshot and are left out when there is none.
+//!
+//! `--timeline` instead lists every pending lock by unlock day, as
+//! `chronx_getAccountLockTimeline` returns them.
+
+use serde::Serialize;
+
+use chronx_core::units::{parse_kx, Amount};
+use chronx_rpc::{RpcLockTimelineDay, RpcTimeLock};
+
+use crate::rpc_client::WalletRpcClient;
+
+const DAY_SECS: i64 = 86_400;
+const YEAR_SECS: i64 = 365 * DAY_SECS;
+
+/// Maturity buckets: label and exclusive upper bound on the seconds left
+/// until unlock. Locks already past their unlock time fall in the first.
+const MATURITY_BUCKETS: [(&str, i64); 5] = [
+    ("0-30d", 30 * DAY_SECS),
+    ("30-90d", 90 * DAY_SECS),
+    ("90-365d", YEAR_SECS),
+    ("1-5y", 5 * YEAR_SECS),
+    ("5y+", i64::MAX),
+];
+
+/// Years covered by the unlock timeline.
+const TIMELINE_YEARS: usize = 10;
+
+/// Widest timeline bar; busier years are scaled down to fit.
+const TIMELINE_WIDTH: usize = 50;
+
+const CSV_HEADER: &str = "lock_id,role,counterparty,amount_kx,unlock_date,status,tags";
+
+const LOCK_TIMELINE_CSV_HEADER: &str = "unlock_date,lock_id,amount_kx,counterparty";
+
+/// One lock as the account sees it.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct PortfolioLock {
+    pub lock_id: String,
+    /// `sender`, `recipient`, or `self` for a lock to the account's own key.
+    pub role: &'static str,
+    /// The other party; the account itself for a `self` lock.
+    pub counterparty: String,
+    pub amount_chronos: u128,
+    pub amount_kx: String,
+    pub unlock_at: i64,
+    pub status: String,
+    pub tags: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct MaturityBucket {
+    pub label: &'static str,
+    pub count: usize,
+    pub total_chronos: u128,
+    /// Value of the bucket at the oracle price, if there is one.
+    pub usd_cents: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Portfolio {
+    pub account: String,
+    /// When the report was built; maturities are measured from here.
+    pub as_of: i64,
+    /// KX/USD oracle price used for the estimates.
+    pub price_cents: Option<u64>,
+    pub buckets: Vec<MaturityBucket>,
+    /// Pending locks unlocking in each of the next ten years.
+    pub timeline: [usize; TIMELINE_YEARS],
+    pub locks: Vec<PortfolioLock>,
+}
+
+impl Portfolio {
+    pub fn new(account: &str, locks: &[RpcTimeLock], price_cents: Option<u64>, now: i64) -> Self {
+        let locks: Vec<PortfolioLock> = locks.iter().map(|l| portfolio_lock(account, l)).collect();
+        let mut buckets: Vec<MaturityBucket> = MATURITY_BUCKETS
+            .iter()
+            .map(|&(label, _)| MaturityBucket { label, count: 0, total_chronos: 0, usd_cents: None })
+            .collect();
+        let mut timeline = [0; TIMELINE_YEARS];
+        for lock in locks.iter().filter(|l| l.status == "Pending") {
+            let remaining = (lock.unlock_at - now).max(0);
+            let bucket = &mut buckets[bucket_index(remaining)];
+            bucket.count += 1;
+            bucket.total_chronos = bucket.total_chronos.saturating_add(lock.amount_chronos);
+            if let Some(slot) = timeline.get_mut((remaining / YEAR_SECS) as usize) {
+                *slot += 1;
+            }
+        }
+        for bucket in &mut buckets {
+            bucket.usd_cents = price_cents.map(|p| crate::lock_value_usd_cents(bucket.total_chronos, p));
+        }
+        Self { account: account.to_string(), as_of: now, price_cents, buckets, timeline, locks }
+    }
+
+    /// Fetch the account's locks and the KX/USD price from the node.
+    pub async fn fetch(client: &WalletRpcClient, account: &str, now: i64) -> anyhow::Result<Self> {
+        let locks = client.get_locks(account).await?;
+        let price_cents = client.get_oracle_snapshot("KX/USD").await?.map(|s| s.price_cents);
+        Ok(Self::new(account, &locks, price_cents, now))
+    }
+
+    /// Maturity summary followed by the unlock timeline.
+    pub fn to_table(&self) -> String {
+        let total: u128 = self.buckets.iter().map(|b| b.total_chronos).sum();
+        let mut out = format!(
+            "{:<10} {:>6} {:>20} {:>8} {:>16}\n",
+            t!("portfolio-col-maturity"),
+            t!("portfolio-col-count"),
+            t!("portfolio-col-total"),
+            t!("portfolio-col-share"),
+            t!("portfolio-col-usd"),
+        );
+        for bucket in &self.buckets {
+            let share = if total == 0 { 0.0 } else { bucket.total_chronos as f64 * 100.0 / total as f64 };
+            out.push_str(&format!(
+                "{:<10} {:>6} {:>20} {:>7.1}% {:>16}\n",
+                bucket.label,
+                bucket.count,
+                format_kx(bucket.total_chronos),
+                share,
+                format_usd(bucket.usd_cents),
+            ));
+        }
+        out.push('\n');
+        out.push_str(&self.to_timeline());
+        out
+    }
+
+    /// One row per year from now, with a `+` per lock (scaled down when a
+    /// year has more than fits).
+    pub fn to_timeline(&self) -> String {
+        let busiest = self.timeline.iter().copied().max().unwrap_or(0);
+        let mut out = format!("{}\n", t!("portfolio-timeline-title", years = TIMELINE_YEARS));
+        for (year, &count) in self.timeline.iter().enumerate() {
+            let marks = if busiest <= TIMELINE_WIDTH { count } else { count.div_ceil(busiest.div_ceil(TIMELINE_WIDTH)) };
+            out.push_str(&format!("{:>3}-{:<3}| {} {count}\n", year, format!("{}y", year + 1), "+".repeat(marks)));
+        }
+        out
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut out = format!("{CSV_HEADER}\n");
+        for lock in &self.locks {
+            let row = [
+                lock.lock_id.clone(),
+                lock.role.to_string(),
+                lock.counterparty.clone(),
+                lock.amount_kx.clone(),
+                unlock_date(lock.unlock_at),
+                lock.status.clone(),
+                lock.tags.join(";"),
+            ];
+            out.push_str(&row.map(|f| csv_field(&f)).join(","));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// The account's pending locks by unlock day, sent and received.
+#[derive(Clone, Debug, Serialize)]
+pub struct LockTimeline {
+    pub account: String,
+    pub days: Vec<RpcLockTimelineDay>,
+}
+
+impl LockTimeline {
+    pub async fn fetch(client: &WalletRpcClient, account: &str) -> anyhow::Result<Self> {
+        let days = client.get_account_lock_timeline(account, None, None, "both").await?;
+        Ok(Self { account: account.to_string(), days })
+    }
+
+    /// A line per day with its total, then a line per lock.
+    pub fn to_table(&self) -> String {
+        if self.days.is_empty() {
+            return format!("{}\n", t!("portfolio-lock-timeline-empty"));
+        }
+        let mut out = String::new();
+        for day in &self.days {
+            out.push_str(&t!(
+                "portfolio-lock-timeline-day",
+                date = day.unlock_date_iso,
+                total = format_kx_str(&day.total_amount_kx),
+                count = day.locks.len(),
+            ));
+            out.push('\n');
+            for lock in &day.locks {
+                out.push_str(&format!(
+                    "  {} {:>20}  {}\n",
+                    lock.lock_id,
+                    format_kx_str(&lock.amount_kx),
+                    lock.counterparty
+                ));
+            }
+        }
+        out
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut out = format!("{LOCK_TIMELINE_CSV_HEADER}\n");
+        for day in &self.days {
+            for lock in &day.locks {
+                let row = [&day.unlock_date_iso, &lock.lock_id, &lock.amount_kx, &lock.counterparty];
+                out.push_str(&row.map(|f| csv_field(f)).join(","));
+                out.push('\n');
+            }
+        }
+        out
+    }
+}
+
+fn portfolio_lock(account: &str, lock: &RpcTimeLock) -> PortfolioLock {
+    let (role, counterparty) = match (lock.sender == account, lock.recipient_account_id == account) {
+        (true, true) => ("self", account),
+        (true, false) => ("sender", lock.recipient_account_id.as_str()),
+        _ => ("recipient", lock.sender.as_str()),
+    };
+    PortfolioLock {
+        lock_id: lock.lock_id.clone(),
+        role,
+        counterparty: counterparty.to_string(),
+        amount_chronos: lock.amount_chronos.parse().unwrap_or(0),
+        amount_kx: lock.amount_kx.clone(),
+        unlock_at: lock.unlock_at,
+        status: lock.status.clone(),
+        tags: lock.tags.clone().unwrap_or_default(),
+    }
+}
+
+fn bucket_index(remaining_secs: i64) -> usize {
+    MATURITY_BUCKETS.iter().position(|(_, bound)| remaining_secs < *bound).unwrap_or(MATURITY_BUCKETS.len() - 1)
+}
+
+fn format_kx(chronos: u128) -> String {
+    Amount(chronos).kx_in(&crate::i18n::current().number_locale())
+}
+
+/// A KX amount from the node, regrouped for the current language.
+fn format_kx_str(kx: &str) -> String {
+    parse_kx(kx).map(format_kx).unwrap_or_else(|_| format!("{kx} KX"))
+}
+
+fn format_usd(cents: Option<u64>) -> String {
+    match cents {
+        Some(c) => format!("${}.{:02}", c / 100, c % 100),
+        None => "-".to_string(),
+    }
+}
+
+fn unlock_date(unlock_at: i64) -> String {
+    chrono::DateTime::from_timestamp(unlock_at, 0)
+        .map(|d| d.format("%Y-%m-%d").to_string())
+        .unwrap_or_else(|| unlock_at.to_string())
+}
+
+/// Quote a CSV field if it holds a comma, quote or line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chronx_core::constants::CHRONOS_PER_KX;
+
+    const NOW: i64 = 1_800_000_000;
+    const ME: &str = "me";
+
+    /// Twenty locks: four pending in each maturity bucket, with two of the
+    /// 0-30d ones already past their unlock time, plus two claimed locks
+    /// that only show up in the export.
+    fn synthetic_locks() -> Vec<RpcTimeLock> {
+        let offsets = [
+            -DAY_SECS, -1, DAY_SECS, 29 * DAY_SECS,
+            30 * DAY_SECS, 45 * DAY_SECS, 60 * DAY_SECS, 89 * DAY_SECS,
+            90 * DAY_SECS, 200 * DAY_SECS, 300 * DAY_SECS, 364 * DAY_SECS,
+            YEAR_SECS, 2 * YEAR_SECS, 3 * YEAR_SECS, 4 * YEAR_SECS + 1,
+            5 * YEAR_SECS, 9 * YEAR_SECS, 20 * YEAR_SECS,
+        ];
+        let mut locks: Vec<RpcTimeLock> = offsets
+            .iter()
+            .enumerate()
+            .map(|(i, offset)| mock_lock(i, NOW + offset, "Pending"))
+            .collect();
+        locks.push(mock_lock(offsets.len(), NOW - YEAR_SECS, "Claimed"));
+        locks
+    }
+
+    /// Lock `i` holds `i + 1` KX. Even locks are sent by the account, odd
+    /// ones received, and every fifth is to itself.
+    fn mock_lock(i: usize, unlock_at: i64, status: &str) -> RpcTimeLock {
+        let (sender, recipient) = match i {
+            _ if i % 5 == 0 => (ME, ME),
+            _ if i % 2 == 0 => (ME, "bob"),
+            _ => ("carol", ME),
+        };
+        let kx = i as u128 + 1;
+        serde_json::from_value(serde_json::json!({
+            "lock_id": format!("{i:064x}"),
+            "sender": sender,
+            "recipient_account_id": recipient,
+            "amount_chronos": (kx * CHRONOS_PER_KX).to_string(),
+            "amount_kx": kx.to_string(),
+            "unlock_at": unlock_at,
+            "created_at": 0,
+            "status": status,
+            "private": false,
+            "lock_version": 0,
+            "tags": if i == 3 { vec!["gift", "birthday, 2030"] } else { vec![] },
+        }))
+        .unwrap()
+    }
+
+    async fn mock_node(price_cents: Option<u64>) -> (String, jsonrpsee::server::ServerHandle) {
+        use jsonrpsee::server::{RpcModule, Server};
+
+        let locks = serde_json::to_value(synthetic_locks()).unwrap();
+        let snapshot = price_cents.map(|price_cents| {
+            serde_json::json!({ "pair": "KX/USD", "price_cents": price_cents, "num_submissions": 3, "updated_at": NOW })
+        });
+        let mut module = RpcModule::new(());
+        module
+            .register_method("chronx_getLocks", move |params, _, _| {
+                let account: String = params.one().unwrap();
+                if account == ME { locks.clone() } else { serde_json::json!([]) }
+            })
+            .unwrap();
+        module
+            .register_method("chronx_getOracleSnapshot", move |_, _, _| snapshot.clone())
+            .unwrap();
+        module
+            .register_method("chronx_getAccountLockTimeline", |params, _, _| {
+                let (account, _, _, role): (String, Option<i64>, Option<i64>, String) = params.parse().unwrap();
+                assert_eq!(role, "both");
+                if account != ME {
+                    return serde_json::json!([]);
+                }
+                serde_json::json!([
+                    { "unlock_date_iso": "2027-01-15", "total_amount_kx": "1234.5", "locks": [
+                        { "lock_id": "aa", "amount_kx": "1234", "counterparty": "bob" },
+                        { "lock_id": "bb", "amount_kx": "0.5", "counterparty": "carol, jr" },
+                    ] },
+                    { "unlock_date_iso": "2027-03-01", "total_amount_kx": "2", "locks": [
+                        { "lock_id": "cc", "amount_kx": "2", "counterparty": "bob" },
+                    ] },
+                ])
+            })
+            .unwrap();
+        let server = Server::builder().build("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", server.local_addr().unwrap());
+        (url, server.start(module))
+    }
+
+    #[tokio::test]
+    async fn groups_pending_locks_by_maturity() {
+        let (url, _handle) = mock_node(Some(250)).await;
+        let portfolio = Portfolio::fetch(&WalletRpcClient::new(&url), ME, NOW).await.unwrap();
+
+        assert_eq!(portfolio.locks.len(), 20);
+        let counts: Vec<usize> = portfolio.buckets.iter().map(|b| b.count).collect();
+        assert_eq!(counts, [4, 4, 4, 4, 3]);
+        // Locks 0-3 hold 1 + 2 + 3 + 4 KX, valued at $2.50 each.
+        assert_eq!(portfolio.buckets[0].total_chronos, 10 * CHRONOS_PER_KX);
+        assert_eq!(portfolio.buckets[0].usd_cents, Some(2_500));
+        assert_eq!(portfolio.buckets[4].total_chronos, (17 + 18 + 19) * CHRONOS_PER_KX);
+        // The 20-year lock is past the end of the timeline.
+        assert_eq!(portfolio.timeline, [12, 1, 1, 1, 1, 1, 0, 0, 0, 1]);
+
+        let table = portfolio.to_table();
+        assert!(table.contains("$25.00"), "{table}");
+        assert!(table.lines().any(|l| l.starts_with("  0-1y | ++++++++++++ 12")), "{table}");
+        assert!(table.lines().any(|l| l.starts_with("  6-7y |  0")), "{table}");
+    }
+
+    #[tokio::test]
+    async fn usd_estimates_need_an_oracle_price() {
+        let (url, _handle) = mock_node(None).await;
+        let portfolio = Portfolio::fetch(&WalletRpcClient::new(&url), ME, NOW).await.unwrap();
+        assert_eq!(portfolio.price_cents, None);
+        assert!(portfolio.buckets.iter().all(|b| b.usd_cents.is_none()));
+
+        let (url, _handle) = mock_node(Some(250)).await;
+        let empty = Portfolio::fetch(&WalletRpcClient::new(&url), "nobody", NOW).await.unwrap();
+        assert!(empty.locks.is_empty());
+        assert!(empty.to_table().contains("0.0%"));
+    }
+
+    #[test]
+    fn csv_export_lists_every_lock() {
+        let portfolio = Portfolio::new(ME, &synthetic_locks(), None, NOW);
+        let csv = portfolio.to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 21);
+        assert_eq!(lines[0], CSV_HEADER);
+        assert_eq!(lines[1], format!("{:064x},self,me,1,2027-01-14,Pending,", 0));
+        assert_eq!(lines[2], format!("{:064x},recipient,carol,2,2027-01-15,Pending,", 1));
+        assert_eq!(lines[3], format!("{:064x},sender,bob,3,2027-01-16,Pending,", 2));
+        assert!(lines[4].ends_with(",Pending,\"gift;birthday, 2030\""), "{}", lines[4]);
+        assert!(lines[20].contains(",Claimed,"));
+    }
+
+    #[tokio::test]
+    async fn lock_timeline_lists_locks_by_day() {
+        let (url, _handle) = mock_node(None).await;
+        let timeline = LockTimeline::fetch(&WalletRpcClient::new(&url), ME).await.unwrap();
+        assert_eq!(timeline.days.len(), 2);
+
+        let table = timeline.to_table();
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines[0], "2027-01-15  total 1,234.5 KX, locks: 2");
+        assert!(lines[1].starts_with("  aa ") && lines[1].ends_with(" 1,234 KX  bob"), "{table}");
+        assert_eq!(lines[3], "2027-03-01  total 2 KX, locks: 1");
+
+        let csv = timeline.to_csv();
+        let rows: Vec<&str> = csv.lines().collect();
+        assert_eq!(rows[0], LOCK_TIMELINE_CSV_HEADER);
+        assert_eq!(rows[2], "2027-01-15,bb,0.5,\"carol, jr\"");
+        assert_eq!(rows.len(), 4);
+
+        let empty = LockTimeline::fetch(&WalletRpcClient::new(&url), "nobody").await.unwrap();
+        assert_eq!(empty.to_table(), "No pending locks.\n");
+    }
+
+    #[test]
+    fn timeline_scales_busy_years() {
+        let locks: Vec<RpcTimeLock> = (0..120).map(|i| mock_lock(i, NOW + DAY_SECS, "Pending")).collect();
+        let timeline = Portfolio::new(ME, &locks, None, NOW).to_timeline();
+        let first = timeline.lines().nth(1).unwrap();
+        assert_eq!(first.matches('+').count(), 40);
+        assert!(first.ends_with(" 120"));
+    }
+}
@@ -3,6 +3,9 @@ use anyhow::{bail, Context};
 use chronx_core::transaction::Transaction;
 use chronx_core::types::TxId;
 
+/// Most lock ids the node accepts in one chronx_getLockSummaryBatch call.
+const LOCK_SUMMARY_BATCH: usize = 100;
+
 /// Simple JSON-RPC 2.0 client used by the wallet to talk to a running node.
 ///
 /// Uses raw HTTP POST with serde_json rather than the full jsonrpsee client
@@ -98,8 +101,34 @@ impl WalletRpcClient {
             .collect()
     }
 
-    /// Submit a signed transaction. Returns the TxId hex.
+    /// Dry-run a signed transaction via chronx_simulateTransaction.
+    pub async fn simulate_transaction(&self, tx: &Transaction) -> anyhow::Result<chronx_rpc::RpcSimulationResult> {
+        let bytes = bincode::serialize(tx).context("serializing transaction")?;
+        let result = self
+            .call("chronx_simulateTransaction", serde_json::json!([hex::encode(&bytes)]))
+            .await?;
+        serde_json::from_value(result).context("parsing simulation result")
+    }
+
+    /// Submit a signed transaction. Returns the TxId hex once the node has
+    /// applied it, or given up waiting with it still queued; a rejection by
+    /// the node is an error.
+    ///
+    /// The transaction is simulated first so a rejection is reported before
+    /// anything is sent. It is sent anyway when the
+    /// node cannot simulate it: an action an older node does not dry-run, or a
+    /// node without chronx_simulateTransaction.
     pub async fn send_transaction(&self, tx: &Transaction) -> anyhow::Result<String> {
+        if let Ok(sim) = self.simulate_transaction(tx).await {
+            if !sim.ok && sim.error.as_deref() != Some("NotSimulatable") {
+                bail!(
+                    "transaction would be rejected ({}): {}",
+                    sim.error.unwrap_or_default(),
+                    sim.error_message.unwrap_or_default()
+                );
+            }
+        }
+
         let bytes = bincode::serialize(tx).context("serializing transaction")?;
         let tx_hex = hex::encode(&bytes);
 
@@ -123,8 +152,232 @@ impl WalletRpcClient {
         Ok(info)
     }
 
+    /// Get node build, host and runtime info via chronx_getSystemInfo.
+    pub async fn get_system_info(&self) -> anyhow::Result<chronx_rpc::RpcSystemInfo> {
+        let result = self
+            .call("chronx_getSystemInfo", serde_json::json!([]))
+            .await?;
+        serde_json::from_value(result).context("parsing system info")
+    }
+
+    /// Current PoW difficulty and latest adjustment via chronx_getDifficulty.
+    pub async fn get_difficulty(&self) -> anyhow::Result<chronx_rpc::RpcDifficulty> {
+        let result = self.call("chronx_getDifficulty", serde_json::json!([])).await?;
+        serde_json::from_value(result).context("parsing difficulty")
+    }
+
+    /// Recent difficulty adjustments, oldest first, via chronx_getDifficultyHistory.
+    pub async fn get_difficulty_history(
+        &self,
+        limit: u32,
+    ) -> anyhow::Result<Vec<chronx_rpc::RpcDifficultyAdjustment>> {
+        let result = self
+            .call("chronx_getDifficultyHistory", serde_json::json!([limit]))
+            .await?;
+        serde_json::from_value(result).context("parsing difficulty history")
+    }
+
+    /// Fetch one time-lock via chronx_getLockById.
+    pub async fn get_lock_by_id(
+        &self,
+        lock_id: &str,
+    ) -> anyhow::Result<Option<chronx_rpc::RpcTimeLock>> {
+        let result = self
+            .call("chronx_getLockById", serde_json::json!([lock_id]))
+            .await?;
+        serde_json::from_value(result).context("parsing time-lock")
+    }
+
+    /// Locks the account sent or receives via chronx_getLocks, newest first.
+    pub async fn get_locks(&self, account_id: &str) -> anyhow::Result<Vec<chronx_rpc::RpcTimeLock>> {
+        let result = self
+            .call("chronx_getLocks", serde_json::json!([account_id]))
+            .await?;
+        serde_json::from_value(result).context("parsing time-locks")
+    }
+
+    /// The account's pending locks grouped by unlock day via
+    /// chronx_getAccountLockTimeline. `role` is `sender`, `recipient` or `both`.
+    pub async fn get_account_lock_timeline(
+        &self,
+        account_id: &str,
+        from_ts: Option<i64>,
+        to_ts: Option<i64>,
+        role: &str,
+    ) -> anyhow::Result<Vec<chronx_rpc::RpcLockTimelineDay>> {
+        let result = self
+            .call("chronx_getAccountLockTimeline", serde_json::json!([account_id, from_ts, to_ts, role]))
+            .await?;
+        serde_json::from_value(result).context("parsing lock timeline")
+    }
+
+    /// Pending locks the account receives via chronx_getPendingIncoming,
+    /// soonest unlock first.
+    pub async fn get_pending_incoming(&self, account_id: &str) -> anyhow::Result<Vec<chronx_rpc::RpcTimeLock>> {
+        let result = self
+            .call("chronx_getPendingIncoming", serde_json::json!([account_id]))
+            .await?;
+        serde_json::from_value(result).context("parsing time-locks")
+    }
+
+    /// Summaries of `lock_ids` in input order, fetched with
+    /// chronx_getLockSummaryBatch in batches of up to 100.
+    pub async fn get_lock_summaries(
+        &self,
+        lock_ids: &[String],
+    ) -> anyhow::Result<Vec<chronx_rpc::RpcLockSummary>> {
+        let mut out = Vec::with_capacity(lock_ids.len());
+        for batch in lock_ids.chunks(LOCK_SUMMARY_BATCH) {
+            let result = self
+                .call("chronx_getLockSummaryBatch", serde_json::json!([batch]))
+                .await?;
+            let summaries: Vec<chronx_rpc::RpcLockSummary> =
+                serde_json::from_value(result).context("parsing lock summaries")?;
+            out.extend(summaries);
+        }
+        Ok(out)
+    }
+
+    /// Fetch a private lock's full details via chronx_getPrivateTimelockDetails,
+    /// proving ownership with `challenge_signature_hex`.
+    pub async fn get_private_timelock_details(
+        &self,
+        lock_id: &str,
+        challenge_signature_hex: &str,
+    ) -> anyhow::Result<chronx_rpc::RpcTimeLock> {
+        let result = self
+            .call(
+                "chronx_getPrivateTimelockDetails",
+                serde_json::json!([lock_id, challenge_signature_hex]),
+            )
+            .await?;
+        serde_json::from_value(result).context("parsing time-lock")
+    }
+
+    /// Fetch the current oracle snapshot for a pair via chronx_getOracleSnapshot.
+    pub async fn get_oracle_snapshot(
+        &self,
+        pair: &str,
+    ) -> anyhow::Result<Option<chronx_rpc::RpcOracleSnapshot>> {
+        let result = self
+            .call("chronx_getOracleSnapshot", serde_json::json!([pair]))
+            .await?;
+        serde_json::from_value(result).context("parsing oracle snapshot")
+    }
+
+    /// Whether the account currently has a recovery in flight (via chronx_getAccount).
+    pub async fn is_recovery_active(&self, account_id: &str) -> anyhow::Result<Option<bool>> {
+        let result = self
+            .call("chronx_getAccount", serde_json::json!([account_id]))
+            .await?;
+        if result.is_null() {
+            return Ok(None);
+        }
+        Ok(Some(result["recovery_active"].as_bool().unwrap_or(false)))
+    }
+
+    /// Full recovery state via chronx_getRecoveryStatus.
+    pub async fn get_recovery_status(
+        &self,
+        account_id: &str,
+    ) -> anyhow::Result<Option<chronx_rpc::RpcRecoveryStatus>> {
+        let result = self
+            .call("chronx_getRecoveryStatus", serde_json::json!([account_id]))
+            .await?;
+        serde_json::from_value(result).context("parsing recovery status")
+    }
+
+    /// Fetch recovery evidence hashes and URIs via chronx_getRecoveryEvidence.
+    pub async fn get_recovery_evidence(
+        &self,
+        account_id: &str,
+    ) -> anyhow::Result<Option<chronx_rpc::RpcRecoveryEvidence>> {
+        let result = self
+            .call("chronx_getRecoveryEvidence", serde_json::json!([account_id]))
+            .await?;
+        serde_json::from_value(result).context("parsing recovery evidence")
+    }
+
+    /// Stream status changes for one lock via the chronx_subscribeLockStatus
+    /// WebSocket subscription (same host/port as the HTTP endpoint).
+    ///
+    /// The channel closes after a terminal status or when the node drops the
+    /// connection.
+    pub async fn subscribe_lock_status(
+        &self,
+        lock_id: &str,
+    ) -> anyhow::Result<tokio::sync::mpsc::Receiver<chronx_rpc::RpcLockStatusEvent>> {
+        use jsonrpsee::core::client::SubscriptionClientT;
+        use jsonrpsee::ws_client::WsClientBuilder;
+
+        let ws_url = if let Some(rest) = self.url.strip_prefix("https://") {
+            format!("wss://{rest}")
+        } else if let Some(rest) = self.url.strip_prefix("http://") {
+            format!("ws://{rest}")
+        } else {
+            self.url.clone()
+        };
+        let ws = WsClientBuilder::default()
+            .build(&ws_url)
+            .await
+            .with_context(|| format!("connecting to node websocket at {ws_url}"))?;
+        let mut sub = ws
+            .subscribe::<chronx_rpc::RpcLockStatusEvent, _>(
+                "chronx_subscribeLockStatus",
+                jsonrpsee::rpc_params![lock_id],
+                "chronx_unsubscribeLockStatus",
+            )
+            .await
+            .context("subscribing to lock status")?;
+
+        let (tx, rx) = tokio::sync::mpsc::channel(16);
+        tokio::spawn(async move {
+            // `ws` must outlive the subscription.
+            let _ws = ws;
+            while let Some(Ok(event)) = sub.next().await {
+                let terminal = event.is_terminal;
+                if tx.send(event).await.is_err() || terminal {
+                    break;
+                }
+            }
+        });
+        Ok(rx)
+    }
+
     /// Look up locks by claim_secret_hash via chronx_getCascadeDetails.
     pub async fn get_cascade_details(&self, claim_hash_hex: &str) -> anyhow::Result<serde_json::Value> {
         self.call("chronx_getCascadeDetails", serde_json::json!([claim_hash_hex])).await
     }
+
+    /// Fetch one account via chronx_getAccount.
+    pub async fn get_account(&self, account_id: &str) -> anyhow::Result<Option<chronx_rpc::RpcAccount>> {
+        let result = self
+            .call("chronx_getAccount", serde_json::json!([account_id]))
+            .await?;
+        serde_json::from_value(result).context("parsing account")
+    }
+
+    /// Resolve a transaction, account, lock or client_ref via chronx_search.
+    pub async fn search(&self, query: &str) -> anyhow::Result<chronx_rpc::RpcSearchResult> {
+        let result = self.call("chronx_search", serde_json::json!([query])).await?;
+        serde_json::from_value(result).context("parsing search result")
+    }
+
+    /// Account ids (base-58) whose bytes start with `prefix_hex`.
+    pub async fn find_account_by_prefix(&self, prefix_hex: &str) -> anyhow::Result<Vec<String>> {
+        let result = self
+            .call("chronx_findAccountByPrefix", serde_json::json!([prefix_hex]))
+            .await?;
+        let matches: Vec<chronx_rpc::RpcAccountMatch> =
+            serde_json::from_value(result).context("parsing account matches")?;
+        Ok(matches.into_iter().map(|m| m.account_id).collect())
+    }
+
+    /// Lock ids (hex) starting with `prefix_hex`.
+    pub async fn find_lock_by_prefix(&self, prefix_hex: &str) -> anyhow::Result<Vec<String>> {
+        let result = self
+            .call("chronx_findLockByPrefix", serde_json::json!([prefix_hex]))
+            .await?;
+        serde_json::from_value(result).context("parsing lock matches")
+    }
 }
@@ -0,0 +1,218 @@
+//! `chronx-wallet tree`: the keyfile's account and the sub-accounts derived
+//! from it.
+//!
+//! Child `i` of a key is [`KeyPair::derive_child`]`(i)`, so every
+//! sub-account follows from the master keyfile alone: backing up that one
+//! file backs them all up. `--generate-child` writes one sub-account's
+//! keypair to its own keyfile, so it can sign without the master key.
+
+use std::fmt;
+use std::str::FromStr;
+
+use chronx_core::units::Amount;
+use chronx_crypto::KeyPair;
+
+use crate::rpc_client::WalletRpcClient;
+
+/// Deepest sub-account level.
+pub const MAX_DEPTH: usize = 3;
+
+/// Most sub-accounts shown per key; also the bound on an exported index.
+pub const MAX_CHILDREN: u32 = 10;
+
+/// Characters of the account id shown per key.
+const SHORT_ACCOUNT_CHARS: usize = 8;
+
+/// Child indices from the master key down, e.g. `2/0` for child 0 of
+/// child 2. The empty path is the master key itself.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KeyPath(pub Vec<u32>);
+
+impl KeyPath {
+    /// The keypair at this path below `master`.
+    pub fn derive(&self, master: &KeyPair) -> KeyPair {
+        let mut indices = self.0.iter();
+        let Some(&first) = indices.next() else {
+            return KeyPair::from_raw(master.public_key.0.clone(), master.secret_key_bytes().to_vec());
+        };
+        indices.fold(master.derive_child(first), |key, &i| key.derive_child(i))
+    }
+
+    fn child(&self, index: u32) -> Self {
+        let mut path = self.0.clone();
+        path.push(index);
+        Self(path)
+    }
+}
+
+impl FromStr for KeyPath {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        let indices = s
+            .split('/')
+            .map(|part| part.trim().parse::<u32>().ok().filter(|i| *i < MAX_CHILDREN))
+            .collect::<Option<Vec<_>>>()
+            .filter(|path| path.len() <= MAX_DEPTH)
+            .ok_or_else(|| {
+                anyhow::anyhow!(t!("err-bad-key-path", path = s, depth = MAX_DEPTH, children = MAX_CHILDREN))
+            })?;
+        Ok(Self(indices))
+    }
+}
+
+impl fmt::Display for KeyPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts: Vec<String> = self.0.iter().map(u32::to_string).collect();
+        f.write_str(&parts.join("/"))
+    }
+}
+
+/// One key in the tree, with the balance of its account.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyNode {
+    pub path: KeyPath,
+    pub account: String,
+    /// `None` until [`fetch_balances`](Self::fetch_balances) runs.
+    pub balance_chronos: Option<u128>,
+    pub children: Vec<KeyNode>,
+}
+
+impl KeyNode {
+    /// `master` and `children` sub-accounts under each key, `depth` levels
+    /// down. Both are capped at [`MAX_DEPTH`] and [`MAX_CHILDREN`].
+    pub fn derive(master: &KeyPair, depth: usize, children: u32) -> Self {
+        Self::derive_at(KeyPath::default(), master, depth.min(MAX_DEPTH), children.min(MAX_CHILDREN))
+    }
+
+    fn derive_at(path: KeyPath, key: &KeyPair, depth: usize, children: u32) -> Self {
+        let children = match depth {
+            0 => Vec::new(),
+            _ => (0..children)
+                .map(|i| Self::derive_at(path.child(i), &key.derive_child(i), depth - 1, children))
+                .collect(),
+        };
+        Self { path, account: key.account_id.to_b58(), balance_chronos: None, children }
+    }
+
+    /// Look up every account's balance on the node.
+    pub async fn fetch_balances(&mut self, client: &WalletRpcClient) -> anyhow::Result<()> {
+        let mut pending = vec![self];
+        while let Some(node) = pending.pop() {
+            node.balance_chronos = Some(client.get_balance(&node.account).await?);
+            pending.extend(node.children.iter_mut());
+        }
+        Ok(())
+    }
+
+    /// The tree drawn in ASCII, the master key marked with ★.
+    pub fn to_tree(&self) -> anyhow::Result<String> {
+        let mut builder = ptree::TreeBuilder::new(format!("★ {}", self.label()));
+        for child in &self.children {
+            child.build(&mut builder);
+        }
+        let mut out = Vec::new();
+        let config = ptree::PrintConfig {
+            characters: ptree::print_config::ASCII_CHARS_TICK.into(),
+            ..Default::default()
+        };
+        ptree::write_tree_with(&builder.build(), &mut out, &config)?;
+        Ok(String::from_utf8(out)?)
+    }
+
+    fn build(&self, builder: &mut ptree::TreeBuilder) {
+        let label = format!("{}  {}", self.path, self.label());
+        if self.children.is_empty() {
+            builder.add_empty_child(label);
+            return;
+        }
+        builder.begin_child(label);
+        for child in &self.children {
+            child.build(builder);
+        }
+        builder.end_child();
+    }
+
+    fn label(&self) -> String {
+        let short: String = self.account.chars().take(SHORT_ACCOUNT_CHARS).collect();
+        let balance = match self.balance_chronos {
+            Some(chronos) => Amount(chronos).kx_in(&crate::i18n::current().number_locale()),
+            None => "-".to_string(),
+        };
+        format!("{short}…  {balance}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chronx_core::constants::CHRONOS_PER_KX;
+
+    /// Serve `chronx_getBalance`: `rich` holds 5 KX, every other account
+    /// nothing.
+    async fn mock_node(rich: String) -> (String, jsonrpsee::server::ServerHandle) {
+        use jsonrpsee::server::{RpcModule, Server};
+
+        let mut module = RpcModule::new(());
+        module
+            .register_method("chronx_getBalance", move |params, _, _| {
+                let account: String = params.one().unwrap();
+                let chronos = if account == rich { 5 * CHRONOS_PER_KX } else { 0 };
+                chronos.to_string()
+            })
+            .unwrap();
+        let server = Server::builder().build("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", server.local_addr().unwrap());
+        (url, server.start(module))
+    }
+
+    #[test]
+    fn key_paths_stay_within_the_limits() {
+        assert_eq!("2".parse::<KeyPath>().unwrap(), KeyPath(vec![2]));
+        assert_eq!("2/0/9".parse::<KeyPath>().unwrap(), KeyPath(vec![2, 0, 9]));
+        assert_eq!(KeyPath(vec![2, 0]).to_string(), "2/0");
+        for bad in ["", "10", "-1", "1/x", "1/2/3/4"] {
+            assert!(bad.parse::<KeyPath>().is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn tree_is_derived_from_the_master_key() {
+        let master = KeyPair::generate();
+        let tree = KeyNode::derive(&master, 2, 3);
+        assert_eq!(tree.account, master.account_id.to_b58());
+        assert_eq!(tree.children.len(), 3);
+        assert!(tree.children.iter().all(|c| c.children.len() == 3 && c.children[0].children.is_empty()));
+
+        let grandchild = &tree.children[2].children[1];
+        assert_eq!(grandchild.path, KeyPath(vec![2, 1]));
+        assert_eq!(grandchild.account, KeyPath(vec![2, 1]).derive(&master).account_id.to_b58());
+        assert_eq!(tree, KeyNode::derive(&master, 2, 3));
+
+        // Depth and width are capped.
+        let capped = KeyNode::derive(&master, 1, 50);
+        assert_eq!(capped.children.len(), MAX_CHILDREN as usize);
+        assert_eq!(KeyPath::default().derive(&master).account_id, master.account_id);
+    }
+
+    #[tokio::test]
+    async fn tree_shows_balances_from_the_node() {
+        let master = KeyPair::generate();
+        let rich = KeyPath(vec![1]).derive(&master).account_id.to_b58();
+        let (url, _handle) = mock_node(rich.clone()).await;
+
+        let mut tree = KeyNode::derive(&master, 1, 2);
+        tree.fetch_balances(&WalletRpcClient::new(&url)).await.unwrap();
+        assert_eq!(tree.balance_chronos, Some(0));
+        assert_eq!(tree.children[1].balance_chronos, Some(5 * CHRONOS_PER_KX));
+
+        let short = |account: &str| account.chars().take(SHORT_ACCOUNT_CHARS).collect::<String>();
+        let text = tree.to_tree().unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with(&format!("★ {}…", short(&tree.account))), "{text}");
+        assert!(lines[1].contains(&format!("0  {}…", short(&tree.children[0].account))), "{text}");
+        assert!(lines[2].contains(&format!("1  {}…", short(&rich))), "{text}");
+        assert!(lines[2].ends_with("5 KX"), "{text}");
+    }
+}